@@ -1,4 +1,14 @@
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+
 use clap::Subcommand;
+use pomodoroom_core::integrations::{
+    discord::DiscordIntegration, github::GitHubIntegration, google::GoogleIntegration,
+    linear::LinearIntegration, notion::NotionIntegration, slack::SlackIntegration, Integration,
+};
+use pomodoroom_core::policy::Compatibility;
+use pomodoroom_core::storage::ConfigBundle;
 use pomodoroom_core::Config;
 
 #[derive(Subcommand)]
@@ -19,6 +29,20 @@ pub enum ConfigAction {
     List,
     /// Reset config to defaults
     Reset,
+    /// Export settings as a portable bundle (no credentials)
+    Export {
+        /// Output file path (prints to stdout if not specified)
+        #[arg(short, long)]
+        output: Option<PathBuf>,
+        /// Output format: json or toml (defaults to the output file extension)
+        #[arg(long)]
+        format: Option<String>,
+    },
+    /// Import settings from a bundle created by `config export`
+    Import {
+        /// Bundle file (.json or .toml)
+        file: PathBuf,
+    },
 }
 
 pub fn run(action: ConfigAction) -> Result<(), Box<dyn std::error::Error>> {
@@ -34,7 +58,7 @@ pub fn run(action: ConfigAction) -> Result<(), Box<dyn std::error::Error>> {
             }
         }
         ConfigAction::Set { key, value } => {
-            let mut config = Config::load_or_default();
+            let mut config = Config::load()?;
             config.set(&key, &value)?;
             println!("ok");
         }
@@ -75,6 +99,12 @@ pub fn run(action: ConfigAction) -> Result<(), Box<dyn std::error::Error>> {
             println!("window_float: {}", config.window_float);
             println!("tray_enabled: {}", config.tray_enabled);
             println!("auto_advance: {}", config.auto_advance);
+            println!("auto_start_breaks: {}", config.auto_start_breaks);
+            println!("auto_start_focus: {}", config.auto_start_focus);
+            println!(
+                "encrypt_sensitive_fields: {}",
+                config.encrypt_sensitive_fields
+            );
             println!("shortcuts: {} entries", config.shortcuts.bindings.len());
         }
         ConfigAction::Reset => {
@@ -82,6 +112,96 @@ pub fn run(action: ConfigAction) -> Result<(), Box<dyn std::error::Error>> {
             config.save()?;
             println!("config reset to defaults");
         }
+        ConfigAction::Export { output, format } => export_bundle(output, format)?,
+        ConfigAction::Import { file } => import_bundle(&file)?,
+    }
+    Ok(())
+}
+
+/// Integrations whose enabled flag is carried in config bundles.
+const INTEGRATIONS: [&str; 6] = ["google", "notion", "linear", "github", "discord", "slack"];
+
+fn is_authenticated(service: &str) -> bool {
+    match service {
+        "google" => GoogleIntegration::new().is_authenticated(),
+        "notion" => NotionIntegration::new().is_authenticated(),
+        "linear" => LinearIntegration::new().is_authenticated(),
+        "github" => GitHubIntegration::new().is_authenticated(),
+        "discord" => DiscordIntegration::new().is_authenticated(),
+        "slack" => SlackIntegration::new().is_authenticated(),
+        _ => false,
+    }
+}
+
+fn is_toml(path: &Path) -> bool {
+    path.extension()
+        .is_some_and(|ext| ext.eq_ignore_ascii_case("toml"))
+}
+
+fn export_bundle(
+    output: Option<PathBuf>,
+    format: Option<String>,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let integrations: BTreeMap<String, bool> = INTEGRATIONS
+        .iter()
+        .map(|service| (service.to_string(), is_authenticated(service)))
+        .collect();
+    let bundle = Config::load_or_default()
+        .export_bundle()
+        .with_integrations(integrations);
+
+    let format = format.unwrap_or_else(|| match &output {
+        Some(path) if is_toml(path) => "toml".to_string(),
+        _ => "json".to_string(),
+    });
+    let content = match format.as_str() {
+        "json" => bundle.to_json()?,
+        "toml" => bundle.to_toml()?,
+        other => return Err(format!("unknown format: {other} (expected json or toml)").into()),
+    };
+
+    match output {
+        Some(path) => {
+            fs::write(&path, &content)?;
+            println!("Config exported to: {}", path.display());
+        }
+        None => println!("{content}"),
+    }
+    Ok(())
+}
+
+fn import_bundle(file: &Path) -> Result<(), Box<dyn std::error::Error>> {
+    let content = fs::read_to_string(file)?;
+    let bundle = if is_toml(file) {
+        ConfigBundle::from_toml(&content)?
+    } else {
+        ConfigBundle::from_json(&content)?
+    };
+
+    let mut config = Config::load()?;
+    let compatibility = config.import_bundle(&bundle)?;
+    if let Compatibility::MinorNewer { .. } = compatibility {
+        println!("Warning: {compatibility}");
+    }
+    config.save()?;
+    println!(
+        "Config imported from: {} (exported {})",
+        file.display(),
+        bundle.exported_at.format("%Y-%m-%d %H:%M:%S UTC")
+    );
+
+    // Credentials are not part of the bundle, so enabled integrations need a new login
+    let relogin: Vec<&String> = bundle
+        .integrations
+        .iter()
+        .filter(|(service, enabled)| **enabled && !is_authenticated(service))
+        .map(|(service, _)| service)
+        .collect();
+    if !relogin.is_empty() {
+        println!("Sign in again to re-enable integrations:");
+        for service in relogin {
+            println!("  pomodoroom-cli auth login {service}");
+        }
     }
     Ok(())
 }
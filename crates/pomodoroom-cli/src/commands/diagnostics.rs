@@ -1,10 +1,12 @@
 //! Diagnostics export command for bug reports.
 
 use clap::Subcommand;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 
-use pomodoroom_core::{Database, DiagnosticsGenerator, SchedulingEvent};
-use pomodoroom_core::storage::data_dir;
+use pomodoroom_core::integrations::keyring_store;
+use pomodoroom_core::storage::migrations::{current_version, CURRENT_SCHEMA_VERSION};
+use pomodoroom_core::storage::{data_dir, ConnectionOptions};
+use pomodoroom_core::{Config, ConfigError, Database, DiagnosticsGenerator, SchedulingEvent};
 
 #[derive(Subcommand)]
 pub enum DiagnosticsAction {
@@ -19,12 +21,163 @@ pub enum DiagnosticsAction {
     },
     /// Show bundle hash only
     Hash,
+    /// Run health checks and suggest fixes for failures
+    Doctor,
 }
 
 pub fn run(action: DiagnosticsAction) -> Result<(), Box<dyn std::error::Error>> {
     match action {
         DiagnosticsAction::Export { output, full } => export_diagnostics(output, full),
         DiagnosticsAction::Hash => show_hash(),
+        DiagnosticsAction::Doctor => doctor(),
+    }
+}
+
+/// Outcome of one `diagnostics doctor` check.
+struct CheckResult {
+    name: &'static str,
+    passed: bool,
+    detail: String,
+    /// What the user can do about a failure
+    hint: Option<String>,
+}
+
+impl CheckResult {
+    fn pass(name: &'static str, detail: impl Into<String>) -> Self {
+        Self {
+            name,
+            passed: true,
+            detail: detail.into(),
+            hint: None,
+        }
+    }
+
+    fn fail(name: &'static str, detail: impl Into<String>, hint: impl Into<String>) -> Self {
+        Self {
+            name,
+            passed: false,
+            detail: detail.into(),
+            hint: Some(hint.into()),
+        }
+    }
+}
+
+fn doctor() -> Result<(), Box<dyn std::error::Error>> {
+    let checks = match data_dir() {
+        Ok(dir) => vec![
+            check_data_dir(&dir),
+            check_database(&dir.join("pomodoroom.db")),
+            check_config(&dir.join("config.toml")),
+            check_keyring(),
+        ],
+        Err(e) => vec![
+            CheckResult::fail(
+                "data_dir",
+                e.to_string(),
+                "Make sure your home directory exists and ~/.config is writable",
+            ),
+            check_keyring(),
+        ],
+    };
+
+    for check in &checks {
+        let status = if check.passed { "PASS" } else { "FAIL" };
+        println!("[{status}] {}: {}", check.name, check.detail);
+        if let Some(hint) = &check.hint {
+            println!("       hint: {hint}");
+        }
+    }
+
+    let failed = checks.iter().filter(|check| !check.passed).count();
+    if failed > 0 {
+        return Err(format!("{failed} of {} checks failed", checks.len()).into());
+    }
+    println!("All {} checks passed", checks.len());
+    Ok(())
+}
+
+/// Check that files can be created in the data directory.
+fn check_data_dir(dir: &Path) -> CheckResult {
+    let probe = dir.join(".doctor-probe");
+    match std::fs::write(&probe, b"ok").and_then(|()| std::fs::remove_file(&probe)) {
+        Ok(()) => CheckResult::pass("data_dir", format!("{} is writable", dir.display())),
+        Err(e) => CheckResult::fail(
+            "data_dir",
+            format!("cannot write to {}: {e}", dir.display()),
+            "Check the directory's owner and permissions, and free disk space",
+        ),
+    }
+}
+
+/// Check that the database opens and its schema matches this build.
+///
+/// The database is opened without migrating so an outdated schema is
+/// reported instead of silently upgraded.
+fn check_database(path: &Path) -> CheckResult {
+    if !path.exists() {
+        return CheckResult::pass("database", "not created yet; it is created on first use");
+    }
+    let version = ConnectionOptions::default()
+        .open(path)
+        .and_then(|conn| current_version(&conn));
+    match version {
+        Ok(version) if version == CURRENT_SCHEMA_VERSION => {
+            CheckResult::pass("database", format!("schema version {version}"))
+        }
+        Ok(version) if version < CURRENT_SCHEMA_VERSION => CheckResult::fail(
+            "database",
+            format!("schema version {version}, expected {CURRENT_SCHEMA_VERSION}"),
+            "Run `pomodoroom-cli task list` to apply pending migrations",
+        ),
+        Ok(version) => CheckResult::fail(
+            "database",
+            format!("schema version {version} is newer than this build ({CURRENT_SCHEMA_VERSION})"),
+            "Upgrade pomodoroom-cli to match the desktop app",
+        ),
+        Err(e) => CheckResult::fail(
+            "database",
+            format!("cannot open {}: {e}", path.display()),
+            "Close other Pomodoroom instances; if the file is corrupt, restore it from a backup",
+        ),
+    }
+}
+
+/// Check that the config file loads, sealed fields included.
+fn check_config(path: &Path) -> CheckResult {
+    if !path.exists() {
+        return CheckResult::pass("config", "not created yet; defaults are used");
+    }
+    match Config::load_from(path) {
+        Ok(_) => CheckResult::pass("config", format!("{} loads", path.display())),
+        Err(e) => {
+            let hint = match e.downcast_ref::<ConfigError>() {
+                Some(ConfigError::KeyUnavailable(_) | ConfigError::DecryptFailed { .. }) => {
+                    "Unlock your keychain, or the key for encrypted fields may be lost"
+                }
+                _ => "Fix the reported key, or move the file away to start from defaults",
+            };
+            let detail = match e.downcast_ref::<toml::de::Error>() {
+                Some(e) => e.message().to_string(),
+                None => e.to_string(),
+            };
+            CheckResult::fail(
+                "config",
+                format!("invalid {}: {detail}", path.display()),
+                hint,
+            )
+        }
+    }
+}
+
+/// Check that the OS keyring answers a lookup.
+fn check_keyring() -> CheckResult {
+    match keyring_store::get("doctor_probe") {
+        Ok(_) => CheckResult::pass("keyring", "accessible"),
+        Err(e) => CheckResult::fail(
+            "keyring",
+            e.to_string(),
+            "Unlock your keychain or start a Secret Service provider (e.g. gnome-keyring)",
+        ),
     }
 }
 
@@ -46,7 +199,7 @@ fn export_diagnostics(
     let events = get_recent_events(&db, if full { 1000 } else { 100 })?;
 
     // Generate bundle
-    let generator = DiagnosticsGenerator::new();
+    let generator = DiagnosticsGenerator::new().with_schema_from(Some(db.conn()));
     let app_version = env!("CARGO_PKG_VERSION");
     let bundle = generator.generate(sessions, config_value, events, app_version);
 
@@ -70,6 +223,11 @@ fn export_diagnostics(
     println!("Bundle hash: {}", bundle.hash);
     println!("Total sessions: {}", bundle.timeline.total_sessions);
     println!("Redacted fields: {}", bundle.config.redacted_fields.len());
+    let stale = if bundle.versions.is_schema_stale() { " [stale]" } else { "" };
+    println!(
+        "Schema version: {} (latest {}){}",
+        bundle.versions.schema_version, bundle.versions.latest_schema_version, stale
+    );
 
     Ok(())
 }
@@ -84,7 +242,7 @@ fn show_hash() -> Result<(), Box<dyn std::error::Error>> {
 
     let events = get_recent_events(&db, 100)?;
 
-    let generator = DiagnosticsGenerator::new();
+    let generator = DiagnosticsGenerator::new().with_schema_from(Some(db.conn()));
     let bundle = generator.generate(sessions, config_value, events, env!("CARGO_PKG_VERSION"));
 
     println!("{}", bundle.hash);
@@ -96,3 +254,51 @@ fn get_recent_events(_db: &Database, _limit: usize) -> Result<Vec<SchedulingEven
     // For now, return empty vector
     Ok(Vec::new())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pomodoroom_core::storage::migrations::migrate_to;
+    use pomodoroom_core::storage::ScheduleDb;
+
+    fn temp_dir() -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("pomodoroom-doctor-{}", uuid::Uuid::new_v4()));
+        std::fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    #[test]
+    fn database_check_reports_schema_version() {
+        let dir = temp_dir();
+
+        let current = dir.join("current.db");
+        ScheduleDb::open_with_options(&current, &ConnectionOptions::default()).unwrap();
+        assert!(check_database(&current).passed);
+
+        // Roll the same database back to an older schema
+        migrate_to(&ConnectionOptions::default().open(&current).unwrap(), 3).unwrap();
+        let check = check_database(&current);
+        assert!(!check.passed);
+        assert!(check.detail.contains("schema version 3"));
+        assert!(check.hint.is_some());
+
+        assert!(check_database(&dir.join("missing.db")).passed);
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn config_check_rejects_unparseable_file() {
+        let dir = temp_dir();
+        let path = dir.join("config.toml");
+        assert!(check_config(&path).passed);
+
+        std::fs::write(&path, toml::to_string(&Config::default()).unwrap()).unwrap();
+        assert!(check_config(&path).passed);
+
+        std::fs::write(&path, "[schedule]\nfocus_duration = \"long\"\n").unwrap();
+        let check = check_config(&path);
+        assert!(!check.passed);
+        assert!(check.detail.contains("invalid"));
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+}
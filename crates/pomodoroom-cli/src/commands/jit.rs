@@ -1,10 +1,15 @@
 //! JIT (Just-In-Time) task engine command.
 
 use clap::Subcommand;
-use chrono::Utc;
+use chrono::{DateTime, Utc};
+use std::collections::HashMap;
 
-use pomodoroom_core::{JitContext, JitEngine};
+use pomodoroom_core::{EnergyCurve, JitContext, JitEngine};
 use pomodoroom_core::storage::schedule_db::ScheduleDb;
+use pomodoroom_core::storage::{data_dir, Database};
+
+/// KV key for recently dismissed suggestions.
+const DISMISSALS_KEY: &str = "jit_dismissals";
 
 #[derive(Subcommand)]
 pub enum JitAction {
@@ -40,6 +45,14 @@ pub enum JitAction {
         /// Number of completed sessions today
         #[arg(long)]
         completed: Option<u32>,
+        /// Interruptions since the last break
+        #[arg(long)]
+        interruptions: Option<u32>,
+    },
+    /// Dismiss a suggested task so it is held back for a while
+    Dismiss {
+        /// Task ID to dismiss
+        task_id: String,
     },
 }
 
@@ -51,13 +64,48 @@ pub fn run(action: JitAction) -> Result<(), Box<dyn std::error::Error>> {
         JitAction::SuggestBreak { energy, completed } => {
             suggest_break(energy, completed);
         }
-        JitAction::ShouldBreak { energy, time_since_break, completed } => {
-            check_should_break(energy, time_since_break, completed);
+        JitAction::ShouldBreak { energy, time_since_break, completed, interruptions } => {
+            check_should_break(energy, time_since_break, completed, interruptions);
+        }
+        JitAction::Dismiss { task_id } => {
+            dismiss_task(&task_id)?;
         }
     }
     Ok(())
 }
 
+fn load_dismissals(db: &Database) -> HashMap<String, DateTime<Utc>> {
+    db.kv_get(DISMISSALS_KEY)
+        .ok()
+        .flatten()
+        .and_then(|json| serde_json::from_str(&json).ok())
+        .unwrap_or_default()
+}
+
+fn dismiss_task(task_id: &str) -> Result<(), Box<dyn std::error::Error>> {
+    let db = Database::open()?;
+    let engine = JitEngine::new();
+    let mut context = build_context(None, None, None);
+    context.recent_dismissals = load_dismissals(&db);
+    engine.expire_dismissals(&mut context);
+    context.dismiss(task_id);
+
+    let json = serde_json::to_string(&context.recent_dismissals)?;
+    db.kv_set(DISMISSALS_KEY, &json)?;
+    println!(
+        "Dismissed {} for {} minutes.",
+        task_id, engine.dismissal_cooldown_min
+    );
+    Ok(())
+}
+
+/// Energy curve saved by `energy update`, if any.
+fn load_energy_curve() -> Option<EnergyCurve> {
+    let path = data_dir().ok()?.join("energy_curve.json");
+    let json = std::fs::read_to_string(path).ok()?;
+    serde_json::from_str(&json).ok()
+}
+
 fn build_context(
     energy: Option<u8>,
     time_since_break: Option<u64>,
@@ -69,6 +117,8 @@ fn build_context(
         current_task: None,
         completed_sessions: completed.unwrap_or(0),
         now: Utc::now(),
+        recent_dismissals: HashMap::new(),
+        interruptions_since_break: 0,
     }
 }
 
@@ -93,8 +143,12 @@ fn suggest_tasks(
         }
     };
 
-    let context = build_context(energy, time_since_break, completed);
-    let engine = JitEngine::new();
+    let mut context = build_context(energy, time_since_break, completed);
+    if let Ok(session_db) = Database::open() {
+        context.recent_dismissals = load_dismissals(&session_db);
+    }
+    let mut engine = JitEngine::new();
+    engine.energy_curve = load_energy_curve();
     let suggestions = engine.suggest_next_tasks(&context, &tasks);
 
     if suggestions.is_empty() {
@@ -120,7 +174,12 @@ fn suggest_tasks(
 
     // Show context summary
     println!("Context:");
-    println!("  Energy: {}/100", context.energy);
+    let effective = engine.effective_energy(&context);
+    if effective == context.energy {
+        println!("  Energy: {}/100", context.energy);
+    } else {
+        println!("  Energy: {}/100 ({}/100 with energy curve)", context.energy, effective);
+    }
     println!("  Time since break: {} min", context.time_since_last_break_min);
     println!("  Completed sessions: {}", context.completed_sessions);
 }
@@ -144,13 +203,17 @@ fn check_should_break(
     energy: Option<u8>,
     time_since_break: Option<u64>,
     completed: Option<u32>,
+    interruptions: Option<u32>,
 ) {
-    let context = build_context(energy, time_since_break, completed);
+    let context = JitContext {
+        interruptions_since_break: interruptions.unwrap_or(0),
+        ..build_context(energy, time_since_break, completed)
+    };
     let engine = JitEngine::new();
-    let should_break = engine.should_take_break(&context);
+    let decision = engine.break_decision(&context);
 
     println!("=== Break Check ===");
-    if should_break {
+    if decision.take_break() {
         println!("  ⚠️  Yes, you should take a break!");
         println!("  Current energy: {}/100", context.energy);
         println!("  Time since break: {} min", context.time_since_last_break_min);
@@ -161,4 +224,9 @@ fn check_should_break(
         println!("  ✓ No, keep going!");
         println!("  Your energy is still good ({}/100)", context.energy);
     }
+    println!(
+        "  {} [confidence {:.0}%]",
+        decision.rationale,
+        decision.confidence * 100.0
+    );
 }
@@ -8,6 +8,7 @@ pub mod profile;
 pub mod project;
 pub mod recipe;
 pub mod schedule;
+pub mod score;
 pub mod stats;
 pub mod sync;
 pub mod task;
@@ -4,7 +4,11 @@
 //! (schedules, break settings, etc.) with semantic versioning compatibility checks.
 
 use clap::Subcommand;
-use pomodoroom_core::policy::{check_compatibility, Compatibility, PolicyBundle, PolicyMetadata, POLICY_VERSION};
+use pomodoroom_core::calendar::generate_signing_key;
+use pomodoroom_core::policy::{
+    check_compatibility, check_raw_signature, migrate_bundle, Compatibility, PolicyBundle,
+    PolicyMetadata, SignatureMode, SignatureStatus, POLICY_VERSION,
+};
 use pomodoroom_core::Config;
 use std::fs;
 use std::path::PathBuf;
@@ -28,6 +32,12 @@ pub enum PolicyAction {
         /// Additional notes
         #[arg(long)]
         notes: Option<String>,
+        /// Sign the bundle with a key derived from this shared secret
+        #[arg(long)]
+        sign_key: Option<String>,
+        /// Signer identifier (defaults to the author)
+        #[arg(long, requires = "sign_key")]
+        signer: Option<String>,
     },
     /// Import policy from a JSON file
     Import {
@@ -39,6 +49,12 @@ pub enum PolicyAction {
         /// Skip compatibility checks
         #[arg(long)]
         force: bool,
+        /// Shared secret used to verify a signed bundle
+        #[arg(long)]
+        verify_key: Option<String>,
+        /// Reject signed bundles that cannot be verified instead of warning
+        #[arg(long)]
+        require_signature: bool,
     },
     /// Show current policy schema version
     Version,
@@ -52,12 +68,30 @@ pub fn run(action: PolicyAction) -> Result<(), Box<dyn std::error::Error>> {
             author,
             intent,
             notes,
-        } => export_policy(output, name, author, intent, notes),
+            sign_key,
+            signer,
+        } => export_policy(
+            output,
+            name,
+            author,
+            intent,
+            notes,
+            sign_key.map(|key| (key, signer)),
+        ),
         PolicyAction::Import {
             file,
             dry_run,
             force,
-        } => import_policy(file, dry_run, force),
+            verify_key,
+            require_signature,
+        } => {
+            let mode = if require_signature {
+                SignatureMode::Reject
+            } else {
+                SignatureMode::Warn
+            };
+            import_policy(file, dry_run, force, verify_key, mode)
+        }
         PolicyAction::Version => {
             println!("Policy schema version: {}", POLICY_VERSION);
             Ok(())
@@ -71,6 +105,7 @@ fn export_policy(
     author: Option<String>,
     intent: Option<String>,
     notes: Option<String>,
+    signing: Option<(String, Option<String>)>,
 ) -> Result<(), Box<dyn std::error::Error>> {
     // Load current config
     let config = Config::load_or_default();
@@ -82,10 +117,11 @@ fn export_policy(
         intent: intent.unwrap_or_default(),
         notes: notes.unwrap_or_default(),
         created_at: chrono::Utc::now(),
+        ..Default::default()
     };
 
     // Create bundle from current config
-    let bundle = PolicyBundle::with_metadata(
+    let mut bundle = PolicyBundle::with_metadata(
         metadata,
         config.schedule.focus_duration,
         config.schedule.short_break,
@@ -94,6 +130,13 @@ fn export_policy(
         config.custom_schedule.clone(),
     );
 
+    if let Some((secret, signer)) = signing {
+        let signer = signer
+            .or_else(|| Some(bundle.metadata.author.clone()).filter(|a| !a.is_empty()))
+            .ok_or("Signing requires --signer or --author to identify the signer.")?;
+        bundle.sign(&signer, &generate_signing_key(&secret));
+    }
+
     let json = bundle.to_json()?;
 
     match output {
@@ -113,12 +156,31 @@ fn import_policy(
     file: PathBuf,
     dry_run: bool,
     force: bool,
+    verify_key: Option<String>,
+    signature_mode: SignatureMode,
 ) -> Result<(), Box<dyn std::error::Error>> {
     // Read the policy file
     let json = fs::read_to_string(&file)?;
 
-    // Parse the bundle
-    let bundle = PolicyBundle::from_json(&json)?;
+    // Check the signature on the bundle as written; migration rewrites signed fields
+    let raw: serde_json::Value = serde_json::from_str(&json)?;
+    let key = verify_key.as_deref().map(generate_signing_key);
+    let signature = check_raw_signature(&raw, key.as_deref(), signature_mode)
+        .map_err(|e| format!("Signature check failed: {}", e))?;
+
+    // Upgrade older versions to the current schema
+    let original_version = raw
+        .get("version")
+        .and_then(|v| v.as_str())
+        .unwrap_or_default()
+        .to_string();
+    let bundle = migrate_bundle(raw)?;
+    if bundle.version != original_version {
+        println!(
+            "Migrated policy from version {} to {}",
+            original_version, bundle.version
+        );
+    }
 
     println!("Policy: {}", bundle.metadata.name);
     println!("Version: {}", bundle.version);
@@ -164,6 +226,21 @@ fn import_policy(
         }
     }
 
+    match signature {
+        SignatureStatus::Unsigned => println!("Signature: none (unsigned bundle)"),
+        SignatureStatus::Verified { signer } => {
+            println!("Signature: verified (signed by {})", signer)
+        }
+        SignatureStatus::Unverified { signer } => println!(
+            "Warning: bundle is signed by {} but no --verify-key was given; signature not checked.",
+            signer
+        ),
+        SignatureStatus::Invalid { signer } => println!(
+            "Warning: signature by {} is INVALID; the bundle may have been tampered with.",
+            signer
+        ),
+    }
+
     // Print policy details
     println!("\nPolicy Settings:");
     println!("  Focus duration: {} min", bundle.policy.focus_duration);
@@ -183,7 +260,7 @@ fn import_policy(
     }
 
     // Apply the policy
-    let mut config = Config::load_or_default();
+    let mut config = Config::load()?;
     bundle.apply_to_config(&mut config);
     config.save()?;
 
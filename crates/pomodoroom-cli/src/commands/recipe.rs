@@ -3,7 +3,7 @@
 use clap::Subcommand;
 use std::io::Read;
 use pomodoroom_core::{Event, Recipe};
-use pomodoroom_core::recipes::{RecipeStore, ActionExecutor};
+use pomodoroom_core::recipes::{RecipeStore, ActionExecutor, RecipeEngine};
 
 #[derive(Subcommand)]
 pub enum RecipeAction {
@@ -50,6 +50,16 @@ pub enum RecipeAction {
         #[arg(long)]
         event: String,
     },
+
+    /// Explain how every recipe (enabled or not) evaluates against an event
+    Explain {
+        /// Event type to simulate
+        #[arg(long)]
+        event: String,
+        /// Print the report as JSON
+        #[arg(long)]
+        json: bool,
+    },
 }
 
 pub fn run(action: RecipeAction) -> Result<(), Box<dyn std::error::Error>> {
@@ -58,6 +68,7 @@ pub fn run(action: RecipeAction) -> Result<(), Box<dyn std::error::Error>> {
         RecipeAction::Add => add_recipe(),
         RecipeAction::Remove { name } => remove_recipe(name),
         RecipeAction::Test { name, event } => test_recipe(name, event),
+        RecipeAction::Explain { event, json } => explain_recipes(event, json),
     }
 }
 
@@ -164,6 +175,43 @@ fn test_recipe(name: String, event_type: String) -> Result<(), Box<dyn std::erro
     Ok(())
 }
 
+fn explain_recipes(event_type: String, json: bool) -> Result<(), Box<dyn std::error::Error>> {
+    let event = create_mock_event(&event_type)?;
+    let report = RecipeEngine::new()?.explain(&event)?;
+
+    if json {
+        println!("{}", serde_json::to_string_pretty(&report)?);
+        return Ok(());
+    }
+
+    if report.is_empty() {
+        println!("No recipes found.");
+        return Ok(());
+    }
+
+    println!("Explaining {} recipe(s) against event '{}':", report.len(), event_type);
+    for explanation in &report {
+        let outcome = match (explanation.matched, explanation.enabled) {
+            (true, true) => "would fire",
+            (true, false) => "matches but disabled",
+            (false, _) => "no match",
+        };
+        println!();
+        println!("{} - {}", explanation.recipe_name, outcome);
+        for evaluation in &explanation.triggers {
+            let mark = if evaluation.matched { "x" } else { " " };
+            println!("  [{}] {}: {}", mark, evaluation.trigger.type_name(), evaluation.detail);
+        }
+        if explanation.matched {
+            for action in &explanation.actions {
+                println!("  -> {}", action.description());
+            }
+        }
+    }
+
+    Ok(())
+}
+
 fn create_mock_event(event_type: &str) -> Result<Event, Box<dyn std::error::Error>> {
     use chrono::Utc;
 
@@ -171,6 +219,8 @@ fn create_mock_event(event_type: &str) -> Result<Event, Box<dyn std::error::Erro
         "TimerCompleted" => Event::TimerCompleted {
             step_index: 0,
             step_type: pomodoroom_core::timer::StepType::Focus,
+            planned_ms: 0,
+            actual_ms: 0,
             at: Utc::now(),
         },
         "TimerSkipped" => Event::TimerSkipped {
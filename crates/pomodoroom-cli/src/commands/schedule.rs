@@ -6,9 +6,14 @@
 
 use chrono::{DateTime, Utc};
 use clap::Subcommand;
-use pomodoroom_core::schedule::{BlockType, DailyTemplate, FixedEvent, ScheduleBlock};
-use pomodoroom_core::scheduler::{AutoScheduler, CalendarEvent, ScheduledBlock};
+use pomodoroom_core::schedule::{
+    feasibility_check, to_ics, BlockType, DailyTemplate, FixedEvent, FixedEventKind, ScheduleBlock,
+};
+use pomodoroom_core::scheduler::{
+    AutoScheduler, CalendarEvent, ScheduledBlock, ScheduledBlockType,
+};
 use pomodoroom_core::storage::ScheduleDb;
+use pomodoroom_core::task::TaskState;
 use uuid::Uuid;
 
 #[derive(Subcommand)]
@@ -31,6 +36,18 @@ pub enum ScheduleAction {
         #[arg(long)]
         json: bool,
     },
+    /// Print today's generated plan without saving it
+    Today {
+        /// Target date in ISO format (YYYY-MM-DD), defaults to today
+        #[arg(short, long)]
+        date: Option<String>,
+        /// Path to JSON file containing calendar events
+        #[arg(long)]
+        calendar_events: Option<String>,
+        /// Output format: table or json
+        #[arg(long, default_value = "table")]
+        format: String,
+    },
     /// Auto-fill available time slots with top priority tasks
     AutoFill {
         /// Target date in ISO format (YYYY-MM-DD), defaults to today
@@ -55,6 +72,21 @@ pub enum ScheduleAction {
         #[arg(long, default_value = "table")]
         format: String,
     },
+    /// Export the generated daily schedule
+    Export {
+        /// Target date in ISO format (YYYY-MM-DD), defaults to today
+        #[arg(short, long)]
+        date: Option<String>,
+        /// Export as iCalendar (.ics)
+        #[arg(long)]
+        ics: bool,
+        /// Write to this file instead of stdout
+        #[arg(short, long)]
+        output: Option<String>,
+        /// Path to JSON file containing calendar events
+        #[arg(long)]
+        calendar_events: Option<String>,
+    },
     /// Block management subcommands
     Block {
         #[command(subcommand)]
@@ -150,6 +182,9 @@ pub enum EventAction {
         /// Comma-separated days (1-7, where 1=Monday)
         #[arg(long)]
         days: String,
+        /// Event kind: meal, meeting or other (meals get a buffer)
+        #[arg(long, default_value = "other")]
+        kind: String,
     },
     /// Remove a fixed event
     Remove {
@@ -167,6 +202,11 @@ pub fn run(action: ScheduleAction) -> Result<(), Box<dyn std::error::Error>> {
             calendar_events,
             json,
         } => run_generate(date, progressive, lanes, calendar_events, json)?,
+        ScheduleAction::Today {
+            date,
+            calendar_events,
+            format,
+        } => run_today(date, calendar_events, format)?,
         ScheduleAction::AutoFill {
             date,
             dry_run,
@@ -174,6 +214,12 @@ pub fn run(action: ScheduleAction) -> Result<(), Box<dyn std::error::Error>> {
             json,
         } => run_auto_fill(date, dry_run, calendar_events, json)?,
         ScheduleAction::Show { date, format } => run_show(date, format)?,
+        ScheduleAction::Export {
+            date,
+            ics,
+            output,
+            calendar_events,
+        } => run_export(date, ics, output, calendar_events)?,
         ScheduleAction::Block { action } => run_block(action)?,
         ScheduleAction::Template { action } => run_template(action)?,
     }
@@ -279,6 +325,16 @@ fn run_generate(
         Utc::now()
     };
 
+    let feasibility = feasibility_check(&template, &tasks, &calendar_events, date);
+    if feasibility.over_committed {
+        eprintln!(
+            "Warning: over-committed by {} min ({} min of work, {} min available)",
+            feasibility.overflow_minutes,
+            feasibility.required_minutes,
+            feasibility.available_minutes
+        );
+    }
+
     let scheduler = AutoScheduler::new();
     let scheduled_blocks = if progressive {
         // Progressive mode: generate using focus schedule pattern
@@ -296,6 +352,92 @@ fn run_generate(
     Ok(())
 }
 
+fn run_today(
+    date_str: Option<String>,
+    calendar_events_path: Option<String>,
+    format: String,
+) -> Result<(), Box<dyn std::error::Error>> {
+    if !matches!(format.as_str(), "table" | "json") {
+        return Err(format!("Invalid format: {format}. Use table or json").into());
+    }
+
+    let db = ScheduleDb::open()?;
+    let template = db.get_daily_template()?.ok_or(
+        "No daily template found. Run onboarding in the app, or set one with \
+         `pomodoroom-cli schedule template set --wake-up 07:00 --sleep 23:00`",
+    )?;
+    let tasks: Vec<_> = db
+        .list_tasks()?
+        .into_iter()
+        .filter(|task| task.state == TaskState::Ready)
+        .collect();
+    let calendar_events = load_calendar_events(calendar_events_path)?;
+
+    let date = if let Some(d) = date_str {
+        parse_date_iso(&d)?
+    } else {
+        Utc::now()
+    };
+
+    // Read-only: blocks are printed, never persisted
+    let mut blocks =
+        AutoScheduler::new().generate_schedule(&template, &tasks, &calendar_events, date);
+    blocks.sort_by(|a, b| {
+        a.start_time
+            .cmp(&b.start_time)
+            .then_with(|| a.lane.cmp(&b.lane))
+    });
+
+    if format == "json" {
+        println!("{}", serde_json::to_string_pretty(&blocks)?);
+        return Ok(());
+    }
+
+    println!(
+        "Plan for {} ({} - {})",
+        date.format("%Y-%m-%d"),
+        template.wake_up,
+        template.sleep
+    );
+    if blocks.is_empty() {
+        println!("  Nothing scheduled. Add READY tasks with `pomodoroom-cli task add`.");
+        return Ok(());
+    }
+    for block in &blocks {
+        let (kind, pomodoros) = match block.block_type {
+            ScheduledBlockType::Focus => ("focus", format!("{} pomodoros", block.pomodoro_count)),
+            ScheduledBlockType::Break => ("break", String::new()),
+        };
+        let lane = block
+            .lane
+            .map_or(String::new(), |lane| format!("  [lane {lane}]"));
+        println!(
+            "  {}-{}  {:<5}  {:<30}  {:<12}{}",
+            block.start_time.format("%H:%M"),
+            block.end_time.format("%H:%M"),
+            kind,
+            block.task_title,
+            pomodoros,
+            lane
+        );
+    }
+    let focus: Vec<_> = blocks
+        .iter()
+        .filter(|block| block.block_type == ScheduledBlockType::Focus)
+        .collect();
+    println!(
+        "Focus: {} blocks, {} pomodoros, {} min",
+        focus.len(),
+        focus.iter().map(|block| block.pomodoro_count).sum::<i32>(),
+        focus
+            .iter()
+            .map(|block| block.duration_minutes())
+            .sum::<i64>()
+    );
+
+    Ok(())
+}
+
 fn run_auto_fill(
     date_str: Option<String>,
     dry_run: bool,
@@ -365,6 +507,43 @@ fn run_show(date_str: Option<String>, format: String) -> Result<(), Box<dyn std:
     Ok(())
 }
 
+fn run_export(
+    date_str: Option<String>,
+    ics: bool,
+    output: Option<String>,
+    calendar_events_path: Option<String>,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let db = ScheduleDb::open()?;
+    let template = load_daily_template(&db)?;
+    let tasks = db.list_tasks()?;
+    let calendar_events = load_calendar_events(calendar_events_path)?;
+
+    let date = if let Some(d) = date_str {
+        parse_date_iso(&d)?
+    } else {
+        Utc::now()
+    };
+
+    let scheduler = AutoScheduler::new();
+    let scheduled_blocks = scheduler.generate_schedule(&template, &tasks, &calendar_events, date);
+
+    let content = if ics {
+        to_ics(&scheduled_blocks)
+    } else {
+        serde_json::to_string_pretty(&scheduled_blocks)?
+    };
+
+    match output {
+        Some(path) => {
+            std::fs::write(&path, content)?;
+            println!("Exported {} blocks to {}", scheduled_blocks.len(), path);
+        }
+        None => print!("{content}"),
+    }
+
+    Ok(())
+}
+
 fn run_block(action: BlockAction) -> Result<(), Box<dyn std::error::Error>> {
     match action {
         BlockAction::List { start, end, json } => {
@@ -545,8 +724,9 @@ fn run_template_event(action: EventAction) -> Result<(), Box<dyn std::error::Err
             start,
             duration,
             days,
+            kind,
         } => {
-            run_template_event_add(name, start, duration, days)?;
+            run_template_event_add(name, start, duration, days, kind)?;
         }
         EventAction::Remove { id } => {
             run_template_event_remove(id)?;
@@ -590,7 +770,10 @@ fn run_template_event_add(
     start: String,
     duration: u32,
     days_str: String,
+    kind: String,
 ) -> Result<(), Box<dyn std::error::Error>> {
+    let kind = FixedEventKind::parse(&kind)
+        .ok_or_else(|| format!("Invalid kind '{kind}' (expected meal, meeting or other)"))?;
     let db = ScheduleDb::open()?;
 
     let mut template = match db.get_daily_template()? {
@@ -627,6 +810,7 @@ fn run_template_event_add(
         duration_minutes: duration as i32,
         days,
         enabled: true,
+        kind,
     };
 
     template.fixed_events.push(event.clone());
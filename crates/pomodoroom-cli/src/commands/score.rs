@@ -0,0 +1,154 @@
+//! Scoring commands for CLI.
+//!
+//! Benchmarks the built-in objective weight presets against the current task
+//! snapshot so a preset can be picked empirically.
+
+use chrono::{DateTime, Utc};
+use clap::Subcommand;
+use pomodoroom_core::storage::ScheduleDb;
+use pomodoroom_core::{benchmark_presets, ObjectiveWeights, PresetBenchmark};
+
+#[derive(Subcommand)]
+pub enum ScoreAction {
+    /// Compare task orderings under each weight preset
+    Benchmark {
+        /// Number of top positions to compare
+        #[arg(long, default_value_t = 5)]
+        top: usize,
+        /// Presets to compare (first is the baseline); defaults to all
+        #[arg(long = "preset")]
+        presets: Vec<String>,
+        /// Simulated start time in RFC 3339 format, defaults to now
+        #[arg(long)]
+        at: Option<String>,
+        /// Output format: table or json
+        #[arg(long, default_value = "table")]
+        format: String,
+    },
+}
+
+pub fn run(action: ScoreAction) -> Result<(), Box<dyn std::error::Error>> {
+    match action {
+        ScoreAction::Benchmark {
+            top,
+            presets,
+            at,
+            format,
+        } => run_benchmark(top, presets, at, &format),
+    }
+}
+
+fn run_benchmark(
+    top: usize,
+    preset_names: Vec<String>,
+    at: Option<String>,
+    format: &str,
+) -> Result<(), Box<dyn std::error::Error>> {
+    if format != "table" && format != "json" {
+        return Err(format!("Unknown format '{format}' (expected table or json)").into());
+    }
+
+    let presets = if preset_names.is_empty() {
+        ObjectiveWeights::presets()
+    } else {
+        preset_names
+            .iter()
+            .map(|name| {
+                ObjectiveWeights::preset(name)
+                    .map(|weights| (name.as_str(), weights))
+                    .ok_or_else(|| format!("Unknown preset '{name}'"))
+            })
+            .collect::<Result<Vec<_>, _>>()?
+    };
+
+    let at = match at {
+        Some(value) => DateTime::parse_from_rfc3339(&value)
+            .map_err(|e| format!("Invalid --at: {e}"))?
+            .with_timezone(&Utc),
+        None => Utc::now(),
+    };
+
+    let db = ScheduleDb::open()?;
+    let tasks = db.list_tasks()?;
+    let report = benchmark_presets(&tasks, &presets, top, at);
+
+    if format == "json" {
+        println!("{}", serde_json::to_string_pretty(&report)?);
+    } else {
+        print_benchmark(&report);
+    }
+    Ok(())
+}
+
+fn print_benchmark(report: &PresetBenchmark) {
+    println!("Scoring Preset Benchmark");
+    println!("At: {} (pass --at to reproduce)", report.at.to_rfc3339());
+    println!("Ready tasks: {}  Top: {}", report.task_count, report.top_n);
+    println!();
+
+    if report.task_count == 0 {
+        println!("No READY tasks to rank.");
+        return;
+    }
+
+    const COLUMN: usize = 24;
+    print!("{:<4}", "#");
+    for ranking in &report.rankings {
+        print!("{:<COLUMN$}", ranking.preset);
+    }
+    println!();
+
+    let rows = report
+        .rankings
+        .iter()
+        .map(|r| r.top.len())
+        .max()
+        .unwrap_or(0);
+    let baseline = report.rankings.first();
+    for i in 0..rows {
+        print!("{:<4}", i + 1);
+        for ranking in &report.rankings {
+            let cell = match ranking.top.get(i) {
+                Some(task) => {
+                    let same = baseline
+                        .and_then(|b| b.top.get(i))
+                        .is_some_and(|b| b.task_id == task.task_id);
+                    let marker = if same { ' ' } else { '*' };
+                    format!("{marker}{}", truncate(&task.title, COLUMN - 3))
+                }
+                None => String::new(),
+            };
+            print!("{cell:<COLUMN$}");
+        }
+        println!();
+    }
+    println!();
+    println!("* differs from baseline ({})", report.baseline);
+    println!();
+
+    println!(
+        "{:<20} {:>8} {:>8} {:>10} {:>12} {:>10}",
+        "Preset", "Changed", "Overlap", "Mean", "vs priority", "Time"
+    );
+    for ranking in &report.rankings {
+        println!(
+            "{:<20} {:>8} {:>8} {:>10.3} {:>11.1}% {:>8}us",
+            ranking.preset,
+            ranking.changed_positions,
+            ranking.overlap,
+            ranking.result.multi_objective_score,
+            ranking.result.improvement_pct,
+            ranking.elapsed_micros
+        );
+    }
+}
+
+fn truncate(value: &str, max: usize) -> String {
+    if value.chars().count() <= max {
+        value.to_string()
+    } else {
+        let mut truncated: String = value.chars().take(max.saturating_sub(1)).collect();
+        truncated.push('…');
+        truncated
+    }
+}
@@ -1,7 +1,7 @@
 use clap::Subcommand;
 use std::path::PathBuf;
 use chrono::{Duration, Utc};
-use pomodoroom_core::storage::Database;
+use pomodoroom_core::storage::{Database, WeeklyFocusTrend};
 use pomodoroom_core::{
     BreakAdherenceAnalyzer, BreakAdherenceReport, EstimateAccuracyTracker, GroupBy, AccuracySessionData,
     InterruptionHeatmapAnalyzer, InterruptionSourceType,
@@ -73,6 +73,15 @@ pub enum StatsAction {
         #[arg(long)]
         hotspots: bool,
     },
+    /// Weekly focus-time trend with moving average
+    Trend {
+        /// Number of weeks to show, including the current one
+        #[arg(long, default_value_t = 12)]
+        weeks: usize,
+        /// Output as JSON
+        #[arg(long)]
+        json: bool,
+    },
 }
 
 pub fn run(action: StatsAction) -> Result<(), Box<dyn std::error::Error>> {
@@ -96,6 +105,14 @@ pub fn run(action: StatsAction) -> Result<(), Box<dyn std::error::Error>> {
         StatsAction::Interruptions { start, end, source, external, internal, hotspots } => {
             show_interruption_heatmap(&db, start, end, source, external, internal, hotspots)?;
         }
+        StatsAction::Trend { weeks, json } => {
+            let trend = db.weekly_focus_trend(weeks)?;
+            if json {
+                println!("{}", serde_json::to_string_pretty(&trend)?);
+            } else {
+                print_focus_trend(&trend);
+            }
+        }
     }
     Ok(())
 }
@@ -172,6 +189,12 @@ fn print_summary(report: &BreakAdherenceReport) {
     if stats.breaks_deferred > 0 {
         println!("Average delay: {:.1} min", stats.avg_delay_min);
     }
+    if let Some(ref streak) = report.longest_grind_streak {
+        println!(
+            "Longest grind streak: {} breaks skipped in a row",
+            streak.skipped_breaks
+        );
+    }
     println!();
 }
 
@@ -243,11 +266,21 @@ fn print_high_risk_windows(report: &BreakAdherenceReport) {
     println!();
 
     for window in &report.high_risk_windows {
+        if let Some(ref streak) = window.grind_streak {
+            println!(
+                "  {} - Grind streak: {} breaks skipped ({} to {})",
+                format_hour(window.hour),
+                streak.skipped_breaks,
+                streak.start.format("%Y-%m-%d %H:%M"),
+                streak.end.format("%H:%M")
+            );
+            continue;
+        }
         println!(
             "  {} - Skip: {:.1}%, Defer: {:.1}%",
             format_hour(window.hour),
-            window.skip_rate * 100.0,
-            window.defer_rate * 100.0
+            window.skip_rate.unwrap_or_default() * 100.0,
+            window.defer_rate.unwrap_or_default() * 100.0
         );
     }
     println!();
@@ -336,10 +369,13 @@ fn export_break_report_csv(report: &BreakAdherenceReport, path: &PathBuf) -> Res
         writeln!(file, "Hour,Skip Rate,Defer Rate")?;
 
         for window in &report.high_risk_windows {
+            let rate = |rate: Option<f64>| rate.map(|r| format!("{:.4}", r)).unwrap_or_default();
             writeln!(
                 file,
-                "{},{:.4},{:.4}",
-                window.hour, window.skip_rate, window.defer_rate
+                "{},{},{}",
+                window.hour,
+                rate(window.skip_rate),
+                rate(window.defer_rate)
             )?;
         }
     }
@@ -508,5 +544,54 @@ fn show_interruption_heatmap(
         println!("{}", analyzer.render_ascii(&heatmap));
     }
 
+    let lost = analyzer.estimated_lost_minutes(&filtered_events);
+    println!(
+        "Estimated focus lost: ~{:.0} minutes over {} days",
+        lost.total_minutes,
+        lost.daily_totals.len()
+    );
+    for (date, minutes) in &lost.daily_totals {
+        println!("  {}  ~{:.0} min", date, minutes);
+    }
+
     Ok(())
 }
+
+/// Print weekly focus totals with the moving average and overall direction
+fn print_focus_trend(trend: &WeeklyFocusTrend) {
+    println!("Weekly Focus Trend ({}-week moving average)", trend.window);
+    println!();
+
+    if trend.raw.iter().all(|&m| m == 0) {
+        println!("No focus sessions in the selected period.");
+        return;
+    }
+
+    println!(
+        "{:<10} {:<12} {:>8} {:>8}",
+        "Week", "Starts", "Focus", "Avg"
+    );
+    println!("{}", "-".repeat(41));
+    for ((start, raw), smoothed) in trend
+        .week_starts
+        .iter()
+        .zip(&trend.raw)
+        .zip(&trend.smoothed)
+    {
+        println!(
+            "{:<10} {:<12} {:>7}m {:>7.0}m",
+            start.format("%G-W%V"),
+            start.format("%Y-%m-%d"),
+            raw,
+            smoothed
+        );
+    }
+    println!();
+
+    let direction = match trend.smoothed.as_slice() {
+        [.., prev, last] if last > prev => "up",
+        [.., prev, last] if last < prev => "down",
+        _ => "flat",
+    };
+    println!("Trend: {}", direction);
+}
@@ -4,20 +4,29 @@
 
 use std::{collections::HashMap, error::Error};
 
-use chrono::Utc;
+use chrono::{Duration, Utc};
 use clap::Subcommand;
 use pomodoroom_core::{
     integrations::{
         google::GoogleIntegration,
+        mapping::{GITHUB_SOURCE, GOOGLE_TASKS_SOURCE},
         oauth::{self, OAuthConfig},
-        Integration,
+        sync_item, ExternalItem, Integration,
     },
     storage::schedule_db::ScheduleDb,
-    task::{Task, TaskState},
+    sync::{
+        event_codec::{project_to_sync_event, task_to_sync_event, tombstone_deletion_event},
+        apply_local_changes, ChangeKind, ChangeSide, ConflictLog, ConflictRecord, SyncEngine,
+        SyncEvent, SyncEventType, SyncPlan,
+    },
+    task::Task,
 };
 use reqwest::Client;
 use serde_json::Value;
 
+/// Days a synced task tombstone is kept so other devices can pick it up.
+const TOMBSTONE_RETENTION_DAYS: i64 = 30;
+
 fn encode_component(input: &str) -> String {
     url::form_urlencoded::byte_serialize(input.as_bytes()).collect()
 }
@@ -39,6 +48,24 @@ pub enum SyncAction {
         #[arg(long)]
         dry_run: bool,
     },
+    /// Show the Google Calendar changes a sync would make, without applying them
+    Plan {
+        /// Apply the plan after showing it
+        #[arg(long)]
+        apply: bool,
+        /// Print the plan as JSON
+        #[arg(long)]
+        json: bool,
+    },
+    /// List automatically resolved conflicts, with both values
+    Conflicts {
+        /// How many days back to list
+        #[arg(long, default_value_t = 7)]
+        days: i64,
+        /// Print the entries as JSON
+        #[arg(long)]
+        json: bool,
+    },
     /// Show sync status for all services
     Status {
         /// Optional service name to check status for specific service
@@ -47,22 +74,6 @@ pub enum SyncAction {
     },
 }
 
-#[derive(Debug, Clone)]
-struct LocalTaskSnapshot {
-    title: String,
-    description: Option<String>,
-    state: TaskState,
-}
-
-#[derive(Debug, Clone)]
-struct RemoteTaskSnapshot {
-    external_id: String,
-    list_title: String,
-    title: String,
-    notes: Option<String>,
-    state: TaskState,
-}
-
 #[derive(Debug, Clone, Copy)]
 enum SyncChangeKind {
     Create,
@@ -83,6 +94,8 @@ pub fn run(action: SyncAction) -> Result<(), Box<dyn Error>> {
     match action {
         SyncAction::Service { service, dry_run } => run_service_sync(&service, dry_run)?,
         SyncAction::All { dry_run } => run_all_sync(dry_run)?,
+        SyncAction::Plan { apply, json } => run_calendar_plan(apply, json)?,
+        SyncAction::Conflicts { days, json } => show_conflicts(days, json)?,
         SyncAction::Status { service } => show_status(service)?,
     }
     Ok(())
@@ -167,6 +180,137 @@ fn run_all_sync(dry_run: bool) -> Result<(), Box<dyn Error>> {
     Ok(())
 }
 
+/// Plan (and optionally apply) a Google Calendar sync of local tasks and projects.
+fn run_calendar_plan(apply: bool, json: bool) -> Result<(), Box<dyn Error>> {
+    if !GoogleIntegration::new().is_authenticated() {
+        return Err("Google is not authenticated. Run 'pomodoroom-cli auth login google' first.".into());
+    }
+
+    let db = ScheduleDb::open()?;
+    let mut local: Vec<SyncEvent> = Vec::new();
+    for task in db.list_tasks()? {
+        local.push(task_to_sync_event(&task)?);
+    }
+    let tombstones = db.list_task_tombstones()?;
+    local.extend(tombstones.iter().map(tombstone_deletion_event));
+    for project in db.list_projects()? {
+        local.push(project_to_sync_event(&project)?);
+    }
+
+    // The calendar client drives its requests on the current runtime
+    let rt = tokio::runtime::Runtime::new()?;
+    let _guard = rt.enter();
+    let mut engine = SyncEngine::new();
+    let plan = engine.plan(&local)?;
+
+    if json {
+        println!("{}", serde_json::to_string_pretty(&plan)?);
+    } else {
+        print_sync_plan(&plan);
+    }
+
+    if apply && !plan.is_empty() {
+        let status = engine.apply(&plan)?;
+        let applied = apply_local_changes(&db, &plan)?;
+        confirm_tombstones(&db, &plan)?;
+        if !json {
+            let skipped = plan.changes_for(ChangeSide::Local).count() - applied;
+            println!();
+            println!("Applied. Unresolved conflicts: {}", status.pending_count);
+            if skipped > 0 {
+                println!("Skipped {skipped} local change(s) for types that cannot be stored locally yet.");
+            }
+        }
+    } else if !json && !plan.is_empty() {
+        println!();
+        println!("Nothing applied. Re-run with --apply to perform these changes.");
+    }
+    Ok(())
+}
+
+/// Mark task deletions as synced once the plan is applied, and purge old ones.
+///
+/// Deletions in conflict stay pending so they are offered again next time.
+fn confirm_tombstones(db: &ScheduleDb, plan: &SyncPlan) -> Result<(), Box<dyn Error>> {
+    let now = Utc::now();
+    let confirmed: Vec<String> = db
+        .list_task_tombstones()?
+        .into_iter()
+        .filter(|tombstone| {
+            !plan.conflicts.iter().any(|conflict| {
+                conflict.local.event_type == SyncEventType::Task
+                    && conflict.local.id == tombstone.id
+            })
+        })
+        .map(|tombstone| tombstone.id)
+        .collect();
+    db.mark_tombstones_synced(&confirmed, now)?;
+    db.purge_tombstones(now - Duration::days(TOMBSTONE_RETENTION_DAYS))?;
+    Ok(())
+}
+
+fn print_sync_plan(plan: &SyncPlan) {
+    println!("Google Calendar sync plan:");
+    for (label, side) in [("remote", ChangeSide::Remote), ("local", ChangeSide::Local)] {
+        println!(
+            "  {label:<9}: {} create, {} update, {} delete",
+            plan.count(side, ChangeKind::Create),
+            plan.count(side, ChangeKind::Update),
+            plan.count(side, ChangeKind::Delete),
+        );
+    }
+    println!("  conflicts: {}", plan.conflicts.len());
+    println!("  unchanged: {}", plan.unchanged);
+
+    if !plan.changes.is_empty() {
+        println!();
+        for change in &plan.changes {
+            println!(
+                "  {:?} {:?} {:?} {}",
+                change.side, change.kind, change.event.event_type, change.event.id
+            );
+        }
+    }
+    for conflict in &plan.conflicts {
+        println!(
+            "  Conflict {:?} {}",
+            conflict.local.event_type, conflict.local.id
+        );
+    }
+}
+
+/// List conflicts resolved in the last `days` days.
+fn show_conflicts(days: i64, json: bool) -> Result<(), Box<dyn Error>> {
+    let since = Utc::now() - Duration::days(days.max(0));
+    let entries = ConflictLog::new().entries(since..)?;
+    if json {
+        println!("{}", serde_json::to_string_pretty(&entries)?);
+        return Ok(());
+    }
+    if entries.is_empty() {
+        println!("No resolved conflicts in the last {days} days.");
+        return Ok(());
+    }
+    for entry in &entries {
+        print_conflict(entry);
+    }
+    Ok(())
+}
+
+fn print_conflict(entry: &ConflictRecord) {
+    println!(
+        "{} {:?} {} .{} ({:?}, kept {:?})",
+        entry.at.format("%Y-%m-%d %H:%M"),
+        entry.entity_type,
+        entry.entity_id,
+        entry.field,
+        entry.strategy,
+        entry.chosen,
+    );
+    println!("    local:  {}", entry.local_value);
+    println!("    remote: {}", entry.remote_value);
+}
+
 /// Show sync status for all or a specific service.
 fn show_status(service: Option<String>) -> Result<(), Box<dyn Error>> {
     if let Some(s) = service {
@@ -232,49 +376,19 @@ fn show_service_status(display_name: &str, service_name: &str) {
     }
 }
 
-fn classify_sync_change(remote: &RemoteTaskSnapshot, existing: Option<&LocalTaskSnapshot>) -> SyncChangeKind {
+/// How syncing changes the local task; `task` is the task after `sync_item`.
+fn classify_sync_change(task: &Task, existing: Option<&Task>) -> SyncChangeKind {
     match existing {
         None => SyncChangeKind::Create,
-        Some(local) => {
-            if local.title == remote.title
-                && local.description == remote.notes
-                && local.state == remote.state
-            {
-                SyncChangeKind::Unchanged
-            } else {
-                SyncChangeKind::Update
-            }
-        }
-    }
-}
-
-fn build_task_from_remote(remote: &RemoteTaskSnapshot, existing: Option<&LocalTaskSnapshot>) -> Task {
-    let now = Utc::now();
-    let mut task = Task::new(remote.title.clone());
-    task.description = remote.notes.clone();
-    task.tags = vec!["google_tasks".to_string(), format!("google_list:{}", remote.list_title)];
-    task.estimated_minutes = Some(25);
-    task.required_minutes = Some(25);
-    task.source_service = Some("google_tasks".to_string());
-    task.source_external_id = Some(remote.external_id.clone());
-    task.updated_at = now;
-
-    let mut state = remote.state;
-    if let Some(local) = existing {
-        if matches!(local.state, TaskState::Running | TaskState::Paused) && remote.state == TaskState::Ready {
-            state = local.state;
+        Some(local)
+            if local.title == task.title
+                && local.description == task.description
+                && local.state == task.state =>
+        {
+            SyncChangeKind::Unchanged
         }
+        Some(_) => SyncChangeKind::Update,
     }
-
-    task.state = state;
-    if state == TaskState::Done {
-        task.completed = true;
-        task.completed_at = Some(now);
-    } else {
-        task.completed = false;
-        task.completed_at = None;
-    }
-    task
 }
 
 fn read_google_tokens() -> Result<oauth::OAuthTokens, Box<dyn Error>> {
@@ -316,41 +430,7 @@ fn get_google_access_token() -> Result<String, Box<dyn Error>> {
     Ok(refreshed.access_token)
 }
 
-fn parse_remote_task(list_id: &str, list_title: &str, raw: &Value) -> Option<RemoteTaskSnapshot> {
-    let task_id = raw.get("id")?.as_str()?.trim();
-    if task_id.is_empty() {
-        return None;
-    }
-    let title = raw
-        .get("title")
-        .and_then(Value::as_str)
-        .unwrap_or("(untitled)")
-        .trim()
-        .to_string();
-    let notes = raw
-        .get("notes")
-        .and_then(Value::as_str)
-        .map(|s| s.trim().to_string())
-        .filter(|s| !s.is_empty());
-    let status = raw
-        .get("status")
-        .and_then(Value::as_str)
-        .unwrap_or("needsAction");
-    let state = if status.eq_ignore_ascii_case("completed") {
-        TaskState::Done
-    } else {
-        TaskState::Ready
-    };
-    Some(RemoteTaskSnapshot {
-        external_id: format!("{list_id}:{task_id}"),
-        list_title: list_title.to_string(),
-        title,
-        notes,
-        state,
-    })
-}
-
-fn fetch_google_remote_tasks(access_token: &str) -> Result<Vec<RemoteTaskSnapshot>, Box<dyn Error>> {
+fn fetch_google_remote_tasks(access_token: &str) -> Result<Vec<ExternalItem>, Box<dyn Error>> {
     let token = access_token.to_string();
     let rt = tokio::runtime::Runtime::new()?;
     rt.block_on(async move {
@@ -391,8 +471,10 @@ fn fetch_google_remote_tasks(access_token: &str) -> Result<Vec<RemoteTaskSnapsho
                 let tasks_json: Value = tasks_resp.json().await?;
                 if let Some(items) = tasks_json.get("items").and_then(Value::as_array) {
                     for raw_task in items {
-                        if let Some(task) = parse_remote_task(list_id, list_title, raw_task) {
-                            tasks.push(task);
+                        if let Some(item) =
+                            ExternalItem::from_google_task(list_id, list_title, raw_task)
+                        {
+                            tasks.push(item);
                         }
                     }
                 }
@@ -402,61 +484,52 @@ fn fetch_google_remote_tasks(access_token: &str) -> Result<Vec<RemoteTaskSnapsho
     })
 }
 
-fn load_existing_google_snapshots(
-    db: &ScheduleDb,
-) -> Result<HashMap<String, LocalTaskSnapshot>, Box<dyn Error>> {
-    let tasks = db.list_tasks()?;
+/// Tasks imported from `service`, keyed by their external ID.
+fn load_source_tasks(db: &ScheduleDb, service: &str) -> Result<HashMap<String, Task>, Box<dyn Error>> {
     let mut map = HashMap::new();
-    for task in tasks {
-        if task.source_service.as_deref() != Some("google_tasks") {
+    for task in db.list_tasks()? {
+        if task.source_service.as_deref() != Some(service) {
             continue;
         }
-        let Some(source_id) = task.source_external_id.clone() else {
-            continue;
-        };
-        map.insert(
-            source_id,
-            LocalTaskSnapshot {
-                title: task.title,
-                description: task.description,
-                state: task.state,
-            },
-        );
+        if let Some(source_id) = task.source_external_id.clone() {
+            map.insert(source_id, task);
+        }
     }
     Ok(map)
 }
 
-fn sync_google(dry_run: bool) -> Result<(), Box<dyn Error>> {
-    let integration = GoogleIntegration::new();
-    if !integration.is_authenticated() {
-        return Err("Google is not authenticated. Run 'pomodoroom-cli auth login google' first.".into());
-    }
-
-    let access_token = get_google_access_token()?;
-    let remote_tasks = fetch_google_remote_tasks(&access_token)?;
+/// Create or update the local tasks for `items` fetched from `service`.
+fn apply_remote_items(
+    service: &str,
+    items: Vec<ExternalItem>,
+    dry_run: bool,
+) -> Result<SyncSummary, Box<dyn Error>> {
     let db = ScheduleDb::open()?;
-    let existing = load_existing_google_snapshots(&db)?;
+    let existing = load_source_tasks(&db, service)?;
 
     let mut summary = SyncSummary {
-        fetched: remote_tasks.len(),
+        fetched: items.len(),
         ..SyncSummary::default()
     };
 
-    for remote in &remote_tasks {
-        let existing_snapshot = existing.get(&remote.external_id);
-        match classify_sync_change(remote, existing_snapshot) {
+    for item in items {
+        let local = existing.get(&item.external_id);
+        let task = sync_item(local, item);
+        match classify_sync_change(&task, local) {
             SyncChangeKind::Create => summary.creates += 1,
             SyncChangeKind::Update => summary.updates += 1,
             SyncChangeKind::Unchanged => summary.unchanged += 1,
         }
 
         if !dry_run {
-            let task = build_task_from_remote(remote, existing_snapshot);
             db.upsert_task_from_source(&task)?;
         }
     }
+    Ok(summary)
+}
 
-    println!("Google Tasks sync:");
+fn print_sync_summary(label: &str, summary: &SyncSummary, dry_run: bool) {
+    println!("{label} sync:");
     println!("  fetched   : {}", summary.fetched);
     println!("  create    : {}", summary.creates);
     println!("  update    : {}", summary.updates);
@@ -466,7 +539,18 @@ fn sync_google(dry_run: bool) -> Result<(), Box<dyn Error>> {
     } else {
         println!("  mode      : applied");
     }
+}
+
+fn sync_google(dry_run: bool) -> Result<(), Box<dyn Error>> {
+    let integration = GoogleIntegration::new();
+    if !integration.is_authenticated() {
+        return Err("Google is not authenticated. Run 'pomodoroom-cli auth login google' first.".into());
+    }
 
+    let access_token = get_google_access_token()?;
+    let remote_tasks = fetch_google_remote_tasks(&access_token)?;
+    let summary = apply_remote_items(GOOGLE_TASKS_SOURCE, remote_tasks, dry_run)?;
+    print_sync_summary("Google Tasks", &summary, dry_run);
     Ok(())
 }
 
@@ -500,18 +584,22 @@ fn sync_linear(dry_run: bool) -> Result<(), Box<dyn Error>> {
     Ok(())
 }
 
-/// Sync GitHub status.
+/// Sync assigned GitHub issues and pull requests.
 fn sync_github(dry_run: bool) -> Result<(), Box<dyn Error>> {
     use pomodoroom_core::integrations::github::GitHubIntegration;
     let g = GitHubIntegration::new();
     if !g.is_authenticated() {
         return Err("GitHub is not authenticated. Run 'pomodoroom-cli auth login github' first.".into());
     }
-    if dry_run {
-        println!("GitHub: authenticated, push-only integration currently (no pull diff)");
-    } else {
-        println!("GitHub: authenticated");
-    }
+
+    let rt = tokio::runtime::Runtime::new()?;
+    let items = {
+        let _guard = rt.enter();
+        g.fetch_assigned_items()?
+    };
+    let items = items.iter().map(ExternalItem::from).collect();
+    let summary = apply_remote_items(GITHUB_SOURCE, items, dry_run)?;
+    print_sync_summary("GitHub", &summary, dry_run);
     Ok(())
 }
 
@@ -547,44 +635,39 @@ fn sync_slack(dry_run: bool) -> Result<(), Box<dyn Error>> {
 
 #[cfg(test)]
 mod tests {
-    use super::{classify_sync_change, LocalTaskSnapshot, RemoteTaskSnapshot, SyncChangeKind};
-    use pomodoroom_core::task::TaskState;
-
-    fn remote() -> RemoteTaskSnapshot {
-        RemoteTaskSnapshot {
-            external_id: "list-1:task-1".to_string(),
-            list_title: "Inbox".to_string(),
-            title: "Write docs".to_string(),
-            notes: Some("details".to_string()),
-            state: TaskState::Ready,
-        }
+    use super::{classify_sync_change, SyncChangeKind};
+    use pomodoroom_core::integrations::{sync_item, ExternalItem};
+
+    fn remote() -> ExternalItem {
+        let raw = serde_json::json!({
+            "id": "task-1",
+            "title": "Write docs",
+            "notes": "details",
+        });
+        ExternalItem::from_google_task("list-1", "Inbox", &raw).unwrap()
     }
 
     #[test]
     fn classify_new_task_as_create() {
-        let change = classify_sync_change(&remote(), None);
+        let task = sync_item(None, remote());
+        let change = classify_sync_change(&task, None);
         assert!(matches!(change, SyncChangeKind::Create));
     }
 
     #[test]
     fn classify_same_content_as_unchanged() {
-        let existing = LocalTaskSnapshot {
-            title: "Write docs".to_string(),
-            description: Some("details".to_string()),
-            state: TaskState::Ready,
-        };
-        let change = classify_sync_change(&remote(), Some(&existing));
+        let existing = sync_item(None, remote());
+        let task = sync_item(Some(&existing), remote());
+        let change = classify_sync_change(&task, Some(&existing));
         assert!(matches!(change, SyncChangeKind::Unchanged));
     }
 
     #[test]
     fn classify_field_change_as_update() {
-        let existing = LocalTaskSnapshot {
-            title: "Old".to_string(),
-            description: Some("details".to_string()),
-            state: TaskState::Ready,
-        };
-        let change = classify_sync_change(&remote(), Some(&existing));
+        let mut existing = sync_item(None, remote());
+        existing.title = "Old".to_string();
+        let task = sync_item(Some(&existing), remote());
+        let change = classify_sync_change(&task, Some(&existing));
         assert!(matches!(change, SyncChangeKind::Update));
     }
 }
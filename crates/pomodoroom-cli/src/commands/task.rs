@@ -1,13 +1,20 @@
 //! Task management commands for CLI.
 //!
 //! Implements full task CRUD operations with state transitions:
-//! - list, get, create, update, delete
+//! - list, get, create, add, update, delete
 //! - start, pause, resume, complete, postpone, extend
+//! - generate-recurring (today's instances of recurring tasks)
+//! - tree (parent tasks with their split segments)
 
-use chrono::Utc;
+use std::collections::HashMap;
+
+use chrono::{DateTime, Duration, Local, NaiveDateTime, TimeZone, Utc, Weekday};
 use clap::Subcommand;
 use pomodoroom_core::storage::schedule_db::ScheduleDb;
-use pomodoroom_core::task::{EnergyLevel, Task, TaskState};
+use pomodoroom_core::task::{EnergyLevel, Recurrence, Task, TaskKind, TaskState, POMODORO_MINUTES};
+use serde::Serialize;
+
+use crate::natural_time::{parse_estimate, parse_natural_time};
 
 /// Format task state for display
 fn format_state(state: TaskState) -> &'static str {
@@ -40,6 +47,44 @@ fn parse_energy(s: &str) -> Option<EnergyLevel> {
     }
 }
 
+/// Parse a recurrence rule: daily, weekdays, weekly or weekly:<days>
+///
+/// Plain `weekly` repeats on the weekday the task was created.
+fn parse_recurrence(s: &str) -> Option<Recurrence> {
+    match s.to_lowercase().as_str() {
+        "daily" => Some(Recurrence::daily()),
+        "weekdays" => Some(Recurrence::weekdays_only()),
+        "weekly" => Some(Recurrence::weekly(Vec::new())),
+        rule => {
+            let days = rule.strip_prefix("weekly:")?;
+            let weekdays = days
+                .split(',')
+                .map(|day| day.trim().parse::<Weekday>().ok())
+                .collect::<Option<Vec<_>>>()?;
+            Some(Recurrence::weekly(weekdays))
+        }
+    }
+}
+
+/// Read a line from stdin after printing `label`.
+fn prompt(label: &str) -> Result<String, Box<dyn std::error::Error>> {
+    use std::io::Write;
+    print!("{}", label);
+    std::io::stdout().flush()?;
+    let mut input = String::new();
+    std::io::stdin().read_line(&mut input)?;
+    Ok(input.trim().to_string())
+}
+
+/// Convert a local wall-clock time to UTC.
+fn local_to_utc(naive: NaiveDateTime) -> Result<DateTime<Utc>, String> {
+    Local
+        .from_local_datetime(&naive)
+        .earliest()
+        .map(|local| local.with_timezone(&Utc))
+        .ok_or_else(|| format!("{} does not exist in the local time zone", naive))
+}
+
 /// Format task as table row
 fn format_task_row(task: &Task) -> String {
     let state_str = format_state(task.state);
@@ -60,6 +105,157 @@ fn format_task_row(task: &Task) -> String {
     )
 }
 
+/// Title of the synthetic root that collects segments whose parent is gone.
+const ORPHAN_ROOT_TITLE: &str = "(missing parent)";
+
+/// A node in `task tree` output.
+#[derive(Debug, Serialize)]
+struct TreeNode {
+    /// Task ID; `None` for the synthetic orphan root
+    id: Option<String>,
+    title: String,
+    state: Option<&'static str>,
+    segment_order: Option<i32>,
+    /// For parents, minutes are summed over the segments
+    elapsed_minutes: u32,
+    estimated_minutes: Option<u32>,
+    /// Segments in DONE state
+    done_segments: usize,
+    total_segments: usize,
+    segments: Vec<TreeNode>,
+}
+
+impl TreeNode {
+    fn leaf(task: &Task) -> Self {
+        Self {
+            id: Some(task.id.clone()),
+            title: task.title.clone(),
+            state: Some(format_state(task.state)),
+            segment_order: task.segment_order,
+            elapsed_minutes: task.elapsed_minutes,
+            estimated_minutes: task.estimated_minutes,
+            done_segments: 0,
+            total_segments: 0,
+            segments: Vec::new(),
+        }
+    }
+
+    /// Attach `segments`, rolling their time and completion up into `self`.
+    fn with_segments(mut self, segments: &[&Task]) -> Self {
+        self.elapsed_minutes = segments.iter().map(|t| t.elapsed_minutes).sum();
+        self.estimated_minutes = segments
+            .iter()
+            .filter_map(|t| t.estimated_minutes)
+            .reduce(|a, b| a + b);
+        self.done_segments = segments
+            .iter()
+            .filter(|t| t.state == TaskState::Done)
+            .count();
+        self.total_segments = segments.len();
+        self.segments = segments.iter().map(|t| Self::leaf(t)).collect();
+        self
+    }
+
+    /// Completion summary, e.g. `1/3 done (33%)`.
+    fn completion(&self) -> String {
+        let pct = (self.done_segments * 100)
+            .checked_div(self.total_segments)
+            .unwrap_or(0);
+        format!(
+            "{}/{} done ({}%)",
+            self.done_segments, self.total_segments, pct
+        )
+    }
+
+    fn minutes(&self) -> String {
+        let estimate = self
+            .estimated_minutes
+            .map_or("-".to_string(), |m| format!("{}m", m));
+        format!("{}m/{}", self.elapsed_minutes, estimate)
+    }
+}
+
+/// Build the parent/segment forest from a flat task list.
+///
+/// Only parents that have segments are included. Segments whose parent is
+/// not in `tasks` are collected under a synthetic root at the end.
+fn build_task_tree(tasks: &[Task]) -> Vec<TreeNode> {
+    let mut children: HashMap<&str, Vec<&Task>> = HashMap::new();
+    for task in tasks {
+        if let Some(parent_id) = task.parent_task_id.as_deref() {
+            children.entry(parent_id).or_default().push(task);
+        }
+    }
+    for segments in children.values_mut() {
+        segments.sort_by_key(|t| (t.segment_order.unwrap_or(i32::MAX), t.created_at));
+    }
+
+    let mut parents: Vec<&Task> = tasks
+        .iter()
+        .filter(|t| children.contains_key(t.id.as_str()))
+        .collect();
+    parents.sort_by_key(|t| t.created_at);
+
+    let mut roots: Vec<TreeNode> = parents
+        .iter()
+        .map(|parent| {
+            let segments = children.remove(parent.id.as_str()).unwrap_or_default();
+            TreeNode::leaf(parent).with_segments(&segments)
+        })
+        .collect();
+
+    let mut orphans: Vec<&Task> = children.into_values().flatten().collect();
+    if !orphans.is_empty() {
+        orphans.sort_by_key(|t| (t.parent_task_id.clone(), t.segment_order, t.created_at));
+        let root = TreeNode {
+            id: None,
+            title: ORPHAN_ROOT_TITLE.to_string(),
+            state: None,
+            segment_order: None,
+            elapsed_minutes: 0,
+            estimated_minutes: None,
+            done_segments: 0,
+            total_segments: 0,
+            segments: Vec::new(),
+        };
+        roots.push(root.with_segments(&orphans));
+    }
+    roots
+}
+
+/// Print the task tree as indented text.
+fn print_task_tree(roots: &[TreeNode]) {
+    for root in roots {
+        match (&root.id, root.state) {
+            (Some(id), Some(state)) => println!(
+                "{} [{}] {} {} - {}",
+                root.title,
+                state,
+                root.minutes(),
+                root.completion(),
+                id
+            ),
+            _ => println!("{} {} {}", root.title, root.minutes(), root.completion()),
+        }
+        let last = root.segments.len().saturating_sub(1);
+        for (i, segment) in root.segments.iter().enumerate() {
+            let branch = if i == last { "└─" } else { "├─" };
+            let order = segment
+                .segment_order
+                .map_or(String::new(), |o| format!("#{} ", o));
+            println!(
+                "  {} {}{} [{}] {} - {}",
+                branch,
+                order,
+                segment.title,
+                segment.state.unwrap_or("-"),
+                segment.minutes(),
+                segment.id.as_deref().unwrap_or("-")
+            );
+        }
+    }
+}
+
 /// Print task list header
 fn print_list_header() {
     println!(
@@ -113,6 +309,40 @@ pub enum TaskAction {
         /// Comma-separated tags
         #[arg(long, short = 't')]
         tags: Option<String>,
+        /// Create as the next segment of this parent task
+        #[arg(long)]
+        parent: Option<String>,
+        /// Make a recurring template: daily, weekdays, weekly or weekly:mon,thu
+        #[arg(long)]
+        recurrence: Option<String>,
+    },
+    /// Add a task using natural dates and estimates
+    ///
+    /// Example: task add "Write report" --due "tomorrow 17:00" --est 3p --energy high
+    Add {
+        /// Task title (prompted for when omitted)
+        title: Option<String>,
+        /// Deadline: today, tomorrow, mon, 2026-03-20, optionally with 17:00 or 9am
+        #[arg(long, conflicts_with = "at")]
+        due: Option<String>,
+        /// Fixed start time, e.g. "mon 9am"
+        #[arg(long)]
+        at: Option<String>,
+        /// Estimate in pomodoros (3p) or as a duration (90m, 2h)
+        #[arg(long)]
+        est: Option<String>,
+        /// Energy level (low, medium, high)
+        #[arg(long)]
+        energy: Option<String>,
+        /// Priority (0-100, default: 50)
+        #[arg(long, short = 'p')]
+        priority: Option<i32>,
+        /// Project ID to associate with
+        #[arg(long)]
+        project: Option<String>,
+        /// Comma-separated tags
+        #[arg(long, short = 't')]
+        tags: Option<String>,
     },
     /// Update a task
     Update {
@@ -171,6 +401,21 @@ pub enum TaskAction {
         /// Minutes to add
         minutes: u32,
     },
+    /// Create today's instances of recurring tasks
+    GenerateRecurring {
+        /// Output as JSON
+        #[arg(long)]
+        json: bool,
+    },
+    /// Show parent tasks with their segments and rolled-up completion
+    Tree {
+        /// Filter by project ID or name
+        #[arg(long)]
+        project: Option<String>,
+        /// Output format (table or json)
+        #[arg(long, default_value = "table")]
+        format: String,
+    },
 }
 
 pub fn run(action: TaskAction) -> Result<(), Box<dyn std::error::Error>> {
@@ -182,7 +427,9 @@ pub fn run(action: TaskAction) -> Result<(), Box<dyn std::error::Error>> {
             project,
             json,
         } => {
+            db.generate_due_instances(Utc::now())?;
             let mut tasks = db.list_tasks()?;
+            tasks.retain(|t| !t.is_recurrence_template());
 
             // Filter by state
             if let Some(ref state_str) = state {
@@ -269,6 +516,8 @@ pub fn run(action: TaskAction) -> Result<(), Box<dyn std::error::Error>> {
             energy,
             project,
             tags,
+            parent,
+            recurrence,
         } => {
             let mut task = Task::new(&title);
             task.description = desc;
@@ -286,12 +535,113 @@ pub fn run(action: TaskAction) -> Result<(), Box<dyn std::error::Error>> {
             task.tags = tags
                 .map(|t| t.split(',').map(|s| s.trim().to_string()).collect())
                 .unwrap_or_default();
+            if let Some(parent_id) = parent {
+                db.get_task(&parent_id)?
+                    .ok_or(format!("Parent task not found: {}", parent_id))?;
+                let next_order = db
+                    .list_tasks()?
+                    .iter()
+                    .filter(|t| t.parent_task_id.as_deref() == Some(parent_id.as_str()))
+                    .filter_map(|t| t.segment_order)
+                    .max()
+                    .unwrap_or(0)
+                    + 1;
+                task.parent_task_id = Some(parent_id);
+                task.segment_order = Some(next_order);
+            }
+            if let Some(rule) = recurrence {
+                task.recurrence = Some(parse_recurrence(&rule).ok_or_else(|| {
+                    format!(
+                        "Invalid recurrence: {}. Use daily, weekdays, weekly or weekly:mon,thu",
+                        rule
+                    )
+                })?);
+            }
 
             db.create_task(&task)?;
             println!("Task created: {}", task.id);
             println!("Title: {}", task.title);
             println!("State: {}", format_state(task.state));
         }
+        TaskAction::Add {
+            title,
+            due,
+            at,
+            est,
+            energy,
+            priority,
+            project,
+            tags,
+        } => {
+            let title = match title {
+                Some(title) => title,
+                None => prompt("Title: ")?,
+            };
+            if title.trim().is_empty() {
+                return Err("Task title cannot be empty".into());
+            }
+
+            let mut task = Task::new(title.trim());
+            task.priority = priority;
+            task.project_id = project;
+            task.tags = tags
+                .map(|t| t.split(',').map(|s| s.trim().to_string()).collect())
+                .unwrap_or_default();
+            if let Some(energy_str) = energy {
+                task.energy = parse_energy(&energy_str).ok_or_else(|| {
+                    format!(
+                        "Invalid energy level: {}. Use low, medium, or high",
+                        energy_str
+                    )
+                })?;
+            }
+            if let Some(est) = est {
+                let estimate = parse_estimate(&est)?;
+                task.estimated_pomodoros = estimate.pomodoros;
+                task.estimated_minutes = Some(estimate.minutes);
+                task.required_minutes = Some(estimate.minutes);
+            }
+
+            let now = Local::now().naive_local();
+            if let Some(due) = due {
+                let deadline = parse_natural_time(&due, now)?.deadline();
+                task.kind = TaskKind::FlexWindow;
+                task.window_end_at = Some(local_to_utc(deadline)?);
+            }
+            if let Some(at) = at {
+                let start = parse_natural_time(&at, now)?
+                    .time()
+                    .ok_or_else(|| format!("--at needs a time of day, e.g. \"{} 9am\"", at))?;
+                let minutes = task.estimated_minutes.unwrap_or(POMODORO_MINUTES);
+                task.kind = TaskKind::FixedEvent;
+                task.fixed_start_at = Some(local_to_utc(start)?);
+                task.fixed_end_at = Some(local_to_utc(start + Duration::minutes(minutes as i64))?);
+            }
+
+            db.create_task(&task)?;
+            println!("Task created: {}", task.id);
+            println!("Title: {}", task.title);
+            if let Some(minutes) = task.estimated_minutes {
+                println!(
+                    "Estimate: {}m ({} pomodoros)",
+                    minutes, task.estimated_pomodoros
+                );
+            }
+            if let Some(due) = task.window_end_at {
+                println!(
+                    "Due: {}",
+                    due.with_timezone(&Local).format("%Y-%m-%d %H:%M")
+                );
+            }
+            if let (Some(start), Some(end)) = (task.fixed_start_at, task.fixed_end_at) {
+                println!(
+                    "At: {} - {}",
+                    start.with_timezone(&Local).format("%Y-%m-%d %H:%M"),
+                    end.with_timezone(&Local).format("%H:%M")
+                );
+            }
+            println!("Energy: {:?}", task.energy);
+        }
         TaskAction::Update {
             id,
             title,
@@ -433,6 +783,53 @@ pub fn run(action: TaskAction) -> Result<(), Box<dyn std::error::Error>> {
                 minutes
             );
         }
+        TaskAction::GenerateRecurring { json } => {
+            let generated = db.generate_due_instances(Utc::now())?;
+            if json {
+                println!("{}", serde_json::to_string_pretty(&generated)?);
+            } else if generated.is_empty() {
+                println!("No recurring tasks due today.");
+            } else {
+                for task in &generated {
+                    println!("Created: {} ({})", task.title, task.id);
+                }
+            }
+        }
+        TaskAction::Tree { project, format } => {
+            let mut tasks = db.list_tasks()?;
+            if let Some(ref project_id) = project {
+                let in_project = |t: &Task| {
+                    t.project_id.as_ref() == Some(project_id)
+                        || t.project_name.as_ref() == Some(project_id)
+                };
+                // Segments often carry no project of their own, so keep
+                // them when their parent matches
+                let parents: Vec<String> = tasks
+                    .iter()
+                    .filter(|t| in_project(t))
+                    .map(|t| t.id.clone())
+                    .collect();
+                tasks.retain(|t| {
+                    in_project(t)
+                        || t.parent_task_id
+                            .as_ref()
+                            .is_some_and(|p| parents.contains(p))
+                });
+            }
+
+            let roots = build_task_tree(&tasks);
+            match format.as_str() {
+                "json" => println!("{}", serde_json::to_string_pretty(&roots)?),
+                "table" => {
+                    if roots.is_empty() {
+                        println!("No split tasks found.");
+                    } else {
+                        print_task_tree(&roots);
+                    }
+                }
+                other => return Err(format!("Unknown format: {}. Use table or json", other).into()),
+            }
+        }
     }
 
     Ok(())
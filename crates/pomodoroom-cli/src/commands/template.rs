@@ -1,7 +1,7 @@
 //! Daily template management commands for CLI.
 
 use clap::Subcommand;
-use pomodoroom_core::schedule::{DailyTemplate, FixedEvent};
+use pomodoroom_core::schedule::{DailyTemplate, FixedEvent, FixedEventKind};
 use pomodoroom_core::storage::schedule_db::ScheduleDb;
 use uuid::Uuid;
 
@@ -46,6 +46,7 @@ pub fn run(action: TemplateAction) -> Result<(), Box<dyn std::error::Error>> {
                     duration_minutes: 60,
                     days: vec![1, 2, 3, 4, 5], // Mon-Fri
                     enabled: true,
+                    kind: FixedEventKind::Meal,
                 }],
                 max_parallel_lanes: Some(2),
             };
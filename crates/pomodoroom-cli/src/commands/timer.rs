@@ -1,7 +1,7 @@
 use clap::Subcommand;
 use pomodoroom_core::storage::Database;
 use pomodoroom_core::timer::TimerEngine;
-use pomodoroom_core::{RecipeEngine, ActionExecutor, Event};
+use pomodoroom_core::{ActionExecutor, Config, Event, RecipeEngine};
 
 const ENGINE_KEY: &str = "timer_engine";
 
@@ -37,12 +37,19 @@ pub enum TimerAction {
 }
 
 fn load_engine(db: &Database) -> TimerEngine {
-    if let Ok(Some(json)) = db.kv_get(ENGINE_KEY) {
-        if let Ok(engine) = serde_json::from_str::<TimerEngine>(&json) {
-            return engine;
-        }
-    }
-    TimerEngine::new()
+    let mut engine = db
+        .kv_get(ENGINE_KEY)
+        .ok()
+        .flatten()
+        .and_then(|json| serde_json::from_str::<TimerEngine>(&json).ok())
+        .unwrap_or_default();
+    let config = Config::load_or_default();
+    engine.set_auto_start(
+        config.schedule(),
+        config.auto_start_breaks,
+        config.auto_start_focus,
+    );
+    engine
 }
 
 fn save_engine(db: &Database, engine: &TimerEngine) -> Result<(), Box<dyn std::error::Error>> {
@@ -52,7 +59,9 @@ fn save_engine(db: &Database, engine: &TimerEngine) -> Result<(), Box<dyn std::e
 }
 
 /// Handle recipe evaluation and execution for a timer event
-fn handle_recipes(event: &Event) {
+///
+/// Timer actions (extend/skip) are applied to `timer`, which the caller saves.
+fn handle_recipes(event: &Event, timer: &mut TimerEngine) {
     let debug_mode = std::env::var("POMODOROOM_DEBUG_RECIPES").is_ok();
 
     if let Err(e) = RecipeEngine::new() {
@@ -68,7 +77,7 @@ fn handle_recipes(event: &Event) {
         Ok(actions) => {
             if !actions.is_empty() {
                 let executor = ActionExecutor::new();
-                let log = executor.execute_batch(actions);
+                let log = executor.execute_batch_with_timer(actions, timer);
 
                 eprintln!("Recipe execution: {} success, {} failed, {} skipped",
                     log.success_count(), log.failure_count(), log.skipped_count());
@@ -94,7 +103,7 @@ pub fn run(action: TimerAction) -> Result<(), Box<dyn std::error::Error>> {
         TimerAction::Update { task_id, title, required, elapsed } => {
             if let Some(event) = engine.update_session(task_id, title, required, elapsed) {
                 println!("{}", serde_json::to_string_pretty(&event)?);
-                handle_recipes(&event);
+                handle_recipes(&event, &mut engine);
             } else {
                 let snapshot = engine.snapshot();
                 println!("{}", serde_json::to_string_pretty(&snapshot)?);
@@ -121,13 +130,13 @@ pub fn run(action: TimerAction) -> Result<(), Box<dyn std::error::Error>> {
         }
         TimerAction::Status => {
             // Tick to update elapsed time
-            let completed = engine.tick();
+            let events = engine.tick_events();
             let snapshot = engine.snapshot();
             println!("{}", serde_json::to_string_pretty(&snapshot)?);
-            if let Some(event) = completed {
-                // Also output completion event
+            // Also output the completion and any auto-started step
+            for event in events {
                 println!("{}", serde_json::to_string_pretty(&event)?);
-                handle_recipes(&event);
+                handle_recipes(&event, &mut engine);
             }
         }
     }
@@ -2,6 +2,7 @@ use clap::{CommandFactory, Parser, Subcommand};
 use clap_complete::{generate, Shell};
 
 mod commands;
+mod natural_time;
 
 #[derive(Parser)]
 #[command(name = "pomodoroom-cli", version)]
@@ -88,6 +89,11 @@ enum Commands {
         #[command(subcommand)]
         action: commands::recipe::RecipeAction,
     },
+    /// Task scoring tools
+    Score {
+        #[command(subcommand)]
+        action: commands::score::ScoreAction,
+    },
     /// Generate shell completion script
     Complete {
         /// Shell type (bash, zsh, fish, elvish, powershell)
@@ -113,6 +119,7 @@ fn main() {
         Commands::Energy { action } => commands::energy::run(action),
         Commands::Jit { action } => commands::jit::run(action),
         Commands::Recipe { action } => commands::recipe::run(action),
+        Commands::Score { action } => commands::score::run(action),
         Commands::Complete { shell } => {
             print_completions(shell);
             Ok(())
@@ -0,0 +1,350 @@
+//! Natural time and estimate parsing for CLI flags.
+//!
+//! Accepts short phrases such as "today", "tomorrow 17:00" or "mon 9am" for
+//! dates, and "3p" (pomodoros) or "90m" for estimates. Input that could mean
+//! more than one thing is rejected with a hint instead of guessed.
+
+use chrono::{Datelike, Duration, NaiveDate, NaiveDateTime, NaiveTime, Weekday};
+use pomodoroom_core::task::POMODORO_MINUTES;
+
+const TIME_FORMS: &str =
+    "today, tomorrow, a weekday (mon, tuesday), YYYY-MM-DD, optionally with a time (17:00, 9am, 9:30pm, noon)";
+
+/// A parsed date phrase.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NaturalTime {
+    /// A whole day, no time given
+    Day(NaiveDate),
+    /// A specific local time
+    At(NaiveDateTime),
+}
+
+impl NaturalTime {
+    /// Latest local time covered: the time itself, or the end of the day.
+    pub fn deadline(self) -> NaiveDateTime {
+        match self {
+            NaturalTime::Day(date) => (date + Duration::days(1)).and_time(NaiveTime::MIN),
+            NaturalTime::At(at) => at,
+        }
+    }
+
+    /// The local time, if one was given.
+    pub fn time(self) -> Option<NaiveDateTime> {
+        match self {
+            NaturalTime::Day(_) => None,
+            NaturalTime::At(at) => Some(at),
+        }
+    }
+}
+
+/// A parsed effort estimate.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Estimate {
+    /// Estimate in pomodoros, rounded up
+    pub pomodoros: i32,
+    /// Estimate in minutes
+    pub minutes: u32,
+}
+
+/// Parse a date phrase relative to the local time `now`.
+///
+/// A weekday means its next occurrence, today included. A time without a
+/// day means its next occurrence. Times and days in the past are rejected.
+pub fn parse_natural_time(input: &str, now: NaiveDateTime) -> Result<NaturalTime, String> {
+    let input = input.trim().to_lowercase();
+    let tokens: Vec<&str> = input.split_whitespace().collect();
+    if tokens.is_empty() || tokens.len() > 2 {
+        return Err(format!("Cannot parse '{}'. Use {}", input, TIME_FORMS));
+    }
+
+    let mut day: Option<DayToken> = None;
+    let mut time: Option<NaiveTime> = None;
+    for token in tokens {
+        if let Some(parsed) = parse_day(token, now.date())? {
+            if day.replace(parsed).is_some() {
+                return Err(format!("'{}' names more than one day", input));
+            }
+        } else if let Some(parsed) = parse_time(token)? {
+            if time.replace(parsed).is_some() {
+                return Err(format!("'{}' names more than one time", input));
+            }
+        } else {
+            return Err(format!("Cannot parse '{}'. Use {}", token, TIME_FORMS));
+        }
+    }
+
+    let today = now.date();
+    match (day, time) {
+        (Some(day), None) => {
+            let date = day.date(today);
+            if date < today {
+                return Err(format!("'{}' is in the past", input));
+            }
+            Ok(NaturalTime::Day(date))
+        }
+        (Some(day), Some(time)) => {
+            let mut at = day.date(today).and_time(time);
+            if at <= now {
+                match day {
+                    // The same weekday later on, e.g. "mon 9am" on Monday at noon
+                    DayToken::Weekday(_) => at += Duration::days(7),
+                    DayToken::Date(_) => return Err(format!("'{}' is in the past", input)),
+                }
+            }
+            Ok(NaturalTime::At(at))
+        }
+        (None, Some(time)) => {
+            let mut at = today.and_time(time);
+            if at <= now {
+                at += Duration::days(1);
+            }
+            Ok(NaturalTime::At(at))
+        }
+        (None, None) => unreachable!("at least one token was parsed"),
+    }
+}
+
+/// Parse an estimate such as "3p", "90m", "2h" or "1h30m".
+pub fn parse_estimate(input: &str) -> Result<Estimate, String> {
+    let input = input.trim().to_lowercase();
+    let hint = "Use pomodoros (3p) or a duration (90m, 2h, 1h30m)";
+    if input.chars().all(|c| c.is_ascii_digit()) {
+        return Err(format!("Ambiguous estimate '{}'. {}", input, hint));
+    }
+
+    let minutes = if let Some(count) = input.strip_suffix('p') {
+        count
+            .parse::<u32>()
+            .ok()
+            .and_then(|count| count.checked_mul(POMODORO_MINUTES))
+            .ok_or_else(|| format!("Invalid estimate '{}'. {}", input, hint))?
+    } else {
+        parse_duration_minutes(&input)
+            .ok_or_else(|| format!("Invalid estimate '{}'. {}", input, hint))?
+    };
+    if minutes == 0 {
+        return Err(format!("Estimate '{}' must be greater than zero", input));
+    }
+
+    Ok(Estimate {
+        pomodoros: minutes.div_ceil(POMODORO_MINUTES) as i32,
+        minutes,
+    })
+}
+
+/// A day named in a phrase.
+#[derive(Debug, Clone, Copy)]
+enum DayToken {
+    Date(NaiveDate),
+    Weekday(Weekday),
+}
+
+impl DayToken {
+    fn date(self, today: NaiveDate) -> NaiveDate {
+        match self {
+            DayToken::Date(date) => date,
+            DayToken::Weekday(weekday) => {
+                let ahead = (weekday.num_days_from_monday() + 7
+                    - today.weekday().num_days_from_monday())
+                    % 7;
+                today + Duration::days(ahead as i64)
+            }
+        }
+    }
+}
+
+/// Parse a day token; `Ok(None)` if it is not a day.
+fn parse_day(token: &str, today: NaiveDate) -> Result<Option<DayToken>, String> {
+    let day = match token {
+        "today" => DayToken::Date(today),
+        "tomorrow" => DayToken::Date(today + Duration::days(1)),
+        "mon" | "monday" => DayToken::Weekday(Weekday::Mon),
+        "tue" | "tues" | "tuesday" => DayToken::Weekday(Weekday::Tue),
+        "wed" | "wednesday" => DayToken::Weekday(Weekday::Wed),
+        "thu" | "thur" | "thurs" | "thursday" => DayToken::Weekday(Weekday::Thu),
+        "fri" | "friday" => DayToken::Weekday(Weekday::Fri),
+        "sat" | "saturday" => DayToken::Weekday(Weekday::Sat),
+        "sun" | "sunday" => DayToken::Weekday(Weekday::Sun),
+        _ if token.contains('/') => {
+            return Err(format!(
+                "Ambiguous date '{}': day and month order is unclear. Use YYYY-MM-DD",
+                token
+            ))
+        }
+        _ => match NaiveDate::parse_from_str(token, "%Y-%m-%d") {
+            Ok(date) => DayToken::Date(date),
+            Err(_) => return Ok(None),
+        },
+    };
+    Ok(Some(day))
+}
+
+/// Parse a time token; `Ok(None)` if it is not a time.
+fn parse_time(token: &str) -> Result<Option<NaiveTime>, String> {
+    if token == "noon" {
+        return Ok(NaiveTime::from_hms_opt(12, 0, 0));
+    }
+
+    let (clock, meridiem) = if let Some(clock) = token.strip_suffix("am") {
+        (clock, Some(false))
+    } else if let Some(clock) = token.strip_suffix("pm") {
+        (clock, Some(true))
+    } else {
+        (token, None)
+    };
+    if clock.is_empty() || !clock.chars().all(|c| c.is_ascii_digit() || c == ':') {
+        return Ok(None);
+    }
+
+    let (hour, minute) = match clock.split_once(':') {
+        Some((hour, minute)) if minute.len() == 2 => (hour, minute),
+        Some(_) => return Err(format!("Invalid time '{}'. Use HH:MM", token)),
+        None if meridiem.is_none() => {
+            return Err(format!(
+                "Ambiguous time '{0}': use {0}am, {0}pm or {0}:00",
+                token
+            ))
+        }
+        None => (clock, "0"),
+    };
+    let invalid = || format!("Invalid time '{}'", token);
+    let mut hour: u32 = hour.parse().map_err(|_| invalid())?;
+    let minute: u32 = minute.parse().map_err(|_| invalid())?;
+    if let Some(pm) = meridiem {
+        if !(1..=12).contains(&hour) {
+            return Err(invalid());
+        }
+        hour = hour % 12 + if pm { 12 } else { 0 };
+    }
+
+    NaiveTime::from_hms_opt(hour, minute, 0)
+        .map(Some)
+        .ok_or_else(invalid)
+}
+
+/// Parse "90m", "2h" or "1h30m" into minutes.
+fn parse_duration_minutes(input: &str) -> Option<u32> {
+    let (hours, rest) = match input.split_once('h') {
+        Some((hours, rest)) => (hours.parse::<u32>().ok()?, rest),
+        None => (0, input),
+    };
+    let minutes = match rest {
+        "" if input.contains('h') => 0,
+        _ => rest.strip_suffix('m')?.parse::<u32>().ok()?,
+    };
+    hours.checked_mul(60)?.checked_add(minutes)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Wednesday 2026-03-04 10:00
+    fn now() -> NaiveDateTime {
+        NaiveDate::from_ymd_opt(2026, 3, 4)
+            .unwrap()
+            .and_hms_opt(10, 0, 0)
+            .unwrap()
+    }
+
+    fn at(day: u32, hour: u32, minute: u32) -> NaturalTime {
+        NaturalTime::At(
+            NaiveDate::from_ymd_opt(2026, 3, day)
+                .unwrap()
+                .and_hms_opt(hour, minute, 0)
+                .unwrap(),
+        )
+    }
+
+    fn day(day: u32) -> NaturalTime {
+        NaturalTime::Day(NaiveDate::from_ymd_opt(2026, 3, day).unwrap())
+    }
+
+    #[test]
+    fn parses_days() {
+        assert_eq!(parse_natural_time("today", now()), Ok(day(4)));
+        assert_eq!(parse_natural_time("Tomorrow", now()), Ok(day(5)));
+        assert_eq!(parse_natural_time("fri", now()), Ok(day(6)));
+        assert_eq!(parse_natural_time("wednesday", now()), Ok(day(4)));
+        assert_eq!(parse_natural_time("mon", now()), Ok(day(9)));
+        assert_eq!(parse_natural_time("2026-03-20", now()), Ok(day(20)));
+        assert_eq!(
+            day(4).deadline(),
+            NaiveDate::from_ymd_opt(2026, 3, 5)
+                .unwrap()
+                .and_hms_opt(0, 0, 0)
+                .unwrap()
+        );
+    }
+
+    #[test]
+    fn parses_days_with_times() {
+        assert_eq!(
+            parse_natural_time("tomorrow 17:00", now()),
+            Ok(at(5, 17, 0))
+        );
+        assert_eq!(parse_natural_time("mon 9am", now()), Ok(at(9, 9, 0)));
+        assert_eq!(parse_natural_time("9:30pm today", now()), Ok(at(4, 21, 30)));
+        assert_eq!(parse_natural_time("today noon", now()), Ok(at(4, 12, 0)));
+        assert_eq!(parse_natural_time("fri 12am", now()), Ok(at(6, 0, 0)));
+        // Same weekday, time already passed: next week
+        assert_eq!(parse_natural_time("wed 9am", now()), Ok(at(11, 9, 0)));
+        // Time alone: next occurrence
+        assert_eq!(parse_natural_time("17:00", now()), Ok(at(4, 17, 0)));
+        assert_eq!(parse_natural_time("9am", now()), Ok(at(5, 9, 0)));
+        assert_eq!(at(5, 9, 0).time(), Some(at(5, 9, 0).deadline()));
+        assert_eq!(day(5).time(), None);
+    }
+
+    #[test]
+    fn rejects_ambiguous_or_invalid_times() {
+        let ambiguous = parse_natural_time("tomorrow 9", now()).unwrap_err();
+        assert!(ambiguous.contains("9am, 9pm or 9:00"), "{}", ambiguous);
+        let ambiguous = parse_natural_time("03/04", now()).unwrap_err();
+        assert!(ambiguous.contains("YYYY-MM-DD"), "{}", ambiguous);
+
+        assert!(parse_natural_time("", now()).is_err());
+        assert!(parse_natural_time("someday", now()).is_err());
+        assert!(parse_natural_time("today tomorrow", now()).is_err());
+        assert!(parse_natural_time("9am 5pm", now()).is_err());
+        assert!(parse_natural_time("next mon at 9am", now()).is_err());
+        assert!(parse_natural_time("13pm", now()).is_err());
+        assert!(parse_natural_time("25:00", now()).is_err());
+        assert!(parse_natural_time("today 8am", now()).is_err());
+        assert!(parse_natural_time("2026-03-01", now()).is_err());
+    }
+
+    #[test]
+    fn parses_estimates() {
+        assert_eq!(
+            parse_estimate("3p"),
+            Ok(Estimate {
+                pomodoros: 3,
+                minutes: 75
+            })
+        );
+        assert_eq!(
+            parse_estimate("90m"),
+            Ok(Estimate {
+                pomodoros: 4,
+                minutes: 90
+            })
+        );
+        assert_eq!(parse_estimate("2h").map(|e| e.minutes), Ok(120));
+        assert_eq!(parse_estimate("1h30m").map(|e| e.minutes), Ok(90));
+
+        let ambiguous = parse_estimate("3").unwrap_err();
+        assert!(ambiguous.contains("Ambiguous"), "{}", ambiguous);
+        assert!(parse_estimate("0p").is_err());
+        assert!(parse_estimate("3x").is_err());
+        assert!(parse_estimate("h").is_err());
+        assert!(parse_estimate("").is_err());
+    }
+
+    #[test]
+    fn rejects_overflowing_estimates() {
+        assert!(parse_estimate("4294967295p").is_err());
+        assert!(parse_estimate("71582789h").is_err());
+        assert!(parse_estimate("71582788h4294967295m").is_err());
+    }
+}
@@ -6,11 +6,22 @@ use std::process::Command;
 
 /// Run a CLI command and return output.
 fn run_cli(args: &[&str]) -> (String, String, i32) {
-    let output = Command::new("cargo")
-        .args(["run", "-p", "pomodoroom-cli", "--"])
-        .args(args)
-        .output()
-        .expect("Failed to execute CLI command");
+    run_cli_with_home(None, args)
+}
+
+/// Run a CLI command against an isolated home directory.
+fn run_cli_in_home(home: &std::path::Path, args: &[&str]) -> (String, String, i32) {
+    run_cli_with_home(Some(home), args)
+}
+
+/// Run a CLI command, with `HOME` set to `home` when given.
+fn run_cli_with_home(home: Option<&std::path::Path>, args: &[&str]) -> (String, String, i32) {
+    let mut command = Command::new("cargo");
+    command.args(["run", "-p", "pomodoroom-cli", "--"]).args(args);
+    if let Some(home) = home {
+        command.env("HOME", home);
+    }
+    let output = command.output().expect("Failed to execute CLI command");
 
     let stdout = String::from_utf8_lossy(&output.stdout).to_string();
     let stderr = String::from_utf8_lossy(&output.stderr).to_string();
@@ -176,6 +187,12 @@ fn test_stats_all() {
     assert_success(&output, "test_stats_all");
 }
 
+#[test]
+fn test_stats_trend() {
+    let output = run_cli(&["stats", "trend", "--weeks", "4"]);
+    assert_success(&output, "test_stats_trend");
+}
+
 #[test]
 fn test_schedule_generate() {
     let output = run_cli(&["schedule", "generate"]);
@@ -252,3 +269,228 @@ fn test_config_reset() {
     let output = run_cli(&["config", "reset"]);
     assert_success(&output, "test_config_reset");
 }
+
+#[test]
+fn test_score_benchmark_json() {
+    let at = "2024-01-10T09:00:00Z";
+    let first = run_cli(&["score", "benchmark", "--format", "json", "--at", at]);
+    assert_success(&first, "test_score_benchmark_json");
+    let parsed: serde_json::Value = serde_json::from_str(&first.0).expect("Failed to parse JSON");
+    assert_eq!(parsed["baseline"], "balanced");
+    assert_eq!(parsed["rankings"].as_array().map(|r| r.len()), Some(4));
+}
+
+#[test]
+fn test_schedule_today_blocks_do_not_overlap() {
+    let now = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .unwrap()
+        .as_micros();
+    let home = std::env::temp_dir().join(format!("pomodoroom-cli-today-{}", now));
+    std::fs::create_dir_all(&home).unwrap();
+
+    // Missing template is reported instead of silently using defaults
+    let missing = run_cli_in_home(&home, &["schedule", "today"]);
+    assert_ne!(missing.2, 0);
+    assert!(missing.1.contains("No daily template"), "{}", missing.1);
+
+    let setup = run_cli_in_home(
+        &home,
+        &[
+            "schedule",
+            "template",
+            "set",
+            "--wake-up",
+            "09:00",
+            "--sleep",
+            "17:00",
+            "--max-lanes",
+            "1",
+        ],
+    );
+    assert_success(&setup, "template set");
+    for title in ["Write report", "Review PR", "Plan sprint"] {
+        let created = run_cli_in_home(&home, &["task", "create", title, "-e", "50"]);
+        assert_success(&created, "task create");
+    }
+
+    let args = [
+        "schedule",
+        "today",
+        "--date",
+        "2026-03-04",
+        "--format",
+        "json",
+    ];
+    let output = run_cli_in_home(&home, &args);
+    assert_success(&output, "schedule today");
+    let blocks: Vec<serde_json::Value> =
+        serde_json::from_str(&output.0).expect("Failed to parse JSON");
+    let focus_titles: Vec<&str> = blocks
+        .iter()
+        .filter(|b| b["block_type"] == "focus")
+        .filter_map(|b| b["task_title"].as_str())
+        .collect();
+    assert_eq!(focus_titles.len(), 3);
+
+    for pair in blocks.windows(2) {
+        let end = pair[0]["end_time"].as_str().unwrap();
+        let next_start = pair[1]["start_time"].as_str().unwrap();
+        assert!(end <= next_start, "{} overlaps {}", end, next_start);
+    }
+
+    // Read-only: nothing was persisted
+    let show = run_cli_in_home(
+        &home,
+        &[
+            "schedule",
+            "show",
+            "--date",
+            "2026-03-04",
+            "--format",
+            "json",
+        ],
+    );
+    assert_success(&show, "schedule show");
+    assert_eq!(show.0.trim(), "[]");
+
+    let _ = std::fs::remove_dir_all(&home);
+}
+
+/// Extract the ID from `task create` output.
+fn created_task_id(stdout: &str) -> String {
+    stdout
+        .lines()
+        .find_map(|line| line.strip_prefix("Task created: "))
+        .expect("task create prints the new ID")
+        .trim()
+        .to_string()
+}
+
+#[test]
+fn test_task_create_recurring_lists_instances_only() {
+    let now = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .unwrap()
+        .as_micros();
+    let home = std::env::temp_dir().join(format!("pomodoroom-cli-recurring-{}", now));
+    std::fs::create_dir_all(&home).unwrap();
+
+    let invalid = run_cli_in_home(
+        &home,
+        &["task", "create", "Standup", "--recurrence", "hourly"],
+    );
+    assert_ne!(invalid.2, 0);
+    assert!(invalid.1.contains("Invalid recurrence"), "{}", invalid.1);
+
+    let created = run_cli_in_home(
+        &home,
+        &["task", "create", "Standup", "--recurrence", "daily"],
+    );
+    assert_success(&created, "task create recurring");
+    let template_id = created_task_id(&created.0);
+
+    let list = run_cli_in_home(&home, &["task", "list", "--json"]);
+    assert_success(&list, "task list");
+    let tasks: Vec<serde_json::Value> =
+        serde_json::from_str(&list.0).expect("Failed to parse JSON");
+    assert_eq!(tasks.len(), 1);
+    assert_eq!(tasks[0]["title"], "Standup");
+    assert_eq!(tasks[0]["recurrence_parent_id"], template_id.as_str());
+
+    let _ = std::fs::remove_dir_all(&home);
+}
+
+#[test]
+fn test_task_tree_shows_parent_with_segments() {
+    let now = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .unwrap()
+        .as_micros();
+    let home = std::env::temp_dir().join(format!("pomodoroom-cli-tree-{}", now));
+    std::fs::create_dir_all(&home).unwrap();
+
+    let parent = run_cli_in_home(&home, &["task", "create", "Write thesis"]);
+    assert_success(&parent, "task create parent");
+    let parent_id = created_task_id(&parent.0);
+
+    let mut segment_ids = Vec::new();
+    for title in ["Outline", "Draft", "Edit"] {
+        let created = run_cli_in_home(
+            &home,
+            &["task", "create", title, "-e", "25", "--parent", &parent_id],
+        );
+        assert_success(&created, "task create segment");
+        segment_ids.push(created_task_id(&created.0));
+    }
+    for action in ["start", "complete"] {
+        let result = run_cli_in_home(&home, &["task", action, &segment_ids[0]]);
+        assert_success(&result, action);
+    }
+
+    let text = run_cli_in_home(&home, &["task", "tree"]);
+    assert_success(&text, "task tree");
+    let lines: Vec<&str> = text.0.lines().collect();
+    assert_eq!(lines.len(), 4, "{}", text.0);
+    assert!(lines[0].starts_with("Write thesis [READY] 0m/75m 1/3 done (33%)"));
+    assert!(lines[1].contains("├─ #1 Outline [DONE]"), "{}", lines[1]);
+    assert!(
+        lines[2].contains("├─ #2 Draft [READY] 0m/25m"),
+        "{}",
+        lines[2]
+    );
+    assert!(lines[3].contains("└─ #3 Edit [READY]"), "{}", lines[3]);
+
+    let json = run_cli_in_home(&home, &["task", "tree", "--format", "json"]);
+    assert_success(&json, "task tree json");
+    let roots: Vec<serde_json::Value> =
+        serde_json::from_str(&json.0).expect("Failed to parse JSON");
+    assert_eq!(roots.len(), 1);
+    assert_eq!(roots[0]["id"], parent_id.as_str());
+    assert_eq!(roots[0]["done_segments"], 1);
+    assert_eq!(roots[0]["total_segments"], 3);
+    let titles: Vec<&str> = roots[0]["segments"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .filter_map(|s| s["title"].as_str())
+        .collect();
+    assert_eq!(titles, ["Outline", "Draft", "Edit"]);
+
+    // Segments outlive a deleted parent under the synthetic root
+    let deleted = run_cli_in_home(&home, &["task", "delete", &parent_id, "--force"]);
+    assert_success(&deleted, "task delete");
+    let orphaned = run_cli_in_home(&home, &["task", "tree"]);
+    assert_success(&orphaned, "task tree orphans");
+    assert!(orphaned.0.starts_with("(missing parent)"), "{}", orphaned.0);
+    assert_eq!(orphaned.0.lines().count(), 4);
+
+    let _ = std::fs::remove_dir_all(&home);
+}
+
+#[test]
+fn test_config_set_never_overwrites_sealed_config() {
+    let now = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .unwrap()
+        .as_micros();
+    let home = std::env::temp_dir().join(format!("pomodoroom-cli-sealed-{}", now));
+    // Sealed value whose key is not in this home's keyring
+    let sealed = "[notifications]\ncustom_sound = \"enc:v1:AAAAAAAAAAAAAAAAAAAAAAAAAAAA\"\n";
+    let paths: Vec<_> = ["pomodoroom", "pomodoroom-dev"]
+        .iter()
+        .map(|dir| home.join(".config").join(dir).join("config.toml"))
+        .collect();
+    for path in &paths {
+        std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+        std::fs::write(path, sealed).unwrap();
+    }
+
+    let output = run_cli_in_home(&home, &["config", "set", "ui.dark_mode", "true"]);
+    assert_ne!(output.2, 0, "{}", output.0);
+    for path in &paths {
+        assert_eq!(std::fs::read_to_string(path).unwrap(), sealed);
+    }
+
+    let _ = std::fs::remove_dir_all(&home);
+}
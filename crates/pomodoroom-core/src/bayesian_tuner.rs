@@ -155,6 +155,11 @@ impl BayesianBreakTuner {
         }
     }
 
+    /// Current tuning configuration.
+    pub fn config(&self) -> &BreakTuningConfig {
+        &self.config
+    }
+
     /// Enable or disable tuning.
     pub fn set_enabled(&mut self, enabled: bool) {
         self.config.enabled = enabled;
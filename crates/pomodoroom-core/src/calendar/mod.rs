@@ -4,9 +4,10 @@ pub mod shard;
 pub mod signed;
 
 pub use shard::{
-    AggregatedView, CalendarShardId, RoutingContext, ShardConfig, ShardPolicy, ShardRouter,
+    AggregatedView, AggregatedViewCache, AggregatedViewCacheStats, CalendarShardId,
+    RoutingContext, ShardConfig, ShardPolicy, ShardRouter,
 };
 pub use signed::{
-    compute_hmac_signature, generate_signing_key, CalendarEventDescription, SCHEMA_VERSION,
-    SignedEventPayload, SignatureError,
+    compute_hmac_signature, generate_signing_key, hmac_sha256_hex, signatures_match,
+    CalendarEventDescription, SignatureError, SignedEventPayload, SCHEMA_VERSION,
 };
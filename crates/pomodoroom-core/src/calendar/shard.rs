@@ -2,6 +2,7 @@
 //!
 //! Supports splitting calendar data by project or stream for scalability.
 
+use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 
 /// Identifier for a calendar shard.
@@ -205,6 +206,9 @@ pub struct AggregatedView {
     pub shards: Vec<String>,
     pub total_events: usize,
     pub latest_event_at: Option<String>,
+    /// When the view was computed from shard data
+    #[serde(default)]
+    pub last_built_at: Option<DateTime<Utc>>,
 }
 
 impl AggregatedView {
@@ -214,6 +218,7 @@ impl AggregatedView {
             shards: Vec::new(),
             total_events: 0,
             latest_event_at: None,
+            last_built_at: None,
         }
     }
 
@@ -233,6 +238,7 @@ impl AggregatedView {
             shards: shard_keys,
             total_events,
             latest_event_at: latest,
+            last_built_at: Some(Utc::now()),
         }
     }
 
@@ -255,6 +261,73 @@ impl AggregatedView {
     }
 }
 
+/// Hit/miss counters for [`AggregatedViewCache`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AggregatedViewCacheStats {
+    pub hits: u64,
+    pub misses: u64,
+    pub invalidations: u64,
+}
+
+impl AggregatedViewCacheStats {
+    /// Share of reads served from the cache (0.0 before any read)
+    pub fn hit_rate(&self) -> f64 {
+        let reads = self.hits + self.misses;
+        if reads == 0 {
+            0.0
+        } else {
+            self.hits as f64 / reads as f64
+        }
+    }
+}
+
+/// Cached aggregated view, rebuilt only after a shard write.
+///
+/// Every mutation of shard data must call `invalidate`; reads in between
+/// return the cached view unchanged.
+#[derive(Debug, Clone, Default)]
+pub struct AggregatedViewCache {
+    view: Option<AggregatedView>,
+    stats: AggregatedViewCacheStats,
+}
+
+impl AggregatedViewCache {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Cached view, or the result of `build` if the cache is empty
+    pub fn get_or_build<E>(
+        &mut self,
+        build: impl FnOnce() -> Result<AggregatedView, E>,
+    ) -> Result<AggregatedView, E> {
+        if let Some(view) = &self.view {
+            self.stats.hits += 1;
+            return Ok(view.clone());
+        }
+        self.stats.misses += 1;
+        let view = build()?;
+        self.view = Some(view.clone());
+        Ok(view)
+    }
+
+    /// Drop the cached view so the next read rebuilds it
+    pub fn invalidate(&mut self) {
+        if self.view.take().is_some() {
+            self.stats.invalidations += 1;
+        }
+    }
+
+    /// When the cached view was built, if one is cached
+    pub fn last_built_at(&self) -> Option<DateTime<Utc>> {
+        self.view.as_ref().and_then(|v| v.last_built_at)
+    }
+
+    pub fn stats(&self) -> AggregatedViewCacheStats {
+        self.stats
+    }
+}
+
 /// Builder for aggregated queries across shards
 pub struct ShardQueryBuilder {
     shard_ids: Vec<CalendarShardId>,
@@ -430,12 +503,14 @@ mod tests {
             shards: vec!["global".to_string()],
             total_events: 100,
             latest_event_at: Some("2026-01-01T00:00:00Z".to_string()),
+            last_built_at: None,
         };
 
         let view2 = AggregatedView {
             shards: vec!["project:p1".to_string()],
             total_events: 50,
             latest_event_at: Some("2026-02-01T00:00:00Z".to_string()),
+            last_built_at: None,
         };
 
         view1.merge(view2);
@@ -79,17 +79,7 @@ impl SignedEventPayload {
 
         let expected_signature = compute_hmac_signature(&payload_to_sign, signing_key);
 
-        // Use constant-time comparison to prevent timing attacks
-        if self.signature.len() != expected_signature.len() {
-            return Ok(false);
-        }
-
-        let mut result = 0u8;
-        for (a, b) in self.signature.bytes().zip(expected_signature.bytes()) {
-            result |= a ^ b;
-        }
-
-        Ok(result == 0)
+        Ok(signatures_match(&self.signature, &expected_signature))
     }
 
     /// Add metadata to the payload
@@ -128,21 +118,39 @@ pub struct PayloadToSign<'a> {
 
 /// Compute HMAC-SHA256 signature
 pub fn compute_hmac_signature(payload: &PayloadToSign, key: &[u8]) -> String {
+    let payload_json = serde_json::to_string(payload).expect("Failed to serialize payload");
+    hmac_sha256_hex(payload_json.as_bytes(), key)
+}
+
+/// Compute a hex-encoded HMAC-SHA256 of arbitrary bytes
+pub fn hmac_sha256_hex(message: &[u8], key: &[u8]) -> String {
     use hmac::{Hmac, Mac};
     use sha2::Sha256;
 
     type HmacSha256 = Hmac<Sha256>;
 
-    let payload_json = serde_json::to_string(payload).expect("Failed to serialize payload");
-
     let mut mac =
         HmacSha256::new_from_slice(key).expect("HMAC can take keys of any size");
-    mac.update(payload_json.as_bytes());
+    mac.update(message);
 
     let result = mac.finalize();
     hex::encode(result.into_bytes())
 }
 
+/// Compare two signatures in constant time to prevent timing attacks
+pub fn signatures_match(actual: &str, expected: &str) -> bool {
+    if actual.len() != expected.len() {
+        return false;
+    }
+
+    let mut result = 0u8;
+    for (a, b) in actual.bytes().zip(expected.bytes()) {
+        result |= a ^ b;
+    }
+
+    result == 0
+}
+
 /// Generate a signing key from a seed
 pub fn generate_signing_key(seed: &str) -> Vec<u8> {
     use sha2::{Digest, Sha256};
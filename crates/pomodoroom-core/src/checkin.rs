@@ -6,6 +6,10 @@
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 
+use crate::stats::BreakAdherenceAnalyzer;
+use crate::storage::Database;
+use crate::task::{Task, TaskState};
+
 /// A completed work segment from the session timeline.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct CompletedSegment {
@@ -59,6 +63,10 @@ pub struct CheckinConfig {
     /// Include next planned tasks
     pub include_next_up: bool,
 
+    /// Include the metrics block; turn off to keep numbers out of posts
+    #[serde(default = "default_include_metrics")]
+    pub include_metrics: bool,
+
     /// Maximum summary length (characters)
     pub max_summary_length: usize,
 
@@ -72,12 +80,31 @@ impl Default for CheckinConfig {
             include_completions: true,
             include_blockers: true,
             include_next_up: true,
+            include_metrics: default_include_metrics(),
             max_summary_length: 1000,
             timezone_offset: 0,
         }
     }
 }
 
+fn default_include_metrics() -> bool {
+    true
+}
+
+/// Numbers for the period covered by a check-in.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CheckinMetrics {
+    /// Focus minutes recorded in the period
+    pub focus_minutes: i64,
+
+    /// Share of focus sessions followed by an on-time break (0.0 to 1.0),
+    /// `None` without focus sessions
+    pub break_adherence_rate: Option<f64>,
+
+    /// Tasks marked Done within the period
+    pub completed_tasks: usize,
+}
+
 /// Generated check-in summary.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct CheckinSummary {
@@ -92,6 +119,10 @@ pub struct CheckinSummary {
 
     /// Preview for manual editing
     pub editable_preview: String,
+
+    /// Metrics included in the summary, if any
+    #[serde(default)]
+    pub metrics: Option<CheckinMetrics>,
 }
 
 /// A link to the underlying source data.
@@ -119,6 +150,9 @@ pub struct CheckinInput {
     /// Next planned task titles
     pub next_up: Vec<String>,
 
+    /// Metrics for the period, see `CheckinGenerator::collect_metrics`
+    pub metrics: Option<CheckinMetrics>,
+
     /// Time range start
     pub range_start: DateTime<Utc>,
 
@@ -126,6 +160,35 @@ pub struct CheckinInput {
     pub range_end: DateTime<Utc>,
 }
 
+impl CheckinInput {
+    /// Fill segment notes from the notes written on their tasks.
+    ///
+    /// Notes dated within the range are joined with "; ". Segments that
+    /// already carry notes are left as they are.
+    pub fn with_task_notes(mut self, tasks: &[Task]) -> Self {
+        let range = self.range_start..=self.range_end;
+        for segment in self
+            .completed_segments
+            .iter_mut()
+            .filter(|s| s.notes.is_none())
+        {
+            let Some(task) = tasks.iter().find(|t| t.id == segment.task_id) else {
+                continue;
+            };
+            let notes: Vec<&str> = task
+                .notes
+                .iter()
+                .filter(|note| range.contains(&note.at))
+                .map(|note| note.text.as_str())
+                .collect();
+            if !notes.is_empty() {
+                segment.notes = Some(notes.join("; "));
+            }
+        }
+        self
+    }
+}
+
 /// Generator for async check-ins.
 pub struct CheckinGenerator {
     config: CheckinConfig,
@@ -144,6 +207,61 @@ impl CheckinGenerator {
         Self { config }
     }
 
+    /// Collect metrics for the period of `input` from recorded sessions.
+    ///
+    /// Returns `None` without reading the database when metrics are
+    /// disabled. Focus minutes and break adherence come from sessions that
+    /// completed within the period; completed tasks are the `tasks` that
+    /// are Done with a `completed_at` inside it.
+    ///
+    /// # Errors
+    /// Returns an error if the session query fails.
+    pub fn collect_metrics(
+        &self,
+        db: &Database,
+        tasks: &[Task],
+        input: &CheckinInput,
+    ) -> Result<Option<CheckinMetrics>, rusqlite::Error> {
+        if !self.config.include_metrics {
+            return Ok(None);
+        }
+
+        let rows: Vec<_> = db
+            .get_break_adherence_data(
+                &input.range_start.format("%Y-%m-%d").to_string(),
+                &input.range_end.format("%Y-%m-%d").to_string(),
+                None,
+            )?
+            .into_iter()
+            .filter(|row| {
+                DateTime::parse_from_rfc3339(&row.completed_at)
+                    .is_ok_and(|at| at >= input.range_start && at <= input.range_end)
+            })
+            .collect();
+
+        let focus_minutes = rows
+            .iter()
+            .filter(|row| row.step_type == "focus")
+            .map(|row| row.duration_min)
+            .sum();
+        let adherence = BreakAdherenceAnalyzer::new().generate_report(&rows).stats;
+        let range = input.range_start..=input.range_end;
+        let completed_tasks = tasks
+            .iter()
+            .filter(|task| {
+                task.state == TaskState::Done
+                    && task.completed_at.is_some_and(|at| range.contains(&at))
+            })
+            .count();
+
+        Ok(Some(CheckinMetrics {
+            focus_minutes,
+            break_adherence_rate: (adherence.total_focus_sessions > 0)
+                .then_some(adherence.adherence_rate),
+            completed_tasks,
+        }))
+    }
+
     /// Generate a check-in summary from the input.
     pub fn generate(&self, input: &CheckinInput) -> CheckinSummary {
         let mut sections: Vec<String> = Vec::new();
@@ -182,6 +300,15 @@ impl CheckinGenerator {
             sections.push(next);
         }
 
+        // Metrics
+        let metrics = input
+            .metrics
+            .clone()
+            .filter(|_| self.config.include_metrics);
+        if let Some(metrics) = &metrics {
+            sections.push(self.format_metrics(metrics));
+        }
+
         let summary_text = sections.join("\n\n");
 
         // Truncate if needed (at char boundary, not byte boundary)
@@ -193,13 +320,14 @@ impl CheckinGenerator {
         };
 
         // Generate editable preview (plain text without markdown)
-        let editable_preview = self.generate_editable_preview(input);
+        let editable_preview = self.generate_editable_preview(input, metrics.as_ref());
 
         CheckinSummary {
             summary_text: truncated,
             source_links,
             generated_at: Utc::now(),
             editable_preview,
+            metrics,
         }
     }
 
@@ -231,7 +359,10 @@ impl CheckinGenerator {
                 } else {
                     format!("{}m", s.duration_minutes)
                 };
-                format!("• {} ({})", s.task_title, duration)
+                match &s.notes {
+                    Some(notes) => format!("• {} ({}) - {}", s.task_title, duration, notes),
+                    None => format!("• {} ({})", s.task_title, duration),
+                }
             })
             .collect();
 
@@ -271,8 +402,24 @@ impl CheckinGenerator {
         format!("⏭️ **Next Up**\n{}", items.join("\n"))
     }
 
+    /// Format the metrics block.
+    fn format_metrics(&self, metrics: &CheckinMetrics) -> String {
+        format!(
+            "📊 **Metrics**\n{}",
+            metric_lines(metrics)
+                .iter()
+                .map(|line| format!("• {}", line))
+                .collect::<Vec<_>>()
+                .join("\n")
+        )
+    }
+
     /// Generate plain text editable preview.
-    fn generate_editable_preview(&self, input: &CheckinInput) -> String {
+    fn generate_editable_preview(
+        &self,
+        input: &CheckinInput,
+        metrics: Option<&CheckinMetrics>,
+    ) -> String {
         let mut lines: Vec<String> = Vec::new();
 
         lines.push("Check-in".to_string());
@@ -282,7 +429,11 @@ impl CheckinGenerator {
         if !input.completed_segments.is_empty() {
             lines.push("Completed:".to_string());
             for seg in &input.completed_segments {
-                lines.push(format!("- {} ({}m)", seg.task_title, seg.duration_minutes));
+                let mut line = format!("- {} ({}m)", seg.task_title, seg.duration_minutes);
+                if let Some(notes) = &seg.notes {
+                    line.push_str(&format!(" - {}", notes));
+                }
+                lines.push(line);
             }
             lines.push(String::new());
         }
@@ -303,6 +454,16 @@ impl CheckinGenerator {
             }
         }
 
+        if let Some(metrics) = metrics {
+            if !input.next_up.is_empty() {
+                lines.push(String::new());
+            }
+            lines.push("Metrics:".to_string());
+            for line in metric_lines(metrics) {
+                lines.push(format!("- {}", line));
+            }
+        }
+
         lines.join("\n")
     }
 }
@@ -313,6 +474,19 @@ impl Default for CheckinGenerator {
     }
 }
 
+/// Metric lines shared by the Markdown summary and the plain preview.
+fn metric_lines(metrics: &CheckinMetrics) -> Vec<String> {
+    let adherence = metrics
+        .break_adherence_rate
+        .map(|rate| format!("{:.0}%", rate * 100.0))
+        .unwrap_or_else(|| "n/a".to_string());
+    vec![
+        format!("Focus: {}m", metrics.focus_minutes),
+        format!("Break adherence: {}", adherence),
+        format!("Tasks completed: {}", metrics.completed_tasks),
+    ]
+}
+
 /// Helper struct for formatted completions.
 struct FormattedCompletions {
     summary: String,
@@ -384,6 +558,44 @@ mod tests {
         assert!(result.summary_text.contains("1.2h"));
     }
 
+    #[test]
+    fn test_task_notes_annotate_completions() {
+        let mut task = Task {
+            id: "1".to_string(),
+            ..Task::new("Write documentation")
+        };
+        task.add_note("Blocked on API key from ops");
+        task.notes.push(crate::task::TaskNote {
+            at: Utc::now() - Duration::days(3),
+            text: "Old note".to_string(),
+        });
+        let input = CheckinInput {
+            completed_segments: vec![
+                make_segment("1", "Write documentation", 45),
+                make_segment("2", "Code review", 30),
+            ],
+            range_start: Utc::now() - Duration::hours(2),
+            range_end: Utc::now() + Duration::minutes(1),
+            ..Default::default()
+        }
+        .with_task_notes(&[task]);
+
+        assert_eq!(
+            input.completed_segments[0].notes.as_deref(),
+            Some("Blocked on API key from ops")
+        );
+        assert!(input.completed_segments[1].notes.is_none());
+
+        let result = CheckinGenerator::new().generate(&input);
+        assert!(result
+            .summary_text
+            .contains("• Write documentation (45m) - Blocked on API key from ops"));
+        assert!(!result.summary_text.contains("Old note"));
+        assert!(result
+            .editable_preview
+            .contains("- Write documentation (45m) - Blocked on API key from ops"));
+    }
+
     #[test]
     fn test_generates_summary_with_blockers() {
         let generator = CheckinGenerator::new();
@@ -510,4 +722,97 @@ mod tests {
         assert!(!result.summary_text.contains("Blockers"));
         assert!(!result.summary_text.contains("Next Up"));
     }
+
+    #[test]
+    fn test_metrics_reflect_sessions_in_period() {
+        use crate::timer::StepType;
+
+        let db = Database::open_memory().unwrap();
+        let end = Utc::now();
+        let start = end - Duration::hours(3);
+        let at = |minutes_before_end: i64| end - Duration::minutes(minutes_before_end);
+        let record = |step_type, minutes: u64, completed_at: DateTime<Utc>| {
+            db.record_session(
+                step_type,
+                "step",
+                minutes,
+                completed_at - Duration::minutes(minutes as i64),
+                completed_at,
+                None,
+                None,
+            )
+            .unwrap();
+        };
+        // Before the period
+        record(StepType::Focus, 50, at(200));
+        // Focus with an on-time break, then focus with none
+        record(StepType::Focus, 25, at(100));
+        record(StepType::Break, 5, at(93));
+        record(StepType::Focus, 25, at(60));
+
+        let mut input = CheckinInput {
+            completed_segments: vec![
+                make_segment("1", "Task 1", 25),
+                make_segment("1", "Task 1", 25),
+                make_segment("2", "Task 2", 10),
+            ],
+            range_start: start,
+            range_end: end,
+            ..Default::default()
+        };
+        // Task 1 was worked on but is still open; Task 3 was finished earlier
+        let done = |id: &str, completed_at: DateTime<Utc>| {
+            let mut task = Task::new(format!("Task {id}"));
+            task.id = id.to_string();
+            task.state = TaskState::Done;
+            task.completed_at = Some(completed_at);
+            task
+        };
+        let tasks = vec![
+            Task::new("Task 1"),
+            done("2", at(30)),
+            done("3", at(300)),
+            done("4", at(10)),
+        ];
+        let generator = CheckinGenerator::new();
+        input.metrics = generator.collect_metrics(&db, &tasks, &input).unwrap();
+
+        let metrics = input.metrics.clone().unwrap();
+        assert_eq!(metrics.focus_minutes, 50);
+        assert_eq!(metrics.break_adherence_rate, Some(0.5));
+        assert_eq!(metrics.completed_tasks, 2);
+
+        let result = generator.generate(&input);
+        assert_eq!(result.metrics, Some(metrics));
+        assert!(result.summary_text.contains("📊 **Metrics**"));
+        assert!(result.summary_text.contains("• Focus: 50m"));
+        assert!(result.summary_text.contains("• Break adherence: 50%"));
+        assert!(result.editable_preview.contains("- Tasks completed: 2"));
+    }
+
+    #[test]
+    fn test_metrics_absent_when_disabled() {
+        let db = Database::open_memory().unwrap();
+        let generator = CheckinGenerator::with_config(CheckinConfig {
+            include_metrics: false,
+            ..Default::default()
+        });
+        let mut input = CheckinInput {
+            completed_segments: vec![make_segment("1", "Task 1", 30)],
+            range_start: Utc::now() - Duration::hours(1),
+            range_end: Utc::now(),
+            ..Default::default()
+        };
+        assert!(generator.collect_metrics(&db, &[], &input).unwrap().is_none());
+
+        // Metrics supplied anyway are left out
+        input.metrics = CheckinGenerator::new()
+            .collect_metrics(&db, &[], &input)
+            .unwrap();
+        assert!(input.metrics.is_some());
+        let result = generator.generate(&input);
+        assert!(result.metrics.is_none());
+        assert!(!result.summary_text.contains("Metrics"));
+        assert!(!result.editable_preview.contains("Metrics"));
+    }
 }
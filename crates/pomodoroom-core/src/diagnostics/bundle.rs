@@ -5,9 +5,13 @@
 //! data is either anonymized or redacted before inclusion.
 
 use chrono::{DateTime, Utc};
+use rusqlite::Connection;
 use serde::{Deserialize, Serialize};
 use sha2::{Digest, Sha256};
 
+use crate::policy::POLICY_VERSION;
+use crate::storage::migrations;
+
 /// Current version of the diagnostics bundle format
 pub const BUNDLE_VERSION: &str = "1.0.0";
 
@@ -28,6 +32,62 @@ pub struct DiagnosticsBundle {
     pub timeline: AnonymizedTimeline,
     /// Scheduling events
     pub events: Vec<SchedulingEvent>,
+    /// Crate, policy and database schema versions
+    #[serde(default)]
+    pub versions: VersionInfo,
+}
+
+/// Versions of the components that produced a bundle
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct VersionInfo {
+    /// Version of the pomodoroom-core crate
+    pub crate_version: String,
+    /// Policy format version
+    pub policy_version: String,
+    /// Database schema version, or "unknown" if it could not be read
+    pub schema_version: String,
+    /// Schema version this build migrates to
+    pub latest_schema_version: i32,
+    /// Migrations applied to the database, as "v<N>: <description>"
+    pub applied_migrations: Vec<String>,
+}
+
+impl Default for VersionInfo {
+    fn default() -> Self {
+        Self::from_connection(None)
+    }
+}
+
+impl VersionInfo {
+    /// Collect version information, reading the schema version from `conn`.
+    ///
+    /// Reports the schema as "unknown" when no connection is available or
+    /// the version cannot be read.
+    pub fn from_connection(conn: Option<&Connection>) -> Self {
+        let schema_version = conn.and_then(|c| migrations::current_version(c).ok());
+        let applied = conn
+            .and_then(|c| migrations::applied_migrations(c).ok())
+            .unwrap_or_default();
+        Self {
+            crate_version: env!("CARGO_PKG_VERSION").to_string(),
+            policy_version: POLICY_VERSION.to_string(),
+            schema_version: schema_version
+                .map(|v| v.to_string())
+                .unwrap_or_else(|| "unknown".to_string()),
+            latest_schema_version: migrations::CURRENT_SCHEMA_VERSION,
+            applied_migrations: applied
+                .into_iter()
+                .map(|m| format!("v{}: {}", m.version, m.description))
+                .collect(),
+        }
+    }
+
+    /// Whether the database schema is known to be older than this build's
+    pub fn is_schema_stale(&self) -> bool {
+        self.schema_version
+            .parse::<i32>()
+            .is_ok_and(|v| v < self.latest_schema_version)
+    }
 }
 
 /// Configuration with sensitive fields redacted
@@ -87,6 +147,8 @@ pub struct DiagnosticsGenerator {
     redact_patterns: Vec<String>,
     /// Salt for anonymization hashing
     anonymization_salt: String,
+    /// Version information attached to generated bundles
+    versions: VersionInfo,
 }
 
 impl Default for DiagnosticsGenerator {
@@ -104,6 +166,7 @@ impl Default for DiagnosticsGenerator {
                 "secret_key".to_string(),
             ],
             anonymization_salt: uuid::Uuid::new_v4().to_string(),
+            versions: VersionInfo::default(),
         }
     }
 }
@@ -122,6 +185,12 @@ impl DiagnosticsGenerator {
         }
     }
 
+    /// Attach schema version information read from the database connection
+    pub fn with_schema_from(mut self, conn: Option<&Connection>) -> Self {
+        self.versions = VersionInfo::from_connection(conn);
+        self
+    }
+
     /// Anonymize a task ID by hashing it
     pub fn anonymize_task(&self, task_id: &str) -> String {
         self.hash_value(&format!("task:{}", task_id))
@@ -187,6 +256,7 @@ impl DiagnosticsGenerator {
             },
             timeline,
             events,
+            versions: self.versions.clone(),
         };
 
         // Compute hash
@@ -290,6 +360,9 @@ impl DiagnosticsGenerator {
         // Hash app version
         hasher.update(bundle.app_version.as_bytes());
 
+        // Hash schema version
+        hasher.update(bundle.versions.schema_version.as_bytes());
+
         // Hash timeline summary (not full sessions to avoid size issues)
         hasher.update(bundle.timeline.total_sessions.to_string().as_bytes());
         hasher.update(bundle.timeline.date_range.0.as_bytes());
@@ -591,6 +664,40 @@ mod tests {
         assert_eq!(end, later_completed.format("%Y-%m-%d").to_string());
     }
 
+    #[test]
+    fn test_bundle_includes_schema_version() {
+        let conn = Connection::open_in_memory().unwrap();
+        let unknown =
+            DiagnosticsGenerator::new().generate(vec![], serde_json::Value::Null, vec![], "0.1.0");
+        assert_eq!(unknown.versions.schema_version, "unknown");
+        assert!(unknown.versions.applied_migrations.is_empty());
+        assert!(!unknown.versions.is_schema_stale());
+        assert_eq!(unknown.versions.policy_version, POLICY_VERSION);
+
+        // Unmigrated database reports version 0
+        let gen = DiagnosticsGenerator::new().with_schema_from(Some(&conn));
+        let bundle = gen.generate(vec![], serde_json::Value::Null, vec![], "0.1.0");
+        assert_eq!(bundle.versions.schema_version, "0");
+        assert!(bundle.versions.is_schema_stale());
+
+        conn.execute_batch(
+            "CREATE TABLE tasks (
+                id TEXT PRIMARY KEY,
+                title TEXT NOT NULL,
+                completed INTEGER NOT NULL DEFAULT 0,
+                created_at TEXT NOT NULL
+            );",
+        )
+        .unwrap();
+        migrations::migrate_to(&conn, 3).unwrap();
+        let gen = DiagnosticsGenerator::new().with_schema_from(Some(&conn));
+        let bundle = gen.generate(vec![], serde_json::Value::Null, vec![], "0.1.0");
+        assert_eq!(bundle.versions.schema_version, "3");
+        assert_eq!(bundle.versions.applied_migrations.len(), 3);
+        assert!(bundle.versions.applied_migrations[2].starts_with("v3: "));
+        assert!(bundle.versions.is_schema_stale());
+    }
+
     #[test]
     fn test_with_custom_redaction_patterns() {
         let gen = DiagnosticsGenerator::with_redaction_patterns(vec![
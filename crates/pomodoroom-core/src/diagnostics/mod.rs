@@ -2,5 +2,5 @@ mod bundle;
 
 pub use bundle::{
     DiagnosticsBundle, RedactedConfig, AnonymizedTimeline,
-    AnonymizedSession, SchedulingEvent, DiagnosticsGenerator,
+    AnonymizedSession, SchedulingEvent, DiagnosticsGenerator, VersionInfo,
 };
@@ -4,9 +4,57 @@
 
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 
-/// Energy level for a specific hour/day combination.
+use crate::stats::InterruptionEvent;
+
+/// Version tag written by [`EnergyCurve::export_json`].
+pub const ENERGY_CURVE_FORMAT_VERSION: u32 = 1;
+
+/// Minimum prominence (energy units) for a peak or dip to be labeled.
+pub const MIN_FEATURE_PROMINENCE: f64 = 0.1;
+
+/// Interruptions per session at which the interruption penalty is at its
+/// full weight.
+pub const INTERRUPTION_DENSITY_SATURATION: f64 = 2.0;
+
+/// Default share of a window's energy removed at full interruption density.
+pub const DEFAULT_INTERRUPTION_WEIGHT: f64 = 0.4;
+
+/// Hours (inclusive) where a trough counts as the post-lunch dip.
+const POST_LUNCH_HOURS: std::ops::RangeInclusive<u8> = 12..=16;
+
+/// Errors when importing a shared energy curve.
+#[derive(Debug, thiserror::Error)]
+pub enum EnergyCurveError {
+    #[error("Invalid energy curve JSON: {0}")]
+    Json(#[from] serde_json::Error),
+
+    #[error("Unsupported energy curve version {0} (expected {ENERGY_CURVE_FORMAT_VERSION})")]
+    UnsupportedVersion(u32),
+
+    #[error("Window out of range: day {day_of_week}, hour {hour}")]
+    WindowOutOfRange { hour: u8, day_of_week: u8 },
+
+    #[error("Duplicate window for day {day_of_week}, hour {hour}")]
+    DuplicateWindow { hour: u8, day_of_week: u8 },
+
+    #[error("Missing window for day {day_of_week}, hour {hour}")]
+    MissingWindow { hour: u8, day_of_week: u8 },
+
+    #[error("Invalid {field} {value} (expected 0.0-1.0)")]
+    InvalidValue { field: &'static str, value: f64 },
+}
+
+/// Versioned envelope used to share an energy curve as JSON.
 #[derive(Debug, Clone, Serialize, Deserialize)]
+struct SharedEnergyCurve {
+    version: u32,
+    curve: EnergyCurve,
+}
+
+/// Energy level for a specific hour/day combination.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct EnergyWindow {
     /// Hour of day (0-23)
     pub hour: u8,
@@ -47,9 +95,49 @@ impl EnergyWindow {
     }
 }
 
+/// Kind of notable feature in an energy curve.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum EnergyFeatureKind {
+    /// Highest energy of the day
+    DailyPeak,
+    /// Trough in the early afternoon
+    PostLunchDip,
+    /// Any other trough
+    Trough,
+}
+
+impl EnergyFeatureKind {
+    /// Human-readable name, e.g. for "you dip around 14:00".
+    pub fn label(&self) -> &'static str {
+        match self {
+            EnergyFeatureKind::DailyPeak => "daily peak",
+            EnergyFeatureKind::PostLunchDip => "post-lunch dip",
+            EnergyFeatureKind::Trough => "energy trough",
+        }
+    }
+}
+
+/// A labeled peak or trough of the daily energy profile.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct EnergyFeature {
+    pub kind: EnergyFeatureKind,
+    /// Hour of the extreme value
+    pub center_hour: u8,
+    /// First hour of the feature
+    pub start_hour: u8,
+    /// Hour after the feature ends (exclusive)
+    pub end_hour: u8,
+    /// Prominence: how far the extreme stands out from its surroundings
+    pub magnitude: f64,
+}
+
 /// Complete energy curve profile for a user.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct EnergyCurve {
+    /// Name used when sharing the curve (e.g. "team-morning")
+    #[serde(default)]
+    pub name: Option<String>,
     /// All energy windows (168 = 24 hours * 7 days)
     pub windows: Vec<EnergyWindow>,
     /// When the curve was last updated
@@ -69,6 +157,7 @@ impl EnergyCurve {
     pub fn new() -> Self {
         let windows = Self::create_default_windows();
         Self {
+            name: None,
             windows,
             last_updated: Utc::now(),
             cold_start_fallback: 0.5,
@@ -127,6 +216,169 @@ impl EnergyCurve {
             .collect()
     }
 
+    /// Notable features of the daily energy profile.
+    ///
+    /// See `features_with_min_prominence`; uses `MIN_FEATURE_PROMINENCE`.
+    pub fn features(&self) -> Vec<EnergyFeature> {
+        self.features_with_min_prominence(MIN_FEATURE_PROMINENCE)
+    }
+
+    /// Detect the daily peak and troughs standing out by `min_prominence`.
+    ///
+    /// Hours are averaged across the week, weighted by sample count, and
+    /// smoothed with their neighbours. Hours without samples are left out,
+    /// so a cold-start or flat curve has no features. A trough must be
+    /// lower than higher ground on both sides; troughs centered between
+    /// 12:00 and 16:00 are labeled as the post-lunch dip. Each feature spans
+    /// the hours within half its prominence of the extreme.
+    pub fn features_with_min_prominence(&self, min_prominence: f64) -> Vec<EnergyFeature> {
+        let profile = self.smoothed_daily_profile();
+        if profile.len() < 3 {
+            return Vec::new();
+        }
+        let values: Vec<f64> = profile.iter().map(|(_, energy)| *energy).collect();
+        let feature = |kind, center: usize, magnitude: f64| {
+            let near = |i: usize| (values[i] - values[center]).abs() <= magnitude / 2.0;
+            let mut start = center;
+            while start > 0 && near(start - 1) {
+                start -= 1;
+            }
+            let mut end = center;
+            while end + 1 < values.len() && near(end + 1) {
+                end += 1;
+            }
+            EnergyFeature {
+                kind,
+                center_hour: profile[center].0,
+                start_hour: profile[start].0,
+                end_hour: profile[end].0 + 1,
+                magnitude,
+            }
+        };
+
+        let mut features = Vec::new();
+        let peak = (0..values.len())
+            .max_by(|&a, &b| values[a].total_cmp(&values[b]))
+            .unwrap_or(0);
+        let lowest = values.iter().copied().fold(f64::INFINITY, f64::min);
+        if values[peak] - lowest >= min_prominence {
+            features.push(feature(
+                EnergyFeatureKind::DailyPeak,
+                peak,
+                values[peak] - lowest,
+            ));
+        }
+
+        for i in 1..values.len() - 1 {
+            let is_minimum = values[i] <= values[i - 1]
+                && values[i] <= values[i + 1]
+                && (values[i] < values[i - 1] || values[i] < values[i + 1]);
+            if !is_minimum {
+                continue;
+            }
+            let left = values[..i].iter().copied().fold(f64::MIN, f64::max);
+            let right = values[i + 1..].iter().copied().fold(f64::MIN, f64::max);
+            let prominence = left.min(right) - values[i];
+            if prominence >= min_prominence {
+                let kind = if POST_LUNCH_HOURS.contains(&profile[i].0) {
+                    EnergyFeatureKind::PostLunchDip
+                } else {
+                    EnergyFeatureKind::Trough
+                };
+                features.push(feature(kind, i, prominence));
+            }
+        }
+        features
+    }
+
+    /// Mean energy per hour across days, smoothed over adjacent hours.
+    ///
+    /// Only hours with samples are included, in hour order.
+    fn smoothed_daily_profile(&self) -> Vec<(u8, f64)> {
+        let mut totals = [(0.0, 0u64); 24];
+        for window in self
+            .windows
+            .iter()
+            .filter(|w| w.sample_count > 0 && w.hour < 24)
+        {
+            let slot = &mut totals[window.hour as usize];
+            slot.0 += window.baseline_energy * window.sample_count as f64;
+            slot.1 += window.sample_count;
+        }
+        let raw: Vec<(u8, f64)> = totals
+            .iter()
+            .enumerate()
+            .filter(|(_, (_, count))| *count > 0)
+            .map(|(hour, (sum, count))| (hour as u8, sum / *count as f64))
+            .collect();
+
+        (0..raw.len())
+            .map(|i| {
+                let neighbours = [i.checked_sub(1), Some(i + 1)];
+                let (sum, weight) = neighbours
+                    .into_iter()
+                    .flatten()
+                    .filter_map(|j| raw.get(j))
+                    .filter(|(hour, _)| hour.abs_diff(raw[i].0) == 1)
+                    .fold((raw[i].1 * 2.0, 2.0), |(sum, weight), (_, energy)| {
+                        (sum + energy, weight + 1.0)
+                    });
+                (raw[i].0, sum / weight)
+            })
+            .collect()
+    }
+
+    /// Export the curve as versioned JSON for sharing.
+    pub fn export_json(&self) -> Result<String, EnergyCurveError> {
+        let shared = SharedEnergyCurve {
+            version: ENERGY_CURVE_FORMAT_VERSION,
+            curve: self.clone(),
+        };
+        Ok(serde_json::to_string_pretty(&shared)?)
+    }
+
+    /// Import a curve exported with [`EnergyCurve::export_json`].
+    ///
+    /// The windows must cover every hour of every day exactly once.
+    pub fn import_json(s: &str) -> Result<Self, EnergyCurveError> {
+        let shared: SharedEnergyCurve = serde_json::from_str(s)?;
+        if shared.version != ENERGY_CURVE_FORMAT_VERSION {
+            return Err(EnergyCurveError::UnsupportedVersion(shared.version));
+        }
+        shared.curve.validate()?;
+        Ok(shared.curve)
+    }
+
+    /// Check that windows form a complete 24h x 7 day grid with valid values.
+    pub fn validate(&self) -> Result<(), EnergyCurveError> {
+        check_unit_range("cold_start_fallback", self.cold_start_fallback)?;
+
+        let mut seen = [[false; 24]; 7];
+        for window in &self.windows {
+            let (hour, day_of_week) = (window.hour, window.day_of_week);
+            if hour >= 24 || day_of_week >= 7 {
+                return Err(EnergyCurveError::WindowOutOfRange { hour, day_of_week });
+            }
+            let slot = &mut seen[day_of_week as usize][hour as usize];
+            if *slot {
+                return Err(EnergyCurveError::DuplicateWindow { hour, day_of_week });
+            }
+            *slot = true;
+            check_unit_range("baseline_energy", window.baseline_energy)?;
+            check_unit_range("confidence", window.confidence)?;
+        }
+
+        for (day_of_week, hours) in seen.iter().enumerate() {
+            if let Some(hour) = hours.iter().position(|covered| !covered) {
+                return Err(EnergyCurveError::MissingWindow {
+                    hour: hour as u8,
+                    day_of_week: day_of_week as u8,
+                });
+            }
+        }
+        Ok(())
+    }
+
     /// Render energy curve as ASCII chart for a specific day.
     pub fn render_ascii_chart(&self, day_of_week: u8) -> String {
         let day_names = ["Sun", "Mon", "Tue", "Wed", "Thu", "Fri", "Sat"];
@@ -161,6 +413,15 @@ impl EnergyCurve {
     }
 }
 
+/// Reject values outside 0.0-1.0 (including NaN).
+fn check_unit_range(field: &'static str, value: f64) -> Result<(), EnergyCurveError> {
+    if (0.0..=1.0).contains(&value) {
+        Ok(())
+    } else {
+        Err(EnergyCurveError::InvalidValue { field, value })
+    }
+}
+
 /// Session data for energy curve computation.
 #[derive(Debug, Clone)]
 pub struct EnergySessionData {
@@ -183,6 +444,9 @@ pub struct EnergyCurveAnalyzer {
     pub min_samples_for_confidence: u64,
     /// Rolling window in days for calculations
     pub rolling_window_days: u64,
+    /// Share of energy removed from a window at full interruption density
+    /// (0.0-1.0); see `adjust_for_interruptions`
+    pub interruption_weight: f64,
 }
 
 impl Default for EnergyCurveAnalyzer {
@@ -197,6 +461,7 @@ impl EnergyCurveAnalyzer {
         Self {
             min_samples_for_confidence: 5,
             rolling_window_days: 30,
+            interruption_weight: DEFAULT_INTERRUPTION_WEIGHT,
         }
     }
 
@@ -205,9 +470,16 @@ impl EnergyCurveAnalyzer {
         Self {
             min_samples_for_confidence: min_samples,
             rolling_window_days: rolling_window,
+            interruption_weight: DEFAULT_INTERRUPTION_WEIGHT,
         }
     }
 
+    /// Set the interruption weight, clamped to 0.0-1.0.
+    pub fn with_interruption_weight(mut self, weight: f64) -> Self {
+        self.interruption_weight = weight.clamp(0.0, 1.0);
+        self
+    }
+
     /// Compute energy curve from session data.
     pub fn compute_curve(&self, sessions: &[EnergySessionData]) -> EnergyCurve {
         let mut curve = EnergyCurve::new();
@@ -266,6 +538,49 @@ impl EnergyCurveAnalyzer {
         0.6 * completion_rate + 0.4 * focus_quality
     }
 
+    /// Lower the energy of windows that are chronically interrupted.
+    ///
+    /// Curves from `compute_curve` and `compute_curve_from_aggregates` are
+    /// purely session-based; call this on the result to also account for
+    /// interruptions, which make an hour feel drained even when its
+    /// sessions complete.
+    ///
+    /// For each window, density is interruptions per session. Energy is
+    /// scaled by `1 - interruption_weight * min(density / 2, 1)`
+    /// ([`INTERRUPTION_DENSITY_SATURATION`]), so with the default weight a
+    /// window with two or more interruptions per session loses 40% of its
+    /// energy. Windows without sessions keep their fallback energy, and a
+    /// weight of 0.0 leaves the curve unchanged.
+    pub fn adjust_for_interruptions(
+        &self,
+        curve: &mut EnergyCurve,
+        interruptions: &[InterruptionEvent],
+    ) {
+        let weight = self.interruption_weight.clamp(0.0, 1.0);
+        if weight == 0.0 {
+            return;
+        }
+
+        let mut counts: HashMap<(u8, u8), u64> = HashMap::new();
+        for event in interruptions {
+            *counts
+                .entry((event.hour(), event.day_of_week()))
+                .or_default() += 1;
+        }
+
+        for window in &mut curve.windows {
+            let Some(&count) = counts.get(&(window.hour, window.day_of_week)) else {
+                continue;
+            };
+            if window.sample_count == 0 {
+                continue;
+            }
+            let density = count as f64 / window.sample_count as f64;
+            let penalty = weight * (density / INTERRUPTION_DENSITY_SATURATION).min(1.0);
+            window.baseline_energy *= 1.0 - penalty;
+        }
+    }
+
     /// Get time-based recommendations.
     pub fn get_recommendations(&self, curve: &EnergyCurve) -> Vec<String> {
         let mut recommendations = Vec::new();
@@ -403,6 +718,130 @@ mod tests {
         assert_eq!(curve.get_energy(9, 1), 0.8);
     }
 
+    #[test]
+    fn test_export_import_round_trip() {
+        let mut curve = EnergyCurve::new();
+        curve.name = Some("team-morning".to_string());
+        if let Some(window) = curve.find_window_mut(9, 1) {
+            window.baseline_energy = 0.85;
+            window.sample_count = 12;
+            window.confidence = 0.9;
+        }
+
+        let json = curve.export_json().unwrap();
+        assert!(json.contains("\"version\": 1"));
+
+        let imported = EnergyCurve::import_json(&json).unwrap();
+        assert_eq!(imported, curve);
+    }
+
+    #[test]
+    fn test_import_rejects_malformed_curves() {
+        let import_with = |edit: fn(&mut EnergyCurve)| {
+            let mut curve = EnergyCurve::new();
+            edit(&mut curve);
+            EnergyCurve::import_json(&curve.export_json().unwrap()).unwrap_err()
+        };
+
+        assert!(matches!(
+            import_with(|c| {
+                c.windows.pop();
+            }),
+            EnergyCurveError::MissingWindow {
+                hour: 23,
+                day_of_week: 6
+            }
+        ));
+        assert!(matches!(
+            import_with(|c| c.windows[1].hour = 0),
+            EnergyCurveError::DuplicateWindow {
+                hour: 0,
+                day_of_week: 0
+            }
+        ));
+        assert!(matches!(
+            import_with(|c| c.windows[0].hour = 24),
+            EnergyCurveError::WindowOutOfRange { hour: 24, .. }
+        ));
+        assert!(matches!(
+            import_with(|c| c.windows[0].baseline_energy = 1.5),
+            EnergyCurveError::InvalidValue {
+                field: "baseline_energy",
+                ..
+            }
+        ));
+
+        let future = EnergyCurve::new()
+            .export_json()
+            .unwrap()
+            .replace("\"version\": 1", "\"version\": 2");
+        assert!(matches!(
+            EnergyCurve::import_json(&future),
+            Err(EnergyCurveError::UnsupportedVersion(2))
+        ));
+        assert!(matches!(
+            EnergyCurve::import_json("{"),
+            Err(EnergyCurveError::Json(_))
+        ));
+    }
+
+    fn curve_with_hours(energies: &[(u8, f64)]) -> EnergyCurve {
+        let mut curve = EnergyCurve::new();
+        for day in 1..=5 {
+            for &(hour, energy) in energies {
+                let window = curve.find_window_mut(hour, day).unwrap();
+                window.baseline_energy = energy;
+                window.sample_count = 4;
+            }
+        }
+        curve
+    }
+
+    #[test]
+    fn test_features_find_post_lunch_dip_and_peak() {
+        let curve = curve_with_hours(&[
+            (8, 0.6),
+            (9, 0.8),
+            (10, 0.9),
+            (11, 0.85),
+            (12, 0.7),
+            (13, 0.45),
+            (14, 0.3),
+            (15, 0.45),
+            (16, 0.7),
+            (17, 0.75),
+            (18, 0.6),
+        ]);
+
+        let features = curve.features();
+        assert_eq!(features.len(), 2, "{features:?}");
+
+        let peak = &features[0];
+        assert_eq!(peak.kind, EnergyFeatureKind::DailyPeak);
+        assert_eq!(peak.center_hour, 10);
+        assert!(peak.start_hour <= 10 && peak.end_hour > 10);
+
+        let dip = &features[1];
+        assert_eq!(dip.kind, EnergyFeatureKind::PostLunchDip);
+        assert_eq!(dip.center_hour, 14);
+        assert!(dip.start_hour >= 12 && dip.end_hour <= 17);
+        assert!(dip.magnitude >= MIN_FEATURE_PROMINENCE);
+    }
+
+    #[test]
+    fn test_flat_or_noisy_curves_have_no_features() {
+        assert!(EnergyCurve::new().features().is_empty());
+
+        let flat: Vec<(u8, f64)> = (8..19).map(|hour| (hour, 0.6)).collect();
+        assert!(curve_with_hours(&flat).features().is_empty());
+
+        // Small wiggles stay below the prominence threshold
+        let noisy: Vec<(u8, f64)> = (8..19)
+            .map(|hour| (hour, if hour % 2 == 0 { 0.62 } else { 0.58 }))
+            .collect();
+        assert!(curve_with_hours(&noisy).features().is_empty());
+    }
+
     #[test]
     fn test_analyzer_compute_curve() {
         let analyzer = EnergyCurveAnalyzer::new();
@@ -443,6 +882,53 @@ mod tests {
         assert_eq!(afternoon.sample_count, 1);
     }
 
+    #[test]
+    fn test_interrupted_hour_infers_lower_energy() {
+        use crate::stats::{InterruptionImpact, InterruptionPriority, InterruptionSource};
+
+        let session = |hour| EnergySessionData {
+            hour,
+            day_of_week: 1,
+            expected_duration: 25,
+            actual_duration: 25,
+            completed: true,
+        };
+        let sessions = vec![session(9), session(9), session(14), session(14)];
+        // 2026-02-16 is a Monday
+        let interruptions: Vec<_> = (0..4)
+            .map(|i| InterruptionEvent {
+                occurred_at: format!("2026-02-16T14:{:02}:00+00:00", i * 10),
+                duration_minutes: 5,
+                source: InterruptionSource::Slack {
+                    priority: InterruptionPriority::Medium,
+                },
+                impact: InterruptionImpact::Moderate,
+            })
+            .collect();
+
+        let analyzer = EnergyCurveAnalyzer::new();
+        let session_only = analyzer.compute_curve(&sessions);
+        assert_eq!(
+            session_only.get_energy(9, 1),
+            session_only.get_energy(14, 1)
+        );
+
+        let mut adjusted = session_only.clone();
+        analyzer.adjust_for_interruptions(&mut adjusted, &interruptions);
+        assert_eq!(adjusted.get_energy(9, 1), session_only.get_energy(9, 1));
+        assert!(adjusted.get_energy(14, 1) < adjusted.get_energy(9, 1));
+        // Two interruptions per session is full density
+        let expected = session_only.get_energy(14, 1) * (1.0 - DEFAULT_INTERRUPTION_WEIGHT);
+        assert!((adjusted.get_energy(14, 1) - expected).abs() < 1e-9);
+
+        // A zero weight keeps the curve session-based
+        let mut unweighted = session_only.clone();
+        EnergyCurveAnalyzer::new()
+            .with_interruption_weight(0.0)
+            .adjust_for_interruptions(&mut unweighted, &interruptions);
+        assert_eq!(unweighted, session_only);
+    }
+
     #[test]
     fn test_analyzer_get_recommendations() {
         let analyzer = EnergyCurveAnalyzer::new();
@@ -5,4 +5,8 @@
 
 mod curve;
 
-pub use curve::{EnergyCurve, EnergyCurveAnalyzer, EnergySessionData, EnergyWindow};
+pub use curve::{
+    EnergyCurve, EnergyCurveAnalyzer, EnergyCurveError, EnergyFeature, EnergyFeatureKind,
+    EnergySessionData, EnergyWindow, DEFAULT_INTERRUPTION_WEIGHT, ENERGY_CURVE_FORMAT_VERSION,
+    INTERRUPTION_DENSITY_SATURATION, MIN_FEATURE_PROMINENCE,
+};
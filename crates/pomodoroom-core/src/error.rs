@@ -6,6 +6,8 @@
 use std::path::PathBuf;
 use thiserror::Error;
 
+use crate::storage::ShortcutViolation;
+
 /// Core error type for pomodoroom-core.
 #[derive(Error, Debug)]
 pub enum CoreError {
@@ -97,6 +99,26 @@ pub enum ConfigError {
     /// Failed to parse configuration
     #[error("Failed to parse configuration: {0}")]
     ParseFailed(String),
+
+    /// Shortcut bindings conflict or use reserved combinations
+    #[error("Conflicting shortcuts: {}", format_violations(.violations))]
+    ShortcutConflict { violations: Vec<ShortcutViolation> },
+
+    /// Key for encrypted config fields is unavailable
+    #[error("Config encryption key unavailable: {0}")]
+    KeyUnavailable(String),
+
+    /// Encrypted config field could not be decrypted
+    #[error("Failed to decrypt configuration value for '{key}': {message}")]
+    DecryptFailed { key: String, message: String },
+}
+
+fn format_violations(violations: &[ShortcutViolation]) -> String {
+    violations
+        .iter()
+        .map(ToString::to_string)
+        .collect::<Vec<_>>()
+        .join("; ")
 }
 
 /// OAuth-specific errors.
@@ -1,7 +1,7 @@
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 
-use crate::timer::{StepType, TimerState};
+use crate::timer::{GatekeeperLevel, NextStepKind, NotificationChannel, StepType, TimerState};
 
 /// Every state change in the system produces an Event.
 /// The GUI polls for events; integrations subscribe to them.
@@ -25,6 +25,12 @@ pub enum Event {
     TimerCompleted {
         step_index: usize,
         step_type: StepType,
+        /// Duration the step was started with.
+        #[serde(default)]
+        planned_ms: u64,
+        /// Time the step actually took, including extensions.
+        #[serde(default)]
+        actual_ms: u64,
         at: DateTime<Utc>,
     },
     /// Timer finished and entered DRIFTING state (user hasn't acted).
@@ -39,6 +45,24 @@ pub enum Event {
         break_debt_ms: u64,
         at: DateTime<Utc>,
     },
+    /// Gatekeeper prompt escalated; `channel` and `dismissible` describe
+    /// the native prompt to raise for the new level.
+    GatekeeperEscalated {
+        prompt_key: String,
+        from_level: GatekeeperLevel,
+        level: GatekeeperLevel,
+        channel: NotificationChannel,
+        dismissible: bool,
+        break_debt_ms: u64,
+        at: DateTime<Utc>,
+    },
+    /// A focus step ended and the gatekeeper should start prompting for a
+    /// break, also when that break was started automatically.
+    GatekeeperPrompt {
+        prompt_key: String,
+        task_id: Option<String>,
+        at: DateTime<Utc>,
+    },
     /// User exited drifting state (break debt accumulated).
     TimerDriftingEnded {
         step_index: usize,
@@ -68,6 +92,15 @@ pub enum Event {
         remaining_ms: u64,
         total_ms: u64,
         schedule_progress_pct: f64,
+        /// Focus steps completed since the last long break.
+        cycle_position: u32,
+        pomodoros_before_long_break: u32,
+        next_step: NextStepKind,
+        at: DateTime<Utc>,
+    },
+    /// Settings were written; `keys` are the dotted config paths that changed.
+    ConfigChanged {
+        keys: Vec<String>,
         at: DateTime<Utc>,
     },
     /// Monthly checkpoint for fast replay - stores the complete system state
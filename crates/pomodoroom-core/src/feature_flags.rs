@@ -3,6 +3,7 @@
 //! This module provides:
 //! - Boolean and parameterized feature flags
 //! - Rules by date, weekday, profile, or manual percentage
+//! - Time-boxed flags that fall back to their default once expired
 //! - Flag state diagnostics for debugging
 
 use chrono::{DateTime, Datelike, Timelike, Utc};
@@ -33,6 +34,9 @@ pub struct FeatureFlag {
     pub created_at: DateTime<Utc>,
     /// When the flag was last modified.
     pub modified_at: DateTime<Utc>,
+    /// After this time rules are ignored and the default value applies.
+    #[serde(default)]
+    pub expires_at: Option<DateTime<Utc>>,
 }
 
 impl FeatureFlag {
@@ -49,6 +53,7 @@ impl FeatureFlag {
             parameter: None,
             created_at: now,
             modified_at: now,
+            expires_at: None,
         }
     }
 
@@ -70,6 +75,7 @@ impl FeatureFlag {
             parameter: Some(parameter.into()),
             created_at: now,
             modified_at: now,
+            expires_at: None,
         }
     }
 
@@ -80,16 +86,32 @@ impl FeatureFlag {
         self
     }
 
+    /// Set when the flag expires.
+    pub fn with_expiry(mut self, expires_at: DateTime<Utc>) -> Self {
+        self.expires_at = Some(expires_at);
+        self.modified_at = Utc::now();
+        self
+    }
+
+    /// Whether the flag has expired at `now`.
+    pub fn is_expired(&self, now: DateTime<Utc>) -> bool {
+        self.expires_at.is_some_and(|expires_at| now >= expires_at)
+    }
+
     /// Check if this flag is active for the given context.
+    ///
+    /// An expired flag ignores its rules and evaluates to the default value.
     pub fn is_active(&self, context: &FlagContext) -> bool {
         if !self.enabled {
             return false;
         }
 
         // Check rules in order - first matching rule wins
-        for rule in &self.rules {
-            if rule.matches(context) {
-                return rule.is_enabled(context);
+        if !self.is_expired(context.now) {
+            for rule in &self.rules {
+                if rule.matches(context) {
+                    return rule.is_enabled(context);
+                }
             }
         }
 
@@ -101,6 +123,24 @@ impl FeatureFlag {
     pub fn get_parameter<T: FromFlagParameter>(&self) -> Option<T> {
         self.parameter.as_ref().and_then(T::from_parameter)
     }
+
+    /// Whether the parameter applies in this context.
+    ///
+    /// Unlike `is_active`, the boolean default value is ignored: the
+    /// parameter applies unless the flag is disabled or the first matching
+    /// rule turns it off. Rules of an expired flag are ignored.
+    pub fn parameter_applies(&self, context: &FlagContext) -> bool {
+        if !self.enabled {
+            return false;
+        }
+        if self.is_expired(context.now) {
+            return true;
+        }
+        self.rules
+            .iter()
+            .find(|rule| rule.matches(context))
+            .is_none_or(|rule| rule.is_enabled(context))
+    }
 }
 
 /// Value of a feature flag.
@@ -138,6 +178,31 @@ pub enum FlagParameter {
 }
 
 impl FlagParameter {
+    /// Look up `key` in a JSON object parameter; an empty key selects the
+    /// parameter itself.
+    pub fn field(&self, key: &str) -> Option<FlagParameter> {
+        if key.is_empty() {
+            return Some(self.clone());
+        }
+        match self {
+            FlagParameter::Json(value) => value.get(key).map(FlagParameter::from_json),
+            _ => None,
+        }
+    }
+
+    /// Convert a JSON value into the closest typed parameter.
+    fn from_json(value: &serde_json::Value) -> FlagParameter {
+        match value {
+            serde_json::Value::String(s) => FlagParameter::String(s.clone()),
+            serde_json::Value::Bool(b) => FlagParameter::Boolean(*b),
+            serde_json::Value::Number(n) => match n.as_i64() {
+                Some(i) => FlagParameter::Integer(i),
+                None => FlagParameter::Float(n.as_f64().unwrap_or_default()),
+            },
+            other => FlagParameter::Json(other.clone()),
+        }
+    }
+
     /// Get as string.
     pub fn as_str(&self) -> Option<&str> {
         match self {
@@ -454,6 +519,8 @@ pub struct FlagManager {
     flags: HashMap<FlagId, FeatureFlag>,
     /// Evaluation cache (flag_id -> context_hash -> result).
     cache: HashMap<String, HashMap<String, bool>>,
+    /// Parameter lookups that fell back to their default.
+    param_fallbacks: Vec<ParamFallback>,
 }
 
 impl FlagManager {
@@ -482,8 +549,13 @@ impl FlagManager {
 
     /// Check if a flag is active.
     pub fn is_active(&mut self, flag_id: &str, context: &FlagContext) -> bool {
-        // Check cache
-        let cache_key = self.cache_key(context);
+        // Check cache; expiry is part of the key so a flag expiring
+        // mid-day is not masked by an earlier result
+        let expired = self
+            .flags
+            .get(flag_id)
+            .is_some_and(|flag| flag.is_expired(context.now));
+        let cache_key = format!("{}:{}", self.cache_key(context), expired);
         if let Some(flag_cache) = self.cache.get(flag_id) {
             if let Some(&result) = flag_cache.get(&cache_key) {
                 return result;
@@ -518,16 +590,96 @@ impl FlagManager {
                     active: flag.is_active(&ctx),
                     has_rules: !flag.rules.is_empty(),
                     parameter: flag.parameter.clone(),
+                    expired: flag.is_expired(ctx.now),
                 }
             })
             .collect()
     }
 
+    /// Get a typed parameter, or `default` when it cannot be resolved.
+    ///
+    /// `key` selects a field of a JSON-object parameter (empty for the whole
+    /// parameter). Falls back when the flag is missing or switched off in
+    /// `context`, the key is absent, or the value has the wrong type; each
+    /// fallback is recorded and reported by `diagnostics`.
+    pub fn param_or<T: FromFlagParameter>(
+        &mut self,
+        flag_id: &str,
+        key: &str,
+        default: T,
+        context: &FlagContext,
+    ) -> T {
+        let resolved = match self.flags.get(flag_id) {
+            None => Err(FallbackReason::FlagNotFound),
+            Some(flag) if !flag.parameter_applies(context) => Err(FallbackReason::FlagInactive),
+            Some(flag) => match flag.parameter.as_ref().and_then(|p| p.field(key)) {
+                None => Err(FallbackReason::Missing),
+                Some(param) => T::from_parameter(&param).ok_or(FallbackReason::WrongType),
+            },
+        };
+
+        match resolved {
+            Ok(value) => value,
+            Err(reason) => {
+                self.record_fallback(flag_id, key, reason, context.now);
+                default
+            }
+        }
+    }
+
+    /// Flags expired at `now`, by ID, for cleanup.
+    pub fn expired_flags(&self, now: DateTime<Utc>) -> Vec<&FeatureFlag> {
+        let mut expired: Vec<_> = self
+            .flags
+            .values()
+            .filter(|flag| flag.is_expired(now))
+            .collect();
+        expired.sort_by(|a, b| a.id.cmp(&b.id));
+        expired
+    }
+
+    /// Parameter lookups that fell back to their default.
+    pub fn param_fallbacks(&self) -> &[ParamFallback] {
+        &self.param_fallbacks
+    }
+
+    fn record_fallback(
+        &mut self,
+        flag_id: &str,
+        key: &str,
+        reason: FallbackReason,
+        at: DateTime<Utc>,
+    ) {
+        match self
+            .param_fallbacks
+            .iter_mut()
+            .find(|f| f.flag_id == flag_id && f.key == key)
+        {
+            Some(existing) => {
+                existing.reason = reason;
+                existing.last_at = at;
+                existing.count += 1;
+            }
+            None => self.param_fallbacks.push(ParamFallback {
+                flag_id: flag_id.to_string(),
+                key: key.to_string(),
+                reason,
+                last_at: at,
+                count: 1,
+            }),
+        }
+    }
+
     /// Generate diagnostics for all flags.
     pub fn diagnostics(&mut self, context: &FlagContext) -> FlagDiagnostics {
         let states = self.get_all_states(context);
         let active_count = states.iter().filter(|s| s.active).count();
         let total_count = states.len();
+        let expired_flags = self
+            .expired_flags(context.now)
+            .into_iter()
+            .map(|flag| flag.id.clone())
+            .collect();
 
         FlagDiagnostics {
             evaluated_at: Utc::now(),
@@ -536,6 +688,8 @@ impl FlagManager {
             total_flags: total_count,
             active_flags: active_count,
             flags: states,
+            param_fallbacks: self.param_fallbacks.clone(),
+            expired_flags,
         }
     }
 
@@ -616,6 +770,9 @@ pub struct FlagState {
     pub has_rules: bool,
     /// Parameter value (if any).
     pub parameter: Option<FlagParameter>,
+    /// Whether the flag has expired and evaluates to its default.
+    #[serde(default)]
+    pub expired: bool,
 }
 
 /// Diagnostics for all feature flags.
@@ -633,6 +790,41 @@ pub struct FlagDiagnostics {
     pub active_flags: usize,
     /// State of all flags.
     pub flags: Vec<FlagState>,
+    /// Parameter lookups that fell back to defaults.
+    #[serde(default)]
+    pub param_fallbacks: Vec<ParamFallback>,
+    /// Flags evaluated as their default because they expired.
+    #[serde(default)]
+    pub expired_flags: Vec<FlagId>,
+}
+
+/// A parameter lookup that returned the caller's default.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct ParamFallback {
+    /// Flag ID.
+    pub flag_id: FlagId,
+    /// Parameter key.
+    pub key: String,
+    /// Why the most recent lookup fell back.
+    pub reason: FallbackReason,
+    /// When the most recent fallback happened.
+    pub last_at: DateTime<Utc>,
+    /// Number of fallbacks for this flag and key.
+    pub count: u32,
+}
+
+/// Why a parameter lookup fell back to its default.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum FallbackReason {
+    /// No flag with this ID is registered.
+    FlagNotFound,
+    /// The flag is disabled or switched off by a rule.
+    FlagInactive,
+    /// The flag has no parameter for the key.
+    Missing,
+    /// The parameter has a different type.
+    WrongType,
 }
 
 #[cfg(test)]
@@ -864,6 +1056,46 @@ mod tests {
         assert!(diag.active_flags >= 1);
     }
 
+    #[test]
+    fn test_expired_flag_evaluates_to_default() {
+        let now = Utc::now();
+        let rule = || RolloutRule::new("Full rollout", RuleCondition::Always, RuleAction::Enable);
+        let mut manager = FlagManager::new();
+        manager.register(
+            FeatureFlag::boolean("stale", "Stale", "Finished experiment", false)
+                .with_rule(rule())
+                .with_expiry(now - chrono::Duration::days(1)),
+        );
+        manager.register(
+            FeatureFlag::boolean("live", "Live", "Running experiment", false)
+                .with_rule(rule())
+                .with_expiry(now + chrono::Duration::days(1)),
+        );
+
+        let mut context = FlagContext::new("stale");
+        context.now = now;
+        assert!(!manager.is_active("stale", &context));
+        assert!(manager.is_active("live", &context));
+
+        let expired: Vec<_> = manager
+            .expired_flags(now)
+            .iter()
+            .map(|f| f.id.clone())
+            .collect();
+        assert_eq!(expired, vec!["stale".to_string()]);
+
+        let diag = manager.diagnostics(&context);
+        assert_eq!(diag.expired_flags, vec!["stale".to_string()]);
+        assert!(diag
+            .flags
+            .iter()
+            .any(|f| f.id == "stale" && f.expired && !f.active));
+
+        // The live flag expires later the same day, despite the cached result
+        context.now = now + chrono::Duration::days(1);
+        assert!(!manager.is_active("live", &context));
+    }
+
     #[test]
     fn test_flag_manager_set_enabled() {
         let mut manager = FlagManager::new();
@@ -895,6 +1127,89 @@ mod tests {
         assert_eq!(flag.get_parameter::<f64>(), Some(3.14));
     }
 
+    #[test]
+    fn test_param_or_present_correct_type() {
+        let mut manager = FlagManager::new();
+        manager.register(FeatureFlag::parameterized(
+            "focus_len",
+            "Focus",
+            "Focus length",
+            50i64,
+        ));
+        manager.register(FeatureFlag::parameterized(
+            "nudge",
+            "Nudge",
+            "Nudge settings",
+            FlagParameter::Json(serde_json::json!({ "delay_secs": 30, "tone": "soft" })),
+        ));
+        let context = FlagContext::new("focus_len");
+
+        assert_eq!(manager.param_or("focus_len", "", 25i64, &context), 50);
+        assert_eq!(manager.param_or("nudge", "delay_secs", 10i64, &context), 30);
+        assert_eq!(
+            manager.param_or("nudge", "tone", "loud".to_string(), &context),
+            "soft"
+        );
+        assert!(manager.param_fallbacks().is_empty());
+    }
+
+    #[test]
+    fn test_param_or_wrong_type_falls_back() {
+        let mut manager = FlagManager::new();
+        manager.register(FeatureFlag::parameterized(
+            "focus_len",
+            "Focus",
+            "Focus length",
+            "long",
+        ));
+        let context = FlagContext::new("focus_len");
+
+        assert_eq!(manager.param_or("focus_len", "", 25i64, &context), 25);
+        assert_eq!(manager.param_or("focus_len", "", 25i64, &context), 25);
+
+        let diag = manager.diagnostics(&context);
+        assert_eq!(diag.param_fallbacks.len(), 1);
+        assert_eq!(diag.param_fallbacks[0].reason, FallbackReason::WrongType);
+        assert_eq!(diag.param_fallbacks[0].count, 2);
+    }
+
+    #[test]
+    fn test_param_or_absent_falls_back() {
+        let mut manager = FlagManager::new();
+        manager.register(FeatureFlag::parameterized(
+            "nudge",
+            "Nudge",
+            "Nudge settings",
+            FlagParameter::Json(serde_json::json!({ "delay_secs": 30 })),
+        ));
+        manager.register(
+            FeatureFlag::parameterized("beta_len", "Beta", "Beta focus length", 45i64).with_rule(
+                RolloutRule::new(
+                    "Not for balanced",
+                    RuleCondition::Profile {
+                        profiles: vec!["balanced".to_string()],
+                    },
+                    RuleAction::Disable,
+                ),
+            ),
+        );
+        let context = FlagContext::new("nudge").with_profile("balanced");
+
+        assert!(!manager.param_or("nudge", "enabled", false, &context));
+        assert_eq!(manager.param_or("missing", "", 1.5, &context), 1.5);
+        assert_eq!(manager.param_or("beta_len", "", 25i64, &context), 25);
+
+        let reasons: Vec<_> = manager.param_fallbacks().iter().map(|f| f.reason).collect();
+        assert_eq!(
+            reasons,
+            vec![
+                FallbackReason::Missing,
+                FallbackReason::FlagNotFound,
+                FallbackReason::FlagInactive
+            ]
+        );
+    }
+
     #[test]
     fn test_flag_json_export_import() {
         let mut manager = FlagManager::new();
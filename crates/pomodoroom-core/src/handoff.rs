@@ -7,8 +7,12 @@
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
+use std::ops::Range;
 use uuid::Uuid;
 
+use crate::storage::SessionRecord;
+use crate::task::{Task, TaskState, POMODORO_MINUTES};
+
 /// Unique identifier for a handoff packet.
 pub type PacketId = String;
 
@@ -70,6 +74,194 @@ pub struct HandoffPacket {
     pub acknowledged_by: Option<String>,
 }
 
+/// Minimum word overlap (Jaccard) for matching steps/blockers without ids.
+const TITLE_SIMILARITY_THRESHOLD: f64 = 0.6;
+
+impl HandoffPacket {
+    /// Compare this packet with a later one for a "since last handoff" view.
+    ///
+    /// Steps and blockers are matched by `id` when both sides have one,
+    /// otherwise by description similarity. A blocker that disappears while
+    /// still open counts as resolved.
+    pub fn diff(&self, other: &HandoffPacket) -> HandoffDiff {
+        let step_matches = match_items(
+            &self.next_steps,
+            &other.next_steps,
+            |s| s.id.as_deref(),
+            |s| &s.description,
+        );
+        let mut diff = HandoffDiff {
+            from_packet: self.id.clone(),
+            to_packet: other.id.clone(),
+            ..HandoffDiff::default()
+        };
+
+        for (after, matched) in other.next_steps.iter().zip(&step_matches) {
+            match matched {
+                Some(i) if self.next_steps[*i] != *after => diff.changed_steps.push(StepChange {
+                    before: self.next_steps[*i].clone(),
+                    after: after.clone(),
+                }),
+                Some(_) => {}
+                None => diff.added_steps.push(after.clone()),
+            }
+        }
+        diff.removed_steps = unmatched(&self.next_steps, &step_matches);
+
+        let blocker_matches = match_items(
+            &self.blockers,
+            &other.blockers,
+            |b| b.id.as_deref(),
+            |b| &b.description,
+        );
+        for (after, matched) in other.blockers.iter().zip(&blocker_matches) {
+            let was_open = matched.is_some_and(|i| !self.blockers[i].resolved);
+            let was_resolved = matched.is_some_and(|i| self.blockers[i].resolved);
+            if after.resolved && was_open {
+                diff.resolved_blockers.push(after.clone());
+            } else if !after.resolved && (matched.is_none() || was_resolved) {
+                diff.opened_blockers.push(after.clone());
+            }
+        }
+        diff.resolved_blockers.extend(
+            unmatched(&self.blockers, &blocker_matches)
+                .into_iter()
+                .filter(|b| !b.resolved),
+        );
+
+        if self.task_state != other.task_state {
+            diff.task_state_change = Some(TaskStateChange {
+                from: self.task_state,
+                to: other.task_state,
+            });
+        }
+
+        diff
+    }
+}
+
+/// Changes between two handoff packets for the same task.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct HandoffDiff {
+    /// Earlier packet
+    pub from_packet: PacketId,
+
+    /// Later packet
+    pub to_packet: PacketId,
+
+    /// Next steps that only appear in the later packet
+    pub added_steps: Vec<NextStep>,
+
+    /// Next steps that no longer appear
+    pub removed_steps: Vec<NextStep>,
+
+    /// Next steps whose description, priority, effort or dependencies changed
+    pub changed_steps: Vec<StepChange>,
+
+    /// Blockers resolved since the earlier packet
+    pub resolved_blockers: Vec<BlockerInfo>,
+
+    /// Blockers that are new or reopened
+    pub opened_blockers: Vec<BlockerInfo>,
+
+    /// Task state change, if any
+    pub task_state_change: Option<TaskStateChange>,
+}
+
+impl HandoffDiff {
+    /// Whether nothing changed between the packets.
+    pub fn is_empty(&self) -> bool {
+        self.added_steps.is_empty()
+            && self.removed_steps.is_empty()
+            && self.changed_steps.is_empty()
+            && self.resolved_blockers.is_empty()
+            && self.opened_blockers.is_empty()
+            && self.task_state_change.is_none()
+    }
+}
+
+/// A next step present in both packets with different content.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StepChange {
+    pub before: NextStep,
+    pub after: NextStep,
+}
+
+/// Transition of the handed-off task's state.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+pub struct TaskStateChange {
+    pub from: HandoffTaskState,
+    pub to: HandoffTaskState,
+}
+
+/// For each item in `after`, the index of its match in `before`.
+///
+/// Ids are matched first; items without an id on either side then match
+/// the most similar unused description above the threshold.
+fn match_items<T>(
+    before: &[T],
+    after: &[T],
+    id: impl Fn(&T) -> Option<&str>,
+    text: impl Fn(&T) -> &str,
+) -> Vec<Option<usize>> {
+    let mut used = vec![false; before.len()];
+    let mut matches: Vec<Option<usize>> = after
+        .iter()
+        .map(|a| {
+            let a_id = id(a)?;
+            let i = before.iter().position(|b| id(b) == Some(a_id))?;
+            (!used[i]).then(|| {
+                used[i] = true;
+                i
+            })
+        })
+        .collect();
+
+    for (a, matched) in after.iter().zip(matches.iter_mut()) {
+        if matched.is_some() || id(a).is_some() {
+            continue;
+        }
+        *matched = before
+            .iter()
+            .enumerate()
+            .filter(|(i, b)| !used[*i] && id(b).is_none())
+            .map(|(i, b)| (i, title_similarity(text(a), text(b))))
+            .filter(|(_, score)| *score >= TITLE_SIMILARITY_THRESHOLD)
+            .max_by(|x, y| x.1.total_cmp(&y.1))
+            .map(|(i, _)| i);
+        if let Some(i) = *matched {
+            used[i] = true;
+        }
+    }
+
+    matches
+}
+
+/// Items of `before` that no item in `after` matched.
+fn unmatched<T: Clone>(before: &[T], matches: &[Option<usize>]) -> Vec<T> {
+    before
+        .iter()
+        .enumerate()
+        .filter(|(i, _)| !matches.contains(&Some(*i)))
+        .map(|(_, item)| item.clone())
+        .collect()
+}
+
+/// Jaccard similarity of the lowercase word sets of two descriptions.
+fn title_similarity(a: &str, b: &str) -> f64 {
+    let words = |s: &str| -> std::collections::HashSet<String> {
+        s.split(|c: char| !c.is_alphanumeric())
+            .filter(|w| !w.is_empty())
+            .map(str::to_lowercase)
+            .collect()
+    };
+    let (a, b) = (words(a), words(b));
+    if a.is_empty() && b.is_empty() {
+        return 1.0;
+    }
+    a.intersection(&b).count() as f64 / a.union(&b).count() as f64
+}
+
 /// State of a task in handoff.
 #[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
 #[serde(rename_all = "snake_case")]
@@ -91,8 +283,12 @@ pub enum HandoffTaskState {
 }
 
 /// Information about a blocker.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub struct BlockerInfo {
+    /// Stable identifier used to match blockers across packets
+    #[serde(default)]
+    pub id: Option<String>,
+
     /// Blocker description
     pub description: String,
 
@@ -136,8 +332,12 @@ pub enum BlockerType {
 }
 
 /// A recommended next step.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub struct NextStep {
+    /// Stable identifier used to match steps across packets
+    #[serde(default)]
+    pub id: Option<String>,
+
     /// Step description
     pub description: String,
 
@@ -162,7 +362,7 @@ pub enum StepPriority {
 }
 
 /// Effort estimate.
-#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
 pub struct EffortEstimate {
     pub minutes: u32,
     pub confidence: f32, // 0.0 to 1.0
@@ -344,6 +544,130 @@ impl HandoffGenerator {
         packet_id
     }
 
+    /// Generate a handoff packet from the sessions recorded in `window`.
+    ///
+    /// The packet is for the task that was running when the window ended,
+    /// or failing that the unfinished task worked on last. Every other task
+    /// with focus sessions in the window becomes an activity entry (a
+    /// milestone if it is now done), as does each note on the handed-off
+    /// task. That task becomes the first next step, with effort inferred
+    /// from its remaining pomodoros.
+    /// The packet can be edited afterwards like any generated packet.
+    pub fn generate_from_sessions(
+        &mut self,
+        from_user: String,
+        sessions: &[SessionRecord],
+        tasks: &[Task],
+        window: Range<DateTime<Utc>>,
+    ) -> Result<PacketId, HandoffError> {
+        let focus: Vec<&SessionRecord> = sessions
+            .iter()
+            .filter(|s| s.step_type == "focus" && window.contains(&s.completed_at))
+            .collect();
+        let task = |id: &str| tasks.iter().find(|t| t.id == id);
+
+        // Focus minutes and last session end per task, in order of last work
+        let mut worked: Vec<(&Task, u64, DateTime<Utc>)> = Vec::new();
+        for session in &focus {
+            let Some(t) = session.task_id.as_deref().and_then(task) else {
+                continue;
+            };
+            match worked.iter_mut().find(|(w, _, _)| w.id == t.id) {
+                Some((_, minutes, last)) => {
+                    *minutes += session.duration_min;
+                    *last = (*last).max(session.completed_at);
+                }
+                None => worked.push((t, session.duration_min, session.completed_at)),
+            }
+        }
+        worked.sort_by_key(|(_, _, last)| *last);
+
+        let current = tasks
+            .iter()
+            .find(|t| t.state == TaskState::Running)
+            .or_else(|| {
+                worked
+                    .iter()
+                    .rev()
+                    .map(|(t, _, _)| *t)
+                    .find(|t| t.state != TaskState::Done)
+            })
+            .ok_or(HandoffError::NothingToHandOff)?;
+
+        let mut recent_activity: Vec<ActivityEntry> = worked
+            .iter()
+            .filter(|(t, _, _)| t.id != current.id)
+            .map(|(t, minutes, last)| {
+                if t.state == TaskState::Done {
+                    ActivityEntry {
+                        timestamp: t.completed_at.unwrap_or(*last),
+                        description: format!("Completed {} ({} min focus)", t.title, minutes),
+                        activity_type: ActivityType::Milestone,
+                    }
+                } else {
+                    ActivityEntry {
+                        timestamp: *last,
+                        description: format!("Worked on {} ({} min focus)", t.title, minutes),
+                        activity_type: ActivityType::Focus,
+                    }
+                }
+            })
+            .collect();
+        recent_activity.extend(current.notes.iter().map(|note| ActivityEntry {
+            timestamp: note.at,
+            description: note.text.clone(),
+            activity_type: ActivityType::Note,
+        }));
+        recent_activity.sort_by_key(|entry| entry.timestamp);
+
+        let context = SessionContext {
+            total_time_minutes: focus.iter().map(|s| s.duration_min as i64).sum(),
+            focus_sessions: focus.len() as u32,
+            recent_activity,
+            decisions: Vec::new(),
+            touched_items: Vec::new(),
+        };
+        let packet_id = self.generate(
+            current.id.clone(),
+            current.title.clone(),
+            from_user,
+            HandoffTaskState::Paused,
+            context,
+        );
+
+        let packet = self
+            .packets
+            .get_mut(&packet_id)
+            .ok_or(HandoffError::PacketNotFound)?;
+        packet.current_focus = Some(current.title.clone());
+        packet.next_steps.insert(
+            0,
+            NextStep {
+                id: Some(current.id.clone()),
+                description: format!("Continue {}", current.title),
+                priority: StepPriority::High,
+                estimated_effort: Some(Self::estimate_remaining_effort(current)),
+                dependencies: Vec::new(),
+            },
+        );
+        Ok(packet_id)
+    }
+
+    /// Effort left on `task` from its remaining pomodoros.
+    ///
+    /// Confidence is higher once some pomodoros are done, since the
+    /// estimate has then been checked against real work.
+    fn estimate_remaining_effort(task: &Task) -> EffortEstimate {
+        let remaining = task
+            .estimated_pomodoros
+            .saturating_sub(task.completed_pomodoros)
+            .max(1);
+        EffortEstimate {
+            minutes: remaining as u32 * POMODORO_MINUTES,
+            confidence: if task.completed_pomodoros > 0 { 0.7 } else { 0.4 },
+        }
+    }
+
     /// Generate progress summary from context.
     fn generate_progress_summary(&self, context: &SessionContext) -> String {
         let hours = context.total_time_minutes / 60;
@@ -381,6 +705,7 @@ impl HandoffGenerator {
         // If there are touched items, suggest continuing work
         if !context.touched_items.is_empty() {
             steps.push(NextStep {
+                id: None,
                 description: format!("Continue work on {}", context.touched_items.first().unwrap()),
                 priority: StepPriority::High,
                 estimated_effort: Some(EffortEstimate {
@@ -623,6 +948,8 @@ pub enum HandoffError {
     PacketNotFound,
     InvalidState,
     AlreadyAcknowledged,
+    /// No unfinished task was running or worked on in the window
+    NothingToHandOff,
 }
 
 #[cfg(test)]
@@ -662,6 +989,85 @@ mod tests {
         assert_eq!(packet.task_state, HandoffTaskState::Paused);
     }
 
+    #[test]
+    fn test_generate_from_sessions() {
+        let start = Utc::now() - chrono::Duration::hours(3);
+        let at = |minutes: i64| start + chrono::Duration::minutes(minutes);
+        let task = |title: &str, state: TaskState| Task {
+            state,
+            estimated_pomodoros: 4,
+            completed_pomodoros: 1,
+            ..Task::new(title)
+        };
+        let mut tasks = vec![
+            task("Write spec", TaskState::Done),
+            task("Review PR", TaskState::Done),
+            task("Fix login bug", TaskState::Running),
+            task("Untouched", TaskState::Ready),
+        ];
+        tasks[2].notes.push(crate::task::TaskNote {
+            at: at(100),
+            text: "Blocked on API key from ops".to_string(),
+        });
+        let session = |task: &Task, step_type: &str, end: i64| SessionRecord {
+            id: end,
+            step_type: step_type.to_string(),
+            step_label: String::new(),
+            duration_min: 25,
+            started_at: at(end - 25),
+            completed_at: at(end),
+            task_id: Some(task.id.clone()),
+            project_id: None,
+        };
+        let sessions = vec![
+            session(&tasks[0], "focus", 25),
+            session(&tasks[0], "break", 30),
+            session(&tasks[1], "focus", 60),
+            session(&tasks[2], "focus", 90),
+            // Outside the window
+            session(&tasks[3], "focus", 200),
+        ];
+
+        let mut generator = HandoffGenerator::new();
+        let packet_id = generator
+            .generate_from_sessions("alice".to_string(), &sessions, &tasks, start..at(120))
+            .unwrap();
+        let packet = generator.get_packet(&packet_id).unwrap();
+
+        assert_eq!(packet.task_id, tasks[2].id);
+        assert_eq!(packet.session_context.focus_sessions, 3);
+        assert_eq!(packet.session_context.total_time_minutes, 75);
+        let activity = &packet.session_context.recent_activity;
+        assert_eq!(activity.len(), 3);
+        assert!(activity[..2]
+            .iter()
+            .all(|a| a.activity_type == ActivityType::Milestone));
+        assert!(activity[0].description.contains("Write spec"));
+        assert_eq!(activity[2].activity_type, ActivityType::Note);
+        assert_eq!(activity[2].description, "Blocked on API key from ops");
+
+        let step = &packet.next_steps[0];
+        assert_eq!(step.id.as_deref(), Some(tasks[2].id.as_str()));
+        assert_eq!(step.estimated_effort.unwrap().minutes, 75);
+
+        // Manual edits still apply to generated packets
+        generator
+            .update_notes(&packet_id, "Repro steps in ticket".to_string())
+            .unwrap();
+        assert!(generator.get_packet(&packet_id).unwrap().notes.is_some());
+
+        // Nothing unfinished to hand off
+        assert!(matches!(
+            generator.generate_from_sessions(
+                "alice".to_string(),
+                &sessions,
+                &tasks[..2],
+                start..at(120)
+            ),
+            Err(HandoffError::NothingToHandOff)
+        ));
+    }
+
     #[test]
     fn test_progress_summary_includes_time() {
         let mut generator = HandoffGenerator::new();
@@ -690,6 +1096,7 @@ mod tests {
         );
 
         let blocker = BlockerInfo {
+            id: None,
             description: "Waiting for API key".to_string(),
             blocker_type: BlockerType::WaitingOnInfo,
             encountered_at: Utc::now(),
@@ -865,4 +1272,92 @@ mod tests {
         let packet = generator.get_packet(&packet_id).unwrap();
         assert_eq!(packet.notes, Some("Some additional context".to_string()));
     }
+
+    fn blocker(description: &str, resolved: bool) -> BlockerInfo {
+        BlockerInfo {
+            id: None,
+            description: description.to_string(),
+            blocker_type: BlockerType::WaitingOnInfo,
+            encountered_at: Utc::now(),
+            resolved,
+            resolution: None,
+        }
+    }
+
+    fn step(id: Option<&str>, description: &str, priority: StepPriority) -> NextStep {
+        NextStep {
+            id: id.map(str::to_string),
+            description: description.to_string(),
+            priority,
+            estimated_effort: None,
+            dependencies: Vec::new(),
+        }
+    }
+
+    fn make_packet(generator: &mut HandoffGenerator, state: HandoffTaskState) -> HandoffPacket {
+        let id = generator.generate(
+            "task-123".to_string(),
+            "Implement authentication".to_string(),
+            "alice".to_string(),
+            state,
+            make_context(),
+        );
+        generator.get_packet(&id).unwrap().clone()
+    }
+
+    #[test]
+    fn test_diff_resolved_blocker_and_new_step() {
+        let mut generator = HandoffGenerator::new();
+
+        let mut evening = make_packet(&mut generator, HandoffTaskState::Blocked);
+        evening.blockers = vec![blocker("Waiting for API key from platform team", false)];
+        evening.next_steps = vec![step(Some("s1"), "Wire up login form", StepPriority::High)];
+
+        let mut morning = make_packet(&mut generator, HandoffTaskState::Paused);
+        // Same blocker, reworded slightly and now resolved
+        morning.blockers = vec![blocker("Waiting for the API key from platform team", true)];
+        morning.next_steps = vec![
+            step(Some("s1"), "Wire up login form", StepPriority::Critical),
+            step(None, "Add token refresh", StepPriority::Medium),
+        ];
+
+        let diff = evening.diff(&morning);
+        assert_eq!(diff.resolved_blockers.len(), 1);
+        assert!(diff.resolved_blockers[0].resolved);
+        assert!(diff.opened_blockers.is_empty());
+
+        assert_eq!(diff.added_steps.len(), 1);
+        assert_eq!(diff.added_steps[0].description, "Add token refresh");
+        assert!(diff.removed_steps.is_empty());
+        assert_eq!(diff.changed_steps.len(), 1);
+        assert_eq!(diff.changed_steps[0].after.priority, StepPriority::Critical);
+
+        assert_eq!(
+            diff.task_state_change,
+            Some(TaskStateChange {
+                from: HandoffTaskState::Blocked,
+                to: HandoffTaskState::Paused,
+            })
+        );
+        assert!(!diff.is_empty());
+    }
+
+    #[test]
+    fn test_diff_unrelated_items_are_added_and_removed() {
+        let mut generator = HandoffGenerator::new();
+        let mut before = make_packet(&mut generator, HandoffTaskState::Paused);
+        before.next_steps = vec![step(None, "Write migration", StepPriority::High)];
+        before.blockers = vec![];
+        let mut after = before.clone();
+        assert!(before.diff(&after).is_empty());
+
+        after.next_steps = vec![step(None, "Review dashboard copy", StepPriority::High)];
+        after.blockers = vec![blocker("Design sign-off", false)];
+
+        let diff = before.diff(&after);
+        assert_eq!(diff.added_steps.len(), 1);
+        assert_eq!(diff.removed_steps.len(), 1);
+        assert_eq!(diff.opened_blockers.len(), 1);
+        assert!(diff.task_state_change.is_none());
+    }
 }
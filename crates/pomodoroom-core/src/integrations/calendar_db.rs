@@ -152,11 +152,102 @@ pub struct CalendarLogStats {
     /// Date range
     pub oldest_event: Option<DateTime<Utc>>,
     pub newest_event: Option<DateTime<Utc>>,
+    /// Total events before pruning (set by prune)
+    pub events_before_prune: Option<usize>,
+    /// Events removed by pruning
+    pub pruned_events: usize,
+}
+
+impl CalendarLogStats {
+    /// Compute statistics for a set of log entries.
+    pub fn from_entries(entries: &[CalendarLogEntry]) -> Self {
+        let mut stats = Self {
+            total_events: entries.len(),
+            ..Self::default()
+        };
+
+        for entry in entries {
+            *stats
+                .events_by_type
+                .entry(entry.payload.event_type.clone())
+                .or_insert(0) += 1;
+
+            if stats.oldest_event.is_none() || Some(entry.created_at) < stats.oldest_event {
+                stats.oldest_event = Some(entry.created_at);
+            }
+
+            if stats.newest_event.is_none() || Some(entry.created_at) > stats.newest_event {
+                stats.newest_event = Some(entry.created_at);
+            }
+        }
+
+        stats
+    }
+}
+
+/// Find the latest checkpoint in a Lamport-ordered log.
+///
+/// Returns the checkpoint's position in `entries` together with the decoded
+/// checkpoint. Checkpoint events whose payload cannot be decoded are skipped.
+pub fn latest_checkpoint(entries: &[CalendarLogEntry]) -> Option<(usize, CalendarCheckpoint)> {
+    entries.iter().enumerate().rev().find_map(|(index, entry)| {
+        if entry.payload.event_type != CalendarEventType::Checkpoint {
+            return None;
+        }
+        serde_json::from_value::<CalendarCheckpoint>(entry.payload.data.clone())
+            .ok()
+            .map(|checkpoint| (index, checkpoint))
+    })
+}
+
+/// Split a log into its latest checkpoint and the entries to replay on top of it.
+///
+/// Without a checkpoint the whole log must be replayed.
+pub fn replay_plan(
+    entries: &[CalendarLogEntry],
+) -> (Option<CalendarCheckpoint>, Vec<&CalendarLogEntry>) {
+    match latest_checkpoint(entries) {
+        Some((index, checkpoint)) => {
+            let tail = entries[index + 1..]
+                .iter()
+                .filter(|entry| entry.payload.lamport_ts > checkpoint.lamport_ts)
+                .collect();
+            (Some(checkpoint), tail)
+        }
+        None => (None, entries.iter().collect()),
+    }
+}
+
+/// Select log entries that can be pruned.
+///
+/// An entry is prunable when it was created before `before` and is already
+/// covered by the latest checkpoint (its Lamport timestamp is not newer than
+/// the checkpoint's). The latest checkpoint itself is always kept, and nothing
+/// is pruned when the log has no checkpoint.
+pub fn prunable_entries(
+    entries: &[CalendarLogEntry],
+    before: DateTime<Utc>,
+) -> Vec<&CalendarLogEntry> {
+    let Some((checkpoint_index, checkpoint)) = latest_checkpoint(entries) else {
+        return Vec::new();
+    };
+
+    entries
+        .iter()
+        .enumerate()
+        .filter(|(index, entry)| {
+            *index != checkpoint_index
+                && entry.created_at < before
+                && entry.payload.lamport_ts <= checkpoint.lamport_ts
+        })
+        .map(|(_, entry)| entry)
+        .collect()
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use chrono::TimeZone;
     use serde_json::json;
 
     #[test]
@@ -197,4 +288,147 @@ mod tests {
         assert_eq!(config.calendar_name, "Pomodoroom Logs");
         assert!(!config.device_id.is_empty());
     }
+
+    /// Log entry created `lamport_ts` days after a fixed origin.
+    fn entry(
+        log_id: &str,
+        event_type: CalendarEventType,
+        entity_id: &str,
+        data: serde_json::Value,
+        lamport_ts: u64,
+    ) -> CalendarLogEntry {
+        CalendarLogEntry {
+            log_id: log_id.to_string(),
+            created_at: day(lamport_ts as i64),
+            payload: CalendarEventPayload::new(event_type, entity_id, data, "device_test")
+                .with_lamport_ts(lamport_ts),
+        }
+    }
+
+    fn day(n: i64) -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap() + chrono::Duration::days(n)
+    }
+
+    fn checkpoint_entry(
+        log_id: &str,
+        lamport_ts: u64,
+        snapshot: serde_json::Value,
+    ) -> CalendarLogEntry {
+        let checkpoint = CalendarCheckpoint {
+            id: format!("checkpoint_{lamport_ts}"),
+            created_at: day(lamport_ts as i64),
+            last_log_id: String::new(),
+            lamport_ts: lamport_ts - 1,
+            state_snapshot: snapshot,
+        };
+        entry(
+            log_id,
+            CalendarEventType::Checkpoint,
+            &checkpoint.id,
+            serde_json::to_value(&checkpoint).unwrap(),
+            lamport_ts,
+        )
+    }
+
+    /// Fold task events into entity -> latest data.
+    fn apply(state: &mut HashMap<String, serde_json::Value>, entry: &CalendarLogEntry) {
+        match entry.payload.event_type {
+            CalendarEventType::TaskDeleted => {
+                state.remove(&entry.payload.entity_id);
+            }
+            CalendarEventType::Checkpoint => {}
+            _ => {
+                state.insert(entry.payload.entity_id.clone(), entry.payload.data.clone());
+            }
+        }
+    }
+
+    #[test]
+    fn test_pruning_keeps_state_reconstructable() {
+        let mut log = vec![
+            entry(
+                "e1",
+                CalendarEventType::TaskCreated,
+                "a",
+                json!({"title": "A"}),
+                1,
+            ),
+            entry(
+                "e2",
+                CalendarEventType::TaskCreated,
+                "b",
+                json!({"title": "B"}),
+                2,
+            ),
+            entry(
+                "e3",
+                CalendarEventType::TaskUpdated,
+                "a",
+                json!({"title": "A2"}),
+                3,
+            ),
+        ];
+        let mut state = HashMap::new();
+        for e in &log {
+            apply(&mut state, e);
+        }
+        log.push(checkpoint_entry(
+            "cp4",
+            4,
+            serde_json::to_value(&state).unwrap(),
+        ));
+        log.push(entry(
+            "e5",
+            CalendarEventType::TaskDeleted,
+            "b",
+            json!({}),
+            5,
+        ));
+        log.push(entry(
+            "e6",
+            CalendarEventType::TaskCreated,
+            "c",
+            json!({"title": "C"}),
+            6,
+        ));
+
+        let mut expected = HashMap::new();
+        for e in &log {
+            apply(&mut expected, e);
+        }
+
+        // Cutoff is past everything; only entries covered by the checkpoint go.
+        let pruned: Vec<&str> = prunable_entries(&log, day(10))
+            .iter()
+            .map(|e| e.log_id.as_str())
+            .collect();
+        assert_eq!(pruned, vec!["e1", "e2", "e3"]);
+
+        let remaining: Vec<CalendarLogEntry> = log
+            .iter()
+            .filter(|e| !pruned.contains(&e.log_id.as_str()))
+            .cloned()
+            .collect();
+        let (restored, tail) = replay_plan(&remaining);
+        let mut rebuilt: HashMap<String, serde_json::Value> =
+            serde_json::from_value(restored.unwrap().state_snapshot).unwrap();
+        for e in tail {
+            apply(&mut rebuilt, e);
+        }
+        assert_eq!(rebuilt, expected);
+    }
+
+    #[test]
+    fn test_prune_respects_cutoff_and_requires_checkpoint() {
+        let mut log = vec![
+            entry("e1", CalendarEventType::TaskCreated, "a", json!({}), 1),
+            entry("e2", CalendarEventType::TaskCreated, "b", json!({}), 2),
+        ];
+        assert!(prunable_entries(&log, day(10)).is_empty());
+
+        log.push(checkpoint_entry("cp3", 3, json!({})));
+        let pruned = prunable_entries(&log, day(2));
+        assert_eq!(pruned.len(), 1);
+        assert_eq!(pruned[0].log_id, "e1");
+    }
 }
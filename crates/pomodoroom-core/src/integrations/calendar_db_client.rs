@@ -5,8 +5,8 @@ use reqwest::Client;
 use serde_json::json;
 
 use super::calendar_db::{
-    CalendarCheckpoint, CalendarDbConfig, CalendarEventPayload, CalendarEventType, CalendarLogEntry,
-    CalendarLogStats,
+    prunable_entries, CalendarCheckpoint, CalendarDbConfig, CalendarEventPayload,
+    CalendarEventType, CalendarLogEntry, CalendarLogStats,
 };
 use super::oauth::{self, OAuthConfig};
 
@@ -232,25 +232,56 @@ impl CalendarDbClient {
     /// Get log statistics.
     pub async fn get_stats(&self) -> Result<CalendarLogStats, Box<dyn std::error::Error>> {
         let entries = self.replay_events(None).await?;
+        Ok(CalendarLogStats::from_entries(&entries))
+    }
 
-        let mut stats = CalendarLogStats::default();
-        stats.total_events = entries.len();
-
-        for entry in &entries {
-            *stats
-                .events_by_type
-                .entry(entry.payload.event_type.clone())
-                .or_insert(0) += 1;
+    /// Delete log entries created before `before` that the latest checkpoint covers.
+    ///
+    /// The latest checkpoint and every entry after it are kept, so state can
+    /// still be rebuilt from the checkpoint plus the remaining log. Returns
+    /// statistics for the pruned log with the pre-prune size.
+    pub async fn prune(
+        &self,
+        before: DateTime<Utc>,
+    ) -> Result<CalendarLogStats, Box<dyn std::error::Error>> {
+        if !self.config.enabled || self.config.calendar_id.is_empty() {
+            return Err("Calendar-DB not configured".into());
+        }
 
-            if stats.oldest_event.is_none() || Some(entry.created_at) < stats.oldest_event {
-                stats.oldest_event = Some(entry.created_at);
-            }
+        let entries = self.replay_events(None).await?;
+        let prunable: Vec<String> = prunable_entries(&entries, before)
+            .into_iter()
+            .map(|entry| entry.log_id.clone())
+            .collect();
 
-            if stats.newest_event.is_none() || Some(entry.created_at) > stats.newest_event {
-                stats.newest_event = Some(entry.created_at);
+        let token = self.access_token().await?;
+        for log_id in &prunable {
+            let url = format!(
+                "https://www.googleapis.com/calendar/v3/calendars/{}/events/{}",
+                self.config.calendar_id, log_id
+            );
+            let resp = self
+                .http_client
+                .delete(&url)
+                .bearer_auth(&token)
+                .send()
+                .await?;
+            // 410 Gone means the entry was already deleted.
+            if !resp.status().is_success() && resp.status() != reqwest::StatusCode::GONE {
+                return Err(
+                    format!("Failed to delete log entry {log_id}: {}", resp.status()).into(),
+                );
             }
         }
 
+        let remaining: Vec<CalendarLogEntry> = entries
+            .iter()
+            .filter(|entry| !prunable.contains(&entry.log_id))
+            .cloned()
+            .collect();
+        let mut stats = CalendarLogStats::from_entries(&remaining);
+        stats.events_before_prune = Some(entries.len());
+        stats.pruned_events = prunable.len();
         Ok(stats)
     }
 
@@ -1,5 +1,10 @@
 //! Discord integration -- post session notifications via webhook.
 
+pub mod presence;
+
+pub use presence::{DiscordIpc, DiscordPresence, PresenceState, PresenceTransport};
+
+use crate::integrations::health::{self, HealthStatus};
 use crate::integrations::keyring_store;
 use crate::integrations::traits::Integration;
 use crate::storage::database::SessionRecord;
@@ -102,6 +107,14 @@ impl Integration for DiscordIntegration {
         Ok(())
     }
 
+    /// Fetching a webhook returns its metadata without posting anything.
+    fn health_check(&self) -> HealthStatus {
+        if !self.is_authenticated() {
+            return HealthStatus::not_configured();
+        }
+        health::probe(Client::new().get(&self.webhook_url))
+    }
+
     fn on_focus_start(
         &self,
         step_label: &str,
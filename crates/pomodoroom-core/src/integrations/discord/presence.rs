@@ -0,0 +1,395 @@
+//! Discord Rich Presence for the running focus session.
+//!
+//! Presence is derived from timer snapshots: a running focus step shows
+//! "Focusing" with a countdown to the end of the session, a running break
+//! shows "On a break", and anything else clears the activity. Updates go to the local Discord client over its IPC
+//! socket. Discord rate limits activity updates, so repeated snapshots are
+//! debounced and only meaningful changes are sent.
+
+use std::io::{Read, Write};
+
+use chrono::{DateTime, Duration, Utc};
+use serde_json::{json, Value};
+
+use crate::events::Event;
+use crate::focus_windows::PrivacyLevel;
+use crate::timer::{StepType, TimerEngine, TimerState};
+
+/// Default minimum time between two activity updates.
+///
+/// Discord accepts about five updates per 20 seconds.
+pub const DEFAULT_MIN_UPDATE_INTERVAL_SECS: i64 = 15;
+
+/// End times closer than this are considered the same countdown.
+const END_TOLERANCE_SECS: i64 = 5;
+
+/// Presence to show on Discord.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PresenceState {
+    /// A focus session is running.
+    Focusing {
+        /// Task title, omitted when privacy hides it
+        task_title: Option<String>,
+        /// When the session is expected to end
+        ends_at: DateTime<Utc>,
+    },
+    /// A break is running.
+    OnBreak {
+        /// When the break is expected to end
+        ends_at: DateTime<Utc>,
+    },
+    /// No session is running; remove the activity.
+    Cleared,
+}
+
+impl PresenceState {
+    /// Presence for a timer `StateSnapshot` event.
+    ///
+    /// Only a running timer shows an activity: focus steps as `Focusing`,
+    /// breaks as `OnBreak`. The task title is included only at
+    /// `PrivacyLevel::Full`. Other events clear the presence.
+    pub fn from_snapshot(snapshot: &Event, privacy: PrivacyLevel) -> Self {
+        match snapshot {
+            Event::StateSnapshot {
+                state: TimerState::Running,
+                step_type,
+                step_label,
+                remaining_ms,
+                at,
+                ..
+            } => {
+                let ends_at = *at + Duration::milliseconds(*remaining_ms as i64);
+                match step_type {
+                    StepType::Focus => PresenceState::Focusing {
+                        task_title: (privacy == PrivacyLevel::Full && !step_label.is_empty())
+                            .then(|| step_label.clone()),
+                        ends_at,
+                    },
+                    StepType::Break => PresenceState::OnBreak { ends_at },
+                }
+            }
+            _ => PresenceState::Cleared,
+        }
+    }
+
+    /// Presence for the current state of `timer`.
+    pub fn from_timer(timer: &TimerEngine, privacy: PrivacyLevel) -> Self {
+        Self::from_snapshot(&timer.snapshot(), privacy)
+    }
+
+    /// Activity object for a `SET_ACTIVITY` command, `null` when cleared.
+    pub fn activity(&self) -> Value {
+        match self {
+            PresenceState::Focusing {
+                task_title,
+                ends_at,
+            } => {
+                let mut activity = json!({
+                    "details": "Focusing",
+                    "timestamps": { "end": ends_at.timestamp() },
+                });
+                if let Some(title) = task_title {
+                    activity["state"] = json!(title);
+                }
+                activity
+            }
+            PresenceState::OnBreak { ends_at } => json!({
+                "details": "On a break",
+                "timestamps": { "end": ends_at.timestamp() },
+            }),
+            PresenceState::Cleared => Value::Null,
+        }
+    }
+
+    /// Whether switching from `self` to `other` is worth an update.
+    fn differs_from(&self, other: &PresenceState) -> bool {
+        match (self, other) {
+            (
+                PresenceState::Focusing {
+                    task_title: a,
+                    ends_at: a_end,
+                },
+                PresenceState::Focusing {
+                    task_title: b,
+                    ends_at: b_end,
+                },
+            ) => a != b || (*a_end - *b_end).num_seconds().abs() >= END_TOLERANCE_SECS,
+            (
+                PresenceState::OnBreak { ends_at: a_end },
+                PresenceState::OnBreak { ends_at: b_end },
+            ) => (*a_end - *b_end).num_seconds().abs() >= END_TOLERANCE_SECS,
+            (PresenceState::Cleared, PresenceState::Cleared) => false,
+            _ => true,
+        }
+    }
+}
+
+/// Destination for Rich Presence commands.
+pub trait PresenceTransport {
+    /// Send one `SET_ACTIVITY` command payload.
+    fn send(&mut self, payload: &Value) -> Result<(), Box<dyn std::error::Error>>;
+}
+
+/// Debounced Rich Presence publisher.
+pub struct DiscordPresence<T: PresenceTransport> {
+    transport: T,
+    min_interval: Duration,
+    last: Option<(PresenceState, DateTime<Utc>)>,
+}
+
+impl<T: PresenceTransport> DiscordPresence<T> {
+    pub fn new(transport: T) -> Self {
+        Self {
+            transport,
+            min_interval: Duration::seconds(DEFAULT_MIN_UPDATE_INTERVAL_SECS),
+            last: None,
+        }
+    }
+
+    /// Set the minimum time between two focus updates.
+    pub fn with_min_interval(mut self, min_interval: Duration) -> Self {
+        self.min_interval = min_interval;
+        self
+    }
+
+    pub fn transport(&self) -> &T {
+        &self.transport
+    }
+
+    /// Publish `state` now.
+    ///
+    /// Returns whether an update was sent; see `set_presence_at`.
+    pub fn set_presence(
+        &mut self,
+        state: PresenceState,
+    ) -> Result<bool, Box<dyn std::error::Error>> {
+        self.set_presence_at(state, Utc::now())
+    }
+
+    /// Publish `state` as of `now`.
+    ///
+    /// Unchanged presence is never resent. Focus updates within the minimum
+    /// interval of the previous update are dropped; the next snapshot after
+    /// the interval sends them. Clearing is always sent immediately so a
+    /// stopped timer never leaves a stale activity behind.
+    pub fn set_presence_at(
+        &mut self,
+        state: PresenceState,
+        now: DateTime<Utc>,
+    ) -> Result<bool, Box<dyn std::error::Error>> {
+        if let Some((last, sent_at)) = &self.last {
+            if !last.differs_from(&state) {
+                return Ok(false);
+            }
+            let throttled = now - *sent_at < self.min_interval;
+            if throttled && state != PresenceState::Cleared {
+                return Ok(false);
+            }
+        } else if state == PresenceState::Cleared {
+            // Nothing was shown yet
+            return Ok(false);
+        }
+
+        let payload = json!({
+            "cmd": "SET_ACTIVITY",
+            "args": { "pid": std::process::id(), "activity": state.activity() },
+            "nonce": uuid::Uuid::new_v4().to_string(),
+        });
+        self.transport.send(&payload)?;
+        self.last = Some((state, now));
+        Ok(true)
+    }
+}
+
+/// Connection to the local Discord client's IPC socket.
+pub struct DiscordIpc {
+    #[cfg(unix)]
+    stream: std::os::unix::net::UnixStream,
+    #[cfg(not(unix))]
+    stream: std::fs::File,
+}
+
+impl DiscordIpc {
+    const OP_HANDSHAKE: u32 = 0;
+    const OP_FRAME: u32 = 1;
+
+    /// Connect and perform the handshake for the Discord application `client_id`.
+    pub fn connect(client_id: &str) -> Result<Self, Box<dyn std::error::Error>> {
+        let mut ipc = Self {
+            stream: Self::open_socket()?,
+        };
+        ipc.write_frame(
+            Self::OP_HANDSHAKE,
+            &json!({ "v": 1, "client_id": client_id }),
+        )?;
+        ipc.read_frame()?;
+        Ok(ipc)
+    }
+
+    #[cfg(unix)]
+    fn open_socket() -> Result<std::os::unix::net::UnixStream, Box<dyn std::error::Error>> {
+        let dir = ["XDG_RUNTIME_DIR", "TMPDIR", "TMP", "TEMP"]
+            .iter()
+            .find_map(|var| std::env::var(var).ok())
+            .unwrap_or_else(|| "/tmp".to_string());
+        for i in 0..10 {
+            let path = std::path::Path::new(&dir).join(format!("discord-ipc-{i}"));
+            if let Ok(stream) = std::os::unix::net::UnixStream::connect(path) {
+                return Ok(stream);
+            }
+        }
+        Err("Discord is not running".into())
+    }
+
+    #[cfg(not(unix))]
+    fn open_socket() -> Result<std::fs::File, Box<dyn std::error::Error>> {
+        for i in 0..10 {
+            let path = format!(r"\\.\pipe\discord-ipc-{i}");
+            if let Ok(file) = std::fs::OpenOptions::new()
+                .read(true)
+                .write(true)
+                .open(path)
+            {
+                return Ok(file);
+            }
+        }
+        Err("Discord is not running".into())
+    }
+
+    /// Frames are an opcode and a length, both little-endian u32, then JSON.
+    fn write_frame(&mut self, op: u32, payload: &Value) -> Result<(), Box<dyn std::error::Error>> {
+        let body = serde_json::to_vec(payload)?;
+        let mut frame = Vec::with_capacity(8 + body.len());
+        frame.extend_from_slice(&op.to_le_bytes());
+        frame.extend_from_slice(&(body.len() as u32).to_le_bytes());
+        frame.extend_from_slice(&body);
+        self.stream.write_all(&frame)?;
+        Ok(())
+    }
+
+    fn read_frame(&mut self) -> Result<Value, Box<dyn std::error::Error>> {
+        let mut header = [0u8; 8];
+        self.stream.read_exact(&mut header)?;
+        let len = u32::from_le_bytes([header[4], header[5], header[6], header[7]]) as usize;
+        let mut body = vec![0u8; len];
+        self.stream.read_exact(&mut body)?;
+        Ok(serde_json::from_slice(&body)?)
+    }
+}
+
+impl PresenceTransport for DiscordIpc {
+    fn send(&mut self, payload: &Value) -> Result<(), Box<dyn std::error::Error>> {
+        self.write_frame(Self::OP_FRAME, payload)?;
+        let reply = self.read_frame()?;
+        if reply["evt"] == "ERROR" {
+            return Err(format!("Discord presence error: {}", reply["data"]["message"]).into());
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Default)]
+    struct Recorder {
+        sent: Vec<Value>,
+    }
+
+    impl PresenceTransport for Recorder {
+        fn send(&mut self, payload: &Value) -> Result<(), Box<dyn std::error::Error>> {
+            self.sent.push(payload.clone());
+            Ok(())
+        }
+    }
+
+    fn running_timer() -> TimerEngine {
+        let mut timer = TimerEngine::new();
+        timer.update_session(
+            Some("task-1".to_string()),
+            Some("Write report".to_string()),
+            25,
+            0,
+        );
+        timer
+    }
+
+    #[test]
+    fn test_running_focus_sets_presence_and_stop_clears_it() {
+        let mut presence = DiscordPresence::new(Recorder::default());
+        let mut timer = running_timer();
+        let now = Utc::now();
+
+        let state = PresenceState::from_timer(&timer, PrivacyLevel::Full);
+        assert!(presence.set_presence_at(state, now).unwrap());
+        let activity = &presence.transport().sent[0]["args"]["activity"];
+        assert_eq!(presence.transport().sent[0]["cmd"], "SET_ACTIVITY");
+        assert_eq!(activity["details"], "Focusing");
+        assert_eq!(activity["state"], "Write report");
+        let end = activity["timestamps"]["end"].as_i64().unwrap();
+        assert!((end - (now + Duration::minutes(25)).timestamp()).abs() <= 2);
+
+        timer.reset();
+        let state = PresenceState::from_timer(&timer, PrivacyLevel::Full);
+        assert_eq!(state, PresenceState::Cleared);
+        assert!(presence.set_presence_at(state, now).unwrap());
+        assert!(presence.transport().sent[1]["args"]["activity"].is_null());
+    }
+
+    #[test]
+    fn test_running_break_shows_on_break() {
+        let at = Utc::now();
+        let snapshot = Event::StateSnapshot {
+            state: TimerState::Running,
+            step_index: 1,
+            step_type: StepType::Break,
+            step_label: "Short Break".to_string(),
+            remaining_ms: 5 * 60_000,
+            total_ms: 5 * 60_000,
+            schedule_progress_pct: 0.0,
+            cycle_position: 1,
+            pomodoros_before_long_break: 4,
+            next_step: crate::timer::NextStepKind::Focus,
+            at,
+        };
+        let state = PresenceState::from_snapshot(&snapshot, PrivacyLevel::Full);
+        assert_eq!(
+            state,
+            PresenceState::OnBreak {
+                ends_at: at + Duration::minutes(5)
+            }
+        );
+        let activity = state.activity();
+        assert_eq!(activity["details"], "On a break");
+        assert!(activity.get("state").is_none());
+    }
+
+    #[test]
+    fn test_privacy_hides_title_and_updates_are_debounced() {
+        let timer = running_timer();
+        let state = PresenceState::from_timer(&timer, PrivacyLevel::Minimal);
+        assert!(state.activity().get("state").is_none());
+
+        let mut presence = DiscordPresence::new(Recorder::default());
+        let now = Utc::now();
+        assert!(presence.set_presence_at(state.clone(), now).unwrap());
+        // Same countdown from the next snapshot is not resent
+        assert!(!presence
+            .set_presence_at(state, now + Duration::seconds(1))
+            .unwrap());
+
+        // A real change inside the interval waits for the next snapshot
+        let extended = PresenceState::Focusing {
+            task_title: None,
+            ends_at: now + Duration::minutes(35),
+        };
+        assert!(!presence
+            .set_presence_at(extended.clone(), now + Duration::seconds(5))
+            .unwrap());
+        assert!(presence
+            .set_presence_at(extended, now + Duration::seconds(20))
+            .unwrap());
+        assert_eq!(presence.transport().sent.len(), 2);
+    }
+}
@@ -1,5 +1,6 @@
 //! GitHub integration -- set user status during focus sessions.
 
+use crate::integrations::health::{self, HealthStatus};
 use crate::integrations::keyring_store;
 use crate::integrations::traits::Integration;
 use crate::storage::database::SessionRecord;
@@ -9,14 +10,19 @@ use serde_json::json;
 
 const USER_AGENT: &str = "pomodoroom";
 
+/// Root of the GitHub REST and GraphQL APIs.
+pub const GITHUB_API: &str = "https://api.github.com";
+
 pub struct GitHubIntegration {
     token: String,
+    api_base: String,
 }
 
 impl Default for GitHubIntegration {
     fn default() -> Self {
         Self {
             token: String::new(),
+            api_base: GITHUB_API.to_string(),
         }
     }
 }
@@ -39,7 +45,19 @@ impl GitHubIntegration {
             .ok()
             .flatten()
             .unwrap_or_default();
-        Self { token }
+        Self {
+            token,
+            ..Self::default()
+        }
+    }
+
+    /// Integration with an explicit token and API root, bypassing the
+    /// keyring (for testing against a mock server).
+    pub fn with_api_base(token: impl Into<String>, api_base: impl Into<String>) -> Self {
+        Self {
+            token: token.into(),
+            api_base: api_base.into(),
+        }
     }
 
     /// Persist user-provided token to the OS keyring and update in-memory state.
@@ -59,10 +77,13 @@ impl GitHubIntegration {
         let client = Client::new();
 
         // Fetch assigned issues
-        let issues_url = "https://api.github.com/issues?filter=assigned&state=open&per_page=50";
+        let issues_url = format!(
+            "{}/issues?filter=assigned&state=open&per_page=50",
+            self.api_base
+        );
         let issues_resp = tokio::runtime::Handle::current().block_on(
             client
-                .get(issues_url)
+                .get(&issues_url)
                 .header("Authorization", format!("Bearer {}", self.token))
                 .header("User-Agent", USER_AGENT)
                 .header("Accept", "application/vnd.github.v3+json")
@@ -149,7 +170,7 @@ impl GitHubIntegration {
 
         let resp = tokio::runtime::Handle::current().block_on(
             client
-                .post("https://api.github.com/graphql")
+                .post(format!("{}/graphql", self.api_base))
                 .header("Authorization", format!("Bearer {}", self.token))
                 .header("User-Agent", USER_AGENT)
                 .json(&body)
@@ -189,7 +210,7 @@ impl Integration for GitHubIntegration {
         let client = Client::new();
         let resp = tokio::runtime::Handle::current().block_on(
             client
-                .get("https://api.github.com/user")
+                .get(format!("{}/user", self.api_base))
                 .header("Authorization", format!("Bearer {}", self.token))
                 .header("User-Agent", USER_AGENT)
                 .send(),
@@ -208,6 +229,18 @@ impl Integration for GitHubIntegration {
         Ok(())
     }
 
+    fn health_check(&self) -> HealthStatus {
+        if !self.is_authenticated() {
+            return HealthStatus::not_configured();
+        }
+        health::probe(
+            Client::new()
+                .get(format!("{}/user", self.api_base))
+                .header("Authorization", format!("Bearer {}", self.token))
+                .header("User-Agent", USER_AGENT),
+        )
+    }
+
     fn on_focus_start(
         &self,
         step_label: &str,
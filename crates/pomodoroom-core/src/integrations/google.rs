@@ -9,6 +9,7 @@ use chrono::{DateTime, Duration, Utc};
 use reqwest::Client;
 use serde_json::json;
 
+use super::health::{self, HealthState, HealthStatus};
 use super::keyring_store;
 use super::oauth::{self, OAuthConfig, OAuthTokens};
 use super::traits::Integration;
@@ -255,6 +256,24 @@ impl Integration for GoogleIntegration {
         Ok(())
     }
 
+    fn health_check(&self) -> HealthStatus {
+        if !self.is_authenticated() {
+            return HealthStatus::not_configured();
+        }
+        let token = match self.access_token() {
+            Ok(token) => token,
+            // A refresh the token endpoint refuses needs a new sign-in
+            Err(e) => {
+                return HealthStatus::new(HealthState::Unauthorized, Some(e.to_string()));
+            }
+        };
+        health::probe(
+            Client::new()
+                .get("https://www.googleapis.com/calendar/v3/users/me/calendarList?maxResults=1")
+                .bearer_auth(token),
+        )
+    }
+
     fn on_focus_start(
         &self,
         step_label: &str,
@@ -0,0 +1,238 @@
+//! Live reachability checks for integrations.
+//!
+//! `Integration::health_check` makes one cheap authenticated request (a
+//! "who am I" call) and classifies the result, so the UI can tell a
+//! revoked token (the user has to sign in again) from a flaky network
+//! (retrying later is enough). `HealthCache` keeps results for a short
+//! while so repeated status polls don't hit the service each time.
+
+use std::collections::HashMap;
+use std::sync::Mutex;
+use std::time::Instant;
+
+use chrono::{DateTime, Duration, Utc};
+use reqwest::{RequestBuilder, StatusCode};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::integrations::traits::Integration;
+
+/// How long a probe may take before the service counts as unreachable.
+pub const PROBE_TIMEOUT: std::time::Duration = std::time::Duration::from_secs(5);
+
+/// Responses slower than this are reported as degraded.
+pub const SLOW_RESPONSE_MS: u64 = 2_000;
+
+/// How long `HealthCache::default` keeps a result, in seconds.
+pub const DEFAULT_HEALTH_TTL_SECS: i64 = 30;
+
+/// Verdict of a health check.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum HealthState {
+    /// The service answered the authenticated call
+    Connected,
+    /// The service answered, but slowly or with a server-side error
+    Degraded,
+    /// Credentials were rejected; the user has to sign in again
+    Unauthorized,
+    /// The service could not be reached (offline, DNS, timeout)
+    Unreachable,
+    /// No credentials are stored
+    NotConfigured,
+}
+
+/// Result of one health check.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct HealthStatus {
+    pub state: HealthState,
+    /// Round-trip time of the probe, if a response arrived
+    pub latency_ms: Option<u64>,
+    /// Short explanation for anything but `Connected`
+    pub message: Option<String>,
+    pub checked_at: DateTime<Utc>,
+}
+
+impl HealthStatus {
+    /// Status without a probe, e.g. when no credentials are stored.
+    pub fn new(state: HealthState, message: Option<String>) -> Self {
+        Self {
+            state,
+            latency_ms: None,
+            message,
+            checked_at: Utc::now(),
+        }
+    }
+
+    /// Status for an integration that has no credentials.
+    pub fn not_configured() -> Self {
+        Self::new(HealthState::NotConfigured, None)
+    }
+
+    /// Classify an HTTP response to an authenticated probe.
+    ///
+    /// 401 and 403 mean the credentials are no longer accepted. Any other
+    /// failure (rate limits, server errors) is degraded, since the
+    /// credentials may still be fine.
+    pub fn from_response(status: StatusCode, latency_ms: u64) -> Self {
+        let (state, message) = match status {
+            StatusCode::UNAUTHORIZED | StatusCode::FORBIDDEN => (
+                HealthState::Unauthorized,
+                Some(format!("credentials rejected (HTTP {})", status.as_u16())),
+            ),
+            s if s.is_success() && latency_ms > SLOW_RESPONSE_MS => (
+                HealthState::Degraded,
+                Some(format!("slow response ({latency_ms} ms)")),
+            ),
+            s if s.is_success() => (HealthState::Connected, None),
+            s => (
+                HealthState::Degraded,
+                Some(format!("service returned HTTP {}", s.as_u16())),
+            ),
+        };
+        Self {
+            state,
+            latency_ms: Some(latency_ms),
+            message,
+            checked_at: Utc::now(),
+        }
+    }
+
+    /// Whether the user has to sign in again.
+    pub fn needs_reauth(&self) -> bool {
+        self.state == HealthState::Unauthorized
+    }
+}
+
+/// Send `request` and classify the response.
+pub fn probe(request: RequestBuilder) -> HealthStatus {
+    probe_with(request, |_| None)
+}
+
+/// Like `probe`, but `inspect` may override the verdict for a successful
+/// response based on its JSON body.
+///
+/// Needed for APIs such as Slack's that report auth failures as HTTP 200.
+pub fn probe_with(
+    request: RequestBuilder,
+    inspect: impl FnOnce(&Value) -> Option<(HealthState, String)>,
+) -> HealthStatus {
+    let started = Instant::now();
+    let result = block_on(async {
+        let resp = request.timeout(PROBE_TIMEOUT).send().await?;
+        let status = resp.status();
+        let body = if status.is_success() {
+            resp.json::<Value>().await.ok()
+        } else {
+            None
+        };
+        Ok::<_, reqwest::Error>((status, body))
+    });
+    let latency_ms = started.elapsed().as_millis() as u64;
+
+    match result {
+        Ok(Ok((status, body))) => {
+            let mut health = HealthStatus::from_response(status, latency_ms);
+            if let Some((state, message)) = body.as_ref().and_then(inspect) {
+                health.state = state;
+                health.message = Some(message);
+            }
+            health
+        }
+        Ok(Err(e)) => HealthStatus::new(HealthState::Unreachable, Some(e.to_string())),
+        Err(e) => HealthStatus::new(HealthState::Unreachable, Some(e)),
+    }
+}
+
+/// Run `future` on the current runtime, or a temporary one outside of it.
+fn block_on<F: std::future::Future>(future: F) -> Result<F::Output, String> {
+    match tokio::runtime::Handle::try_current() {
+        Ok(handle) => Ok(handle.block_on(future)),
+        Err(_) => tokio::runtime::Builder::new_current_thread()
+            .enable_all()
+            .build()
+            .map(|rt| rt.block_on(future))
+            .map_err(|e| format!("failed to start runtime: {e}")),
+    }
+}
+
+/// Recent health results per integration.
+#[derive(Debug)]
+pub struct HealthCache {
+    ttl: Duration,
+    entries: Mutex<HashMap<String, HealthStatus>>,
+}
+
+impl HealthCache {
+    /// Cache keeping results for `ttl`.
+    pub fn new(ttl: Duration) -> Self {
+        Self {
+            ttl,
+            entries: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Health of `integration`, probing only if the cached result is stale.
+    pub fn check(&self, integration: &dyn Integration) -> HealthStatus {
+        let name = integration.name().to_string();
+        if let Some(cached) = self.entries.lock().unwrap().get(&name) {
+            if Utc::now() - cached.checked_at < self.ttl {
+                return cached.clone();
+            }
+        }
+        let status = integration.health_check();
+        self.entries.lock().unwrap().insert(name, status.clone());
+        status
+    }
+
+    /// Drop the cached result for `name`, e.g. after signing in again.
+    pub fn invalidate(&self, name: &str) {
+        self.entries.lock().unwrap().remove(name);
+    }
+}
+
+impl Default for HealthCache {
+    fn default() -> Self {
+        Self::new(Duration::seconds(DEFAULT_HEALTH_TTL_SECS))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::integrations::MockIntegration;
+
+    #[test]
+    fn cache_reuses_fresh_results_until_invalidated() {
+        let mock = MockIntegration::new("mock");
+        let cache = HealthCache::default();
+
+        assert_eq!(cache.check(&mock).state, HealthState::Connected);
+        mock.fail_on("health_check", "connection refused");
+        assert_eq!(cache.check(&mock).state, HealthState::Connected);
+        assert_eq!(mock.call_count("health_check"), 1);
+
+        cache.invalidate("mock");
+        let status = cache.check(&mock);
+        assert_eq!(status.state, HealthState::Unreachable);
+        assert_eq!(status.message.as_deref(), Some("connection refused"));
+
+        let no_cache = HealthCache::new(Duration::zero());
+        no_cache.check(&mock);
+        no_cache.check(&mock);
+        assert_eq!(mock.call_count("health_check"), 4);
+    }
+
+    #[test]
+    fn response_codes_separate_auth_from_transient_failures() {
+        let state = |code: u16, ms| {
+            HealthStatus::from_response(StatusCode::from_u16(code).unwrap(), ms).state
+        };
+        assert_eq!(state(200, 120), HealthState::Connected);
+        assert_eq!(state(200, SLOW_RESPONSE_MS + 1), HealthState::Degraded);
+        assert_eq!(state(401, 80), HealthState::Unauthorized);
+        assert_eq!(state(403, 80), HealthState::Unauthorized);
+        assert_eq!(state(429, 80), HealthState::Degraded);
+        assert_eq!(state(503, 80), HealthState::Degraded);
+    }
+}
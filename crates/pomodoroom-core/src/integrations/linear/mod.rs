@@ -1,5 +1,10 @@
 //! Linear integration -- time tracking via the Linear GraphQL API.
 
+pub mod state_mapping;
+
+pub use state_mapping::{reconcile_issues, LinearStateType, MappedState, StateMapping};
+
+use crate::integrations::health::{self, HealthStatus};
 use crate::integrations::keyring_store;
 use crate::integrations::traits::Integration;
 use crate::storage::database::SessionRecord;
@@ -26,6 +31,8 @@ pub struct LinearIssue {
     pub identifier: String,
     pub title: String,
     pub state: String,
+    /// Workflow state category, when Linear reported one
+    pub state_type: Option<LinearStateType>,
     pub priority: i64,
     pub url: String,
 }
@@ -63,7 +70,7 @@ impl LinearIntegration {
                             id
                             identifier
                             title
-                            state { name color }
+                            state { name color type }
                             priority
                             url
                         }
@@ -119,6 +126,10 @@ impl LinearIntegration {
                 .unwrap_or("Unknown")
                 .to_string();
 
+            let state_type = node["state"]["type"]
+                .as_str()
+                .and_then(LinearStateType::parse);
+
             let priority = node["priority"]
                 .as_u64()
                 .unwrap_or(0) as i64;
@@ -133,6 +144,7 @@ impl LinearIntegration {
                 identifier,
                 title,
                 state: state_name,
+                state_type,
                 priority,
                 url,
             });
@@ -186,6 +198,18 @@ impl Integration for LinearIntegration {
         Ok(())
     }
 
+    fn health_check(&self) -> HealthStatus {
+        if !self.is_authenticated() {
+            return HealthStatus::not_configured();
+        }
+        health::probe(
+            Client::new()
+                .post("https://api.linear.app/graphql")
+                .header("Authorization", &self.api_key)
+                .json(&json!({ "query": "{ viewer { id } }" })),
+        )
+    }
+
     fn on_focus_start(
         &self,
         _step_label: &str,
@@ -0,0 +1,252 @@
+//! Mapping from Linear workflow states to local task states.
+//!
+//! Linear teams name their workflow states freely ("In Progress", "Code
+//! Review", ...) but every state belongs to one of a fixed set of categories.
+//! Issues are mapped by category, with a user-editable table of per-name
+//! overrides checked first. Canceled issues become Done and archived so they
+//! stay out of the active lists. The resolved `MappedState` is applied by the
+//! shared `integrations::mapping` layer like every other service's items.
+
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+
+use super::LinearIssue;
+use crate::integrations::mapping::{merge_mapped, ExternalItem};
+use crate::task::{Task, TaskCategory, TaskState};
+
+/// `source_service` value for tasks imported from Linear.
+pub const LINEAR_SOURCE: &str = "linear";
+
+pub use crate::integrations::mapping::{MappedState, ARCHIVED_TAG};
+
+/// Linear workflow state category (`WorkflowState.type`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum LinearStateType {
+    Triage,
+    Backlog,
+    Unstarted,
+    Started,
+    Completed,
+    Canceled,
+}
+
+impl LinearStateType {
+    /// Parse the category reported by the Linear API.
+    pub fn parse(value: &str) -> Option<Self> {
+        match value.to_ascii_lowercase().as_str() {
+            "triage" => Some(Self::Triage),
+            "backlog" => Some(Self::Backlog),
+            "unstarted" => Some(Self::Unstarted),
+            "started" => Some(Self::Started),
+            "completed" => Some(Self::Completed),
+            "canceled" | "cancelled" => Some(Self::Canceled),
+            _ => None,
+        }
+    }
+
+    /// Guess the category from a default Linear state name.
+    ///
+    /// Used when an issue was fetched without its state category.
+    pub fn from_state_name(name: &str) -> Option<Self> {
+        match name.trim().to_ascii_lowercase().as_str() {
+            "triage" => Some(Self::Triage),
+            "backlog" | "icebox" => Some(Self::Backlog),
+            "todo" | "to do" | "unstarted" => Some(Self::Unstarted),
+            "in progress" | "in review" | "started" => Some(Self::Started),
+            "done" | "completed" => Some(Self::Completed),
+            "canceled" | "cancelled" | "duplicate" => Some(Self::Canceled),
+            _ => None,
+        }
+    }
+}
+
+/// Table translating Linear workflow states to task states.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct StateMapping {
+    /// Mapping per workflow state category
+    pub types: HashMap<LinearStateType, MappedState>,
+    /// Overrides for specific state names, matched case-insensitively
+    #[serde(default)]
+    pub names: HashMap<String, MappedState>,
+}
+
+impl Default for StateMapping {
+    fn default() -> Self {
+        use TaskCategory::{Active, Floating};
+        use TaskState::{Done, Ready, Running};
+
+        let types = HashMap::from([
+            (LinearStateType::Triage, MappedState::new(Ready, Floating)),
+            (LinearStateType::Backlog, MappedState::new(Ready, Floating)),
+            (LinearStateType::Unstarted, MappedState::new(Ready, Active)),
+            (LinearStateType::Started, MappedState::new(Running, Active)),
+            (LinearStateType::Completed, MappedState::new(Done, Active)),
+            (
+                LinearStateType::Canceled,
+                MappedState::new(Done, Active).archived(),
+            ),
+        ]);
+        Self {
+            types,
+            names: HashMap::new(),
+        }
+    }
+}
+
+impl StateMapping {
+    /// Map every state named `name` to `mapped`, ahead of its category.
+    pub fn with_name_override(mut self, name: &str, mapped: MappedState) -> Self {
+        self.names.insert(name.trim().to_ascii_lowercase(), mapped);
+        self
+    }
+
+    /// Replace the mapping for a whole category.
+    pub fn with_type(mut self, state_type: LinearStateType, mapped: MappedState) -> Self {
+        self.types.insert(state_type, mapped);
+        self
+    }
+
+    /// Resolve the local state for an issue.
+    ///
+    /// Name overrides win, then the reported category, then a category
+    /// guessed from the name. Unknown states map to Ready.
+    pub fn resolve(&self, issue: &LinearIssue) -> MappedState {
+        if let Some(mapped) = self.names.get(&issue.state.trim().to_ascii_lowercase()) {
+            return *mapped;
+        }
+        issue
+            .state_type
+            .or_else(|| LinearStateType::from_state_name(&issue.state))
+            .and_then(|state_type| self.types.get(&state_type).copied())
+            .unwrap_or(MappedState::new(TaskState::Ready, TaskCategory::Active))
+    }
+
+    /// Shared `ExternalItem` for `issue`, with the status this mapping resolves.
+    pub fn item(&self, issue: &LinearIssue) -> ExternalItem {
+        ExternalItem::new(
+            LINEAR_SOURCE,
+            issue.id.clone(),
+            format!("{} {}", issue.identifier, issue.title),
+        )
+        .with_status(self.resolve(issue).status())
+    }
+
+    /// Update `task` to reflect `issue`.
+    ///
+    /// Goes through `mapping::merge_mapped`, so Linear tasks are filled like
+    /// every other import; only the resolved state and category differ.
+    pub fn apply(&self, task: &mut Task, issue: &LinearIssue) {
+        merge_mapped(task, &self.item(issue), self.resolve(issue));
+    }
+}
+
+/// Create or update local tasks for `issues`.
+///
+/// Tasks are matched by `source_external_id` among tasks imported from
+/// Linear. Returns the number of tasks created.
+pub fn reconcile_issues(
+    tasks: &mut Vec<Task>,
+    issues: &[LinearIssue],
+    mapping: &StateMapping,
+) -> usize {
+    let mut created = 0;
+    for issue in issues {
+        let item = mapping.item(issue);
+        let mapped = mapping.resolve(issue);
+        match tasks.iter_mut().find(|task| item.matches(task)) {
+            Some(task) => merge_mapped(task, &item, mapped),
+            None => {
+                let mut task = Task::new(item.title.clone());
+                merge_mapped(&mut task, &item, mapped);
+                tasks.push(task);
+                created += 1;
+            }
+        }
+    }
+    created
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::integrations::mapping::ExternalStatus;
+
+    fn issue(id: &str, state: &str, state_type: Option<LinearStateType>) -> LinearIssue {
+        LinearIssue {
+            id: id.to_string(),
+            identifier: "ENG-1".to_string(),
+            title: "Fix login".to_string(),
+            state: state.to_string(),
+            state_type,
+            priority: 2,
+            url: String::new(),
+        }
+    }
+
+    #[test]
+    fn test_custom_state_names_map_by_category_and_override() {
+        let mapping = StateMapping::default();
+        let review = issue("a", "Code Review", Some(LinearStateType::Started));
+        assert_eq!(mapping.resolve(&review).state, TaskState::Running);
+
+        // Without a category an unknown name falls back to Ready
+        let unknown = issue("a", "Code Review", None);
+        assert_eq!(mapping.resolve(&unknown).state, TaskState::Ready);
+
+        let mapping = mapping.with_name_override(
+            "code review",
+            MappedState::new(TaskState::Paused, TaskCategory::Wait),
+        );
+        let mapped = mapping.resolve(&review);
+        assert_eq!(mapped.state, TaskState::Paused);
+        assert_eq!(mapped.category, TaskCategory::Wait);
+    }
+
+    #[test]
+    fn test_reconcile_updates_existing_task_and_archives_canceled() {
+        let mapping = StateMapping::default();
+        let mut tasks = Vec::new();
+
+        let created = reconcile_issues(&mut tasks, &[issue("lin-1", "Todo", None)], &mapping);
+        assert_eq!(created, 1);
+        assert_eq!(tasks[0].state, TaskState::Ready);
+        assert_eq!(tasks[0].source_external_id.as_deref(), Some("lin-1"));
+
+        let canceled = issue("lin-1", "Canceled", Some(LinearStateType::Canceled));
+        let created = reconcile_issues(&mut tasks, &[canceled], &mapping);
+        assert_eq!(created, 0);
+        assert_eq!(tasks.len(), 1);
+        assert_eq!(tasks[0].state, TaskState::Done);
+        assert!(tasks[0].completed);
+        assert!(tasks[0].tags.contains(&ARCHIVED_TAG.to_string()));
+
+        // Reopening the issue clears the archive tag
+        reconcile_issues(&mut tasks, &[issue("lin-1", "Todo", None)], &mapping);
+        assert_eq!(tasks[0].state, TaskState::Ready);
+        assert_eq!(tasks[0].tags, vec![LINEAR_SOURCE]);
+        assert!(tasks[0].completed_at.is_none());
+    }
+
+    #[test]
+    fn test_shared_mapping_honors_overrides_and_categories() {
+        let mapping = StateMapping::default().with_name_override(
+            "blocked",
+            MappedState::new(TaskState::Paused, TaskCategory::Wait),
+        );
+        let mut tasks = Vec::new();
+
+        reconcile_issues(&mut tasks, &[issue("lin-1", "Backlog", None)], &mapping);
+        assert_eq!(tasks[0].category, TaskCategory::Floating);
+
+        reconcile_issues(&mut tasks, &[issue("lin-1", "Blocked", None)], &mapping);
+        assert_eq!(tasks[0].state, TaskState::Paused);
+        assert_eq!(tasks[0].category, TaskCategory::Wait);
+        assert_eq!(mapping.item(&issue("lin-1", "Blocked", None)).status, ExternalStatus::Open);
+        assert_eq!(
+            ExternalItem::from(&issue("lin-1", "Canceled", None)).status,
+            ExternalStatus::Canceled
+        );
+    }
+}
@@ -0,0 +1,437 @@
+//! Common mapping from integration items to local tasks.
+//!
+//! Integrations describe what they fetched as an `ExternalItem` and leave
+//! the translation to `Task` to this module, so every service fills the same
+//! fields the same way. GitHub, Google Tasks and Linear items go through it;
+//! Linear's configurable `StateMapping` only decides the `MappedState` that
+//! `merge_mapped` applies. Imported tasks are keyed by
+//! `(source_service, source_external_id)`, the pair the task database's
+//! source index deduplicates on (see `ScheduleDb::upsert_task_from_source`).
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use super::github::GitHubItem;
+use super::linear::{LinearIssue, StateMapping};
+use crate::task::{Task, TaskCategory, TaskState};
+
+/// Tag added to tasks whose external item was canceled.
+pub const ARCHIVED_TAG: &str = "archived";
+
+/// `source_service` value for tasks imported from GitHub.
+pub const GITHUB_SOURCE: &str = "github";
+
+/// `source_service` value for tasks imported from Google Tasks.
+pub const GOOGLE_TASKS_SOURCE: &str = "google_tasks";
+
+/// Status of an external item, normalized across services.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ExternalStatus {
+    #[default]
+    Open,
+    InProgress,
+    Done,
+    /// Closed without being done; the task is archived
+    Canceled,
+}
+
+impl ExternalStatus {
+    /// Local state for this status, keeping the task's current `category`.
+    pub fn mapped(self, category: TaskCategory) -> MappedState {
+        match self {
+            ExternalStatus::Open => MappedState::new(TaskState::Ready, category),
+            ExternalStatus::InProgress => MappedState::new(TaskState::Running, category),
+            ExternalStatus::Done => MappedState::new(TaskState::Done, category),
+            ExternalStatus::Canceled => MappedState::new(TaskState::Done, category).archived(),
+        }
+    }
+}
+
+/// Local task state an external item maps to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MappedState {
+    pub state: TaskState,
+    pub category: TaskCategory,
+    /// Whether the task is hidden from active lists
+    #[serde(default)]
+    pub archived: bool,
+}
+
+impl MappedState {
+    pub fn new(state: TaskState, category: TaskCategory) -> Self {
+        Self {
+            state,
+            category,
+            archived: false,
+        }
+    }
+
+    /// Mark the mapped task as archived.
+    pub fn archived(mut self) -> Self {
+        self.archived = true;
+        self
+    }
+
+    /// Normalized status for this state.
+    pub fn status(&self) -> ExternalStatus {
+        match self.state {
+            TaskState::Done if self.archived => ExternalStatus::Canceled,
+            TaskState::Done => ExternalStatus::Done,
+            TaskState::Running => ExternalStatus::InProgress,
+            TaskState::Ready | TaskState::Paused => ExternalStatus::Open,
+        }
+    }
+}
+
+/// An item fetched from an integration, before it becomes a task.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ExternalItem {
+    /// Integration name, stored as `source_service`
+    pub service: String,
+    /// ID of the item in the service, stored as `source_external_id`
+    pub external_id: String,
+    pub title: String,
+    /// Deadline, mapped to `window_end_at`
+    #[serde(default)]
+    pub due: Option<DateTime<Utc>>,
+    #[serde(default)]
+    pub status: ExternalStatus,
+    /// Service labels, mapped to tags
+    #[serde(default)]
+    pub labels: Vec<String>,
+    /// Item body, mapped to `description` when the service has one
+    #[serde(default)]
+    pub notes: Option<String>,
+}
+
+impl ExternalItem {
+    pub fn new(
+        service: impl Into<String>,
+        external_id: impl Into<String>,
+        title: impl Into<String>,
+    ) -> Self {
+        Self {
+            service: service.into(),
+            external_id: external_id.into(),
+            title: title.into(),
+            due: None,
+            status: ExternalStatus::Open,
+            labels: Vec::new(),
+            notes: None,
+        }
+    }
+
+    pub fn with_due(mut self, due: DateTime<Utc>) -> Self {
+        self.due = Some(due);
+        self
+    }
+
+    pub fn with_status(mut self, status: ExternalStatus) -> Self {
+        self.status = status;
+        self
+    }
+
+    pub fn with_labels(mut self, labels: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        self.labels = labels.into_iter().map(Into::into).collect();
+        self
+    }
+
+    pub fn with_notes(mut self, notes: impl Into<String>) -> Self {
+        self.notes = Some(notes.into());
+        self
+    }
+
+    /// Whether `task` was imported from this item.
+    pub fn matches(&self, task: &Task) -> bool {
+        task.source_service.as_deref() == Some(self.service.as_str())
+            && task.source_external_id.as_deref() == Some(self.external_id.as_str())
+    }
+
+    /// Tags for this item: the service name, then its labels.
+    ///
+    /// Labels are trimmed and lowercased; empty and repeated ones are dropped.
+    fn tags(&self) -> Vec<String> {
+        let mut tags = vec![self.service.to_ascii_lowercase()];
+        for label in &self.labels {
+            let tag = label.trim().to_lowercase();
+            if !tag.is_empty() && !tags.contains(&tag) {
+                tags.push(tag);
+            }
+        }
+        tags
+    }
+}
+
+impl From<&GitHubItem> for ExternalItem {
+    fn from(item: &GitHubItem) -> Self {
+        let status = match item.state.to_ascii_lowercase().as_str() {
+            "closed" | "merged" => ExternalStatus::Done,
+            _ => ExternalStatus::Open,
+        };
+        ExternalItem::new(
+            GITHUB_SOURCE,
+            format!("{}#{}", item.repository, item.number),
+            item.title.clone(),
+        )
+        .with_status(status)
+        .with_labels([item.repository.clone(), item.item_type.to_string()])
+    }
+}
+
+impl ExternalItem {
+    /// Item for a task from the Google Tasks API, keyed `list_id:task_id`.
+    ///
+    /// The list title becomes a `google_list:` label. Returns `None` for a
+    /// task without an ID.
+    pub fn from_google_task(
+        list_id: &str,
+        list_title: &str,
+        raw: &serde_json::Value,
+    ) -> Option<Self> {
+        let task_id = raw.get("id")?.as_str()?.trim();
+        if task_id.is_empty() {
+            return None;
+        }
+        let title = raw
+            .get("title")
+            .and_then(serde_json::Value::as_str)
+            .unwrap_or("(untitled)")
+            .trim();
+        let status = raw
+            .get("status")
+            .and_then(serde_json::Value::as_str)
+            .unwrap_or("needsAction");
+        let status = if status.eq_ignore_ascii_case("completed") {
+            ExternalStatus::Done
+        } else {
+            ExternalStatus::Open
+        };
+        let mut item =
+            ExternalItem::new(GOOGLE_TASKS_SOURCE, format!("{list_id}:{task_id}"), title)
+                .with_status(status)
+                .with_labels([format!("google_list:{list_title}")]);
+        item.notes = raw
+            .get("notes")
+            .and_then(serde_json::Value::as_str)
+            .map(|s| s.trim().to_string())
+            .filter(|s| !s.is_empty());
+        Some(item)
+    }
+}
+
+impl From<&LinearIssue> for ExternalItem {
+    /// Item with the status from the default `StateMapping`; see
+    /// `StateMapping::item` for a user-configured mapping.
+    fn from(issue: &LinearIssue) -> Self {
+        StateMapping::default().item(issue)
+    }
+}
+
+/// Create a new task for `item`.
+pub fn to_task(item: ExternalItem) -> Task {
+    let mut task = Task::new(item.title.clone());
+    merge_into(&mut task, &item);
+    task
+}
+
+/// Update `existing` from `item`, mapping its status with `ExternalStatus::mapped`.
+///
+/// The task's category is left as it is. An open item leaves a task the
+/// user is running or has paused in that state, since services do not
+/// track local focus.
+pub fn merge_into(existing: &mut Task, item: &ExternalItem) {
+    let mut mapped = item.status.mapped(existing.category);
+    if item.status == ExternalStatus::Open
+        && matches!(existing.state, TaskState::Running | TaskState::Paused)
+    {
+        mapped.state = existing.state;
+    }
+    merge_mapped(existing, item, mapped);
+}
+
+/// Task to store for `item`: `existing` updated with `merge_into`, or a
+/// new task from `to_task` when the item was not imported before.
+pub fn sync_item(existing: Option<&Task>, item: ExternalItem) -> Task {
+    match existing {
+        Some(task) => {
+            let mut task = task.clone();
+            merge_into(&mut task, &item);
+            task
+        }
+        None => to_task(item),
+    }
+}
+
+/// Update `existing` from `item`, setting the state given by `mapped`.
+///
+/// The service is the source of truth for title, notes, deadline and state,
+/// so the state is set directly instead of going through `Task::transition_to`.
+/// Tags the user added locally are kept.
+pub fn merge_mapped(existing: &mut Task, item: &ExternalItem, mapped: MappedState) {
+    let now = Utc::now();
+    let state = mapped.state;
+
+    existing.title = item.title.clone();
+    if let Some(notes) = &item.notes {
+        existing.description = Some(notes.clone());
+    }
+    existing.source_service = Some(item.service.clone());
+    existing.source_external_id = Some(item.external_id.clone());
+    existing.window_end_at = item.due;
+    existing.category = mapped.category;
+    existing.completed = state == TaskState::Done;
+    match state {
+        TaskState::Done => {
+            existing.completed_at.get_or_insert(now);
+        }
+        TaskState::Running => {
+            existing.started_at.get_or_insert(now);
+            existing.completed_at = None;
+        }
+        TaskState::Ready | TaskState::Paused => existing.completed_at = None,
+    }
+    if state != TaskState::Paused {
+        existing.paused_at = None;
+    }
+
+    for tag in item.tags() {
+        if !existing.tags.contains(&tag) {
+            existing.tags.push(tag);
+        }
+    }
+    existing.tags.retain(|tag| tag != ARCHIVED_TAG);
+    if mapped.archived {
+        existing.tags.push(ARCHIVED_TAG.to_string());
+    }
+
+    existing.state = state;
+    existing.updated_at = now;
+}
+
+/// Create or update local tasks for `items`.
+///
+/// Tasks are matched on `(source_service, source_external_id)`. Returns the
+/// number of tasks created.
+pub fn reconcile_items(tasks: &mut Vec<Task>, items: Vec<ExternalItem>) -> usize {
+    let mut created = 0;
+    for item in items {
+        match tasks.iter_mut().find(|task| item.matches(task)) {
+            Some(task) => merge_into(task, &item),
+            None => {
+                tasks.push(to_task(item));
+                created += 1;
+            }
+        }
+    }
+    created
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Duration;
+
+    #[test]
+    fn test_services_map_to_consistently_populated_tasks() {
+        let github = GitHubItem {
+            number: 42,
+            title: "Fix login".to_string(),
+            item_type: "issue",
+            state: "open".to_string(),
+            url: String::new(),
+            repository: "acme/web".to_string(),
+        };
+        let linear = LinearIssue {
+            id: "lin-1".to_string(),
+            identifier: "ENG-7".to_string(),
+            title: "Ship search".to_string(),
+            state: "Done".to_string(),
+            state_type: None,
+            priority: 2,
+            url: String::new(),
+        };
+        let due = Utc::now() + Duration::days(2);
+
+        let from_github = to_task(ExternalItem::from(&github).with_due(due));
+        let from_linear = to_task(
+            ExternalItem::from(&linear)
+                .with_due(due)
+                .with_labels(["Backend"]),
+        );
+
+        assert_eq!(from_github.source_service.as_deref(), Some("github"));
+        assert_eq!(
+            from_github.source_external_id.as_deref(),
+            Some("acme/web#42")
+        );
+        assert_eq!(from_github.tags, vec!["github", "acme/web", "issue"]);
+        assert_eq!(from_github.state, TaskState::Ready);
+        assert!(from_github.completed_at.is_none());
+
+        assert_eq!(from_linear.source_service.as_deref(), Some("linear"));
+        assert_eq!(from_linear.source_external_id.as_deref(), Some("lin-1"));
+        assert_eq!(from_linear.title, "ENG-7 Ship search");
+        assert_eq!(from_linear.tags, vec!["linear", "backend"]);
+        assert_eq!(from_linear.state, TaskState::Done);
+        assert!(from_linear.completed && from_linear.completed_at.is_some());
+
+        for task in [&from_github, &from_linear] {
+            assert_eq!(task.window_end_at, Some(due));
+        }
+    }
+
+    #[test]
+    fn test_reconcile_dedups_by_source_and_keeps_local_tags() {
+        let mut tasks = Vec::new();
+        let item = ExternalItem::new("notion", "page-1", "Draft post").with_labels(["Writing"]);
+
+        assert_eq!(reconcile_items(&mut tasks, vec![item.clone()]), 1);
+        tasks[0].tags.push("deep-work".to_string());
+
+        let canceled = ExternalItem {
+            title: "Draft blog post".to_string(),
+            ..item.with_status(ExternalStatus::Canceled)
+        };
+        assert_eq!(reconcile_items(&mut tasks, vec![canceled]), 0);
+        assert_eq!(tasks.len(), 1);
+        assert_eq!(tasks[0].title, "Draft blog post");
+        assert_eq!(tasks[0].state, TaskState::Done);
+        assert_eq!(
+            tasks[0].tags,
+            vec!["notion", "writing", "deep-work", ARCHIVED_TAG]
+        );
+
+        // The same ID from another service is a different item
+        let other = ExternalItem::new("google_tasks", "page-1", "Draft post");
+        assert_eq!(reconcile_items(&mut tasks, vec![other]), 1);
+    }
+
+    #[test]
+    fn test_open_item_keeps_local_running_state() {
+        let raw = serde_json::json!({
+            "id": "1",
+            "title": " Write report ",
+            "notes": "Quarterly numbers",
+            "status": "needsAction",
+        });
+        let item = ExternalItem::from_google_task("list", "Work", &raw).unwrap();
+        assert_eq!(item.external_id, "list:1");
+        assert_eq!(item.title, "Write report");
+        let mut task = sync_item(None, item.clone());
+        assert_eq!(task.description.as_deref(), Some("Quarterly numbers"));
+        assert_eq!(task.tags, vec!["google_tasks", "google_list:work"]);
+
+        for local in [TaskState::Running, TaskState::Paused] {
+            task.state = local;
+            let synced = sync_item(Some(&task), item.clone());
+            assert_eq!(synced.id, task.id);
+            assert_eq!(synced.state, local);
+        }
+
+        task.state = TaskState::Running;
+        let done = sync_item(Some(&task), item.with_status(ExternalStatus::Done));
+        assert_eq!(done.state, TaskState::Done);
+        assert!(done.completed);
+    }
+}
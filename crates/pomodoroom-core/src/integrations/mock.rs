@@ -0,0 +1,232 @@
+//! In-memory `Integration` for tests.
+//!
+//! `MockIntegration` records every trait call with its arguments and returns
+//! programmable results, so recipes and session hooks can be exercised end to
+//! end without credentials or network access. Compiled for unit tests and,
+//! for other crates, behind the `testing` feature.
+
+use std::collections::HashMap;
+use std::sync::Mutex;
+
+use crate::integrations::health::{HealthState, HealthStatus};
+use crate::integrations::traits::Integration;
+use crate::storage::database::SessionRecord;
+
+/// A recorded `Integration` call.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum IntegrationCall {
+    Authenticate,
+    Disconnect,
+    FocusStart {
+        step_label: String,
+        duration_min: u64,
+    },
+    BreakStart {
+        step_label: String,
+        duration_min: u64,
+    },
+    SessionComplete {
+        session_id: i64,
+        step_type: String,
+        duration_min: u64,
+    },
+    Sync,
+    HealthCheck,
+}
+
+impl IntegrationCall {
+    /// Name of the trait method that produced this call.
+    pub fn method(&self) -> &'static str {
+        match self {
+            IntegrationCall::Authenticate => "authenticate",
+            IntegrationCall::Disconnect => "disconnect",
+            IntegrationCall::FocusStart { .. } => "on_focus_start",
+            IntegrationCall::BreakStart { .. } => "on_break_start",
+            IntegrationCall::SessionComplete { .. } => "on_session_complete",
+            IntegrationCall::Sync => "sync",
+            IntegrationCall::HealthCheck => "health_check",
+        }
+    }
+}
+
+/// Integration double with a call log and programmable failures.
+///
+/// Every method succeeds unless an error was registered for it with
+/// `fail_on`. A successful `authenticate` signs the mock in and
+/// `disconnect` signs it out. A failing `health_check` reports the service
+/// as unreachable.
+#[derive(Debug)]
+pub struct MockIntegration {
+    name: String,
+    authenticated: bool,
+    errors: Mutex<HashMap<String, String>>,
+    calls: Mutex<Vec<IntegrationCall>>,
+}
+
+impl MockIntegration {
+    /// Create a signed-in mock registered under `name`.
+    pub fn new(name: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            authenticated: true,
+            errors: Mutex::new(HashMap::new()),
+            calls: Mutex::new(Vec::new()),
+        }
+    }
+
+    /// Set whether `is_authenticated` reports a signed-in user.
+    pub fn with_authenticated(mut self, authenticated: bool) -> Self {
+        self.authenticated = authenticated;
+        self
+    }
+
+    /// Make `method` (e.g. "sync") return an error with `message`.
+    pub fn fail_on(&self, method: &str, message: impl Into<String>) {
+        self.errors
+            .lock()
+            .unwrap()
+            .insert(method.to_string(), message.into());
+    }
+
+    /// Make `method` succeed again.
+    pub fn succeed_on(&self, method: &str) {
+        self.errors.lock().unwrap().remove(method);
+    }
+
+    /// Calls recorded so far, oldest first.
+    pub fn calls(&self) -> Vec<IntegrationCall> {
+        self.calls.lock().unwrap().clone()
+    }
+
+    /// Number of recorded calls to `method`.
+    pub fn call_count(&self, method: &str) -> usize {
+        self.calls
+            .lock()
+            .unwrap()
+            .iter()
+            .filter(|call| call.method() == method)
+            .count()
+    }
+
+    /// Forget all recorded calls.
+    pub fn clear_calls(&self) {
+        self.calls.lock().unwrap().clear();
+    }
+
+    fn record(&self, call: IntegrationCall) -> Result<(), Box<dyn std::error::Error>> {
+        let error = self.errors.lock().unwrap().get(call.method()).cloned();
+        self.calls.lock().unwrap().push(call);
+        match error {
+            Some(message) => Err(message.into()),
+            None => Ok(()),
+        }
+    }
+}
+
+impl Integration for MockIntegration {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn display_name(&self) -> &str {
+        "Mock"
+    }
+
+    fn is_authenticated(&self) -> bool {
+        self.authenticated
+    }
+
+    fn authenticate(&mut self) -> Result<(), Box<dyn std::error::Error>> {
+        self.record(IntegrationCall::Authenticate)?;
+        self.authenticated = true;
+        Ok(())
+    }
+
+    fn disconnect(&mut self) -> Result<(), Box<dyn std::error::Error>> {
+        self.record(IntegrationCall::Disconnect)?;
+        self.authenticated = false;
+        Ok(())
+    }
+
+    fn on_focus_start(
+        &self,
+        step_label: &str,
+        duration_min: u64,
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        self.record(IntegrationCall::FocusStart {
+            step_label: step_label.to_string(),
+            duration_min,
+        })
+    }
+
+    fn on_break_start(
+        &self,
+        step_label: &str,
+        duration_min: u64,
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        self.record(IntegrationCall::BreakStart {
+            step_label: step_label.to_string(),
+            duration_min,
+        })
+    }
+
+    fn on_session_complete(
+        &self,
+        session: &SessionRecord,
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        self.record(IntegrationCall::SessionComplete {
+            session_id: session.id,
+            step_type: session.step_type.clone(),
+            duration_min: session.duration_min,
+        })
+    }
+
+    fn sync(&self) -> Result<(), Box<dyn std::error::Error>> {
+        self.record(IntegrationCall::Sync)
+    }
+
+    fn health_check(&self) -> HealthStatus {
+        match self.record(IntegrationCall::HealthCheck) {
+            Err(e) => HealthStatus::new(HealthState::Unreachable, Some(e.to_string())),
+            Ok(()) if !self.authenticated => HealthStatus::not_configured(),
+            Ok(()) => HealthStatus {
+                latency_ms: Some(0),
+                ..HealthStatus::new(HealthState::Connected, None)
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_mock_records_calls_and_programmed_errors() {
+        let mut mock = MockIntegration::new("mock").with_authenticated(false);
+        assert!(!mock.is_authenticated());
+
+        mock.authenticate().unwrap();
+        assert!(mock.is_authenticated());
+        mock.on_focus_start("Focus", 25).unwrap();
+
+        mock.fail_on("sync", "rate limited");
+        assert_eq!(mock.sync().unwrap_err().to_string(), "rate limited");
+        mock.succeed_on("sync");
+        mock.sync().unwrap();
+
+        assert_eq!(
+            mock.calls(),
+            vec![
+                IntegrationCall::Authenticate,
+                IntegrationCall::FocusStart {
+                    step_label: "Focus".to_string(),
+                    duration_min: 25,
+                },
+                IntegrationCall::Sync,
+                IntegrationCall::Sync,
+            ]
+        );
+        assert_eq!(mock.call_count("sync"), 2);
+    }
+}
@@ -3,18 +3,29 @@ pub mod calendar_db_client;
 pub mod discord;
 pub mod github;
 pub mod google;
+pub mod health;
 pub mod linear;
+pub mod mapping;
+#[cfg(any(test, feature = "testing"))]
+pub mod mock;
 pub mod notion;
 pub mod oauth;
 pub mod slack;
 pub mod traits;
 
 pub use traits::Integration;
+pub use health::{probe, HealthCache, HealthState, HealthStatus};
+#[cfg(any(test, feature = "testing"))]
+pub use mock::{IntegrationCall, MockIntegration};
 pub use calendar_db::{
-    CalendarCheckpoint, CalendarDbConfig, CalendarEventPayload, CalendarEventType,
-    CalendarLogEntry, CalendarLogStats,
+    latest_checkpoint, prunable_entries, replay_plan, CalendarCheckpoint, CalendarDbConfig,
+    CalendarEventPayload, CalendarEventType, CalendarLogEntry, CalendarLogStats,
 };
 pub use calendar_db_client::CalendarDbClient;
+pub use mapping::{
+    merge_into, merge_mapped, reconcile_items, sync_item, to_task, ExternalItem, ExternalStatus,
+    MappedState,
+};
 
 /// Thin wrapper around the OS keyring for credential storage.
 pub mod keyring_store {
@@ -1,3 +1,4 @@
+use crate::integrations::health::{self, HealthStatus};
 use crate::integrations::keyring_store;
 use crate::integrations::traits::Integration;
 use crate::storage::database::SessionRecord;
@@ -187,6 +188,18 @@ impl Integration for NotionIntegration {
         Ok(())
     }
 
+    fn health_check(&self) -> HealthStatus {
+        if !self.is_authenticated() {
+            return HealthStatus::not_configured();
+        }
+        health::probe(
+            Client::new()
+                .get("https://api.notion.com/v1/users/me")
+                .header("Authorization", format!("Bearer {}", self.api_token))
+                .header("Notion-Version", NOTION_VERSION),
+        )
+    }
+
     fn on_focus_start(
         &self,
         _step_label: &str,
@@ -1,5 +1,6 @@
 //! Slack integration -- set user status + DND during focus sessions.
 
+use crate::integrations::health::{self, HealthState, HealthStatus};
 use crate::integrations::keyring_store;
 use crate::integrations::traits::Integration;
 use crate::storage::database::SessionRecord;
@@ -154,6 +155,33 @@ impl Integration for SlackIntegration {
         Ok(())
     }
 
+    fn health_check(&self) -> HealthStatus {
+        if !self.is_authenticated() {
+            return HealthStatus::not_configured();
+        }
+        // auth.test answers HTTP 200 even for a revoked token
+        health::probe_with(
+            Client::new()
+                .post("https://slack.com/api/auth.test")
+                .header("Authorization", format!("Bearer {}", self.token)),
+            |body| {
+                if body.get("ok").and_then(|v| v.as_bool()) == Some(true) {
+                    return None;
+                }
+                let error = body
+                    .get("error")
+                    .and_then(|v| v.as_str())
+                    .unwrap_or("unknown error");
+                let state = match error {
+                    "invalid_auth" | "not_authed" | "token_revoked" | "token_expired"
+                    | "account_inactive" => HealthState::Unauthorized,
+                    _ => HealthState::Degraded,
+                };
+                Some((state, format!("auth.test failed: {error}")))
+            },
+        )
+    }
+
     fn on_focus_start(
         &self,
         step_label: &str,
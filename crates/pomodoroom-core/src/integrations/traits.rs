@@ -4,6 +4,7 @@
 //! implements the `Integration` trait. Integrations are stateless between calls --
 //! credentials come from the OS keyring, looked up by `name()`.
 
+use crate::integrations::health::HealthStatus;
 use crate::storage::database::SessionRecord;
 
 /// Every external service integration implements this trait.
@@ -19,7 +20,9 @@ use crate::storage::database::SessionRecord;
 ///    - `on_focus_start()` - when a focus session begins
 ///    - `on_break_start()` - when a break begins
 ///    - `on_session_complete()` - when any session completes
-/// 4. `disconnect()` removes stored credentials
+/// 4. `sync()` may be requested at any time, e.g. by a recipe action
+/// 5. `health_check()` probes the service live, e.g. for a status dot
+/// 6. `disconnect()` removes stored credentials
 pub trait Integration: Send + Sync {
     /// Unique identifier (e.g. "google", "notion", "linear").
     fn name(&self) -> &str;
@@ -61,4 +64,24 @@ pub trait Integration: Send + Sync {
     ) -> Result<(), Box<dyn std::error::Error>> {
         Ok(()) // default no-op
     }
+
+    /// Pull or push pending changes with the service.
+    fn sync(&self) -> Result<(), Box<dyn std::error::Error>> {
+        Ok(()) // default no-op
+    }
+
+    /// Probe the service with a lightweight authenticated call.
+    ///
+    /// The default has no live check and only reports whether credentials
+    /// are stored. Use `HealthCache` to avoid probing on every poll.
+    fn health_check(&self) -> HealthStatus {
+        if self.is_authenticated() {
+            HealthStatus::new(
+                crate::integrations::health::HealthState::Connected,
+                Some("no live check for this service".to_string()),
+            )
+        } else {
+            HealthStatus::not_configured()
+        }
+    }
 }
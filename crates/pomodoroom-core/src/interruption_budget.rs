@@ -7,6 +7,9 @@ use chrono::{DateTime, Duration, Utc};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
+use crate::focus_windows::PrivacyLevel;
+use crate::stats::{classify_interruption, InterruptionContext, InterruptionSourceType};
+
 /// A single interruption record.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct InterruptionRecord {
@@ -258,6 +261,66 @@ pub struct InterruptionDashboard {
     pub is_anonymized: bool,
 }
 
+/// One member's statistics submitted for a team rollup.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TeamMemberStats {
+    /// Member identifier; only reported at `PrivacyLevel::Full`
+    pub member_id: String,
+
+    /// How much of this member's data may appear in the rollup
+    pub privacy: PrivacyLevel,
+
+    /// Exclude this member from the rollup entirely
+    pub opted_out: bool,
+
+    /// Stats for the current period
+    pub current: InterruptionStats,
+
+    /// Stats for the comparison period, if any
+    pub previous: Option<InterruptionStats>,
+}
+
+/// What a member contributes to a rollup, per `PrivacyLevel`.
+///
+/// - `Minimal`: counts and lost minutes go into the team totals only; the
+///   member is not listed and their type breakdown is left out.
+/// - `Category`: the type breakdown is also merged into the team totals, but
+///   the member is not listed.
+/// - `Full`: the member is listed by id with their type breakdown.
+///
+/// Members are never listed under a pseudonym: a stable one would let a
+/// reader follow a member across rollups.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MemberContribution {
+    /// Member id
+    pub member_id: String,
+
+    /// Interruptions in the current period
+    pub interruption_count: usize,
+
+    /// Lost focus time in the current period (minutes)
+    pub lost_focus_minutes: i64,
+
+    /// Breakdown by type
+    pub by_type: Option<HashMap<InterruptionType, TypeStats>>,
+}
+
+/// Team-level aggregate of member interruption statistics.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TeamRollup {
+    /// Members included in the rollup
+    pub member_count: usize,
+
+    /// Team trend; `trend.current` holds the team totals
+    pub trend: TrendAnalysis,
+
+    /// Members at `PrivacyLevel::Full`, listed individually
+    pub members: Vec<MemberContribution>,
+
+    /// Team-level policy recommendations
+    pub recommendations: Vec<PolicyRecommendation>,
+}
+
 /// Tracker for interruption budget at team level.
 pub struct InterruptionBudgetTracker {
     config: InterruptionBudgetConfig,
@@ -286,6 +349,28 @@ impl InterruptionBudgetTracker {
         self.records.push(record);
     }
 
+    /// Record an interruption, auto-tagging it if the user left it unlabeled.
+    ///
+    /// `chosen` is the type the user picked and is kept as-is, `Other`
+    /// included; only with `None` is the type classified from `context`.
+    pub fn record_classified(
+        &mut self,
+        mut record: InterruptionRecord,
+        chosen: Option<InterruptionType>,
+        context: &InterruptionContext,
+    ) {
+        match chosen {
+            Some(interruption_type) => record.interruption_type = interruption_type,
+            None => {
+                let classification = classify_interruption(context);
+                record.interruption_type = classification.interruption_type();
+                record.is_internal =
+                    classification.source_type == InterruptionSourceType::Internal;
+            }
+        }
+        self.records.push(record);
+    }
+
     /// Record multiple interruptions.
     pub fn record_batch(&mut self, records: Vec<InterruptionRecord>) {
         self.records.extend(records);
@@ -424,40 +509,137 @@ impl InterruptionBudgetTracker {
             Some(self.compute_stats(prev_start, prev_end))
         };
 
-        let wow_change_percent = if let Some(ref prev) = previous {
-            if prev.total_lost_minutes > 0 {
-                ((current.total_lost_minutes as f32 - prev.total_lost_minutes as f32)
-                    / prev.total_lost_minutes as f32)
-                    * 100.0
-            } else {
-                0.0
+        trend_between(current, previous)
+    }
+
+    /// Aggregate member statistics into team totals, trend and recommendations.
+    ///
+    /// Opted-out members are skipped entirely. What the others contribute
+    /// depends on their `PrivacyLevel`; see `MemberContribution`.
+    pub fn team_rollup(&self, members: &[TeamMemberStats]) -> TeamRollup {
+        let included: Vec<_> = members.iter().filter(|m| !m.opted_out).collect();
+
+        let mut totals = Vec::new();
+        let mut previous = Vec::new();
+        let mut contributions = Vec::new();
+        for member in &included {
+            let shares_breakdown = member.privacy != PrivacyLevel::Minimal;
+            totals.push((&member.current, shares_breakdown));
+            if let Some(prev) = &member.previous {
+                previous.push((prev, shares_breakdown));
             }
-        } else {
-            0.0
-        };
+            if member.privacy == PrivacyLevel::Full {
+                contributions.push(MemberContribution {
+                    member_id: member.member_id.clone(),
+                    interruption_count: member.current.total_count,
+                    lost_focus_minutes: member.current.total_lost_minutes,
+                    by_type: Some(member.current.by_type.clone()),
+                });
+            }
+        }
 
-        let trend_description = if wow_change_percent > 20.0 {
-            "Significant increase in interruptions".to_string()
-        } else if wow_change_percent > 5.0 {
-            "Slight increase in interruptions".to_string()
-        } else if wow_change_percent < -20.0 {
-            "Significant decrease in interruptions".to_string()
-        } else if wow_change_percent < -5.0 {
-            "Slight decrease in interruptions".to_string()
-        } else {
-            "Interruptions stable".to_string()
+        let current = self.merge_stats(&totals);
+        let previous = (!previous.is_empty()).then(|| self.merge_stats(&previous));
+        let mut recommendations = self.recommendations_for(&current, included.len());
+        if let Some(no_meeting) = no_meeting_block(&current) {
+            recommendations.push(no_meeting);
+            recommendations.sort_by_key(|r| r.priority);
+        }
+
+        TeamRollup {
+            member_count: included.len(),
+            trend: trend_between(current, previous),
+            members: contributions,
+            recommendations,
+        }
+    }
+
+    /// Sum member stats; breakdowns are merged only where `shares_breakdown`.
+    fn merge_stats(&self, members: &[(&InterruptionStats, bool)]) -> InterruptionStats {
+        let period_start = members.iter().map(|(s, _)| s.period_start).min();
+        let period_end = members.iter().map(|(s, _)| s.period_end).max();
+        let now = Utc::now();
+        let mut merged = InterruptionStats {
+            total_count: 0,
+            total_lost_minutes: 0,
+            avg_duration_minutes: 0.0,
+            internal_ratio: 0.0,
+            by_type: HashMap::new(),
+            by_team: HashMap::new(),
+            period_start: period_start.unwrap_or(now),
+            period_end: period_end.unwrap_or(now),
         };
 
-        TrendAnalysis {
-            current,
-            previous,
-            wow_change_percent,
-            trend_description,
+        let mut internal_count = 0.0;
+        let mut team_members: HashMap<String, i64> = HashMap::new();
+        for (stats, shares_breakdown) in members {
+            merged.total_count += stats.total_count;
+            merged.total_lost_minutes += stats.total_lost_minutes;
+            internal_count += stats.internal_ratio * stats.total_count as f32;
+            if !shares_breakdown {
+                continue;
+            }
+            for (itype, type_stats) in &stats.by_type {
+                let entry = merged.by_type.entry(*itype).or_insert(TypeStats {
+                    count: 0,
+                    total_minutes: 0,
+                    avg_cost_score: 0.0,
+                });
+                let count = entry.count + type_stats.count;
+                if count > 0 {
+                    entry.avg_cost_score = (entry.avg_cost_score * entry.count as f32
+                        + type_stats.avg_cost_score * type_stats.count as f32)
+                        / count as f32;
+                }
+                entry.count = count;
+                entry.total_minutes += type_stats.total_minutes;
+            }
+            for (team, team_stats) in &stats.by_team {
+                *team_members.entry(team.clone()).or_default() += 1;
+                let entry = merged.by_team.entry(team.clone()).or_insert(TeamStats {
+                    team_id: team_stats.team_id.clone(),
+                    interruption_count: 0,
+                    lost_focus_minutes: 0,
+                    budget_utilization: 0.0,
+                    trend_direction: 0.0,
+                    risk_level: InterruptionRisk::Low,
+                });
+                entry.interruption_count += team_stats.interruption_count;
+                entry.lost_focus_minutes += team_stats.lost_focus_minutes;
+            }
         }
+
+        if merged.total_count > 0 {
+            merged.avg_duration_minutes =
+                merged.total_lost_minutes as f32 / merged.total_count as f32;
+            merged.internal_ratio = internal_count / merged.total_count as f32;
+        }
+
+        // Each contributing member brings their own daily budget
+        let days = (merged.period_end - merged.period_start).num_days().max(1);
+        for (team, entry) in merged.by_team.iter_mut() {
+            let budget = self.config.daily_budget_minutes * days * team_members[team];
+            let utilization = (entry.lost_focus_minutes as f32 / budget as f32).min(2.0);
+            entry.budget_utilization = utilization * 100.0;
+            entry.risk_level = InterruptionRisk::from(utilization);
+        }
+
+        merged
     }
 
     /// Generate policy recommendations based on data.
     pub fn generate_recommendations(&self, stats: &InterruptionStats) -> Vec<PolicyRecommendation> {
+        self.recommendations_for(stats, 1)
+    }
+
+    /// Recommendations for stats pooled over `people` members.
+    ///
+    /// The daily interruption threshold applies per person.
+    fn recommendations_for(
+        &self,
+        stats: &InterruptionStats,
+        people: usize,
+    ) -> Vec<PolicyRecommendation> {
         let mut recommendations = Vec::new();
 
         if stats.total_count < self.config.min_samples_for_recommendation {
@@ -466,7 +648,7 @@ impl InterruptionBudgetTracker {
 
         // Check for high overall interruption count
         let days = (stats.period_end - stats.period_start).num_days().max(1);
-        let daily_avg = stats.total_count as f32 / days as f32;
+        let daily_avg = stats.total_count as f32 / days as f32 / people.max(1) as f32;
 
         if daily_avg > self.config.high_interruption_threshold {
             recommendations.push(PolicyRecommendation {
@@ -600,6 +782,67 @@ impl Default for InterruptionBudgetTracker {
     }
 }
 
+/// Compare `current` against `previous` for a `TrendAnalysis`.
+fn trend_between(current: InterruptionStats, previous: Option<InterruptionStats>) -> TrendAnalysis {
+    let wow_change_percent = if let Some(ref prev) = previous {
+        if prev.total_lost_minutes > 0 {
+            ((current.total_lost_minutes as f32 - prev.total_lost_minutes as f32)
+                / prev.total_lost_minutes as f32)
+                * 100.0
+        } else {
+            0.0
+        }
+    } else {
+        0.0
+    };
+
+    let trend_description = if wow_change_percent > 20.0 {
+        "Significant increase in interruptions".to_string()
+    } else if wow_change_percent > 5.0 {
+        "Slight increase in interruptions".to_string()
+    } else if wow_change_percent < -20.0 {
+        "Significant decrease in interruptions".to_string()
+    } else if wow_change_percent < -5.0 {
+        "Slight decrease in interruptions".to_string()
+    } else {
+        "Interruptions stable".to_string()
+    };
+
+    TrendAnalysis {
+        current,
+        previous,
+        wow_change_percent,
+        trend_description,
+    }
+}
+
+/// Suggest a shared no-meeting block when meetings dominate lost time.
+fn no_meeting_block(stats: &InterruptionStats) -> Option<PolicyRecommendation> {
+    let meetings = stats.by_type.get(&InterruptionType::Meeting)?;
+    let typed_minutes: i64 = stats.by_type.values().map(|t| t.total_minutes).sum();
+    if typed_minutes == 0 {
+        return None;
+    }
+    let meeting_ratio = meetings.total_minutes as f32 / typed_minutes as f32;
+    if meeting_ratio <= 0.3 {
+        return None;
+    }
+    Some(PolicyRecommendation {
+        recommendation_type: RecommendationType::FocusBlocks,
+        title: "Institute a No-Meeting Block".to_string(),
+        description: format!(
+            "Meetings account for {:.0}% of the team's lost focus time. Consider a shared meeting-free block each day.",
+            meeting_ratio * 100.0
+        ),
+        expected_impact_percent: 20.0,
+        supporting_metrics: vec![
+            format!("Meeting interruptions: {}", meetings.count),
+            format!("Meeting minutes: {}", meetings.total_minutes),
+        ],
+        priority: 2,
+    })
+}
+
 /// Anonymize a team name.
 fn anonymize_team(team: &str) -> String {
     // Simple hash-based anonymization
@@ -657,6 +900,43 @@ mod tests {
         assert_eq!(stats.total_lost_minutes, 0);
     }
 
+    #[test]
+    fn test_record_classified_keeps_manual_labels() {
+        let mut tracker = InterruptionBudgetTracker::new();
+        let now = Utc::now();
+        let context = InterruptionContext {
+            note: Some("Slack thread about the release".to_string()),
+            active_task_title: None,
+            occurred_at: now,
+            preceded_by_external_event: false,
+        };
+
+        tracker.record_classified(
+            make_record("1", "t1", None, InterruptionType::Other, 5, true),
+            None,
+            &context,
+        );
+        tracker.record_classified(
+            make_record("2", "t1", None, InterruptionType::Colleague, 5, false),
+            Some(InterruptionType::Colleague),
+            &context,
+        );
+        tracker.record_classified(
+            make_record("3", "t1", None, InterruptionType::Other, 5, true),
+            Some(InterruptionType::Other),
+            &context,
+        );
+
+        let records =
+            tracker.get_records_in_range(now - Duration::hours(1), now + Duration::hours(1));
+        assert_eq!(records[0].interruption_type, InterruptionType::Notification);
+        assert!(!records[0].is_internal);
+        assert_eq!(records[1].interruption_type, InterruptionType::Colleague);
+        // Other picked by the user is not reclassified
+        assert_eq!(records[2].interruption_type, InterruptionType::Other);
+        assert!(records[2].is_internal);
+    }
+
     #[test]
     fn test_records_in_range() {
         let mut tracker = InterruptionBudgetTracker::new();
@@ -867,6 +1147,87 @@ mod tests {
         assert!(dashboard.is_anonymized);
     }
 
+    fn member(
+        member_id: &str,
+        privacy: PrivacyLevel,
+        opted_out: bool,
+        records: Vec<InterruptionRecord>,
+    ) -> TeamMemberStats {
+        let mut tracker = InterruptionBudgetTracker::new();
+        tracker.record_batch(records);
+        let now = Utc::now();
+        TeamMemberStats {
+            member_id: member_id.to_string(),
+            privacy,
+            opted_out,
+            current: tracker.compute_stats(now - Duration::hours(25), now + Duration::hours(1)),
+            previous: None,
+        }
+    }
+
+    #[test]
+    fn test_team_rollup_honors_opt_out_and_privacy() {
+        let tracker = InterruptionBudgetTracker::new();
+        let records = |n: usize, itype: InterruptionType, minutes: i64| -> Vec<_> {
+            (0..n)
+                .map(|i| make_record(&i.to_string(), "t1", None, itype, minutes, false))
+                .collect()
+        };
+        let members = vec![
+            member(
+                "alex",
+                PrivacyLevel::Full,
+                false,
+                records(6, InterruptionType::Meeting, 30),
+            ),
+            member(
+                "sam",
+                PrivacyLevel::Minimal,
+                false,
+                records(5, InterruptionType::Notification, 10),
+            ),
+            member(
+                "kim",
+                PrivacyLevel::Full,
+                true,
+                records(1, InterruptionType::Colleague, 90),
+            ),
+            member(
+                "jordan",
+                PrivacyLevel::Category,
+                false,
+                records(2, InterruptionType::System, 5),
+            ),
+        ];
+
+        let rollup = tracker.team_rollup(&members);
+        assert_eq!(rollup.member_count, 3);
+        let totals = &rollup.trend.current;
+        assert_eq!(totals.total_count, 13);
+        assert_eq!(totals.total_lost_minutes, 240);
+
+        // The opted-out member is nowhere in the rollup
+        assert!(!totals.by_type.contains_key(&InterruptionType::Colleague));
+        let json = serde_json::to_string(&rollup).unwrap();
+        assert!(!json.contains("kim"));
+
+        // The minimal-privacy member only adds to the counts
+        assert!(!totals.by_type.contains_key(&InterruptionType::Notification));
+        assert!(!json.contains("sam"));
+
+        // The category-privacy member adds their breakdown but is not listed
+        assert_eq!(totals.by_type[&InterruptionType::System].count, 2);
+        assert!(!json.contains("jordan"));
+        assert_eq!(rollup.members.len(), 1);
+        assert_eq!(rollup.members[0].member_id, "alex");
+        assert!(rollup.members[0].by_type.is_some());
+
+        assert!(rollup
+            .recommendations
+            .iter()
+            .any(|r| r.title == "Institute a No-Meeting Block"));
+    }
+
     #[test]
     fn test_export_with_anonymization() {
         let config = InterruptionBudgetConfig {
@@ -123,7 +123,7 @@ impl JITEngine {
             .list_tasks()
             .map_err(|e| format!("Failed to fetch tasks: {}", e))?
             .into_iter()
-            .filter(|t| t.state == TaskState::Ready)
+            .filter(|t| t.state == TaskState::Ready && !t.is_recurrence_template())
             .collect();
 
         Ok(tasks)
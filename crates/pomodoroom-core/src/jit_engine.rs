@@ -18,9 +18,11 @@
 //! let break_duration = engine.suggest_break_duration(&context);
 //! ```
 
-use chrono::{DateTime, Utc};
+use chrono::{DateTime, Datelike, Duration, Timelike, Utc};
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 
+use crate::energy::EnergyCurve;
 use crate::task::{EnergyLevel, Task, TaskCategory, TaskState};
 
 /// Current context for JIT calculations
@@ -36,6 +38,19 @@ pub struct JitContext {
     pub completed_sessions: u32,
     /// Current timestamp for context
     pub now: DateTime<Utc>,
+    /// When each task's suggestion was last dismissed, keyed by task ID
+    #[serde(default)]
+    pub recent_dismissals: HashMap<String, DateTime<Utc>>,
+    /// Interruptions logged since the last break
+    #[serde(default)]
+    pub interruptions_since_break: u32,
+}
+
+impl JitContext {
+    /// Record that the user dismissed the suggestion for `task_id` at `now`.
+    pub fn dismiss(&mut self, task_id: &str) {
+        self.recent_dismissals.insert(task_id.to_string(), self.now);
+    }
 }
 
 /// Summary of a task for suggestion purposes
@@ -78,14 +93,100 @@ pub enum SuggestionReason {
     HighPriority,
     /// Matches current energy level
     EnergyMatch,
+    /// Matches the energy expected from the learned curve at this hour
+    EnergyCurveMatch,
     /// Quick win (short duration)
     QuickWin,
     /// Most recently deferred
     RecentlyDeferred,
     /// Part of active project
     ActiveProject,
+    /// Demoted after a recent dismissal; only shown when nothing else is ready
+    RecentlyDismissed,
+}
+
+/// What to do at the end of a focus session
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum BreakRecommendation {
+    /// Start a break now
+    TakeBreak,
+    /// Keep going for one more focus session
+    OneMoreFocus,
+}
+
+/// A signal that pushes toward taking a break
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum BreakFactor {
+    /// A full long-break cycle of sessions is done
+    LongBreakDue { completed_sessions: u32 },
+    /// Energy is below the break threshold
+    LowEnergy { energy: u8 },
+    /// Too long without a break
+    LongStretch { minutes_since_break: u64 },
+    /// Frequent interruptions since the last break
+    FrequentInterruptions {
+        interruptions: u32,
+        minutes_since_break: u64,
+    },
+}
+
+impl BreakFactor {
+    /// How strongly this factor alone argues for a break (0.0-1.0)
+    fn weight(&self) -> f64 {
+        match self {
+            Self::LongBreakDue { .. } => 0.4,
+            Self::LowEnergy { .. } => 0.5,
+            Self::LongStretch { .. } => 0.5,
+            Self::FrequentInterruptions { .. } => 0.3,
+        }
+    }
+
+    /// Short phrase for a rationale, e.g. "you've done 4 sessions"
+    pub fn describe(&self) -> String {
+        match self {
+            Self::LongBreakDue { completed_sessions } => {
+                format!("you've done {completed_sessions} sessions")
+            }
+            Self::LowEnergy { energy } => format!("energy is low ({energy}/100)"),
+            Self::LongStretch {
+                minutes_since_break,
+            } => format!("no break for {minutes_since_break} min"),
+            Self::FrequentInterruptions {
+                interruptions,
+                minutes_since_break,
+            } => format!("{interruptions} interruptions in {minutes_since_break} min"),
+        }
+    }
+}
+
+/// Break-or-continue recommendation with its reasons
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BreakDecision {
+    pub recommendation: BreakRecommendation,
+    /// How sure the recommendation is (0.0-1.0)
+    pub confidence: f64,
+    /// Signals toward a break; empty for `OneMoreFocus`
+    pub factors: Vec<BreakFactor>,
+    /// One-line explanation for display
+    pub rationale: String,
 }
 
+impl BreakDecision {
+    /// Whether a break is recommended
+    pub fn take_break(&self) -> bool {
+        self.recommendation == BreakRecommendation::TakeBreak
+    }
+}
+
+/// Energy below which a break is recommended
+const LOW_ENERGY_THRESHOLD: u8 = 30;
+
+/// Minutes of work after which a break is recommended
+const LONG_STRETCH_MIN: u64 = 120;
+
+/// Interruptions per hour since the last break that count as frequent
+const FREQUENT_INTERRUPTIONS_PER_HOUR: f64 = 4.0;
+
 /// JIT Engine for calculating next tasks on demand
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct JitEngine {
@@ -97,6 +198,24 @@ pub struct JitEngine {
     pub long_break: u32,
     /// Pomodoros before long break
     pub pomodoros_before_long_break: u32,
+    /// Minutes a dismissed suggestion is held back
+    #[serde(default = "default_dismissal_cooldown_min")]
+    pub dismissal_cooldown_min: u32,
+    /// Learned energy curve blended into the reported energy, if any
+    #[serde(default)]
+    pub energy_curve: Option<EnergyCurve>,
+    /// Share of the curve's expected energy in the blend (0.0-1.0), scaled
+    /// by the confidence of the current hour's window
+    #[serde(default = "default_curve_weight")]
+    pub curve_weight: f64,
+}
+
+fn default_dismissal_cooldown_min() -> u32 {
+    30
+}
+
+fn default_curve_weight() -> f64 {
+    0.5
 }
 
 impl Default for JitEngine {
@@ -106,6 +225,9 @@ impl Default for JitEngine {
             short_break: 5,
             long_break: 15,
             pomodoros_before_long_break: 4,
+            dismissal_cooldown_min: default_dismissal_cooldown_min(),
+            energy_curve: None,
+            curve_weight: default_curve_weight(),
         }
     }
 }
@@ -128,9 +250,35 @@ impl JitEngine {
             short_break,
             long_break,
             pomodoros_before_long_break,
+            ..Self::default()
         }
     }
 
+    /// Blend the learned energy curve into suggestions
+    pub fn with_energy_curve(mut self, curve: EnergyCurve) -> Self {
+        self.energy_curve = Some(curve);
+        self
+    }
+
+    /// Energy (0-100) used for ranking at `context.now`
+    ///
+    /// Without a curve, or when the curve has no samples for the current
+    /// hour, this is the reported `context.energy`.
+    pub fn effective_energy(&self, context: &JitContext) -> u8 {
+        let Some(curve) = &self.energy_curve else {
+            return context.energy;
+        };
+        let hour = context.now.hour() as u8;
+        let day = context.now.weekday().num_days_from_sunday() as u8;
+        let Some(window) = curve.find_window(hour, day).filter(|w| w.sample_count > 0) else {
+            return context.energy;
+        };
+        let weight = (self.curve_weight * window.confidence).clamp(0.0, 1.0);
+        let expected = window.baseline_energy.clamp(0.0, 1.0) * 100.0;
+        let blended = f64::from(context.energy.min(100)) * (1.0 - weight) + expected * weight;
+        blended.round() as u8
+    }
+
     /// Calculate next 3 tasks based on current context
     ///
     /// # Arguments
@@ -138,7 +286,8 @@ impl JitEngine {
     /// * `tasks` - All available tasks
     ///
     /// # Returns
-    /// Up to 3 task suggestions, sorted by score
+    /// Up to 3 task suggestions, sorted by score. Tasks dismissed within the
+    /// cooldown window are left out unless no other task is ready.
     pub fn suggest_next_tasks(
         &self,
         context: &JitContext,
@@ -148,17 +297,31 @@ impl JitEngine {
         let ready_tasks: Vec<_> = tasks
             .iter()
             .filter(|t| t.state == TaskState::Ready && t.category == TaskCategory::Active)
+            .filter(|t| !t.is_recurrence_template())
             .collect();
 
         if ready_tasks.is_empty() {
             return Vec::new();
         }
 
+        // Hold back recently dismissed tasks, falling back to them when
+        // nothing else is ready
+        let (cooling, fresh): (Vec<_>, Vec<_>) = ready_tasks
+            .into_iter()
+            .partition(|task| self.is_cooling_down(context, &task.id));
+        let demoted = fresh.is_empty();
+        let candidates = if demoted { cooling } else { fresh };
+
         // Score each task based on multiple factors
-        let mut suggestions: Vec<TaskSuggestion> = ready_tasks
+        let mut suggestions: Vec<TaskSuggestion> = candidates
             .iter()
             .map(|task| self.score_task(context, task))
             .collect();
+        if demoted {
+            for suggestion in &mut suggestions {
+                suggestion.reason = SuggestionReason::RecentlyDismissed;
+            }
+        }
 
         // Sort by score (descending)
         suggestions.sort_by(|a, b| b.score.cmp(&a.score));
@@ -168,6 +331,26 @@ impl JitEngine {
         suggestions
     }
 
+    /// Whether `task_id` was dismissed within the cooldown window
+    pub fn is_cooling_down(&self, context: &JitContext, task_id: &str) -> bool {
+        context
+            .recent_dismissals
+            .get(task_id)
+            .is_some_and(|dismissed_at| {
+                context.now - *dismissed_at
+                    < Duration::minutes(i64::from(self.dismissal_cooldown_min))
+            })
+    }
+
+    /// Drop dismissals whose cooldown has expired
+    pub fn expire_dismissals(&self, context: &mut JitContext) {
+        let now = context.now;
+        let cooldown = Duration::minutes(i64::from(self.dismissal_cooldown_min));
+        context
+            .recent_dismissals
+            .retain(|_, dismissed_at| now - *dismissed_at < cooldown);
+    }
+
     /// Calculate optimal break duration based on context
     ///
     /// # Arguments
@@ -193,18 +376,77 @@ impl JitEngine {
     /// * `context` - Current execution context
     ///
     /// # Returns
-    /// true if break is recommended
+    /// true if break is recommended; see `break_decision` for the reasons
     pub fn should_take_break(&self, context: &JitContext) -> bool {
-        // Suggest break if:
-        // - Energy is low (< 30)
-        // - Been working for > 2 hours without break
-        // - Just completed 4 pomodoros (long break cycle)
-        let energy_low = context.energy < 30;
-        let long_work_session = context.time_since_last_break_min > 120;
-        let long_break_cycle = context.completed_sessions % self.pomodoros_before_long_break == 0
-            && context.completed_sessions > 0;
+        self.break_decision(context).take_break()
+    }
 
-        energy_low || long_work_session || long_break_cycle
+    /// Recommend a break or one more focus session, with the reasons
+    ///
+    /// A break is recommended when any factor applies:
+    /// - Just completed a long break cycle of pomodoros
+    /// - Energy is low (< 30)
+    /// - Been working for > 2 hours without break
+    /// - Four or more interruptions per hour since the last break
+    ///
+    /// Each factor adds to the confidence of a break. Without factors the
+    /// confidence in continuing grows with the energy left.
+    pub fn break_decision(&self, context: &JitContext) -> BreakDecision {
+        let mut factors = Vec::new();
+        if context.completed_sessions > 0
+            && self.pomodoros_before_long_break > 0
+            && context
+                .completed_sessions
+                .is_multiple_of(self.pomodoros_before_long_break)
+        {
+            factors.push(BreakFactor::LongBreakDue {
+                completed_sessions: context.completed_sessions,
+            });
+        }
+        if context.energy < LOW_ENERGY_THRESHOLD {
+            factors.push(BreakFactor::LowEnergy {
+                energy: context.energy,
+            });
+        }
+        if context.time_since_last_break_min > LONG_STRETCH_MIN {
+            factors.push(BreakFactor::LongStretch {
+                minutes_since_break: context.time_since_last_break_min,
+            });
+        }
+        let hours = (context.time_since_last_break_min.max(1) as f64) / 60.0;
+        if context.interruptions_since_break >= 2
+            && f64::from(context.interruptions_since_break) / hours
+                >= FREQUENT_INTERRUPTIONS_PER_HOUR
+        {
+            factors.push(BreakFactor::FrequentInterruptions {
+                interruptions: context.interruptions_since_break,
+                minutes_since_break: context.time_since_last_break_min,
+            });
+        }
+
+        if factors.is_empty() {
+            let headroom = f64::from(context.energy.saturating_sub(LOW_ENERGY_THRESHOLD))
+                / f64::from(100 - LOW_ENERGY_THRESHOLD);
+            return BreakDecision {
+                recommendation: BreakRecommendation::OneMoreFocus,
+                confidence: 0.5 + 0.5 * headroom.min(1.0),
+                factors,
+                rationale: format!(
+                    "Recommended: one more focus (energy is {}/100)",
+                    context.energy
+                ),
+            };
+        }
+
+        // Factors are independent signals; each one closes part of the gap
+        let doubt: f64 = factors.iter().map(|f| 1.0 - f.weight()).product();
+        let reasons: Vec<String> = factors.iter().map(BreakFactor::describe).collect();
+        BreakDecision {
+            recommendation: BreakRecommendation::TakeBreak,
+            confidence: 1.0 - doubt,
+            rationale: format!("Recommended: break ({})", join_reasons(&reasons)),
+            factors,
+        }
     }
 
     /// Score a single task based on context
@@ -213,15 +455,21 @@ impl JitEngine {
         let mut reason = SuggestionReason::HighPriority;
 
         // Energy match: +20 if task energy matches current energy level
-        let energy_match = match context.energy {
-            0..=30 => task.energy == EnergyLevel::Low,
-            31..=70 => task.energy == EnergyLevel::Medium,
-            71..=100 => task.energy == EnergyLevel::High,
-            _ => task.energy == EnergyLevel::Medium, // default
+        let energy_band = |energy: u8| match energy {
+            0..=30 => EnergyLevel::Low,
+            31..=70 => EnergyLevel::Medium,
+            71..=100 => EnergyLevel::High,
+            _ => EnergyLevel::Medium, // default
         };
-        if energy_match {
+        let band = energy_band(self.effective_energy(context));
+        if task.energy == band {
             score = score.saturating_add(20);
-            reason = SuggestionReason::EnergyMatch;
+            // Credit the curve when it moved the energy into another band
+            reason = if band == energy_band(context.energy) {
+                SuggestionReason::EnergyMatch
+            } else {
+                SuggestionReason::EnergyCurveMatch
+            };
         }
 
         // Priority influence: +30 for high priority tasks (>70)
@@ -254,6 +502,15 @@ impl JitEngine {
     }
 }
 
+/// "a", "a and b", "a, b and c"
+fn join_reasons(reasons: &[String]) -> String {
+    match reasons {
+        [] => String::new(),
+        [only] => only.clone(),
+        [rest @ .., last] => format!("{} and {last}", rest.join(", ")),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -303,6 +560,10 @@ mod tests {
             allow_split: true,
             suggested_tags: vec![],
             approved_tags: vec![],
+            external_block: None,
+            recurrence: None,
+            recurrence_parent_id: None,
+            notes: Vec::new(),
         }
     }
 
@@ -322,6 +583,8 @@ mod tests {
             current_task: None,
             completed_sessions: 2,
             now: Utc::now(),
+            recent_dismissals: HashMap::new(),
+            interruptions_since_break: 0,
         };
 
         // After 2 sessions, still need short break
@@ -344,6 +607,8 @@ mod tests {
             current_task: None,
             completed_sessions: 1,
             now: Utc::now(),
+            recent_dismissals: HashMap::new(),
+            interruptions_since_break: 0,
         };
 
         assert!(engine.should_take_break(&context));
@@ -357,6 +622,71 @@ mod tests {
         assert!(!engine.should_take_break(&context_good));
     }
 
+    #[test]
+    fn test_break_decision_lists_sessions_and_low_energy() {
+        let engine = JitEngine::new();
+        let context = JitContext {
+            energy: 20,
+            time_since_last_break_min: 100,
+            current_task: None,
+            completed_sessions: 4,
+            now: Utc::now(),
+            recent_dismissals: HashMap::new(),
+            interruptions_since_break: 0,
+        };
+
+        let decision = engine.break_decision(&context);
+        assert_eq!(decision.recommendation, BreakRecommendation::TakeBreak);
+        assert_eq!(
+            decision.factors,
+            vec![
+                BreakFactor::LongBreakDue {
+                    completed_sessions: 4
+                },
+                BreakFactor::LowEnergy { energy: 20 },
+            ]
+        );
+        assert!(decision.confidence >= 0.7, "{}", decision.confidence);
+        assert_eq!(
+            decision.rationale,
+            "Recommended: break (you've done 4 sessions and energy is low (20/100))"
+        );
+        assert!(engine.should_take_break(&context));
+
+        // A single factor is a weaker recommendation
+        let one_factor = engine.break_decision(&JitContext {
+            energy: 80,
+            ..context.clone()
+        });
+        assert!(one_factor.take_break());
+        assert!(one_factor.confidence < decision.confidence);
+
+        let rested = engine.break_decision(&JitContext {
+            energy: 80,
+            completed_sessions: 2,
+            time_since_last_break_min: 30,
+            ..context.clone()
+        });
+        assert_eq!(rested.recommendation, BreakRecommendation::OneMoreFocus);
+        assert!(rested.factors.is_empty());
+
+        // Interruption density alone can call for a break
+        let interrupted = engine.break_decision(&JitContext {
+            energy: 80,
+            completed_sessions: 2,
+            time_since_last_break_min: 30,
+            interruptions_since_break: 3,
+            ..context
+        });
+        assert!(matches!(
+            interrupted.factors.as_slice(),
+            [BreakFactor::FrequentInterruptions {
+                interruptions: 3,
+                ..
+            }]
+        ));
+    }
+
     #[test]
     fn test_suggest_next_tasks_empty() {
         let engine = JitEngine::new();
@@ -366,6 +696,8 @@ mod tests {
             current_task: None,
             completed_sessions: 1,
             now: Utc::now(),
+            recent_dismissals: HashMap::new(),
+            interruptions_since_break: 0,
         };
 
         let tasks: Vec<Task> = vec![];
@@ -383,6 +715,8 @@ mod tests {
             current_task: None,
             completed_sessions: 1,
             now: Utc::now(),
+            recent_dismissals: HashMap::new(),
+            interruptions_since_break: 0,
         };
 
         let tasks = vec![
@@ -412,6 +746,8 @@ mod tests {
             current_task: None,
             completed_sessions: 1,
             now: Utc::now(),
+            recent_dismissals: HashMap::new(),
+            interruptions_since_break: 0,
         };
 
         let tasks: Vec<Task> = (1..=10)
@@ -441,6 +777,8 @@ mod tests {
             current_task: None,
             completed_sessions: 1,
             now: Utc::now(),
+            recent_dismissals: HashMap::new(),
+            interruptions_since_break: 0,
         };
 
         let tasks = vec![
@@ -467,6 +805,8 @@ mod tests {
             current_task: None,
             completed_sessions: 1,
             now: Utc::now(),
+            recent_dismissals: HashMap::new(),
+            interruptions_since_break: 0,
         };
 
         let tasks = vec![
@@ -492,6 +832,8 @@ mod tests {
             current_task: None,
             completed_sessions: 1,
             now: Utc::now(),
+            recent_dismissals: HashMap::new(),
+            interruptions_since_break: 0,
         };
 
         let tasks = vec![
@@ -517,6 +859,8 @@ mod tests {
             current_task: None,
             completed_sessions: 1,
             now: Utc::now(),
+            recent_dismissals: HashMap::new(),
+            interruptions_since_break: 0,
         };
 
         let tasks = vec![
@@ -533,6 +877,125 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_dismissed_task_promotes_runner_up() {
+        let engine = JitEngine::new();
+        let mut context = JitContext {
+            energy: 50,
+            time_since_last_break_min: 30,
+            current_task: None,
+            completed_sessions: 1,
+            now: Utc::now(),
+            recent_dismissals: HashMap::new(),
+            interruptions_since_break: 0,
+        };
+
+        let tasks = vec![
+            create_test_task("1", "Top task", EnergyLevel::Medium, 90, Some(30)),
+            create_test_task("2", "Runner-up", EnergyLevel::Medium, 60, Some(30)),
+        ];
+
+        let suggestions = engine.suggest_next_tasks(&context, &tasks);
+        assert_eq!(suggestions[0].task.id, "1");
+
+        context.dismiss("1");
+        let suggestions = engine.suggest_next_tasks(&context, &tasks);
+        assert_eq!(suggestions.len(), 1);
+        assert_eq!(suggestions[0].task.id, "2");
+
+        // Cooldown expires
+        context.now += Duration::minutes(i64::from(engine.dismissal_cooldown_min));
+        let suggestions = engine.suggest_next_tasks(&context, &tasks);
+        assert_eq!(suggestions[0].task.id, "1");
+
+        engine.expire_dismissals(&mut context);
+        assert!(context.recent_dismissals.is_empty());
+    }
+
+    #[test]
+    fn test_all_dismissed_falls_back_with_reason() {
+        let engine = JitEngine::new();
+        let mut context = JitContext {
+            energy: 50,
+            time_since_last_break_min: 30,
+            current_task: None,
+            completed_sessions: 1,
+            now: Utc::now(),
+            recent_dismissals: HashMap::new(),
+            interruptions_since_break: 0,
+        };
+        context.dismiss("1");
+
+        let tasks = vec![create_test_task(
+            "1",
+            "Only task",
+            EnergyLevel::Medium,
+            50,
+            Some(30),
+        )];
+
+        let suggestions = engine.suggest_next_tasks(&context, &tasks);
+        assert_eq!(suggestions.len(), 1);
+        assert!(matches!(
+            suggestions[0].reason,
+            SuggestionReason::RecentlyDismissed
+        ));
+    }
+
+    #[test]
+    fn test_energy_curve_favors_demanding_task_at_peak_hour() {
+        // A Monday at 09:00 UTC with neutral reported energy
+        let now = DateTime::parse_from_rfc3339("2024-01-08T09:30:00Z")
+            .unwrap()
+            .with_timezone(&Utc);
+        let context = JitContext {
+            energy: 50,
+            time_since_last_break_min: 30,
+            current_task: None,
+            completed_sessions: 1,
+            now,
+            recent_dismissals: HashMap::new(),
+            interruptions_since_break: 0,
+        };
+        let tasks = vec![
+            create_test_task("trivial", "Trivial", EnergyLevel::Low, 50, Some(10)),
+            create_test_task("demanding", "Demanding", EnergyLevel::High, 50, Some(45)),
+        ];
+
+        let plain = JitEngine::new();
+        assert_eq!(plain.effective_energy(&context), 50);
+        assert_eq!(
+            plain.suggest_next_tasks(&context, &tasks)[0].task.id,
+            "trivial"
+        );
+
+        let mut curve = EnergyCurve::new();
+        let window = curve.find_window_mut(9, 1).unwrap();
+        window.baseline_energy = 0.95;
+        window.sample_count = 20;
+        window.confidence = 1.0;
+        let engine = JitEngine::new().with_energy_curve(curve.clone());
+        assert!(engine.effective_energy(&context) > 70);
+
+        let suggestions = engine.suggest_next_tasks(&context, &tasks);
+        assert_eq!(suggestions[0].task.id, "demanding");
+        assert!(matches!(
+            suggestions[0].reason,
+            SuggestionReason::EnergyCurveMatch
+        ));
+
+        // Hours without samples fall back to the reported energy
+        let evening = JitContext {
+            now: now + Duration::hours(10),
+            ..context
+        };
+        assert_eq!(engine.effective_energy(&evening), 50);
+        assert_eq!(
+            engine.suggest_next_tasks(&evening, &tasks)[0].task.id,
+            "trivial"
+        );
+    }
+
     #[test]
     fn test_with_settings() {
         let engine = JitEngine::with_settings(30, 10, 20, 3);
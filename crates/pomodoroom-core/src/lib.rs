@@ -52,51 +52,60 @@ pub mod task;
 pub mod timeline;
 pub mod timer;
 
-pub use calendar::{AggregatedView, CalendarShardId, RoutingContext, ShardConfig, ShardPolicy, ShardRouter};
+pub use calendar::{AggregatedView, AggregatedViewCache, AggregatedViewCacheStats, CalendarShardId, RoutingContext, ShardConfig, ShardPolicy, ShardRouter};
 pub use bayesian_tuner::{BayesianBreakTuner, BreakLengthSummary, BreakObservation, BreakTuningConfig, TunerState, TuningDecision};
-pub use checkin::{Blocker, CheckinConfig, CheckinGenerator, CheckinInput, CheckinSummary, CompletedSegment, PostingDestination, PostingResult, SourceLink};
+pub use checkin::{Blocker, CheckinConfig, CheckinGenerator, CheckinInput, CheckinMetrics, CheckinSummary, CompletedSegment, PostingDestination, PostingResult, SourceLink};
 pub use context_switch::{ContextId, SwitchCostMatrix, SwitchOverheadReport};
 pub use error::{ConfigError, CoreError, DatabaseError, OAuthError, ValidationError};
 pub use events::Event;
-pub use feature_flags::{FeatureFlag, FlagContext, FlagDiagnostics, FlagId, FlagManager, FlagParameter, FlagState, FlagValue, FromFlagParameter, RolloutRule, RuleAction, RuleCondition};
+pub use feature_flags::{FallbackReason, FeatureFlag, FlagContext, FlagDiagnostics, FlagId, FlagManager, FlagParameter, FlagState, FlagValue, FromFlagParameter, ParamFallback, RolloutRule, RuleAction, RuleCondition};
 pub use focus_windows::{AlternativeSlot, ConflictSeverity, DndPlatform, DndSyncError, DndSyncResult, DndSyncStatus, FocusWindow, FocusWindowConfig, FocusWindowError, FocusWindowManager, OverlapConflict, PrivacyLevel, PublishedFocusWindow, UserId, WindowId, WorkspaceSharingSettings, WorkspaceId};
-pub use handoff::{ActivityEntry, ActivityType, BlockerInfo, BlockerType, EffortEstimate, HandoffError, HandoffGenerator, HandoffHistoryEntry, HandoffPacket, HandoffState, HandoffTaskState, NextStep, PacketId, Reference, ReferenceType, SessionContext, StepPriority, TaskId, TaskLink, TaskRelationship};
-pub use interruption_budget::{InterruptionBudgetConfig, InterruptionBudgetTracker, InterruptionDashboard, InterruptionRecord, InterruptionRisk, InterruptionStats, PolicyRecommendation, RecommendationType, TeamStats, TrendAnalysis, TypeStats};
-pub use long_break_placement::{BreakCandidate, LongBreakConfig, LongBreakPlacer, PlacementResult};
-pub use onboarding::{EnergyCurveType, OnboardingWizard, QuestionCategory, QuestionChoice, QuestionResponse, ScoreAdjustments, SessionId, StarterProfile, WizardConfig, WizardError, WizardProgress, WizardQuestion, WizardSession};
-pub use pair_focus::{AttendanceEntry, AttendanceEvent, OptOutReason, OptOutRecord, PairFocusError, PairFocusManager, Participant, ParticipantId, ParticipantStatus, ParticipantSummary, RoomId, RoomState, SessionPhase, SessionSummary, SharedPolicy, SharedSessionRoom, Vote};
+pub use handoff::{ActivityEntry, ActivityType, BlockerInfo, BlockerType, EffortEstimate, HandoffDiff, HandoffError, HandoffGenerator, HandoffHistoryEntry, HandoffPacket, HandoffState, HandoffTaskState, NextStep, PacketId, Reference, ReferenceType, SessionContext, StepChange, StepPriority, TaskId, TaskLink, TaskRelationship, TaskStateChange};
+pub use interruption_budget::{InterruptionBudgetConfig, InterruptionBudgetTracker, InterruptionDashboard, InterruptionRecord, InterruptionRisk, InterruptionStats, MemberContribution, PolicyRecommendation, RecommendationType, TeamMemberStats, TeamRollup, TeamStats, TrendAnalysis, TypeStats};
+pub use long_break_placement::{BreakCandidate, BreakLengthSource, LongBreakConfig, LongBreakPlacer, PlacementResult};
+pub use onboarding::{EnergyCurveType, OnboardingWizard, QuestionCategory, QuestionChoice, QuestionResponse, ScoreAdjustments, SessionId, StarterProfile, WizardConfig, WizardError, WizardProgress, WizardQuestion, WizardSession, WizardSessionStore};
+pub use pair_focus::{AttendanceEntry, AttendanceEvent, OptOutReason, OptOutRecord, PairFocusError, PairFocusManager, Participant, ParticipantId, ParticipantStatus, ParticipantSummary, PhaseSync, RoomId, RoomState, SessionPhase, SessionSummary, SharedPolicy, SharedSessionRoom, Vote};
 pub use policy::{
-    check_compatibility, parse_version, Compatibility, ExperimentDefinition, ExperimentEngine,
-    ExperimentMetric, ExperimentRegistry, ExperimentStatus, ExperimentSummary, ExperimentVariant,
-    NotificationPolicyConfig, NotificationStyle, PolicyBundle, PolicyData, PolicyMetadata,
-    POLICY_VERSION, RandomizationStrategy,
+    check_compatibility, migrate_bundle, parse_version, Compatibility, ExperimentDefinition,
+    ExperimentEngine, ExperimentMetric, ExperimentRegistry, ExperimentStatus, ExperimentSummary,
+    ExperimentVariant, NotificationPolicyConfig, NotificationStyle, PolicyBundle, PolicyData,
+    PolicyMetadata, PolicyMigrationError, POLICY_VERSION, RandomizationStrategy,
 };
 pub use recipes::{Recipe, Trigger, Action, ActionExecutor, RecipeEngine};
 pub use recipes::{ActionResult, ActionLog, ExecutionStatus, RecipeError};
-pub use robustness::{MonteCarloConfig, MonteCarloSimulator, RiskLevel, RobustnessResult, TaskRobustnessInfo};
-pub use schedule::{BlockType, DailyTemplate, FixedEvent, Project, ScheduleBlock};
-pub use scheduler::{AutoScheduler, CalendarEvent, ScheduledBlock, SchedulerConfig};
-pub use jit_engine::{JitContext, JitEngine, SuggestionReason, TaskSuggestion, TaskSummary};
+pub use recipes::{RecipeExplanation, TriggerEvaluation};
+pub use robustness::{
+    MonteCarloConfig, MonteCarloSimulator, RiskLevel, RobustnessResult, TaskImpact,
+    TaskRobustnessInfo, TaskUncertainty,
+};
+pub use schedule::{BlockType, DailyTemplate, FixedEvent, FixedEventKind, Project, ScheduleBlock};
+pub use scheduler::{AutoScheduler, CalendarEvent, PackingStrategy, ScheduledBlock, SchedulerConfig, SchedulingOutcome, UnschedulableReason, UnschedulableTask};
+pub use jit_engine::{
+    BreakDecision, BreakFactor, BreakRecommendation, JitContext, JitEngine, SuggestionReason,
+    TaskSuggestion, TaskSummary,
+};
 pub use scoring::{
-    BenchmarkResult, ObjectiveTerm, ObjectiveWeights, Ordering, ScoreBreakdown, ScoringContext,
-    ScoringEngine,
+    benchmark_presets, default_task_ordering, BenchmarkResult, NormalizedTerm, ObjectiveTerm,
+    ObjectiveWeights, Ordering, PresetBenchmark, PresetRanking, RankedTask, ScoreBreakdown,
+    ScoringContext, ScoringEngine, TaskComparator,
 };
 
 // Pressure engine exports
 pub use scoring::{PressureContext, PressureEngine, PressureMode, PressureResult};
-pub use simulation::{DeterministicRng, SimulationHarness, SimulationMetrics, SimulationResult, SimulationScenario, SimulationSeed, ScenarioVariation};
-pub use stats::{BreakAdherenceStats, BreakAdherenceReport, BreakAdherenceAnalyzer, EstimateAccuracy, AccuracyStats, GroupBy, AccuracySessionData, EstimateAccuracyTracker, InterruptionHeatmap, HeatmapCell, InterruptionEvent, InterruptionSource, InterruptionSourceType, InterruptionPriority, InterruptionImpact, InterruptionHeatmapAnalyzer};
-pub use diagnostics::{DiagnosticsBundle, RedactedConfig, AnonymizedTimeline, SchedulingEvent, DiagnosticsGenerator};
-pub use energy::{EnergyCurve, EnergyCurveAnalyzer, EnergySessionData, EnergyWindow};
+pub use simulation::{check_invariants, DeterministicRng, InvariantViolation, PlanningInvariant, ParameterGrid, SimulationHarness, SimulationMetrics, SimulationReport, SimulationResult, SimulationScenario, SimulationSeed, ScenarioVariation, SweepCell, SweepMatrix, SweepMetric, SweepParameter, MAX_SWEEP_CELLS};
+pub use stats::{BreakAdherenceStats, BreakAdherenceReport, BreakAdherenceAnalyzer, GrindStreak, EstimateAccuracy, AccuracyStats, GroupBy, AccuracySessionData, EstimateAccuracyTracker, CalibrationBucket, SizeBucket, InterruptionHeatmap, HeatmapCell, InterruptionEvent, InterruptionSource, InterruptionSourceType, InterruptionPriority, InterruptionImpact, InterruptionHeatmapAnalyzer, InterruptionCostConfig, LostFocusReport, classify_interruption, InterruptionClassification, InterruptionContext};
+pub use diagnostics::{DiagnosticsBundle, RedactedConfig, AnonymizedTimeline, SchedulingEvent, DiagnosticsGenerator, VersionInfo};
+pub use energy::{EnergyCurve, EnergyCurveAnalyzer, EnergyCurveError, EnergyFeature, EnergyFeatureKind, EnergySessionData, EnergyWindow};
 pub use storage::{AccuracyDataRow, Config, Database, EnergyCurveRow, ScheduleDb, SessionRecord};
 pub use sync::{SyncEvent, SyncError, SyncEventType, SyncStatus};
 pub use task::{
-    calculate_remaining_workload, CarryOverEngine, CarryOverPolicy, CarryOverResult,
-    DroppedSegment, DropReason, EnergyLevel, ParentTaskStatus, RemainingWorkload, Task,
-    TaskCategory, TaskState, TaskTransitionError,
+    calculate_remaining_workload, BatchTransitionResult, CarryOverEngine, CarryOverPolicy,
+    CarryOverResult, DroppedSegment, DropReason, EnergyLevel, ParentTaskStatus,
+    RemainingWorkload, Task, TaskCategory, TaskNote, TaskState, TaskTransitionError,
+    TaskTransitionOutcome,
 };
 pub use timeline::{TaskProposal, TimeGap, TimelineItem, TimelineItemSource, TimelineItemType};
 pub use timer::{
-    InterruptionType, StepType, StreakDecayCalculator, StreakDecayConfig, StreakDecayEvent,
-    StreakManager, TimerEngine, TimerState,
+    InterruptionType, NextStepKind, StepType, StreakDecayCalculator, StreakDecayConfig,
+    StreakDecayEvent, StreakManager, TimerEngine, TimerState,
 };
@@ -1,11 +1,14 @@
 //! Dynamic long-break placement optimization.
 //!
 //! This module evaluates candidate insertion points for long breaks
-//! based on fatigue accumulation and calendar constraints.
+//! based on fatigue accumulation and calendar constraints. When a
+//! `BayesianBreakTuner` has learned enough, its best break length sizes the
+//! break instead of the configured duration.
 
 use chrono::{DateTime, Duration, Utc};
 use serde::{Deserialize, Serialize};
 
+use crate::bayesian_tuner::{BayesianBreakTuner, BreakLengthSummary};
 use crate::scheduler::{CalendarEvent, ScheduledBlock, ScheduledBlockType};
 
 /// Configuration for dynamic long-break placement.
@@ -47,6 +50,17 @@ impl Default for LongBreakConfig {
     }
 }
 
+/// What determined the length of a placed break.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum BreakLengthSource {
+    /// `LongBreakConfig::break_duration`
+    #[default]
+    Configured,
+    /// The break tuner's best-performing length
+    Tuned,
+}
+
 /// A candidate position for long-break insertion.
 #[derive(Debug, Clone)]
 pub struct BreakCandidate {
@@ -73,6 +87,9 @@ pub struct PlacementResult {
     pub score: f32,
     /// Rationale for the selection
     pub rationale: String,
+    /// Whether the break length came from config or the tuner
+    #[serde(default)]
+    pub length_source: BreakLengthSource,
     /// All evaluated candidates (for debugging/transparency)
     #[serde(default)]
     pub evaluated_candidates: Vec<CandidateInfo>,
@@ -89,19 +106,61 @@ pub struct CandidateInfo {
 /// Evaluator for dynamic long-break placement.
 pub struct LongBreakPlacer {
     config: LongBreakConfig,
+    /// Tuner statistics per break length
+    tuned_lengths: Vec<BreakLengthSummary>,
+    /// Samples a length needs before the tuner's summary is trusted
+    tuned_min_samples: usize,
 }
 
 impl LongBreakPlacer {
     /// Create a new placer with default config.
     pub fn new() -> Self {
-        Self {
-            config: LongBreakConfig::default(),
-        }
+        Self::with_config(LongBreakConfig::default())
     }
 
     /// Create a placer with custom config.
     pub fn with_config(config: LongBreakConfig) -> Self {
-        Self { config }
+        Self {
+            config,
+            tuned_lengths: Vec::new(),
+            tuned_min_samples: 0,
+        }
+    }
+
+    /// Size breaks from the tuner's current statistics.
+    pub fn with_tuner(self, tuner: &BayesianBreakTuner) -> Self {
+        let min_samples = tuner.config().min_samples;
+        self.with_tuned_lengths(tuner.get_statistics_summary(), min_samples)
+    }
+
+    /// Size breaks from break length statistics.
+    ///
+    /// Lengths with fewer than `min_samples` observations are ignored.
+    pub fn with_tuned_lengths(
+        mut self,
+        summary: Vec<BreakLengthSummary>,
+        min_samples: usize,
+    ) -> Self {
+        self.tuned_lengths = summary;
+        self.tuned_min_samples = min_samples.max(1);
+        self
+    }
+
+    /// Long break length in minutes and where it came from.
+    ///
+    /// Uses the tuned length with the best outcome, penalized by its safety
+    /// violation rate, once any length has enough samples. Falls back to the
+    /// configured duration otherwise.
+    pub fn break_length(&self) -> (i64, BreakLengthSource) {
+        self.tuned_lengths
+            .iter()
+            .filter(|s| s.sample_count >= self.tuned_min_samples && s.break_length > 0)
+            .max_by(|a, b| {
+                let score = |s: &BreakLengthSummary| s.mean_outcome - s.safety_violation_rate;
+                score(a).total_cmp(&score(b))
+            })
+            .map(|s| (s.break_length as i64, BreakLengthSource::Tuned))
+            .unwrap_or((self.config.break_duration, BreakLengthSource::Configured))
     }
 
     /// Set fixed mode.
@@ -130,17 +189,20 @@ impl LongBreakPlacer {
             return self.fixed_placement(cycle_end);
         }
 
+        let (break_duration, length_source) = self.break_length();
+
         // Check if we have enough pomodoros for a long break
         if pomodoro_count < self.config.pomodoros_before_break {
             return PlacementResult {
                 fixed_mode_used: false,
                 break_start: cycle_end,
-                break_end: cycle_end + Duration::minutes(self.config.break_duration),
+                break_end: cycle_end + Duration::minutes(break_duration),
                 score: 0.0,
                 rationale: format!(
                     "Not enough pomodoros ({}/{})",
                     pomodoro_count, self.config.pomodoros_before_break
                 ),
+                length_source,
                 evaluated_candidates: vec![],
             };
         }
@@ -179,18 +241,21 @@ impl LongBreakPlacer {
             break_end: best.end_time,
             score: best.score,
             rationale: best.rationale,
+            length_source,
             evaluated_candidates: evaluated,
         }
     }
 
     /// Fixed placement at end of cycle.
     fn fixed_placement(&self, cycle_end: DateTime<Utc>) -> PlacementResult {
+        let (break_duration, length_source) = self.break_length();
         PlacementResult {
             fixed_mode_used: true,
             break_start: cycle_end,
-            break_end: cycle_end + Duration::minutes(self.config.break_duration),
+            break_end: cycle_end + Duration::minutes(break_duration),
             score: 1.0,
             rationale: "Fixed mode: break at end of cycle".to_string(),
+            length_source,
             evaluated_candidates: vec![],
         }
     }
@@ -204,6 +269,7 @@ impl LongBreakPlacer {
         cycle_end: DateTime<Utc>,
     ) -> Vec<BreakCandidate> {
         let mut candidates = Vec::new();
+        let (break_duration, _) = self.break_length();
 
         // Find gaps between focus blocks
         let focus_blocks: Vec<_> = scheduled_blocks
@@ -222,9 +288,9 @@ impl LongBreakPlacer {
 
             // Check if there's enough gap for a long break
             let gap_minutes = (next_start - current_end).num_minutes();
-            if gap_minutes >= self.config.break_duration {
+            if gap_minutes >= break_duration {
                 let break_start = current_end;
-                let break_end = current_end + Duration::minutes(self.config.break_duration);
+                let break_end = current_end + Duration::minutes(break_duration);
 
                 // Check for calendar conflicts
                 if !self.has_calendar_conflict(break_start, break_end, calendar_events) {
@@ -241,7 +307,7 @@ impl LongBreakPlacer {
         // Also consider end of cycle as a fallback
         candidates.push(BreakCandidate {
             start_time: cycle_end,
-            end_time: cycle_end + Duration::minutes(self.config.break_duration),
+            end_time: cycle_end + Duration::minutes(break_duration),
             score: 0.0,
             rationale: String::new(),
         });
@@ -517,4 +583,41 @@ mod tests {
         // Rationale should contain scoring information
         assert!(!result.rationale.is_empty() || result.fixed_mode_used);
     }
+
+    #[test]
+    fn test_tuned_break_length_sizes_placed_break() {
+        let now = Utc::now();
+        let blocks = vec![
+            make_block("1", now, 50),
+            make_block("2", now + Duration::minutes(90), 50),
+        ];
+        let cycle_end = now + Duration::minutes(140);
+
+        let configured =
+            LongBreakPlacer::new().find_optimal_break_position(&blocks, &[], 4, now, cycle_end);
+        assert_eq!(configured.length_source, BreakLengthSource::Configured);
+        assert_eq!(
+            (configured.break_end - configured.break_start).num_minutes(),
+            15
+        );
+
+        let summary = |break_length, sample_count, mean_outcome| BreakLengthSummary {
+            break_length,
+            sample_count,
+            mean_outcome,
+            std_dev: 0.1,
+            safety_violation_rate: 0.0,
+        };
+        let tuned = LongBreakPlacer::new()
+            .with_tuned_lengths(vec![summary(15, 8, 0.5), summary(25, 8, 0.9)], 5)
+            .find_optimal_break_position(&blocks, &[], 4, now, cycle_end);
+        assert_eq!(tuned.length_source, BreakLengthSource::Tuned);
+        assert_eq!((tuned.break_end - tuned.break_start).num_minutes(), 25);
+
+        // Too few samples falls back to config
+        let sparse = LongBreakPlacer::new()
+            .with_tuned_lengths(vec![summary(25, 2, 0.9)], 5)
+            .break_length();
+        assert_eq!(sparse, (15, BreakLengthSource::Configured));
+    }
 }
@@ -4,10 +4,14 @@
 //! - Interactive question flow for task mix, interruptions, energy patterns
 //! - Starter profile generation from responses
 //! - Wizard re-run capability from settings
+//! - Resumable sessions persisted to disk
 
-use chrono::{DateTime, Utc};
+use chrono::{DateTime, Duration, Utc};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
+use std::path::PathBuf;
+
+use crate::storage::data_dir;
 
 /// Unique identifier for a wizard session.
 pub type SessionId = String;
@@ -145,6 +149,15 @@ impl WizardSession {
     pub fn is_within_target_time(&self) -> bool {
         self.duration_seconds() <= 180 // 3 minutes = 180 seconds
     }
+
+    /// Time of the most recent activity (last response, or session start).
+    pub fn last_activity(&self) -> DateTime<Utc> {
+        self.responses
+            .iter()
+            .map(|r| r.responded_at)
+            .chain(self.completed_at)
+            .fold(self.started_at, DateTime::max)
+    }
 }
 
 impl Default for WizardSession {
@@ -168,6 +181,9 @@ pub struct StarterProfile {
     pub long_break_interval: u32,
     /// Energy curve type.
     pub energy_curve: EnergyCurveType,
+    /// Name of a shared `EnergyCurve` to seed the user with, if any.
+    #[serde(default)]
+    pub shared_energy_curve: Option<String>,
     /// Interruption tolerance level.
     pub interruption_tolerance: u32,
     /// Suggested work hours.
@@ -191,6 +207,7 @@ impl Default for StarterProfile {
             daily_target: 8,
             long_break_interval: 4,
             energy_curve: EnergyCurveType::default(),
+            shared_energy_curve: None,
             interruption_tolerance: 50,
             suggested_work_hours: 8,
             name: "Default Profile".to_string(),
@@ -210,6 +227,8 @@ pub struct OnboardingWizard {
     sessions: HashMap<SessionId, WizardSession>,
     /// Configuration.
     config: WizardConfig,
+    /// Persistent session storage, if configured.
+    store: Option<WizardSessionStore>,
 }
 
 /// Configuration for the wizard.
@@ -221,6 +240,16 @@ pub struct WizardConfig {
     pub min_questions: usize,
     /// Whether to allow full skip.
     pub allow_skip: bool,
+    /// Shared energy curve name referenced by generated profiles (team onboarding).
+    #[serde(default)]
+    pub shared_energy_curve: Option<String>,
+    /// Days of inactivity after which a saved session can no longer be resumed.
+    #[serde(default = "default_session_expiry_days")]
+    pub session_expiry_days: i64,
+}
+
+fn default_session_expiry_days() -> i64 {
+    7
 }
 
 impl Default for WizardConfig {
@@ -229,6 +258,8 @@ impl Default for WizardConfig {
             target_time_seconds: 180, // 3 minutes
             min_questions: 3,
             allow_skip: true,
+            shared_energy_curve: None,
+            session_expiry_days: default_session_expiry_days(),
         }
     }
 }
@@ -248,6 +279,10 @@ pub enum WizardError {
     CannotSkip(String),
     /// Not enough responses to generate profile.
     InsufficientResponses(usize, usize),
+    /// Saved session is older than the expiry window.
+    SessionExpired(SessionId),
+    /// Session storage failed or is not configured.
+    Storage(String),
 }
 
 impl std::fmt::Display for WizardError {
@@ -261,6 +296,8 @@ impl std::fmt::Display for WizardError {
             WizardError::InsufficientResponses(have, need) => {
                 write!(f, "Need {} responses, got {}", need, have)
             }
+            WizardError::SessionExpired(id) => write!(f, "Session expired: {}", id),
+            WizardError::Storage(msg) => write!(f, "Session storage error: {}", msg),
         }
     }
 }
@@ -274,6 +311,7 @@ impl OnboardingWizard {
             questions: Self::create_default_questions(),
             sessions: HashMap::new(),
             config: WizardConfig::default(),
+            store: None,
         }
     }
 
@@ -283,9 +321,16 @@ impl OnboardingWizard {
             questions: Self::create_default_questions(),
             sessions: HashMap::new(),
             config,
+            store: None,
         }
     }
 
+    /// Attach persistent storage used by `save_session` and `resume`.
+    pub fn with_store(mut self, store: WizardSessionStore) -> Self {
+        self.store = Some(store);
+        self
+    }
+
     /// Create the default question set.
     fn create_default_questions() -> Vec<WizardQuestion> {
         vec![
@@ -699,7 +744,10 @@ impl OnboardingWizard {
 
         session.skipped = true;
         session.completed_at = Some(Utc::now());
-        session.generated_profile = Some(StarterProfile::default());
+        session.generated_profile = Some(StarterProfile {
+            shared_energy_curve: self.config.shared_energy_curve.clone(),
+            ..StarterProfile::default()
+        });
 
         Ok(session.generated_profile.clone().unwrap())
     }
@@ -788,6 +836,7 @@ impl OnboardingWizard {
             daily_target,
             long_break_interval: base.long_break_interval,
             energy_curve: adjustments.energy_curve.unwrap_or_default(),
+            shared_energy_curve: self.config.shared_energy_curve.clone(),
             interruption_tolerance: adjustments.interruption_tolerance.unwrap_or(50) as u32,
             suggested_work_hours: 8,
             name,
@@ -853,6 +902,7 @@ impl OnboardingWizard {
             daily_target,
             long_break_interval: base.long_break_interval,
             energy_curve: adjustments.energy_curve.unwrap_or_default(),
+            shared_energy_curve: self.config.shared_energy_curve.clone(),
             interruption_tolerance: adjustments.interruption_tolerance.unwrap_or(50) as u32,
             suggested_work_hours: 8,
             name,
@@ -891,6 +941,52 @@ impl OnboardingWizard {
         (name, description)
     }
 
+    /// Persist a session so it can be resumed after the app restarts.
+    ///
+    /// Expired sessions are pruned from storage at the same time.
+    pub fn save_session(&self, session_id: &SessionId) -> Result<(), WizardError> {
+        let store = self.require_store()?;
+        let session = self.sessions.get(session_id)
+            .ok_or_else(|| WizardError::SessionNotFound(session_id.clone()))?;
+
+        let mut saved = store.load_all()?;
+        let cutoff = Utc::now() - Duration::days(self.config.session_expiry_days);
+        saved.retain(|_, s| s.last_activity() >= cutoff);
+        saved.insert(session_id.clone(), session.clone());
+        store.save_all(&saved)
+    }
+
+    /// Restore a saved session, including its responses and current question.
+    ///
+    /// Sessions inactive for longer than `session_expiry_days` are removed
+    /// from storage and reported as `SessionExpired`.
+    pub fn resume(&mut self, session_id: &SessionId) -> Result<WizardProgress, WizardError> {
+        let store = self.require_store()?;
+        let mut saved = store.load_all()?;
+        let session = saved.get(session_id)
+            .ok_or_else(|| WizardError::SessionNotFound(session_id.clone()))?;
+
+        let cutoff = Utc::now() - Duration::days(self.config.session_expiry_days);
+        if session.last_activity() < cutoff {
+            saved.remove(session_id);
+            store.save_all(&saved)?;
+            return Err(WizardError::SessionExpired(session_id.clone()));
+        }
+
+        if session.current_index > self.questions.len() {
+            return Err(WizardError::InvalidQuestionIndex(session.current_index));
+        }
+
+        self.sessions.insert(session_id.clone(), session.clone());
+        self.get_progress(session_id)
+    }
+
+    fn require_store(&self) -> Result<&WizardSessionStore, WizardError> {
+        self.store
+            .as_ref()
+            .ok_or_else(|| WizardError::Storage("no session store configured".to_string()))
+    }
+
     /// Get the generated profile for a completed session.
     pub fn get_profile(&self, session_id: &SessionId) -> Result<Option<&StarterProfile>, WizardError> {
         let session = self.sessions.get(session_id)
@@ -909,6 +1005,16 @@ impl OnboardingWizard {
             .filter(|r| r.choice_id.is_some())
             .count();
         let skipped = session.responses.len() - answered;
+        let completion_pct = if session.is_complete() || total == 0 {
+            100.0
+        } else {
+            session.current_index as f64 / total as f64 * 100.0
+        };
+        let current_category = if session.is_complete() {
+            None
+        } else {
+            self.questions.get(session.current_index).map(|q| q.category)
+        };
 
         Ok(WizardProgress {
             current_index: session.current_index,
@@ -918,6 +1024,8 @@ impl OnboardingWizard {
             is_complete: session.is_complete(),
             is_skipped: session.skipped,
             duration_seconds: session.duration_seconds(),
+            completion_pct,
+            current_category,
         })
     }
 }
@@ -945,6 +1053,52 @@ pub struct WizardProgress {
     pub is_skipped: bool,
     /// Time spent so far in seconds.
     pub duration_seconds: i64,
+    /// Share of questions answered or skipped (0-100).
+    #[serde(default)]
+    pub completion_pct: f64,
+    /// Category of the current question (None when complete).
+    #[serde(default)]
+    pub current_category: Option<QuestionCategory>,
+}
+
+/// Disk storage for in-progress wizard sessions, keyed by session ID.
+#[derive(Debug, Clone)]
+pub struct WizardSessionStore {
+    path: PathBuf,
+}
+
+impl WizardSessionStore {
+    /// Open the session store in the data directory.
+    ///
+    /// The file is created on the first save.
+    pub fn open() -> Result<Self, WizardError> {
+        let dir = data_dir().map_err(|e| WizardError::Storage(e.to_string()))?;
+        Ok(Self {
+            path: dir.join("onboarding_sessions.json"),
+        })
+    }
+
+    /// Create a session store at a custom path.
+    pub fn with_path(path: PathBuf) -> Self {
+        Self { path }
+    }
+
+    /// Load all saved sessions; a missing file yields an empty map.
+    pub fn load_all(&self) -> Result<HashMap<SessionId, WizardSession>, WizardError> {
+        if !self.path.exists() {
+            return Ok(HashMap::new());
+        }
+        let content = std::fs::read_to_string(&self.path)
+            .map_err(|e| WizardError::Storage(e.to_string()))?;
+        serde_json::from_str(&content).map_err(|e| WizardError::Storage(e.to_string()))
+    }
+
+    /// Replace all saved sessions.
+    pub fn save_all(&self, sessions: &HashMap<SessionId, WizardSession>) -> Result<(), WizardError> {
+        let content = serde_json::to_string_pretty(sessions)
+            .map_err(|e| WizardError::Storage(e.to_string()))?;
+        std::fs::write(&self.path, content).map_err(|e| WizardError::Storage(e.to_string()))
+    }
 }
 
 #[cfg(test)]
@@ -1080,6 +1234,18 @@ mod tests {
         assert_eq!(profile.energy_curve, EnergyCurveType::MorningPeak);
     }
 
+    #[test]
+    fn test_profile_references_shared_energy_curve() {
+        let mut wizard = OnboardingWizard::with_config(WizardConfig {
+            shared_energy_curve: Some("team-morning".to_string()),
+            ..WizardConfig::default()
+        });
+        let session = wizard.start_session();
+
+        let profile = wizard.skip_wizard(&session.id).unwrap();
+        assert_eq!(profile.shared_energy_curve.as_deref(), Some("team-morning"));
+    }
+
     #[test]
     fn test_session_within_target_time() {
         let session = WizardSession::new();
@@ -1172,4 +1338,48 @@ mod tests {
         let profile = wizard.get_profile(&session.id).unwrap().unwrap();
         assert_eq!(profile.interruption_tolerance, 100);
     }
+
+    #[test]
+    fn test_resume_saved_session() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let store = WizardSessionStore::with_path(dir.path().join("sessions.json"));
+
+        let mut wizard = OnboardingWizard::new().with_store(store.clone());
+        let session = wizard.start_session();
+        wizard.answer_question(&session.id, "coding").unwrap();
+        let next = wizard.answer_question(&session.id, "moderate").unwrap();
+        let next_id = next.unwrap().id.clone();
+        wizard.save_session(&session.id).unwrap();
+
+        // A fresh wizard (app restart) restores responses and position
+        let mut resumed = OnboardingWizard::new().with_store(store);
+        let progress = resumed.resume(&session.id).unwrap();
+        assert_eq!(progress.current_index, 2);
+        assert_eq!(progress.answered_questions, 2);
+        assert!((progress.completion_pct - 2.0 / 7.0 * 100.0).abs() < 1e-9);
+        assert_eq!(progress.current_category, Some(QuestionCategory::Interruptions));
+        assert_eq!(resumed.get_current_question(&session.id).unwrap().id, next_id);
+    }
+
+    #[test]
+    fn test_resume_expired_session() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let store = WizardSessionStore::with_path(dir.path().join("sessions.json"));
+
+        let mut stale = WizardSession::new();
+        stale.started_at = Utc::now() - Duration::days(30);
+        let id = stale.id.clone();
+        store.save_all(&HashMap::from([(id.clone(), stale)])).unwrap();
+
+        let mut wizard = OnboardingWizard::new().with_store(store.clone());
+        assert_eq!(
+            wizard.resume(&id).unwrap_err(),
+            WizardError::SessionExpired(id.clone())
+        );
+        assert!(store.load_all().unwrap().is_empty());
+
+        // Without a store there is nothing to resume from
+        let mut wizard = OnboardingWizard::new();
+        assert!(matches!(wizard.resume(&id), Err(WizardError::Storage(_))));
+    }
 }
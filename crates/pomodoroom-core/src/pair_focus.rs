@@ -3,7 +3,7 @@
 //! This module supports pair/mob programming sessions where participants
 //! share focus/break policies and coordinate their work sessions.
 
-use chrono::{DateTime, Utc};
+use chrono::{DateTime, Duration, Utc};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use uuid::Uuid;
@@ -43,6 +43,14 @@ pub struct SharedSessionRoom {
 
     /// Session end time
     pub session_ended_at: Option<DateTime<Utc>>,
+
+    /// Phase currently running, if any
+    #[serde(default)]
+    pub current_phase: Option<SessionPhase>,
+
+    /// When the current phase started
+    #[serde(default)]
+    pub phase_started_at: Option<DateTime<Utc>>,
 }
 
 /// State of a shared session room.
@@ -127,6 +135,10 @@ pub struct Participant {
 
     /// Opt-out records
     pub opt_outs: Vec<OptOutRecord>,
+
+    /// Number of non-pending votes cast
+    #[serde(default)]
+    pub votes_cast: u32,
 }
 
 /// Participation status of a participant.
@@ -245,6 +257,22 @@ pub enum AttendanceEvent {
     Rejoined,
 }
 
+/// Room timer state replayed to a reconnecting participant.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PhaseSync {
+    /// Current room state
+    pub state: RoomState,
+
+    /// Phase in progress, if any
+    pub phase: Option<SessionPhase>,
+
+    /// When the phase is due to end
+    pub phase_ends_at: Option<DateTime<Utc>>,
+
+    /// Seconds left in the phase (0 once overdue or with no phase running)
+    pub remaining_seconds: i64,
+}
+
 /// Session summary with attendance timeline.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct SessionSummary {
@@ -281,9 +309,214 @@ pub struct SessionSummary {
 pub struct ParticipantSummary {
     pub id: ParticipantId,
     pub name: String,
+    /// Focus minutes credited from attendance (equal to `active_minutes`)
     pub total_focus_minutes: i64,
     pub total_break_minutes: i64,
     pub opt_out_count: usize,
+    /// Minutes present and active during the session
+    #[serde(default)]
+    pub active_minutes: i64,
+    /// Minutes away, opted out, or between leaving and rejoining
+    #[serde(default)]
+    pub away_minutes: i64,
+    /// Number of votes cast
+    #[serde(default)]
+    pub votes_cast: u32,
+}
+
+/// Presence of a participant while replaying the attendance log.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Presence {
+    NotJoined,
+    Active,
+    Away,
+    Left,
+}
+
+impl SharedSessionRoom {
+    /// Planned length of `phase` under the room policy.
+    pub fn phase_duration(&self, phase: SessionPhase) -> Duration {
+        Duration::minutes(match phase {
+            SessionPhase::Focus => self.policy.focus_duration_minutes,
+            SessionPhase::ShortBreak => self.policy.break_duration_minutes,
+            SessionPhase::LongBreak => self.policy.long_break_minutes,
+        })
+    }
+
+    /// Timer state of the room at `now`.
+    pub fn phase_sync(&self, now: DateTime<Utc>) -> PhaseSync {
+        let running = matches!(self.state, RoomState::FocusActive | RoomState::BreakActive);
+        let phase = self.current_phase.filter(|_| running);
+        let phase_ends_at = phase
+            .zip(self.phase_started_at)
+            .map(|(phase, started_at)| started_at + self.phase_duration(phase));
+        PhaseSync {
+            state: self.state,
+            phase,
+            phase_ends_at,
+            remaining_seconds: phase_ends_at
+                .map_or(0, |ends_at| (ends_at - now).num_seconds().max(0)),
+        }
+    }
+
+    /// Restore a dropped participant to Active and resync them at `now`.
+    ///
+    /// Any vote held from before the drop is reset to pending, so decisions
+    /// taken while they were gone are not counted for or against them.
+    /// Returns the current phase and the attendance entry to record.
+    ///
+    /// # Errors
+    /// `RejoinAfterSessionEnded` once the session is over,
+    /// `ParticipantNotFound` for someone who never joined, or `NotDisconnected`
+    /// unless the participant is Left or Away.
+    pub fn reconnect_participant(
+        &mut self,
+        participant_id: &ParticipantId,
+        now: DateTime<Utc>,
+    ) -> Result<(PhaseSync, AttendanceEntry), PairFocusError> {
+        if self.state == RoomState::Ended {
+            return Err(PairFocusError::RejoinAfterSessionEnded {
+                ended_at: self.session_ended_at.unwrap_or(now),
+            });
+        }
+        let participant = self
+            .participants
+            .get_mut(participant_id)
+            .ok_or(PairFocusError::ParticipantNotFound)?;
+        if !matches!(
+            participant.status,
+            ParticipantStatus::Left | ParticipantStatus::Away
+        ) {
+            return Err(PairFocusError::NotDisconnected);
+        }
+        participant.status = ParticipantStatus::Active;
+        participant.left_at = None;
+        participant.vote = Some(Vote::Pending);
+
+        let entry = AttendanceEntry {
+            participant_id: participant_id.clone(),
+            participant_name: participant.name.clone(),
+            event: AttendanceEvent::Rejoined,
+            timestamp: now,
+        };
+        Ok((self.phase_sync(now), entry))
+    }
+
+    /// Build a session summary from the attendance log.
+    ///
+    /// Each participant's events are replayed in timestamp order within the
+    /// session window (start until `session_ended_at`, or now). Time between
+    /// leaving and rejoining counts as away; time after a final leave or
+    /// before joining is not counted. Repeated events that do not change a
+    /// participant's presence are ignored, and participants without a
+    /// `Joined` entry (the creator) are treated as joined at `joined_at`.
+    pub fn compute_summary(&self, attendance: &[AttendanceEntry]) -> SessionSummary {
+        let start = self.session_started_at.unwrap_or(self.created_at);
+        let end = self.session_ended_at.unwrap_or_else(Utc::now).max(start);
+
+        let total_minutes = (end - start).num_minutes();
+        let cycle_minutes =
+            (self.policy.focus_duration_minutes + self.policy.break_duration_minutes).max(1);
+        let completed_cycles = (total_minutes / cycle_minutes) as u32;
+        let total_focus_minutes = completed_cycles as i64 * self.policy.focus_duration_minutes;
+        let total_break_minutes = completed_cycles as i64 * self.policy.break_duration_minutes;
+
+        let mut final_participants: Vec<ParticipantSummary> = self
+            .participants
+            .values()
+            .map(|p| {
+                let (active, away) = Self::attendance_durations(p, attendance, start, end);
+                ParticipantSummary {
+                    id: p.id.clone(),
+                    name: p.name.clone(),
+                    total_focus_minutes: active.num_minutes(),
+                    total_break_minutes,
+                    opt_out_count: p.opt_outs.len(),
+                    active_minutes: active.num_minutes(),
+                    away_minutes: away.num_minutes(),
+                    votes_cast: p.votes_cast,
+                }
+            })
+            .collect();
+        final_participants.sort_by(|a, b| a.id.cmp(&b.id));
+
+        SessionSummary {
+            room_id: self.id.clone(),
+            room_name: self.name.clone(),
+            started_at: start,
+            ended_at: end,
+            total_focus_minutes,
+            total_break_minutes,
+            completed_cycles,
+            attendance: attendance.to_vec(),
+            final_participants,
+        }
+    }
+
+    /// Active and away time of one participant within `[start, end]`.
+    fn attendance_durations(
+        participant: &Participant,
+        attendance: &[AttendanceEntry],
+        start: DateTime<Utc>,
+        end: DateTime<Utc>,
+    ) -> (Duration, Duration) {
+        let mut events: Vec<(DateTime<Utc>, &AttendanceEvent)> = attendance
+            .iter()
+            .filter(|e| e.participant_id == participant.id)
+            .map(|e| (e.timestamp, &e.event))
+            .collect();
+        if !events
+            .iter()
+            .any(|(_, e)| matches!(e, AttendanceEvent::Joined))
+        {
+            events.push((participant.joined_at, &AttendanceEvent::Joined));
+        }
+        events.sort_by_key(|(at, _)| *at);
+
+        let mut presence = Presence::NotJoined;
+        let mut active = Duration::zero();
+        let mut away = Duration::zero();
+        // Time since leaving; becomes away time only if the participant returns
+        let mut pending = Duration::zero();
+        let mut last = start;
+
+        for (at, event) in events {
+            let at = at.clamp(start, end);
+            let elapsed = at - last;
+            last = at;
+            match presence {
+                Presence::Active => active += elapsed,
+                Presence::Away => away += elapsed,
+                Presence::Left => pending += elapsed,
+                Presence::NotJoined => {}
+            }
+
+            presence = match (presence, event) {
+                (
+                    Presence::NotJoined | Presence::Away | Presence::Left,
+                    AttendanceEvent::Joined | AttendanceEvent::Returned | AttendanceEvent::Rejoined,
+                ) => {
+                    if presence == Presence::Left {
+                        away += pending;
+                    }
+                    pending = Duration::zero();
+                    Presence::Active
+                }
+                (Presence::Active, AttendanceEvent::Away | AttendanceEvent::OptedOut) => {
+                    Presence::Away
+                }
+                (Presence::Active | Presence::Away, AttendanceEvent::Left) => Presence::Left,
+                (unchanged, _) => unchanged,
+            };
+        }
+
+        match presence {
+            Presence::Active => active += end - last,
+            Presence::Away => away += end - last,
+            Presence::Left | Presence::NotJoined => {}
+        }
+        (active, away)
+    }
 }
 
 /// Manager for shared session rooms.
@@ -323,6 +556,7 @@ impl PairFocusManager {
                 left_at: None,
                 vote: Some(Vote::Pending),
                 opt_outs: Vec::new(),
+                votes_cast: 0,
             },
         );
 
@@ -336,6 +570,8 @@ impl PairFocusManager {
             created_at: now,
             session_started_at: None,
             session_ended_at: None,
+            current_phase: None,
+            phase_started_at: None,
         };
 
         self.rooms.insert(room_id.clone(), room);
@@ -368,6 +604,7 @@ impl PairFocusManager {
                 left_at: None,
                 vote: Some(Vote::Pending),
                 opt_outs: Vec::new(),
+                votes_cast: 0,
             },
         );
 
@@ -428,6 +665,9 @@ impl PairFocusManager {
             .get_mut(participant_id)
             .ok_or(PairFocusError::ParticipantNotFound)?;
 
+        if vote != Vote::Pending {
+            participant.votes_cast += 1;
+        }
         participant.vote = Some(vote);
         Ok(())
     }
@@ -480,8 +720,11 @@ impl PairFocusManager {
             return Err(PairFocusError::NotEnoughParticipants);
         }
 
+        let now = Utc::now();
         room.state = RoomState::FocusActive;
-        room.session_started_at = Some(Utc::now());
+        room.session_started_at = Some(now);
+        room.current_phase = Some(SessionPhase::Focus);
+        room.phase_started_at = Some(now);
 
         // Reset votes
         for participant in room.participants.values_mut() {
@@ -515,6 +758,12 @@ impl PairFocusManager {
         } else {
             RoomState::BreakActive
         };
+        room.current_phase = Some(if is_long_break {
+            SessionPhase::LongBreak
+        } else {
+            SessionPhase::ShortBreak
+        });
+        room.phase_started_at = Some(Utc::now());
 
         // Reset votes
         for participant in room.participants.values_mut() {
@@ -601,57 +850,34 @@ impl PairFocusManager {
         Ok(())
     }
 
+    /// Bring a dropped participant back into a running session.
+    ///
+    /// See [`SharedSessionRoom::reconnect_participant`]; the rejoin is
+    /// added to the attendance log.
+    pub fn reconnect(
+        &mut self,
+        room_id: &RoomId,
+        participant_id: &ParticipantId,
+    ) -> Result<PhaseSync, PairFocusError> {
+        let room = self.rooms.get_mut(room_id).ok_or(PairFocusError::RoomNotFound)?;
+        let (sync, entry) = room.reconnect_participant(participant_id, Utc::now())?;
+        if let Some(log) = self.attendance_logs.get_mut(room_id) {
+            log.push(entry);
+        }
+        Ok(sync)
+    }
+
     /// End a session.
     pub fn end_session(&mut self, room_id: &RoomId) -> Result<SessionSummary, PairFocusError> {
         let room = self.rooms.get_mut(room_id).ok_or(PairFocusError::RoomNotFound)?;
 
         room.state = RoomState::Ended;
         room.session_ended_at = Some(Utc::now());
-
-        let start = room.session_started_at.unwrap_or(room.created_at);
-        let end = room.session_ended_at.unwrap_or(Utc::now());
-
-        // Calculate focus/break time (simplified)
-        let total_minutes = (end - start).num_minutes().max(0);
-        let cycle_minutes = room.policy.focus_duration_minutes + room.policy.break_duration_minutes;
-        let completed_cycles = (total_minutes / cycle_minutes) as u32;
-        let total_focus_minutes = completed_cycles as i64 * room.policy.focus_duration_minutes;
-        let total_break_minutes = completed_cycles as i64 * room.policy.break_duration_minutes;
+        room.current_phase = None;
+        room.phase_started_at = None;
 
         let attendance = self.attendance_logs.get(room_id).cloned().unwrap_or_default();
-
-        let final_participants: Vec<ParticipantSummary> = room
-            .participants
-            .values()
-            .map(|p| {
-                let focus_time = if p.status == ParticipantStatus::Active
-                    || p.status == ParticipantStatus::Left
-                {
-                    total_focus_minutes
-                } else {
-                    0
-                };
-                ParticipantSummary {
-                    id: p.id.clone(),
-                    name: p.name.clone(),
-                    total_focus_minutes: focus_time,
-                    total_break_minutes: total_break_minutes,
-                    opt_out_count: p.opt_outs.len(),
-                }
-            })
-            .collect();
-
-        Ok(SessionSummary {
-            room_id: room.id.clone(),
-            room_name: room.name.clone(),
-            started_at: start,
-            ended_at: end,
-            total_focus_minutes,
-            total_break_minutes,
-            completed_cycles,
-            attendance,
-            final_participants,
-        })
+        Ok(room.compute_summary(&attendance))
     }
 
     /// Get room by ID.
@@ -684,6 +910,10 @@ pub enum PairFocusError {
     NotInFocusSession,
     OptOutNotAllowed,
     NotOptedOut,
+    /// Only participants who left or stepped away can reconnect
+    NotDisconnected,
+    /// The session ended before the participant reconnected
+    RejoinAfterSessionEnded { ended_at: DateTime<Utc> },
 }
 
 #[cfg(test)]
@@ -949,4 +1179,172 @@ mod tests {
         assert!(summary.attendance.iter().any(|e| matches!(e.event, AttendanceEvent::OptedOut)));
         assert!(summary.attendance.iter().any(|e| matches!(e.event, AttendanceEvent::Rejoined)));
     }
+
+    #[test]
+    fn test_compute_summary_excludes_away_time() {
+        use chrono::TimeZone;
+
+        let at = |minute: u32| Utc.with_ymd_and_hms(2026, 1, 5, 10, minute, 0).unwrap();
+        let participant = |id: &str, joined_at| Participant {
+            id: id.to_string(),
+            name: id.to_string(),
+            status: ParticipantStatus::Active,
+            joined_at,
+            left_at: None,
+            vote: None,
+            opt_outs: Vec::new(),
+            votes_cast: 0,
+        };
+        let entry = |id: &str, event, timestamp| AttendanceEntry {
+            participant_id: id.to_string(),
+            participant_name: id.to_string(),
+            event,
+            timestamp,
+        };
+
+        let mut creator = participant("alice", at(0) - Duration::minutes(5));
+        creator.votes_cast = 2;
+        let room = SharedSessionRoom {
+            id: "room".to_string(),
+            name: "Pairing".to_string(),
+            creator_id: "alice".to_string(),
+            policy: SharedPolicy::default(),
+            state: RoomState::Ended,
+            participants: HashMap::from([
+                ("alice".to_string(), creator),
+                ("bob".to_string(), participant("bob", at(40))),
+            ]),
+            created_at: at(0) - Duration::minutes(5),
+            session_started_at: Some(at(0)),
+            session_ended_at: Some(at(0) + Duration::minutes(60)),
+            current_phase: None,
+            phase_started_at: None,
+        };
+
+        // Bob joins late, leaves for a break and rejoins; the duplicate join is ignored
+        let attendance = vec![
+            entry("bob", AttendanceEvent::Joined, at(5)),
+            entry("alice", AttendanceEvent::Away, at(20)),
+            entry("alice", AttendanceEvent::Returned, at(25)),
+            entry("bob", AttendanceEvent::Left, at(30)),
+            entry("bob", AttendanceEvent::Joined, at(40)),
+            entry("bob", AttendanceEvent::Joined, at(41)),
+        ];
+
+        let summary = room.compute_summary(&attendance);
+        assert_eq!(summary.completed_cycles, 2);
+
+        let alice = &summary.final_participants[0];
+        assert_eq!(alice.active_minutes, 55);
+        assert_eq!(alice.away_minutes, 5);
+        assert_eq!(alice.votes_cast, 2);
+
+        let bob = &summary.final_participants[1];
+        assert_eq!(bob.id, "bob");
+        assert_eq!(bob.active_minutes, 45);
+        assert_eq!(bob.total_focus_minutes, 45);
+        assert_eq!(bob.away_minutes, 10);
+    }
+
+    #[test]
+    fn test_dropped_participant_rejoins_with_remaining_focus_time() {
+        let mut manager = make_manager();
+        let policy = SharedPolicy {
+            require_consensus: true,
+            ..SharedPolicy::default()
+        };
+        let room_id = manager.create_room(
+            "Test Room".to_string(),
+            "user1".to_string(),
+            "Alice".to_string(),
+            policy,
+        );
+        manager
+            .join_room(&room_id, "user2".to_string(), "Bob".to_string())
+            .unwrap();
+        manager.cast_vote(&room_id, &"user1".to_string(), Vote::Agree).unwrap();
+        manager.cast_vote(&room_id, &"user2".to_string(), Vote::Agree).unwrap();
+        manager.start_focus(&room_id).unwrap();
+
+        // Bob agrees to break, then drops; Alice alone reaches consensus
+        manager.cast_vote(&room_id, &"user2".to_string(), Vote::Agree).unwrap();
+        manager.leave_room(&room_id, &"user2".to_string()).unwrap();
+        manager.cast_vote(&room_id, &"user1".to_string(), Vote::Agree).unwrap();
+        assert!(manager.check_consensus(&room_id).unwrap());
+
+        // Ten minutes into a 25 minute focus
+        let room = manager.rooms.get_mut(&room_id).unwrap();
+        let started_at = Utc::now() - Duration::minutes(10);
+        room.phase_started_at = Some(started_at);
+
+        let (sync, entry) = room
+            .reconnect_participant(&"user2".to_string(), started_at + Duration::minutes(10))
+            .unwrap();
+        assert_eq!(sync.state, RoomState::FocusActive);
+        assert_eq!(sync.phase, Some(SessionPhase::Focus));
+        assert_eq!(sync.phase_ends_at, Some(started_at + Duration::minutes(25)));
+        assert_eq!(sync.remaining_seconds, 15 * 60);
+        assert!(matches!(entry.event, AttendanceEvent::Rejoined));
+
+        // The vote cast before dropping no longer counts
+        let bob = &room.participants["user2"];
+        assert_eq!(bob.status, ParticipantStatus::Active);
+        assert_eq!(bob.vote, Some(Vote::Pending));
+        assert!(!manager.check_consensus(&room_id).unwrap());
+
+        manager.leave_room(&room_id, &"user2".to_string()).unwrap();
+        let sync = manager.reconnect(&room_id, &"user2".to_string()).unwrap();
+        assert_eq!(sync.phase, Some(SessionPhase::Focus));
+        assert!(sync.remaining_seconds <= 15 * 60);
+        assert!(matches!(
+            manager.attendance_logs[&room_id].last().unwrap().event,
+            AttendanceEvent::Rejoined
+        ));
+
+        manager.end_session(&room_id).unwrap();
+        assert!(matches!(
+            manager.reconnect(&room_id, &"user2".to_string()),
+            Err(PairFocusError::RejoinAfterSessionEnded { .. })
+        ));
+    }
+
+    #[test]
+    fn test_reconnect_requires_dropped_participant() {
+        let mut manager = make_manager();
+        let room_id = manager.create_room(
+            "Test Room".to_string(),
+            "user1".to_string(),
+            "Alice".to_string(),
+            SharedPolicy::default(),
+        );
+        manager
+            .join_room(&room_id, "user2".to_string(), "Bob".to_string())
+            .unwrap();
+        let log_len = manager.attendance_logs[&room_id].len();
+
+        // Still active
+        assert!(matches!(
+            manager.reconnect(&room_id, &"user2".to_string()),
+            Err(PairFocusError::NotDisconnected)
+        ));
+
+        // Opted out participants come back through `rejoin` instead
+        manager
+            .rooms
+            .get_mut(&room_id)
+            .unwrap()
+            .participants
+            .get_mut("user2")
+            .unwrap()
+            .status = ParticipantStatus::OptedOut;
+        assert!(matches!(
+            manager.reconnect(&room_id, &"user2".to_string()),
+            Err(PairFocusError::NotDisconnected)
+        ));
+        assert_eq!(
+            manager.rooms[&room_id].participants["user2"].status,
+            ParticipantStatus::OptedOut
+        );
+        assert_eq!(manager.attendance_logs[&room_id].len(), log_len);
+    }
 }
@@ -1,11 +1,14 @@
 //! Policy bundle for import/export functionality.
 //!
 //! A policy bundle contains timer configuration that can be exported to JSON
-//! and imported with semantic versioning compatibility checks.
+//! and imported with semantic versioning compatibility checks. Bundles shared
+//! with others can be signed with an HMAC-SHA256 key so tampering is detected
+//! on import; unsigned bundles remain valid for local use.
 
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 
+use crate::calendar::{hmac_sha256_hex, signatures_match, SignatureError};
 use crate::storage::Config;
 use crate::timer::Schedule;
 
@@ -29,6 +32,12 @@ pub struct PolicyMetadata {
     pub notes: String,
     /// When this policy was created.
     pub created_at: DateTime<Utc>,
+    /// Identifier of whoever signed the bundle.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub signer: Option<String>,
+    /// When the bundle was signed.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub signed_at: Option<DateTime<Utc>>,
 }
 
 impl Default for PolicyMetadata {
@@ -39,6 +48,8 @@ impl Default for PolicyMetadata {
             intent: String::new(),
             notes: String::new(),
             created_at: Utc::now(),
+            signer: None,
+            signed_at: None,
         }
     }
 }
@@ -80,6 +91,33 @@ pub struct PolicyBundle {
     pub metadata: PolicyMetadata,
     /// The actual policy settings.
     pub policy: PolicyData,
+    /// Hex-encoded HMAC-SHA256 over the rest of the bundle, if signed.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub signature: Option<String>,
+}
+
+/// How import treats a signature that cannot be verified.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SignatureMode {
+    /// Report the problem and let the import proceed.
+    #[default]
+    Warn,
+    /// Refuse the bundle.
+    Reject,
+}
+
+/// Outcome of checking a bundle's signature.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SignatureStatus {
+    /// The bundle carries no signature.
+    Unsigned,
+    /// The signature matches the verification key.
+    Verified { signer: String },
+    /// The bundle is signed but no verification key was supplied.
+    Unverified { signer: String },
+    /// The signature does not match; the bundle was altered or signed with another key.
+    Invalid { signer: String },
 }
 
 impl PolicyBundle {
@@ -105,6 +143,7 @@ impl PolicyBundle {
                 pomodoros_before_long_break,
                 custom_schedule,
             },
+            signature: None,
         }
     }
 
@@ -127,6 +166,7 @@ impl PolicyBundle {
                 pomodoros_before_long_break,
                 custom_schedule,
             },
+            signature: None,
         }
     }
 
@@ -146,6 +186,58 @@ impl PolicyBundle {
         serde_json::from_str(json)
     }
 
+    /// Whether the bundle carries a signature.
+    pub fn is_signed(&self) -> bool {
+        self.signature.is_some()
+    }
+
+    /// Sign the bundle as `signer`, replacing any previous signature.
+    ///
+    /// The signature covers the version, metadata (including signer and
+    /// signing time) and policy settings.
+    pub fn sign(&mut self, signer: &str, key: &[u8]) {
+        self.metadata.signer = Some(signer.to_string());
+        self.metadata.signed_at = Some(Utc::now());
+        self.signature = Some(self.compute_signature(key));
+    }
+
+    /// Verify the signature against `key`.
+    ///
+    /// # Errors
+    /// Returns `SignatureError::MissingField` for an unsigned bundle and
+    /// `SignatureError::VerificationFailed` if the signature does not match.
+    pub fn verify(&self, key: &[u8]) -> Result<(), SignatureError> {
+        let signature = self
+            .signature
+            .as_deref()
+            .ok_or_else(|| SignatureError::MissingField("signature".to_string()))?;
+        if signatures_match(signature, &self.compute_signature(key)) {
+            Ok(())
+        } else {
+            Err(SignatureError::VerificationFailed)
+        }
+    }
+
+    /// Check the signature before import.
+    ///
+    /// See `check_raw_signature`, which this applies to the bundle's JSON.
+    ///
+    /// # Errors
+    /// As for `check_raw_signature`.
+    pub fn check_signature(
+        &self,
+        key: Option<&[u8]>,
+        mode: SignatureMode,
+    ) -> Result<SignatureStatus, SignatureError> {
+        let raw = serde_json::to_value(self).expect("policy bundle is serializable");
+        check_raw_signature(&raw, key, mode)
+    }
+
+    fn compute_signature(&self, key: &[u8]) -> String {
+        let raw = serde_json::to_value(self).expect("policy bundle is serializable");
+        raw_signature(&raw, key)
+    }
+
     /// Apply this policy to a config, overwriting schedule settings.
     pub fn apply_to_config(&self, config: &mut Config) {
         config.schedule.focus_duration = self.policy.focus_duration;
@@ -156,12 +248,70 @@ impl PolicyBundle {
     }
 }
 
+/// Check the signature of a bundle as read from disk, before migration.
+///
+/// Older bundles are signed in their own layout, so the signature must be
+/// checked on the raw JSON; `migrate_bundle` rewrites fields it covers.
+/// Unsigned bundles are accepted only in `SignatureMode::Warn` without a
+/// `key`; otherwise stripping the signature would bypass verification.
+/// A signed bundle that cannot be verified, because `key` is missing or
+/// the signature does not match, is reported in `SignatureMode::Warn` and
+/// refused in `SignatureMode::Reject`.
+///
+/// # Errors
+/// Returns a `SignatureError` when the bundle is unsigned but a `key` was
+/// given or `mode` is `Reject`, and when `mode` is `Reject` and the
+/// signature could not be verified.
+pub fn check_raw_signature(
+    bundle: &serde_json::Value,
+    key: Option<&[u8]>,
+    mode: SignatureMode,
+) -> Result<SignatureStatus, SignatureError> {
+    let Some(signature) = bundle.get("signature").and_then(|s| s.as_str()) else {
+        return match (mode, key) {
+            (SignatureMode::Warn, None) => Ok(SignatureStatus::Unsigned),
+            _ => Err(SignatureError::MissingField("signature".to_string())),
+        };
+    };
+    let signer = bundle
+        .pointer("/metadata/signer")
+        .and_then(|s| s.as_str())
+        .unwrap_or_default()
+        .to_string();
+
+    let status = match key {
+        None => SignatureStatus::Unverified { signer },
+        Some(key) if signatures_match(signature, &raw_signature(bundle, key)) => {
+            return Ok(SignatureStatus::Verified { signer })
+        }
+        Some(_) => SignatureStatus::Invalid { signer },
+    };
+    match (mode, &status) {
+        (SignatureMode::Reject, SignatureStatus::Unverified { .. }) => {
+            Err(SignatureError::MissingField("verification key".to_string()))
+        }
+        (SignatureMode::Reject, _) => Err(SignatureError::VerificationFailed),
+        (SignatureMode::Warn, _) => Ok(status),
+    }
+}
+
+/// HMAC over the bundle's JSON without its `signature` field.
+fn raw_signature(bundle: &serde_json::Value, key: &[u8]) -> String {
+    let mut unsigned = bundle.clone();
+    if let Some(fields) = unsigned.as_object_mut() {
+        fields.remove("signature");
+    }
+    let payload = serde_json::to_vec(&unsigned).expect("JSON values are serializable");
+    hmac_sha256_hex(&payload, key)
+}
+
 impl Default for PolicyBundle {
     fn default() -> Self {
         Self {
             version: POLICY_VERSION.to_string(),
             metadata: PolicyMetadata::default(),
             policy: PolicyData::default(),
+            signature: None,
         }
     }
 }
@@ -365,6 +515,8 @@ mod tests {
             intent: "Maximize deep work sessions".to_string(),
             notes: "Best used in the morning".to_string(),
             created_at: "2024-06-01T08:00:00Z".parse().unwrap(),
+            signer: None,
+            signed_at: None,
         };
 
         let bundle = PolicyBundle::with_metadata(
@@ -415,6 +567,7 @@ mod tests {
                 pomodoros_before_long_break: 2,
                 custom_schedule: None,
             },
+            signature: None,
         };
 
         let mut config = Config::default();
@@ -425,4 +578,103 @@ mod tests {
         assert_eq!(config.schedule.long_break, 30);
         assert_eq!(config.schedule.pomodoros_before_long_break, 2);
     }
+
+    #[test]
+    fn signed_bundle_verifies_and_detects_tampering() {
+        let key = crate::calendar::generate_signing_key("community-secret");
+        let mut bundle = PolicyBundle::new("Shared".to_string(), 50, 10, 30, 3, None);
+        bundle.sign("alice@example.com", &key);
+
+        assert!(bundle.is_signed());
+        assert_eq!(bundle.metadata.signer.as_deref(), Some("alice@example.com"));
+        assert!(bundle.metadata.signed_at.is_some());
+
+        // Survives a JSON round trip
+        let restored = PolicyBundle::from_json(&bundle.to_json().unwrap()).unwrap();
+        assert!(restored.verify(&key).is_ok());
+        assert!(matches!(
+            restored.verify(b"other key"),
+            Err(SignatureError::VerificationFailed)
+        ));
+
+        let mut tampered = restored.clone();
+        tampered.policy.focus_duration = 90;
+        assert!(matches!(
+            tampered.verify(&key),
+            Err(SignatureError::VerificationFailed)
+        ));
+        let mut tampered = restored;
+        tampered.metadata.signer = Some("mallory".to_string());
+        assert!(tampered.verify(&key).is_err());
+
+        assert_eq!(
+            tampered
+                .check_signature(Some(&key), SignatureMode::Warn)
+                .unwrap(),
+            SignatureStatus::Invalid {
+                signer: "mallory".to_string()
+            }
+        );
+        assert!(tampered
+            .check_signature(Some(&key), SignatureMode::Reject)
+            .is_err());
+    }
+
+    #[test]
+    fn unsigned_bundle_passes_signature_check_only_when_not_required() {
+        let bundle = PolicyBundle::default();
+        assert!(!bundle.to_json().unwrap().contains("signature"));
+        assert!(matches!(
+            bundle.verify(b"key"),
+            Err(SignatureError::MissingField(_))
+        ));
+        assert_eq!(
+            bundle.check_signature(None, SignatureMode::Warn).unwrap(),
+            SignatureStatus::Unsigned
+        );
+        assert!(bundle.check_signature(None, SignatureMode::Reject).is_err());
+        assert!(bundle
+            .check_signature(Some(b"key"), SignatureMode::Warn)
+            .is_err());
+
+        let mut signed = bundle;
+        signed.sign("alice", b"key");
+        assert_eq!(
+            signed.check_signature(None, SignatureMode::Warn).unwrap(),
+            SignatureStatus::Unverified {
+                signer: "alice".to_string()
+            }
+        );
+        assert!(signed.check_signature(None, SignatureMode::Reject).is_err());
+        assert_eq!(
+            signed
+                .check_signature(Some(b"key"), SignatureMode::Reject)
+                .unwrap(),
+            SignatureStatus::Verified {
+                signer: "alice".to_string()
+            }
+        );
+    }
+
+    #[test]
+    fn stripped_signature_does_not_bypass_verification() {
+        let key = b"key";
+        let mut bundle = PolicyBundle::default();
+        bundle.sign("alice", key);
+
+        // Tamper with the policy and drop the signature to hide it
+        let mut stripped = bundle;
+        stripped.policy.focus_duration = 90;
+        stripped.signature = None;
+        stripped.metadata.signer = None;
+        assert!(!stripped.is_signed());
+
+        assert!(stripped.check_signature(None, SignatureMode::Reject).is_err());
+        assert!(stripped
+            .check_signature(Some(key), SignatureMode::Reject)
+            .is_err());
+        assert!(stripped
+            .check_signature(Some(key), SignatureMode::Warn)
+            .is_err());
+    }
 }
@@ -11,7 +11,7 @@ use serde::{Deserialize, Serialize};
 
 use super::bundle::{PolicyBundle, PolicyMetadata, POLICY_VERSION};
 use crate::storage::{Config, ScheduleConfig};
-use crate::timer::{Schedule, Step, StepType};
+use crate::timer::{Schedule, StepType};
 
 /// Validation constraints for policy values.
 pub mod constraints {
@@ -393,36 +393,13 @@ impl PolicyEditor {
     }
 
     fn generate_schedule_from_config(&self) -> Schedule {
-        let focus = self.schedule.focus_duration;
-        let short_break = self.schedule.short_break;
-        let long_break = self.schedule.long_break;
-        let pomodoros = self.schedule.pomodoros_before_long_break;
-
-        let mut steps = Vec::new();
-        for i in 0..pomodoros {
-            steps.push(Step {
-                step_type: StepType::Focus,
-                duration_min: focus as u64,
-                label: format!("Focus {}", i + 1),
-                description: String::new(),
-            });
-            let is_long_break = (i + 1) as u32 % pomodoros == 0;
-            steps.push(Step {
-                step_type: StepType::Break,
-                duration_min: if is_long_break {
-                    long_break
-                } else {
-                    short_break
-                } as u64,
-                label: if is_long_break {
-                    "Long Break".to_string()
-                } else {
-                    "Short Break".to_string()
-                },
-                description: String::new(),
-            });
-        }
-        Schedule::new(steps).unwrap_or_else(|_| Schedule::default_progressive())
+        Schedule::pomodoro(
+            self.schedule.focus_duration,
+            self.schedule.short_break,
+            self.schedule.long_break,
+            self.schedule.pomodoros_before_long_break,
+        )
+        .unwrap_or_else(|_| Schedule::default_progressive())
     }
 
     /// Apply the policy to a config.
@@ -568,6 +545,7 @@ fn minutes_to_time(minutes: u64) -> NaiveTime {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::timer::Step;
 
     #[test]
     fn editor_starts_with_defaults() {
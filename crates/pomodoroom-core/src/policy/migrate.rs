@@ -0,0 +1,223 @@
+//! Upgrades older policy bundles to the current schema on import.
+//!
+//! Bundles are migrated as raw JSON because an older layout may not
+//! deserialize into the current `PolicyBundle`. Each step upgrades a bundle
+//! by one major version; steps run in order until the bundle reaches
+//! `POLICY_VERSION`. Bundles older than `MIN_MIGRATABLE_VERSION` are refused.
+
+use serde_json::{Map, Value};
+
+use super::bundle::{PolicyBundle, POLICY_VERSION};
+use super::compat::parse_version;
+
+/// Oldest bundle version that can still be migrated.
+pub const MIN_MIGRATABLE_VERSION: &str = "0.9.0";
+
+/// Errors when migrating a policy bundle.
+#[derive(Debug, thiserror::Error)]
+pub enum PolicyMigrationError {
+    #[error("Invalid policy bundle JSON: {0}")]
+    Json(#[from] serde_json::Error),
+
+    #[error("Invalid policy version {0:?}")]
+    InvalidVersion(String),
+
+    #[error(
+        "Policy version {version} is too old to migrate (oldest supported is {MIN_MIGRATABLE_VERSION})"
+    )]
+    TooOld { version: String },
+}
+
+/// A migration step upgrading a bundle from its major version to the next.
+type MigrationStep = fn(&mut Map<String, Value>);
+
+/// Migration steps keyed by the major version they upgrade from.
+const MIGRATIONS: &[(u32, MigrationStep)] = &[(0, migrate_v0_to_v1)];
+
+/// Upgrade `bundle` to the current policy schema.
+///
+/// Bundles at or above the current major version are only deserialized, so
+/// `check_compatibility` still reports newer versions. Older bundles are
+/// upgraded step by step and stamped with `POLICY_VERSION`.
+///
+/// # Errors
+/// Returns `PolicyMigrationError::TooOld` for bundles older than
+/// `MIN_MIGRATABLE_VERSION`, `InvalidVersion` if the version is missing or
+/// malformed, and `Json` if the upgraded bundle does not deserialize.
+pub fn migrate_bundle(mut bundle: Value) -> Result<PolicyBundle, PolicyMigrationError> {
+    let version = bundle
+        .get("version")
+        .and_then(Value::as_str)
+        .unwrap_or_default()
+        .to_string();
+    let parsed = parse_version(&version)
+        .ok_or_else(|| PolicyMigrationError::InvalidVersion(version.clone()))?;
+    let floor = parse_version(MIN_MIGRATABLE_VERSION).expect("floor is valid semver");
+    let (current_major, _, _) =
+        parse_version(POLICY_VERSION).expect("POLICY_VERSION is valid semver");
+
+    if parsed < floor {
+        return Err(PolicyMigrationError::TooOld { version });
+    }
+
+    if parsed.0 < current_major {
+        let fields = bundle
+            .as_object_mut()
+            .ok_or_else(|| PolicyMigrationError::InvalidVersion(version.clone()))?;
+        for (from_major, step) in MIGRATIONS {
+            if *from_major >= parsed.0 {
+                step(fields);
+            }
+        }
+        fields.insert("version".to_string(), Value::from(POLICY_VERSION));
+    }
+
+    Ok(serde_json::from_value(bundle)?)
+}
+
+/// 0.x bundles come from the draft export format.
+///
+/// Metadata strings were nullable, the long break cadence was called
+/// `long_break_interval`, and `parallel_break_policy` has since moved out of
+/// the policy into scheduler config.
+fn migrate_v0_to_v1(bundle: &mut Map<String, Value>) {
+    let metadata = object_entry(bundle, "metadata");
+    if !metadata.contains_key("name") {
+        metadata.insert("name".to_string(), Value::from("Unnamed Policy"));
+    }
+    if !metadata.contains_key("created_at") {
+        metadata.insert(
+            "created_at".to_string(),
+            Value::from(chrono::Utc::now().to_rfc3339()),
+        );
+    }
+    for field in ["author", "intent", "notes"] {
+        if metadata.get(field).is_none_or(Value::is_null) {
+            metadata.insert(field.to_string(), Value::from(""));
+        }
+    }
+
+    let policy = object_entry(bundle, "policy");
+    if let Some(interval) = policy.remove("long_break_interval") {
+        policy
+            .entry("pomodoros_before_long_break")
+            .or_insert(interval);
+    }
+    policy.remove("parallel_break_policy");
+}
+
+fn object_entry<'a>(bundle: &'a mut Map<String, Value>, key: &str) -> &'a mut Map<String, Value> {
+    let entry = bundle
+        .entry(key)
+        .or_insert_with(|| Value::Object(Map::new()));
+    if !entry.is_object() {
+        *entry = Value::Object(Map::new());
+    }
+    entry
+        .as_object_mut()
+        .expect("entry was just made an object")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::policy::{
+        check_compatibility, check_raw_signature, Compatibility, PolicyEditor, SignatureMode,
+        SignatureStatus,
+    };
+    use serde_json::json;
+
+    #[test]
+    fn draft_bundle_upgrades_to_current_schema() {
+        let old = json!({
+            "version": "0.9.2",
+            "metadata": {
+                "name": "Deep Work",
+                "author": null,
+                "intent": "Long focus blocks",
+                "notes": null,
+                "created_at": "2026-01-10T09:00:00Z"
+            },
+            "policy": {
+                "focus_duration": 50,
+                "short_break": 10,
+                "long_break": 30,
+                "long_break_interval": 3,
+                "parallel_break_policy": "shared",
+                "custom_schedule": null
+            }
+        });
+
+        let bundle = migrate_bundle(old).unwrap();
+        assert_eq!(bundle.version, POLICY_VERSION);
+        assert_eq!(bundle.metadata.name, "Deep Work");
+        assert_eq!(bundle.metadata.author, "");
+        assert_eq!(bundle.policy.focus_duration, 50);
+        assert_eq!(bundle.policy.pomodoros_before_long_break, 3);
+        assert!(matches!(
+            check_compatibility(POLICY_VERSION, &bundle.version),
+            Compatibility::Compatible
+        ));
+
+        let mut editor = PolicyEditor::new();
+        editor.import_bundle(&bundle).unwrap();
+        assert!(editor.validate().is_valid);
+    }
+
+    #[test]
+    fn signed_draft_bundle_is_verified_before_migration() {
+        let key = b"team-key";
+        let mut old = json!({
+            "version": "0.9.1",
+            "metadata": {
+                "name": "Team Focus",
+                "author": null,
+                "created_at": "2026-01-10T09:00:00Z",
+                "signer": "lead"
+            },
+            "policy": {
+                "focus_duration": 45,
+                "short_break": 5,
+                "long_break": 20,
+                "long_break_interval": 4
+            }
+        });
+        let payload = serde_json::to_vec(&old).unwrap();
+        old["signature"] = Value::from(crate::calendar::hmac_sha256_hex(&payload, key));
+
+        assert_eq!(
+            check_raw_signature(&old, Some(key), SignatureMode::Reject).unwrap(),
+            SignatureStatus::Verified {
+                signer: "lead".to_string()
+            }
+        );
+
+        // Migration rewrites signed fields, so the upgraded bundle no longer verifies
+        let migrated = migrate_bundle(old.clone()).unwrap();
+        assert!(migrated
+            .check_signature(Some(key), SignatureMode::Reject)
+            .is_err());
+
+        old["policy"]["focus_duration"] = Value::from(90);
+        assert!(check_raw_signature(&old, Some(key), SignatureMode::Reject).is_err());
+    }
+
+    #[test]
+    fn current_and_too_old_bundles() {
+        let current = PolicyBundle::default();
+        let value = serde_json::to_value(&current).unwrap();
+        assert_eq!(migrate_bundle(value).unwrap(), current);
+
+        let ancient = json!({ "version": "0.3.0", "metadata": {}, "policy": {} });
+        assert!(matches!(
+            migrate_bundle(ancient),
+            Err(PolicyMigrationError::TooOld { .. })
+        ));
+
+        let unversioned = json!({ "metadata": {}, "policy": {} });
+        assert!(matches!(
+            migrate_bundle(unversioned),
+            Err(PolicyMigrationError::InvalidVersion(_))
+        ));
+    }
+}
@@ -2,6 +2,7 @@
 //!
 //! This module provides functionality for:
 //! - Exporting and importing timer policies with semantic versioning
+//! - Migrating bundles from older policy versions on import
 //! - Editing focus/break profiles with validation
 //! - Previewing generated day plans from policy settings
 
@@ -9,8 +10,12 @@ mod bundle;
 mod compat;
 mod editor;
 mod experiments;
+mod migrate;
 
-pub use bundle::{PolicyBundle, PolicyData, PolicyMetadata, POLICY_VERSION};
+pub use bundle::{
+    check_raw_signature, PolicyBundle, PolicyData, PolicyMetadata, SignatureMode, SignatureStatus,
+    POLICY_VERSION,
+};
 pub use compat::{check_compatibility, parse_version, Compatibility};
 pub use editor::{
     constraints, DayPlanPreview, EditorMetadata, PolicyEditor, StepPreview, ValidationError,
@@ -21,3 +26,4 @@ pub use experiments::{
     ExperimentStatus, ExperimentSummary, ExperimentVariant, NotificationPolicyConfig,
     NotificationStyle, RandomizationStrategy, VariantId, VariantMetrics, VariantSummary,
 };
+pub use migrate::{migrate_bundle, PolicyMigrationError, MIN_MIGRATABLE_VERSION};
@@ -13,6 +13,21 @@ pub enum Action {
         /// Duration of the break in minutes
         duration_mins: u32,
     },
+    /// Extend the running focus session
+    #[serde(rename = "TimerExtend")]
+    TimerExtend {
+        /// Minutes to add to the remaining time
+        minutes: u32,
+    },
+    /// End the running focus session and move on to the break
+    #[serde(rename = "TimerSkip")]
+    TimerSkip,
+    /// Ask an integration to sync with its service
+    #[serde(rename = "IntegrationSync")]
+    IntegrationSync {
+        /// Integration name (e.g. "google", "notion")
+        service: String,
+    },
 }
 
 impl Action {
@@ -25,14 +40,26 @@ impl Action {
             Action::CreateBreak { duration_mins } => {
                 format!("Create {} minute break [placeholder - not yet implemented]", duration_mins)
             }
+            Action::TimerExtend { minutes } => {
+                format!("Extend current focus by {} minutes", minutes)
+            }
+            Action::TimerSkip => "Skip to break".to_string(),
+            Action::IntegrationSync { service } => format!("Sync {}", service),
         }
     }
 
+    /// Whether this action operates on the timer engine
+    pub fn targets_timer(&self) -> bool {
+        matches!(self, Action::TimerExtend { .. } | Action::TimerSkip)
+    }
 
     /// Get the type name of this action
     pub fn type_name(&self) -> &'static str {
         match self {
             Action::CreateBreak { .. } => "CreateBreak",
+            Action::TimerExtend { .. } => "TimerExtend",
+            Action::TimerSkip => "TimerSkip",
+            Action::IntegrationSync { .. } => "IntegrationSync",
         }
     }
 }
@@ -55,4 +82,15 @@ mod tests {
         assert!(action.description().contains("Create 10 minute break"));
         assert!(action.description().contains("[placeholder"));
     }
+
+    #[test]
+    fn test_timer_action_serialize() {
+        let extend: Action = toml::from_str("type = \"TimerExtend\"\nminutes = 10").unwrap();
+        assert_eq!(extend, Action::TimerExtend { minutes: 10 });
+        assert!(extend.targets_timer());
+
+        let skip = toml::to_string(&Action::TimerSkip).unwrap();
+        assert!(skip.contains(r#"type = "TimerSkip""#));
+        assert!(!Action::CreateBreak { duration_mins: 5 }.targets_timer());
+    }
 }
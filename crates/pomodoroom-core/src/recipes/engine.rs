@@ -3,7 +3,7 @@
 //! Evaluates events against recipes and produces actions for execution.
 
 use crate::Event;
-use crate::recipes::{Action, Recipe, RecipeStore, Result};
+use crate::recipes::{explain, Action, Recipe, RecipeExplanation, RecipeStore, Result};
 
 /// Recipe engine that evaluates events and returns matching actions
 pub struct RecipeEngine {
@@ -40,6 +40,15 @@ impl RecipeEngine {
 
         Ok(results)
     }
+
+    /// Explain how every stored recipe, enabled or not, evaluates against an event
+    ///
+    /// Side-effect free: nothing is executed and disabled recipes are
+    /// reported with their match result.
+    pub fn explain(&self, event: &Event) -> Result<Vec<RecipeExplanation>> {
+        let recipes = self.store.load_all()?;
+        Ok(explain::explain_recipes(&recipes, event))
+    }
 }
 
 impl Default for RecipeEngine {
@@ -76,6 +85,8 @@ mod tests {
         let event = Event::TimerCompleted {
             step_index: 0,
             step_type: crate::timer::StepType::Focus,
+            planned_ms: 0,
+            actual_ms: 0,
             at: Utc::now(),
         };
 
@@ -109,6 +120,13 @@ mod tests {
         let actions = engine.evaluate_event(&event).unwrap();
         assert_eq!(actions.len(), 0);
 
+        // Explain still reports the disabled recipe's match
+        let report = engine.explain(&event).unwrap();
+        assert_eq!(report.len(), 1);
+        assert!(report[0].matched);
+        assert!(!report[0].enabled);
+        assert!(!report[0].would_fire);
+
         std::fs::remove_dir_all(temp_dir).unwrap();
     }
 }
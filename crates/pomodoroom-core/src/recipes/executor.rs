@@ -2,7 +2,9 @@
 //!
 //! Executes actions produced by the recipe engine and logs results.
 
+use crate::integrations::Integration;
 use crate::recipes::{action::Action, log::{ActionResult, ActionLog, ExecutionStatus}};
+use crate::timer::{TimerEngine, TimerState};
 
 /// Executes actions and logs results
 pub struct ActionExecutor {
@@ -22,11 +24,44 @@ impl ActionExecutor {
     }
 
     /// Execute a batch of actions and return the log
+    ///
+    /// Timer and integration actions are skipped because no engine or
+    /// integration is available; use `execute_batch_with_timer` or
+    /// `execute_batch_with_integrations` to run them.
     pub fn execute_batch(&self, actions: Vec<(String, Action)>) -> ActionLog {
+        self.run_batch(actions, None, &[])
+    }
+
+    /// Execute a batch of actions against `timer` and return the log
+    pub fn execute_batch_with_timer(
+        &self,
+        actions: Vec<(String, Action)>,
+        timer: &mut TimerEngine,
+    ) -> ActionLog {
+        self.run_batch(actions, Some(timer), &[])
+    }
+
+    /// Execute a batch of actions, dispatching integration actions to
+    /// `integrations` by name, and return the log
+    pub fn execute_batch_with_integrations(
+        &self,
+        actions: Vec<(String, Action)>,
+        integrations: &[&dyn Integration],
+    ) -> ActionLog {
+        self.run_batch(actions, None, integrations)
+    }
+
+    fn run_batch(
+        &self,
+        actions: Vec<(String, Action)>,
+        mut timer: Option<&mut TimerEngine>,
+        integrations: &[&dyn Integration],
+    ) -> ActionLog {
         let mut results = Vec::new();
 
         for (recipe_name, action) in actions {
-            let result = self.execute_action(&recipe_name, &action);
+            let result =
+                self.execute_action(&recipe_name, &action, timer.as_deref_mut(), integrations);
             results.push(result);
         }
 
@@ -34,7 +69,13 @@ impl ActionExecutor {
     }
 
     /// Execute a single action
-    fn execute_action(&self, recipe_name: &str, action: &Action) -> ActionResult {
+    fn execute_action(
+        &self,
+        recipe_name: &str,
+        action: &Action,
+        timer: Option<&mut TimerEngine>,
+        integrations: &[&dyn Integration],
+    ) -> ActionResult {
         let action_type = action.type_name().to_string();
 
         if self.dry_run {
@@ -47,7 +88,7 @@ impl ActionExecutor {
             };
         }
 
-        match action {
+        let status = match action {
             // CreateBreak action requires TimerEngine integration (#239)
             // This will be implemented when break session creation is needed
             Action::CreateBreak { duration_mins } => {
@@ -56,6 +97,68 @@ impl ActionExecutor {
                     duration_mins
                 )
             }
+            Action::TimerExtend { .. } | Action::TimerSkip => {
+                Self::execute_timer_action(action, timer)
+            }
+            Action::IntegrationSync { service } => {
+                Self::execute_integration_sync(service, integrations)
+            }
+        };
+
+        ActionResult {
+            recipe_name: recipe_name.to_string(),
+            action_type,
+            status,
+        }
+    }
+
+    /// Apply a timer action; a missing engine or idle timer is a no-op, not an error
+    fn execute_timer_action(action: &Action, timer: Option<&mut TimerEngine>) -> ExecutionStatus {
+        let Some(timer) = timer else {
+            return ExecutionStatus::Skipped {
+                reason: "no timer engine available".to_string(),
+            };
+        };
+        if timer.state() != TimerState::Running {
+            return ExecutionStatus::Skipped {
+                reason: "no timer running".to_string(),
+            };
+        }
+
+        match action {
+            Action::TimerExtend { minutes } => timer.extend(*minutes),
+            Action::TimerSkip => {
+                timer.skip_to_break();
+            }
+            Action::CreateBreak { .. } | Action::IntegrationSync { .. } => {
+                unreachable!("not a timer action")
+            }
+        }
+        ExecutionStatus::Success
+    }
+
+    /// Sync the named integration; a missing or signed-out integration is skipped
+    fn execute_integration_sync(
+        service: &str,
+        integrations: &[&dyn Integration],
+    ) -> ExecutionStatus {
+        let Some(integration) = integrations.iter().find(|i| i.name() == service) else {
+            return ExecutionStatus::Skipped {
+                reason: format!("integration '{}' not available", service),
+            };
+        };
+        if !integration.is_authenticated() {
+            return ExecutionStatus::Skipped {
+                reason: format!("integration '{}' not authenticated", service),
+            };
+        }
+
+        match integration.sync() {
+            Ok(()) => ExecutionStatus::Success,
+            Err(e) => ExecutionStatus::Failed {
+                reason: e.to_string(),
+                retriable: true,
+            },
         }
     }
 }
@@ -93,4 +196,107 @@ mod tests {
         // This should panic with unimplemented! message
         executor.execute_batch(actions);
     }
+
+    fn running_timer() -> TimerEngine {
+        let mut timer = TimerEngine::new();
+        timer.update_session(Some("task-1".to_string()), Some("Focus".to_string()), 25, 0);
+        timer
+    }
+
+    #[test]
+    fn test_executor_timer_actions_change_engine() {
+        let executor = ActionExecutor::new();
+
+        let mut timer = running_timer();
+        let before = timer.remaining_ms();
+        let log = executor.execute_batch_with_timer(
+            vec![("build-ready".to_string(), Action::TimerExtend { minutes: 10 })],
+            &mut timer,
+        );
+        assert_eq!(log.success_count(), 1);
+        assert!(timer.remaining_ms() >= before + 9 * 60_000);
+        assert_eq!(timer.total_ms(), 35 * 60_000);
+
+        let log = executor.execute_batch_with_timer(
+            vec![("skip".to_string(), Action::TimerSkip)],
+            &mut timer,
+        );
+        assert_eq!(log.success_count(), 1);
+        assert_eq!(timer.state(), TimerState::Drifting);
+        assert_eq!(timer.remaining_ms(), 0);
+    }
+
+    #[test]
+    fn test_executor_timer_actions_noop_when_idle() {
+        let executor = ActionExecutor::new();
+        let mut timer = TimerEngine::new();
+        let actions = vec![
+            ("test".to_string(), Action::TimerExtend { minutes: 10 }),
+            ("test".to_string(), Action::TimerSkip),
+        ];
+
+        let log = executor.execute_batch_with_timer(actions.clone(), &mut timer);
+        assert_eq!(log.skipped_count(), 2);
+        assert_eq!(timer.state(), TimerState::Idle);
+        assert_eq!(timer.total_ms(), 0);
+
+        // Without an engine timer actions are skipped too
+        let log = executor.execute_batch(actions);
+        assert_eq!(log.skipped_count(), 2);
+    }
+
+    #[test]
+    fn test_recipe_syncs_mock_integration() {
+        use crate::integrations::{IntegrationCall, MockIntegration};
+        use crate::recipes::{Recipe, Trigger};
+        use crate::timer::StepType;
+
+        let recipe = Recipe {
+            name: "sync-after-focus".to_string(),
+            description: "Sync tasks when a focus session ends".to_string(),
+            enabled: true,
+            triggers: vec![Trigger::TimerCompleted {
+                step_type: StepType::Focus,
+            }],
+            actions: vec![Action::IntegrationSync {
+                service: "mock".to_string(),
+            }],
+        };
+        let event = crate::Event::TimerCompleted {
+            step_index: 0,
+            step_type: StepType::Focus,
+            planned_ms: 0,
+            actual_ms: 0,
+            at: chrono::Utc::now(),
+        };
+        let actions: Vec<(String, Action)> = recipe
+            .matches_event(&event)
+            .unwrap()
+            .iter()
+            .map(|action| (recipe.name.clone(), action.clone()))
+            .collect();
+
+        let mock = MockIntegration::new("mock");
+        let executor = ActionExecutor::new();
+        let log = executor.execute_batch_with_integrations(actions.clone(), &[&mock]);
+        assert_eq!(log.success_count(), 1);
+        assert_eq!(mock.calls(), vec![IntegrationCall::Sync]);
+
+        mock.fail_on("sync", "service unavailable");
+        let log = executor.execute_batch_with_integrations(actions.clone(), &[&mock]);
+        assert!(matches!(
+            log.results[0].status,
+            ExecutionStatus::Failed {
+                retriable: true,
+                ..
+            }
+        ));
+
+        // Signed-out or unregistered integrations are skipped without a call
+        let signed_out = MockIntegration::new("mock").with_authenticated(false);
+        let log = executor.execute_batch_with_integrations(actions.clone(), &[&signed_out]);
+        assert_eq!(log.skipped_count(), 1);
+        assert!(signed_out.calls().is_empty());
+        assert_eq!(executor.execute_batch(actions).skipped_count(), 1);
+    }
 }
@@ -0,0 +1,183 @@
+//! Side-effect free recipe evaluation report.
+//!
+//! Explains, for every recipe, which triggers match an event and which
+//! actions would run, so a recipe that never fires can be debugged.
+
+use serde::{Deserialize, Serialize};
+
+use crate::recipes::{Action, Recipe, Trigger};
+use crate::Event;
+
+/// Result of evaluating one trigger against an event
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TriggerEvaluation {
+    /// The evaluated trigger
+    pub trigger: Trigger,
+    /// Whether the trigger matches the event
+    pub matched: bool,
+    /// Human-readable explanation of the condition result
+    pub detail: String,
+}
+
+/// Evaluation report for a single recipe
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RecipeExplanation {
+    /// Recipe name
+    pub recipe_name: String,
+    /// Whether the recipe is enabled
+    pub enabled: bool,
+    /// Per-trigger condition results, in recipe order
+    pub triggers: Vec<TriggerEvaluation>,
+    /// Whether any trigger matches, regardless of enabled status
+    pub matched: bool,
+    /// Whether the recipe would actually fire (matched and enabled)
+    pub would_fire: bool,
+    /// Actions that would run if the recipe fires
+    pub actions: Vec<Action>,
+}
+
+/// Explain how every recipe evaluates against `event` without executing anything.
+pub fn explain_recipes(recipes: &[Recipe], event: &Event) -> Vec<RecipeExplanation> {
+    recipes.iter().map(|recipe| explain_recipe(recipe, event)).collect()
+}
+
+/// Explain how a single recipe evaluates against `event`.
+pub fn explain_recipe(recipe: &Recipe, event: &Event) -> RecipeExplanation {
+    let triggers: Vec<TriggerEvaluation> = recipe
+        .triggers
+        .iter()
+        .map(|trigger| evaluate_trigger(trigger, event))
+        .collect();
+    let matched = triggers.iter().any(|t| t.matched);
+
+    RecipeExplanation {
+        recipe_name: recipe.name.clone(),
+        enabled: recipe.enabled,
+        triggers,
+        matched,
+        would_fire: matched && recipe.enabled,
+        actions: recipe.actions.clone(),
+    }
+}
+
+/// Evaluate one trigger and describe why it did or did not match.
+///
+/// This is the only trigger matcher; `Recipe::matches_event` uses its
+/// `matched` result, so explanations always agree with what fires.
+pub fn evaluate_trigger(trigger: &Trigger, event: &Event) -> TriggerEvaluation {
+    let (matched, detail) = match (trigger, event) {
+        (Trigger::TimerCompleted { step_type: expected }, Event::TimerCompleted { step_type, .. })
+        | (Trigger::TimerStarted { step_type: expected }, Event::TimerStarted { step_type, .. }) => (
+            expected == step_type,
+            format!("step_type: expected {:?}, got {:?}", expected, step_type),
+        ),
+        (Trigger::TimerSkipped { from_step: expected }, Event::TimerSkipped { from_step, .. }) => (
+            expected == from_step,
+            format!("from_step: expected {}, got {}", expected, from_step),
+        ),
+        (Trigger::TimerReset, Event::TimerReset { .. }) => {
+            (true, "event type matches".to_string())
+        }
+        _ => (
+            false,
+            format!(
+                "event type: expected {}, got {}",
+                trigger.type_name(),
+                event_type_name(event)
+            ),
+        ),
+    };
+
+    TriggerEvaluation {
+        trigger: trigger.clone(),
+        matched,
+        detail,
+    }
+}
+
+/// Serialized `type` tag of an event.
+fn event_type_name(event: &Event) -> String {
+    serde_json::to_value(event)
+        .ok()
+        .and_then(|value| value.get("type")?.as_str().map(str::to_string))
+        .unwrap_or_else(|| "unknown".to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::timer::StepType;
+    use chrono::Utc;
+
+    fn recipe(name: &str, enabled: bool, triggers: Vec<Trigger>) -> Recipe {
+        Recipe {
+            name: name.to_string(),
+            description: String::new(),
+            enabled,
+            triggers,
+            actions: vec![Action::TimerExtend { minutes: 10 }],
+        }
+    }
+
+    #[test]
+    fn test_explain_reports_disabled_match() {
+        let recipes = vec![
+            recipe(
+                "disabled-extend",
+                false,
+                vec![Trigger::TimerCompleted {
+                    step_type: StepType::Focus,
+                }],
+            ),
+            recipe("reset-only", true, vec![Trigger::TimerReset]),
+        ];
+        let event = Event::TimerCompleted {
+            step_index: 0,
+            step_type: StepType::Focus,
+            planned_ms: 0,
+            actual_ms: 0,
+            at: Utc::now(),
+        };
+
+        let report = explain_recipes(&recipes, &event);
+        assert_eq!(report.len(), 2);
+
+        assert!(report[0].matched);
+        assert!(!report[0].enabled);
+        assert!(!report[0].would_fire);
+        assert_eq!(report[0].actions, vec![Action::TimerExtend { minutes: 10 }]);
+
+        assert!(!report[1].matched);
+        assert!(!report[1].would_fire);
+        assert_eq!(
+            report[1].triggers[0].detail,
+            "event type: expected TimerReset, got TimerCompleted"
+        );
+    }
+
+    #[test]
+    fn test_explain_shows_condition_mismatch() {
+        let recipes = vec![recipe(
+            "after-break",
+            true,
+            vec![
+                Trigger::TimerCompleted {
+                    step_type: StepType::Break,
+                },
+                Trigger::TimerSkipped { from_step: 2 },
+            ],
+        )];
+        let event = Event::TimerSkipped {
+            from_step: 2,
+            to_step: 3,
+            at: Utc::now(),
+        };
+
+        let report = explain_recipes(&recipes, &event);
+        let triggers = &report[0].triggers;
+        assert!(!triggers[0].matched);
+        assert!(triggers[1].matched);
+        assert_eq!(triggers[1].detail, "from_step: expected 2, got 2");
+        assert!(report[0].would_fire);
+    }
+}
@@ -9,6 +9,7 @@ pub mod store;
 pub mod error;
 pub mod engine;
 pub mod executor;
+pub mod explain;
 pub mod log;
 
 pub use trigger::Trigger;
@@ -17,5 +18,6 @@ pub use recipe::Recipe;
 pub use store::RecipeStore;
 pub use engine::RecipeEngine;
 pub use executor::ActionExecutor;
+pub use explain::{RecipeExplanation, TriggerEvaluation};
 pub use log::{ActionResult, ActionLog, ExecutionStatus};
 pub use error::{RecipeError, Result};
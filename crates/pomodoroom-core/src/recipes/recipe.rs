@@ -3,6 +3,7 @@
 //! A recipe defines a complete if-this-then-that automation rule.
 
 use serde::{Deserialize, Serialize};
+use super::explain::evaluate_trigger;
 use super::{Trigger, Action};
 
 /// A complete recipe with triggers and actions
@@ -46,23 +47,9 @@ impl Recipe {
         None
     }
 
+    /// Whether `trigger` matches `event`, decided by [`evaluate_trigger`].
     fn trigger_matches(&self, trigger: &Trigger, event: &crate::Event) -> bool {
-        match (trigger, event) {
-            (Trigger::TimerCompleted { step_type: s1 },
-             crate::Event::TimerCompleted { step_type: s2, .. }) => {
-                s1 == s2
-            }
-            (Trigger::TimerSkipped { from_step: s1 },
-             crate::Event::TimerSkipped { from_step, .. }) => {
-                s1 == from_step
-            }
-            (Trigger::TimerStarted { step_type: s1 },
-             crate::Event::TimerStarted { step_type: s2, .. }) => {
-                s1 == s2
-            }
-            (Trigger::TimerReset, crate::Event::TimerReset { .. }) => true,
-            _ => false,
-        }
+        evaluate_trigger(trigger, event).matched
     }
 }
 
@@ -87,6 +74,8 @@ mod tests {
         let event = Event::TimerCompleted {
             step_index: 0,
             step_type: crate::timer::StepType::Focus,
+            planned_ms: 0,
+            actual_ms: 0,
             at: Utc::now(),
         };
 
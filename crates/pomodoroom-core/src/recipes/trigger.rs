@@ -30,6 +30,18 @@ pub enum Trigger {
     TimerReset,
 }
 
+impl Trigger {
+    /// Get the type name of this trigger
+    pub fn type_name(&self) -> &'static str {
+        match self {
+            Trigger::TimerCompleted { .. } => "TimerCompleted",
+            Trigger::TimerSkipped { .. } => "TimerSkipped",
+            Trigger::TimerStarted { .. } => "TimerStarted",
+            Trigger::TimerReset => "TimerReset",
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
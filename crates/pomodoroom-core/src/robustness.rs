@@ -35,6 +35,31 @@ pub struct MonteCarloConfig {
 
     /// Random seed for reproducibility (None = random)
     pub seed: Option<u64>,
+
+    /// On-time probability the recommended buffers aim for (0.0-1.0)
+    #[serde(default = "default_buffer_target")]
+    pub buffer_target: f32,
+
+    /// Overrun behavior for specific tasks, keyed by task ID
+    #[serde(default)]
+    pub task_uncertainty: HashMap<String, TaskUncertainty>,
+}
+
+fn default_buffer_target() -> f32 {
+    0.8
+}
+
+/// Seed used for sensitivity analysis when the config has none, so every
+/// perturbed run sees the same random draws as the baseline.
+const SENSITIVITY_SEED: u64 = 0x5EED;
+
+/// Overrun behavior of one task, replacing the global probabilities.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub struct TaskUncertainty {
+    /// Probability of task overrun (0.0-1.0)
+    pub overrun_probability: f32,
+    /// Maximum overrun as percentage of task duration (0.0-1.0)
+    pub max_overrun_ratio: f32,
 }
 
 impl Default for MonteCarloConfig {
@@ -47,6 +72,8 @@ impl Default for MonteCarloConfig {
             avg_interruption_minutes: 15,
             interruption_variance: 10,
             seed: None,
+            buffer_target: default_buffer_target(),
+            task_uncertainty: HashMap::new(),
         }
     }
 }
@@ -71,6 +98,10 @@ pub struct RobustnessResult {
 
     /// Breakdown by task
     pub task_analysis: Vec<TaskRobustnessInfo>,
+
+    /// Sum of the per-task recommended buffers (minutes)
+    #[serde(default)]
+    pub suggested_daily_buffer_minutes: f32,
 }
 
 /// Risk level classification for plans.
@@ -108,6 +139,22 @@ pub struct TaskRobustnessInfo {
     pub task_title: String,
     pub on_time_rate: f32,
     pub avg_delay_minutes: f32,
+    /// Extra minutes that make the task finish on time with the configured
+    /// `buffer_target` probability; zero when it already does
+    #[serde(default)]
+    pub recommended_buffer_minutes: f32,
+}
+
+/// The task whose re-estimation most reduces the day's overrun risk.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TaskImpact {
+    pub task_id: String,
+    pub task_title: String,
+    /// Percentage of simulations overrunning the day with the plan as is
+    pub baseline_overrun_rate: f32,
+    /// Percentage points of overrun rate removed if this task ran exactly
+    /// to its estimate
+    pub overrun_rate_reduction: f32,
 }
 
 /// Monte Carlo simulator for plan robustness.
@@ -148,6 +195,7 @@ impl MonteCarloSimulator {
                 avg_interruptions: 0.0,
                 risk_level: RiskLevel::Low,
                 task_analysis: vec![],
+                suggested_daily_buffer_minutes: 0.0,
             };
         }
 
@@ -155,6 +203,7 @@ impl MonteCarloSimulator {
         let mut total_overrun = 0.0f32;
         let mut total_interruptions = 0usize;
         let mut task_delays: HashMap<String, (f32, usize)> = HashMap::new();
+        let mut delay_samples: HashMap<String, Vec<f32>> = HashMap::new();
 
         for _ in 0..self.config.iterations {
             let (completed, overrun, interruptions, delays) =
@@ -168,9 +217,12 @@ impl MonteCarloSimulator {
 
             // Accumulate task-level delays
             for (task_id, delay) in delays {
-                let entry = task_delays.entry(task_id).or_insert((0.0, 0));
-                entry.0 += delay;
-                entry.1 += 1;
+                if delay > 0.0 {
+                    let entry = task_delays.entry(task_id.clone()).or_insert((0.0, 0));
+                    entry.0 += delay;
+                    entry.1 += 1;
+                }
+                delay_samples.entry(task_id).or_default().push(delay);
             }
         }
 
@@ -200,14 +252,23 @@ impl MonteCarloSimulator {
                     * 100.0)
                     .max(0.0)
                     .min(100.0);
+                let recommended_buffer_minutes = delay_samples
+                    .get_mut(&b.task_id)
+                    .map(|samples| percentile(samples, self.config.buffer_target))
+                    .unwrap_or(0.0);
                 TaskRobustnessInfo {
                     task_id: b.task_id.clone(),
                     task_title: b.task_title.clone(),
                     on_time_rate,
                     avg_delay_minutes: avg_delay,
+                    recommended_buffer_minutes,
                 }
             })
             .collect();
+        let suggested_daily_buffer_minutes = task_analysis
+            .iter()
+            .map(|t| t.recommended_buffer_minutes)
+            .sum();
 
         RobustnessResult {
             robustness_score: robustness_score.clamp(0.0, 100.0),
@@ -216,10 +277,65 @@ impl MonteCarloSimulator {
             avg_interruptions,
             risk_level: RiskLevel::from(robustness_score),
             task_analysis,
+            suggested_daily_buffer_minutes,
+        }
+    }
+
+    /// Find the task whose estimate matters most for finishing the day.
+    ///
+    /// Each focus task is in turn made certain (no overrun) and the plan is
+    /// re-simulated; the task whose certainty lowers the day's overrun rate
+    /// the most is returned. All runs share one seed, the configured one or
+    /// a fixed default, so differences come from the perturbation rather
+    /// than sampling noise. Ties go to the earlier task. Returns `None` for
+    /// plans without focus blocks.
+    pub fn most_impactful_task(
+        &self,
+        blocks: &[ScheduledBlock],
+        day_end: DateTime<Utc>,
+    ) -> Option<TaskImpact> {
+        let seeded = |task_uncertainty| {
+            MonteCarloSimulator::with_config(MonteCarloConfig {
+                seed: Some(self.config.seed.unwrap_or(SENSITIVITY_SEED)),
+                task_uncertainty,
+                ..self.config.clone()
+            })
+        };
+        let overrun_rate = |result: &RobustnessResult| 100.0 - result.completion_rate;
+
+        let baseline = seeded(self.config.task_uncertainty.clone()).simulate(blocks, day_end);
+        let baseline_overrun_rate = overrun_rate(&baseline);
+
+        let mut best: Option<TaskImpact> = None;
+        for info in &baseline.task_analysis {
+            let mut task_uncertainty = self.config.task_uncertainty.clone();
+            task_uncertainty.insert(
+                info.task_id.clone(),
+                TaskUncertainty {
+                    overrun_probability: 0.0,
+                    max_overrun_ratio: 0.0,
+                },
+            );
+            let perturbed = seeded(task_uncertainty).simulate(blocks, day_end);
+            let reduction = baseline_overrun_rate - overrun_rate(&perturbed);
+            if best
+                .as_ref()
+                .is_none_or(|b| reduction > b.overrun_rate_reduction)
+            {
+                best = Some(TaskImpact {
+                    task_id: info.task_id.clone(),
+                    task_title: info.task_title.clone(),
+                    baseline_overrun_rate,
+                    overrun_rate_reduction: reduction,
+                });
+            }
         }
+        best
     }
 
     /// Run a single simulation iteration.
+    ///
+    /// Returns the delay of every task, zero when it finished on time.
     fn run_single_simulation(
         &self,
         blocks: &[&ScheduledBlock],
@@ -233,9 +349,20 @@ impl MonteCarloSimulator {
 
         for block in blocks {
             // Apply overrun to this task
+            let (overrun_probability, max_overrun_ratio) =
+                match self.config.task_uncertainty.get(&block.task_id) {
+                    Some(u) => (u.overrun_probability, u.max_overrun_ratio),
+                    None => (
+                        self.config.overrun_probability,
+                        self.config.max_overrun_ratio,
+                    ),
+                };
             let original_duration = block.duration_minutes() as f32;
-            let actual_duration = if rng.gen::<f32>() < self.config.overrun_probability {
-                let overrun_ratio = rng.gen::<f32>() * self.config.max_overrun_ratio;
+            // Both draws are always taken so that changing one task's
+            // uncertainty leaves the random stream of the others untouched
+            let overrun_roll = rng.gen::<f32>();
+            let overrun_ratio = rng.gen::<f32>() * max_overrun_ratio;
+            let actual_duration = if overrun_roll < overrun_probability {
                 original_duration * (1.0 + overrun_ratio)
             } else {
                 original_duration
@@ -255,13 +382,11 @@ impl MonteCarloSimulator {
             // Calculate task end time
             let planned_end = block.end_time;
             let actual_end = current_time
-                + Duration::seconds((actual_duration + interruption_duration * 60.0) as i64);
+                + Duration::seconds(((actual_duration + interruption_duration) * 60.0) as i64);
 
             // Calculate delay for this task
             let delay = (actual_end - planned_end).num_minutes().max(0) as f32;
-            if delay > 0.0 {
-                task_delays.push((block.task_id.clone(), delay));
-            }
+            task_delays.push((block.task_id.clone(), delay));
 
             current_time = actual_end;
             total_overrun += delay;
@@ -310,6 +435,16 @@ impl Default for MonteCarloSimulator {
     }
 }
 
+/// Value below which `target` (0.0-1.0) of `samples` fall.
+fn percentile(samples: &mut [f32], target: f32) -> f32 {
+    if samples.is_empty() {
+        return 0.0;
+    }
+    samples.sort_by(|a, b| a.total_cmp(b));
+    let rank = (target.clamp(0.0, 1.0) * samples.len() as f32).ceil() as usize;
+    samples[rank.clamp(1, samples.len()) - 1]
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -444,4 +579,90 @@ mod tests {
         assert!(result.task_analysis.iter().any(|t| t.task_id == "task-1"));
         assert!(result.task_analysis.iter().any(|t| t.task_id == "task-2"));
     }
+
+    #[test]
+    fn test_high_variance_task_needs_more_buffer() {
+        let now = Utc::now();
+        // Both overrun by 20% of 25 minutes on average
+        let mut task_uncertainty = HashMap::new();
+        task_uncertainty.insert(
+            "steady".to_string(),
+            TaskUncertainty {
+                overrun_probability: 1.0,
+                max_overrun_ratio: 0.4,
+            },
+        );
+        task_uncertainty.insert(
+            "erratic".to_string(),
+            TaskUncertainty {
+                overrun_probability: 0.5,
+                max_overrun_ratio: 0.8,
+            },
+        );
+        task_uncertainty.insert(
+            "fits".to_string(),
+            TaskUncertainty {
+                overrun_probability: 0.0,
+                max_overrun_ratio: 0.0,
+            },
+        );
+        let simulator = MonteCarloSimulator::with_config(MonteCarloConfig {
+            iterations: 1000,
+            seed: Some(7),
+            interruption_probability: 0.0,
+            task_uncertainty,
+            ..Default::default()
+        });
+        let day_end = now + Duration::hours(2);
+        let buffer = |task_id: &str| {
+            let result = simulator.simulate(&[make_block(task_id, now, 25)], day_end);
+            assert_eq!(
+                result.suggested_daily_buffer_minutes,
+                result.task_analysis[0].recommended_buffer_minutes
+            );
+            result.task_analysis[0].recommended_buffer_minutes
+        };
+
+        let steady = buffer("steady");
+        let erratic = buffer("erratic");
+        assert!(steady > 0.0);
+        assert!(erratic > steady, "erratic {erratic} vs steady {steady}");
+        assert_eq!(buffer("fits"), 0.0);
+    }
+
+    #[test]
+    fn test_large_erratic_task_is_most_impactful() {
+        let now = Utc::now();
+        let mut task_uncertainty = HashMap::new();
+        task_uncertainty.insert(
+            "big".to_string(),
+            TaskUncertainty {
+                overrun_probability: 0.6,
+                max_overrun_ratio: 1.0,
+            },
+        );
+        let simulator = MonteCarloSimulator::with_config(MonteCarloConfig {
+            iterations: 500,
+            interruption_probability: 0.0,
+            task_uncertainty,
+            ..Default::default()
+        });
+        let blocks = vec![
+            make_block("small-1", now, 25),
+            make_block("big", now + Duration::minutes(25), 90),
+            make_block("small-2", now + Duration::minutes(115), 25),
+        ];
+        let day_end = now + Duration::minutes(160);
+
+        let impact = simulator.most_impactful_task(&blocks, day_end).unwrap();
+        assert_eq!(impact.task_id, "big");
+        assert!(impact.baseline_overrun_rate > 0.0);
+        assert!(impact.overrun_rate_reduction > 0.0);
+
+        // Unseeded configs still give repeatable answers
+        let again = simulator.most_impactful_task(&blocks, day_end).unwrap();
+        assert_eq!(again.overrun_rate_reduction, impact.overrun_rate_reduction);
+
+        assert!(simulator.most_impactful_task(&[], day_end).is_none());
+    }
 }
@@ -0,0 +1,197 @@
+//! Pre-flight feasibility check for a day's workload.
+//!
+//! Compares the READY task workload against the time actually available in
+//! the awake window, without running the scheduler itself.
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use super::DailyTemplate;
+use crate::scheduler::{AutoScheduler, CalendarEvent, SchedulerConfig};
+use crate::task::{Task, TaskCategory, TaskState};
+use crate::timeline::TimelineEvent;
+
+/// Result of a workload feasibility check for a single day.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FeasibilityReport {
+    /// Remaining focus time across READY tasks (minutes).
+    pub focus_minutes: i64,
+    /// Short and long break time needed between those focus sessions (minutes).
+    pub break_minutes: i64,
+    /// Total time the workload needs (focus + breaks).
+    pub required_minutes: i64,
+    /// Length of the awake window (minutes).
+    pub window_minutes: i64,
+    /// Time in the window left after fixed events, calendar events and running tasks.
+    pub available_minutes: i64,
+    /// Whether the workload cannot fit in the available time.
+    pub over_committed: bool,
+    /// Minutes by which the workload exceeds availability (0 when it fits).
+    pub overflow_minutes: i64,
+}
+
+/// Check whether READY tasks can fit into `day` using the default scheduler config.
+pub fn feasibility_check(
+    template: &DailyTemplate,
+    tasks: &[Task],
+    calendar_events: &[CalendarEvent],
+    day: DateTime<Utc>,
+) -> FeasibilityReport {
+    feasibility_check_with_config(
+        template,
+        tasks,
+        calendar_events,
+        day,
+        &SchedulerConfig::default(),
+    )
+}
+
+/// Check whether READY tasks can fit into `day` using `config` durations.
+///
+/// Only tasks the scheduler would place (READY, Active category, with
+/// remaining pomodoros, not a recurrence template) count toward the
+/// workload. Breaks are counted between
+/// consecutive splittable pomodoros, with every `pomodoros_before_long_break`-th
+/// break being a long break.
+pub fn feasibility_check_with_config(
+    template: &DailyTemplate,
+    tasks: &[Task],
+    calendar_events: &[CalendarEvent],
+    day: DateTime<Utc>,
+    config: &SchedulerConfig,
+) -> FeasibilityReport {
+    let scheduler = AutoScheduler::with_config(config.clone());
+
+    let (window_minutes, available_minutes) = match scheduler.parse_day_boundaries(template, day) {
+        Some((day_start, day_end)) => {
+            let events: Vec<TimelineEvent> = scheduler
+                .build_fixed_events(template, day)
+                .into_iter()
+                .chain(scheduler.build_meal_buffers(template, day))
+                .chain(scheduler.build_running_task_events(tasks, day_start, day_end))
+                .chain(
+                    calendar_events
+                        .iter()
+                        .map(|e| TimelineEvent::new(e.start_time, e.end_time)),
+                )
+                .collect();
+            let available = crate::timeline::detect_time_gaps(&events, day_start, day_end)
+                .iter()
+                .map(|gap| gap.duration_minutes())
+                .sum();
+            ((day_end - day_start).num_minutes(), available)
+        }
+        None => (0, 0),
+    };
+
+    let mut focus_minutes = 0;
+    let mut splittable_pomodoros: i64 = 0;
+    for task in tasks.iter().filter(|t| {
+        t.state == TaskState::Ready
+            && !t.completed
+            && t.category == TaskCategory::Active
+            && !t.is_recurrence_template()
+    }) {
+        let remaining = i64::from((task.estimated_pomodoros - task.completed_pomodoros).max(0));
+        focus_minutes += remaining * config.focus_duration;
+        if task.allow_split {
+            splittable_pomodoros += remaining;
+        }
+    }
+
+    let break_count = (splittable_pomodoros - 1).max(0);
+    let long_breaks = if config.pomodoros_before_long_break > 0 {
+        break_count / i64::from(config.pomodoros_before_long_break)
+    } else {
+        0
+    };
+    let short_breaks = break_count - long_breaks;
+    let break_minutes = short_breaks * config.short_break + long_breaks * config.long_break;
+
+    let required_minutes = focus_minutes + break_minutes;
+    let overflow_minutes = (required_minutes - available_minutes).max(0);
+
+    FeasibilityReport {
+        focus_minutes,
+        break_minutes,
+        required_minutes,
+        window_minutes,
+        available_minutes,
+        over_committed: overflow_minutes > 0,
+        overflow_minutes,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    fn ready_task(pomodoros: i32) -> Task {
+        let mut task = Task::new("Work");
+        task.estimated_pomodoros = pomodoros;
+        task
+    }
+
+    fn eight_hour_template() -> DailyTemplate {
+        DailyTemplate {
+            wake_up: "09:00".to_string(),
+            sleep: "17:00".to_string(),
+            fixed_events: vec![],
+            max_parallel_lanes: Some(1),
+        }
+    }
+
+    #[test]
+    fn ten_hours_of_tasks_overflow_eight_hour_window() {
+        let day = Utc.with_ymd_and_hms(2024, 1, 10, 0, 0, 0).unwrap();
+        // 24 pomodoros x 25 min = 600 min (10h) of focus.
+        let tasks = vec![ready_task(12), ready_task(12)];
+
+        let report = feasibility_check(&eight_hour_template(), &tasks, &[], day);
+
+        assert_eq!(report.window_minutes, 480);
+        assert_eq!(report.available_minutes, 480);
+        assert_eq!(report.focus_minutes, 600);
+        // 23 breaks: 5 long (every 4th) + 18 short.
+        assert_eq!(report.break_minutes, 5 * 15 + 18 * 5);
+        assert!(report.over_committed);
+        assert_eq!(report.overflow_minutes, 600 + 165 - 480);
+    }
+
+    #[test]
+    fn calendar_events_reduce_availability() {
+        let day = Utc.with_ymd_and_hms(2024, 1, 10, 0, 0, 0).unwrap();
+        let meeting = CalendarEvent::new(
+            "m1".to_string(),
+            "Meeting".to_string(),
+            Utc.with_ymd_and_hms(2024, 1, 10, 10, 0, 0).unwrap(),
+            Utc.with_ymd_and_hms(2024, 1, 10, 12, 0, 0).unwrap(),
+        );
+
+        let report = feasibility_check(&eight_hour_template(), &[ready_task(4)], &[meeting], day);
+
+        assert_eq!(report.available_minutes, 360);
+        assert_eq!(report.required_minutes, 4 * 25 + 3 * 5);
+        assert!(!report.over_committed);
+        assert_eq!(report.overflow_minutes, 0);
+    }
+
+    #[test]
+    fn non_ready_tasks_are_ignored() {
+        let day = Utc.with_ymd_and_hms(2024, 1, 10, 0, 0, 0).unwrap();
+        let mut done = ready_task(40);
+        done.state = TaskState::Done;
+        done.completed = true;
+
+        let report = feasibility_check(&eight_hour_template(), &[done], &[], day);
+        assert_eq!(report.required_minutes, 0);
+        assert!(!report.over_committed);
+
+        // Recurrence templates only spawn instances; the instances count
+        let mut template = ready_task(40);
+        template.recurrence = Some(crate::task::Recurrence::daily());
+        let report = feasibility_check(&eight_hour_template(), &[template], &[], day);
+        assert_eq!(report.required_minutes, 0);
+    }
+}
@@ -0,0 +1,163 @@
+//! iCalendar (RFC 5545) export of scheduled blocks.
+//!
+//! Produces a minimal VCALENDAR with one VEVENT per block so a generated day
+//! can be imported into any calendar app. Times are always emitted in UTC.
+
+use chrono::{DateTime, Utc};
+
+use crate::scheduler::{ScheduledBlock, ScheduledBlockType};
+
+/// Maximum line length in octets before folding (RFC 5545 §3.1).
+const MAX_LINE_OCTETS: usize = 75;
+
+/// Render `blocks` as an iCalendar document.
+///
+/// Blocks with zero or negative duration are skipped. Break blocks get the
+/// `BREAK` category and focus blocks `FOCUS`.
+pub fn to_ics(blocks: &[ScheduledBlock]) -> String {
+    to_ics_at(blocks, Utc::now())
+}
+
+/// Render `blocks` as an iCalendar document with a fixed DTSTAMP.
+pub fn to_ics_at(blocks: &[ScheduledBlock], stamp: DateTime<Utc>) -> String {
+    let mut lines = vec![
+        "BEGIN:VCALENDAR".to_string(),
+        "VERSION:2.0".to_string(),
+        "PRODID:-//Pomodoroom//Schedule Export//EN".to_string(),
+        "CALSCALE:GREGORIAN".to_string(),
+    ];
+
+    for block in blocks.iter().filter(|b| b.end_time > b.start_time) {
+        let (category, fallback) = match block.block_type {
+            ScheduledBlockType::Focus => ("FOCUS", "Focus"),
+            ScheduledBlockType::Break => ("BREAK", "Break"),
+        };
+        let summary = if block.task_title.is_empty() {
+            fallback
+        } else {
+            block.task_title.as_str()
+        };
+
+        lines.push("BEGIN:VEVENT".to_string());
+        lines.push(format!("UID:{}@pomodoroom", escape_text(&block.id)));
+        lines.push(format!("DTSTAMP:{}", format_utc(stamp)));
+        lines.push(format!("DTSTART:{}", format_utc(block.start_time)));
+        lines.push(format!("DTEND:{}", format_utc(block.end_time)));
+        lines.push(format!("SUMMARY:{}", escape_text(summary)));
+        lines.push(format!("CATEGORIES:{category}"));
+        lines.push("END:VEVENT".to_string());
+    }
+
+    lines.push("END:VCALENDAR".to_string());
+
+    let mut out = String::new();
+    for line in &lines {
+        out.push_str(&fold_line(line));
+        out.push_str("\r\n");
+    }
+    out
+}
+
+/// Format a UTC timestamp as an iCalendar DATE-TIME (`YYYYMMDDTHHMMSSZ`).
+fn format_utc(dt: DateTime<Utc>) -> String {
+    dt.format("%Y%m%dT%H%M%SZ").to_string()
+}
+
+/// Escape a TEXT value (RFC 5545 §3.3.11).
+fn escape_text(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len());
+    for c in value.chars() {
+        match c {
+            '\\' => escaped.push_str("\\\\"),
+            ';' => escaped.push_str("\\;"),
+            ',' => escaped.push_str("\\,"),
+            '\n' => escaped.push_str("\\n"),
+            '\r' => {}
+            _ => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+/// Fold a content line at 75 octets without splitting UTF-8 characters.
+fn fold_line(line: &str) -> String {
+    let mut folded = String::with_capacity(line.len());
+    let mut width = 0;
+    for c in line.chars() {
+        let len = c.len_utf8();
+        if width + len > MAX_LINE_OCTETS {
+            folded.push_str("\r\n ");
+            // The leading space counts toward the continuation line.
+            width = 1;
+        }
+        folded.push(c);
+        width += len;
+    }
+    folded
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    fn block(
+        id: &str,
+        title: &str,
+        block_type: ScheduledBlockType,
+        minutes: i64,
+    ) -> ScheduledBlock {
+        let start = Utc.with_ymd_and_hms(2024, 1, 10, 9, 0, 0).unwrap();
+        let mut block = ScheduledBlock::new(
+            "task-1".to_string(),
+            title.to_string(),
+            start,
+            start + chrono::Duration::minutes(minutes),
+            block_type,
+            Some(0),
+            1,
+            5,
+        );
+        block.id = id.to_string();
+        block
+    }
+
+    #[test]
+    fn exports_events_in_utc_with_categories() {
+        let stamp = Utc.with_ymd_and_hms(2024, 1, 9, 0, 0, 0).unwrap();
+        let ics = to_ics_at(
+            &[
+                block("b1", "Write report", ScheduledBlockType::Focus, 25),
+                block("b2", "", ScheduledBlockType::Break, 5),
+            ],
+            stamp,
+        );
+
+        assert!(ics.starts_with("BEGIN:VCALENDAR\r\nVERSION:2.0\r\n"));
+        assert!(ics.ends_with("END:VCALENDAR\r\n"));
+        assert_eq!(ics.matches("BEGIN:VEVENT").count(), 2);
+        assert!(ics.contains("UID:b1@pomodoroom\r\n"));
+        assert!(ics.contains("DTSTAMP:20240109T000000Z\r\n"));
+        assert!(ics.contains("DTSTART:20240110T090000Z\r\nDTEND:20240110T092500Z\r\n"));
+        assert!(ics.contains("SUMMARY:Write report\r\nCATEGORIES:FOCUS\r\n"));
+        assert!(ics.contains("SUMMARY:Break\r\nCATEGORIES:BREAK\r\n"));
+    }
+
+    #[test]
+    fn skips_zero_duration_blocks() {
+        let ics = to_ics(&[block("b1", "Empty", ScheduledBlockType::Focus, 0)]);
+        assert!(!ics.contains("BEGIN:VEVENT"));
+    }
+
+    #[test]
+    fn escapes_and_folds_text() {
+        assert_eq!(escape_text("a,b;c\\d\ne"), "a\\,b\\;c\\\\d\\ne");
+
+        let long = format!("SUMMARY:{}", "é".repeat(60));
+        let folded = fold_line(&long);
+        for line in folded.split("\r\n") {
+            assert!(line.len() <= MAX_LINE_OCTETS);
+        }
+        assert_eq!(folded.replace("\r\n ", ""), long);
+    }
+}
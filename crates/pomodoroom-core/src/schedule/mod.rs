@@ -3,12 +3,18 @@
 //! The Task type has been moved to the `task` module with v2 extensions.
 //! This module re-exports it for backward compatibility.
 
+mod feasibility;
+mod ics;
+
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 
 // Re-export Task types from the task module
 pub use crate::task::{EnergyLevel, Task, TaskCategory, TaskKind, TaskState, TaskTransitionError};
 
+pub use feasibility::{feasibility_check, feasibility_check_with_config, FeasibilityReport};
+pub use ics::{to_ics, to_ics_at};
+
 /// Category of task for organizing work.
 ///
 /// NOTE: This type has been moved to the `task` module.
@@ -60,6 +66,29 @@ pub struct Group {
     pub updated_at: DateTime<Utc>,
 }
 
+/// What a fixed event is, for scheduling rules that differ by kind.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum FixedEventKind {
+    /// Meal time; the scheduler keeps a buffer around it.
+    Meal,
+    Meeting,
+    #[default]
+    Other,
+}
+
+impl FixedEventKind {
+    /// Parse "meal", "meeting" or "other", case-insensitively.
+    pub fn parse(value: &str) -> Option<Self> {
+        match value.trim().to_ascii_lowercase().as_str() {
+            "meal" => Some(Self::Meal),
+            "meeting" => Some(Self::Meeting),
+            "other" => Some(Self::Other),
+            _ => None,
+        }
+    }
+}
+
 /// A fixed event that occurs at specific times on specific days.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct FixedEvent {
@@ -69,6 +98,8 @@ pub struct FixedEvent {
     pub duration_minutes: i32,
     pub days: Vec<u8>, // 0=Sun ... 6=Sat
     pub enabled: bool,
+    #[serde(default)]
+    pub kind: FixedEventKind,
 }
 
 /// Daily template defining wake/sleep times and fixed events.
@@ -164,6 +195,10 @@ mod tests {
             allow_split: true,
             suggested_tags: vec![],
             approved_tags: vec![],
+            external_block: None,
+            recurrence: None,
+            recurrence_parent_id: None,
+            notes: Vec::new(),
         };
 
         let json = serde_json::to_string(&task).unwrap();
@@ -179,6 +214,7 @@ mod tests {
             duration_minutes: 30,
             days: vec![1, 2, 3, 4, 5], // Mon-Fri
             enabled: true,
+            kind: FixedEventKind::Other,
         };
 
         let json = serde_json::to_string(&event).unwrap();
@@ -8,15 +8,17 @@
 
 pub mod slack;
 
+use std::collections::HashSet;
+
 use chrono::{DateTime, Datelike, Duration, Timelike, Utc};
 use serde::{Deserialize, Serialize};
 
-use crate::schedule::{DailyTemplate, FixedEvent};
+use crate::schedule::{BlockType, DailyTemplate, FixedEvent, FixedEventKind, ScheduleBlock};
 use crate::task::{EnergyLevel, Task, TaskCategory, TaskKind, TaskState};
 use crate::timeline::TimelineEvent;
 
 /// A scheduled Pomodoro block
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct ScheduledBlock {
     pub id: String,
     pub task_id: String,
@@ -27,6 +29,10 @@ pub struct ScheduledBlock {
     pub lane: Option<i32>,
     pub pomodoro_count: i32,
     pub break_minutes: i32,
+    /// Placed where the user put it (a locked block or a `FixedEvent` task)
+    /// rather than by the scheduler
+    #[serde(default)]
+    pub pinned: bool,
 }
 
 #[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
@@ -58,6 +64,7 @@ impl ScheduledBlock {
             lane,
             pomodoro_count,
             break_minutes,
+            pinned: false,
         }
     }
 
@@ -67,6 +74,49 @@ impl ScheduledBlock {
     }
 }
 
+/// Why a task could not be (fully) scheduled
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum UnschedulableReason {
+    /// The task's deadline (`window_end_at`) is before the day starts
+    DeadlinePassed { deadline: DateTime<Utc> },
+    /// The task's earliest start is at or after the end of the day
+    StartsAfterDay { earliest_start: DateTime<Utc> },
+    /// The earliest start is not before the deadline
+    EmptyWindow,
+    /// A fixed-time task lies entirely outside the day
+    OutsideDay,
+    /// A `FixedEvent` task has no `fixed_start_at` to pin it at
+    MissingFixedStart,
+    /// Not enough free time to place every remaining pomodoro
+    InsufficientTime { placed: i32, required: i32 },
+    /// `max_daily_focus_minutes` was reached before every pomodoro was placed
+    DailyFocusCap { placed: i32, required: i32 },
+}
+
+/// A task left out of (or only partly placed in) the schedule
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct UnschedulableTask {
+    pub task_id: String,
+    pub task_title: String,
+    pub reason: UnschedulableReason,
+}
+
+/// Result of scheduling a day
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct SchedulingOutcome {
+    /// Scheduled blocks, ordered by start time then lane
+    pub blocks: Vec<ScheduledBlock>,
+    /// Tasks that could not be fully scheduled, with the cause
+    pub unschedulable: Vec<UnschedulableTask>,
+    /// Share of the day's free time (0.0-1.0) covered by new blocks
+    ///
+    /// Free time excludes fixed events, calendar events, running tasks and
+    /// locked blocks. Compare across `PackingStrategy` values.
+    #[serde(default)]
+    pub utilization: f64,
+}
+
 /// Calendar event for conflict detection
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct CalendarEvent {
@@ -113,6 +163,54 @@ pub struct SchedulerConfig {
     pub min_gap_minutes: i64,
     /// Parallel break placement policy.
     pub parallel_break_policy: ParallelBreakPolicy,
+    /// How tasks are packed into free gaps.
+    #[serde(default)]
+    pub packing_strategy: PackingStrategy,
+    /// Minutes kept free before and after meal events (minutes)
+    #[serde(default = "default_meal_buffer_minutes")]
+    pub meal_buffer_minutes: i64,
+    /// Emit breaks as `Break` blocks between focus rounds.
+    ///
+    /// When off, breaks are only implied by `break_minutes` on focus blocks;
+    /// the break time is still kept free.
+    #[serde(default = "default_emit_break_blocks")]
+    pub emit_break_blocks: bool,
+    /// Most focus time to schedule in one day (minutes)
+    ///
+    /// Locked focus blocks count toward the cap. Once it is reached, no more
+    /// focus is placed and the remaining tasks are reported with
+    /// `UnschedulableReason::DailyFocusCap`.
+    #[serde(default)]
+    pub max_daily_focus_minutes: Option<i64>,
+    /// Most focus time between long breaks (minutes)
+    ///
+    /// A long break replaces the short one whenever another pomodoro would
+    /// take continuous focus past the cap. Non-splittable tasks still run as
+    /// one block.
+    #[serde(default)]
+    pub max_continuous_focus_minutes: Option<i64>,
+}
+
+fn default_meal_buffer_minutes() -> i64 {
+    10
+}
+
+fn default_emit_break_blocks() -> bool {
+    true
+}
+
+/// How tasks are packed into free gaps.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum PackingStrategy {
+    /// Walk tasks in priority order; a task that does not fit ends the gap.
+    #[default]
+    Greedy,
+    /// Per gap, choose the tasks that fill it most completely.
+    ///
+    /// A 0/1 knapsack over whole remaining tasks, in minutes, picks what
+    /// goes into each gap; ties keep priority order. Splittable tasks that
+    /// were not picked still fill leftover space.
+    BestFit,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
@@ -132,6 +230,11 @@ impl Default for SchedulerConfig {
             pomodoros_before_long_break: 4,
             min_gap_minutes: 15,
             parallel_break_policy: ParallelBreakPolicy::Shared,
+            packing_strategy: PackingStrategy::Greedy,
+            meal_buffer_minutes: default_meal_buffer_minutes(),
+            emit_break_blocks: default_emit_break_blocks(),
+            max_daily_focus_minutes: None,
+            max_continuous_focus_minutes: None,
         }
     }
 }
@@ -156,6 +259,17 @@ impl AutoScheduler {
 
     /// Generate schedule for a specific day
     ///
+    /// Tasks are placed by kind, in this order:
+    /// 1. `FixedEvent` tasks are pinned at `fixed_start_at` as immovable blocks;
+    ///    those without one are reported as unschedulable
+    /// 2. Time-constrained tasks are placed only within their window (see below)
+    /// 3. `DurationOnly` tasks fill the remaining gaps
+    /// 4. `BufferFill` tasks expand to fill any leftover gap space
+    ///
+    /// `FlexWindow` and `DurationOnly` tasks are time-constrained when they
+    /// have an earliest start (`window_start_at`, or `fixed_start_at` for
+    /// non-fixed tasks) or a hard deadline (`window_end_at`).
+    ///
     /// # Arguments
     /// * `template` - Daily template with wake/sleep times and fixed events
     /// * `tasks` - Pool of available tasks to schedule
@@ -163,7 +277,8 @@ impl AutoScheduler {
     /// * `day` - Target day to schedule for
     ///
     /// # Returns
-    /// Vector of scheduled Pomodoro blocks
+    /// Vector of scheduled Pomodoro blocks; use `generate_outcome` to also
+    /// learn which tasks could not be scheduled
     pub fn generate_schedule(
         &self,
         template: &DailyTemplate,
@@ -171,21 +286,99 @@ impl AutoScheduler {
         calendar_events: &[CalendarEvent],
         day: DateTime<Utc>,
     ) -> Vec<ScheduledBlock> {
+        self.generate_outcome(template, tasks, calendar_events, day)
+            .blocks
+    }
+
+    /// Generate schedule for a specific day, reporting unschedulable tasks
+    ///
+    /// Placement follows `generate_schedule`. Tasks whose window lies outside
+    /// the day, or that do not fit, are listed with the cause.
+    pub fn generate_outcome(
+        &self,
+        template: &DailyTemplate,
+        tasks: &[Task],
+        calendar_events: &[CalendarEvent],
+        day: DateTime<Utc>,
+    ) -> SchedulingOutcome {
+        self.regenerate_outcome(template, tasks, calendar_events, &[], day)
+    }
+
+    /// Regenerate a day's schedule, keeping the user's locked blocks
+    ///
+    /// Locked blocks in `existing_blocks` that overlap the day are immovable
+    /// occupied time, like fixed events, and are returned unchanged (same
+    /// ID) among the new blocks. Pomodoros of a locked focus block count
+    /// toward its task. Unlocked blocks are ignored and recomputed.
+    pub fn regenerate_schedule(
+        &self,
+        template: &DailyTemplate,
+        tasks: &[Task],
+        calendar_events: &[CalendarEvent],
+        existing_blocks: &[ScheduleBlock],
+        day: DateTime<Utc>,
+    ) -> Vec<ScheduledBlock> {
+        self.regenerate_outcome(template, tasks, calendar_events, existing_blocks, day)
+            .blocks
+    }
+
+    /// Regenerate a day's schedule around locked blocks, reporting
+    /// unschedulable tasks
+    ///
+    /// See `regenerate_schedule` and `generate_outcome`.
+    pub fn regenerate_outcome(
+        &self,
+        template: &DailyTemplate,
+        tasks: &[Task],
+        calendar_events: &[CalendarEvent],
+        existing_blocks: &[ScheduleBlock],
+        day: DateTime<Utc>,
+    ) -> SchedulingOutcome {
         // 1. Validate date bounds
         let (day_start, day_end) = match self.parse_day_boundaries(template, day) {
             Some(bounds) => bounds,
-            None => return Vec::new(),
+            None => return SchedulingOutcome::default(),
         };
 
-        // 2. Build fixed events for this day
+        // 2. Keep locked blocks and credit their pomodoros to their tasks
+        let pinned: Vec<ScheduledBlock> = existing_blocks
+            .iter()
+            .filter(|b| b.locked && b.start_time < day_end && b.end_time > day_start)
+            .map(|b| self.pin_locked_block(b, tasks))
+            .collect();
+        let tasks: Vec<Task> = tasks
+            .iter()
+            .filter(|task| !task.is_recurrence_template())
+            .cloned()
+            .map(|mut task| {
+                let locked: i32 = pinned
+                    .iter()
+                    .filter(|b| b.block_type == ScheduledBlockType::Focus && b.task_id == task.id)
+                    .map(|b| b.pomodoro_count)
+                    .sum();
+                task.completed_pomodoros = (task.completed_pomodoros + locked)
+                    .min(task.estimated_pomodoros.max(task.completed_pomodoros));
+                task
+            })
+            .collect();
+        let tasks = tasks.as_slice();
+
+        // 3. Build fixed events for this day
         let fixed_events = self.build_fixed_events(template, day);
+        let meal_buffers = self.build_meal_buffers(template, day);
         let running_task_events = self.build_running_task_events(tasks, day_start, day_end);
 
-        // 3. Combine fixed events and calendar events
+        // 4. Combine fixed events, locked blocks and calendar events
         let all_events: Vec<TimelineEvent> = fixed_events
             .iter()
             .cloned()
+            .chain(meal_buffers.iter().cloned())
             .chain(running_task_events.iter().cloned())
+            .chain(
+                pinned
+                    .iter()
+                    .map(|b| TimelineEvent::new(b.start_time, b.end_time)),
+            )
             .chain(
                 calendar_events
                     .iter()
@@ -193,26 +386,262 @@ impl AutoScheduler {
             )
             .collect();
 
-        // 4. Find time gaps
-        let gaps = crate::timeline::detect_time_gaps(&all_events, day_start, day_end);
-
         // 5. Filter READY tasks only (progressive focus requirement)
-        let mut ready_tasks: Vec<_> = tasks
+        let ready_tasks: Vec<&Task> = tasks
             .iter()
             .filter(|t| t.state == TaskState::Ready)
             .filter(|t| !t.completed && t.category == TaskCategory::Active)
-            .filter(|t| t.estimated_pomodoros > t.completed_pomodoros)
+            .collect();
+        let has_remaining = |t: &&Task| t.estimated_pomodoros > t.completed_pomodoros;
+
+        // 6. Get max parallel lanes from template (default to 1 if not set)
+        let max_lanes = template.max_parallel_lanes.unwrap_or(1).max(1) as usize;
+        let free_gaps = crate::timeline::detect_time_gaps(&all_events, day_start, day_end);
+
+        let locked_focus: i64 = pinned
+            .iter()
+            .filter(|b| b.block_type == ScheduledBlockType::Focus)
+            .map(ScheduledBlock::duration_minutes)
+            .sum();
+        let mut budget = FocusBudget::new(self.config.max_daily_focus_minutes, locked_focus);
+
+        let mut occupied = all_events;
+        let mut scheduled = Vec::new();
+        let mut unschedulable = Vec::new();
+        let mut drop_task = |task: &Task, reason: UnschedulableReason| {
+            unschedulable.push(UnschedulableTask {
+                task_id: task.id.clone(),
+                task_title: task.title.clone(),
+                reason,
+            })
+        };
+
+        // 7. Pin FixedEvent tasks at their fixed time as immovable blocks
+        let (mut fixed_tasks, unpinnable): (Vec<&Task>, Vec<&Task>) = ready_tasks
+            .iter()
+            .copied()
+            .filter(|t| t.kind == TaskKind::FixedEvent)
+            .filter(|t| !pinned.iter().any(|b| b.task_id == t.id))
+            .partition(|t| t.fixed_start_at.is_some());
+        for task in unpinnable {
+            drop_task(task, UnschedulableReason::MissingFixedStart);
+        }
+        fixed_tasks.sort_by(|a, b| (a.fixed_start_at, &a.id).cmp(&(b.fixed_start_at, &b.id)));
+        for task in fixed_tasks {
+            match self.pin_fixed_task(task, day_start, day_end) {
+                Some(block) => {
+                    occupied.push(TimelineEvent::new(block.start_time, block.end_time));
+                    scheduled.push(block);
+                }
+                None => drop_task(task, UnschedulableReason::OutsideDay),
+            }
+        }
+
+        // 8. Place time-constrained tasks only inside their window
+        let mut windowed_tasks: Vec<Task> = ready_tasks
+            .iter()
+            .copied()
+            .filter(has_remaining)
+            .filter(|t| has_time_constraint(t))
             .cloned()
             .collect();
+        self.sort_tasks_by_energy_and_priority(&mut windowed_tasks, day_start);
+        for task in &windowed_tasks {
+            let earliest_start = earliest_start(task);
+            if let Some(deadline) = task.window_end_at.filter(|d| *d <= day_start) {
+                drop_task(task, UnschedulableReason::DeadlinePassed { deadline });
+                continue;
+            }
+            if let Some(earliest_start) = earliest_start.filter(|s| *s >= day_end) {
+                drop_task(task, UnschedulableReason::StartsAfterDay { earliest_start });
+                continue;
+            }
+            let window_start = earliest_start.unwrap_or(day_start).max(day_start);
+            let window_end = task.window_end_at.unwrap_or(day_end).min(day_end);
+            if window_end <= window_start {
+                drop_task(task, UnschedulableReason::EmptyWindow);
+                continue;
+            }
+            let gaps = crate::timeline::detect_time_gaps(&occupied, window_start, window_end);
+            let blocks =
+                self.pack_tasks_into_gaps(std::slice::from_ref(task), &gaps, 1, &mut budget);
+            occupied.extend(
+                blocks
+                    .iter()
+                    .map(|b| TimelineEvent::new(b.start_time, b.end_time)),
+            );
+            scheduled.extend(blocks);
+            if let Some(reason) = shortfall(task, &scheduled, budget.turned_away(&task.id)) {
+                drop_task(task, reason);
+            }
+        }
 
-        // 6. Sort by energy-aware priority (progressive focus)
-        self.sort_tasks_by_energy_and_priority(&mut ready_tasks, day_start);
+        // 9. Fill remaining gaps with unconstrained DurationOnly and
+        //    FlexWindow tasks, sorted by energy-aware priority
+        let mut duration_tasks: Vec<Task> = ready_tasks
+            .iter()
+            .copied()
+            .filter(has_remaining)
+            .filter(|t| match t.kind {
+                TaskKind::FixedEvent | TaskKind::BufferFill => false,
+                TaskKind::FlexWindow | TaskKind::DurationOnly => !has_time_constraint(t),
+                TaskKind::Break => true,
+            })
+            .cloned()
+            .collect();
+        self.sort_tasks_by_energy_and_priority(&mut duration_tasks, day_start);
+        let gaps = crate::timeline::detect_time_gaps(&occupied, day_start, day_end);
+        let blocks = self.pack_tasks_into_gaps(&duration_tasks, &gaps, max_lanes, &mut budget);
+        occupied.extend(
+            blocks
+                .iter()
+                .map(|b| TimelineEvent::new(b.start_time, b.end_time)),
+        );
+        scheduled.extend(blocks);
+        for task in &duration_tasks {
+            if let Some(reason) = shortfall(task, &scheduled, budget.turned_away(&task.id)) {
+                drop_task(task, reason);
+            }
+        }
 
-        // 7. Get max parallel lanes from template (default to 1 if not set)
-        let max_lanes = template.max_parallel_lanes.unwrap_or(1).max(1) as usize;
+        // 10. Expand BufferFill tasks into whatever gap space is left
+        let mut buffer_tasks: Vec<&Task> = ready_tasks
+            .iter()
+            .copied()
+            .filter(|t| t.kind == TaskKind::BufferFill)
+            .collect();
+        buffer_tasks.sort_by(|a, b| {
+            let key = |t: &Task| (std::cmp::Reverse(t.priority.unwrap_or(50)), t.id.clone());
+            key(a).cmp(&key(b))
+        });
+        for task in buffer_tasks {
+            let window_start = task.window_start_at.unwrap_or(day_start).max(day_start);
+            let window_end = task.window_end_at.unwrap_or(day_end).min(day_end);
+            if window_end <= window_start {
+                continue;
+            }
+            for gap in crate::timeline::detect_time_gaps(&occupied, window_start, window_end) {
+                if gap.duration_minutes() < self.config.min_gap_minutes {
+                    continue;
+                }
+                // Under a daily cap the buffer only takes what is left of it
+                let minutes = gap.duration_minutes().min(budget.available());
+                if !budget.allows(&task.id, minutes.max(self.config.min_gap_minutes)) {
+                    break;
+                }
+                budget.spend(minutes);
+                let end = gap.start_time + Duration::minutes(minutes);
+                let pomodoros = (minutes / self.config.focus_duration.max(1)).max(1);
+                occupied.push(TimelineEvent::new(gap.start_time, end));
+                scheduled.push(ScheduledBlock::new(
+                    task.id.clone(),
+                    task.title.clone(),
+                    gap.start_time,
+                    end,
+                    ScheduledBlockType::Focus,
+                    Some(0),
+                    pomodoros as i32,
+                    0,
+                ));
+            }
+        }
+
+        let utilization = gap_utilization(&free_gaps, &scheduled);
+        if !self.config.emit_break_blocks {
+            // Placement kept the break time free; only the blocks are dropped
+            scheduled.retain(|b| b.block_type != ScheduledBlockType::Break);
+        }
+        scheduled.extend(pinned);
+        scheduled.sort_by(|a, b| {
+            (a.start_time, a.lane.unwrap_or(-1), &a.task_id).cmp(&(
+                b.start_time,
+                b.lane.unwrap_or(-1),
+                &b.task_id,
+            ))
+        });
+        SchedulingOutcome {
+            blocks: scheduled,
+            unschedulable,
+            utilization,
+        }
+    }
+
+    /// Build the immovable block for a FixedEvent task on this day.
+    ///
+    /// The end falls back to `required_minutes`, then to the remaining
+    /// pomodoros. Tasks entirely outside the day window are skipped.
+    fn pin_fixed_task(
+        &self,
+        task: &Task,
+        day_start: DateTime<Utc>,
+        day_end: DateTime<Utc>,
+    ) -> Option<ScheduledBlock> {
+        let start = task.fixed_start_at?;
+        let remaining_pomodoros = (task.estimated_pomodoros - task.completed_pomodoros).max(1);
+        let end = task.fixed_end_at.unwrap_or_else(|| {
+            let minutes = task
+                .required_minutes
+                .map(i64::from)
+                .unwrap_or(i64::from(remaining_pomodoros) * self.config.focus_duration);
+            start + Duration::minutes(minutes)
+        });
+        if end <= start || end <= day_start || start >= day_end {
+            return None;
+        }
+
+        Some(ScheduledBlock {
+            pinned: true,
+            ..ScheduledBlock::new(
+                task.id.clone(),
+                task.title.clone(),
+                start,
+                end,
+                ScheduledBlockType::Focus,
+                Some(0),
+                remaining_pomodoros,
+                0,
+            )
+        })
+    }
+
+    /// Carry a locked timeline block over into the generated schedule.
+    ///
+    /// Routine and calendar blocks become focus blocks without pomodoros.
+    fn pin_locked_block(&self, block: &ScheduleBlock, tasks: &[Task]) -> ScheduledBlock {
+        let task_id = block.task_id.clone().unwrap_or_default();
+        let task_title = block
+            .label
+            .clone()
+            .or_else(|| {
+                tasks
+                    .iter()
+                    .find(|t| t.id == task_id)
+                    .map(|t| t.title.clone())
+            })
+            .unwrap_or_default();
+        let minutes = (block.end_time - block.start_time).num_minutes();
+        let (block_type, pomodoro_count, break_minutes) = match block.block_type {
+            BlockType::Focus => (
+                ScheduledBlockType::Focus,
+                (minutes / self.config.focus_duration.max(1)).max(1) as i32,
+                0,
+            ),
+            BlockType::Break => (ScheduledBlockType::Break, 0, minutes as i32),
+            BlockType::Routine | BlockType::Calendar => (ScheduledBlockType::Focus, 0, 0),
+        };
 
-        // 8. Assign tasks to gaps with parallel lane support
-        self.assign_tasks_to_gaps(&ready_tasks, &gaps, max_lanes)
+        ScheduledBlock {
+            id: block.id.clone(),
+            task_id,
+            task_title,
+            start_time: block.start_time,
+            end_time: block.end_time,
+            block_type,
+            lane: block.lane,
+            pomodoro_count,
+            break_minutes,
+            pinned: true,
+        }
     }
 
     /// Auto-fill available slots with top priority tasks
@@ -229,7 +658,7 @@ impl AutoScheduler {
     }
 
     /// Parse wake up and sleep times from template
-    fn parse_day_boundaries(
+    pub(crate) fn parse_day_boundaries(
         &self,
         template: &DailyTemplate,
         day: DateTime<Utc>,
@@ -267,7 +696,7 @@ impl AutoScheduler {
     }
 
     /// Build fixed events for a specific day
-    fn build_fixed_events(
+    pub(crate) fn build_fixed_events(
         &self,
         template: &DailyTemplate,
         day: DateTime<Utc>,
@@ -282,7 +711,38 @@ impl AutoScheduler {
             .collect()
     }
 
-    fn build_running_task_events(
+    /// Buffers kept free around today's meal events.
+    ///
+    /// Each meal gets `meal_buffer_minutes` before its start and after its
+    /// end, so focus does not run right up to a meal or start the moment it
+    /// ends.
+    pub(crate) fn build_meal_buffers(
+        &self,
+        template: &DailyTemplate,
+        day: DateTime<Utc>,
+    ) -> Vec<TimelineEvent> {
+        let buffer = Duration::minutes(self.config.meal_buffer_minutes.max(0));
+        if buffer.is_zero() {
+            return Vec::new();
+        }
+        let weekday = day.weekday().num_days_from_monday() as u8;
+
+        template
+            .fixed_events
+            .iter()
+            .filter(|event| event.enabled && event.kind == FixedEventKind::Meal)
+            .filter(|event| event.days.contains(&weekday))
+            .filter_map(|event| self.parse_fixed_event(event, day))
+            .flat_map(|meal| {
+                [
+                    TimelineEvent::new(meal.start_time - buffer, meal.start_time),
+                    TimelineEvent::new(meal.end_time, meal.end_time + buffer),
+                ]
+            })
+            .collect()
+    }
+
+    pub(crate) fn build_running_task_events(
         &self,
         tasks: &[Task],
         day_start: DateTime<Utc>,
@@ -302,12 +762,15 @@ impl AutoScheduler {
                     .max(1);
                 let remaining_minutes = (total_minutes - i64::from(task.elapsed_minutes)).max(1);
 
+                // Without a recorded start, the last update (usually the
+                // switch to Running) stands in, keeping this independent of
+                // the wall clock
                 let start = task
                     .started_at
                     .or(task.fixed_start_at)
                     .or(task.window_start_at)
                     .or(task.estimated_start_at)
-                    .unwrap_or_else(Utc::now);
+                    .unwrap_or(task.updated_at);
 
                 let end = start + Duration::minutes(remaining_minutes);
                 let clipped_start = start.max(day_start);
@@ -349,6 +812,9 @@ impl AutoScheduler {
     /// - Morning (6-12): HIGH energy tasks first
     /// - Afternoon (12-17): MEDIUM energy tasks first
     /// - Evening (17-22): LOW energy tasks first
+    ///
+    /// Remaining ties go by task id, so the order never depends on the
+    /// order tasks were passed in.
     fn sort_tasks_by_energy_and_priority(&self, tasks: &mut Vec<Task>, day_start: DateTime<Utc>) {
         let hour = day_start.hour();
         let preferred_energy = if hour < 12 {
@@ -375,7 +841,7 @@ impl AutoScheduler {
                             match (&a.project_id, &b.project_id) {
                                 (Some(_), None) => std::cmp::Ordering::Less,
                                 (None, Some(_)) => std::cmp::Ordering::Greater,
-                                _ => std::cmp::Ordering::Equal,
+                                _ => a.id.cmp(&b.id),
                             }
                         }
                         other => other,
@@ -393,14 +859,26 @@ impl AutoScheduler {
     ///
     /// When `allow_split = false`, the task is scheduled as one continuous block
     /// without breaks inside (e.g., sleep, long meetings).
+    ///
+    /// Splittable tasks stay in their lane until all their pomodoros are placed.
+    /// Every focus round is followed by `short_break`, or `long_break` after each
+    /// `pomodoros_before_long_break` rounds within a gap.
     fn assign_tasks_to_gaps(
         &self,
         tasks: &[Task],
         gaps: &[crate::timeline::TimeGap],
         max_lanes: usize,
+        budget: &mut FocusBudget,
     ) -> Vec<ScheduledBlock> {
         let mut scheduled = Vec::new();
         let mut next_task_idx: usize = 0;
+        let mut remaining: Vec<i32> = tasks
+            .iter()
+            .map(|t| (t.estimated_pomodoros - t.completed_pomodoros).max(0))
+            .collect();
+        // Task each lane is working through; a lane keeps its task until all
+        // of its pomodoros are placed, even across gaps.
+        let mut lane_tasks: Vec<Option<usize>> = vec![None; max_lanes];
 
         for gap in gaps {
             if gap.duration_minutes() < self.config.min_gap_minutes {
@@ -408,41 +886,55 @@ impl AutoScheduler {
             }
             let mut cursor = gap.start_time;
             let gap_end = gap.end_time;
+            // Focus rounds placed in this gap, used for the long break cadence
+            let mut rounds: i32 = 0;
+            // Focus minutes since the last long break
+            let mut continuous: i64 = 0;
+
+            while next_task_idx < tasks.len() || lane_tasks.iter().any(Option::is_some) {
+                // Only start a non-splittable task once no lane is mid-task
+                if lane_tasks.iter().all(Option::is_none) {
+                    // Peek at the first task to check allow_split
+                    let task = &tasks[next_task_idx];
+                    let remaining_pomodoros = remaining[next_task_idx];
+
+                    if remaining_pomodoros == 0 {
+                        next_task_idx += 1;
+                        continue;
+                    }
 
-            while next_task_idx < tasks.len() {
-                // Peek at the first task to check allow_split
-                let task = &tasks[next_task_idx];
-                let remaining_pomodoros =
-                    (task.estimated_pomodoros - task.completed_pomodoros).max(0);
-
-                if remaining_pomodoros == 0 {
-                    next_task_idx += 1;
-                    continue;
-                }
+                    // For non-splittable tasks, schedule as one continuous block
+                    if !task.allow_split {
+                        let total_minutes =
+                            (remaining_pomodoros as i64) * self.config.focus_duration;
+                        let task_end = cursor + Duration::minutes(total_minutes);
 
-                // For non-splittable tasks, schedule as one continuous block
-                if !task.allow_split {
-                    let total_minutes = (remaining_pomodoros as i64) * self.config.focus_duration;
-                    let task_end = cursor + Duration::minutes(total_minutes);
+                        if task_end > gap_end {
+                            // Not enough space in this gap, move to next gap
+                            break;
+                        }
+                        if !budget.allows(&task.id, total_minutes) {
+                            // Over the daily cap in any gap; leave the task out
+                            next_task_idx += 1;
+                            continue;
+                        }
+                        budget.spend(total_minutes);
 
-                    if task_end > gap_end {
-                        // Not enough space in this gap, move to next gap
-                        break;
+                        scheduled.push(ScheduledBlock::new(
+                            task.id.clone(),
+                            task.title.clone(),
+                            cursor,
+                            task_end,
+                            ScheduledBlockType::Focus,
+                            Some(0), // Non-splittable tasks use lane 0
+                            remaining_pomodoros,
+                            0, // No breaks for non-splittable tasks
+                        ));
+                        cursor = task_end;
+                        remaining[next_task_idx] = 0;
+                        next_task_idx += 1;
+                        continue;
                     }
-
-                    scheduled.push(ScheduledBlock::new(
-                        task.id.clone(),
-                        task.title.clone(),
-                        cursor,
-                        task_end,
-                        ScheduledBlockType::Focus,
-                        Some(0), // Non-splittable tasks use lane 0
-                        remaining_pomodoros,
-                        0, // No breaks for non-splittable tasks
-                    ));
-                    cursor = task_end;
-                    next_task_idx += 1;
-                    continue;
                 }
 
                 // For splittable tasks, use the standard Pomodoro rhythm with breaks
@@ -450,27 +942,41 @@ impl AutoScheduler {
                 if focus_end > gap_end {
                     break;
                 }
+                if budget.available() < self.config.focus_duration {
+                    // The cap never frees up again, so every task still
+                    // waiting in this pass is turned away by it
+                    let waiting = lane_tasks
+                        .iter()
+                        .flatten()
+                        .copied()
+                        .chain(next_task_idx..tasks.len());
+                    for idx in waiting.filter(|&idx| remaining[idx] > 0) {
+                        budget.turn_away(&tasks[idx].id);
+                    }
+                    break;
+                }
 
+                let round_start = scheduled.len();
                 let mut active_lanes: Vec<i32> = Vec::new();
-                let mut task_idx = next_task_idx;
-                for lane_idx in 0..max_lanes {
-                    // Find the next splittable task (skip non-splittable ones)
-                    while task_idx < tasks.len() {
-                        let t = &tasks[task_idx];
-                        let remaining = (t.estimated_pomodoros - t.completed_pomodoros).max(0);
-                        if remaining == 0 || !t.allow_split {
-                            task_idx += 1;
+                for (lane_idx, lane_task) in lane_tasks.iter_mut().enumerate() {
+                    if lane_task.is_none() {
+                        // Find the next splittable task (skip non-splittable ones)
+                        while next_task_idx < tasks.len()
+                            && (remaining[next_task_idx] == 0 || !tasks[next_task_idx].allow_split)
+                        {
+                            next_task_idx += 1;
+                        }
+                        if next_task_idx >= tasks.len() {
                             continue;
                         }
-                        break;
-                    }
-
-                    if task_idx >= tasks.len() {
-                        break;
+                        *lane_task = Some(next_task_idx);
+                        next_task_idx += 1;
                     }
+                    let Some(task_idx) = *lane_task else {
+                        continue;
+                    };
 
                     let task = &tasks[task_idx];
-
                     scheduled.push(ScheduledBlock::new(
                         task.id.clone(),
                         task.title.clone(),
@@ -479,25 +985,47 @@ impl AutoScheduler {
                         ScheduledBlockType::Focus,
                         Some(lane_idx as i32),
                         1,
-                        self.config.short_break as i32,
+                        0,
                     ));
                     active_lanes.push(lane_idx as i32);
-                    task_idx += 1;
-                }
 
-                // Update next_task_idx to skip all processed tasks
-                // But keep non-splittable tasks for the next outer iteration
-                next_task_idx = task_idx;
+                    remaining[task_idx] -= 1;
+                    if remaining[task_idx] == 0 {
+                        *lane_task = None;
+                    }
+                }
 
                 if active_lanes.is_empty() {
                     break;
                 }
 
+                budget.spend(self.config.focus_duration);
                 cursor = focus_end;
-                let break_end = cursor + Duration::minutes(self.config.short_break);
+                rounds += 1;
+                continuous += self.config.focus_duration;
+                let fatigued = self
+                    .config
+                    .max_continuous_focus_minutes
+                    .is_some_and(|cap| continuous + self.config.focus_duration > cap);
+                let break_minutes = if fatigued
+                    || (self.config.pomodoros_before_long_break > 0
+                        && rounds % self.config.pomodoros_before_long_break == 0)
+                {
+                    // The long break cadence restarts after any long break
+                    rounds = 0;
+                    continuous = 0;
+                    self.config.long_break
+                } else {
+                    self.config.short_break
+                };
+                let break_end = cursor + Duration::minutes(break_minutes);
                 if break_end > gap_end {
+                    // No room for a break; the gap boundary ends the run
                     break;
                 }
+                for block in &mut scheduled[round_start..] {
+                    block.break_minutes = break_minutes as i32;
+                }
 
                 match self.config.parallel_break_policy {
                     ParallelBreakPolicy::Shared => {
@@ -533,6 +1061,88 @@ impl AutoScheduler {
 
         scheduled
     }
+
+    /// Place `tasks` into `gaps` with the configured packing strategy.
+    fn pack_tasks_into_gaps(
+        &self,
+        tasks: &[Task],
+        gaps: &[crate::timeline::TimeGap],
+        max_lanes: usize,
+        budget: &mut FocusBudget,
+    ) -> Vec<ScheduledBlock> {
+        match self.config.packing_strategy {
+            PackingStrategy::Greedy => self.assign_tasks_to_gaps(tasks, gaps, max_lanes, budget),
+            PackingStrategy::BestFit => self.best_fit_into_gaps(tasks, gaps, max_lanes, budget),
+        }
+    }
+
+    /// Fill gaps one at a time with the task set that uses each best.
+    ///
+    /// The chosen tasks, followed by the splittable leftovers, are placed by
+    /// `assign_tasks_to_gaps`, so block layout and breaks match `Greedy`.
+    fn best_fit_into_gaps(
+        &self,
+        tasks: &[Task],
+        gaps: &[crate::timeline::TimeGap],
+        max_lanes: usize,
+        budget: &mut FocusBudget,
+    ) -> Vec<ScheduledBlock> {
+        let lanes = max_lanes.max(1) as i64;
+        let mut pending: Vec<Task> = tasks.to_vec();
+        let mut scheduled = Vec::new();
+
+        for gap in gaps {
+            if gap.duration_minutes() < self.config.min_gap_minutes {
+                continue;
+            }
+            let open: Vec<usize> = (0..pending.len())
+                .filter(|&i| pending[i].estimated_pomodoros > pending[i].completed_pomodoros)
+                .collect();
+            if open.is_empty() {
+                break;
+            }
+
+            // Size of each task placed whole, in lane-minutes. A
+            // non-splittable task runs alone, so it takes every lane.
+            let sizes: Vec<usize> = open
+                .iter()
+                .map(|&i| {
+                    let task = &pending[i];
+                    let pomodoros = i64::from(task.estimated_pomodoros - task.completed_pomodoros);
+                    let minutes = if task.allow_split {
+                        pomodoros * (self.config.focus_duration + self.config.short_break)
+                            - self.config.short_break
+                    } else {
+                        pomodoros * self.config.focus_duration * lanes
+                    };
+                    minutes.max(0) as usize
+                })
+                .collect();
+            let capacity = (gap.duration_minutes() * lanes).max(0) as usize;
+            let chosen = best_fit_subset(&sizes, capacity);
+
+            let mut order: Vec<Task> = Vec::with_capacity(open.len());
+            order.extend(chosen.iter().map(|&k| pending[open[k]].clone()));
+            order.extend(
+                (0..open.len())
+                    .filter(|k| !chosen.contains(k) && pending[open[*k]].allow_split)
+                    .map(|k| pending[open[k]].clone()),
+            );
+
+            let blocks =
+                self.assign_tasks_to_gaps(&order, std::slice::from_ref(gap), max_lanes, budget);
+            for block in &blocks {
+                if block.block_type != ScheduledBlockType::Focus {
+                    continue;
+                }
+                if let Some(task) = pending.iter_mut().find(|t| t.id == block.task_id) {
+                    task.completed_pomodoros += block.pomodoro_count;
+                }
+            }
+            scheduled.extend(blocks);
+        }
+        scheduled
+    }
 }
 
 impl Default for AutoScheduler {
@@ -541,48 +1151,193 @@ impl Default for AutoScheduler {
     }
 }
 
-/// Calculate energy level match score for task prioritization.
-///
-/// Returns higher score for tasks matching the preferred energy level:
-/// - Exact match: 3
-/// - One level off: 1
-/// - Two levels off: 0
-fn energy_level_match_score(task_energy: EnergyLevel, preferred: EnergyLevel) -> u8 {
-    match (task_energy, preferred) {
-        (EnergyLevel::High, EnergyLevel::High)
-        | (EnergyLevel::Medium, EnergyLevel::Medium)
-        | (EnergyLevel::Low, EnergyLevel::Low) => 3,
-        (EnergyLevel::High, EnergyLevel::Medium)
-        | (EnergyLevel::Medium, EnergyLevel::High)
-        | (EnergyLevel::Medium, EnergyLevel::Low)
-        | (EnergyLevel::Low, EnergyLevel::Medium) => 1,
-        _ => 0, // High vs Low mismatch
-    }
+/// Focus minutes left under `SchedulerConfig::max_daily_focus_minutes`.
+struct FocusBudget {
+    /// `None` when there is no daily cap
+    remaining: Option<i64>,
+    /// Tasks the cap has turned focus away from
+    turned_away: HashSet<String>,
 }
 
-/// Convert Task to timeline item for priority calculation
-pub fn task_to_timeline_item(task: &Task) -> crate::timeline::TimelineItem {
-    let estimated_minutes =
-        (task.estimated_pomodoros - task.completed_pomodoros).max(0) as i64 * 25;
+impl FocusBudget {
+    fn new(cap: Option<i64>, spent: i64) -> Self {
+        Self {
+            remaining: cap.map(|cap| (cap - spent).max(0)),
+            turned_away: HashSet::new(),
+        }
+    }
 
-    crate::timeline::TimelineItem::new(
-        task.id.clone(),
-        crate::timeline::TimelineItemType::Task,
-        crate::timeline::TimelineItemSource::Manual,
-        &task.title,
-        Utc::now(),
-        Utc::now() + Duration::minutes(estimated_minutes),
-    )
-    .with_priority(task.priority.unwrap_or(50) as u8)
-}
+    fn available(&self) -> i64 {
+        self.remaining.unwrap_or(i64::MAX)
+    }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use crate::task::{EnergyLevel, TaskKind, TaskState};
+    /// Whether `minutes` more focus for `task_id` fit under the cap;
+    /// records a refusal against the task.
+    fn allows(&mut self, task_id: &str, minutes: i64) -> bool {
+        let fits = minutes <= self.available();
+        if !fits {
+            self.turn_away(task_id);
+        }
+        fits
+    }
 
-    // Property-based testing imports
-    use proptest::prelude::*;
+    fn turn_away(&mut self, task_id: &str) {
+        self.turned_away.insert(task_id.to_string());
+    }
+
+    /// Whether the cap has turned focus away from `task_id`.
+    fn turned_away(&self, task_id: &str) -> bool {
+        self.turned_away.contains(task_id)
+    }
+
+    fn spend(&mut self, minutes: i64) {
+        if let Some(remaining) = &mut self.remaining {
+            *remaining = (*remaining - minutes).max(0);
+        }
+    }
+}
+
+/// Indices of the items whose sizes sum closest to `capacity` without
+/// exceeding it, in input order.
+///
+/// Classic 0/1 knapsack over minutes. Only a strictly better fill replaces
+/// an earlier choice, so ties keep the higher-priority (earlier) items and
+/// the result is deterministic.
+fn best_fit_subset(sizes: &[usize], capacity: usize) -> Vec<usize> {
+    // best[c]: largest fill reachable within c; taken[i][c]: item i used there
+    let mut best = vec![0usize; capacity + 1];
+    let mut taken = vec![vec![false; capacity + 1]; sizes.len()];
+    for (i, &size) in sizes.iter().enumerate() {
+        if size == 0 || size > capacity {
+            continue;
+        }
+        for c in (size..=capacity).rev() {
+            let fill = best[c - size] + size;
+            if fill > best[c] {
+                best[c] = fill;
+                taken[i][c] = true;
+            }
+        }
+    }
+
+    let mut chosen = Vec::new();
+    let mut c = capacity;
+    for i in (0..sizes.len()).rev() {
+        if taken[i][c] {
+            chosen.push(i);
+            c -= sizes[i];
+        }
+    }
+    chosen.reverse();
+    chosen
+}
+
+/// Share of the free gaps covered by `blocks`, counting overlapping lanes once.
+fn gap_utilization(gaps: &[crate::timeline::TimeGap], blocks: &[ScheduledBlock]) -> f64 {
+    let free: i64 = gaps.iter().map(|g| g.duration_minutes()).sum();
+    if free <= 0 {
+        return 0.0;
+    }
+
+    let mut spans: Vec<(DateTime<Utc>, DateTime<Utc>)> =
+        blocks.iter().map(|b| (b.start_time, b.end_time)).collect();
+    spans.sort();
+    let mut merged: Vec<(DateTime<Utc>, DateTime<Utc>)> = Vec::new();
+    for (start, end) in spans {
+        match merged.last_mut() {
+            Some(last) if start <= last.1 => last.1 = last.1.max(end),
+            _ => merged.push((start, end)),
+        }
+    }
+
+    let used: i64 = gaps
+        .iter()
+        .flat_map(|gap| {
+            merged.iter().map(move |(start, end)| {
+                let overlap = (*end).min(gap.end_time) - (*start).max(gap.start_time);
+                overlap.num_minutes().max(0)
+            })
+        })
+        .sum();
+    (used as f64 / free as f64).min(1.0)
+}
+
+/// Whether a task must be placed inside its own time window.
+fn has_time_constraint(task: &Task) -> bool {
+    matches!(task.kind, TaskKind::FlexWindow | TaskKind::DurationOnly)
+        && (earliest_start(task).is_some() || task.window_end_at.is_some())
+}
+
+/// Earliest allowed start: the later of `window_start_at` and `fixed_start_at`.
+fn earliest_start(task: &Task) -> Option<DateTime<Utc>> {
+    task.window_start_at.max(task.fixed_start_at)
+}
+
+/// `InsufficientTime` when fewer pomodoros were placed than the task needs,
+/// or `DailyFocusCap` when the daily cap turned the task's focus away.
+fn shortfall(
+    task: &Task,
+    scheduled: &[ScheduledBlock],
+    capped: bool,
+) -> Option<UnschedulableReason> {
+    let required = (task.estimated_pomodoros - task.completed_pomodoros).max(0);
+    let placed: i32 = scheduled
+        .iter()
+        .filter(|b| b.block_type == ScheduledBlockType::Focus && b.task_id == task.id)
+        .map(|b| b.pomodoro_count)
+        .sum();
+    (placed < required).then_some(if capped {
+        UnschedulableReason::DailyFocusCap { placed, required }
+    } else {
+        UnschedulableReason::InsufficientTime { placed, required }
+    })
+}
+
+/// Calculate energy level match score for task prioritization.
+///
+/// Returns higher score for tasks matching the preferred energy level:
+/// - Exact match: 3
+/// - One level off: 1
+/// - Two levels off: 0
+fn energy_level_match_score(task_energy: EnergyLevel, preferred: EnergyLevel) -> u8 {
+    match (task_energy, preferred) {
+        (EnergyLevel::High, EnergyLevel::High)
+        | (EnergyLevel::Medium, EnergyLevel::Medium)
+        | (EnergyLevel::Low, EnergyLevel::Low) => 3,
+        (EnergyLevel::High, EnergyLevel::Medium)
+        | (EnergyLevel::Medium, EnergyLevel::High)
+        | (EnergyLevel::Medium, EnergyLevel::Low)
+        | (EnergyLevel::Low, EnergyLevel::Medium) => 1,
+        _ => 0, // High vs Low mismatch
+    }
+}
+
+/// Convert Task to timeline item for priority calculation
+///
+/// The item spans the task's remaining pomodoros from `start`.
+pub fn task_to_timeline_item(task: &Task, start: DateTime<Utc>) -> crate::timeline::TimelineItem {
+    let estimated_minutes =
+        (task.estimated_pomodoros - task.completed_pomodoros).max(0) as i64 * 25;
+
+    crate::timeline::TimelineItem::new(
+        task.id.clone(),
+        crate::timeline::TimelineItemType::Task,
+        crate::timeline::TimelineItemSource::Manual,
+        &task.title,
+        start,
+        start + Duration::minutes(estimated_minutes),
+    )
+    .with_priority(task.priority.unwrap_or(50) as u8)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::simulation::{check_invariants, InvariantViolation, PlanningInvariant};
+    use crate::task::{EnergyLevel, TaskKind, TaskState};
+
+    // Property-based testing imports
+    use proptest::prelude::*;
 
     fn make_test_task(id: &str, priority: i32, estimated: i32) -> Task {
         Task {
@@ -623,6 +1378,10 @@ mod tests {
             allow_split: true,
             suggested_tags: Vec::new(),
             approved_tags: Vec::new(),
+            external_block: None,
+            recurrence: None,
+            recurrence_parent_id: None,
+            notes: Vec::new(),
         }
     }
 
@@ -670,6 +1429,10 @@ mod tests {
             allow_split: true,
             suggested_tags: Vec::new(),
             approved_tags: Vec::new(),
+            external_block: None,
+            recurrence: None,
+            recurrence_parent_id: None,
+            notes: Vec::new(),
         }
     }
 
@@ -684,6 +1447,7 @@ mod tests {
                 duration_minutes: 60,
                 days: vec![0, 1, 2, 3, 4, 5, 6], // All days
                 enabled: true,
+                kind: FixedEventKind::Other,
             }],
             max_parallel_lanes: Some(2),
         }
@@ -716,6 +1480,7 @@ mod tests {
             duration_minutes: 120,
             days: vec![0, 1, 2, 3, 4, 5, 6],
             enabled: true,
+            kind: FixedEventKind::Other,
         });
 
         let day = Utc::now();
@@ -735,6 +1500,42 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_focus_keeps_meal_buffer() {
+        let scheduler = AutoScheduler::new();
+        let mut template = make_test_template();
+        template.max_parallel_lanes = Some(1);
+        template.fixed_events[0].kind = FixedEventKind::Meal;
+        let buffer = scheduler.config.meal_buffer_minutes;
+
+        let day = Utc::now();
+        let tasks = vec![make_test_task("1", 80, 12)];
+        let scheduled = scheduler.generate_schedule(&template, &tasks, &[], day);
+
+        let lunch_start = day
+            .with_hour(12)
+            .unwrap()
+            .with_minute(0)
+            .unwrap()
+            .with_second(0)
+            .unwrap()
+            .with_nanosecond(0)
+            .unwrap();
+        let lunch_end = lunch_start + Duration::minutes(60);
+        let focus: Vec<_> = scheduled
+            .iter()
+            .filter(|b| b.block_type == ScheduledBlockType::Focus)
+            .collect();
+        assert!(focus.iter().any(|b| b.end_time <= lunch_start));
+        for block in focus {
+            if block.end_time <= lunch_start {
+                assert!(block.end_time <= lunch_start - Duration::minutes(buffer));
+            } else {
+                assert!(block.start_time >= lunch_end + Duration::minutes(buffer));
+            }
+        }
+    }
+
     #[test]
     fn test_task_priority_ordering() {
         let scheduler = AutoScheduler::new();
@@ -780,6 +1581,23 @@ mod tests {
         assert_eq!(scheduled[0].task_id, "ready");
     }
 
+    #[test]
+    fn test_recurring_templates_not_scheduled() {
+        let scheduler = AutoScheduler::new();
+        let template = make_test_template();
+        let day = Utc::now();
+
+        let mut recurring = make_test_task("recurring", 90, 1);
+        recurring.recurrence = Some(crate::task::Recurrence::daily());
+        let ready_task = make_test_task("ready", 60, 1);
+
+        let tasks = vec![recurring, ready_task];
+        let scheduled = scheduler.generate_schedule(&template, &tasks, &[], day);
+
+        assert!(!scheduled.is_empty());
+        assert!(scheduled.iter().all(|b| b.task_id != "recurring"));
+    }
+
     #[test]
     fn test_running_task_blocks_new_schedule_until_its_remaining_time() {
         let scheduler = AutoScheduler::new();
@@ -1123,6 +1941,10 @@ mod tests {
                 allow_split: true,
                 suggested_tags: Vec::new(),
                 approved_tags: Vec::new(),
+                external_block: None,
+                recurrence: None,
+                recurrence_parent_id: None,
+                notes: Vec::new(),
             }
         })
     }
@@ -1136,6 +1958,7 @@ mod tests {
             duration_minutes: duration as i32,
             days: vec![day.weekday().num_days_from_monday() as u8],
             enabled: true,
+            kind: FixedEventKind::Other,
         })
     }
 
@@ -1152,6 +1975,20 @@ mod tests {
         })
     }
 
+    /// Violations of `invariant` in a generated schedule
+    fn violations_of(
+        invariant: PlanningInvariant,
+        blocks: &[ScheduledBlock],
+        template: &DailyTemplate,
+        events: &[CalendarEvent],
+        day: DateTime<Utc>,
+    ) -> Vec<InvariantViolation> {
+        check_invariants(blocks, template, events, day)
+            .into_iter()
+            .filter(|v| v.invariant == invariant)
+            .collect()
+    }
+
     proptest! {
         /// Invariant: No two scheduled blocks should overlap
         #[test]
@@ -1172,20 +2009,71 @@ mod tests {
 
             let scheduled = scheduler.generate_schedule(&template, &tasks, &calendar_events, day);
 
-            // Check no overlaps between any two blocks
-            for i in 0..scheduled.len() {
-                for j in (i + 1)..scheduled.len() {
-                    let block_a = &scheduled[i];
-                    let block_b = &scheduled[j];
-                    prop_assert!(
-                        !(block_a.start_time < block_b.end_time && block_a.end_time > block_b.start_time),
-                        "Blocks {} and {} overlap: [{:?}, {:?}) vs [{:?}, {:?})",
-                        block_a.id, block_b.id,
-                        block_a.start_time, block_a.end_time,
-                        block_b.start_time, block_b.end_time
-                    );
-                }
-            }
+            let violations = violations_of(
+                PlanningInvariant::NoOverlap, &scheduled, &template, &calendar_events, day,
+            );
+            prop_assert!(violations.is_empty(), "{:?}", violations);
+        }
+
+        /// Invariant: BestFit packing keeps every planning invariant
+        #[test]
+        fn prop_best_fit_keeps_invariants(
+            tasks in prop::collection::vec(arbitrary_task(), 1..10),
+            calendar_events in prop::collection::vec(
+                arbitrary_calendar_event(Utc::now()), 0..5
+            )
+        ) {
+            let scheduler = AutoScheduler::with_config(SchedulerConfig {
+                packing_strategy: PackingStrategy::BestFit,
+                ..SchedulerConfig::default()
+            });
+            let template = DailyTemplate {
+                wake_up: "08:00".to_string(),
+                sleep: "20:00".to_string(),
+                fixed_events: vec![],
+                max_parallel_lanes: Some(2),
+            };
+            let day = Utc::now();
+
+            let outcome = scheduler.generate_outcome(&template, &tasks, &calendar_events, day);
+
+            let violations = check_invariants(&outcome.blocks, &template, &calendar_events, day);
+            prop_assert!(violations.is_empty(), "{:?}", violations);
+            prop_assert!((0.0..=1.0).contains(&outcome.utilization));
+        }
+
+        /// Scheduling is a pure function of its inputs: the same tasks, in
+        /// any order, give the same blocks
+        #[test]
+        fn prop_identical_inputs_give_identical_schedules(
+            tasks in prop::collection::vec(arbitrary_task(), 1..10),
+            calendar_events in prop::collection::vec(
+                arbitrary_calendar_event(Utc::now()), 0..5
+            )
+        ) {
+            let scheduler = AutoScheduler::new();
+            let template = DailyTemplate {
+                wake_up: "08:00".to_string(),
+                sleep: "20:00".to_string(),
+                fixed_events: vec![],
+                max_parallel_lanes: Some(2),
+            };
+            let day = Utc::now();
+            let without_ids = |blocks: Vec<ScheduledBlock>| -> Vec<ScheduledBlock> {
+                blocks
+                    .into_iter()
+                    .map(|b| ScheduledBlock { id: String::new(), ..b })
+                    .collect()
+            };
+
+            let first = scheduler.generate_schedule(&template, &tasks, &calendar_events, day);
+            let second = scheduler.generate_schedule(&template, &tasks, &calendar_events, day);
+            let reversed: Vec<Task> = tasks.iter().rev().cloned().collect();
+            let third = scheduler.generate_schedule(&template, &reversed, &calendar_events, day);
+
+            let first = without_ids(first);
+            prop_assert_eq!(&first, &without_ids(second));
+            prop_assert_eq!(&first, &without_ids(third));
         }
 
         /// Invariant: All scheduled blocks must have positive duration
@@ -1204,14 +2092,10 @@ mod tests {
 
             let scheduled = scheduler.generate_schedule(&template, &tasks, &[], day);
 
-            for block in &scheduled {
-                let duration = block.duration_minutes();
-                prop_assert!(
-                    duration > 0,
-                    "Block {} has non-positive duration: {} minutes",
-                    block.id, duration
-                );
-            }
+            let violations = violations_of(
+                PlanningInvariant::PositiveDuration, &scheduled, &template, &[], day,
+            );
+            prop_assert!(violations.is_empty(), "{:?}", violations);
         }
 
         /// Invariant: Scheduled blocks must not overlap with fixed events
@@ -1231,24 +2115,10 @@ mod tests {
 
             let scheduled = scheduler.generate_schedule(&template, &tasks, &[], day);
 
-            // Parse fixed event time
-            let parts: Vec<&str> = fixed_event.start_time.split(':').collect();
-            if parts.len() == 2 {
-                if let (Ok(hour), Ok(minute)) = (parts[0].parse::<u32>(), parts[1].parse::<u32>()) {
-                    let event_start = day.with_hour(hour).and_then(|d| d.with_minute(minute)).and_then(|d| d.with_second(0)).and_then(|d| d.with_nanosecond(0));
-                    if let Some(start) = event_start {
-                        let event_end = start + Duration::minutes(fixed_event.duration_minutes as i64);
-
-                        for block in &scheduled {
-                            prop_assert!(
-                                !(block.start_time < event_end && block.end_time > start),
-                                "Block {} overlaps with fixed event: [{:?}, {:?}) vs [{:?}, {:?})",
-                                block.id, block.start_time, block.end_time, start, event_end
-                            );
-                        }
-                    }
-                }
-            }
+            let violations = violations_of(
+                PlanningInvariant::NoFixedEventOverlap, &scheduled, &template, &[], day,
+            );
+            prop_assert!(violations.is_empty(), "{:?}", violations);
         }
 
         /// Invariant: Scheduled blocks must not overlap with calendar events
@@ -1266,16 +2136,13 @@ mod tests {
             };
             let day = Utc::now();
 
-            let scheduled = scheduler.generate_schedule(&template, &tasks, &[calendar_event.clone()], day);
+            let events = [calendar_event];
+            let scheduled = scheduler.generate_schedule(&template, &tasks, &events, day);
 
-            for block in &scheduled {
-                prop_assert!(
-                    !(block.start_time < calendar_event.end_time && block.end_time > calendar_event.start_time),
-                    "Block {} overlaps with calendar event: [{:?}, {:?}) vs [{:?}, {:?})",
-                    block.id, block.start_time, block.end_time,
-                    calendar_event.start_time, calendar_event.end_time
-                );
-            }
+            let violations = violations_of(
+                PlanningInvariant::NoCalendarOverlap, &scheduled, &template, &events, day,
+            );
+            prop_assert!(violations.is_empty(), "{:?}", violations);
         }
 
         /// Invariant: All scheduled blocks must be within day boundaries
@@ -1292,18 +2159,12 @@ mod tests {
             };
             let day = Utc::now();
 
-            let day_start = day.with_hour(8).unwrap().with_minute(0).unwrap().with_second(0).unwrap().with_nanosecond(0).unwrap();
-            let day_end = day.with_hour(18).unwrap().with_minute(0).unwrap().with_second(0).unwrap().with_nanosecond(0).unwrap();
-
             let scheduled = scheduler.generate_schedule(&template, &tasks, &[], day);
 
-            for block in &scheduled {
-                prop_assert!(
-                    block.start_time >= day_start && block.end_time <= day_end,
-                    "Block {} outside day boundaries: [{:?}, {:?}) not in [{:?}, {:?})",
-                    block.id, block.start_time, block.end_time, day_start, day_end
-                );
-            }
+            let violations = violations_of(
+                PlanningInvariant::WithinDay, &scheduled, &template, &[], day,
+            );
+            prop_assert!(violations.is_empty(), "{:?}", violations);
         }
 
         /// Invariant: No duplicate task IDs in scheduled blocks
@@ -1334,6 +2195,237 @@ mod tests {
         }
     }
 
+    fn single_lane_template() -> DailyTemplate {
+        DailyTemplate {
+            wake_up: "09:00".to_string(),
+            sleep: "18:00".to_string(),
+            fixed_events: vec![],
+            max_parallel_lanes: Some(1),
+        }
+    }
+
+    #[test]
+    fn test_flex_window_task_not_scheduled_outside_window() {
+        let scheduler = AutoScheduler::new();
+        let day = Utc::now();
+        let window_start = day.with_hour(14).unwrap().with_minute(0).unwrap();
+        let window_end = day.with_hour(16).unwrap().with_minute(0).unwrap();
+
+        let mut flex = make_test_task("flex", 90, 2);
+        flex.kind = TaskKind::FlexWindow;
+        flex.window_start_at = Some(window_start);
+        flex.window_end_at = Some(window_end);
+
+        let scheduled = scheduler.generate_schedule(&single_lane_template(), &[flex], &[], day);
+
+        let focus: Vec<_> = scheduled
+            .iter()
+            .filter(|b| b.task_id == "flex" && b.block_type == ScheduledBlockType::Focus)
+            .collect();
+        // The morning gap is free, but the task must still wait for its window.
+        assert!(!focus.is_empty());
+        for block in focus {
+            assert!(block.start_time >= window_start && block.end_time <= window_end);
+        }
+    }
+
+    #[test]
+    fn test_duration_task_with_earliest_start_skips_morning_gap() {
+        let scheduler = AutoScheduler::new();
+        let day = Utc::now();
+        let earliest = day.with_hour(14).unwrap().with_minute(0).unwrap();
+
+        let mut later = make_test_task("later", 90, 2);
+        later.window_start_at = Some(earliest);
+        let free = make_test_task("free", 10, 1);
+
+        let outcome = scheduler.generate_outcome(&single_lane_template(), &[later, free], &[], day);
+
+        let later_blocks: Vec<_> = outcome
+            .blocks
+            .iter()
+            .filter(|b| b.task_id == "later")
+            .collect();
+        assert_eq!(later_blocks.len(), 2);
+        assert!(later_blocks.iter().all(|b| b.start_time >= earliest));
+        // The 9:00 gap goes to the unconstrained task instead
+        let first = outcome
+            .blocks
+            .iter()
+            .find(|b| b.block_type == ScheduledBlockType::Focus)
+            .unwrap();
+        assert_eq!(first.task_id, "free");
+        assert_eq!(first.start_time.hour(), 9);
+        assert!(outcome.unschedulable.is_empty());
+    }
+
+    #[test]
+    fn test_task_past_its_deadline_is_dropped_with_reason() {
+        let scheduler = AutoScheduler::new();
+        let day = Utc::now();
+        let deadline = day.with_hour(9).unwrap().with_minute(0).unwrap() - Duration::days(1);
+
+        let mut overdue = make_test_task("overdue", 90, 1);
+        overdue.window_end_at = Some(deadline);
+        let mut too_big = make_test_task("too-big", 80, 4);
+        too_big.window_start_at = Some(day.with_hour(17).unwrap().with_minute(0).unwrap());
+
+        let outcome =
+            scheduler.generate_outcome(&single_lane_template(), &[overdue, too_big], &[], day);
+
+        assert!(outcome.blocks.iter().all(|b| b.task_id != "overdue"));
+        assert_eq!(outcome.unschedulable.len(), 2);
+        let reason_for = |id: &str| {
+            outcome
+                .unschedulable
+                .iter()
+                .find(|u| u.task_id == id)
+                .map(|u| u.reason.clone())
+                .unwrap()
+        };
+        assert_eq!(
+            reason_for("overdue"),
+            UnschedulableReason::DeadlinePassed { deadline }
+        );
+        assert_eq!(
+            reason_for("too-big"),
+            UnschedulableReason::InsufficientTime {
+                placed: 2,
+                required: 4
+            }
+        );
+    }
+
+    #[test]
+    fn test_fixed_event_task_pinned_and_avoided() {
+        let scheduler = AutoScheduler::new();
+        let day = Utc::now();
+        let fixed_start = day.with_hour(9).unwrap().with_minute(0).unwrap().with_second(0).unwrap();
+        let fixed_end = fixed_start + Duration::hours(1);
+
+        let mut fixed = make_test_task("fixed", 10, 1);
+        fixed.kind = TaskKind::FixedEvent;
+        fixed.fixed_start_at = Some(fixed_start);
+        fixed.fixed_end_at = Some(fixed_end);
+        let other = make_test_task("other", 90, 2);
+
+        let scheduled =
+            scheduler.generate_schedule(&single_lane_template(), &[other, fixed], &[], day);
+
+        let pinned = scheduled.iter().find(|b| b.task_id == "fixed").unwrap();
+        assert_eq!(pinned.start_time, fixed_start);
+        assert_eq!(pinned.end_time, fixed_end);
+        for block in scheduled.iter().filter(|b| b.task_id == "other") {
+            assert!(block.start_time >= fixed_end);
+        }
+    }
+
+    #[test]
+    fn test_fixed_event_task_without_start_is_reported() {
+        let scheduler = AutoScheduler::new();
+        let day = Utc::now();
+
+        let mut fixed = make_test_task("fixed", 10, 1);
+        fixed.kind = TaskKind::FixedEvent;
+
+        let outcome = scheduler.generate_outcome(&single_lane_template(), &[fixed], &[], day);
+
+        assert!(outcome.blocks.iter().all(|b| b.task_id != "fixed"));
+        assert_eq!(outcome.unschedulable.len(), 1);
+        assert_eq!(outcome.unschedulable[0].task_id, "fixed");
+        assert_eq!(
+            outcome.unschedulable[0].reason,
+            UnschedulableReason::MissingFixedStart
+        );
+    }
+
+    #[test]
+    fn test_locked_block_survives_regeneration() {
+        let scheduler = AutoScheduler::new();
+        let day = Utc::now();
+        let at = |hour: u32| {
+            day.with_hour(hour)
+                .unwrap()
+                .with_minute(0)
+                .unwrap()
+                .with_second(0)
+                .unwrap()
+                .with_nanosecond(0)
+                .unwrap()
+        };
+        let block = |id: &str, task_id: &str, hour: u32, locked: bool| ScheduleBlock {
+            id: id.to_string(),
+            block_type: BlockType::Focus,
+            task_id: Some(task_id.to_string()),
+            start_time: at(hour),
+            end_time: at(hour) + Duration::minutes(25),
+            locked,
+            label: None,
+            lane: Some(0),
+        };
+        let existing = vec![
+            block("locked-2pm", "pinned", 14, true),
+            block("stale-9am", "other", 9, false),
+        ];
+        let tasks = vec![
+            make_test_task("pinned", 10, 1),
+            make_test_task("other", 90, 12),
+        ];
+
+        let scheduled =
+            scheduler.regenerate_schedule(&single_lane_template(), &tasks, &[], &existing, day);
+
+        let locked = scheduled.iter().find(|b| b.id == "locked-2pm").unwrap();
+        assert_eq!(locked.task_id, "pinned");
+        assert_eq!(locked.task_title, "Task pinned");
+        assert_eq!(locked.start_time, at(14));
+        assert_eq!(locked.end_time, at(14) + Duration::minutes(25));
+        // The locked pomodoro already covers the pinned task
+        assert_eq!(
+            scheduled.iter().filter(|b| b.task_id == "pinned").count(),
+            1
+        );
+        assert!(!scheduled.iter().any(|b| b.id == "stale-9am"));
+        // Other work fills the day around the locked block, never over it
+        assert!(scheduled
+            .iter()
+            .any(|b| b.task_id == "other" && b.end_time > at(14)));
+        for other in scheduled.iter().filter(|b| b.id != "locked-2pm") {
+            assert!(
+                other.end_time <= locked.start_time || other.start_time >= locked.end_time,
+                "{} overlaps the locked block",
+                other.id
+            );
+        }
+    }
+
+    #[test]
+    fn test_buffer_fill_expands_into_leftover_gaps() {
+        let scheduler = AutoScheduler::new();
+        let day = Utc::now();
+
+        let mut buffer = make_test_task("buffer", 10, 1);
+        buffer.kind = TaskKind::BufferFill;
+        let work = make_test_task("work", 90, 1);
+
+        let scheduled =
+            scheduler.generate_schedule(&single_lane_template(), &[buffer, work], &[], day);
+
+        let work_end = scheduled
+            .iter()
+            .filter(|b| b.task_id != "buffer")
+            .map(|b| b.end_time)
+            .max()
+            .unwrap();
+        let buffer_blocks: Vec<_> = scheduled.iter().filter(|b| b.task_id == "buffer").collect();
+        assert_eq!(buffer_blocks.len(), 1);
+        assert_eq!(buffer_blocks[0].start_time, work_end);
+        assert_eq!(
+            buffer_blocks[0].end_time,
+            day.with_hour(18).unwrap().with_minute(0).unwrap().with_second(0).unwrap().with_nanosecond(0).unwrap()
+        );
+    }
+
     #[test]
     fn test_shared_break_policy_creates_single_break_block_per_round() {
         let scheduler = AutoScheduler::new();
@@ -1357,6 +2449,39 @@ mod tests {
         assert_eq!(break_blocks[0].lane, None);
     }
 
+    #[test]
+    fn test_best_fit_fills_gap_that_greedy_leaves_empty() {
+        let template = DailyTemplate {
+            wake_up: "09:00".to_string(),
+            sleep: "10:00".to_string(),
+            fixed_events: vec![],
+            max_parallel_lanes: Some(1),
+        };
+        let day = Utc::now();
+        // The top task is too long for the hour, which stops the greedy walk
+        let mut tasks = vec![
+            make_test_task("long", 90, 3),
+            make_test_task("short", 50, 2),
+        ];
+        for task in &mut tasks {
+            task.allow_split = false;
+        }
+
+        let greedy = AutoScheduler::new().generate_outcome(&template, &tasks, &[], day);
+        let best_fit = AutoScheduler::with_config(SchedulerConfig {
+            packing_strategy: PackingStrategy::BestFit,
+            ..SchedulerConfig::default()
+        })
+        .generate_outcome(&template, &tasks, &[], day);
+
+        assert!(greedy.blocks.is_empty());
+        assert_eq!(greedy.utilization, 0.0);
+        let placed: Vec<&str> = best_fit.blocks.iter().map(|b| b.task_id.as_str()).collect();
+        assert_eq!(placed, vec!["short"]);
+        assert!(best_fit.utilization > greedy.utilization);
+        assert!(check_invariants(&best_fit.blocks, &template, &[], day).is_empty());
+    }
+
     #[test]
     fn test_isolated_break_policy_creates_per_lane_break_blocks() {
         let scheduler = AutoScheduler::with_config(SchedulerConfig {
@@ -1385,6 +2510,215 @@ mod tests {
         assert_eq!(lanes, vec![0, 1, 2]);
     }
 
+    #[test]
+    fn test_long_break_after_fourth_pomodoro() {
+        let scheduler = AutoScheduler::new();
+        let mut template = make_test_template();
+        template.fixed_events.clear();
+        template.max_parallel_lanes = Some(1);
+        let day = Utc::now();
+        let tasks = vec![make_test_task("task1", 80, 5)];
+
+        let scheduled = scheduler.generate_schedule(&template, &tasks, &[], day);
+        let focus_blocks: Vec<_> = scheduled
+            .iter()
+            .filter(|b| b.block_type == ScheduledBlockType::Focus)
+            .collect();
+        let break_blocks: Vec<_> = scheduled
+            .iter()
+            .filter(|b| b.block_type == ScheduledBlockType::Break)
+            .collect();
+
+        assert_eq!(focus_blocks.len(), 5);
+        assert!(focus_blocks.iter().all(|b| b.task_id == "task1"));
+        let breaks: Vec<i32> = focus_blocks.iter().map(|b| b.break_minutes).collect();
+        assert_eq!(breaks, vec![5, 5, 5, 15, 5]);
+
+        // The break after the fourth pomodoro is long and pushes the fifth back
+        let fourth_break = &break_blocks[3];
+        assert_eq!(fourth_break.start_time, focus_blocks[3].end_time);
+        assert_eq!(
+            (fourth_break.end_time - fourth_break.start_time).num_minutes(),
+            15
+        );
+        assert_eq!(focus_blocks[4].start_time, fourth_break.end_time);
+    }
+
+    #[test]
+    fn test_break_blocks_follow_emit_option() {
+        let mut template = make_test_template();
+        template.fixed_events.clear();
+        template.max_parallel_lanes = Some(1);
+        let day = Utc::now();
+        let mut tasks = vec![make_test_task("task1", 80, 5)];
+        let mut buffer = make_test_task("buffer", 10, 1);
+        buffer.kind = TaskKind::BufferFill;
+        tasks.push(buffer);
+
+        let with_breaks = AutoScheduler::new().generate_schedule(&template, &tasks, &[], day);
+        let focus: Vec<_> = with_breaks
+            .iter()
+            .filter(|b| b.block_type == ScheduledBlockType::Focus && b.task_id == "task1")
+            .collect();
+        let breaks: Vec<_> = with_breaks
+            .iter()
+            .filter(|b| b.block_type == ScheduledBlockType::Break)
+            .collect();
+        let minutes: Vec<i64> = breaks.iter().map(|b| b.duration_minutes()).collect();
+        assert_eq!(minutes, vec![5, 5, 5, 15, 5]);
+        for (focus, brk) in focus.iter().zip(&breaks) {
+            assert_eq!(brk.start_time, focus.end_time);
+            assert_eq!(brk.duration_minutes(), i64::from(focus.break_minutes));
+        }
+        for (i, a) in with_breaks.iter().enumerate() {
+            for b in &with_breaks[i + 1..] {
+                assert!(
+                    a.end_time <= b.start_time || b.end_time <= a.start_time,
+                    "{} overlaps {}",
+                    a.task_title,
+                    b.task_title
+                );
+            }
+        }
+        assert!(check_invariants(&with_breaks, &template, &[], day).is_empty());
+
+        let without_breaks = AutoScheduler::with_config(SchedulerConfig {
+            emit_break_blocks: false,
+            ..SchedulerConfig::default()
+        })
+        .generate_schedule(&template, &tasks, &[], day);
+        assert!(without_breaks
+            .iter()
+            .all(|b| b.block_type == ScheduledBlockType::Focus));
+        // Same layout, and the implied breaks are not filled by other tasks
+        let starts = |blocks: &[ScheduledBlock]| -> Vec<_> {
+            blocks
+                .iter()
+                .filter(|b| b.block_type == ScheduledBlockType::Focus)
+                .map(|b| (b.task_id.clone(), b.start_time, b.end_time, b.break_minutes))
+                .collect()
+        };
+        assert_eq!(starts(&without_breaks), starts(&with_breaks));
+    }
+
+    #[test]
+    fn test_continuous_focus_cap_forces_long_break() {
+        let scheduler = AutoScheduler::with_config(SchedulerConfig {
+            focus_duration: 30,
+            pomodoros_before_long_break: 10,
+            max_continuous_focus_minutes: Some(120),
+            ..SchedulerConfig::default()
+        });
+        let mut template = make_test_template();
+        template.fixed_events.clear();
+        template.max_parallel_lanes = Some(1);
+        let day = Utc::now();
+        let tasks = vec![make_test_task("task1", 80, 6)];
+
+        let scheduled = scheduler.generate_schedule(&template, &tasks, &[], day);
+        let breaks: Vec<i32> = scheduled
+            .iter()
+            .filter(|b| b.block_type == ScheduledBlockType::Focus)
+            .map(|b| b.break_minutes)
+            .collect();
+
+        // Four 30-minute pomodoros reach the cap, so the fourth break is long
+        assert_eq!(breaks, vec![5, 5, 5, 15, 5, 5]);
+        let long_break = scheduled
+            .iter()
+            .find(|b| b.block_type == ScheduledBlockType::Break && b.duration_minutes() == 15)
+            .unwrap();
+        let focus_before: i64 = scheduled
+            .iter()
+            .filter(|b| {
+                b.block_type == ScheduledBlockType::Focus && b.end_time <= long_break.start_time
+            })
+            .map(ScheduledBlock::duration_minutes)
+            .sum();
+        assert_eq!(focus_before, 120);
+    }
+
+    #[test]
+    fn test_daily_focus_cap_leaves_tasks_unscheduled_with_reason() {
+        let scheduler = AutoScheduler::with_config(SchedulerConfig {
+            max_daily_focus_minutes: Some(100),
+            ..SchedulerConfig::default()
+        });
+        let mut template = make_test_template();
+        template.fixed_events.clear();
+        template.max_parallel_lanes = Some(1);
+        let day = Utc::now();
+        let tasks = vec![
+            make_test_task("first", 90, 3),
+            make_test_task("second", 80, 3),
+        ];
+
+        let outcome = scheduler.generate_outcome(&template, &tasks, &[], day);
+        let focus_minutes: i64 = outcome
+            .blocks
+            .iter()
+            .filter(|b| b.block_type == ScheduledBlockType::Focus)
+            .map(ScheduledBlock::duration_minutes)
+            .sum();
+        assert_eq!(focus_minutes, 100);
+
+        assert_eq!(outcome.unschedulable.len(), 1);
+        assert_eq!(outcome.unschedulable[0].task_id, "second");
+        assert_eq!(
+            outcome.unschedulable[0].reason,
+            UnschedulableReason::DailyFocusCap {
+                placed: 1,
+                required: 3
+            }
+        );
+
+        // Without the cap the whole day has room for both tasks
+        let uncapped = AutoScheduler::new().generate_outcome(&template, &tasks, &[], day);
+        assert!(uncapped.unschedulable.is_empty());
+    }
+
+    #[test]
+    fn test_daily_focus_cap_reason_only_for_tasks_it_turned_away() {
+        let scheduler = AutoScheduler::with_config(SchedulerConfig {
+            max_daily_focus_minutes: Some(25),
+            ..SchedulerConfig::default()
+        });
+        let day = Utc::now();
+        let at = |hour, minute| day.with_hour(hour).unwrap().with_minute(minute).unwrap();
+
+        let mut capped = make_test_task("capped", 90, 2);
+        capped.window_start_at = Some(at(10, 0));
+        capped.window_end_at = Some(at(16, 0));
+        // Too short a window for even one pomodoro, cap or not
+        let mut tight = make_test_task("tight", 10, 1);
+        tight.window_start_at = Some(at(16, 0));
+        tight.window_end_at = Some(at(16, 20));
+
+        let outcome =
+            scheduler.generate_outcome(&single_lane_template(), &[capped, tight], &[], day);
+        let reason = |id: &str| {
+            outcome
+                .unschedulable
+                .iter()
+                .find(|u| u.task_id == id)
+                .map(|u| u.reason.clone())
+        };
+        assert_eq!(
+            reason("capped"),
+            Some(UnschedulableReason::DailyFocusCap {
+                placed: 1,
+                required: 2
+            })
+        );
+        assert_eq!(
+            reason("tight"),
+            Some(UnschedulableReason::InsufficientTime {
+                placed: 0,
+                required: 1
+            })
+        );
+    }
+
     #[test]
     fn test_non_splittable_task_scheduled_as_continuous_block() {
         let scheduler = AutoScheduler::new();
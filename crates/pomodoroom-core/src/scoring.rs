@@ -28,7 +28,7 @@ use chrono::{Datelike, DateTime, Timelike, Utc, Weekday};
 use serde::{Deserialize, Serialize};
 
 use crate::schedule::DailyTemplate;
-use crate::task::{EnergyLevel, Task, TaskState};
+use crate::task::{EnergyLevel, Task, TaskState, POMODORO_MINUTES};
 
 /// Individual objective term with weight and score
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
@@ -63,6 +63,17 @@ impl ObjectiveTerm {
     }
 }
 
+/// Share of the total score attributed to one term
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct NormalizedTerm {
+    /// Term name
+    pub name: String,
+    /// Raw weighted contribution, as in `ObjectiveTerm::contribution`
+    pub contribution: f64,
+    /// Percentage of the total score (all terms sum to 100)
+    pub percent: f64,
+}
+
 /// Complete scoring breakdown for explainability
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct ScoreBreakdown {
@@ -103,6 +114,26 @@ impl ScoreBreakdown {
         sorted.sort_by(|a, b| b.contribution.partial_cmp(&a.contribution).unwrap());
         sorted
     }
+
+    /// Each term's contribution as a percentage of the total
+    ///
+    /// Percentages sum to 100 whatever the weights add up to. When nothing
+    /// contributed (zero total) every term gets 0%.
+    pub fn normalized(&self) -> Vec<NormalizedTerm> {
+        let total: f64 = self.terms.iter().map(|t| t.contribution.max(0.0)).sum();
+        self.terms
+            .iter()
+            .map(|term| NormalizedTerm {
+                name: term.name.clone(),
+                contribution: term.contribution,
+                percent: if total > f64::EPSILON {
+                    term.contribution.max(0.0) / total * 100.0
+                } else {
+                    0.0
+                },
+            })
+            .collect()
+    }
 }
 
 impl Default for ScoreBreakdown {
@@ -124,6 +155,23 @@ pub struct ObjectiveWeights {
     pub break_compliance: f64,
     /// Weight for priority (higher = respect task priority values)
     pub priority: f64,
+    /// Weight for task age (higher = resurface tasks left untouched for long)
+    ///
+    /// Off (0.0) in every preset, so existing rankings are unchanged until
+    /// the weight is set.
+    #[serde(default = "default_age_weight")]
+    pub age: f64,
+    /// How fast the age score grows, per day since the task was last touched
+    #[serde(default = "default_age_growth_per_day")]
+    pub age_growth_per_day: f64,
+}
+
+fn default_age_weight() -> f64 {
+    0.0
+}
+
+fn default_age_growth_per_day() -> f64 {
+    0.1
 }
 
 impl ObjectiveWeights {
@@ -135,6 +183,8 @@ impl ObjectiveWeights {
             energy_fit: 0.20,
             break_compliance: 0.15,
             priority: 0.20,
+            age: default_age_weight(),
+            age_growth_per_day: default_age_growth_per_day(),
         }
     }
 
@@ -146,6 +196,8 @@ impl ObjectiveWeights {
             energy_fit: 0.15,
             break_compliance: 0.10,
             priority: 0.20,
+            age: default_age_weight(),
+            age_growth_per_day: default_age_growth_per_day(),
         }
     }
 
@@ -157,6 +209,8 @@ impl ObjectiveWeights {
             energy_fit: 0.25,
             break_compliance: 0.15,
             priority: 0.10,
+            age: default_age_weight(),
+            age_growth_per_day: default_age_growth_per_day(),
         }
     }
 
@@ -168,33 +222,65 @@ impl ObjectiveWeights {
             energy_fit: 0.30,
             break_compliance: 0.30,
             priority: 0.10,
+            age: default_age_weight(),
+            age_growth_per_day: default_age_growth_per_day(),
         }
     }
 
+    /// Built-in presets, in display order. The first one is the benchmark baseline.
+    pub fn presets() -> Vec<(&'static str, ObjectiveWeights)> {
+        vec![
+            ("balanced", Self::balanced()),
+            ("deadline_focused", Self::deadline_focused()),
+            ("deep_work", Self::deep_work()),
+            ("sustainable", Self::sustainable()),
+        ]
+    }
+
+    /// Look up a built-in preset by name
+    pub fn preset(name: &str) -> Option<Self> {
+        Self::presets()
+            .into_iter()
+            .find(|(preset, _)| *preset == name)
+            .map(|(_, weights)| weights)
+    }
+
     /// Normalize weights to sum to 1.0
+    ///
+    /// `age_growth_per_day` is a rate, not a weight, and is left unchanged.
     pub fn normalize(&mut self) {
         let sum = self.due_date_risk
             + self.context_switch
             + self.energy_fit
             + self.break_compliance
-            + self.priority;
+            + self.priority
+            + self.age;
         if sum > 0.0 {
             self.due_date_risk /= sum;
             self.context_switch /= sum;
             self.energy_fit /= sum;
             self.break_compliance /= sum;
             self.priority /= sum;
+            self.age /= sum;
         }
     }
 
-    /// Validate that all weights are in [0.0, 1.0]
+    /// Validate that all weights are in [0.0, 1.0] and the age growth rate is not negative
     pub fn validate(&self) -> Result<(), String> {
+        if self.age_growth_per_day < 0.0 {
+            return Err(format!(
+                "age_growth_per_day must not be negative, got {}",
+                self.age_growth_per_day
+            ));
+        }
+
         let weights = [
             ("due_date_risk", self.due_date_risk),
             ("context_switch", self.context_switch),
             ("energy_fit", self.energy_fit),
             ("break_compliance", self.break_compliance),
             ("priority", self.priority),
+            ("age", self.age),
         ];
 
         for (name, weight) in weights {
@@ -235,22 +321,51 @@ pub struct ScoringContext<'a> {
     pub weights: ObjectiveWeights,
 }
 
+/// Ranks two scored tasks; `Less` means `a` goes first.
+pub type TaskComparator = fn(a: &Task, a_score: f64, b: &Task, b_score: f64) -> std::cmp::Ordering;
+
+/// Default stable ordering for scored tasks.
+///
+/// Higher score first, then earlier deadline (tasks without one last), then
+/// older tasks, then task ID. Only identical tasks compare equal, so ranking
+/// never depends on input order.
+pub fn default_task_ordering(a: &Task, a_score: f64, b: &Task, b_score: f64) -> std::cmp::Ordering {
+    b_score
+        .total_cmp(&a_score)
+        .then_with(|| match (a.window_end_at, b.window_end_at) {
+            (Some(a_due), Some(b_due)) => a_due.cmp(&b_due),
+            (Some(_), None) => std::cmp::Ordering::Less,
+            (None, Some(_)) => std::cmp::Ordering::Greater,
+            (None, None) => std::cmp::Ordering::Equal,
+        })
+        .then_with(|| a.created_at.cmp(&b.created_at))
+        .then_with(|| a.id.cmp(&b.id))
+}
+
 /// Multi-objective scoring engine
 pub struct ScoringEngine {
     weights: ObjectiveWeights,
+    comparator: TaskComparator,
 }
 
 impl ScoringEngine {
     /// Create a new engine with default weights
     pub fn new() -> Self {
-        Self {
-            weights: ObjectiveWeights::default(),
-        }
+        Self::with_weights(ObjectiveWeights::default())
     }
 
     /// Create with custom weights
     pub fn with_weights(weights: ObjectiveWeights) -> Self {
-        Self { weights }
+        Self {
+            weights,
+            comparator: default_task_ordering,
+        }
+    }
+
+    /// Replace the ordering used to rank scored tasks
+    pub fn with_comparator(mut self, comparator: TaskComparator) -> Self {
+        self.comparator = comparator;
+        self
     }
 
     /// Update weights
@@ -307,6 +422,10 @@ impl ScoringEngine {
             priority_score,
         ));
 
+        // Age
+        let age_score = self.calculate_age_score(ctx);
+        breakdown.add_term(ObjectiveTerm::new("age", ctx.weights.age, age_score));
+
         breakdown
     }
 
@@ -390,7 +509,92 @@ impl ScoringEngine {
         priority / 100.0
     }
 
+    /// Calculate age score
+    /// Higher score = task has gone untouched for longer
+    ///
+    /// Age counts from the last update, which is the creation time for tasks
+    /// never edited. The score approaches 1.0 asymptotically, so the term can
+    /// never contribute more than its weight.
+    fn calculate_age_score(&self, ctx: &ScoringContext) -> f64 {
+        let age_days =
+            (ctx.start_time - ctx.task.updated_at).num_minutes().max(0) as f64 / (24.0 * 60.0);
+        1.0 - (-ctx.weights.age_growth_per_day * age_days).exp()
+    }
+
+    /// Order READY tasks greedily, starting at `at`.
+    ///
+    /// Each step picks the best-scoring remaining task given the previously
+    /// picked one, then advances the clock by the task's pomodoros. Ties are
+    /// broken by the engine's comparator (`default_task_ordering` unless
+    /// replaced), so the same snapshot always yields the same order.
+    pub fn rank_tasks(&self, tasks: &[Task], at: DateTime<Utc>) -> Vec<RankedTask> {
+        let mut remaining = benchmark_candidates(tasks);
+        let mut ranking = Vec::with_capacity(remaining.len());
+        let mut cursor = at;
+        let mut previous: Option<&Task> = None;
+
+        while !remaining.is_empty() {
+            let mut best: Option<(usize, f64)> = None;
+            for (index, task) in remaining.iter().enumerate() {
+                let score = self.score_at(task, previous, cursor).total_score;
+                let better = best.is_none_or(|(best_index, best_score)| {
+                    (self.comparator)(task, score, remaining[best_index], best_score)
+                        == std::cmp::Ordering::Less
+                });
+                if better {
+                    best = Some((index, score));
+                }
+            }
+            let Some((index, score)) = best else { break };
+            let task = remaining.remove(index);
+            ranking.push(RankedTask {
+                task_id: task.id.clone(),
+                title: task.title.clone(),
+                score,
+            });
+            cursor += task_duration(task);
+            previous = Some(task);
+        }
+
+        ranking
+    }
+
+    /// Score tasks in a fixed order, as `rank_tasks` would have placed them.
+    fn score_sequence(&self, order: &[&Task], at: DateTime<Utc>) -> Vec<f64> {
+        let mut cursor = at;
+        let mut previous: Option<&Task> = None;
+        order
+            .iter()
+            .map(|task| {
+                let score = self.score_at(task, previous, cursor).total_score;
+                cursor += task_duration(task);
+                previous = Some(task);
+                score
+            })
+            .collect()
+    }
+
+    /// Score `task` starting at `start` after `previous`.
+    fn score_at(
+        &self,
+        task: &Task,
+        previous: Option<&Task>,
+        start: DateTime<Utc>,
+    ) -> ScoreBreakdown {
+        self.score_task(&ScoringContext {
+            task,
+            start_time: start,
+            end_time: start + task_duration(task),
+            previous_task: previous,
+            hour_of_day: start.hour(),
+            streak_without_break: 0,
+            weights: self.weights,
+        })
+    }
+
     /// Compare two tasks and return the better one with explanation
+    ///
+    /// Equal scores are resolved by the engine's comparator.
     pub fn compare_tasks(
         &self,
         ctx_a: &ScoringContext,
@@ -399,12 +603,15 @@ impl ScoringEngine {
         let score_a = self.score_task(ctx_a);
         let score_b = self.score_task(ctx_b);
 
-        let ordering = if score_a.total_score > score_b.total_score {
-            Ordering::Better
-        } else if score_a.total_score < score_b.total_score {
-            Ordering::Worse
-        } else {
-            Ordering::Equal
+        let ordering = match (self.comparator)(
+            ctx_a.task,
+            score_a.total_score,
+            ctx_b.task,
+            score_b.total_score,
+        ) {
+            std::cmp::Ordering::Less => Ordering::Better,
+            std::cmp::Ordering::Greater => Ordering::Worse,
+            std::cmp::Ordering::Equal => Ordering::Equal,
         };
 
         (ordering, score_a, score_b)
@@ -462,6 +669,143 @@ impl BenchmarkResult {
     }
 }
 
+/// Task position in a ranking
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RankedTask {
+    /// Task ID
+    pub task_id: String,
+    /// Task title
+    pub title: String,
+    /// Total score at the position it was placed
+    pub score: f64,
+}
+
+/// Ranking produced by one weight preset
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PresetRanking {
+    /// Preset name
+    pub preset: String,
+    /// Weights used
+    pub weights: ObjectiveWeights,
+    /// Top-N tasks in order
+    pub top: Vec<RankedTask>,
+    /// Top-N mean score against the priority-only heuristic order
+    pub result: BenchmarkResult,
+    /// Positions in the top-N that differ from the baseline preset
+    pub changed_positions: usize,
+    /// Tasks shared with the baseline preset's top-N
+    pub overlap: usize,
+    /// Wall-clock time to rank all tasks (microseconds)
+    pub elapsed_micros: u64,
+}
+
+/// Comparison of weight presets over one task snapshot
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PresetBenchmark {
+    /// Simulated start time of the ranking
+    pub at: DateTime<Utc>,
+    /// Number of READY tasks ranked
+    pub task_count: usize,
+    /// Number of top positions compared
+    pub top_n: usize,
+    /// Preset the others are compared against
+    pub baseline: String,
+    /// One ranking per preset
+    pub rankings: Vec<PresetRanking>,
+}
+
+/// Rank `tasks` under each preset and compare the top-N orderings.
+///
+/// The first preset is the baseline. Everything except `elapsed_micros` is
+/// deterministic for a given task snapshot and `at`.
+pub fn benchmark_presets(
+    tasks: &[Task],
+    presets: &[(&str, ObjectiveWeights)],
+    top_n: usize,
+    at: DateTime<Utc>,
+) -> PresetBenchmark {
+    let candidates = benchmark_candidates(tasks);
+    let mut heuristic = candidates.clone();
+    heuristic.sort_by_key(|task| std::cmp::Reverse(task.priority.unwrap_or(50)));
+    heuristic.truncate(top_n);
+
+    let mut rankings: Vec<PresetRanking> = Vec::with_capacity(presets.len());
+    for (name, weights) in presets {
+        let engine = ScoringEngine::with_weights(*weights);
+        let started = std::time::Instant::now();
+        let mut top = engine.rank_tasks(tasks, at);
+        let elapsed_micros = started.elapsed().as_micros() as u64;
+        top.truncate(top_n);
+
+        let mean = |scores: &[f64]| {
+            if scores.is_empty() {
+                0.0
+            } else {
+                scores.iter().sum::<f64>() / scores.len() as f64
+            }
+        };
+        let own_scores: Vec<f64> = top.iter().map(|ranked| ranked.score).collect();
+        let mut result = BenchmarkResult::new(
+            mean(&engine.score_sequence(&heuristic, at)),
+            mean(&own_scores),
+            candidates.len(),
+        );
+        result.timestamp = at;
+
+        let (changed_positions, overlap) = match rankings.first() {
+            Some(baseline) => (
+                (0..top.len().max(baseline.top.len()))
+                    .filter(|&i| {
+                        top.get(i).map(|t| &t.task_id) != baseline.top.get(i).map(|t| &t.task_id)
+                    })
+                    .count(),
+                top.iter()
+                    .filter(|t| baseline.top.iter().any(|b| b.task_id == t.task_id))
+                    .count(),
+            ),
+            None => (0, top.len()),
+        };
+
+        rankings.push(PresetRanking {
+            preset: name.to_string(),
+            weights: *weights,
+            top,
+            result,
+            changed_positions,
+            overlap,
+            elapsed_micros,
+        });
+    }
+
+    PresetBenchmark {
+        at,
+        task_count: candidates.len(),
+        top_n,
+        baseline: presets
+            .first()
+            .map(|(name, _)| name.to_string())
+            .unwrap_or_default(),
+        rankings,
+    }
+}
+
+/// READY tasks sorted by ID.
+fn benchmark_candidates(tasks: &[Task]) -> Vec<&Task> {
+    let mut candidates: Vec<&Task> = tasks
+        .iter()
+        .filter(|task| task.state == TaskState::Ready && !task.completed)
+        .collect();
+    candidates.sort_by(|a, b| a.id.cmp(&b.id));
+    candidates
+}
+
+/// Planned duration of a task (`POMODORO_MINUTES` per pomodoro, at least one).
+fn task_duration(task: &Task) -> chrono::Duration {
+    chrono::Duration::minutes(
+        i64::from(task.estimated_pomodoros.max(1)) * i64::from(POMODORO_MINUTES),
+    )
+}
+
 // ============================================================================
 // Pressure Engine
 // ============================================================================
@@ -799,9 +1143,10 @@ impl Default for PressureEngine {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::schedule::FixedEvent;
+    use crate::schedule::{FixedEvent, FixedEventKind};
     use crate::task::{TaskCategory, TaskKind, TaskState};
     use chrono::{Duration, Utc};
+    use proptest::prelude::*;
 
     fn make_test_task_with_due_date(
         id: &str,
@@ -848,6 +1193,10 @@ mod tests {
             allow_split: true,
             suggested_tags: vec![],
             approved_tags: vec![],
+            external_block: None,
+            recurrence: None,
+            recurrence_parent_id: None,
+            notes: Vec::new(),
         }
     }
 
@@ -896,6 +1245,10 @@ mod tests {
             allow_split: true,
             suggested_tags: vec![],
             approved_tags: vec![],
+            external_block: None,
+            recurrence: None,
+            recurrence_parent_id: None,
+            notes: Vec::new(),
         }
     }
 
@@ -910,6 +1263,27 @@ mod tests {
         assert_eq!(breakdown.terms.len(), 2);
     }
 
+    #[test]
+    fn test_normalized_breakdown_sums_to_100() {
+        let mut breakdown = ScoreBreakdown::new();
+        // Weights add up to 1.5, so raw contributions exceed a 0-1 total
+        breakdown.add_term(ObjectiveTerm::new("due_date_risk", 0.6, 0.9));
+        breakdown.add_term(ObjectiveTerm::new("priority", 0.5, 0.4));
+        breakdown.add_term(ObjectiveTerm::new("energy_fit", 0.4, 0.0));
+
+        let normalized = breakdown.normalized();
+        let sum: f64 = normalized.iter().map(|t| t.percent).sum();
+        assert!((sum - 100.0).abs() < 1e-9);
+        assert!((normalized[0].contribution - 0.54).abs() < 1e-9);
+        assert!((normalized[0].percent - 0.54 / 0.74 * 100.0).abs() < 1e-9);
+        assert_eq!(normalized[2].percent, 0.0);
+
+        let mut zero = ScoreBreakdown::new();
+        zero.add_term(ObjectiveTerm::new("a", 0.5, 0.0));
+        zero.add_term(ObjectiveTerm::new("b", 0.0, 1.0));
+        assert!(zero.normalized().iter().all(|t| t.percent == 0.0));
+    }
+
     #[test]
     fn test_objective_term_creation() {
         let term = ObjectiveTerm::new("test", 0.5, 0.8);
@@ -1110,6 +1484,155 @@ mod tests {
         assert!(sustainable.break_compliance > balanced.break_compliance);
     }
 
+    #[test]
+    fn test_rank_tasks_is_deterministic() {
+        let at = Utc::now();
+        let mut tasks = vec![
+            make_test_task_with_due_date("c", 50, EnergyLevel::Medium, None),
+            make_test_task_with_due_date("a", 50, EnergyLevel::Medium, None),
+            make_test_task_with_due_date("b", 90, EnergyLevel::Medium, None),
+        ];
+        for task in &mut tasks {
+            task.created_at = at;
+            task.updated_at = at;
+        }
+        let mut reversed = tasks.clone();
+        reversed.reverse();
+
+        let engine = ScoringEngine::new();
+        let first = engine.rank_tasks(&tasks, at);
+        let ids: Vec<&str> = first.iter().map(|t| t.task_id.as_str()).collect();
+        // Higher priority first, then ties broken by ID (same deadline and age).
+        assert_eq!(ids, vec!["b", "a", "c"]);
+        assert_eq!(first, engine.rank_tasks(&reversed, at));
+    }
+
+    #[test]
+    fn test_equal_scores_break_ties_by_deadline_then_age() {
+        let at = Utc::now();
+        let mut later = make_test_task_with_due_date("a", 50, EnergyLevel::Medium, Some(48));
+        let mut sooner = make_test_task_with_due_date("b", 50, EnergyLevel::Medium, Some(24));
+        sooner.window_end_at = later.window_end_at.map(|due| due - Duration::hours(1));
+        later.created_at = at;
+        sooner.created_at = at;
+        let mut newer = make_test_task_with_due_date("c", 50, EnergyLevel::Medium, None);
+        let mut older = make_test_task_with_due_date("d", 50, EnergyLevel::Medium, None);
+        newer.created_at = at;
+        older.created_at = at - Duration::days(1);
+
+        let order = |a: &Task, b: &Task| default_task_ordering(a, 0.5, b, 0.5);
+        assert_eq!(order(&sooner, &later), std::cmp::Ordering::Less);
+        assert_eq!(order(&later, &newer), std::cmp::Ordering::Less);
+        assert_eq!(order(&older, &newer), std::cmp::Ordering::Less);
+        assert_eq!(
+            default_task_ordering(&newer, 0.9, &sooner, 0.5),
+            std::cmp::Ordering::Less
+        );
+
+        // A custom comparator replaces the chain
+        let engine = ScoringEngine::new().with_comparator(|a, _, b, _| b.id.cmp(&a.id));
+        let ids: Vec<String> = engine
+            .rank_tasks(&[newer, older], at)
+            .into_iter()
+            .map(|t| t.task_id)
+            .collect();
+        assert_eq!(ids, vec!["d", "c"]);
+    }
+
+    /// Priority, days until due and age in days per task
+    type TaskSpecs = Vec<(i32, Option<i64>, i64)>;
+
+    /// Task specs and a permutation of their indices
+    fn specs_with_permutation() -> impl Strategy<Value = (TaskSpecs, Vec<usize>)> {
+        prop::collection::vec((0..3i32, prop::option::of(0..3i64), 0..3i64), 1..8).prop_flat_map(
+            |specs| {
+                let indices: Vec<usize> = (0..specs.len()).collect();
+                (Just(specs), Just(indices).prop_shuffle())
+            },
+        )
+    }
+
+    proptest! {
+        /// Shuffling the input and reranking yields the same order
+        #[test]
+        fn prop_rank_order_ignores_input_order((specs, permutation) in specs_with_permutation()) {
+            let at = Utc::now();
+            let tasks: Vec<Task> = specs
+                .iter()
+                .enumerate()
+                .map(|(i, (priority, due, age))| {
+                    let mut task = make_test_task_with_due_date(
+                        &format!("t{i}"),
+                        priority * 25,
+                        EnergyLevel::Medium,
+                        None,
+                    );
+                    task.window_end_at = due.map(|d| at + Duration::days(d + 1));
+                    task.created_at = at - Duration::days(*age);
+                    task.updated_at = at;
+                    task
+                })
+                .collect();
+            let shuffled: Vec<Task> = permutation.iter().map(|&i| tasks[i].clone()).collect();
+
+            let engine = ScoringEngine::new();
+            prop_assert_eq!(engine.rank_tasks(&tasks, at), engine.rank_tasks(&shuffled, at));
+        }
+    }
+
+    #[test]
+    fn test_benchmark_presets_compares_against_baseline() {
+        let at = Utc::now();
+        let tasks = vec![
+            make_test_task_with_due_date("distant_due", 10, EnergyLevel::Medium, Some(100)),
+            make_test_task_with_due_date("high_priority", 100, EnergyLevel::Medium, None),
+        ];
+        let presets = [
+            (
+                "priority_only",
+                ObjectiveWeights {
+                    due_date_risk: 0.0,
+                    context_switch: 0.0,
+                    energy_fit: 0.0,
+                    break_compliance: 0.0,
+                    priority: 1.0,
+                    ..ObjectiveWeights::balanced()
+                },
+            ),
+            (
+                "deadline_only",
+                ObjectiveWeights {
+                    due_date_risk: 1.0,
+                    context_switch: 0.0,
+                    energy_fit: 0.0,
+                    break_compliance: 0.0,
+                    priority: 0.0,
+                    ..ObjectiveWeights::balanced()
+                },
+            ),
+        ];
+
+        let report = benchmark_presets(&tasks, &presets, 1, at);
+
+        assert_eq!(report.baseline, "priority_only");
+        assert_eq!(report.task_count, 2);
+        assert_eq!(report.rankings[0].top[0].task_id, "high_priority");
+        assert_eq!(report.rankings[1].top[0].task_id, "distant_due");
+        assert_eq!(report.rankings[1].changed_positions, 1);
+        assert_eq!(report.rankings[1].overlap, 0);
+        assert_eq!(report.rankings[1].result.timestamp, at);
+    }
+
+    #[test]
+    fn test_preset_lookup() {
+        assert_eq!(
+            ObjectiveWeights::preset("deep_work"),
+            Some(ObjectiveWeights::deep_work())
+        );
+        assert!(ObjectiveWeights::preset("unknown").is_none());
+        assert_eq!(ObjectiveWeights::presets()[0].0, "balanced");
+    }
+
     #[test]
     fn test_complete_scoring_workflow() {
         let engine = ScoringEngine::with_weights(ObjectiveWeights::balanced());
@@ -1128,7 +1651,7 @@ mod tests {
 
         let breakdown = engine.score_task(&ctx);
 
-        assert_eq!(breakdown.terms.len(), 5);
+        assert_eq!(breakdown.terms.len(), 6);
         assert!(breakdown.total_score > 0.0);
         assert!(breakdown.total_score <= 1.0);
 
@@ -1139,6 +1662,59 @@ mod tests {
         assert!(term_names.contains(&"energy_fit".to_string()));
         assert!(term_names.contains(&"break_compliance".to_string()));
         assert!(term_names.contains(&"priority".to_string()));
+        assert!(term_names.contains(&"age".to_string()));
+    }
+
+    #[test]
+    fn test_age_scoring_favors_older_task() {
+        let engine = ScoringEngine::new();
+        let now = Utc::now();
+        let fresh = make_test_task_with_due_date("fresh", 50, EnergyLevel::Medium, None);
+        let mut stale = make_test_task_with_due_date("stale", 50, EnergyLevel::Medium, None);
+        stale.created_at = now - Duration::days(14);
+        stale.updated_at = stale.created_at;
+
+        let weights = ObjectiveWeights {
+            age: 0.05,
+            ..ObjectiveWeights::balanced()
+        };
+        let score_with = |task: &Task, weights: ObjectiveWeights| {
+            engine.score_task(&ScoringContext {
+                task,
+                start_time: now,
+                end_time: now + Duration::hours(1),
+                previous_task: None,
+                hour_of_day: 14,
+                streak_without_break: 0,
+                weights,
+            })
+        };
+        let score = |task: &Task| score_with(task, weights);
+        let age_term = |breakdown: &ScoreBreakdown| {
+            breakdown
+                .terms
+                .iter()
+                .find(|term| term.name == "age")
+                .unwrap()
+                .contribution
+        };
+
+        let fresh_score = score(&fresh);
+        let stale_score = score(&stale);
+        assert!(age_term(&fresh_score) < 0.001);
+        assert!(stale_score.total_score > fresh_score.total_score);
+
+        // The boost stays below a 30-point priority difference
+        let boost = age_term(&stale_score) - age_term(&fresh_score);
+        assert!(boost <= weights.age);
+        assert!(boost < 0.30 * weights.priority);
+
+        // Presets leave age out
+        let preset = ObjectiveWeights::balanced();
+        assert_eq!(
+            score_with(&stale, preset).total_score,
+            score_with(&fresh, preset).total_score
+        );
     }
 
     #[test]
@@ -1216,6 +1792,10 @@ mod tests {
             allow_split: true,
             suggested_tags: vec![],
             approved_tags: vec![],
+            external_block: None,
+            recurrence: None,
+            recurrence_parent_id: None,
+            notes: Vec::new(),
         }
     }
 
@@ -1330,6 +1910,7 @@ mod tests {
             duration_minutes: 60,
             days: vec![current_weekday_num as u8],
             enabled: true,
+            kind: FixedEventKind::Other,
         });
 
         // Use 10:00 today (before the 14:00 event)
@@ -5,6 +5,8 @@
 //! - Seed-based deterministic scheduling
 //! - Scenario recording and replay
 //! - Regression testing with known inputs
+//! - Planning invariant checks shared by tests and the live scheduler
+//! - Reproducible reports to share a run with its seed and parameters
 
 use chrono::{DateTime, Duration, Timelike, Utc};
 use serde::{Deserialize, Serialize};
@@ -178,10 +180,127 @@ pub struct SimulationResult {
     pub scheduled_blocks: Vec<ScheduledBlock>,
     /// Metrics
     pub metrics: SimulationMetrics,
+    /// Planning invariants the schedule breaks
+    #[serde(default)]
+    pub violations: Vec<InvariantViolation>,
     /// Timestamp
     pub run_at: DateTime<Utc>,
 }
 
+impl SimulationResult {
+    /// Build a report that can be shared and rerun from its seed.
+    ///
+    /// Only inputs and outcomes that follow from the seed and parameters
+    /// are included; `run_at`, block IDs and task timestamps are left out,
+    /// so the same scenario always yields a byte-identical report.
+    pub fn to_report(&self) -> SimulationReport {
+        let scenario = &self.scenario;
+        let mut violations: Vec<(PlanningInvariant, usize)> = Vec::new();
+        for violation in &self.violations {
+            match violations
+                .iter_mut()
+                .find(|(i, _)| *i == violation.invariant)
+            {
+                Some((_, count)) => *count += 1,
+                None => violations.push((violation.invariant, 1)),
+            }
+        }
+
+        let mut report = SimulationReport {
+            scenario_name: scenario.name.clone(),
+            seed: scenario.seed,
+            day: scenario.day,
+            template: scenario.template.clone(),
+            config: scenario.config.clone(),
+            task_count: scenario.tasks.len(),
+            calendar_event_count: scenario.calendar_events.len(),
+            metrics: self.metrics.clone(),
+            violations,
+            summary: String::new(),
+        };
+        report.summary = report.render_summary();
+        report
+    }
+}
+
+/// Self-contained record of a simulation run
+///
+/// Rerunning the named scenario with `seed` and these parameters reproduces
+/// the generated tasks, events and metrics.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SimulationReport {
+    /// Scenario name
+    pub scenario_name: String,
+    /// Seed to rerun with
+    pub seed: SimulationSeed,
+    /// Simulated day
+    pub day: DateTime<Utc>,
+    /// Daily template
+    pub template: DailyTemplate,
+    /// Scheduler configuration
+    pub config: SchedulerConfig,
+    /// Tasks in the scenario
+    pub task_count: usize,
+    /// Calendar events in the scenario
+    pub calendar_event_count: usize,
+    /// Metrics of the run
+    pub metrics: SimulationMetrics,
+    /// Number of violations per broken invariant, in order of first occurrence
+    pub violations: Vec<(PlanningInvariant, usize)>,
+    /// Rendered text summary
+    pub summary: String,
+}
+
+impl SimulationReport {
+    /// Report as pretty-printed JSON
+    pub fn to_json(&self) -> Result<String, serde_json::Error> {
+        serde_json::to_string_pretty(self)
+    }
+
+    /// Plain text summary of the run
+    fn render_summary(&self) -> String {
+        let metrics = &self.metrics;
+        let violations = if self.violations.is_empty() {
+            "none".to_string()
+        } else {
+            self.violations
+                .iter()
+                .map(|(invariant, count)| format!("{:?} x{}", invariant, count))
+                .collect::<Vec<_>>()
+                .join(", ")
+        };
+        [
+            format!("Simulation report: {}", self.scenario_name),
+            format!("Seed: {}", self.seed.0),
+            format!("Day: {}", self.day.format("%Y-%m-%d")),
+            format!(
+                "Template: {}-{}, {} fixed events",
+                self.template.wake_up,
+                self.template.sleep,
+                self.template.fixed_events.len()
+            ),
+            format!(
+                "Scheduler: focus {}m, short break {}m, long break {}m every {}, {:?} packing",
+                self.config.focus_duration,
+                self.config.short_break,
+                self.config.long_break,
+                self.config.pomodoros_before_long_break,
+                self.config.packing_strategy
+            ),
+            format!(
+                "Tasks: {}/{} scheduled, {} calendar events",
+                metrics.tasks_scheduled, metrics.total_tasks, self.calendar_event_count
+            ),
+            format!(
+                "Pomodoros: {} over {}m, average priority {:.1}",
+                metrics.total_pomodoros, metrics.total_duration_minutes, metrics.avg_priority
+            ),
+            format!("Violations: {}", violations),
+        ]
+        .join("\n")
+    }
+}
+
 /// Simulation metrics
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct SimulationMetrics {
@@ -233,11 +352,18 @@ impl SimulationHarness {
         );
 
         let metrics = self.calculate_metrics(&scheduled_blocks, &scenario.tasks);
+        let violations = check_invariants(
+            &scheduled_blocks,
+            &scenario.template,
+            &scenario.calendar_events,
+            scenario.day,
+        );
 
         let result = SimulationResult {
             scenario: scenario.clone(),
             scheduled_blocks,
             metrics,
+            violations,
             run_at: Utc::now(),
         };
 
@@ -262,6 +388,44 @@ impl SimulationHarness {
         results
     }
 
+    /// Run the scenario across every combination of values in `grid`.
+    ///
+    /// Each cell starts from `base` with its seed reset to `base.seed`, so
+    /// generated tasks and events are identical across cells and only the
+    /// swept parameters differ. Fails if the grid exceeds `MAX_SWEEP_CELLS`.
+    pub fn sweep(
+        &mut self,
+        base: &SimulationScenario,
+        grid: &ParameterGrid,
+    ) -> Result<SweepMatrix, String> {
+        let cell_count = grid.cell_count();
+        if cell_count > MAX_SWEEP_CELLS {
+            return Err(format!(
+                "Sweep grid has {} cells, exceeding the limit of {}",
+                cell_count, MAX_SWEEP_CELLS
+            ));
+        }
+
+        let mut cells = Vec::with_capacity(cell_count);
+        for params in grid.combinations() {
+            let mut scenario = base.clone();
+            scenario.seed = base.seed;
+            for (parameter, value) in &params {
+                parameter.apply(&mut scenario, *value);
+            }
+            let result = self.run_scenario(&scenario);
+            cells.push(SweepCell {
+                params,
+                metrics: result.metrics,
+            });
+        }
+
+        Ok(SweepMatrix {
+            axes: grid.axes.iter().map(|(p, _)| *p).collect(),
+            cells,
+        })
+    }
+
     /// Calculate metrics from results
     fn calculate_metrics(&self, blocks: &[ScheduledBlock], tasks: &[Task]) -> SimulationMetrics {
         let total_pomodoros: i32 = blocks.iter().map(|b| b.pomodoro_count).sum();
@@ -361,6 +525,287 @@ impl ScenarioVariation {
     }
 }
 
+/// Maximum number of cells a single parameter sweep may run
+pub const MAX_SWEEP_CELLS: usize = 1000;
+
+/// Scenario parameter that can be swept over a grid
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SweepParameter {
+    /// Focus duration per Pomodoro (minutes)
+    FocusDuration,
+    /// Short break duration (minutes)
+    ShortBreak,
+    /// Long break duration (minutes)
+    LongBreak,
+    /// Pomodoros before a long break
+    LongBreakInterval,
+    /// Number of generated tasks
+    TaskCount,
+    /// Number of generated calendar events
+    CalendarEventCount,
+}
+
+impl SweepParameter {
+    /// Apply a parameter value to a scenario
+    pub fn apply(&self, scenario: &mut SimulationScenario, value: i64) {
+        match self {
+            SweepParameter::FocusDuration => scenario.config.focus_duration = value,
+            SweepParameter::ShortBreak => scenario.config.short_break = value,
+            SweepParameter::LongBreak => scenario.config.long_break = value,
+            SweepParameter::LongBreakInterval => {
+                scenario.config.pomodoros_before_long_break = value as i32
+            }
+            SweepParameter::TaskCount => scenario.generate_random_tasks(value.max(0) as usize),
+            SweepParameter::CalendarEventCount => {
+                scenario.generate_random_calendar_events(value.max(0) as usize)
+            }
+        }
+    }
+}
+
+/// Parameter values to sweep; the sweep runs their cartesian product
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ParameterGrid {
+    /// Swept parameters with their candidate values, in axis order
+    pub axes: Vec<(SweepParameter, Vec<i64>)>,
+}
+
+impl ParameterGrid {
+    /// Create an empty grid
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add an axis to the grid
+    pub fn with_axis(mut self, parameter: SweepParameter, values: Vec<i64>) -> Self {
+        self.axes.push((parameter, values));
+        self
+    }
+
+    /// Number of cells in the cartesian product
+    pub fn cell_count(&self) -> usize {
+        self.axes
+            .iter()
+            .fold(1usize, |acc, (_, values)| acc.saturating_mul(values.len()))
+    }
+
+    /// All parameter combinations, varying the last axis fastest
+    fn combinations(&self) -> Vec<Vec<(SweepParameter, i64)>> {
+        let mut combos = vec![Vec::new()];
+        for (parameter, values) in &self.axes {
+            combos = combos
+                .into_iter()
+                .flat_map(|combo| {
+                    values.iter().map(move |value| {
+                        let mut next = combo.clone();
+                        next.push((*parameter, *value));
+                        next
+                    })
+                })
+                .collect();
+        }
+        combos
+    }
+}
+
+/// Metric used to rank sweep cells
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SweepMetric {
+    /// Most tasks scheduled
+    TasksScheduled,
+    /// Most Pomodoros scheduled
+    TotalPomodoros,
+    /// Most scheduled minutes
+    TotalDurationMinutes,
+    /// Fewest gaps in the schedule
+    GapCount,
+}
+
+impl SweepMetric {
+    /// Score of `metrics` under this metric; higher is better
+    pub fn score(&self, metrics: &SimulationMetrics) -> f64 {
+        match self {
+            SweepMetric::TasksScheduled => metrics.tasks_scheduled as f64,
+            SweepMetric::TotalPomodoros => metrics.total_pomodoros as f64,
+            SweepMetric::TotalDurationMinutes => metrics.total_duration_minutes as f64,
+            SweepMetric::GapCount => -(metrics.gap_count as f64),
+        }
+    }
+}
+
+/// One configuration of a parameter sweep and its metrics
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SweepCell {
+    /// Parameter values for this cell, in axis order
+    pub params: Vec<(SweepParameter, i64)>,
+    /// Metrics of the simulation run
+    pub metrics: SimulationMetrics,
+}
+
+/// Result matrix of a parameter sweep
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SweepMatrix {
+    /// Swept parameters, in axis order
+    pub axes: Vec<SweepParameter>,
+    /// Cells in row-major order (last axis varies fastest)
+    pub cells: Vec<SweepCell>,
+}
+
+impl SweepMatrix {
+    /// Best-performing cell by `metric`; ties keep the earliest cell
+    pub fn best_by(&self, metric: SweepMetric) -> Option<&SweepCell> {
+        self.cells
+            .iter()
+            .fold(None, |best: Option<&SweepCell>, cell| match best {
+                Some(b) if metric.score(&b.metrics) >= metric.score(&cell.metrics) => Some(b),
+                _ => Some(cell),
+            })
+    }
+}
+
+/// Correctness rule a generated schedule must satisfy
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PlanningInvariant {
+    /// Every block ends after it starts
+    PositiveDuration,
+    /// Blocks in the same lane never overlap
+    NoOverlap,
+    /// Blocks lie between wake-up and sleep
+    WithinDay,
+    /// Blocks avoid the template's fixed events
+    NoFixedEventOverlap,
+    /// Blocks avoid calendar events
+    NoCalendarOverlap,
+}
+
+/// A broken planning invariant and the blocks that break it
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct InvariantViolation {
+    pub invariant: PlanningInvariant,
+    /// IDs of the offending blocks
+    pub block_ids: Vec<String>,
+    /// Human-readable description
+    pub message: String,
+}
+
+impl std::fmt::Display for InvariantViolation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:?}: {}", self.invariant, self.message)
+    }
+}
+
+/// Check `blocks` scheduled on `day` against the planning invariants.
+///
+/// Returns every violation found, in block order; an empty list means the
+/// schedule is valid. Blocks without a lane share lane 0. Day bounds are
+/// skipped when the template's wake-up or sleep time does not parse.
+///
+/// Pinned blocks (locked blocks and `FixedEvent` tasks) sit where the user
+/// put them, so they are exempt; only a scheduler-placed block overlapping
+/// one of them is reported.
+pub fn check_invariants(
+    blocks: &[ScheduledBlock],
+    template: &DailyTemplate,
+    events: &[CalendarEvent],
+    day: DateTime<Utc>,
+) -> Vec<InvariantViolation> {
+    let scheduler = AutoScheduler::new();
+    let bounds = scheduler.parse_day_boundaries(template, day);
+    let fixed_events = scheduler.build_fixed_events(template, day);
+    let span = |b: &ScheduledBlock| format!("[{}, {})", b.start_time, b.end_time);
+    let mut violations = Vec::new();
+
+    for (i, block) in blocks.iter().enumerate() {
+        if !block.pinned && block.end_time <= block.start_time {
+            violations.push(InvariantViolation {
+                invariant: PlanningInvariant::PositiveDuration,
+                block_ids: vec![block.id.clone()],
+                message: format!(
+                    "Block {} has non-positive duration {}",
+                    block.id,
+                    span(block)
+                ),
+            });
+        }
+
+        for other in &blocks[i + 1..] {
+            if !(block.pinned && other.pinned)
+                && block.lane.unwrap_or(0) == other.lane.unwrap_or(0)
+                && block.start_time < other.end_time
+                && block.end_time > other.start_time
+            {
+                violations.push(InvariantViolation {
+                    invariant: PlanningInvariant::NoOverlap,
+                    block_ids: vec![block.id.clone(), other.id.clone()],
+                    message: format!(
+                        "Blocks {} {} and {} {} overlap",
+                        block.id,
+                        span(block),
+                        other.id,
+                        span(other)
+                    ),
+                });
+            }
+        }
+
+        if block.pinned {
+            continue;
+        }
+
+        if let Some((day_start, day_end)) = bounds {
+            if block.start_time < day_start || block.end_time > day_end {
+                violations.push(InvariantViolation {
+                    invariant: PlanningInvariant::WithinDay,
+                    block_ids: vec![block.id.clone()],
+                    message: format!(
+                        "Block {} {} is outside the day [{}, {})",
+                        block.id,
+                        span(block),
+                        day_start,
+                        day_end
+                    ),
+                });
+            }
+        }
+
+        for event in &fixed_events {
+            if block.start_time < event.end_time && block.end_time > event.start_time {
+                violations.push(InvariantViolation {
+                    invariant: PlanningInvariant::NoFixedEventOverlap,
+                    block_ids: vec![block.id.clone()],
+                    message: format!(
+                        "Block {} {} overlaps a fixed event [{}, {})",
+                        block.id,
+                        span(block),
+                        event.start_time,
+                        event.end_time
+                    ),
+                });
+            }
+        }
+
+        for event in events {
+            if event.overlaps(block.start_time, block.end_time) {
+                violations.push(InvariantViolation {
+                    invariant: PlanningInvariant::NoCalendarOverlap,
+                    block_ids: vec![block.id.clone()],
+                    message: format!(
+                        "Block {} {} overlaps calendar event {}",
+                        block.id,
+                        span(block),
+                        event.id
+                    ),
+                });
+            }
+        }
+    }
+
+    violations
+}
+
 /// Generate a random task using deterministic RNG
 fn generate_random_task(rng: &mut DeterministicRng, index: usize) -> Task {
     let priorities = [10, 30, 50, 70, 90];
@@ -406,6 +851,10 @@ fn generate_random_task(rng: &mut DeterministicRng, index: usize) -> Task {
         allow_split: true,
         suggested_tags: vec![],
         approved_tags: vec![],
+        external_block: None,
+        recurrence: None,
+        recurrence_parent_id: None,
+        notes: Vec::new(),
     }
 }
 
@@ -481,6 +930,37 @@ mod tests {
         assert_eq!(result.metrics.total_tasks, 5);
     }
 
+    #[test]
+    fn test_report_is_reproducible_and_embeds_seed() {
+        let seed = SimulationSeed::new(777);
+        let day = DateTime::parse_from_rfc3339("2026-03-04T00:00:00Z")
+            .unwrap()
+            .with_timezone(&Utc);
+        let report = || {
+            let mut scenario = SimulationScenario::new("report", seed).with_day(day);
+            scenario.generate_random_tasks(6);
+            scenario.generate_random_calendar_events(2);
+            SimulationHarness::new(seed)
+                .run_scenario(&scenario)
+                .to_report()
+        };
+
+        let first = report();
+        let second = report();
+        assert_eq!(first.to_json().unwrap(), second.to_json().unwrap());
+
+        assert_eq!(first.seed, seed);
+        assert!(first.to_json().unwrap().contains("\"seed\": 777"));
+        assert!(first.summary.contains("Seed: 777"));
+        assert!(first.summary.contains("Tasks: "));
+        assert_eq!(first.task_count, 6);
+        assert_eq!(first.calendar_event_count, 2);
+
+        let decoded: SimulationReport = serde_json::from_str(&first.to_json().unwrap()).unwrap();
+        assert_eq!(decoded.seed, seed);
+        assert_eq!(decoded.summary, first.summary);
+    }
+
     #[test]
     fn test_deterministic_scheduling() {
         let seed = SimulationSeed::new(42);
@@ -526,4 +1006,113 @@ mod tests {
         let varied = ScenarioVariation::WakeTime("07:00".to_string()).apply(base);
         assert_eq!(varied.template.wake_up, "07:00");
     }
+
+    #[test]
+    fn test_parameter_sweep() {
+        use chrono::TimeZone;
+
+        let day = Utc.with_ymd_and_hms(2024, 1, 10, 0, 0, 0).unwrap();
+        let mut base = SimulationScenario::new("sweep", SimulationSeed::new(7)).with_day(day);
+        base.generate_random_tasks(8);
+        let grid = ParameterGrid::new()
+            .with_axis(SweepParameter::FocusDuration, vec![25, 40, 50])
+            .with_axis(SweepParameter::LongBreakInterval, vec![3, 4, 5]);
+
+        let mut harness = SimulationHarness::new(SimulationSeed::default());
+        let matrix = harness.sweep(&base, &grid).unwrap();
+        assert_eq!(matrix.cells.len(), 9);
+        assert_eq!(
+            matrix.cells[1].params,
+            vec![
+                (SweepParameter::FocusDuration, 25),
+                (SweepParameter::LongBreakInterval, 4)
+            ]
+        );
+        assert!(matrix.cells.iter().all(|c| c.metrics.total_tasks == 8));
+
+        // Same seed per cell: rerunning gives identical metrics
+        let rerun = SimulationHarness::new(SimulationSeed::new(99))
+            .sweep(&base, &grid)
+            .unwrap();
+        let best = matrix.best_by(SweepMetric::TotalPomodoros).unwrap();
+        let best_rerun = rerun.best_by(SweepMetric::TotalPomodoros).unwrap();
+        assert_eq!(best.params, best_rerun.params);
+        assert!(matrix
+            .cells
+            .iter()
+            .all(|c| c.metrics.total_pomodoros <= best.metrics.total_pomodoros));
+
+        let huge = ParameterGrid::new()
+            .with_axis(SweepParameter::FocusDuration, (1..=100).collect())
+            .with_axis(SweepParameter::ShortBreak, (1..=100).collect());
+        assert!(harness.sweep(&base, &huge).is_err());
+    }
+
+    #[test]
+    fn test_check_invariants_reports_overlapping_blocks() {
+        let day = Utc::now();
+        let template = DailyTemplate {
+            wake_up: "08:00".to_string(),
+            sleep: "20:00".to_string(),
+            fixed_events: vec![],
+            max_parallel_lanes: Some(1),
+        };
+        let start = day
+            .with_hour(10)
+            .and_then(|d| d.with_minute(0))
+            .and_then(|d| d.with_second(0))
+            .and_then(|d| d.with_nanosecond(0))
+            .unwrap();
+        let block = |task_id: &str, offset: i64, lane: Option<i32>| {
+            let begin = start + Duration::minutes(offset);
+            ScheduledBlock::new(
+                task_id.to_string(),
+                task_id.to_string(),
+                begin,
+                begin + Duration::minutes(25),
+                crate::scheduler::ScheduledBlockType::Focus,
+                lane,
+                1,
+                5,
+            )
+        };
+        let first = block("a", 0, None);
+        let second = block("b", 10, None);
+        let parallel = block("c", 10, Some(1));
+        let later = block("d", 40, None);
+
+        let blocks = vec![first.clone(), second.clone(), parallel, later];
+        let violations = check_invariants(&blocks, &template, &[], day);
+        assert_eq!(violations.len(), 1);
+        assert_eq!(violations[0].invariant, PlanningInvariant::NoOverlap);
+        assert_eq!(
+            violations[0].block_ids,
+            vec![first.id.clone(), second.id.clone()]
+        );
+
+        // A calendar event over a block is its own violation
+        let meeting = CalendarEvent::new(
+            "m1".to_string(),
+            "Meeting".to_string(),
+            start + Duration::minutes(50),
+            start + Duration::minutes(80),
+        );
+        let violations = check_invariants(&blocks[2..], &template, &[meeting.clone()], day);
+        assert_eq!(violations.len(), 1);
+        assert_eq!(
+            violations[0].invariant,
+            PlanningInvariant::NoCalendarOverlap
+        );
+
+        // Pinned blocks are exempt, except where a placed block overlaps one
+        let pin = |b: &ScheduledBlock| ScheduledBlock {
+            pinned: true,
+            ..b.clone()
+        };
+        let pinned = vec![pin(&first), pin(&second), pin(&blocks[3])];
+        assert!(check_invariants(&pinned, &template, &[meeting], day).is_empty());
+        let violations = check_invariants(&[pin(&first), second.clone()], &template, &[], day);
+        assert_eq!(violations.len(), 1);
+        assert_eq!(violations[0].block_ids, vec![first.id, second.id]);
+    }
 }
@@ -70,15 +70,30 @@ pub struct ProjectAdherence {
     pub stats: BreakAdherenceStats,
 }
 
+/// Run of consecutive focus sessions whose following break was skipped
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct GrindStreak {
+    /// Start of the first focus session in the run
+    pub start: DateTime<Utc>,
+    /// End of the last focus session in the run
+    pub end: DateTime<Utc>,
+    /// Number of consecutive skipped breaks
+    pub skipped_breaks: u32,
+}
+
 /// High-risk time window identified by analysis
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct HighRiskWindow {
     /// Hour of day (0-23)
     pub hour: u32,
-    /// Ratio of skipped breaks in this window
-    pub skip_rate: f64,
-    /// Ratio of deferred breaks in this window
-    pub defer_rate: f64,
+    /// Ratio of skipped breaks in this window; `None` for windows raised by
+    /// a grind streak, which are not measured per hour
+    pub skip_rate: Option<f64>,
+    /// Ratio of deferred breaks in this window; `None` like `skip_rate`
+    pub defer_rate: Option<f64>,
+    /// Grind streak that triggered this window, if any
+    #[serde(default)]
+    pub grind_streak: Option<GrindStreak>,
 }
 
 /// Complete break adherence report
@@ -92,6 +107,9 @@ pub struct BreakAdherenceReport {
     pub by_project: Vec<ProjectAdherence>,
     /// Identified high-risk windows
     pub high_risk_windows: Vec<HighRiskWindow>,
+    /// Longest run of consecutive skipped breaks
+    #[serde(default)]
+    pub longest_grind_streak: Option<GrindStreak>,
 }
 
 /// Analyzer for break adherence patterns
@@ -101,6 +119,10 @@ pub struct BreakAdherenceAnalyzer {
     pub skip_threshold_min: i64,
     /// Minutes of delay before a break is considered "deferred" (vs just late)
     pub defer_threshold_min: i64,
+    /// Minimum consecutive skipped breaks reported as a grind streak
+    pub grind_streak_min: u32,
+    /// Grind streak length that is surfaced as a high-risk window
+    pub grind_risk_threshold: u32,
 }
 
 impl Default for BreakAdherenceAnalyzer {
@@ -108,6 +130,8 @@ impl Default for BreakAdherenceAnalyzer {
         Self {
             skip_threshold_min: 30,
             defer_threshold_min: 5,
+            grind_streak_min: 2,
+            grind_risk_threshold: 4,
         }
     }
 }
@@ -123,6 +147,7 @@ impl BreakAdherenceAnalyzer {
         Self {
             skip_threshold_min,
             defer_threshold_min,
+            ..Self::default()
         }
     }
 
@@ -242,8 +267,9 @@ impl BreakAdherenceAnalyzer {
             .filter(|h| h.skip_rate > 0.3 || h.defer_rate > 0.5)
             .map(|h| HighRiskWindow {
                 hour: h.hour,
-                skip_rate: h.skip_rate,
-                defer_rate: h.defer_rate,
+                skip_rate: Some(h.skip_rate),
+                defer_rate: Some(h.defer_rate),
+                grind_streak: None,
             })
             .collect();
 
@@ -252,6 +278,7 @@ impl BreakAdherenceAnalyzer {
             by_hour,
             by_project,
             high_risk_windows,
+            longest_grind_streak: None,
         }
     }
 
@@ -274,6 +301,7 @@ impl BreakAdherenceAnalyzer {
     /// - Groups by hour for hourly breakdown
     /// - Groups by project_id for project breakdown
     /// - Identifies high-risk windows where skip_rate > 0.3
+    /// - Adds a high-risk window for each grind streak of at least `grind_risk_threshold`
     pub fn generate_report(&self, rows: &[BreakAdherenceRow]) -> BreakAdherenceReport {
         let mut stats = BreakAdherenceStats::default();
         let mut hourly_map: HashMap<u8, HourlyBuilder> = HashMap::new();
@@ -298,39 +326,7 @@ impl BreakAdherenceAnalyzer {
             let focus_end = parse_datetime(&row.completed_at);
             let hour = row.hour;
 
-            // Look for the next session (could be a break or next focus)
-            let next_row = if i + 1 < rows.len() {
-                Some(&rows[i + 1])
-            } else {
-                None
-            };
-
-            // Determine break status
-            let (status, break_start) = if let Some(next) = next_row {
-                if next.step_type == "break" {
-                    // Found a break following the focus session
-                    // Calculate break start time: completed_at - duration_min
-                    let break_completed = parse_datetime(&next.completed_at);
-                    let break_start_time = break_completed - chrono::Duration::minutes(next.duration_min);
-                    let gap_minutes = (break_start_time - focus_end).num_minutes();
-
-                    let status = if gap_minutes <= self.defer_threshold_min {
-                        BreakStatus::Taken
-                    } else if gap_minutes <= self.skip_threshold_min {
-                        BreakStatus::Deferred
-                    } else {
-                        BreakStatus::Skipped
-                    };
-
-                    (status, Some(break_start_time))
-                } else {
-                    // Next session is a focus, meaning no break was taken
-                    (BreakStatus::Skipped, None)
-                }
-            } else {
-                // No more sessions after this focus
-                (BreakStatus::Skipped, None)
-            };
+            let (status, break_start) = self.next_break_status(rows, i);
 
             // Update statistics based on status
             match status {
@@ -391,21 +387,119 @@ impl BreakAdherenceAnalyzer {
             .collect();
 
         // Identify high-risk windows (hours with skip_rate > 0.3)
-        let high_risk_windows: Vec<HighRiskWindow> = by_hour
+        let mut high_risk_windows: Vec<HighRiskWindow> = by_hour
             .iter()
             .filter(|h| h.skip_rate > 0.3)
             .map(|h| HighRiskWindow {
                 hour: h.hour,
-                skip_rate: h.skip_rate,
-                defer_rate: h.defer_rate,
+                skip_rate: Some(h.skip_rate),
+                defer_rate: Some(h.defer_rate),
+                grind_streak: None,
             })
             .collect();
 
+        // Long grind streaks predict burnout regardless of the hourly rates
+        let streaks = self.grind_streaks(rows);
+        high_risk_windows.extend(
+            streaks
+                .iter()
+                .filter(|s| s.skipped_breaks >= self.grind_risk_threshold)
+                .map(|s| HighRiskWindow {
+                    hour: s.start.hour(),
+                    skip_rate: None,
+                    defer_rate: None,
+                    grind_streak: Some(s.clone()),
+                }),
+        );
+        let longest_grind_streak = streaks.into_iter().reduce(|a, b| {
+            if b.skipped_breaks > a.skipped_breaks {
+                b
+            } else {
+                a
+            }
+        });
+
         BreakAdherenceReport {
             stats,
             by_hour,
             by_project,
             high_risk_windows,
+            longest_grind_streak,
+        }
+    }
+
+    /// Find runs of consecutive focus sessions whose following break was skipped.
+    ///
+    /// A break is skipped when the next session is another focus session, the
+    /// break is recorded with zero duration, or it starts after `skip_threshold_min`.
+    /// Only runs of at least `grind_streak_min` skipped breaks are returned,
+    /// oldest first.
+    pub fn grind_streaks(&self, rows: &[BreakAdherenceRow]) -> Vec<GrindStreak> {
+        let mut streaks = Vec::new();
+        let mut current: Option<GrindStreak> = None;
+
+        for (i, row) in rows.iter().enumerate() {
+            if row.step_type != "focus" {
+                continue;
+            }
+
+            let (status, _) = self.next_break_status(rows, i);
+            if status != BreakStatus::Skipped {
+                streaks.extend(current.take());
+                continue;
+            }
+
+            let focus_end = parse_datetime(&row.completed_at);
+            match current.as_mut() {
+                Some(streak) => {
+                    streak.end = focus_end;
+                    streak.skipped_breaks += 1;
+                }
+                None => {
+                    current = Some(GrindStreak {
+                        start: focus_end - chrono::Duration::minutes(row.duration_min),
+                        end: focus_end,
+                        skipped_breaks: 1,
+                    });
+                }
+            }
+        }
+        streaks.extend(current);
+
+        streaks.retain(|s| s.skipped_breaks >= self.grind_streak_min);
+        streaks
+    }
+
+    /// Break status for the focus session at `rows[i]`, from the row that follows it.
+    fn next_break_status(
+        &self,
+        rows: &[BreakAdherenceRow],
+        i: usize,
+    ) -> (BreakStatus, Option<DateTime<Utc>>) {
+        let focus_end = parse_datetime(&rows[i].completed_at);
+
+        match rows.get(i + 1) {
+            // A zero-duration break is recorded when the break was skipped
+            Some(next) if next.step_type == "break" && next.duration_min > 0 => {
+                // Found a break following the focus session
+                // Calculate break start time: completed_at - duration_min
+                let break_completed = parse_datetime(&next.completed_at);
+                let break_start_time =
+                    break_completed - chrono::Duration::minutes(next.duration_min);
+                let gap_minutes = (break_start_time - focus_end).num_minutes();
+
+                let status = if gap_minutes <= self.defer_threshold_min {
+                    BreakStatus::Taken
+                } else if gap_minutes <= self.skip_threshold_min {
+                    BreakStatus::Deferred
+                } else {
+                    BreakStatus::Skipped
+                };
+
+                (status, Some(break_start_time))
+            }
+            // Next session is a focus or a skipped break, or no more sessions
+            _ => (BreakStatus::Skipped, None),
         }
     }
 }
@@ -527,6 +621,7 @@ impl StatsBuilder {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::storage::database::BreakAdherenceRow;
     use chrono::TimeZone;
 
     fn utc_datetime(year: i32, month: u32, day: u32, hour: u32, min: u32) -> DateTime<Utc> {
@@ -710,7 +805,7 @@ mod tests {
         // Hour 10 should be a high-risk window
         assert_eq!(report.high_risk_windows.len(), 1);
         assert_eq!(report.high_risk_windows[0].hour, 10);
-        assert!(report.high_risk_windows[0].skip_rate > 0.3);
+        assert!(report.high_risk_windows[0].skip_rate.unwrap() > 0.3);
     }
 
     #[test]
@@ -953,7 +1048,98 @@ mod tests {
         assert!(!report.high_risk_windows.is_empty());
         let hour_10_risk = report.high_risk_windows.iter().find(|w| w.hour == 10);
         assert!(hour_10_risk.is_some());
-        assert_eq!(hour_10_risk.unwrap().skip_rate, 1.0);
+        assert_eq!(hour_10_risk.unwrap().skip_rate, Some(1.0));
+    }
+
+    fn focus_row(completed_at: &str, hour: u8) -> BreakAdherenceRow {
+        BreakAdherenceRow {
+            completed_at: completed_at.into(),
+            step_type: "focus".into(),
+            duration_min: 25,
+            project_id: None,
+            hour,
+            day_of_week: 4,
+        }
+    }
+
+    #[test]
+    fn test_grind_streak_without_breaks_is_high_risk() {
+        let analyzer = BreakAdherenceAnalyzer::new();
+        // Four back-to-back focus sessions with no breaks in between
+        let rows = vec![
+            focus_row("2026-01-01T09:25:00Z", 9),
+            focus_row("2026-01-01T09:50:00Z", 9),
+            focus_row("2026-01-01T10:15:00Z", 10),
+            focus_row("2026-01-01T10:40:00Z", 10),
+        ];
+
+        let streaks = analyzer.grind_streaks(&rows);
+        assert_eq!(
+            streaks,
+            vec![GrindStreak {
+                start: utc_datetime(2026, 1, 1, 9, 0),
+                end: utc_datetime(2026, 1, 1, 10, 40),
+                skipped_breaks: 4,
+            }]
+        );
+
+        let report = analyzer.generate_report(&rows);
+        assert_eq!(report.longest_grind_streak, Some(streaks[0].clone()));
+        let grind_window = report
+            .high_risk_windows
+            .iter()
+            .find(|w| w.grind_streak.is_some())
+            .expect("grind streak should surface a high-risk window");
+        assert_eq!(grind_window.hour, 9);
+        assert_eq!(grind_window.skip_rate, None);
+        assert_eq!(
+            grind_window.grind_streak.as_ref().unwrap().skipped_breaks,
+            4
+        );
+    }
+
+    #[test]
+    fn test_zero_duration_break_counts_as_skipped() {
+        let analyzer = BreakAdherenceAnalyzer::new();
+        let rows = vec![
+            focus_row("2026-01-01T09:25:00Z", 9),
+            BreakAdherenceRow {
+                completed_at: "2026-01-01T09:25:00Z".into(),
+                step_type: "break".into(),
+                duration_min: 0,
+                project_id: None,
+                hour: 9,
+                day_of_week: 4,
+            },
+            focus_row("2026-01-01T09:50:00Z", 9),
+            BreakAdherenceRow {
+                completed_at: "2026-01-01T09:55:00Z".into(),
+                step_type: "break".into(),
+                duration_min: 5,
+                project_id: None,
+                hour: 9,
+                day_of_week: 4,
+            },
+            focus_row("2026-01-01T10:20:00Z", 10),
+        ];
+
+        let report = analyzer.generate_report(&rows);
+        assert_eq!(report.stats.breaks_skipped, 2);
+        assert_eq!(report.stats.breaks_taken, 1);
+
+        // The taken break splits the skips into two separate runs
+        let analyzer = BreakAdherenceAnalyzer {
+            grind_streak_min: 1,
+            ..BreakAdherenceAnalyzer::new()
+        };
+        let streaks = analyzer.grind_streaks(&rows);
+        assert_eq!(streaks.len(), 2);
+        assert!(streaks.iter().all(|s| s.skipped_breaks == 1));
+        assert!(analyzer
+            .generate_report(&rows)
+            .high_risk_windows
+            .iter()
+            .all(|w| w.grind_streak.is_none()));
     }
 
     #[test]
@@ -5,6 +5,8 @@
 
 use serde::{Deserialize, Serialize};
 
+use crate::task::{Task, TaskState, POMODORO_MINUTES};
+
 /// Accuracy metrics for a single estimate.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct EstimateAccuracy {
@@ -152,6 +154,57 @@ pub struct AccuracySessionData {
     pub project: Option<String>,
 }
 
+/// Estimated size bucket for the calibration curve.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SizeBucket {
+    /// Estimated at one pomodoro or less
+    OnePomodoro,
+    /// Estimated at two pomodoros
+    TwoPomodoros,
+    /// Estimated at three pomodoros or more
+    ThreePlusPomodoros,
+}
+
+impl SizeBucket {
+    /// All buckets, smallest first.
+    pub const ALL: [SizeBucket; 3] = [
+        SizeBucket::OnePomodoro,
+        SizeBucket::TwoPomodoros,
+        SizeBucket::ThreePlusPomodoros,
+    ];
+
+    /// Bucket for an estimate in minutes, rounded up to whole pomodoros.
+    pub fn for_minutes(estimated_minutes: u32) -> Self {
+        match estimated_minutes.div_ceil(POMODORO_MINUTES) {
+            0 | 1 => SizeBucket::OnePomodoro,
+            2 => SizeBucket::TwoPomodoros,
+            _ => SizeBucket::ThreePlusPomodoros,
+        }
+    }
+
+    /// Short label ("1p", "2p", "3+p").
+    pub fn label(&self) -> &'static str {
+        match self {
+            SizeBucket::OnePomodoro => "1p",
+            SizeBucket::TwoPomodoros => "2p",
+            SizeBucket::ThreePlusPomodoros => "3+p",
+        }
+    }
+}
+
+/// One point of the calibration curve.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CalibrationBucket {
+    pub bucket: SizeBucket,
+    /// Accuracy stats for tasks in this bucket, keyed by the bucket label
+    pub stats: AccuracyStats,
+    /// Median actual/estimated ratio (1.0 when the bucket is empty)
+    pub median_ratio: f64,
+    /// Fewer samples than the tracker needs for a confident estimate
+    pub low_confidence: bool,
+}
+
 /// Tracker for computing estimate accuracy.
 #[derive(Debug, Clone)]
 pub struct EstimateAccuracyTracker {
@@ -291,6 +344,55 @@ impl EstimateAccuracyTracker {
         }
     }
 
+    /// Compute the calibration curve over completed tasks.
+    ///
+    /// Tasks are bucketed by estimate (1p, 2p, 3+p) and each bucket reports
+    /// its median actual/estimated ratio. Only completed tasks with both an
+    /// estimate and elapsed time are counted. All three buckets are returned
+    /// in size order, empty ones included.
+    pub fn calibration_curve(&self, tasks: &[Task]) -> Vec<CalibrationBucket> {
+        let samples: Vec<(SizeBucket, AccuracySessionData)> = tasks
+            .iter()
+            .filter(|task| task.state == TaskState::Done && task.elapsed_minutes > 0)
+            .filter_map(|task| {
+                let estimated = task
+                    .estimated_minutes
+                    .unwrap_or_else(|| task.estimated_pomodoros.max(0) as u32 * POMODORO_MINUTES);
+                (estimated > 0).then(|| {
+                    let data = AccuracySessionData {
+                        planned_duration: estimated,
+                        actual_duration: task.elapsed_minutes,
+                        tag: None,
+                        project: task.project_name.clone(),
+                    };
+                    (SizeBucket::for_minutes(estimated), data)
+                })
+            })
+            .collect();
+
+        SizeBucket::ALL
+            .iter()
+            .map(|&bucket| {
+                let sessions: Vec<&AccuracySessionData> = samples
+                    .iter()
+                    .filter(|(b, _)| *b == bucket)
+                    .map(|(_, data)| data)
+                    .collect();
+                let mut ratios: Vec<f64> = sessions
+                    .iter()
+                    .map(|s| s.actual_duration as f64 / s.planned_duration as f64)
+                    .collect();
+                let stats = self.compute_group_stats(bucket.label().to_string(), sessions);
+                CalibrationBucket {
+                    bucket,
+                    low_confidence: stats.session_count < self.min_sessions_for_confidence,
+                    median_ratio: median(&mut ratios).unwrap_or(1.0),
+                    stats,
+                }
+            })
+            .collect()
+    }
+
     /// Render accuracy report as ASCII table.
     pub fn render_report(&self, stats: &[AccuracyStats]) -> String {
         let mut output = String::new();
@@ -339,6 +441,19 @@ impl EstimateAccuracyTracker {
     }
 }
 
+fn median(values: &mut [f64]) -> Option<f64> {
+    if values.is_empty() {
+        return None;
+    }
+    values.sort_by(|a, b| a.total_cmp(b));
+    let mid = values.len() / 2;
+    if values.len().is_multiple_of(2) {
+        Some((values[mid - 1] + values[mid]) / 2.0)
+    } else {
+        Some(values[mid])
+    }
+}
+
 fn truncate(s: &str, max_len: usize) -> String {
     if s.len() <= max_len {
         s.to_string()
@@ -491,4 +606,72 @@ mod tests {
         assert!((test_stats.corrective_factor - 2.0).abs() < 0.01);
         assert!(test_stats.correction_suggestion().contains("100% longer"));
     }
+
+    fn done_task(estimated_pomodoros: i32, elapsed_minutes: u32) -> Task {
+        let mut task = Task::new("Task");
+        task.estimated_pomodoros = estimated_pomodoros;
+        task.elapsed_minutes = elapsed_minutes;
+        task.state = TaskState::Done;
+        task.completed = true;
+        task
+    }
+
+    #[test]
+    fn test_calibration_curve_reports_median_ratio_per_bucket() {
+        let tracker = EstimateAccuracyTracker::with_settings(3);
+        let mut tasks = vec![
+            // 1p: roughly on target
+            done_task(1, 25),
+            done_task(1, 24),
+            done_task(1, 27),
+            // 2p: one sample, heavily overrun
+            done_task(2, 100),
+            // 3+p: consistently underestimated by ~1.5x
+            done_task(3, 110),
+            done_task(4, 150),
+            done_task(3, 120),
+            done_task(5, 190),
+        ];
+        // Ignored: not done, or no elapsed time
+        let mut running = done_task(1, 200);
+        running.state = TaskState::Running;
+        tasks.push(running);
+        tasks.push(done_task(2, 0));
+
+        let curve = tracker.calibration_curve(&tasks);
+        let labels: Vec<_> = curve.iter().map(|b| b.stats.key.as_str()).collect();
+        assert_eq!(labels, vec!["1p", "2p", "3+p"]);
+
+        let one = &curve[0];
+        assert_eq!(one.stats.session_count, 3);
+        assert!((one.median_ratio - 1.0).abs() < 0.01);
+        assert!(!one.low_confidence);
+
+        let two = &curve[1];
+        assert_eq!(two.stats.session_count, 1);
+        assert!((two.median_ratio - 2.0).abs() < 0.01);
+        assert!(two.low_confidence);
+
+        let three = &curve[2];
+        assert_eq!(three.bucket, SizeBucket::ThreePlusPomodoros);
+        assert_eq!(three.stats.session_count, 4);
+        assert!((three.median_ratio - 1.5).abs() < 0.05);
+        assert!(three.stats.mean_bias > 0.0);
+        assert!(!three.low_confidence);
+    }
+
+    #[test]
+    fn test_calibration_curve_buckets_by_estimated_minutes() {
+        assert_eq!(SizeBucket::for_minutes(20), SizeBucket::OnePomodoro);
+        assert_eq!(SizeBucket::for_minutes(26), SizeBucket::TwoPomodoros);
+        assert_eq!(SizeBucket::for_minutes(51), SizeBucket::ThreePlusPomodoros);
+
+        let mut task = done_task(1, 30);
+        task.estimated_minutes = Some(60);
+        let curve = EstimateAccuracyTracker::new().calibration_curve(&[task]);
+        assert_eq!(curve[2].stats.session_count, 1);
+        assert!((curve[2].median_ratio - 0.5).abs() < 0.01);
+        assert!(curve[0].low_confidence);
+        assert_eq!(curve[0].median_ratio, 1.0);
+    }
 }
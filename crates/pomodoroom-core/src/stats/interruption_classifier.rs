@@ -0,0 +1,271 @@
+//! Heuristic interruption classification.
+//!
+//! Guesses the source and priority of an interruption the user did not label,
+//! from the interruption note, the active task title, the time of day and
+//! whether an external event (webhook, calendar) arrived just before it.
+
+use chrono::{DateTime, Timelike, Utc};
+use serde::{Deserialize, Serialize};
+
+use super::interruption_heatmap::{
+    InterruptionPriority, InterruptionSource, InterruptionSourceType,
+};
+use crate::interruption_budget::InterruptionType;
+
+/// Signals available when an interruption is recorded.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct InterruptionContext {
+    /// Free-form note or payload attached to the interruption
+    pub note: Option<String>,
+    /// Title of the task that was active when the interruption happened
+    pub active_task_title: Option<String>,
+    /// When the interruption happened
+    pub occurred_at: DateTime<Utc>,
+    /// Whether a webhook or other external event arrived just before
+    pub preceded_by_external_event: bool,
+}
+
+/// Best-guess classification of an interruption.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct InterruptionClassification {
+    /// Most likely source
+    pub source: InterruptionSource,
+    /// External/internal classification; `External` for unrecognized
+    /// interruptions that followed an external event
+    pub source_type: InterruptionSourceType,
+    /// Estimated priority
+    pub priority: InterruptionPriority,
+    /// Confidence of the guess (0.0-1.0)
+    pub confidence: f32,
+}
+
+impl InterruptionClassification {
+    /// Equivalent budget tracker interruption type.
+    pub fn interruption_type(&self) -> InterruptionType {
+        match self.source {
+            InterruptionSource::Slack { .. } | InterruptionSource::Email { .. } => {
+                InterruptionType::Notification
+            }
+            InterruptionSource::Phone { .. } | InterruptionSource::Meeting { .. } => {
+                InterruptionType::Meeting
+            }
+            InterruptionSource::Blocker => InterruptionType::System,
+            InterruptionSource::ContextSwitch | InterruptionSource::Fatigue => {
+                InterruptionType::SelfDistraction
+            }
+            InterruptionSource::Other(_) => match self.source_type {
+                InterruptionSourceType::External => InterruptionType::Notification,
+                InterruptionSourceType::Internal => InterruptionType::Other,
+            },
+        }
+    }
+}
+
+/// Words that raise the priority of a matched source.
+const URGENT_KEYWORDS: &[&str] = &["urgent", "asap", "@here", "@channel", "incident", "outage"];
+
+/// Classify an unlabeled interruption.
+///
+/// The note is the strongest signal, then the active task title, then an
+/// external event, then the time of day. Callers should only use this when
+/// the user has not labeled the interruption themselves.
+pub fn classify_interruption(context: &InterruptionContext) -> InterruptionClassification {
+    let note = context.note.as_deref().unwrap_or_default().to_lowercase();
+    let title = context
+        .active_task_title
+        .as_deref()
+        .unwrap_or_default()
+        .to_lowercase();
+
+    let matched = match_source(&note)
+        .map(|source| (source, 0.9))
+        .or_else(|| match_source(&title).map(|source| (source, 0.6)));
+
+    if let Some((source, confidence)) = matched {
+        let urgent = URGENT_KEYWORDS.iter().any(|k| note.contains(k));
+        let priority = match (&source, urgent) {
+            (_, true) | (InterruptionSource::Blocker, _) => InterruptionPriority::High,
+            (InterruptionSource::Slack { priority }, _)
+            | (InterruptionSource::Email { priority }, _)
+            | (InterruptionSource::Phone { priority }, _)
+            | (InterruptionSource::Meeting { priority }, _) => priority.clone(),
+            _ => InterruptionPriority::Low,
+        };
+        let source = with_priority(source, priority.clone());
+        return InterruptionClassification {
+            source_type: source.source_type(),
+            source,
+            priority,
+            confidence,
+        };
+    }
+
+    if context.preceded_by_external_event {
+        return InterruptionClassification {
+            source: InterruptionSource::Other("external event".to_string()),
+            source_type: InterruptionSourceType::External,
+            priority: InterruptionPriority::Medium,
+            confidence: 0.5,
+        };
+    }
+
+    // Late-night interruptions with no other signal are most likely fatigue
+    let hour = context.occurred_at.hour();
+    if !(6..20).contains(&hour) {
+        return InterruptionClassification {
+            source: InterruptionSource::Fatigue,
+            source_type: InterruptionSourceType::Internal,
+            priority: InterruptionPriority::Low,
+            confidence: 0.3,
+        };
+    }
+
+    InterruptionClassification {
+        source: InterruptionSource::Other("unclassified".to_string()),
+        source_type: InterruptionSourceType::Internal,
+        priority: InterruptionPriority::Low,
+        confidence: 0.1,
+    }
+}
+
+/// Source implied by keywords in `text`; the first matching group wins.
+fn match_source(text: &str) -> Option<InterruptionSource> {
+    let has = |keywords: &[&str]| keywords.iter().any(|k| contains_word(text, k));
+
+    if has(&[
+        "build failed",
+        "build failure",
+        "ci failed",
+        "pipeline",
+        "test failure",
+        "outage",
+        "incident",
+        "deploy",
+        "blocked",
+        "crash",
+    ]) {
+        Some(InterruptionSource::Blocker)
+    } else if has(&["meeting", "standup", "stand-up", "1:1", "sync", "calendar"]) {
+        Some(InterruptionSource::Meeting {
+            priority: InterruptionPriority::Medium,
+        })
+    } else if has(&["phone", "call"]) {
+        Some(InterruptionSource::Phone {
+            priority: InterruptionPriority::High,
+        })
+    } else if has(&["slack", "teams", "discord", "mention", "dm", "chat"]) {
+        Some(InterruptionSource::Slack {
+            priority: InterruptionPriority::Medium,
+        })
+    } else if has(&["email", "e-mail", "mail", "inbox"]) {
+        Some(InterruptionSource::Email {
+            priority: InterruptionPriority::Low,
+        })
+    } else if has(&["tired", "sleepy", "exhausted", "fatigue"]) {
+        Some(InterruptionSource::Fatigue)
+    } else if has(&["switch", "context", "another task"]) {
+        Some(InterruptionSource::ContextSwitch)
+    } else {
+        None
+    }
+}
+
+/// Whether `text` contains `needle` not embedded in a longer word.
+fn contains_word(text: &str, needle: &str) -> bool {
+    text.match_indices(needle).any(|(start, _)| {
+        let end = start + needle.len();
+        let before = text[..start].chars().next_back();
+        let after = text[end..].chars().next();
+        !before.is_some_and(char::is_alphanumeric) && !after.is_some_and(char::is_alphanumeric)
+    })
+}
+
+/// Replace the priority carried by a source, if it has one.
+fn with_priority(source: InterruptionSource, priority: InterruptionPriority) -> InterruptionSource {
+    match source {
+        InterruptionSource::Slack { .. } => InterruptionSource::Slack { priority },
+        InterruptionSource::Email { .. } => InterruptionSource::Email { priority },
+        InterruptionSource::Phone { .. } => InterruptionSource::Phone { priority },
+        InterruptionSource::Meeting { .. } => InterruptionSource::Meeting { priority },
+        other => other,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    fn context(note: &str, title: &str) -> InterruptionContext {
+        InterruptionContext {
+            note: Some(note.to_string()),
+            active_task_title: Some(title.to_string()),
+            occurred_at: Utc.with_ymd_and_hms(2026, 1, 5, 10, 0, 0).unwrap(),
+            preceded_by_external_event: false,
+        }
+    }
+
+    #[test]
+    fn classifies_keywords_from_note() {
+        let slack = classify_interruption(&context("Slack DM from Alex", "Write report"));
+        assert_eq!(
+            slack.source,
+            InterruptionSource::Slack {
+                priority: InterruptionPriority::Medium
+            }
+        );
+        assert_eq!(slack.source_type, InterruptionSourceType::External);
+        assert_eq!(slack.interruption_type(), InterruptionType::Notification);
+
+        let meeting = classify_interruption(&context("Pulled into a meeting", "Write report"));
+        assert_eq!(meeting.source_type, InterruptionSourceType::External);
+        assert_eq!(meeting.interruption_type(), InterruptionType::Meeting);
+
+        let build = classify_interruption(&context("build failed on main", "Write report"));
+        assert_eq!(build.source, InterruptionSource::Blocker);
+        assert_eq!(build.source_type, InterruptionSourceType::Internal);
+        assert_eq!(build.priority, InterruptionPriority::High);
+        assert_eq!(build.interruption_type(), InterruptionType::System);
+    }
+
+    #[test]
+    fn urgent_words_raise_priority() {
+        let result = classify_interruption(&context("slack: urgent, prod question", ""));
+        assert_eq!(result.priority, InterruptionPriority::High);
+        assert_eq!(
+            result.source,
+            InterruptionSource::Slack {
+                priority: InterruptionPriority::High
+            }
+        );
+    }
+
+    #[test]
+    fn falls_back_to_title_event_and_time() {
+        // Task title is weaker than the note but still used
+        let from_title = classify_interruption(&context("", "Weekly sync prep"));
+        assert!(matches!(
+            from_title.source,
+            InterruptionSource::Meeting { .. }
+        ));
+        assert!(from_title.confidence < 0.9);
+
+        // Keywords inside longer words do not match
+        let unmatched = classify_interruption(&context("fixed the ddmm parser", "Refactor"));
+        assert_eq!(unmatched.source_type, InterruptionSourceType::Internal);
+        assert_eq!(unmatched.interruption_type(), InterruptionType::Other);
+
+        let mut external = context("", "Refactor");
+        external.preceded_by_external_event = true;
+        let external = classify_interruption(&external);
+        assert_eq!(external.source_type, InterruptionSourceType::External);
+        assert_eq!(external.interruption_type(), InterruptionType::Notification);
+
+        let mut late = context("", "Refactor");
+        late.occurred_at = Utc.with_ymd_and_hms(2026, 1, 5, 23, 30, 0).unwrap();
+        assert_eq!(
+            classify_interruption(&late).source,
+            InterruptionSource::Fatigue
+        );
+    }
+}
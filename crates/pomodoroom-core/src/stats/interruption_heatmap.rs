@@ -3,9 +3,13 @@
 //! This module provides interruption heatmap analysis by hour and source
 //! to identify when and why interruptions cluster.
 
-use chrono::{DateTime, Utc, Datelike, Timelike};
+use std::collections::BTreeMap;
+
+use chrono::{DateTime, Duration, Utc, Datelike, Timelike};
 use serde::{Deserialize, Serialize};
 
+use super::interruption_classifier::{classify_interruption, InterruptionContext};
+
 /// Interruption source classification.
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum InterruptionSourceType {
@@ -27,6 +31,17 @@ pub enum InterruptionSource {
 }
 
 impl InterruptionSource {
+    /// Priority of external sources; internal ones have none.
+    pub fn priority(&self) -> Option<&InterruptionPriority> {
+        match self {
+            InterruptionSource::Slack { priority }
+            | InterruptionSource::Email { priority }
+            | InterruptionSource::Phone { priority }
+            | InterruptionSource::Meeting { priority } => Some(priority),
+            _ => None,
+        }
+    }
+
     /// Get the source type classification.
     pub fn source_type(&self) -> InterruptionSourceType {
         match self {
@@ -72,6 +87,9 @@ pub enum InterruptionImpact {
     Severe,
 }
 
+/// `(paused_at, resumed_at)` span of a paused session.
+pub type PauseInterval = (DateTime<Utc>, DateTime<Utc>);
+
 /// Single interruption event.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct InterruptionEvent {
@@ -83,6 +101,10 @@ pub struct InterruptionEvent {
 
 impl InterruptionEvent {
     /// Parse an interruption event from a database row.
+    ///
+    /// Labeled operation types are authoritative; unlabeled interruptions
+    /// (`interruption:unlabeled` or any unknown suffix) are auto-tagged with
+    /// [`classify_interruption`] from the data payload.
     pub fn from_row(
         occurred_at: String,
         operation_type: String,
@@ -97,6 +119,17 @@ impl InterruptionEvent {
             "interruption:context" => InterruptionSource::ContextSwitch,
             "interruption:fatigue" => InterruptionSource::Fatigue,
             "interruption:blocker" => InterruptionSource::Blocker,
+            other if other.starts_with("interruption:") => {
+                let context = InterruptionContext {
+                    note: Some(data.clone()),
+                    active_task_title: None,
+                    occurred_at: DateTime::parse_from_rfc3339(&occurred_at)
+                        .ok()?
+                        .with_timezone(&Utc),
+                    preceded_by_external_event: false,
+                };
+                classify_interruption(&context).source
+            }
             _ => return None,
         };
 
@@ -111,12 +144,29 @@ impl InterruptionEvent {
 
         Some(InterruptionEvent {
             occurred_at,
-            duration_minutes: 0, // Would be parsed from data
+            duration_minutes: 0,
             source,
             impact,
         })
     }
 
+    /// Set `duration_minutes` from the pause this interruption falls in.
+    ///
+    /// An interruption logged up to a minute before the pause still counts
+    /// as its cause. Events outside every pause keep a duration of zero.
+    pub fn attach_pause(&mut self, pauses: &[PauseInterval]) {
+        let Ok(at) = DateTime::parse_from_rfc3339(&self.occurred_at) else {
+            return;
+        };
+        let at = at.with_timezone(&Utc);
+        if let Some((paused_at, resumed_at)) = pauses
+            .iter()
+            .find(|(paused_at, resumed_at)| *paused_at - Duration::minutes(1) <= at && at <= *resumed_at)
+        {
+            self.duration_minutes = (*resumed_at - *paused_at).num_minutes().max(0) as u32;
+        }
+    }
+
     /// Get the hour of day (0-23) from occurred_at timestamp.
     pub fn hour(&self) -> u8 {
         if let Ok(dt) = DateTime::parse_from_rfc3339(&self.occurred_at) {
@@ -126,6 +176,13 @@ impl InterruptionEvent {
         }
     }
 
+    /// Calendar date (YYYY-MM-DD, UTC) of the interruption.
+    pub fn date(&self) -> Option<String> {
+        DateTime::parse_from_rfc3339(&self.occurred_at)
+            .ok()
+            .map(|dt| dt.with_timezone(&Utc).format("%Y-%m-%d").to_string())
+    }
+
     /// Get the day of week (0-6, Sunday=0) from occurred_at timestamp.
     pub fn day_of_week(&self) -> u8 {
         if let Ok(dt) = DateTime::parse_from_rfc3339(&self.occurred_at) {
@@ -204,9 +261,60 @@ impl HeatmapCell {
     }
 }
 
+/// Parameters for estimating the focus time an interruption costs.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct InterruptionCostConfig {
+    /// Minutes needed to get back to full focus after an interruption
+    pub ramp_up_minutes: f64,
+    /// Ramp-up multiplier for low-priority interruptions
+    pub low_priority_factor: f64,
+    /// Ramp-up multiplier for medium-priority and internal interruptions
+    pub medium_priority_factor: f64,
+    /// Ramp-up multiplier for high-priority interruptions
+    pub high_priority_factor: f64,
+}
+
+impl Default for InterruptionCostConfig {
+    fn default() -> Self {
+        Self {
+            ramp_up_minutes: 10.0,
+            low_priority_factor: 0.5,
+            medium_priority_factor: 1.0,
+            high_priority_factor: 1.5,
+        }
+    }
+}
+
+impl InterruptionCostConfig {
+    /// Focus minutes lost to one interruption: the paused time plus the
+    /// ramp-up scaled by priority.
+    pub fn lost_minutes(&self, event: &InterruptionEvent) -> f64 {
+        let factor = match event.source.priority() {
+            Some(InterruptionPriority::Low) => self.low_priority_factor,
+            Some(InterruptionPriority::High) => self.high_priority_factor,
+            Some(InterruptionPriority::Medium) | None => self.medium_priority_factor,
+        };
+        event.duration_minutes as f64 + self.ramp_up_minutes * factor
+    }
+}
+
+/// Estimated focus time lost to interruptions.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct LostFocusReport {
+    /// Minutes lost across all interruptions
+    pub total_minutes: f64,
+    /// Minutes lost per source name
+    pub by_source: BTreeMap<String, f64>,
+    /// Minutes lost per hour of day (24 entries)
+    pub by_hour: Vec<f64>,
+    /// Minutes lost per day (YYYY-MM-DD)
+    pub daily_totals: BTreeMap<String, f64>,
+}
+
 /// Interruption heatmap analyzer.
 pub struct InterruptionHeatmapAnalyzer {
     pub min_heat_threshold: u64,
+    pub cost: InterruptionCostConfig,
 }
 
 impl Default for InterruptionHeatmapAnalyzer {
@@ -220,9 +328,44 @@ impl InterruptionHeatmapAnalyzer {
     pub fn new() -> Self {
         Self {
             min_heat_threshold: 3,
+            cost: InterruptionCostConfig::default(),
         }
     }
 
+    /// Use `cost` to estimate lost focus time.
+    pub fn with_cost(mut self, cost: InterruptionCostConfig) -> Self {
+        self.cost = cost;
+        self
+    }
+
+    /// Estimate the focus minutes lost to `events`.
+    ///
+    /// Each interruption costs the time the session was paused plus a
+    /// ramp-up period to regain focus, see [`InterruptionCostConfig`].
+    /// Events with an unparseable timestamp count towards the total and the
+    /// source breakdown only.
+    pub fn estimated_lost_minutes(&self, events: &[InterruptionEvent]) -> LostFocusReport {
+        let mut report = LostFocusReport {
+            by_hour: vec![0.0; 24],
+            ..LostFocusReport::default()
+        };
+
+        for event in events {
+            let lost = self.cost.lost_minutes(event);
+            report.total_minutes += lost;
+            *report
+                .by_source
+                .entry(event.source.name().to_string())
+                .or_default() += lost;
+            if let Some(date) = event.date() {
+                report.by_hour[event.hour() as usize] += lost;
+                *report.daily_totals.entry(date).or_default() += lost;
+            }
+        }
+
+        report
+    }
+
     /// Build heatmap from interruption events.
     pub fn build_heatmap(&self, events: &[InterruptionEvent]) -> InterruptionHeatmap {
         let mut cells = vec![HeatmapCell::new(0, 0); 168];
@@ -440,6 +583,92 @@ mod tests {
         assert_eq!(fatigue.name(), "fatigue");
     }
 
+    #[test]
+    fn test_from_row_auto_tags_unlabeled_interruptions() {
+        let at = "2026-01-05T10:00:00Z".to_string();
+
+        // Manual labels win even when the payload suggests otherwise
+        let labeled = InterruptionEvent::from_row(
+            at.clone(),
+            "interruption:email".to_string(),
+            r#"{"note":"slack ping"}"#.to_string(),
+        )
+        .unwrap();
+        assert_eq!(labeled.source.name(), "email");
+
+        let unlabeled = InterruptionEvent::from_row(
+            at,
+            "interruption:unlabeled".to_string(),
+            r#"{"note":"build failed on main"}"#.to_string(),
+        )
+        .unwrap();
+        assert_eq!(unlabeled.source, InterruptionSource::Blocker);
+    }
+
+    #[test]
+    fn test_high_priority_long_pause_costs_more_than_quick_low_priority() {
+        let analyzer = InterruptionHeatmapAnalyzer::new();
+        let phone = InterruptionEvent {
+            occurred_at: "2026-02-17T10:15:00+00:00".to_string(),
+            duration_minutes: 20,
+            source: InterruptionSource::Phone { priority: InterruptionPriority::High },
+            impact: InterruptionImpact::Severe,
+        };
+        let email = InterruptionEvent {
+            occurred_at: "2026-02-17T14:05:00+00:00".to_string(),
+            duration_minutes: 0,
+            source: InterruptionSource::Email { priority: InterruptionPriority::Low },
+            impact: InterruptionImpact::Minimal,
+        };
+
+        let phone_cost = analyzer.cost.lost_minutes(&phone);
+        let email_cost = analyzer.cost.lost_minutes(&email);
+        assert_eq!(phone_cost, 35.0);
+        assert_eq!(email_cost, 5.0);
+        assert!(phone_cost > email_cost);
+
+        let report = analyzer.estimated_lost_minutes(&[phone, email]);
+        assert_eq!(report.total_minutes, 40.0);
+        assert_eq!(report.by_source["phone"], 35.0);
+        assert_eq!(report.by_hour[10], 35.0);
+        assert_eq!(report.by_hour[14], 5.0);
+        assert_eq!(report.daily_totals["2026-02-17"], 40.0);
+    }
+
+    #[test]
+    fn test_attach_pause_uses_pause_resume_span() {
+        let at = |m: u32| {
+            DateTime::parse_from_rfc3339(&format!("2026-01-05T10:{m:02}:00Z"))
+                .unwrap()
+                .with_timezone(&Utc)
+        };
+        let pauses = [(at(0), at(12)), (at(30), at(35))];
+        let event = |m: u32| {
+            InterruptionEvent::from_row(
+                format!("2026-01-05T10:{m:02}:00Z"),
+                "interruption:phone".to_string(),
+                "{}".to_string(),
+            )
+            .unwrap()
+        };
+
+        let mut during = event(5);
+        during.attach_pause(&pauses);
+        assert_eq!(during.duration_minutes, 12);
+
+        // Logged just before the pause started
+        let mut before = InterruptionEvent {
+            occurred_at: "2026-01-05T10:29:30Z".to_string(),
+            ..event(0)
+        };
+        before.attach_pause(&pauses);
+        assert_eq!(before.duration_minutes, 5);
+
+        let mut unpaused = event(20);
+        unpaused.attach_pause(&pauses);
+        assert_eq!(unpaused.duration_minutes, 0);
+    }
+
     #[test]
     fn test_heatmap_cell_new() {
         let cell = HeatmapCell::new(1, 9);
@@ -6,22 +6,28 @@
 
 mod break_adherence;
 mod estimate_accuracy;
+mod interruption_classifier;
 mod interruption_heatmap;
 mod split_efficiency;
 
 pub use break_adherence::{
     BreakStatus, BreakAdherenceStats, BreakAdherenceReport,
-    HourlyAdherence, ProjectAdherence, HighRiskWindow, BreakAdherenceAnalyzer,
+    HourlyAdherence, ProjectAdherence, HighRiskWindow, BreakAdherenceAnalyzer, GrindStreak,
 };
 
 pub use estimate_accuracy::{
     EstimateAccuracy, AccuracyStats, GroupBy, AccuracySessionData, EstimateAccuracyTracker,
+    CalibrationBucket, SizeBucket,
+};
+
+pub use interruption_classifier::{
+    classify_interruption, InterruptionClassification, InterruptionContext,
 };
 
 pub use interruption_heatmap::{
     InterruptionHeatmap, HeatmapCell, InterruptionEvent, InterruptionSource,
     InterruptionSourceType, InterruptionPriority, InterruptionImpact,
-    InterruptionHeatmapAnalyzer,
+    InterruptionHeatmapAnalyzer, InterruptionCostConfig, LostFocusReport, PauseInterval,
 };
 
 pub use split_efficiency::{
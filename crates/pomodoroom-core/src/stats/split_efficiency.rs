@@ -684,6 +684,10 @@ mod tests {
             allow_split: true,
             suggested_tags: vec![],
             approved_tags: vec![],
+            external_block: None,
+            recurrence: None,
+            recurrence_parent_id: None,
+            notes: Vec::new(),
         }
     }
 
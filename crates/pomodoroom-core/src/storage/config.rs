@@ -10,13 +10,22 @@
 //!
 //! Configuration is stored at `~/.config/pomodoroom/config.toml`.
 
+use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
-use std::path::PathBuf;
+use std::collections::{BTreeMap, HashMap};
+use std::io::Write;
+use std::ops::RangeInclusive;
+use std::path::{Path, PathBuf};
 
 use super::data_dir;
+use super::sealed::{has_sealed_fields, FieldCipher};
+use crate::error::ConfigError;
+use crate::policy::{check_compatibility, constraints, Compatibility};
 use crate::timer::Schedule;
 
+/// Current config bundle format version (semver).
+pub const CONFIG_BUNDLE_VERSION: &str = "1.0.0";
+
 /// Schedule-specific configuration.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ScheduleConfig {
@@ -80,6 +89,17 @@ pub struct ShortcutsConfig {
     pub bindings: HashMap<String, String>,
 }
 
+/// Calendar sync configuration.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct SyncConfig {
+    /// Longest value written to the conflict log, in characters.
+    #[serde(default)]
+    pub conflict_log_max_value_chars: Option<usize>,
+    /// Fields whose values are redacted in the conflict log.
+    #[serde(default)]
+    pub conflict_log_redacted_fields: Vec<String>,
+}
+
 /// Application configuration.
 ///
 /// Serialized to/from TOML at `~/.config/pomodoroom/config.toml`.
@@ -95,6 +115,8 @@ pub struct Config {
     pub youtube: YouTubeConfig,
     #[serde(default)]
     pub shortcuts: ShortcutsConfig,
+    #[serde(default)]
+    pub sync: SyncConfig,
     /// Custom schedule override (progressive or custom).
     #[serde(default)]
     pub custom_schedule: Option<Schedule>,
@@ -109,6 +131,73 @@ pub struct Config {
     pub tray_enabled: bool,
     #[serde(default = "default_true")]
     pub auto_advance: bool,
+    /// Start the break as soon as a focus step ends.
+    #[serde(default)]
+    pub auto_start_breaks: bool,
+    /// Start the next focus step as soon as a break ends.
+    #[serde(default)]
+    pub auto_start_focus: bool,
+    /// Encrypt sensitive fields in `config.toml` with a key from the OS keyring.
+    #[serde(default)]
+    pub encrypt_sensitive_fields: bool,
+}
+
+/// Portable settings bundle for moving to another machine.
+///
+/// Carries no credentials: OAuth tokens live in the OS keyring, never in
+/// `Config`. Integrations are only listed as enabled flags so the user knows
+/// which services to sign in to again.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ConfigBundle {
+    /// Bundle format version (semver).
+    pub version: String,
+    /// When the bundle was exported.
+    pub exported_at: DateTime<Utc>,
+    /// Exported settings.
+    pub config: Config,
+    /// Integration name to whether it was enabled on the exporting machine.
+    #[serde(default)]
+    pub integrations: BTreeMap<String, bool>,
+}
+
+impl ConfigBundle {
+    /// Attach integration enabled flags.
+    pub fn with_integrations(mut self, integrations: BTreeMap<String, bool>) -> Self {
+        self.integrations = integrations;
+        self
+    }
+
+    /// Serialize the bundle to a JSON string.
+    ///
+    /// # Errors
+    /// Returns an error if serialization fails.
+    pub fn to_json(&self) -> Result<String, serde_json::Error> {
+        serde_json::to_string_pretty(self)
+    }
+
+    /// Deserialize a bundle from a JSON string.
+    ///
+    /// # Errors
+    /// Returns an error if the JSON is invalid.
+    pub fn from_json(json: &str) -> Result<Self, serde_json::Error> {
+        serde_json::from_str(json)
+    }
+
+    /// Serialize the bundle to a TOML string.
+    ///
+    /// # Errors
+    /// Returns an error if serialization fails.
+    pub fn to_toml(&self) -> Result<String, toml::ser::Error> {
+        toml::to_string_pretty(self)
+    }
+
+    /// Deserialize a bundle from a TOML string.
+    ///
+    /// # Errors
+    /// Returns an error if the TOML is invalid.
+    pub fn from_toml(content: &str) -> Result<Self, toml::de::Error> {
+        toml::from_str(content)
+    }
 }
 
 // Default functions
@@ -199,6 +288,145 @@ impl Default for ShortcutsConfig {
     }
 }
 
+/// Normalized key combinations reserved by the operating system.
+const RESERVED_SHORTCUTS: &[&str] = &[
+    "ctrl+alt+delete",
+    "alt+tab",
+    "alt+f4",
+    "meta+tab",
+    "meta+q",
+    "meta+l",
+];
+
+/// Modifier order used by normalized keybindings.
+const MODIFIER_ORDER: [&str; 4] = ["ctrl", "alt", "shift", "meta"];
+
+/// A problem found when validating shortcut bindings.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum ShortcutViolation {
+    /// The same keybinding is mapped to several commands.
+    Conflict {
+        /// Normalized keybinding
+        keybinding: String,
+        /// Commands bound to it, sorted
+        commands: Vec<String>,
+    },
+    /// A command is bound to a combination reserved by the OS.
+    Reserved {
+        /// Offending command
+        command: String,
+        /// Normalized keybinding
+        keybinding: String,
+    },
+}
+
+impl std::fmt::Display for ShortcutViolation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Conflict {
+                keybinding,
+                commands,
+            } => write!(f, "'{keybinding}' is bound to {}", commands.join(", ")),
+            Self::Reserved {
+                command,
+                keybinding,
+            } => write!(f, "'{keybinding}' ({command}) is reserved by the system"),
+        }
+    }
+}
+
+/// Normalize a keybinding so equivalent spellings compare equal.
+///
+/// Lowercases every part, maps modifier aliases (`Control`, `Cmd`, `Option`,
+/// ...) to `ctrl`/`meta`/`alt` and orders modifiers as ctrl, alt, shift,
+/// meta: `"Shift+Ctrl+P"` becomes `"ctrl+shift+p"`.
+pub fn normalize_keybinding(binding: &str) -> String {
+    // A trailing "++" means the key itself is '+'
+    let (rest, plus_key) = match binding.strip_suffix("++") {
+        Some(rest) => (rest, true),
+        None => (binding, false),
+    };
+
+    let mut modifiers = Vec::new();
+    let mut key = plus_key.then(|| "+".to_string());
+    for part in rest.split('+').filter(|p| !p.is_empty()) {
+        let part = part.trim().to_lowercase();
+        match part.as_str() {
+            "ctrl" | "control" => modifiers.push("ctrl"),
+            "alt" | "option" | "opt" => modifiers.push("alt"),
+            "shift" => modifiers.push("shift"),
+            "meta" | "cmd" | "command" | "super" | "win" => modifiers.push("meta"),
+            // A whitespace-only part is the space bar
+            "" | "spacebar" => key = Some("space".to_string()),
+            "esc" => key = Some("escape".to_string()),
+            _ => key = Some(part),
+        }
+    }
+
+    let mut parts: Vec<String> = MODIFIER_ORDER
+        .iter()
+        .filter(|m| modifiers.contains(m))
+        .map(|m| m.to_string())
+        .collect();
+    parts.extend(key);
+    parts.join("+")
+}
+
+impl ShortcutsConfig {
+    /// Find conflicting and reserved bindings.
+    ///
+    /// Bindings are normalized before comparison; empty bindings are treated
+    /// as unbound. Results are sorted for stable output.
+    pub fn validate(&self) -> Vec<ShortcutViolation> {
+        let mut by_binding: HashMap<String, Vec<String>> = HashMap::new();
+        let mut violations = Vec::new();
+
+        for (command, binding) in &self.bindings {
+            let normalized = normalize_keybinding(binding);
+            if normalized.is_empty() {
+                continue;
+            }
+            if RESERVED_SHORTCUTS.contains(&normalized.as_str()) {
+                violations.push(ShortcutViolation::Reserved {
+                    command: command.clone(),
+                    keybinding: normalized.clone(),
+                });
+            }
+            by_binding.entry(normalized).or_default().push(command.clone());
+        }
+
+        for (keybinding, mut commands) in by_binding {
+            if commands.len() > 1 {
+                commands.sort();
+                violations.push(ShortcutViolation::Conflict {
+                    keybinding,
+                    commands,
+                });
+            }
+        }
+
+        violations.sort();
+        violations
+    }
+
+    /// Replace all bindings, rejecting sets with conflicts or reserved combos.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ConfigError::ShortcutConflict` listing every violation; the
+    /// current bindings are left untouched.
+    pub fn set_bindings(&mut self, bindings: HashMap<String, String>) -> Result<(), ConfigError> {
+        let candidate = Self { bindings };
+        let violations = candidate.validate();
+        if !violations.is_empty() {
+            return Err(ConfigError::ShortcutConflict { violations });
+        }
+        *self = candidate;
+        Ok(())
+    }
+}
+
 impl Default for Config {
     fn default() -> Self {
         Self {
@@ -207,11 +435,278 @@ impl Default for Config {
             ui: UiConfig::default(),
             youtube: YouTubeConfig::default(),
             shortcuts: ShortcutsConfig::default(),
+            sync: SyncConfig::default(),
             custom_schedule: None,
             window_pinned: false,
             window_float: false,
             tray_enabled: false,
             auto_advance: true,
+            auto_start_breaks: false,
+            auto_start_focus: false,
+            encrypt_sensitive_fields: false,
+        }
+    }
+}
+
+/// A scalar config setting, addressed by its dotted key.
+///
+/// Structured settings (`shortcuts.bindings`, `custom_schedule`) are not
+/// keys; they have their own validated setters.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum ConfigKey {
+    FocusDuration,
+    ShortBreak,
+    LongBreak,
+    PomodorosBeforeLongBreak,
+    NotificationsEnabled,
+    NotificationVolume,
+    Vibration,
+    CustomSound,
+    DarkMode,
+    HighlightColor,
+    StickyWidgetSize,
+    YouTubeWidgetWidth,
+    NextTaskCandidatesCount,
+    YouTubeAutoplayOnFocus,
+    YouTubePauseOnBreak,
+    YouTubeDefaultVolume,
+    YouTubeLoopEnabled,
+    WindowPinned,
+    WindowFloat,
+    TrayEnabled,
+    AutoAdvance,
+    AutoStartBreaks,
+    AutoStartFocus,
+    EncryptSensitiveFields,
+}
+
+impl ConfigKey {
+    /// Every key, in config file order.
+    pub const ALL: [ConfigKey; 24] = [
+        Self::FocusDuration,
+        Self::ShortBreak,
+        Self::LongBreak,
+        Self::PomodorosBeforeLongBreak,
+        Self::NotificationsEnabled,
+        Self::NotificationVolume,
+        Self::Vibration,
+        Self::CustomSound,
+        Self::DarkMode,
+        Self::HighlightColor,
+        Self::StickyWidgetSize,
+        Self::YouTubeWidgetWidth,
+        Self::NextTaskCandidatesCount,
+        Self::YouTubeAutoplayOnFocus,
+        Self::YouTubePauseOnBreak,
+        Self::YouTubeDefaultVolume,
+        Self::YouTubeLoopEnabled,
+        Self::WindowPinned,
+        Self::WindowFloat,
+        Self::TrayEnabled,
+        Self::AutoAdvance,
+        Self::AutoStartBreaks,
+        Self::AutoStartFocus,
+        Self::EncryptSensitiveFields,
+    ];
+
+    /// Dotted key as used by [`Config::get`] and [`Config::set`].
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::FocusDuration => "schedule.focus_duration",
+            Self::ShortBreak => "schedule.short_break",
+            Self::LongBreak => "schedule.long_break",
+            Self::PomodorosBeforeLongBreak => "schedule.pomodoros_before_long_break",
+            Self::NotificationsEnabled => "notifications.enabled",
+            Self::NotificationVolume => "notifications.volume",
+            Self::Vibration => "notifications.vibration",
+            Self::CustomSound => "notifications.custom_sound",
+            Self::DarkMode => "ui.dark_mode",
+            Self::HighlightColor => "ui.highlight_color",
+            Self::StickyWidgetSize => "ui.sticky_widget_size",
+            Self::YouTubeWidgetWidth => "ui.youtube_widget_width",
+            Self::NextTaskCandidatesCount => "ui.next_task_candidates_count",
+            Self::YouTubeAutoplayOnFocus => "youtube.autoplay_on_focus",
+            Self::YouTubePauseOnBreak => "youtube.pause_on_break",
+            Self::YouTubeDefaultVolume => "youtube.default_volume",
+            Self::YouTubeLoopEnabled => "youtube.loop_enabled",
+            Self::WindowPinned => "window_pinned",
+            Self::WindowFloat => "window_float",
+            Self::TrayEnabled => "tray_enabled",
+            Self::AutoAdvance => "auto_advance",
+            Self::AutoStartBreaks => "auto_start_breaks",
+            Self::AutoStartFocus => "auto_start_focus",
+            Self::EncryptSensitiveFields => "encrypt_sensitive_fields",
+        }
+    }
+
+    /// Key for a dotted path, if it names a scalar setting.
+    pub fn from_path(key: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|k| k.as_str() == key)
+    }
+
+    /// Accepted range for numeric keys.
+    pub fn range(self) -> Option<RangeInclusive<u32>> {
+        match self {
+            Self::FocusDuration => Some(constraints::FOCUS_MIN..=constraints::FOCUS_MAX),
+            Self::ShortBreak => Some(constraints::SHORT_BREAK_MIN..=constraints::SHORT_BREAK_MAX),
+            Self::LongBreak => Some(constraints::LONG_BREAK_MIN..=constraints::LONG_BREAK_MAX),
+            Self::PomodorosBeforeLongBreak => {
+                Some(constraints::POMODOROS_MIN..=constraints::POMODOROS_MAX)
+            }
+            Self::NotificationVolume | Self::YouTubeDefaultVolume => Some(0..=100),
+            Self::StickyWidgetSize | Self::YouTubeWidgetWidth | Self::NextTaskCandidatesCount => {
+                Some(1..=u32::MAX)
+            }
+            _ => None,
+        }
+    }
+
+    /// Parse a string as a value of this key's type.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ConfigError::InvalidValue` if `raw` is not a valid bool or
+    /// number for a bool or numeric key. Ranges are checked on set.
+    pub fn parse_value(self, raw: &str) -> Result<ConfigValue, ConfigError> {
+        let invalid = |expected: &str| ConfigError::InvalidValue {
+            key: self.as_str().to_string(),
+            message: format!("expected {expected}, got '{raw}'"),
+        };
+        match self {
+            Self::CustomSound | Self::HighlightColor => Ok(ConfigValue::Text(raw.to_string())),
+            _ if self.range().is_some() => raw
+                .trim()
+                .parse()
+                .map(ConfigValue::Number)
+                .map_err(|_| invalid("a whole number")),
+            _ => raw
+                .trim()
+                .parse()
+                .map(ConfigValue::Bool)
+                .map_err(|_| invalid("true or false")),
+        }
+    }
+}
+
+impl std::fmt::Display for ConfigKey {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// A typed value for a [`ConfigKey`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum ConfigValue {
+    Bool(bool),
+    Number(u32),
+    /// Text; for `notifications.custom_sound` an empty string means unset.
+    Text(String),
+}
+
+impl std::fmt::Display for ConfigValue {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Bool(value) => write!(f, "{value}"),
+            Self::Number(value) => write!(f, "{value}"),
+            Self::Text(value) => f.write_str(value),
+        }
+    }
+}
+
+/// Mutable field behind a [`ConfigKey`].
+enum Slot<'a> {
+    Bool(&'a mut bool),
+    Number(&'a mut u32),
+    Text(&'a mut String),
+    OptionalText(&'a mut Option<String>),
+}
+
+/// Replace `path` with `contents` so readers see the old or the new file,
+/// never a truncated one.
+///
+/// The contents go to a temp file in the same directory, which is synced
+/// and then renamed over `path`. `std::fs::rename` replaces an existing
+/// file on both Unix and Windows (`MOVEFILE_REPLACE_EXISTING`).
+fn write_atomic(path: &Path, contents: &[u8]) -> std::io::Result<()> {
+    write_atomic_with(path, contents, rename_replacing)
+}
+
+fn write_atomic_with(
+    path: &Path,
+    contents: &[u8],
+    rename: impl FnOnce(&Path, &Path) -> std::io::Result<()>,
+) -> std::io::Result<()> {
+    let file_name = path.file_name().ok_or_else(|| {
+        std::io::Error::new(std::io::ErrorKind::InvalidInput, "path has no file name")
+    })?;
+    let mut temp_name = std::ffi::OsString::from(".");
+    temp_name.push(file_name);
+    temp_name.push(format!(".{}.tmp", std::process::id()));
+    let temp_path = path.with_file_name(temp_name);
+
+    let result = (|| {
+        let mut file = std::fs::File::create(&temp_path)?;
+        file.write_all(contents)?;
+        file.sync_all()?;
+        drop(file);
+        rename(&temp_path, path)
+    })();
+    if result.is_err() {
+        let _ = std::fs::remove_file(&temp_path);
+        return result;
+    }
+
+    // Persist the rename itself
+    #[cfg(unix)]
+    if let Some(dir) = path.parent().filter(|d| !d.as_os_str().is_empty()) {
+        std::fs::File::open(dir)?.sync_all()?;
+    }
+    Ok(())
+}
+
+#[cfg(not(windows))]
+fn rename_replacing(from: &Path, to: &Path) -> std::io::Result<()> {
+    std::fs::rename(from, to)
+}
+
+/// Windows refuses the replace while another process (an editor, a virus
+/// scanner) briefly holds the target open, so retry for a moment.
+#[cfg(windows)]
+fn rename_replacing(from: &Path, to: &Path) -> std::io::Result<()> {
+    let mut attempts = 0;
+    loop {
+        match std::fs::rename(from, to) {
+            Err(e) if e.kind() == std::io::ErrorKind::PermissionDenied && attempts < 5 => {
+                attempts += 1;
+                std::thread::sleep(std::time::Duration::from_millis(20));
+            }
+            result => return result,
+        }
+    }
+}
+
+/// Collect the leaf values of `value` under dotted paths.
+///
+/// Arrays are compared whole, like other leaf values.
+fn flatten_json(
+    prefix: &str,
+    value: &serde_json::Value,
+    out: &mut BTreeMap<String, serde_json::Value>,
+) {
+    match value {
+        serde_json::Value::Object(map) if !map.is_empty() => {
+            for (key, child) in map {
+                let path = if prefix.is_empty() {
+                    key.clone()
+                } else {
+                    format!("{prefix}.{key}")
+                };
+                flatten_json(&path, child, out);
+            }
+        }
+        _ => {
+            out.insert(prefix.to_string(), value.clone());
         }
     }
 }
@@ -288,19 +783,131 @@ impl Config {
         Ok(data_dir()?.join("config.toml"))
     }
 
+    fn slot(&mut self, key: ConfigKey) -> Slot<'_> {
+        match key {
+            ConfigKey::FocusDuration => Slot::Number(&mut self.schedule.focus_duration),
+            ConfigKey::ShortBreak => Slot::Number(&mut self.schedule.short_break),
+            ConfigKey::LongBreak => Slot::Number(&mut self.schedule.long_break),
+            ConfigKey::PomodorosBeforeLongBreak => {
+                Slot::Number(&mut self.schedule.pomodoros_before_long_break)
+            }
+            ConfigKey::NotificationsEnabled => Slot::Bool(&mut self.notifications.enabled),
+            ConfigKey::NotificationVolume => Slot::Number(&mut self.notifications.volume),
+            ConfigKey::Vibration => Slot::Bool(&mut self.notifications.vibration),
+            ConfigKey::CustomSound => Slot::OptionalText(&mut self.notifications.custom_sound),
+            ConfigKey::DarkMode => Slot::Bool(&mut self.ui.dark_mode),
+            ConfigKey::HighlightColor => Slot::Text(&mut self.ui.highlight_color),
+            ConfigKey::StickyWidgetSize => Slot::Number(&mut self.ui.sticky_widget_size),
+            ConfigKey::YouTubeWidgetWidth => Slot::Number(&mut self.ui.youtube_widget_width),
+            ConfigKey::NextTaskCandidatesCount => {
+                Slot::Number(&mut self.ui.next_task_candidates_count)
+            }
+            ConfigKey::YouTubeAutoplayOnFocus => Slot::Bool(&mut self.youtube.autoplay_on_focus),
+            ConfigKey::YouTubePauseOnBreak => Slot::Bool(&mut self.youtube.pause_on_break),
+            ConfigKey::YouTubeDefaultVolume => Slot::Number(&mut self.youtube.default_volume),
+            ConfigKey::YouTubeLoopEnabled => Slot::Bool(&mut self.youtube.loop_enabled),
+            ConfigKey::WindowPinned => Slot::Bool(&mut self.window_pinned),
+            ConfigKey::WindowFloat => Slot::Bool(&mut self.window_float),
+            ConfigKey::TrayEnabled => Slot::Bool(&mut self.tray_enabled),
+            ConfigKey::AutoAdvance => Slot::Bool(&mut self.auto_advance),
+            ConfigKey::AutoStartBreaks => Slot::Bool(&mut self.auto_start_breaks),
+            ConfigKey::AutoStartFocus => Slot::Bool(&mut self.auto_start_focus),
+            ConfigKey::EncryptSensitiveFields => Slot::Bool(&mut self.encrypt_sensitive_fields),
+        }
+    }
+
+    /// Timer durations and long break cadence.
+    pub fn timer(&self) -> &ScheduleConfig {
+        &self.schedule
+    }
+
+    /// Mutable timer settings; use [`Config::set_value`] for range checks.
+    pub fn timer_mut(&mut self) -> &mut ScheduleConfig {
+        &mut self.schedule
+    }
+
+    /// Current value of a setting.
+    pub fn value(&self, key: ConfigKey) -> ConfigValue {
+        use ConfigValue::{Bool, Number, Text};
+        match key {
+            ConfigKey::FocusDuration => Number(self.schedule.focus_duration),
+            ConfigKey::ShortBreak => Number(self.schedule.short_break),
+            ConfigKey::LongBreak => Number(self.schedule.long_break),
+            ConfigKey::PomodorosBeforeLongBreak => {
+                Number(self.schedule.pomodoros_before_long_break)
+            }
+            ConfigKey::NotificationsEnabled => Bool(self.notifications.enabled),
+            ConfigKey::NotificationVolume => Number(self.notifications.volume),
+            ConfigKey::Vibration => Bool(self.notifications.vibration),
+            ConfigKey::CustomSound => {
+                Text(self.notifications.custom_sound.clone().unwrap_or_default())
+            }
+            ConfigKey::DarkMode => Bool(self.ui.dark_mode),
+            ConfigKey::HighlightColor => Text(self.ui.highlight_color.clone()),
+            ConfigKey::StickyWidgetSize => Number(self.ui.sticky_widget_size),
+            ConfigKey::YouTubeWidgetWidth => Number(self.ui.youtube_widget_width),
+            ConfigKey::NextTaskCandidatesCount => Number(self.ui.next_task_candidates_count),
+            ConfigKey::YouTubeAutoplayOnFocus => Bool(self.youtube.autoplay_on_focus),
+            ConfigKey::YouTubePauseOnBreak => Bool(self.youtube.pause_on_break),
+            ConfigKey::YouTubeDefaultVolume => Number(self.youtube.default_volume),
+            ConfigKey::YouTubeLoopEnabled => Bool(self.youtube.loop_enabled),
+            ConfigKey::WindowPinned => Bool(self.window_pinned),
+            ConfigKey::WindowFloat => Bool(self.window_float),
+            ConfigKey::TrayEnabled => Bool(self.tray_enabled),
+            ConfigKey::AutoAdvance => Bool(self.auto_advance),
+            ConfigKey::AutoStartBreaks => Bool(self.auto_start_breaks),
+            ConfigKey::AutoStartFocus => Bool(self.auto_start_focus),
+            ConfigKey::EncryptSensitiveFields => Bool(self.encrypt_sensitive_fields),
+        }
+    }
+
+    /// Set a setting, checking its type and range.
+    ///
+    /// Does not save; call [`Config::save`] afterwards.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ConfigError::InvalidValue` if `value` has the wrong type for
+    /// `key` or is outside [`ConfigKey::range`]; the config is unchanged.
+    pub fn set_value(&mut self, key: ConfigKey, value: ConfigValue) -> Result<(), ConfigError> {
+        let invalid = |message: String| ConfigError::InvalidValue {
+            key: key.as_str().to_string(),
+            message,
+        };
+        match (self.slot(key), value) {
+            (Slot::Bool(field), ConfigValue::Bool(value)) => *field = value,
+            (Slot::Number(field), ConfigValue::Number(value)) => {
+                if let Some(range) = key.range().filter(|r| !r.contains(&value)) {
+                    return Err(invalid(format!(
+                        "must be between {} and {}, got {value}",
+                        range.start(),
+                        range.end()
+                    )));
+                }
+                *field = value;
+            }
+            (Slot::Text(field), ConfigValue::Text(value)) => *field = value,
+            (Slot::OptionalText(field), ConfigValue::Text(value)) => {
+                *field = (!value.is_empty()).then_some(value);
+            }
+            (_, value) => return Err(invalid(format!("wrong type of value: {value:?}"))),
+        }
+        Ok(())
+    }
+
     /// Load from disk or return default.
     ///
+    /// Sealed sensitive fields are decrypted with the keyring key.
+    ///
     /// # Errors
     ///
     /// Returns an error if the config file exists but cannot be parsed,
-    /// or if the default config cannot be written to disk.
+    /// if sealed fields cannot be decrypted, or if the default config cannot
+    /// be written to disk.
     pub fn load() -> Result<Self, Box<dyn std::error::Error>> {
         let path = Self::path()?;
         match std::fs::read_to_string(&path) {
-            Ok(content) => {
-                let cfg: Config = toml::from_str(&content)?;
-                Ok(cfg)
-            }
+            Ok(content) => Self::parse_stored(&content),
             Err(_) => {
                 let cfg = Self::default();
                 cfg.save()?;
@@ -309,19 +916,102 @@ impl Config {
         }
     }
 
+    /// Load a config file at `path` the way [`Config::load`] does, without
+    /// creating it when missing.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file cannot be read or parsed, or if sealed
+    /// fields cannot be decrypted.
+    pub fn load_from(path: &Path) -> Result<Self, Box<dyn std::error::Error>> {
+        Self::parse_stored(&std::fs::read_to_string(path)?)
+    }
+
+    /// Parse stored TOML, decrypting sealed fields with the keyring key.
+    fn parse_stored(content: &str) -> Result<Self, Box<dyn std::error::Error>> {
+        let doc: toml::Value = toml::from_str(content)?;
+        let cipher = if has_sealed_fields(&doc) {
+            Some(FieldCipher::from_keyring(false)?)
+        } else {
+            None
+        };
+        Self::from_stored_toml(content, cipher.as_ref())
+    }
+
     /// Persist to disk.
     ///
+    /// The file is replaced atomically, so a crash mid-save leaves the
+    /// previous config in place. With `encrypt_sensitive_fields` on,
+    /// sensitive fields are sealed with the keyring key, which is created
+    /// on first use.
+    ///
     /// # Errors
     ///
-    /// Returns an error if the config cannot be serialized or written to disk.
+    /// Returns an error if the config cannot be serialized or written to
+    /// disk, or if encryption is on and the keyring is unavailable.
     pub fn save(&self) -> Result<(), Box<dyn std::error::Error>> {
-        let content = toml::to_string_pretty(self)?;
-        std::fs::write(Self::path()?, content)?;
+        let cipher = if self.encrypt_sensitive_fields {
+            Some(FieldCipher::from_keyring(true)?)
+        } else {
+            None
+        };
+        let content = self.to_stored_toml(cipher.as_ref())?;
+        write_atomic(&Self::path()?, content.as_bytes())?;
         Ok(())
     }
 
+    /// Serialize as stored in `config.toml`.
+    ///
+    /// Sensitive fields are sealed with `cipher` when
+    /// `encrypt_sensitive_fields` is on, including values that were written
+    /// in plaintext before encryption was enabled.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ConfigError::KeyUnavailable` if encryption is on and no
+    /// cipher is given, rather than writing the fields in plaintext.
+    pub fn to_stored_toml(
+        &self,
+        cipher: Option<&FieldCipher>,
+    ) -> Result<String, Box<dyn std::error::Error>> {
+        let mut doc = toml::Value::try_from(self)?;
+        if self.encrypt_sensitive_fields {
+            let cipher = cipher.ok_or_else(|| {
+                ConfigError::KeyUnavailable("no key to encrypt sensitive fields".to_string())
+            })?;
+            cipher.seal_fields(&mut doc);
+        }
+        Ok(toml::to_string_pretty(&doc)?)
+    }
+
+    /// Parse the contents of `config.toml`, decrypting sealed fields.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the TOML is invalid, or if a sealed field is
+    /// present and `cipher` is missing or cannot decrypt it.
+    pub fn from_stored_toml(
+        content: &str,
+        cipher: Option<&FieldCipher>,
+    ) -> Result<Self, Box<dyn std::error::Error>> {
+        let mut doc: toml::Value = toml::from_str(content)?;
+        if has_sealed_fields(&doc) {
+            let cipher = cipher.ok_or_else(|| {
+                ConfigError::KeyUnavailable("no key to decrypt sensitive fields".to_string())
+            })?;
+            cipher.open_fields(&mut doc)?;
+        }
+        Ok(doc.try_into()?)
+    }
+
     /// Get a config value as string by dot-separated key.
+    ///
+    /// Scalar keys go through [`Config::value`]; structured values are
+    /// returned as JSON.
     pub fn get(&self, key: &str) -> Option<String> {
+        if let Some(key) = ConfigKey::from_path(key) {
+            return Some(self.value(key).to_string());
+        }
         let json = serde_json::to_value(self).ok()?;
         let val = Self::get_json_value_by_path(&json, key)?;
         match val {
@@ -330,64 +1020,163 @@ impl Config {
         }
     }
 
-    /// Set a config value by key. Returns error if key is unknown.
+    /// Set a config value by key and save. Returns error if key is unknown.
     ///
     /// # Errors
     ///
-    /// Returns an error if the key is unknown, the value cannot be parsed,
-    /// or the config cannot be saved.
+    /// Returns an error if the key is unknown, the value cannot be parsed or
+    /// is out of range, or the config cannot be saved.
     pub fn set(&mut self, key: &str, value: &str) -> Result<(), Box<dyn std::error::Error>> {
+        self.set_str(key, value)?;
+        self.save()?;
+        Ok(())
+    }
+
+    /// Set a config value from strings without saving.
+    ///
+    /// Scalar keys are parsed with [`ConfigKey::parse_value`] and set with
+    /// [`Config::set_value`]. Structured values take JSON; shortcut bindings
+    /// are checked with [`ShortcutsConfig::set_bindings`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the key is unknown or the value is invalid.
+    pub fn set_str(&mut self, key: &str, value: &str) -> Result<(), Box<dyn std::error::Error>> {
+        if let Some(key) = ConfigKey::from_path(key) {
+            let value = key.parse_value(value)?;
+            self.set_value(key, value)?;
+            return Ok(());
+        }
+        if key == "shortcuts.bindings" {
+            self.shortcuts.set_bindings(serde_json::from_str(value)?)?;
+            return Ok(());
+        }
         let mut json = serde_json::to_value(&*self)?;
         Self::set_json_value_by_path(&mut json, key, value)?;
         *self = serde_json::from_value(json)?;
-        self.save()?;
         Ok(())
     }
 
+    /// Dotted keys whose values differ between `self` and `other`, sorted.
+    ///
+    /// Keys are leaf paths as accepted by [`Config::get`]; a key present in
+    /// only one of the two counts as changed.
+    pub fn changed_keys(&self, other: &Config) -> Vec<String> {
+        let mut before = BTreeMap::new();
+        let mut after = BTreeMap::new();
+        if let (Ok(a), Ok(b)) = (serde_json::to_value(self), serde_json::to_value(other)) {
+            flatten_json("", &a, &mut before);
+            flatten_json("", &b, &mut after);
+        }
+        let mut keys: Vec<String> = before
+            .keys()
+            .chain(after.keys())
+            .filter(|key| before.get(*key) != after.get(*key))
+            .cloned()
+            .collect();
+        keys.sort();
+        keys.dedup();
+        keys
+    }
+
+    /// Whether any of `keys` changes the timer's step schedule or auto-start.
+    pub fn affects_timer(keys: &[String]) -> bool {
+        keys.iter().any(|key| {
+            key.starts_with("schedule.")
+                || key.starts_with("custom_schedule")
+                || key.starts_with("auto_start_")
+        })
+    }
+
     pub fn schedule(&self) -> Schedule {
         // Use custom_schedule if set, otherwise generate from ScheduleConfig
         if let Some(ref custom) = self.custom_schedule {
             custom.clone()
         } else {
             // Create a simple pomodoro schedule from config
-            let focus = self.schedule.focus_duration;
-            let short_break = self.schedule.short_break;
-            let long_break = self.schedule.long_break;
-            let pomodoros = self.schedule.pomodoros_before_long_break;
-
-            let mut steps = Vec::new();
-            for i in 0..pomodoros {
-                steps.push(crate::timer::Step {
-                    step_type: crate::timer::StepType::Focus,
-                    duration_min: focus as u64,
-                    label: format!("Focus {}", i + 1),
-                    description: String::new(),
-                });
-                let is_long_break = (i + 1) as u32 % pomodoros == 0;
-                steps.push(crate::timer::Step {
-                    step_type: crate::timer::StepType::Break,
-                    duration_min: if is_long_break {
-                        long_break
-                    } else {
-                        short_break
-                    } as u64,
-                    label: if is_long_break {
-                        "Long Break".to_string()
-                    } else {
-                        "Short Break".to_string()
-                    },
-                    description: String::new(),
-                });
-            }
-            Schedule::new(steps).unwrap_or_else(|_| Schedule::default_progressive())
+            Schedule::pomodoro(
+                self.schedule.focus_duration,
+                self.schedule.short_break,
+                self.schedule.long_break,
+                self.schedule.pomodoros_before_long_break,
+            )
+            .unwrap_or_else(|_| Schedule::default_progressive())
         }
     }
 
     /// Load from disk, returning default on error.
     /// This is a convenience method that never fails.
+    ///
+    /// For read-only use only: the defaults may stand in for a config that
+    /// could not be decrypted, so never `save` the result. Paths that load,
+    /// modify and save must use [`Config::load`].
     pub fn load_or_default() -> Self {
         Self::load().unwrap_or_default()
     }
+
+    /// Export settings as a portable bundle.
+    ///
+    /// The custom notification sound is a local file path and is left out.
+    pub fn export_bundle(&self) -> ConfigBundle {
+        let mut config = self.clone();
+        config.notifications.custom_sound = None;
+        ConfigBundle {
+            version: CONFIG_BUNDLE_VERSION.to_string(),
+            exported_at: Utc::now(),
+            config,
+            integrations: BTreeMap::new(),
+        }
+    }
+
+    /// Validate a bundle and replace these settings with it.
+    ///
+    /// The local custom notification sound is kept. Does not save; call
+    /// [`Config::save`] afterwards. Returns the version check result, which
+    /// is `MinorNewer` when the bundle comes from a newer compatible release.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the bundle version is incompatible, a schedule
+    /// duration is zero, or the shortcuts conflict.
+    pub fn import_bundle(&mut self, bundle: &ConfigBundle) -> Result<Compatibility, ConfigError> {
+        let compatibility = check_compatibility(CONFIG_BUNDLE_VERSION, &bundle.version);
+        if let Compatibility::Incompatible { .. } = compatibility {
+            return Err(ConfigError::InvalidValue {
+                key: "version".to_string(),
+                message: format!(
+                    "bundle version {} is not supported (current {}); update Pomodoroom to import it",
+                    bundle.version, CONFIG_BUNDLE_VERSION
+                ),
+            });
+        }
+
+        let schedule = &bundle.config.schedule;
+        for (key, value) in [
+            ("schedule.focus_duration", schedule.focus_duration),
+            ("schedule.short_break", schedule.short_break),
+            ("schedule.long_break", schedule.long_break),
+            (
+                "schedule.pomodoros_before_long_break",
+                schedule.pomodoros_before_long_break,
+            ),
+        ] {
+            if value == 0 {
+                return Err(ConfigError::InvalidValue {
+                    key: key.to_string(),
+                    message: "must be greater than zero".to_string(),
+                });
+            }
+        }
+        let violations = bundle.config.shortcuts.validate();
+        if !violations.is_empty() {
+            return Err(ConfigError::ShortcutConflict { violations });
+        }
+
+        let custom_sound = self.notifications.custom_sound.take();
+        *self = bundle.config.clone();
+        self.notifications.custom_sound = custom_sound;
+        Ok(compatibility)
+    }
 }
 
 #[cfg(test)]
@@ -411,6 +1200,20 @@ mod tests {
         assert!(cfg.get("ui.missing_key").is_none());
     }
 
+    #[test]
+    fn changed_keys_lists_modified_leaves() {
+        let before = Config::default();
+        let mut after = before.clone();
+        after.schedule.focus_duration = 50;
+        after.ui.dark_mode = !before.ui.dark_mode;
+
+        let keys = before.changed_keys(&after);
+        assert_eq!(keys, vec!["schedule.focus_duration", "ui.dark_mode"]);
+        assert!(Config::affects_timer(&keys));
+        assert!(!Config::affects_timer(&["ui.dark_mode".to_string()]));
+        assert!(before.changed_keys(&before.clone()).is_empty());
+    }
+
     #[test]
     fn set_json_value_by_path_updates_nested_bool() {
         let mut json = serde_json::to_value(Config::default()).unwrap();
@@ -467,6 +1270,92 @@ mod tests {
         assert!(cfg.get("ui.highlight_color").is_some());
     }
 
+    #[test]
+    fn typed_setter_validates_ranges() {
+        let mut cfg = Config::default();
+        cfg.set_value(ConfigKey::FocusDuration, ConfigValue::Number(50))
+            .unwrap();
+        assert_eq!(cfg.timer().focus_duration, 50);
+        assert_eq!(cfg.value(ConfigKey::FocusDuration), ConfigValue::Number(50));
+
+        for out_of_range in [0, constraints::FOCUS_MAX + 1] {
+            let err = cfg
+                .set_value(ConfigKey::FocusDuration, ConfigValue::Number(out_of_range))
+                .unwrap_err();
+            assert!(
+                matches!(err, ConfigError::InvalidValue { ref key, .. } if key == "schedule.focus_duration")
+            );
+        }
+        assert!(cfg
+            .set_value(ConfigKey::FocusDuration, ConfigValue::Bool(true))
+            .is_err());
+        assert_eq!(cfg.timer().focus_duration, 50);
+
+        cfg.set_value(ConfigKey::CustomSound, ConfigValue::Text("bell.wav".into()))
+            .unwrap();
+        assert_eq!(cfg.notifications.custom_sound.as_deref(), Some("bell.wav"));
+        cfg.set_value(ConfigKey::CustomSound, ConfigValue::Text(String::new()))
+            .unwrap();
+        assert_eq!(cfg.notifications.custom_sound, None);
+    }
+
+    #[test]
+    fn string_adapter_routes_to_typed_setter() {
+        for key in ConfigKey::ALL {
+            assert_eq!(ConfigKey::from_path(key.as_str()), Some(key));
+            assert!(Config::default().get(key.as_str()).is_some());
+        }
+
+        let mut cfg = Config::default();
+        cfg.set_str("schedule.focus_duration", "45").unwrap();
+        cfg.set_str("ui.dark_mode", "false").unwrap();
+        cfg.set_str("youtube.default_volume", "80").unwrap();
+        assert_eq!(cfg.schedule.focus_duration, 45);
+        assert!(!cfg.ui.dark_mode);
+        assert_eq!(
+            cfg.value(ConfigKey::YouTubeDefaultVolume),
+            ConfigValue::Number(80)
+        );
+        assert_eq!(cfg.get("youtube.default_volume").as_deref(), Some("80"));
+
+        // Range and type errors surface through the string API too
+        assert!(cfg.set_str("schedule.focus_duration", "0").is_err());
+        assert!(cfg.set_str("notifications.volume", "101").is_err());
+        assert!(cfg.set_str("ui.dark_mode", "maybe").is_err());
+        assert_eq!(cfg.schedule.focus_duration, 45);
+
+        // Structured keys still take JSON
+        cfg.set_str("shortcuts.bindings", r#"{"toggle": "Ctrl+Space"}"#)
+            .unwrap();
+        assert_eq!(cfg.shortcuts.bindings["toggle"], "Ctrl+Space");
+        assert!(cfg.set_str("ui.missing_key", "1").is_err());
+    }
+
+    #[test]
+    fn failed_atomic_write_keeps_original_file() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let path = dir.path().join("config.toml");
+        let original = toml::to_string_pretty(&Config::default()).unwrap();
+        std::fs::write(&path, &original).unwrap();
+
+        let mut changed = Config::default();
+        changed.schedule.focus_duration = 50;
+        let update = toml::to_string_pretty(&changed).unwrap();
+        let result = write_atomic_with(&path, update.as_bytes(), |_, _| {
+            Err(std::io::Error::other("simulated crash before rename"))
+        });
+
+        assert!(result.is_err());
+        assert_eq!(std::fs::read_to_string(&path).unwrap(), original);
+        // The temp file is cleaned up
+        assert_eq!(std::fs::read_dir(dir.path()).unwrap().count(), 1);
+
+        write_atomic(&path, update.as_bytes()).unwrap();
+        let saved: Config = toml::from_str(&std::fs::read_to_string(&path).unwrap()).unwrap();
+        assert_eq!(saved.schedule.focus_duration, 50);
+        assert_eq!(std::fs::read_dir(dir.path()).unwrap().count(), 1);
+    }
+
     #[test]
     fn config_default_values() {
         let cfg = Config::default();
@@ -495,4 +1384,145 @@ mod tests {
         assert_eq!(parsed.notifications.enabled, cfg.notifications.enabled);
         assert_eq!(parsed.schedule.focus_duration, cfg.schedule.focus_duration);
     }
+
+    #[test]
+    fn keybinding_normalization() {
+        assert_eq!(normalize_keybinding("Ctrl+Shift+P"), "ctrl+shift+p");
+        assert_eq!(normalize_keybinding("shift+ctrl+p"), "ctrl+shift+p");
+        assert_eq!(normalize_keybinding("Cmd + Option + K"), "alt+meta+k");
+        assert_eq!(normalize_keybinding("Control+Esc"), "ctrl+escape");
+        assert_eq!(normalize_keybinding(" "), "space");
+        assert_eq!(normalize_keybinding("Ctrl++"), "ctrl++");
+        assert_eq!(normalize_keybinding(""), "");
+    }
+
+    #[test]
+    fn shortcut_conflicts_are_detected() {
+        let mut shortcuts = ShortcutsConfig::default();
+        let bindings: HashMap<String, String> = [
+            ("commandPalette", "Ctrl+Shift+P"),
+            ("openNotes", "shift+ctrl+p"),
+            ("quit", "Alt+F4"),
+            ("reset", "r"),
+            ("unbound", ""),
+        ]
+        .into_iter()
+        .map(|(c, k)| (c.to_string(), k.to_string()))
+        .collect();
+
+        let violations = ShortcutsConfig {
+            bindings: bindings.clone(),
+        }
+        .validate();
+        assert_eq!(
+            violations,
+            vec![
+                ShortcutViolation::Conflict {
+                    keybinding: "ctrl+shift+p".to_string(),
+                    commands: vec!["commandPalette".to_string(), "openNotes".to_string()],
+                },
+                ShortcutViolation::Reserved {
+                    command: "quit".to_string(),
+                    keybinding: "alt+f4".to_string(),
+                },
+            ]
+        );
+
+        let err = shortcuts.set_bindings(bindings).unwrap_err();
+        assert!(matches!(err, ConfigError::ShortcutConflict { ref violations } if violations.len() == 2));
+        assert!(shortcuts.bindings.is_empty());
+
+        let valid = HashMap::from([("reset".to_string(), "r".to_string())]);
+        shortcuts.set_bindings(valid).unwrap();
+        assert_eq!(shortcuts.bindings.len(), 1);
+    }
+
+    fn collect_keys(value: &serde_json::Value, keys: &mut Vec<String>) {
+        match value {
+            serde_json::Value::Object(map) => {
+                for (key, child) in map {
+                    keys.push(key.to_lowercase());
+                    collect_keys(child, keys);
+                }
+            }
+            serde_json::Value::Array(items) => {
+                items.iter().for_each(|item| collect_keys(item, keys));
+            }
+            _ => {}
+        }
+    }
+
+    #[test]
+    fn config_bundle_round_trips_without_secrets() {
+        let mut config = Config::default();
+        config.schedule.focus_duration = 50;
+        config.ui.dark_mode = false;
+        config.youtube.default_volume = 20;
+        config.notifications.custom_sound = Some("/home/me/bell.wav".to_string());
+        config
+            .shortcuts
+            .bindings
+            .insert("reset".to_string(), "r".to_string());
+
+        let bundle = config
+            .export_bundle()
+            .with_integrations(BTreeMap::from([("google".to_string(), true)]));
+        let json = bundle.to_json().unwrap();
+
+        let mut keys = Vec::new();
+        collect_keys(&serde_json::from_str(&json).unwrap(), &mut keys);
+        for marker in ["token", "secret", "password", "api_key", "credential"] {
+            assert!(
+                keys.iter().all(|key| !key.contains(marker)),
+                "bundle contains a {marker} key"
+            );
+        }
+        assert!(!json.contains("bell.wav"));
+
+        let mut imported = Config::default();
+        imported.notifications.custom_sound = Some("/local/chime.wav".to_string());
+        let compatibility = imported
+            .import_bundle(&ConfigBundle::from_json(&json).unwrap())
+            .unwrap();
+        assert_eq!(compatibility, Compatibility::Compatible);
+        assert_eq!(imported.schedule.focus_duration, 50);
+        assert!(!imported.ui.dark_mode);
+        assert_eq!(imported.youtube.default_volume, 20);
+        assert_eq!(imported.shortcuts.bindings["reset"], "r");
+        assert_eq!(
+            imported.notifications.custom_sound.as_deref(),
+            Some("/local/chime.wav")
+        );
+
+        let toml = bundle.to_toml().unwrap();
+        let from_toml = ConfigBundle::from_toml(&toml).unwrap();
+        assert!(from_toml.integrations["google"]);
+        assert_eq!(from_toml.config.schedule.focus_duration, 50);
+    }
+
+    #[test]
+    fn config_bundle_import_rejects_incompatible_or_invalid() {
+        let mut config = Config::default();
+
+        let mut bundle = Config::default().export_bundle();
+        bundle.version = "2.0.0".to_string();
+        let err = config.import_bundle(&bundle).unwrap_err();
+        assert!(matches!(err, ConfigError::InvalidValue { ref key, .. } if key == "version"));
+
+        let mut bundle = Config::default().export_bundle();
+        bundle.version = "1.3.0".to_string();
+        assert!(matches!(
+            config.import_bundle(&bundle),
+            Ok(Compatibility::MinorNewer { .. })
+        ));
+
+        let mut bundle = Config::default().export_bundle();
+        bundle.config.schedule.short_break = 0;
+        bundle.config.ui.dark_mode = false;
+        let err = config.import_bundle(&bundle).unwrap_err();
+        assert!(
+            matches!(err, ConfigError::InvalidValue { ref key, .. } if key == "schedule.short_break")
+        );
+        assert!(config.ui.dark_mode);
+    }
 }
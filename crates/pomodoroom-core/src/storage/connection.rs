@@ -0,0 +1,66 @@
+//! SQLite connection settings shared by `Database` and `ScheduleDb`.
+//!
+//! Both stores open the same `pomodoroom.db`, often from the desktop app and
+//! the CLI at the same time. WAL journaling lets readers proceed while a
+//! writer holds the lock, and a busy timeout makes writers wait instead of
+//! failing with "database is locked".
+
+use std::path::Path;
+use std::time::Duration;
+
+use rusqlite::{Connection, Result as SqliteResult};
+
+/// Default time to wait for a competing lock before giving up.
+pub const DEFAULT_BUSY_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// Pragmas applied when opening a database connection.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ConnectionOptions {
+    /// Use write-ahead logging (`journal_mode=WAL`). Ignored for in-memory databases.
+    pub wal: bool,
+    /// How long to retry when the database is locked by another connection
+    pub busy_timeout: Duration,
+    /// Enforce foreign key constraints (`foreign_keys=ON`)
+    pub foreign_keys: bool,
+}
+
+impl Default for ConnectionOptions {
+    fn default() -> Self {
+        Self {
+            wal: true,
+            busy_timeout: DEFAULT_BUSY_TIMEOUT,
+            foreign_keys: true,
+        }
+    }
+}
+
+impl ConnectionOptions {
+    /// Open the database file at `path` and apply these options.
+    ///
+    /// The WAL and shared-memory files are created next to `path`.
+    pub fn open(&self, path: &Path) -> SqliteResult<Connection> {
+        let conn = Connection::open(path)?;
+        self.apply(&conn)?;
+        Ok(conn)
+    }
+
+    /// Open an in-memory database and apply these options.
+    pub fn open_in_memory(&self) -> SqliteResult<Connection> {
+        let conn = Connection::open_in_memory()?;
+        self.apply(&conn)?;
+        Ok(conn)
+    }
+
+    /// Apply these options to an open connection.
+    pub fn apply(&self, conn: &Connection) -> SqliteResult<()> {
+        conn.busy_timeout(self.busy_timeout)?;
+        conn.pragma_update(None, "foreign_keys", self.foreign_keys)?;
+        if self.wal {
+            // In-memory databases report "memory" and keep their journal mode
+            conn.pragma_update_and_check(None, "journal_mode", "WAL", |row| {
+                row.get::<_, String>(0)
+            })?;
+        }
+        Ok(())
+    }
+}
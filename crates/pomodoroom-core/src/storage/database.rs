@@ -5,13 +5,17 @@
 //! - Session statistics (daily and all-time)
 //! - Key-value store for application state
 
-use chrono::{DateTime, Utc};
+use chrono::{DateTime, Datelike, Duration, NaiveDate, Utc};
 use rusqlite::{params, Connection};
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
 
+use crate::calendar::{AggregatedView, AggregatedViewCache, AggregatedViewCacheStats};
 use crate::timer::StepType;
 
-use super::data_dir;
+use super::{data_dir, ConnectionOptions};
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct SessionRecord {
@@ -35,6 +39,70 @@ pub struct Stats {
     pub today_focus_min: u64,
 }
 
+/// Number of weeks in the trailing moving average of [`WeeklyFocusTrend`].
+pub const FOCUS_TREND_WINDOW: usize = 4;
+
+/// Weekly focus totals with a smoothed moving average.
+///
+/// Weeks are ISO weeks (Monday start, UTC). Weeks without sessions are
+/// reported as zero so the series is continuous.
+#[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq)]
+pub struct WeeklyFocusTrend {
+    /// Monday of each week, oldest first.
+    pub week_starts: Vec<NaiveDate>,
+    /// Total focus minutes per week.
+    pub raw: Vec<u64>,
+    /// Trailing moving average of `raw` over `window` weeks.
+    pub smoothed: Vec<f64>,
+    /// Moving average window in weeks.
+    pub window: usize,
+}
+
+impl WeeklyFocusTrend {
+    /// Bucket focus sessions into the `weeks` ISO weeks ending with the week of `now`.
+    ///
+    /// Break sessions and skipped (zero-duration) sessions are ignored.
+    pub fn from_records(records: &[SessionRecord], weeks: usize, now: DateTime<Utc>) -> Self {
+        let current = week_start(now.date_naive());
+        let first = current - Duration::weeks(weeks.saturating_sub(1) as i64);
+        let week_starts: Vec<NaiveDate> = (0..weeks)
+            .map(|i| first + Duration::weeks(i as i64))
+            .collect();
+
+        let mut raw = vec![0u64; weeks];
+        for record in records {
+            if record.step_type != "focus" || record.duration_min == 0 {
+                continue;
+            }
+            let week = week_start(record.completed_at.date_naive());
+            if week < first || week > current {
+                continue;
+            }
+            raw[(week - first).num_weeks() as usize] += record.duration_min;
+        }
+
+        let window = FOCUS_TREND_WINDOW;
+        let smoothed = (0..raw.len())
+            .map(|i| {
+                let slice = &raw[(i + 1).saturating_sub(window)..=i];
+                slice.iter().sum::<u64>() as f64 / slice.len() as f64
+            })
+            .collect();
+
+        Self {
+            week_starts,
+            raw,
+            smoothed,
+            window,
+        }
+    }
+}
+
+/// Monday of the ISO week containing `date`.
+fn week_start(date: NaiveDate) -> NaiveDate {
+    date - Duration::days(date.weekday().num_days_from_monday() as i64)
+}
+
 /// Row type for session queries.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct SessionRow {
@@ -99,14 +167,47 @@ pub struct TaskOperationLogRow {
     pub context_json: String,
 }
 
+/// kv key holding the note left by the last database recovery.
+pub const DB_RECOVERY_KEY: &str = "diagnostics.db_recovery";
+
+/// Record of a database that failed to open and was replaced.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DbRecovery {
+    /// Where the unreadable file was moved.
+    pub backup_path: PathBuf,
+    /// Why the original file could not be opened.
+    pub error: String,
+    /// When the fresh database was created.
+    pub recovered_at: DateTime<Utc>,
+}
+
 /// SQLite database for session storage.
 ///
 /// Stores completed Pomodoro sessions and provides statistics.
 pub struct Database {
     conn: Connection,
+    /// Aggregated shard view, invalidated by every shard write
+    shard_view_cache: Mutex<ShardViewCache>,
+}
+
+/// Cached aggregated view and the `PRAGMA data_version` it was read at.
+///
+/// The version changes when another connection commits, so writes made
+/// outside this `Database` also invalidate the view.
+#[derive(Default)]
+struct ShardViewCache {
+    view: AggregatedViewCache,
+    data_version: Option<i64>,
 }
 
 impl Database {
+    fn from_conn(conn: Connection) -> Self {
+        Self {
+            conn,
+            shard_view_cache: Mutex::new(ShardViewCache::default()),
+        }
+    }
+
     /// Get a reference to the underlying SQLite connection.
     pub fn conn(&self) -> &Connection {
         &self.conn
@@ -120,16 +221,92 @@ impl Database {
     /// Returns an error if the database cannot be opened or migrated.
     pub fn open() -> Result<Self, Box<dyn std::error::Error>> {
         let path = data_dir()?.join("pomodoroom.db");
-        let conn = Connection::open(path)?;
-        let db = Self { conn };
+        Self::open_with_options(&path, &ConnectionOptions::default())
+    }
+
+    /// Open the default database, replacing it if it cannot be opened.
+    ///
+    /// See `open_or_recover_at`.
+    ///
+    /// # Errors
+    /// Returns an error if the data directory is unavailable or even a fresh
+    /// database cannot be created.
+    pub fn open_or_recover() -> Result<(Self, Option<DbRecovery>), Box<dyn std::error::Error>> {
+        let path = data_dir()?.join("pomodoroom.db");
+        Self::open_or_recover_at(&path, &ConnectionOptions::default())
+    }
+
+    /// Open the database at `path`, replacing it if it is corrupted.
+    ///
+    /// A file SQLite reports as corrupt or not a database is moved aside to a
+    /// timestamped backup, together with its WAL sidecars, and a fresh
+    /// database is created in its place. The recovery is returned and noted
+    /// under `DB_RECOVERY_KEY`. Any other failure (locked by another process,
+    /// permissions, migration) is returned and the file is left untouched.
+    ///
+    /// # Errors
+    /// Returns an error if the file cannot be opened for a reason other than
+    /// corruption, the bad file cannot be moved aside, or the fresh database
+    /// cannot be created.
+    pub fn open_or_recover_at(
+        path: &Path,
+        options: &ConnectionOptions,
+    ) -> Result<(Self, Option<DbRecovery>), Box<dyn std::error::Error>> {
+        let error = match Self::open_with_options(path, options) {
+            Ok(db) => return Ok((db, None)),
+            Err(e) if is_corruption(e.as_ref()) => e.to_string(),
+            Err(e) => return Err(e),
+        };
+
+        let recovered_at = Utc::now();
+        let backup_path = backup_path_for(path, recovered_at);
+        if path.exists() {
+            std::fs::rename(path, &backup_path)?;
+        }
+        for suffix in ["-wal", "-shm"] {
+            let sidecar = sidecar_path(path, suffix);
+            if sidecar.exists() {
+                std::fs::rename(&sidecar, sidecar_path(&backup_path, suffix))?;
+            }
+        }
+
+        let db = Self::open_with_options(path, options)?;
+        let recovery = DbRecovery {
+            backup_path,
+            error,
+            recovered_at,
+        };
+        db.kv_set(DB_RECOVERY_KEY, &serde_json::to_string(&recovery)?)?;
+        Ok((db, Some(recovery)))
+    }
+
+    /// The note left by the last database recovery, if any.
+    pub fn last_recovery(&self) -> Result<Option<DbRecovery>, rusqlite::Error> {
+        Ok(self
+            .kv_get(DB_RECOVERY_KEY)?
+            .and_then(|json| serde_json::from_str(&json).ok()))
+    }
+
+    /// Open the database at `path` with explicit connection options.
+    ///
+    /// Creates the database file and schema if they don't exist.
+    ///
+    /// # Errors
+    /// Returns an error if the database cannot be opened, configured or migrated.
+    pub fn open_with_options(
+        path: &Path,
+        options: &ConnectionOptions,
+    ) -> Result<Self, Box<dyn std::error::Error>> {
+        let conn = options.open(path)?;
+        let db = Self::from_conn(conn);
         db.migrate()?;
         Ok(db)
     }
 
     /// Open an in-memory database (primarily for tests and ephemeral usage).
     pub fn open_memory() -> Result<Self, Box<dyn std::error::Error>> {
-        let conn = Connection::open_in_memory()?;
-        let db = Self { conn };
+        let conn = ConnectionOptions::default().open_in_memory()?;
+        let db = Self::from_conn(conn);
         db.migrate()?;
         Ok(db)
     }
@@ -656,6 +833,7 @@ impl Database {
                  VALUES (?1, ?2, 0, ?3)",
                 params![shard_key, shard_type, now],
             )?;
+            self.invalidate_aggregated_view();
         }
         Ok(())
     }
@@ -666,6 +844,17 @@ impl Database {
             "UPDATE calendar_shards SET event_count = event_count + 1 WHERE shard_key = ?1",
             params![shard_key],
         )?;
+        self.invalidate_aggregated_view();
+        Ok(())
+    }
+
+    /// Decrement event count for a shard after an event is removed
+    pub fn decrement_shard_event_count(&self, shard_key: &str) -> Result<(), rusqlite::Error> {
+        self.conn.execute(
+            "UPDATE calendar_shards SET event_count = MAX(event_count - 1, 0) WHERE shard_key = ?1",
+            params![shard_key],
+        )?;
+        self.invalidate_aggregated_view();
         Ok(())
     }
 
@@ -720,9 +909,43 @@ impl Database {
              VALUES (?1, (SELECT shard_type FROM calendar_shards WHERE shard_key = ?2), 0, ?3)",
             params![new_shard_key, shard_key, now],
         )?;
+        self.invalidate_aggregated_view();
         Ok(())
     }
 
+    /// Aggregate all shards, bypassing the cache.
+    pub fn aggregated_view(&self) -> Result<AggregatedView, rusqlite::Error> {
+        Ok(AggregatedView::from_shards(&self.get_all_shards()?))
+    }
+
+    /// Aggregated view of all shards, rebuilt only after a shard write.
+    ///
+    /// Writes from other connections are detected through
+    /// `PRAGMA data_version` and drop the cached view as well.
+    pub fn aggregated_view_cached(&self) -> Result<AggregatedView, rusqlite::Error> {
+        let data_version: i64 = self
+            .conn
+            .query_row("PRAGMA data_version", [], |row| row.get(0))?;
+        let mut cache = self.shard_view_cache.lock().unwrap();
+        if cache.data_version != Some(data_version) {
+            cache.view.invalidate();
+            cache.data_version = Some(data_version);
+        }
+        cache.view.get_or_build(|| self.aggregated_view())
+    }
+
+    /// Drop the cached aggregated view.
+    ///
+    /// Shard writes call this automatically.
+    pub fn invalidate_aggregated_view(&self) {
+        self.shard_view_cache.lock().unwrap().view.invalidate();
+    }
+
+    /// Hit/miss counters of the aggregated view cache.
+    pub fn aggregated_view_cache_stats(&self) -> AggregatedViewCacheStats {
+        self.shard_view_cache.lock().unwrap().view.stats()
+    }
+
     // Break Adherence functions for analytics dashboard
 
     /// Get break adherence data from sessions within a date range.
@@ -775,29 +998,7 @@ impl Database {
              ORDER BY started_at ASC"
         )?;
 
-        let rows = stmt.query_map([], |row| {
-            let started_at_str: String = row.get(4)?;
-            let completed_at_str: String = row.get(5)?;
-
-            let started_at = chrono::DateTime::parse_from_rfc3339(&started_at_str)
-                .map(|dt| dt.with_timezone(&chrono::Utc))
-                .unwrap_or_else(|_| chrono::Utc::now());
-
-            let completed_at = chrono::DateTime::parse_from_rfc3339(&completed_at_str)
-                .map(|dt| dt.with_timezone(&chrono::Utc))
-                .unwrap_or_else(|_| chrono::Utc::now());
-
-            Ok(SessionRecord {
-                id: row.get(0)?,
-                step_type: row.get(1)?,
-                step_label: row.get(2)?,
-                duration_min: row.get::<_, i64>(3)? as u64,
-                started_at,
-                completed_at,
-                task_id: row.get(6)?,
-                project_id: row.get(7)?,
-            })
-        })?;
+        let rows = stmt.query_map([], session_record_from_row)?;
 
         let mut sessions = Vec::new();
         for row in rows {
@@ -806,6 +1007,32 @@ impl Database {
         Ok(sessions)
     }
 
+    /// Weekly focus minutes for the last `weeks` ISO weeks, including the current one.
+    ///
+    /// Returns raw totals and a trailing moving average; weeks with no focus
+    /// sessions are explicit zeros.
+    pub fn weekly_focus_trend(&self, weeks: usize) -> Result<WeeklyFocusTrend, rusqlite::Error> {
+        let now = Utc::now();
+        let first = week_start(now.date_naive()) - Duration::weeks(weeks.saturating_sub(1) as i64);
+        let mut stmt = self.conn.prepare(
+            "SELECT id, step_type, step_label, duration_min, started_at, completed_at, task_id, project_id
+             FROM sessions
+             WHERE step_type = 'focus' AND duration_min > 0 AND completed_at >= ?1
+             ORDER BY completed_at ASC",
+        )?;
+
+        let rows = stmt.query_map(
+            params![format!("{first}T00:00:00+00:00")],
+            session_record_from_row,
+        )?;
+
+        let mut records = Vec::new();
+        for row in rows {
+            records.push(row?);
+        }
+        Ok(WeeklyFocusTrend::from_records(&records, weeks, now))
+    }
+
     /// Get energy curve data aggregated by hour and day of week.
     ///
     /// Returns aggregated session data for computing energy curves.
@@ -928,8 +1155,9 @@ impl Database {
 
     /// Get interruption events for heatmap analysis.
     ///
-    /// Returns operation log entries that match interruption patterns.
-    /// For now, returns a simplified representation as we build out the interruption tracking.
+    /// Returns operation log entries that match interruption patterns, with
+    /// `duration_minutes` taken from the pause each one caused (see
+    /// [`Database::pause_intervals`]).
     pub fn get_interruption_events(
         &self,
         start_date: Option<&str>,
@@ -981,9 +1209,51 @@ impl Database {
             }
         }
 
+        let pauses = self.pause_intervals()?;
+        for event in &mut results {
+            event.attach_pause(&pauses);
+        }
+
         Ok(results)
     }
 
+    /// Pause spans from the task operation log.
+    ///
+    /// Each `pause` is paired with the same task's next `resume`; pauses that
+    /// were never resumed are left out.
+    pub fn pause_intervals(&self) -> Result<Vec<crate::stats::PauseInterval>, rusqlite::Error> {
+        let mut stmt = self.conn.prepare(
+            "SELECT task_id, operation, timestamp
+             FROM task_operation_log
+             WHERE operation IN ('pause', 'resume')
+             ORDER BY timestamp ASC",
+        )?;
+        let rows = stmt.query_map([], |row| {
+            Ok((
+                row.get::<_, String>(0)?,
+                row.get::<_, String>(1)?,
+                row.get::<_, String>(2)?,
+            ))
+        })?;
+
+        let mut open: HashMap<String, DateTime<Utc>> = HashMap::new();
+        let mut intervals = Vec::new();
+        for row in rows {
+            let (task_id, operation, timestamp) = row?;
+            let Ok(at) = DateTime::parse_from_rfc3339(&timestamp) else {
+                continue;
+            };
+            let at = at.with_timezone(&Utc);
+            if operation == "pause" {
+                open.entry(task_id).or_insert(at);
+            } else if let Some(paused_at) = open.remove(&task_id) {
+                intervals.push((paused_at, at));
+            }
+        }
+        intervals.sort();
+        Ok(intervals)
+    }
+
     // Task Operation Log functions for Context System (Issue #464)
 
     /// Save a task operation log entry.
@@ -1076,6 +1346,60 @@ pub struct ShardInfo {
     pub rotated_at: Option<String>,
 }
 
+/// Map a `sessions` row selected in `SessionRecord` column order.
+fn session_record_from_row(row: &rusqlite::Row) -> Result<SessionRecord, rusqlite::Error> {
+    let started_at_str: String = row.get(4)?;
+    let completed_at_str: String = row.get(5)?;
+
+    let started_at = chrono::DateTime::parse_from_rfc3339(&started_at_str)
+        .map(|dt| dt.with_timezone(&chrono::Utc))
+        .unwrap_or_else(|_| chrono::Utc::now());
+
+    let completed_at = chrono::DateTime::parse_from_rfc3339(&completed_at_str)
+        .map(|dt| dt.with_timezone(&chrono::Utc))
+        .unwrap_or_else(|_| chrono::Utc::now());
+
+    Ok(SessionRecord {
+        id: row.get(0)?,
+        step_type: row.get(1)?,
+        step_label: row.get(2)?,
+        duration_min: row.get::<_, i64>(3)? as u64,
+        started_at,
+        completed_at,
+        task_id: row.get(6)?,
+        project_id: row.get(7)?,
+    })
+}
+
+/// Timestamped backup path next to `path` that does not exist yet.
+fn backup_path_for(path: &Path, at: DateTime<Utc>) -> PathBuf {
+    let stamp = at.format("%Y%m%dT%H%M%SZ");
+    let mut candidate = sidecar_path(path, &format!(".corrupt-{stamp}"));
+    let mut n = 1;
+    while candidate.exists() {
+        candidate = sidecar_path(path, &format!(".corrupt-{stamp}-{n}"));
+        n += 1;
+    }
+    candidate
+}
+
+/// Whether `error` means the file itself is damaged, as opposed to being
+/// busy, locked or inaccessible.
+fn is_corruption(error: &(dyn std::error::Error + 'static)) -> bool {
+    matches!(
+        error
+            .downcast_ref::<rusqlite::Error>()
+            .and_then(rusqlite::Error::sqlite_error_code),
+        Some(rusqlite::ErrorCode::DatabaseCorrupt | rusqlite::ErrorCode::NotADatabase)
+    )
+}
+
+fn sidecar_path(path: &Path, suffix: &str) -> PathBuf {
+    let mut name = path.as_os_str().to_owned();
+    name.push(suffix);
+    PathBuf::from(name)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1125,7 +1449,7 @@ mod tests {
         )
         .unwrap();
 
-        let db = Database { conn };
+        let db = Database::from_conn(conn);
         db.migrate().unwrap();
 
         // Columns added by migration should be available for indexed queries.
@@ -1215,6 +1539,68 @@ mod tests {
         assert_eq!(stats.total_sessions, 5); // 3 + 2
     }
 
+    #[test]
+    fn weekly_focus_trend_fills_empty_weeks_and_smooths() {
+        use chrono::TimeZone;
+
+        // Wednesday of ISO week 2024-W03
+        let now = Utc.with_ymd_and_hms(2024, 1, 17, 12, 0, 0).unwrap();
+        let record = |step_type: &str, minutes: u64, completed_at: DateTime<Utc>| SessionRecord {
+            id: 0,
+            step_type: step_type.to_string(),
+            step_label: String::new(),
+            duration_min: minutes,
+            started_at: completed_at,
+            completed_at,
+            task_id: None,
+            project_id: None,
+        };
+        let jan = |day, hour| Utc.with_ymd_and_hms(2024, 1, day, hour, 0, 0).unwrap();
+        let records = vec![
+            // 2024-W01 (Monday Jan 1)
+            record("focus", 25, jan(1, 9)),
+            record("focus", 25, jan(7, 23)),
+            // Breaks and skipped sessions are ignored
+            record("break", 5, jan(2, 9)),
+            record("focus", 0, jan(3, 9)),
+            // 2024-W03
+            record("focus", 30, jan(15, 9)),
+            // Outside the window
+            record("focus", 99, jan(1, 9) - chrono::Duration::days(1)),
+        ];
+
+        let trend = WeeklyFocusTrend::from_records(&records, 3, now);
+
+        assert_eq!(
+            trend.week_starts,
+            vec![
+                NaiveDate::from_ymd_opt(2024, 1, 1).unwrap(),
+                NaiveDate::from_ymd_opt(2024, 1, 8).unwrap(),
+                NaiveDate::from_ymd_opt(2024, 1, 15).unwrap(),
+            ]
+        );
+        assert_eq!(trend.raw, vec![50, 0, 30]);
+        assert_eq!(trend.smoothed, vec![50.0, 25.0, 80.0 / 3.0]);
+        assert_eq!(trend.window, FOCUS_TREND_WINDOW);
+    }
+
+    #[test]
+    fn weekly_focus_trend_reads_recorded_sessions() {
+        let db = Database::open_memory().unwrap();
+        let now = Utc::now();
+        db.record_session(StepType::Focus, "Work", 25, now, now, None, None)
+            .unwrap();
+        db.record_session(StepType::Focus, "Skipped", 0, now, now, None, None)
+            .unwrap();
+        db.record_session(StepType::Break, "Break", 5, now, now, None, None)
+            .unwrap();
+
+        let trend = db.weekly_focus_trend(8).unwrap();
+        assert_eq!(trend.raw.len(), 8);
+        assert_eq!(trend.raw[7], 25);
+        assert!(trend.raw[..7].iter().all(|&m| m == 0));
+    }
+
     /// Checkpoint tests
     #[test]
     fn create_and_retrieve_checkpoint() {
@@ -1317,6 +1703,28 @@ mod tests {
         assert_eq!(ops[0].lamport_ts, 1);
     }
 
+    #[test]
+    fn interruption_duration_comes_from_task_pause() {
+        let db = Database::open_memory().unwrap();
+        let now = Utc::now();
+        let paused_at = (now - Duration::minutes(1)).to_rfc3339();
+        let resumed_at = (now + Duration::minutes(9)).to_rfc3339();
+        db.save_operation_log("p1", "t1", "pause", &paused_at, 10, "{}")
+            .unwrap();
+        db.save_operation_log("r1", "t1", "resume", &resumed_at, 10, "{}")
+            .unwrap();
+        // A pause that was never resumed is ignored
+        db.save_operation_log("p2", "t2", "pause", &paused_at, 0, "{}")
+            .unwrap();
+        db.append_operation("op-1", "interruption:phone", "{}", 1, "device-1", None)
+            .unwrap();
+
+        assert_eq!(db.pause_intervals().unwrap().len(), 1);
+        let events = db.get_interruption_events(None, None).unwrap();
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0].duration_minutes, 10);
+    }
+
     #[test]
     fn get_max_lamport_ts() {
         let db = Database::open_memory().unwrap();
@@ -1404,6 +1812,56 @@ mod tests {
         assert_eq!(count, 1);
     }
 
+    #[test]
+    fn shard_write_invalidates_cached_aggregated_view() {
+        let db = Database::open_memory().unwrap();
+        db.get_or_create_shard("global", "global").unwrap();
+        db.increment_shard_event_count("global").unwrap();
+
+        let first = db.aggregated_view_cached().unwrap();
+        assert_eq!(first.total_events, 1);
+        assert!(first.last_built_at.is_some());
+        let again = db.aggregated_view_cached().unwrap();
+        assert_eq!(again.last_built_at, first.last_built_at);
+
+        db.increment_shard_event_count("global").unwrap();
+        assert_eq!(db.aggregated_view_cached().unwrap().total_events, 2);
+
+        db.get_or_create_shard("project:p1", "project").unwrap();
+        let view = db.aggregated_view_cached().unwrap();
+        assert_eq!(view.shards.len(), 2);
+
+        db.decrement_shard_event_count("global").unwrap();
+        assert_eq!(db.aggregated_view_cached().unwrap().total_events, 1);
+
+        let stats = db.aggregated_view_cache_stats();
+        assert_eq!(stats.hits, 1);
+        assert_eq!(stats.misses, 4);
+        assert_eq!(stats.invalidations, 3);
+        assert!((stats.hit_rate() - 0.2).abs() < 1e-9);
+    }
+
+    #[test]
+    fn shard_write_from_another_connection_invalidates_cached_view() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let path = dir.path().join("pomodoroom.db");
+        let options = ConnectionOptions::default();
+        let reader = Database::open_with_options(&path, &options).unwrap();
+        let writer = Database::open_with_options(&path, &options).unwrap();
+        writer.get_or_create_shard("global", "global").unwrap();
+        writer.increment_shard_event_count("global").unwrap();
+
+        assert_eq!(reader.aggregated_view_cached().unwrap().total_events, 1);
+        assert_eq!(reader.aggregated_view_cached().unwrap().total_events, 1);
+
+        writer.increment_shard_event_count("global").unwrap();
+        assert_eq!(reader.aggregated_view_cached().unwrap().total_events, 2);
+
+        let stats = reader.aggregated_view_cache_stats();
+        assert_eq!(stats.hits, 1);
+        assert_eq!(stats.misses, 2);
+    }
+
     #[test]
     fn get_all_shards() {
         let db = Database::open_memory().unwrap();
@@ -1557,4 +2015,123 @@ mod tests {
         // 2026-02-16 is a Monday, so day_of_week should be 1
         assert_eq!(data[0].day_of_week, 1);
     }
+
+    #[test]
+    fn test_wal_allows_concurrent_reads_during_writes() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let path = dir.path().join("pomodoroom.db");
+        let options = ConnectionOptions::default();
+
+        let writer = Database::open_with_options(&path, &options).unwrap();
+        let mode: String = writer
+            .conn()
+            .query_row("PRAGMA journal_mode", [], |row| row.get(0))
+            .unwrap();
+        assert_eq!(mode, "wal");
+        let foreign_keys: bool = writer
+            .conn()
+            .query_row("PRAGMA foreign_keys", [], |row| row.get(0))
+            .unwrap();
+        assert!(foreign_keys);
+
+        let readers: Vec<_> = (0..2)
+            .map(|_| {
+                let path = path.clone();
+                std::thread::spawn(move || {
+                    let reader = Database::open_with_options(&path, &options).unwrap();
+                    for _ in 0..50 {
+                        reader.get_all_session_records().unwrap();
+                        reader.kv_get("last_writer").unwrap();
+                    }
+                })
+            })
+            .collect();
+
+        let start = Utc::now();
+        for i in 0..50 {
+            writer
+                .record_session(
+                    StepType::Focus,
+                    "Work",
+                    25,
+                    start,
+                    start + Duration::minutes(25),
+                    None,
+                    None,
+                )
+                .unwrap();
+            writer.kv_set("last_writer", &i.to_string()).unwrap();
+        }
+
+        for reader in readers {
+            reader.join().unwrap();
+        }
+        assert_eq!(writer.get_all_session_records().unwrap().len(), 50);
+        // WAL files sit next to the database
+        assert!(dir.path().join("pomodoroom.db-wal").exists());
+    }
+
+    #[test]
+    fn unreadable_database_is_backed_up_and_replaced() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let path = dir.path().join("pomodoroom.db");
+        let options = ConnectionOptions::default();
+        let garbage = b"this is not a sqlite database, just some bytes".repeat(100);
+        std::fs::write(&path, &garbage).unwrap();
+
+        let (db, recovery) = Database::open_or_recover_at(&path, &options).unwrap();
+        let recovery = recovery.expect("bad file triggers recovery");
+        assert_eq!(std::fs::read(&recovery.backup_path).unwrap(), garbage);
+        assert!(recovery.backup_path.to_string_lossy().contains(".corrupt-"));
+        assert!(db.get_all_session_records().unwrap().is_empty());
+        assert_eq!(db.last_recovery().unwrap(), Some(recovery.clone()));
+        drop(db);
+
+        // A healthy database opens without recovery
+        let (_, again) = Database::open_or_recover_at(&path, &options).unwrap();
+        assert!(again.is_none());
+
+        // A second failure in the same second never overwrites the first backup
+        std::fs::write(&path, b"still not a database").unwrap();
+        std::fs::remove_file(sidecar_path(&path, "-wal")).ok();
+        std::fs::remove_file(sidecar_path(&path, "-shm")).ok();
+        let (_, second) = Database::open_or_recover_at(&path, &options).unwrap();
+        let second = second.unwrap();
+        assert_ne!(second.backup_path, recovery.backup_path);
+        assert_eq!(std::fs::read(&recovery.backup_path).unwrap(), garbage);
+    }
+
+    #[test]
+    fn locked_database_is_left_in_place() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let path = dir.path().join("pomodoroom.db");
+        // Rollback journal, so switching to WAL needs the lock held below
+        let holder = Connection::open(&path).unwrap();
+        holder
+            .execute_batch("CREATE TABLE t (x INTEGER); BEGIN EXCLUSIVE;")
+            .unwrap();
+
+        let options = ConnectionOptions {
+            busy_timeout: std::time::Duration::from_millis(50),
+            ..ConnectionOptions::default()
+        };
+        let error = Database::open_or_recover_at(&path, &options)
+            .err()
+            .expect("busy database is reported, not recovered");
+        assert!(!is_corruption(error.as_ref()));
+
+        holder.execute_batch("COMMIT;").unwrap();
+        let siblings: Vec<_> = std::fs::read_dir(dir.path())
+            .unwrap()
+            .map(|entry| entry.unwrap().file_name().to_string_lossy().into_owned())
+            .filter(|name| name.contains(".corrupt-"))
+            .collect();
+        assert!(siblings.is_empty());
+        let count: i64 = holder
+            .query_row("SELECT COUNT(*) FROM sqlite_master WHERE name = 't'", [], |row| {
+                row.get(0)
+            })
+            .unwrap();
+        assert_eq!(count, 1);
+    }
 }
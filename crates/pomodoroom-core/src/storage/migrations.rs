@@ -1,56 +1,340 @@
 //! Database schema migrations for pomodoroom.
 //!
 //! Migrations are versioned and applied automatically when opening the database.
-//! The `schema_version` table tracks the current migration version.
+//! The `schema_version` table tracks the current migration version and the
+//! `schema_migrations` table records every applied migration. Each migration
+//! declares `down` SQL so `migrate_to` can roll the schema back; every step
+//! runs in its own transaction.
 
-use rusqlite::{Connection, Error as SqliteError, Result as SqliteResult, Transaction};
+use chrono::Utc;
+use rusqlite::{params, Connection, Error as SqliteError, Result as SqliteResult, Transaction};
+
+use crate::error::DatabaseError;
 
 /// Current schema version.
 ///
 /// Increment this when adding new migrations.
-#[allow(dead_code)]
-const CURRENT_SCHEMA_VERSION: i32 = 8;
+pub const CURRENT_SCHEMA_VERSION: i32 = 15;
+
+/// A single schema migration.
+pub struct Migration {
+    /// Version this migration brings the schema to
+    pub version: i32,
+    /// Short description
+    pub description: &'static str,
+    /// Forward step
+    up: fn(&Transaction<'_>) -> SqliteResult<()>,
+    /// SQL reverting the forward step
+    pub down: &'static str,
+}
+
+/// Every migration, in order.
+///
+/// Keep in sync with `CURRENT_SCHEMA_VERSION` when adding new migrations.
+pub const MIGRATIONS: &[Migration] = &[
+    Migration {
+        version: 1,
+        description: "initial schema baseline",
+        up: migrate_v1,
+        // Baseline tables are owned by ScheduleDb
+        down: "",
+    },
+    Migration {
+        version: 2,
+        description: "task state and lifecycle columns",
+        up: migrate_v2,
+        down: "ALTER TABLE tasks DROP COLUMN project_name;
+               ALTER TABLE tasks DROP COLUMN paused_at;
+               ALTER TABLE tasks DROP COLUMN completed_at;
+               ALTER TABLE tasks DROP COLUMN updated_at;
+               ALTER TABLE tasks DROP COLUMN group_name;
+               ALTER TABLE tasks DROP COLUMN energy;
+               ALTER TABLE tasks DROP COLUMN elapsed_minutes;
+               ALTER TABLE tasks DROP COLUMN estimated_minutes;
+               ALTER TABLE tasks DROP COLUMN state;",
+    },
+    Migration {
+        version: 3,
+        description: "task kind and scheduling window columns",
+        up: migrate_v3,
+        down: "ALTER TABLE tasks DROP COLUMN window_end_at;
+               ALTER TABLE tasks DROP COLUMN window_start_at;
+               ALTER TABLE tasks DROP COLUMN fixed_end_at;
+               ALTER TABLE tasks DROP COLUMN fixed_start_at;
+               ALTER TABLE tasks DROP COLUMN required_minutes;
+               ALTER TABLE tasks DROP COLUMN kind;",
+    },
+    Migration {
+        version: 4,
+        description: "estimated_start_at column",
+        up: migrate_v4,
+        down: "ALTER TABLE tasks DROP COLUMN estimated_start_at;",
+    },
+    Migration {
+        version: 5,
+        description: "project/group join tables, references and memo",
+        up: migrate_v5,
+        down: "DROP TABLE IF EXISTS project_references;
+               DROP TABLE IF EXISTS task_groups;
+               DROP TABLE IF EXISTS task_projects;
+               DROP TABLE IF EXISTS groups;
+               ALTER TABLE projects DROP COLUMN memo_md;",
+    },
+    Migration {
+        version: 6,
+        description: "project pin flag",
+        up: migrate_v6,
+        // is_pinned is part of the baseline projects table, so it is kept
+        down: "",
+    },
+    Migration {
+        version: 7,
+        description: "integration source columns",
+        up: migrate_v7,
+        down: "DROP INDEX IF EXISTS idx_tasks_source_unique;
+               ALTER TABLE tasks DROP COLUMN source_external_id;
+               ALTER TABLE tasks DROP COLUMN source_service;",
+    },
+    Migration {
+        version: 8,
+        description: "split task segment columns",
+        up: migrate_v8,
+        down: "DROP INDEX IF EXISTS idx_tasks_parent_segment;
+               ALTER TABLE tasks DROP COLUMN segment_order;
+               ALTER TABLE tasks DROP COLUMN parent_task_id;",
+    },
+    Migration {
+        version: 9,
+        description: "task lookup indexes",
+        up: migrate_v9,
+        down: "DROP INDEX IF EXISTS idx_task_projects_project;
+               DROP INDEX IF EXISTS idx_tasks_created_at;
+               DROP INDEX IF EXISTS idx_tasks_project_id;
+               DROP INDEX IF EXISTS idx_tasks_category;
+               DROP INDEX IF EXISTS idx_tasks_state;",
+    },
+    Migration {
+        version: 10,
+        description: "external_block column",
+        up: migrate_v10,
+        down: "ALTER TABLE tasks DROP COLUMN external_block;",
+    },
+    Migration {
+        version: 11,
+        description: "recurring task templates",
+        up: migrate_v11,
+        down: "DROP INDEX IF EXISTS idx_tasks_recurrence_parent;
+               ALTER TABLE tasks DROP COLUMN recurrence_parent_id;
+               ALTER TABLE tasks DROP COLUMN recurrence;",
+    },
+    Migration {
+        version: 12,
+        description: "task deletion tombstones",
+        up: migrate_v12,
+        down: "DROP TABLE IF EXISTS task_tombstones;",
+    },
+    Migration {
+        version: 13,
+        description: "recipes and recipe execution log",
+        up: migrate_v13,
+        down: "DROP INDEX IF EXISTS idx_recipe_executions_executed_at;
+               DROP TABLE IF EXISTS recipe_executions;
+               DROP TABLE IF EXISTS recipes;",
+    },
+    Migration {
+        version: 14,
+        description: "cascade deletes into task join tables",
+        up: migrate_v14,
+        down: "DROP TRIGGER IF EXISTS trg_groups_delete_task_groups;
+               DROP TRIGGER IF EXISTS trg_projects_delete_task_projects;
+               DROP TRIGGER IF EXISTS trg_tasks_delete_joins;",
+    },
+    Migration {
+        version: 15,
+        description: "task notes",
+        up: migrate_v15,
+        down: "DROP TRIGGER IF EXISTS trg_tasks_delete_notes;
+               DROP TABLE IF EXISTS task_notes;",
+    },
+];
+
+/// A migration recorded in the `schema_migrations` table.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AppliedMigration {
+    /// Migration version
+    pub version: i32,
+    /// Migration description
+    pub description: String,
+    /// When the migration was applied (RFC 3339), or `None` if it was applied
+    /// before migrations were recorded
+    pub applied_at: Option<String>,
+}
 
 /// Apply all pending migrations to bring the database to the current schema version.
 ///
 /// # Errors
 /// Returns an error if migration fails.
 pub fn migrate(conn: &Connection) -> SqliteResult<()> {
-    // Ensure schema_version table exists
-    create_schema_version_table(conn)?;
-
-    // Get current version
-    let current_version = get_schema_version(conn);
-
-    // Apply migrations sequentially
-    if current_version < 1 {
-        migrate_v1(conn)?;
-    }
-    if current_version < 2 {
-        migrate_v2(conn)?;
-    }
-    if current_version < 3 {
-        migrate_v3(conn)?;
+    prepare(conn)?;
+    let version = current_version(conn)?;
+    for migration in MIGRATIONS.iter().filter(|m| m.version > version) {
+        apply_up(conn, migration)?;
     }
-    if current_version < 4 {
-        migrate_v4(conn)?;
+    Ok(())
+}
+
+/// Migrate the schema up or down to `target_version`.
+///
+/// Forward steps run in order and rollbacks in reverse order, each in its
+/// own transaction, so a failing step leaves the schema at the last
+/// completed version. Target 0 rolls back every migration.
+///
+/// # Errors
+/// Returns `DatabaseError::MigrationFailed` if the target is unknown or a
+/// step fails.
+pub fn migrate_to(conn: &Connection, target_version: i32) -> Result<(), DatabaseError> {
+    if !(0..=CURRENT_SCHEMA_VERSION).contains(&target_version) {
+        return Err(DatabaseError::MigrationFailed(format!(
+            "unknown target version {} (latest is {})",
+            target_version, CURRENT_SCHEMA_VERSION
+        )));
     }
-    if current_version < 5 {
-        migrate_v5(conn)?;
+
+    let failed = |version: i32, e: SqliteError| {
+        DatabaseError::MigrationFailed(format!("v{}: {}", version, e))
+    };
+    prepare(conn).map_err(|e| failed(0, e))?;
+    let version = current_version(conn).map_err(|e| failed(0, e))?;
+
+    if target_version >= version {
+        for migration in MIGRATIONS
+            .iter()
+            .filter(|m| m.version > version && m.version <= target_version)
+        {
+            apply_up(conn, migration).map_err(|e| failed(migration.version, e))?;
+        }
+    } else {
+        for migration in MIGRATIONS
+            .iter()
+            .rev()
+            .filter(|m| m.version <= version && m.version > target_version)
+        {
+            apply_down(conn, migration).map_err(|e| failed(migration.version, e))?;
+        }
     }
-    if current_version < 6 {
-        migrate_v6(conn)?;
+    Ok(())
+}
+
+/// Read the schema version recorded in the database.
+///
+/// Returns 0 for a database that has never been migrated.
+///
+/// # Errors
+/// Returns an error if the `schema_version` table cannot be read.
+pub fn current_version(conn: &Connection) -> SqliteResult<i32> {
+    if !table_exists(conn, "schema_version")? {
+        return Ok(0);
     }
-    if current_version < 7 {
-        migrate_v7(conn)?;
+
+    match conn.query_row("SELECT MAX(version) FROM schema_version", [], |row| {
+        row.get::<_, Option<i32>>(0)
+    }) {
+        Ok(version) => Ok(version.unwrap_or(0)),
+        Err(SqliteError::QueryReturnedNoRows) => Ok(0),
+        Err(e) => Err(e),
     }
-    if current_version < 8 {
-        migrate_v8(conn)?;
+}
+
+/// Migrations recorded as applied, in version order.
+///
+/// # Errors
+/// Returns an error if the `schema_migrations` table cannot be read.
+pub fn applied_migrations(conn: &Connection) -> SqliteResult<Vec<AppliedMigration>> {
+    if !table_exists(conn, "schema_migrations")? {
+        return Ok(Vec::new());
     }
 
+    let mut stmt = conn.prepare(
+        "SELECT version, description, applied_at FROM schema_migrations ORDER BY version",
+    )?;
+    let rows = stmt.query_map([], |row| {
+        Ok(AppliedMigration {
+            version: row.get(0)?,
+            description: row.get(1)?,
+            applied_at: row.get(2)?,
+        })
+    })?;
+    rows.collect()
+}
+
+/// Create the tracking tables and reconcile the migration records with the
+/// recorded schema version.
+///
+/// Databases migrated before `schema_migrations` existed get their earlier
+/// migrations backfilled without a timestamp, and records above the current
+/// version are discarded.
+fn prepare(conn: &Connection) -> SqliteResult<()> {
+    create_schema_version_table(conn)?;
+    conn.execute_batch(
+        "CREATE TABLE IF NOT EXISTS schema_migrations (
+            version     INTEGER PRIMARY KEY,
+            description TEXT NOT NULL,
+            applied_at  TEXT
+        );",
+    )?;
+
+    let version = current_version(conn)?;
+    conn.execute(
+        "DELETE FROM schema_migrations WHERE version > ?1",
+        [version],
+    )?;
+    for migration in MIGRATIONS.iter().filter(|m| m.version <= version) {
+        conn.execute(
+            "INSERT OR IGNORE INTO schema_migrations (version, description, applied_at)
+             VALUES (?1, ?2, NULL)",
+            params![migration.version, migration.description],
+        )?;
+    }
     Ok(())
 }
 
+/// Apply one migration and record it, atomically.
+fn apply_up(conn: &Connection, migration: &Migration) -> SqliteResult<()> {
+    let tx = conn.unchecked_transaction()?;
+    (migration.up)(&tx)?;
+    set_schema_version(&tx, migration.version)?;
+    tx.execute(
+        "INSERT OR REPLACE INTO schema_migrations (version, description, applied_at)
+         VALUES (?1, ?2, ?3)",
+        params![
+            migration.version,
+            migration.description,
+            Utc::now().to_rfc3339()
+        ],
+    )?;
+    tx.commit()
+}
+
+/// Roll back one migration and remove its record, atomically.
+fn apply_down(conn: &Connection, migration: &Migration) -> SqliteResult<()> {
+    let tx = conn.unchecked_transaction()?;
+    tx.execute_batch(migration.down)?;
+    set_schema_version(&tx, migration.version - 1)?;
+    tx.execute(
+        "DELETE FROM schema_migrations WHERE version = ?1",
+        [migration.version],
+    )?;
+    tx.commit()
+}
+
+fn table_exists(conn: &Connection, table: &str) -> SqliteResult<bool> {
+    conn.query_row(
+        "SELECT EXISTS(SELECT 1 FROM sqlite_master WHERE type = 'table' AND name = ?1)",
+        [table],
+        |row| row.get(0),
+    )
+}
+
 /// Create the schema_version table if it doesn't exist.
 fn create_schema_version_table(conn: &Connection) -> SqliteResult<()> {
     conn.execute_batch(
@@ -60,24 +344,6 @@ fn create_schema_version_table(conn: &Connection) -> SqliteResult<()> {
     )
 }
 
-/// Get the current schema version from the database.
-///
-/// Returns 0 if no version is set (initial database).
-fn get_schema_version(conn: &Connection) -> i32 {
-    conn.query_row("SELECT version FROM schema_version", [], |row| {
-        row.get::<_, i32>(0)
-    })
-    .unwrap_or_else(|e| {
-        // If table doesn't exist or query fails, return 0
-        if matches!(e, rusqlite::Error::QueryReturnedNoRows) {
-            0
-        } else {
-            eprintln!("Warning: failed to read schema_version: {}", e);
-            0
-        }
-    })
-}
-
 /// Set the schema version in the database.
 fn set_schema_version(conn: &Connection, version: i32) -> SqliteResult<()> {
     // Delete any existing version
@@ -126,9 +392,8 @@ fn add_column_if_missing(
 ///
 /// This migration represents the original schema before any migrations were tracked.
 /// It's a no-op since the tables are created by ScheduleDb::migrate() directly.
-fn migrate_v1(conn: &Connection) -> SqliteResult<()> {
-    // Mark as v1 (tables already exist)
-    set_schema_version(conn, 1)?;
+fn migrate_v1(_tx: &Transaction<'_>) -> SqliteResult<()> {
+    // Tables already exist
     Ok(())
 }
 
@@ -146,9 +411,7 @@ fn migrate_v1(conn: &Connection) -> SqliteResult<()> {
 /// - project_name: Project name (denormalized for convenience)
 ///
 /// Also migrates existing data: completed=1 -> state=DONE, others -> READY.
-fn migrate_v2(conn: &Connection) -> SqliteResult<()> {
-    let tx = conn.unchecked_transaction()?;
-
+fn migrate_v2(tx: &Transaction<'_>) -> SqliteResult<()> {
     // Add new columns with default values
     tx.execute_batch(
         "ALTER TABLE tasks ADD COLUMN state TEXT NOT NULL DEFAULT 'READY';
@@ -176,12 +439,6 @@ fn migrate_v2(conn: &Connection) -> SqliteResult<()> {
         "UPDATE tasks SET completed_at = created_at WHERE completed = 1 AND completed_at IS NULL",
         [],
     )?;
-
-    // Mark as v2
-    tx.execute("DELETE FROM schema_version", [])?;
-    tx.execute("INSERT INTO schema_version (version) VALUES (?1)", [2])?;
-
-    tx.commit()?;
     Ok(())
 }
 
@@ -192,41 +449,39 @@ fn migrate_v2(conn: &Connection) -> SqliteResult<()> {
 /// - required_minutes: required duration in minutes
 /// - fixed_start_at / fixed_end_at
 /// - window_start_at / window_end_at
-fn migrate_v3(conn: &Connection) -> SqliteResult<()> {
-    let tx = conn.unchecked_transaction()?;
-
+fn migrate_v3(tx: &Transaction<'_>) -> SqliteResult<()> {
     add_column_if_missing(
-        &tx,
+        tx,
         "tasks",
         "kind",
         "ALTER TABLE tasks ADD COLUMN kind TEXT NOT NULL DEFAULT 'duration_only'",
     )?;
     add_column_if_missing(
-        &tx,
+        tx,
         "tasks",
         "required_minutes",
         "ALTER TABLE tasks ADD COLUMN required_minutes INTEGER",
     )?;
     add_column_if_missing(
-        &tx,
+        tx,
         "tasks",
         "fixed_start_at",
         "ALTER TABLE tasks ADD COLUMN fixed_start_at TEXT",
     )?;
     add_column_if_missing(
-        &tx,
+        tx,
         "tasks",
         "fixed_end_at",
         "ALTER TABLE tasks ADD COLUMN fixed_end_at TEXT",
     )?;
     add_column_if_missing(
-        &tx,
+        tx,
         "tasks",
         "window_start_at",
         "ALTER TABLE tasks ADD COLUMN window_start_at TEXT",
     )?;
     add_column_if_missing(
-        &tx,
+        tx,
         "tasks",
         "window_end_at",
         "ALTER TABLE tasks ADD COLUMN window_end_at TEXT",
@@ -252,37 +507,22 @@ fn migrate_v3(conn: &Connection) -> SqliteResult<()> {
             [],
         )?;
     }
-
-    // Mark as v3
-    tx.execute("DELETE FROM schema_version", [])?;
-    tx.execute("INSERT INTO schema_version (version) VALUES (?1)", [3])?;
-
-    tx.commit()?;
     Ok(())
 }
 
 /// Migration v4: Add estimated_start_at for auto-scheduled estimated start time.
-fn migrate_v4(conn: &Connection) -> SqliteResult<()> {
-    let tx = conn.unchecked_transaction()?;
-
+fn migrate_v4(tx: &Transaction<'_>) -> SqliteResult<()> {
     add_column_if_missing(
-        &tx,
+        tx,
         "tasks",
         "estimated_start_at",
         "ALTER TABLE tasks ADD COLUMN estimated_start_at TEXT",
     )?;
-
-    tx.execute("DELETE FROM schema_version", [])?;
-    tx.execute("INSERT INTO schema_version (version) VALUES (?1)", [4])?;
-
-    tx.commit()?;
     Ok(())
 }
 
 /// Migration v5: normalized project/group join tables + references and memo column.
-fn migrate_v5(conn: &Connection) -> SqliteResult<()> {
-    let tx = conn.unchecked_transaction()?;
-
+fn migrate_v5(tx: &Transaction<'_>) -> SqliteResult<()> {
     tx.execute_batch(
         "
         CREATE TABLE IF NOT EXISTS projects (
@@ -340,18 +580,11 @@ fn migrate_v5(conn: &Connection) -> SqliteResult<()> {
     if !has_memo_column {
         tx.execute("ALTER TABLE projects ADD COLUMN memo_md TEXT", [])?;
     }
-
-    tx.execute("DELETE FROM schema_version", [])?;
-    tx.execute("INSERT INTO schema_version (version) VALUES (?1)", [5])?;
-
-    tx.commit()?;
     Ok(())
 }
 
 /// Migration v6: add project pin flag.
-fn migrate_v6(conn: &Connection) -> SqliteResult<()> {
-    let tx = conn.unchecked_transaction()?;
-
+fn migrate_v6(tx: &Transaction<'_>) -> SqliteResult<()> {
     let has_is_pinned_column: bool = tx
         .query_row(
             "SELECT COUNT(*) FROM pragma_table_info('projects') WHERE name = 'is_pinned'",
@@ -366,11 +599,6 @@ fn migrate_v6(conn: &Connection) -> SqliteResult<()> {
             [],
         )?;
     }
-
-    tx.execute("DELETE FROM schema_version", [])?;
-    tx.execute("INSERT INTO schema_version (version) VALUES (?1)", [6])?;
-
-    tx.commit()?;
     Ok(())
 }
 
@@ -381,17 +609,15 @@ fn migrate_v6(conn: &Connection) -> SqliteResult<()> {
 /// - source_external_id: External task ID from the integration service
 ///
 /// Creates a unique index on (source_service, source_external_id) to prevent duplicate imports.
-fn migrate_v7(conn: &Connection) -> SqliteResult<()> {
-    let tx = conn.unchecked_transaction()?;
-
+fn migrate_v7(tx: &Transaction<'_>) -> SqliteResult<()> {
     add_column_if_missing(
-        &tx,
+        tx,
         "tasks",
         "source_service",
         "ALTER TABLE tasks ADD COLUMN source_service TEXT",
     )?;
     add_column_if_missing(
-        &tx,
+        tx,
         "tasks",
         "source_external_id",
         "ALTER TABLE tasks ADD COLUMN source_external_id TEXT",
@@ -404,11 +630,6 @@ fn migrate_v7(conn: &Connection) -> SqliteResult<()> {
          WHERE source_service IS NOT NULL AND source_external_id IS NOT NULL",
         [],
     )?;
-
-    tx.execute("DELETE FROM schema_version", [])?;
-    tx.execute("INSERT INTO schema_version (version) VALUES (?1)", [7])?;
-
-    tx.commit()?;
     Ok(())
 }
 
@@ -419,17 +640,15 @@ fn migrate_v7(conn: &Connection) -> SqliteResult<()> {
 /// - segment_order: Optional ordering within a split chain.
 ///
 /// Creates an index on (parent_task_id, segment_order) for child retrieval.
-fn migrate_v8(conn: &Connection) -> SqliteResult<()> {
-    let tx = conn.unchecked_transaction()?;
-
+fn migrate_v8(tx: &Transaction<'_>) -> SqliteResult<()> {
     add_column_if_missing(
-        &tx,
+        tx,
         "tasks",
         "parent_task_id",
         "ALTER TABLE tasks ADD COLUMN parent_task_id TEXT",
     )?;
     add_column_if_missing(
-        &tx,
+        tx,
         "tasks",
         "segment_order",
         "ALTER TABLE tasks ADD COLUMN segment_order INTEGER",
@@ -440,11 +659,168 @@ fn migrate_v8(conn: &Connection) -> SqliteResult<()> {
          ON tasks(parent_task_id, segment_order)",
         [],
     )?;
+    Ok(())
+}
+
+/// Migration v9: Add indexes backing filtered/paginated task queries.
+///
+/// Creates indexes on tasks(state), tasks(category), tasks(project_id),
+/// tasks(created_at) and task_projects(project_id). Indexes are skipped for
+/// columns that do not exist in legacy schemas.
+fn migrate_v9(tx: &Transaction<'_>) -> SqliteResult<()> {
+    for (index, table, column) in [
+        ("idx_tasks_state", "tasks", "state"),
+        ("idx_tasks_category", "tasks", "category"),
+        ("idx_tasks_project_id", "tasks", "project_id"),
+        ("idx_tasks_created_at", "tasks", "created_at"),
+        ("idx_task_projects_project", "task_projects", "project_id"),
+    ] {
+        let has_column: bool = tx
+            .query_row(
+                "SELECT COUNT(*) FROM pragma_table_info(?1) WHERE name = ?2",
+                [table, column],
+                |row| row.get::<_, i32>(0),
+            )
+            .unwrap_or(0)
+            > 0;
+        if has_column {
+            tx.execute(
+                &format!("CREATE INDEX IF NOT EXISTS {index} ON {table}({column})"),
+                [],
+            )?;
+        }
+    }
+    Ok(())
+}
+
+/// Migration v10: Add external_block to distinguish Wait from Floating paused tasks.
+///
+/// Existing rows default to NULL (not blocked).
+fn migrate_v10(tx: &Transaction<'_>) -> SqliteResult<()> {
+    add_column_if_missing(
+        tx,
+        "tasks",
+        "external_block",
+        "ALTER TABLE tasks ADD COLUMN external_block TEXT",
+    )?;
+    Ok(())
+}
+
+/// Migration v11: Add recurring task templates and instance links.
+///
+/// Adds:
+/// - recurrence: JSON-encoded recurrence rule for template tasks.
+/// - recurrence_parent_id: Template ID for generated instances.
+///
+/// Creates an index on recurrence_parent_id for instance lookup.
+fn migrate_v11(tx: &Transaction<'_>) -> SqliteResult<()> {
+    add_column_if_missing(
+        tx,
+        "tasks",
+        "recurrence",
+        "ALTER TABLE tasks ADD COLUMN recurrence TEXT",
+    )?;
+    add_column_if_missing(
+        tx,
+        "tasks",
+        "recurrence_parent_id",
+        "ALTER TABLE tasks ADD COLUMN recurrence_parent_id TEXT",
+    )?;
+
+    tx.execute(
+        "CREATE INDEX IF NOT EXISTS idx_tasks_recurrence_parent
+         ON tasks(recurrence_parent_id)",
+        [],
+    )?;
+    Ok(())
+}
+
+/// Migration v12: Add task_tombstones table.
+///
+/// Deleted tasks leave a tombstone so sync can propagate the deletion.
+fn migrate_v12(tx: &Transaction<'_>) -> SqliteResult<()> {
+    tx.execute_batch(
+        "CREATE TABLE IF NOT EXISTS task_tombstones (
+            id         TEXT PRIMARY KEY,
+            deleted_at TEXT NOT NULL,
+            synced_at  TEXT
+        );",
+    )?;
+    Ok(())
+}
 
-    tx.execute("DELETE FROM schema_version", [])?;
-    tx.execute("INSERT INTO schema_version (version) VALUES (?1)", [8])?;
+/// Migration v13: Add recipes and recipe_executions tables.
+///
+/// Recipe definitions are stored as JSON next to the columns the engine
+/// filters on, so new trigger and action kinds need no schema change.
+fn migrate_v13(tx: &Transaction<'_>) -> SqliteResult<()> {
+    tx.execute_batch(
+        "CREATE TABLE IF NOT EXISTS recipes (
+            id         TEXT PRIMARY KEY,
+            name       TEXT NOT NULL,
+            enabled    INTEGER NOT NULL DEFAULT 1,
+            priority   INTEGER NOT NULL DEFAULT 0,
+            definition TEXT NOT NULL,
+            updated_at TEXT NOT NULL
+        );
+        CREATE TABLE IF NOT EXISTS recipe_executions (
+            id          INTEGER PRIMARY KEY AUTOINCREMENT,
+            recipe_id   TEXT NOT NULL,
+            executed_at TEXT NOT NULL,
+            success     INTEGER NOT NULL,
+            result      TEXT NOT NULL
+        );
+        CREATE INDEX IF NOT EXISTS idx_recipe_executions_executed_at
+            ON recipe_executions(executed_at);",
+    )?;
+    Ok(())
+}
 
-    tx.commit()?;
+/// Migration v14: Remove join rows when their task, project or group is deleted.
+///
+/// Triggers give `ON DELETE CASCADE` behaviour without rebuilding the join
+/// tables. Real foreign keys would also reject inserts, but a task may be
+/// linked to a project or group that has not been synced yet.
+fn migrate_v14(tx: &Transaction<'_>) -> SqliteResult<()> {
+    tx.execute_batch(
+        "CREATE TRIGGER IF NOT EXISTS trg_tasks_delete_joins
+         AFTER DELETE ON tasks
+         BEGIN
+             DELETE FROM task_projects WHERE task_id = OLD.id;
+             DELETE FROM task_groups WHERE task_id = OLD.id;
+         END;
+         CREATE TRIGGER IF NOT EXISTS trg_projects_delete_task_projects
+         AFTER DELETE ON projects
+         BEGIN
+             DELETE FROM task_projects WHERE project_id = OLD.id;
+         END;
+         CREATE TRIGGER IF NOT EXISTS trg_groups_delete_task_groups
+         AFTER DELETE ON groups
+         BEGIN
+             DELETE FROM task_groups WHERE group_id = OLD.id;
+         END;",
+    )?;
+    Ok(())
+}
+
+/// Migration v15: Add task_notes table.
+///
+/// Notes are append-only; the unique key lets a task be saved repeatedly
+/// without duplicating the notes it already has.
+fn migrate_v15(tx: &Transaction<'_>) -> SqliteResult<()> {
+    tx.execute_batch(
+        "CREATE TABLE IF NOT EXISTS task_notes (
+            task_id TEXT NOT NULL,
+            at      TEXT NOT NULL,
+            text    TEXT NOT NULL,
+            UNIQUE (task_id, at, text)
+        );
+        CREATE TRIGGER IF NOT EXISTS trg_tasks_delete_notes
+        AFTER DELETE ON tasks
+        BEGIN
+            DELETE FROM task_notes WHERE task_id = OLD.id;
+        END;",
+    )?;
     Ok(())
 }
 
@@ -494,8 +870,11 @@ mod tests {
         migrate(&conn).unwrap();
 
         // Check version
-        let version = get_schema_version(&conn);
-        assert_eq!(version, 8);
+        assert_eq!(current_version(&conn).unwrap(), CURRENT_SCHEMA_VERSION);
+        assert_eq!(
+            applied_migrations(&conn).unwrap().len(),
+            CURRENT_SCHEMA_VERSION as usize
+        );
 
         // Check that new columns exist
         let mut stmt = conn
@@ -560,9 +939,9 @@ mod tests {
         migrate(&conn).unwrap();
         migrate(&conn).unwrap();
 
-        // Should still be at version 8
-        let version = get_schema_version(&conn);
-        assert_eq!(version, 8);
+        // Should still be at the current version
+        let version = current_version(&conn).unwrap();
+        assert_eq!(version, CURRENT_SCHEMA_VERSION);
     }
 
     /// Test incremental migration (v1 -> v6)
@@ -593,9 +972,15 @@ mod tests {
         // Run migrations
         migrate(&conn).unwrap();
 
-        // Should be at version 8
-        let version = get_schema_version(&conn);
-        assert_eq!(version, 8);
+        // Should be at the current version
+        let version = current_version(&conn).unwrap();
+        assert_eq!(version, CURRENT_SCHEMA_VERSION);
+
+        // v1 predates migration tracking and is backfilled without a timestamp
+        let applied = applied_migrations(&conn).unwrap();
+        assert_eq!(applied.len(), CURRENT_SCHEMA_VERSION as usize);
+        assert_eq!(applied[0].applied_at, None);
+        assert!(applied[1].applied_at.is_some());
 
         // New columns should exist
         let stmt = conn
@@ -606,4 +991,141 @@ mod tests {
         // Query should not fail (columns exist)
         drop(stmt);
     }
+
+    /// Baseline tables as created by ScheduleDb before migrations run.
+    fn baseline_conn() -> Connection {
+        let conn = Connection::open_in_memory().unwrap();
+        conn.execute_batch(
+            "CREATE TABLE tasks (
+                id TEXT PRIMARY KEY,
+                title TEXT NOT NULL,
+                description TEXT,
+                estimated_pomodoros INTEGER NOT NULL DEFAULT 0,
+                completed_pomodoros INTEGER NOT NULL DEFAULT 0,
+                completed INTEGER NOT NULL DEFAULT 0,
+                project_id TEXT,
+                tags TEXT NOT NULL DEFAULT '[]',
+                priority INTEGER,
+                category TEXT NOT NULL DEFAULT 'Active',
+                created_at TEXT NOT NULL
+            );
+            CREATE TABLE projects (
+                id TEXT PRIMARY KEY,
+                name TEXT NOT NULL,
+                deadline TEXT,
+                created_at TEXT NOT NULL,
+                is_pinned INTEGER NOT NULL DEFAULT 0
+            );",
+        )
+        .unwrap();
+        conn
+    }
+
+    /// Tables with their columns, and index names, excluding tracking tables.
+    fn schema_snapshot(conn: &Connection) -> Vec<String> {
+        let mut stmt = conn
+            .prepare(
+                "SELECT type, name, tbl_name FROM sqlite_master
+                 WHERE name NOT LIKE 'sqlite_%'
+                   AND tbl_name NOT IN ('schema_version', 'schema_migrations')
+                 ORDER BY type, name",
+            )
+            .unwrap();
+        let objects: Vec<(String, String, String)> = stmt
+            .query_map([], |row| Ok((row.get(0)?, row.get(1)?, row.get(2)?)))
+            .unwrap()
+            .collect::<SqliteResult<_>>()
+            .unwrap();
+
+        let mut snapshot = Vec::new();
+        for (kind, name, table) in objects {
+            if kind == "table" {
+                let mut stmt = conn
+                    .prepare("SELECT name, type FROM pragma_table_info(?1) ORDER BY cid")
+                    .unwrap();
+                let columns: Vec<String> = stmt
+                    .query_map([&name], |row| {
+                        Ok(format!(
+                            "{} {}",
+                            row.get::<_, String>(0)?,
+                            row.get::<_, String>(1)?
+                        ))
+                    })
+                    .unwrap()
+                    .collect::<SqliteResult<_>>()
+                    .unwrap();
+                snapshot.push(format!("table {}({})", name, columns.join(", ")));
+            } else {
+                snapshot.push(format!("{} {} on {}", kind, name, table));
+            }
+        }
+        snapshot
+    }
+
+    /// Migrating up to N then down to N-1 matches migrating straight to N-1
+    #[test]
+    fn test_migrate_down_restores_schema() {
+        for migration in MIGRATIONS {
+            let n = migration.version;
+
+            let expected = baseline_conn();
+            migrate_to(&expected, n - 1).unwrap();
+
+            let conn = baseline_conn();
+            migrate_to(&conn, n).unwrap();
+            assert_eq!(current_version(&conn).unwrap(), n);
+            migrate_to(&conn, n - 1).unwrap();
+
+            assert_eq!(current_version(&conn).unwrap(), n - 1, "v{}", n);
+            assert_eq!(
+                schema_snapshot(&conn),
+                schema_snapshot(&expected),
+                "rolling back v{}",
+                n
+            );
+            assert_eq!(applied_migrations(&conn).unwrap().len(), (n - 1) as usize);
+        }
+
+        // Full round trip back up to the latest version
+        let conn = baseline_conn();
+        migrate_to(&conn, CURRENT_SCHEMA_VERSION).unwrap();
+        let latest = schema_snapshot(&conn);
+        migrate_to(&conn, 0).unwrap();
+        migrate(&conn).unwrap();
+        assert_eq!(schema_snapshot(&conn), latest);
+    }
+
+    /// A failing rollback step leaves the schema untouched
+    #[test]
+    fn test_migrate_to_failed_step_is_atomic() {
+        let conn = baseline_conn();
+        migrate_to(&conn, 11).unwrap();
+        // An index on the column prevents v11 from dropping it
+        conn.execute(
+            "CREATE INDEX idx_custom_recurrence ON tasks(recurrence)",
+            [],
+        )
+        .unwrap();
+        let before = schema_snapshot(&conn);
+
+        let err = migrate_to(&conn, 10).unwrap_err();
+        assert!(err.to_string().contains("v11"));
+        assert_eq!(current_version(&conn).unwrap(), 11);
+        assert_eq!(schema_snapshot(&conn), before);
+        assert_eq!(applied_migrations(&conn).unwrap().len(), 11);
+
+        assert!(migrate_to(&conn, CURRENT_SCHEMA_VERSION + 1).is_err());
+        assert!(migrate_to(&conn, -1).is_err());
+    }
+
+    #[test]
+    fn test_migrations_table_matches_current_version() {
+        for (index, migration) in MIGRATIONS.iter().enumerate() {
+            assert_eq!(migration.version, index as i32 + 1);
+        }
+        assert_eq!(
+            MIGRATIONS.last().map(|m| m.version),
+            Some(CURRENT_SCHEMA_VERSION)
+        );
+    }
 }
@@ -1,16 +1,30 @@
 mod config;
+mod connection;
 pub mod database;
 pub mod migrations;
 pub mod profiles;
 pub mod schedule_db;
+pub mod sealed;
 
-pub use config::{Config, NotificationsConfig, ScheduleConfig, ShortcutsConfig, UiConfig, YouTubeConfig};
-pub use database::{AccuracyDataRow, Database, EnergyCurveRow, SessionRecord, Stats};
+pub use config::{
+    normalize_keybinding, Config, ConfigBundle, ConfigKey, ConfigValue, NotificationsConfig,
+    ScheduleConfig, ShortcutViolation, ShortcutsConfig, SyncConfig, UiConfig, YouTubeConfig,
+    CONFIG_BUNDLE_VERSION,
+};
+pub use connection::{ConnectionOptions, DEFAULT_BUSY_TIMEOUT};
+pub use database::{
+    AccuracyDataRow, Database, DbRecovery, EnergyCurveRow, SessionRecord, Stats,
+    WeeklyFocusTrend, DB_RECOVERY_KEY,
+};
 pub use profiles::{
     find_pack, get_builtin_packs, pack_ids, ProfileBackup, ProfileComparison, ProfileConfig,
     ProfileManager, ProfilePack, ProfilePackId, ProfilePerformance,
 };
-pub use schedule_db::{DataResetOptions, DataResetSummary, ScheduleDb};
+pub use schedule_db::{
+    DataResetOptions, DataResetSummary, OrphanReport, RecipeExecutionRecord, ScheduleDb,
+    StoredRecipe, TaskPage, TaskQuery, TaskTombstone,
+};
+pub use sealed::{FieldCipher, SENSITIVE_KEYS};
 
 use std::path::PathBuf;
 
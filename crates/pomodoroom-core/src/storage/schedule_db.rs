@@ -4,12 +4,17 @@ use chrono::{DateTime, Utc};
 use rusqlite::{params, Connection, OptionalExtension};
 use serde_json;
 use uuid::Uuid;
+use std::collections::{HashMap, HashSet};
+use std::path::Path;
 use std::sync::atomic::{AtomicUsize, Ordering};
 
-use super::data_dir;
+use super::{data_dir, ConnectionOptions};
 use super::migrations;
 use crate::schedule::{DailyTemplate, FixedEvent, Group, Project, ScheduleBlock};
-use crate::task::{EnergyLevel, Task, TaskCategory, TaskKind, TaskState};
+use crate::task::{
+    generate_due_instances, BatchTransitionResult, EnergyLevel, Task, TaskCategory, TaskKind,
+    TaskNote, TaskState, TaskStateMachine, TaskTransitionOutcome, TransitionAction,
+};
 use crate::schedule::ProjectReference;
 
 // === Datetime Parse Tracking ===
@@ -303,6 +308,187 @@ fn row_to_schedule_block(row: &rusqlite::Row) -> Result<ScheduleBlock, rusqlite:
     })
 }
 
+/// Columns selected for a full task row, in the order expected by [`row_to_task`].
+const TASK_COLUMNS: &str = "id, title, description, estimated_pomodoros, completed_pomodoros,
+    completed, project_id, tags, priority, category, created_at,
+    state, estimated_minutes, elapsed_minutes, energy, group_name,
+    updated_at, completed_at, paused_at, project_name, kind,
+    required_minutes, fixed_start_at, fixed_end_at, window_start_at, window_end_at, estimated_start_at,
+    source_service, source_external_id, parent_task_id, segment_order, external_block,
+    recurrence, recurrence_parent_id";
+
+/// Stable task ordering shared by listing and paginated queries.
+const TASK_ORDER: &str = "COALESCE(priority, 50) DESC, created_at ASC, id ASC";
+
+fn parse_optional_datetime(value: Option<String>) -> Option<DateTime<Utc>> {
+    value
+        .and_then(|s| DateTime::parse_from_rfc3339(&s).ok())
+        .map(|dt| dt.with_timezone(&Utc))
+}
+
+/// Build a Task from a row selected with [`TASK_COLUMNS`].
+///
+/// `project_ids`, `group_ids` and `notes` live in their own tables and are
+/// left empty here.
+fn row_to_task(row: &rusqlite::Row) -> Result<Task, rusqlite::Error> {
+    let tags_json: String = row.get(7)?;
+    let tags: Vec<String> = serde_json::from_str(&tags_json).unwrap_or_default();
+
+    let category_str: String = row.get(9)?;
+    let category = parse_task_category(&category_str);
+
+    let created_at_str: String = row.get(10)?;
+    let created_at = parse_datetime_fallback(&created_at_str).datetime;
+
+    // New v2 fields
+    let state_str: String = row.get(11)?;
+    let state = parse_task_state(&state_str);
+
+    let energy_str: Option<String> = row.get(14)?;
+    let energy = parse_energy_level(energy_str.as_deref());
+    let kind_str: Option<String> = row.get(20)?;
+    let kind = parse_task_kind(kind_str.as_deref());
+
+    let updated_at_str: String = row.get(16)?;
+    let updated_at = parse_datetime_fallback(&updated_at_str).datetime;
+
+    Ok(Task {
+        id: row.get(0)?,
+        title: row.get(1)?,
+        description: row.get(2)?,
+        estimated_pomodoros: row.get(3)?,
+        completed_pomodoros: row.get(4)?,
+        completed: row.get(5)?,
+        state,
+        project_id: row.get(6)?,
+        project_name: row.get(19)?,
+        project_ids: Vec::new(),
+        kind,
+        required_minutes: row.get(21)?,
+        fixed_start_at: parse_optional_datetime(row.get(22)?),
+        fixed_end_at: parse_optional_datetime(row.get(23)?),
+        window_start_at: parse_optional_datetime(row.get(24)?),
+        window_end_at: parse_optional_datetime(row.get(25)?),
+        tags,
+        priority: row.get(8)?,
+        category,
+        estimated_minutes: row.get(12)?,
+        estimated_start_at: parse_optional_datetime(row.get(26)?),
+        elapsed_minutes: row.get(13)?,
+        energy,
+        group: row.get(15)?,
+        group_ids: Vec::new(),
+        created_at,
+        updated_at,
+        completed_at: parse_optional_datetime(row.get(17)?),
+        paused_at: parse_optional_datetime(row.get(18)?),
+        started_at: None, // TODO: Add to database schema
+        source_service: row.get(27)?,
+        source_external_id: row.get(28)?,
+        parent_task_id: row.get(29)?,
+        segment_order: row.get(30)?,
+        allow_split: true,
+        suggested_tags: Vec::new(),
+        approved_tags: Vec::new(),
+        external_block: row.get(31)?,
+        recurrence: row
+            .get::<_, Option<String>>(32)?
+            .and_then(|json| serde_json::from_str(&json).ok()),
+        recurrence_parent_id: row.get(33)?,
+        notes: Vec::new(),
+    })
+}
+
+/// Filter and pagination options for [`ScheduleDb::query_tasks`].
+#[derive(Debug, Clone, Default)]
+pub struct TaskQuery {
+    /// Only tasks in this state.
+    pub state: Option<TaskState>,
+    /// Only tasks with this stored category.
+    pub category: Option<TaskCategory>,
+    /// Only tasks linked to this project (primary or via `project_ids`).
+    pub project_id: Option<String>,
+    /// Inclusive lower bound on `created_at`.
+    pub created_from: Option<DateTime<Utc>>,
+    /// Exclusive upper bound on `created_at`.
+    pub created_to: Option<DateTime<Utc>>,
+    /// Maximum number of tasks to return (None = no limit).
+    pub limit: Option<u32>,
+    /// Number of matching tasks to skip.
+    pub offset: u32,
+    /// Also return recurring templates (skipped by default).
+    pub include_templates: bool,
+}
+
+/// One page of results from [`ScheduleDb::query_tasks`].
+#[derive(Debug, Clone)]
+pub struct TaskPage {
+    /// Tasks in this page.
+    pub tasks: Vec<Task>,
+    /// Total number of tasks matching the filter across all pages.
+    pub total: usize,
+}
+
+/// Record of a deleted task, kept until sync has propagated the deletion.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TaskTombstone {
+    /// ID of the deleted task.
+    pub id: String,
+    /// When the task was deleted.
+    pub deleted_at: DateTime<Utc>,
+    /// When sync confirmed the deletion remotely, if it has.
+    pub synced_at: Option<DateTime<Utc>>,
+}
+
+/// Join rows that point at a missing task, project or group.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct OrphanReport {
+    /// Dangling `(task_id, project_id)` rows in `task_projects`.
+    pub task_projects: Vec<(String, String)>,
+    /// Dangling `(task_id, group_id)` rows in `task_groups`.
+    pub task_groups: Vec<(String, String)>,
+}
+
+impl OrphanReport {
+    /// Whether no dangling rows were found.
+    pub fn is_clean(&self) -> bool {
+        self.task_projects.is_empty() && self.task_groups.is_empty()
+    }
+}
+
+/// A persisted automation recipe.
+///
+/// The engine owns the recipe format; storage keeps it as an opaque JSON
+/// `definition` alongside the fields it needs to list recipes.
+#[derive(Debug, Clone, PartialEq)]
+pub struct StoredRecipe {
+    /// Recipe ID.
+    pub id: String,
+    /// Display name.
+    pub name: String,
+    /// Whether the recipe runs on matching triggers.
+    pub enabled: bool,
+    /// Execution priority (lower runs first).
+    pub priority: i32,
+    /// Full recipe as serialized by the engine.
+    pub definition: serde_json::Value,
+    /// When the recipe was last saved.
+    pub updated_at: DateTime<Utc>,
+}
+
+/// A persisted recipe execution result.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RecipeExecutionRecord {
+    /// ID of the executed recipe.
+    pub recipe_id: String,
+    /// When the recipe ran.
+    pub executed_at: DateTime<Utc>,
+    /// Whether every condition and action succeeded.
+    pub success: bool,
+    /// Full result as serialized by the engine.
+    pub result: serde_json::Value,
+}
+
 /// SQLite database for schedule storage.
 ///
 /// Stores tasks, projects, and daily templates.
@@ -337,16 +523,29 @@ impl ScheduleDb {
     /// Returns an error if the database cannot be opened or migrated.
     pub fn open() -> Result<Self, Box<dyn std::error::Error>> {
         let path = data_dir()?.join("pomodoroom.db");
-        let conn = Connection::open(path)?;
+        Self::open_with_options(&path, &ConnectionOptions::default())
+    }
+
+    /// Open the database at `path` with explicit connection options.
+    ///
+    /// Creates tables if they don't exist.
+    ///
+    /// # Errors
+    /// Returns an error if the database cannot be opened, configured or migrated.
+    pub fn open_with_options(
+        path: &Path,
+        options: &ConnectionOptions,
+    ) -> Result<Self, Box<dyn std::error::Error>> {
+        let conn = options.open(path)?;
         let db = Self { conn };
         db.migrate()?;
         Ok(db)
     }
 
     /// Open an in-memory database (for tests).
-    #[cfg(test)]
+    #[cfg(any(test, feature = "testing"))]
     pub fn open_memory() -> Result<Self, Box<dyn std::error::Error>> {
-        let conn = Connection::open_in_memory()?;
+        let conn = ConnectionOptions::default().open_in_memory()?;
         let db = Self { conn };
         db.migrate()?;
         Ok(db)
@@ -468,6 +667,55 @@ impl ScheduleDb {
         Ok(values)
     }
 
+    /// Store notes that are not saved yet; existing notes are never removed.
+    fn save_task_notes(&self, task_id: &str, notes: &[TaskNote]) -> Result<(), rusqlite::Error> {
+        for note in notes {
+            self.conn.execute(
+                "INSERT OR IGNORE INTO task_notes (task_id, at, text) VALUES (?1, ?2, ?3)",
+                params![task_id, note.at.to_rfc3339(), note.text],
+            )?;
+        }
+        Ok(())
+    }
+
+    fn load_task_notes(&self, task_id: &str) -> Result<Vec<TaskNote>, rusqlite::Error> {
+        let mut stmt = self
+            .conn
+            .prepare("SELECT at, text FROM task_notes WHERE task_id = ?1")?;
+        let mut notes = stmt
+            .query_map(params![task_id], |row| {
+                Ok(TaskNote {
+                    at: parse_datetime_fallback(&row.get::<_, String>(0)?).datetime,
+                    text: row.get(1)?,
+                })
+            })?
+            .collect::<Result<Vec<_>, _>>()?;
+        notes.sort_by_key(|note| note.at);
+        Ok(notes)
+    }
+
+    /// Fill in `notes` for every task with one query.
+    fn attach_task_notes(&self, tasks: &mut [Task]) -> Result<(), rusqlite::Error> {
+        let mut stmt = self
+            .conn
+            .prepare("SELECT task_id, at, text FROM task_notes")?;
+        let mut rows = stmt.query([])?;
+        let mut by_task: HashMap<String, Vec<TaskNote>> = HashMap::new();
+        while let Some(row) = rows.next()? {
+            by_task.entry(row.get(0)?).or_default().push(TaskNote {
+                at: parse_datetime_fallback(&row.get::<_, String>(1)?).datetime,
+                text: row.get(2)?,
+            });
+        }
+        for task in tasks {
+            if let Some(mut notes) = by_task.remove(&task.id) {
+                notes.sort_by_key(|note| note.at);
+                task.notes = notes;
+            }
+        }
+        Ok(())
+    }
+
     fn set_project_references(
         &self,
         project_id: &str,
@@ -582,6 +830,10 @@ impl ScheduleDb {
     /// Create a new task.
     pub fn create_task(&self, task: &Task) -> Result<(), rusqlite::Error> {
         let tags_json = serde_json::to_string(&task.tags).unwrap();
+        let recurrence_json = task
+            .recurrence
+            .as_ref()
+            .map(|recurrence| serde_json::to_string(recurrence).unwrap());
         let category_str = format_task_category(task.category);
         let state_str = format_task_state(task.state);
         let kind_str = format_task_kind(task.kind);
@@ -594,8 +846,9 @@ impl ScheduleDb {
                 state, estimated_minutes, elapsed_minutes, energy, group_name,
                 updated_at, completed_at, paused_at, project_name, kind,
                 required_minutes, fixed_start_at, fixed_end_at, window_start_at, window_end_at, estimated_start_at,
-                source_service, source_external_id, parent_task_id, segment_order
-             ) VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12, ?13, ?14, ?15, ?16, ?17, ?18, ?19, ?20, ?21, ?22, ?23, ?24, ?25, ?26, ?27, ?28, ?29, ?30, ?31)",
+                source_service, source_external_id, parent_task_id, segment_order, external_block,
+                recurrence, recurrence_parent_id
+             ) VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12, ?13, ?14, ?15, ?16, ?17, ?18, ?19, ?20, ?21, ?22, ?23, ?24, ?25, ?26, ?27, ?28, ?29, ?30, ?31, ?32, ?33, ?34)",
             params![
                 task.id,
                 task.title,
@@ -628,10 +881,19 @@ impl ScheduleDb {
                 task.source_external_id,
                 task.parent_task_id,
                 task.segment_order,
+                task.external_block,
+                recurrence_json,
+                task.recurrence_parent_id,
             ],
         )?;
+        // Re-creating a deleted task cancels its pending deletion
+        self.conn.execute(
+            "DELETE FROM task_tombstones WHERE id = ?1",
+            params![task.id],
+        )?;
         self.set_task_projects(&task.id, &task.project_ids)?;
         self.set_task_groups(&task.id, &task.group_ids)?;
+        self.save_task_notes(&task.id, &task.notes)?;
         if let Some(parent_id) = task.parent_task_id.as_deref() {
             self.rollup_parent_completion(parent_id)?;
         }
@@ -640,117 +902,14 @@ impl ScheduleDb {
 
     /// Get a task by ID.
     pub fn get_task(&self, id: &str) -> Result<Option<Task>, rusqlite::Error> {
-        let mut stmt = self.conn.prepare(
-            "SELECT id, title, description, estimated_pomodoros, completed_pomodoros,
-                    completed, project_id, tags, priority, category, created_at,
-                    state, estimated_minutes, elapsed_minutes, energy, group_name,
-                    updated_at, completed_at, paused_at, project_name, kind,
-                    required_minutes, fixed_start_at, fixed_end_at, window_start_at, window_end_at, estimated_start_at,
-                    source_service, source_external_id, parent_task_id, segment_order
-             FROM tasks WHERE id = ?1",
-        )?;
-
-        let result = stmt.query_row(params![id], |row| {
-            let tags_json: String = row.get(7)?;
-            let tags: Vec<String> = serde_json::from_str(&tags_json).unwrap_or_default();
-
-            let category_str: String = row.get(9)?;
-            let category = parse_task_category(&category_str);
-
-            let created_at_str: String = row.get(10)?;
-            let created_at = parse_datetime_fallback(&created_at_str).datetime;
-
-            // New v2 fields
-            let state_str: String = row.get(11)?;
-            let state = parse_task_state(&state_str);
-
-            let energy_str: Option<String> = row.get(14)?;
-            let energy = parse_energy_level(energy_str.as_deref());
-            let kind_str: Option<String> = row.get(20)?;
-            let kind = parse_task_kind(kind_str.as_deref());
-
-            let updated_at_str: String = row.get(16)?;
-            let updated_at = parse_datetime_fallback(&updated_at_str).datetime;
-
-            let completed_at_str: Option<String> = row.get(17)?;
-            let completed_at = completed_at_str
-                .and_then(|s| DateTime::parse_from_rfc3339(&s).ok())
-                .map(|dt| dt.with_timezone(&Utc));
-
-            let paused_at_str: Option<String> = row.get(18)?;
-            let paused_at = paused_at_str
-                .and_then(|s| DateTime::parse_from_rfc3339(&s).ok())
-                .map(|dt| dt.with_timezone(&Utc));
-            let fixed_start_at_str: Option<String> = row.get(22)?;
-            let fixed_start_at = fixed_start_at_str
-                .and_then(|s| DateTime::parse_from_rfc3339(&s).ok())
-                .map(|dt| dt.with_timezone(&Utc));
-            let fixed_end_at_str: Option<String> = row.get(23)?;
-            let fixed_end_at = fixed_end_at_str
-                .and_then(|s| DateTime::parse_from_rfc3339(&s).ok())
-                .map(|dt| dt.with_timezone(&Utc));
-            let window_start_at_str: Option<String> = row.get(24)?;
-            let window_start_at = window_start_at_str
-                .and_then(|s| DateTime::parse_from_rfc3339(&s).ok())
-                .map(|dt| dt.with_timezone(&Utc));
-            let window_end_at_str: Option<String> = row.get(25)?;
-            let window_end_at = window_end_at_str
-                .and_then(|s| DateTime::parse_from_rfc3339(&s).ok())
-                .map(|dt| dt.with_timezone(&Utc));
-            let estimated_start_at_str: Option<String> = row.get(26)?;
-            let estimated_start_at = estimated_start_at_str
-                .and_then(|s| DateTime::parse_from_rfc3339(&s).ok())
-                .map(|dt| dt.with_timezone(&Utc));
-            let source_service: Option<String> = row.get(27)?;
-            let source_external_id: Option<String> = row.get(28)?;
-            let parent_task_id: Option<String> = row.get(29)?;
-            let segment_order: Option<i32> = row.get(30)?;
-
-            Ok(Task {
-                id: row.get(0)?,
-                title: row.get(1)?,
-                description: row.get(2)?,
-                estimated_pomodoros: row.get(3)?,
-                completed_pomodoros: row.get(4)?,
-                completed: row.get(5)?,
-                state,
-                project_id: row.get(6)?,
-                project_name: row.get(19)?,
-                project_ids: Vec::new(),
-                kind,
-                required_minutes: row.get(21)?,
-                fixed_start_at,
-                fixed_end_at,
-                window_start_at,
-                window_end_at,
-                tags,
-                priority: row.get(8)?,
-                category,
-                estimated_minutes: row.get(12)?,
-                estimated_start_at,
-                elapsed_minutes: row.get(13)?,
-                energy,
-                group: row.get(15)?,
-                group_ids: Vec::new(),
-                created_at,
-                updated_at,
-                completed_at,
-                paused_at,
-                started_at: None, // TODO: Add to database schema
-                source_service,
-                source_external_id,
-                parent_task_id,
-                segment_order,
-                allow_split: true,
-                suggested_tags: Vec::new(),
-                approved_tags: Vec::new(),
-            })
-        });
+        let sql = format!("SELECT {TASK_COLUMNS} FROM tasks WHERE id = ?1");
+        let result = self.conn.query_row(&sql, params![id], row_to_task);
 
         match result {
             Ok(mut task) => {
                 task.project_ids = self.load_task_projects(&task.id)?;
                 task.group_ids = self.load_task_groups(&task.id)?;
+                task.notes = self.load_task_notes(&task.id)?;
                 Ok(Some(task))
             }
             Err(rusqlite::Error::QueryReturnedNoRows) => Ok(None),
@@ -760,120 +919,92 @@ impl ScheduleDb {
 
     /// List all tasks.
     pub fn list_tasks(&self) -> Result<Vec<Task>, rusqlite::Error> {
-        let mut stmt = self.conn.prepare(
-            "SELECT id, title, description, estimated_pomodoros, completed_pomodoros,
-                    completed, project_id, tags, priority, category, created_at,
-                    state, estimated_minutes, elapsed_minutes, energy, group_name,
-                    updated_at, completed_at, paused_at, project_name, kind,
-                    required_minutes, fixed_start_at, fixed_end_at, window_start_at, window_end_at, estimated_start_at,
-                    source_service, source_external_id, parent_task_id, segment_order
-             FROM tasks
-             ORDER BY COALESCE(priority, 50) DESC, created_at ASC",
-        )?;
-
-        let tasks = stmt.query_map([], |row| {
-            let tags_json: String = row.get(7)?;
-            let tags: Vec<String> = serde_json::from_str(&tags_json).unwrap_or_default();
-
-            let category_str: String = row.get(9)?;
-            let category = parse_task_category(&category_str);
-
-            let created_at_str: String = row.get(10)?;
-            let created_at = parse_datetime_fallback(&created_at_str).datetime;
-
-            // New v2 fields
-            let state_str: String = row.get(11)?;
-            let state = parse_task_state(&state_str);
-
-            let energy_str: Option<String> = row.get(14)?;
-            let energy = parse_energy_level(energy_str.as_deref());
-            let kind_str: Option<String> = row.get(20)?;
-            let kind = parse_task_kind(kind_str.as_deref());
+        let sql = format!("SELECT {TASK_COLUMNS} FROM tasks ORDER BY {TASK_ORDER}");
+        let mut stmt = self.conn.prepare(&sql)?;
+        let mut tasks = stmt
+            .query_map([], row_to_task)?
+            .collect::<Result<Vec<_>, _>>()?;
+        self.attach_task_notes(&mut tasks)?;
+        Ok(tasks)
+    }
 
-            let updated_at_str: String = row.get(16)?;
-            let updated_at = parse_datetime_fallback(&updated_at_str).datetime;
+    /// Query tasks with filters and pagination.
+    ///
+    /// Results are ordered by priority (desc), then `created_at` and `id`, so
+    /// consecutive pages never skip or repeat rows. `total` is the number of
+    /// matching tasks ignoring `limit`/`offset`.
+    pub fn query_tasks(&self, query: &TaskQuery) -> Result<TaskPage, rusqlite::Error> {
+        let mut clauses: Vec<&str> = Vec::new();
+        let mut values: Vec<rusqlite::types::Value> = Vec::new();
+
+        if !query.include_templates {
+            clauses.push("recurrence IS NULL");
+        }
+        if let Some(state) = query.state {
+            clauses.push("state = ?");
+            values.push(format_task_state(state).to_string().into());
+        }
+        if let Some(category) = query.category {
+            clauses.push("category = ?");
+            values.push(format_task_category(category).to_string().into());
+        }
+        if let Some(project_id) = query.project_id.as_ref() {
+            clauses.push(
+                "(project_id = ? OR id IN (SELECT task_id FROM task_projects WHERE project_id = ?))",
+            );
+            values.push(project_id.clone().into());
+            values.push(project_id.clone().into());
+        }
+        if let Some(created_from) = query.created_from {
+            clauses.push("created_at >= ?");
+            values.push(created_from.to_rfc3339().into());
+        }
+        if let Some(created_to) = query.created_to {
+            clauses.push("created_at < ?");
+            values.push(created_to.to_rfc3339().into());
+        }
 
-            let completed_at_str: Option<String> = row.get(17)?;
-            let completed_at = completed_at_str
-                .and_then(|s| DateTime::parse_from_rfc3339(&s).ok())
-                .map(|dt| dt.with_timezone(&Utc));
+        let where_sql = if clauses.is_empty() {
+            String::new()
+        } else {
+            format!(" WHERE {}", clauses.join(" AND "))
+        };
 
-            let paused_at_str: Option<String> = row.get(18)?;
-            let paused_at = paused_at_str
-                .and_then(|s| DateTime::parse_from_rfc3339(&s).ok())
-                .map(|dt| dt.with_timezone(&Utc));
-            let fixed_start_at_str: Option<String> = row.get(22)?;
-            let fixed_start_at = fixed_start_at_str
-                .and_then(|s| DateTime::parse_from_rfc3339(&s).ok())
-                .map(|dt| dt.with_timezone(&Utc));
-            let fixed_end_at_str: Option<String> = row.get(23)?;
-            let fixed_end_at = fixed_end_at_str
-                .and_then(|s| DateTime::parse_from_rfc3339(&s).ok())
-                .map(|dt| dt.with_timezone(&Utc));
-            let window_start_at_str: Option<String> = row.get(24)?;
-            let window_start_at = window_start_at_str
-                .and_then(|s| DateTime::parse_from_rfc3339(&s).ok())
-                .map(|dt| dt.with_timezone(&Utc));
-            let window_end_at_str: Option<String> = row.get(25)?;
-            let window_end_at = window_end_at_str
-                .and_then(|s| DateTime::parse_from_rfc3339(&s).ok())
-                .map(|dt| dt.with_timezone(&Utc));
-            let estimated_start_at_str: Option<String> = row.get(26)?;
-            let estimated_start_at = estimated_start_at_str
-                .and_then(|s| DateTime::parse_from_rfc3339(&s).ok())
-                .map(|dt| dt.with_timezone(&Utc));
-            let source_service: Option<String> = row.get(27)?;
-            let source_external_id: Option<String> = row.get(28)?;
-            let parent_task_id: Option<String> = row.get(29)?;
-            let segment_order: Option<i32> = row.get(30)?;
+        let total: i64 = self.conn.query_row(
+            &format!("SELECT COUNT(*) FROM tasks{where_sql}"),
+            rusqlite::params_from_iter(values.iter()),
+            |row| row.get(0),
+        )?;
 
-            Ok(Task {
-                id: row.get(0)?,
-                title: row.get(1)?,
-                description: row.get(2)?,
-                estimated_pomodoros: row.get(3)?,
-                completed_pomodoros: row.get(4)?,
-                completed: row.get(5)?,
-                state,
-                project_id: row.get(6)?,
-                project_name: row.get(19)?,
-                project_ids: Vec::new(),
-                kind,
-                required_minutes: row.get(21)?,
-                fixed_start_at,
-                fixed_end_at,
-                window_start_at,
-                window_end_at,
-                tags,
-                priority: row.get(8)?,
-                category,
-                estimated_minutes: row.get(12)?,
-                estimated_start_at,
-                elapsed_minutes: row.get(13)?,
-                energy,
-                group: row.get(15)?,
-                group_ids: Vec::new(),
-                created_at,
-                updated_at,
-                completed_at,
-                paused_at,
-                started_at: None, // TODO: Add to database schema
-                source_service,
-                source_external_id,
-                parent_task_id,
-                segment_order,
-                allow_split: true,
-                suggested_tags: Vec::new(),
-                approved_tags: Vec::new(),
-            })
-        })?;
+        // SQLite treats a negative LIMIT as "no limit".
+        let limit = query.limit.map(i64::from).unwrap_or(-1);
+        let sql = format!(
+            "SELECT {TASK_COLUMNS} FROM tasks{where_sql} ORDER BY {TASK_ORDER} LIMIT {limit} OFFSET {}",
+            query.offset
+        );
+        let mut stmt = self.conn.prepare(&sql)?;
+        let mut tasks = stmt
+            .query_map(rusqlite::params_from_iter(values.iter()), row_to_task)?
+            .collect::<Result<Vec<_>, _>>()?;
+        for task in &mut tasks {
+            task.project_ids = self.load_task_projects(&task.id)?;
+            task.group_ids = self.load_task_groups(&task.id)?;
+            task.notes = self.load_task_notes(&task.id)?;
+        }
 
-        tasks.collect()
+        Ok(TaskPage {
+            tasks,
+            total: total as usize,
+        })
     }
 
     /// Update an existing task.
     pub fn update_task(&self, task: &Task) -> Result<(), rusqlite::Error> {
         let tags_json = serde_json::to_string(&task.tags).unwrap();
+        let recurrence_json = task
+            .recurrence
+            .as_ref()
+            .map(|recurrence| serde_json::to_string(recurrence).unwrap());
         let category_str = format_task_category(task.category);
         let state_str = format_task_state(task.state);
         let kind_str = format_task_kind(task.kind);
@@ -896,8 +1027,9 @@ impl ScheduleDb {
                  group_name = ?14, updated_at = ?15, completed_at = ?16, paused_at = ?17,
                  project_name = ?18, kind = ?19, required_minutes = ?20, fixed_start_at = ?21,
                  fixed_end_at = ?22, window_start_at = ?23, window_end_at = ?24, estimated_start_at = ?25,
-                 source_service = ?26, source_external_id = ?27, parent_task_id = ?28, segment_order = ?29
-             WHERE id = ?30",
+                 source_service = ?26, source_external_id = ?27, parent_task_id = ?28, segment_order = ?29,
+                 external_block = ?30, recurrence = ?31, recurrence_parent_id = ?32
+             WHERE id = ?33",
             params![
                 task.title,
                 task.description,
@@ -928,6 +1060,9 @@ impl ScheduleDb {
                 task.source_external_id,
                 task.parent_task_id,
                 task.segment_order,
+                task.external_block,
+                recurrence_json,
+                task.recurrence_parent_id,
                 task.id,
             ],
         )?;
@@ -942,6 +1077,7 @@ impl ScheduleDb {
         if self.has_child_segments(&task.id)? {
             self.rollup_parent_completion(&task.id)?;
         }
+        self.save_task_notes(&task.id, &task.notes)?;
         Ok(())
     }
 
@@ -979,7 +1115,45 @@ impl ScheduleDb {
         Ok(task.id.clone())
     }
 
+    /// Materialize recurring task instances due on `now`'s day.
+    ///
+    /// Loads every recurring template with its existing instances, creates
+    /// the instances that are missing for the current period, and returns them.
+    /// Instances the user deleted (still tombstoned) are not recreated.
+    pub fn generate_due_instances(&self, now: DateTime<Utc>) -> Result<Vec<Task>, rusqlite::Error> {
+        let sql = format!(
+            "SELECT {TASK_COLUMNS} FROM tasks
+             WHERE recurrence IS NOT NULL OR recurrence_parent_id IS NOT NULL
+             ORDER BY {TASK_ORDER}"
+        );
+        let mut stmt = self.conn.prepare(&sql)?;
+        let mut tasks = stmt
+            .query_map([], row_to_task)?
+            .collect::<Result<Vec<_>, _>>()?;
+        for task in &mut tasks {
+            task.project_ids = self.load_task_projects(&task.id)?;
+            task.group_ids = self.load_task_groups(&task.id)?;
+        }
+
+        let deleted: HashSet<String> = self
+            .list_task_tombstones()?
+            .into_iter()
+            .map(|tombstone| tombstone.id)
+            .collect();
+        let instances: Vec<Task> = generate_due_instances(&tasks, now)
+            .into_iter()
+            .filter(|instance| !deleted.contains(&instance.id))
+            .collect();
+        for instance in &instances {
+            self.create_task(instance)?;
+        }
+        Ok(instances)
+    }
+
     /// Delete a task.
+    ///
+    /// The task row is replaced by a tombstone so sync can propagate the
+    /// deletion; see [`ScheduleDb::purge_tombstones`].
     pub fn delete_task(&self, id: &str) -> Result<(), rusqlite::Error> {
         let parent_task_id: Option<String> = self
             .conn
@@ -996,14 +1170,262 @@ impl ScheduleDb {
         )?;
         self.conn
             .execute("DELETE FROM task_groups WHERE task_id = ?1", params![id])?;
-        self.conn
-            .execute("DELETE FROM tasks WHERE id = ?1", params![id])?;
+        self.remove_task_row(id)?;
         if let Some(parent_id) = parent_task_id {
             self.rollup_parent_completion(&parent_id)?;
         }
         Ok(())
     }
 
+    /// Delete a task row, leaving a tombstone if the task existed.
+    fn remove_task_row(&self, id: &str) -> Result<(), rusqlite::Error> {
+        let deleted = self
+            .conn
+            .execute("DELETE FROM tasks WHERE id = ?1", params![id])?;
+        if deleted > 0 {
+            self.conn.execute(
+                "INSERT OR REPLACE INTO task_tombstones (id, deleted_at, synced_at)
+                 VALUES (?1, ?2, NULL)",
+                params![id, Utc::now().to_rfc3339()],
+            )?;
+        }
+        Ok(())
+    }
+
+    /// List tombstones of deleted tasks, oldest first.
+    pub fn list_task_tombstones(&self) -> Result<Vec<TaskTombstone>, rusqlite::Error> {
+        let mut stmt = self.conn.prepare(
+            "SELECT id, deleted_at, synced_at FROM task_tombstones ORDER BY deleted_at, id",
+        )?;
+        let tombstones = stmt.query_map([], |row| {
+            let synced_at: Option<String> = row.get(2)?;
+            Ok(TaskTombstone {
+                id: row.get(0)?,
+                deleted_at: parse_datetime_fallback(&row.get::<_, String>(1)?).datetime,
+                synced_at: synced_at
+                    .as_deref()
+                    .map(|value| parse_datetime_fallback(value).datetime),
+            })
+        })?;
+        tombstones.collect()
+    }
+
+    /// Record that sync has propagated the given deletions.
+    ///
+    /// Returns the number of tombstones updated.
+    pub fn mark_tombstones_synced(
+        &self,
+        ids: &[String],
+        at: DateTime<Utc>,
+    ) -> Result<usize, rusqlite::Error> {
+        let mut updated = 0;
+        for id in ids {
+            updated += self.conn.execute(
+                "UPDATE task_tombstones SET synced_at = ?2 WHERE id = ?1",
+                params![id, at.to_rfc3339()],
+            )?;
+        }
+        Ok(updated)
+    }
+
+    /// Remove tombstones whose deletion was synced before `before`.
+    ///
+    /// Unsynced tombstones are kept so the deletion still reaches remote.
+    /// Returns the number of tombstones removed.
+    pub fn purge_tombstones(&self, before: DateTime<Utc>) -> Result<usize, rusqlite::Error> {
+        self.conn.execute(
+            "DELETE FROM task_tombstones WHERE synced_at IS NOT NULL AND synced_at < ?1",
+            params![before.to_rfc3339()],
+        )
+    }
+
+    // === Recipes ===
+
+    /// Insert or replace a recipe.
+    pub fn upsert_recipe(&self, recipe: &StoredRecipe) -> Result<(), rusqlite::Error> {
+        self.conn.execute(
+            "INSERT OR REPLACE INTO recipes (id, name, enabled, priority, definition, updated_at)
+             VALUES (?1, ?2, ?3, ?4, ?5, ?6)",
+            params![
+                recipe.id,
+                recipe.name,
+                recipe.enabled,
+                recipe.priority,
+                recipe.definition.to_string(),
+                recipe.updated_at.to_rfc3339(),
+            ],
+        )?;
+        Ok(())
+    }
+
+    /// List recipes ordered by priority, then ID.
+    pub fn list_recipes(&self) -> Result<Vec<StoredRecipe>, rusqlite::Error> {
+        let mut stmt = self.conn.prepare(
+            "SELECT id, name, enabled, priority, definition, updated_at
+             FROM recipes ORDER BY priority, id",
+        )?;
+        let recipes = stmt.query_map([], |row| {
+            let definition: String = row.get(4)?;
+            Ok(StoredRecipe {
+                id: row.get(0)?,
+                name: row.get(1)?,
+                enabled: row.get(2)?,
+                priority: row.get(3)?,
+                definition: serde_json::from_str(&definition).map_err(|e| {
+                    rusqlite::Error::FromSqlConversionFailure(
+                        4,
+                        rusqlite::types::Type::Text,
+                        Box::new(e),
+                    )
+                })?,
+                updated_at: parse_datetime_fallback(&row.get::<_, String>(5)?).datetime,
+            })
+        })?;
+        recipes.collect()
+    }
+
+    /// Delete a recipe. Its execution history is kept.
+    ///
+    /// Returns whether a recipe was removed.
+    pub fn delete_recipe(&self, id: &str) -> Result<bool, rusqlite::Error> {
+        let deleted = self
+            .conn
+            .execute("DELETE FROM recipes WHERE id = ?1", params![id])?;
+        Ok(deleted > 0)
+    }
+
+    /// Append a recipe execution to the log.
+    pub fn record_recipe_execution(
+        &self,
+        record: &RecipeExecutionRecord,
+    ) -> Result<(), rusqlite::Error> {
+        self.conn.execute(
+            "INSERT INTO recipe_executions (recipe_id, executed_at, success, result)
+             VALUES (?1, ?2, ?3, ?4)",
+            params![
+                record.recipe_id,
+                record.executed_at.to_rfc3339(),
+                record.success,
+                record.result.to_string(),
+            ],
+        )?;
+        Ok(())
+    }
+
+    /// Recipe executions since `since`, newest first, at most `limit`.
+    pub fn recent_recipe_executions(
+        &self,
+        since: DateTime<Utc>,
+        limit: usize,
+    ) -> Result<Vec<RecipeExecutionRecord>, rusqlite::Error> {
+        let mut stmt = self.conn.prepare(
+            "SELECT recipe_id, executed_at, success, result FROM recipe_executions
+             WHERE executed_at >= ?1 ORDER BY executed_at DESC, id DESC LIMIT ?2",
+        )?;
+        let records = stmt.query_map(params![since.to_rfc3339(), limit as i64], |row| {
+            let result: String = row.get(3)?;
+            Ok(RecipeExecutionRecord {
+                recipe_id: row.get(0)?,
+                executed_at: parse_datetime_fallback(&row.get::<_, String>(1)?).datetime,
+                success: row.get(2)?,
+                result: serde_json::from_str(&result).map_err(|e| {
+                    rusqlite::Error::FromSqlConversionFailure(
+                        3,
+                        rusqlite::types::Type::Text,
+                        Box::new(e),
+                    )
+                })?,
+            })
+        })?;
+        records.collect()
+    }
+
+    /// Remove recipe executions older than `before`.
+    ///
+    /// Returns the number of records removed.
+    pub fn prune_recipe_executions(&self, before: DateTime<Utc>) -> Result<usize, rusqlite::Error> {
+        self.conn.execute(
+            "DELETE FROM recipe_executions WHERE executed_at < ?1",
+            params![before.to_rfc3339()],
+        )
+    }
+
+    /// Apply `action` to each task in `ids`, in one transaction.
+    ///
+    /// A missing task or an invalid transition (e.g. completing a task that
+    /// is already done) is recorded as a failed outcome and the remaining
+    /// tasks are still transitioned. Only a database error aborts the batch,
+    /// rolling back every change.
+    pub fn apply_transitions(
+        &self,
+        ids: &[String],
+        action: TransitionAction,
+    ) -> Result<BatchTransitionResult, rusqlite::Error> {
+        self.conn.execute_batch("BEGIN IMMEDIATE TRANSACTION;")?;
+        let result: Result<Vec<TaskTransitionOutcome>, rusqlite::Error> = (|| {
+            let mut outcomes = Vec::with_capacity(ids.len());
+            for id in ids {
+                let Some(task) = self.get_task(id)? else {
+                    outcomes.push(TaskTransitionOutcome::Failed {
+                        task_id: id.clone(),
+                        error: format!("Task not found: {id}"),
+                    });
+                    continue;
+                };
+                let mut machine = TaskStateMachine::new(task);
+                match machine.apply_action(action) {
+                    Ok(()) => {
+                        self.update_task(&machine.task)?;
+                        outcomes.push(TaskTransitionOutcome::Applied {
+                            task: Box::new(machine.task),
+                        });
+                    }
+                    Err(err) => outcomes.push(TaskTransitionOutcome::Failed {
+                        task_id: id.clone(),
+                        error: err.to_string(),
+                    }),
+                }
+            }
+            Ok(outcomes)
+        })();
+        match result {
+            Ok(outcomes) => {
+                self.conn.execute_batch("COMMIT;")?;
+                Ok(BatchTransitionResult { outcomes })
+            }
+            Err(err) => {
+                let _ = self.conn.execute_batch("ROLLBACK;");
+                Err(err)
+            }
+        }
+    }
+
+    /// Find join rows whose task, project or group no longer exists.
+    ///
+    /// Deletes cascade into the join tables, so rows reported here predate
+    /// that or were linked to an entity that was never created locally.
+    pub fn orphan_check(&self) -> Result<OrphanReport, rusqlite::Error> {
+        let dangling = |sql: &str| -> Result<Vec<(String, String)>, rusqlite::Error> {
+            let mut stmt = self.conn.prepare(sql)?;
+            let rows = stmt.query_map([], |row| Ok((row.get(0)?, row.get(1)?)))?;
+            rows.collect()
+        };
+        Ok(OrphanReport {
+            task_projects: dangling(
+                "SELECT task_id, project_id FROM task_projects
+                 WHERE task_id NOT IN (SELECT id FROM tasks)
+                    OR project_id NOT IN (SELECT id FROM projects)
+                 ORDER BY task_id, project_id",
+            )?,
+            task_groups: dangling(
+                "SELECT task_id, group_id FROM task_groups
+                 WHERE task_id NOT IN (SELECT id FROM tasks)
+                    OR group_id NOT IN (SELECT id FROM groups)
+                 ORDER BY task_id, group_id",
+            )?,
+        })
+    }
+
     /// Delete a project and optionally its linked tasks in a single transaction.
     pub fn delete_project_with_tasks_transactional(
         &self,
@@ -1029,8 +1451,7 @@ impl ScheduleDb {
                     )?;
                     self.conn
                         .execute("DELETE FROM task_groups WHERE task_id = ?1", params![task_id])?;
-                    self.conn
-                        .execute("DELETE FROM tasks WHERE id = ?1", params![task_id])?;
+                    self.remove_task_row(&task_id)?;
                 }
             }
             self.conn.execute(
@@ -1518,7 +1939,7 @@ impl ScheduleDb {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::schedule::Group;
+    use crate::schedule::{FixedEventKind, Group};
 
     fn make_test_task() -> Task {
         Task {
@@ -1559,6 +1980,10 @@ mod tests {
             allow_split: true,
             suggested_tags: vec![],
             approved_tags: vec![],
+            external_block: None,
+            recurrence: None,
+            recurrence_parent_id: None,
+            notes: Vec::new(),
         }
     }
 
@@ -1588,6 +2013,61 @@ mod tests {
         assert_eq!(tasks.len(), 2);
     }
 
+    #[test]
+    fn query_tasks_filters_and_paginates_stably() {
+        let db = ScheduleDb::open_memory().unwrap();
+        let base = Utc::now() - chrono::Duration::days(10);
+        for i in 0..5 {
+            let mut task = make_test_task();
+            task.title = format!("ready {i}");
+            task.priority = Some(50);
+            task.created_at = base + chrono::Duration::days(i);
+            task.project_id = Some("p1".to_string());
+            db.create_task(&task).unwrap();
+        }
+        let mut done = make_test_task();
+        done.state = TaskState::Done;
+        done.category = TaskCategory::Floating;
+        db.create_task(&done).unwrap();
+
+        let query = TaskQuery {
+            state: Some(TaskState::Ready),
+            project_id: Some("p1".to_string()),
+            limit: Some(2),
+            ..Default::default()
+        };
+        let first = db.query_tasks(&query).unwrap();
+        assert_eq!(first.total, 5);
+        assert_eq!(first.tasks.len(), 2);
+
+        let mut seen: Vec<String> = first.tasks.iter().map(|t| t.title.clone()).collect();
+        for offset in [2, 4] {
+            let page = db
+                .query_tasks(&TaskQuery { offset, ..query.clone() })
+                .unwrap();
+            seen.extend(page.tasks.iter().map(|t| t.title.clone()));
+        }
+        assert_eq!(seen, vec!["ready 0", "ready 1", "ready 2", "ready 3", "ready 4"]);
+
+        let floating = db
+            .query_tasks(&TaskQuery {
+                category: Some(TaskCategory::Floating),
+                ..Default::default()
+            })
+            .unwrap();
+        assert_eq!(floating.total, 1);
+        assert_eq!(floating.tasks[0].id, done.id);
+
+        let ranged = db
+            .query_tasks(&TaskQuery {
+                created_from: Some(base + chrono::Duration::days(1)),
+                created_to: Some(base + chrono::Duration::days(3)),
+                ..Default::default()
+            })
+            .unwrap();
+        assert_eq!(ranged.total, 2);
+    }
+
     #[test]
     fn update_task() {
         let db = ScheduleDb::open_memory().unwrap();
@@ -1613,6 +2093,176 @@ mod tests {
         assert!(db.get_task(&task.id).unwrap().is_none());
     }
 
+    #[test]
+    fn batch_transition_reports_invalid_tasks_and_applies_the_rest() {
+        let db = ScheduleDb::open_memory().unwrap();
+        let mut ids = Vec::new();
+        for (title, state) in [
+            ("Write report", TaskState::Running),
+            ("Review PR", TaskState::Running),
+            ("Shipped", TaskState::Done),
+        ] {
+            let mut task = Task::new(title);
+            task.state = state;
+            db.create_task(&task).unwrap();
+            ids.push(task.id);
+        }
+        ids.push("missing-task".to_string());
+
+        let result = db
+            .apply_transitions(&ids, TransitionAction::Complete)
+            .unwrap();
+        assert_eq!(result.applied_count(), 2);
+        assert_eq!(result.failed_count(), 2);
+        let order: Vec<_> = result.outcomes.iter().map(|o| o.task_id()).collect();
+        assert_eq!(order, ids.iter().map(String::as_str).collect::<Vec<_>>());
+        assert!(matches!(
+            &result.outcomes[2],
+            TaskTransitionOutcome::Failed { error, .. } if error.contains("Invalid state transition")
+        ));
+
+        for id in &ids[..2] {
+            let task = db.get_task(id).unwrap().unwrap();
+            assert_eq!(task.state, TaskState::Done);
+            assert!(task.completed_at.is_some());
+        }
+        assert_eq!(db.get_task(&ids[2]).unwrap().unwrap().state, TaskState::Done);
+    }
+
+    #[test]
+    fn recipes_and_executions_round_trip() {
+        let db = ScheduleDb::open_memory().unwrap();
+        let now = Utc::now();
+        let recipe = |id: &str, priority: i32| StoredRecipe {
+            id: id.to_string(),
+            name: format!("Recipe {id}"),
+            enabled: true,
+            priority,
+            definition: serde_json::json!({ "id": id }),
+            updated_at: now,
+        };
+        db.upsert_recipe(&recipe("b", 1)).unwrap();
+        db.upsert_recipe(&recipe("a", 5)).unwrap();
+        db.upsert_recipe(&StoredRecipe {
+            enabled: false,
+            ..recipe("a", 0)
+        })
+        .unwrap();
+
+        let recipes = db.list_recipes().unwrap();
+        assert_eq!(recipes.len(), 2);
+        assert_eq!(recipes[0].id, "a");
+        assert!(!recipes[0].enabled);
+        assert_eq!(recipes[1].definition, serde_json::json!({ "id": "b" }));
+
+        assert!(db.delete_recipe("b").unwrap());
+        assert!(!db.delete_recipe("b").unwrap());
+        assert_eq!(db.list_recipes().unwrap().len(), 1);
+
+        let old = now - chrono::Duration::days(40);
+        for (executed_at, success) in [(old, true), (now, false)] {
+            db.record_recipe_execution(&RecipeExecutionRecord {
+                recipe_id: "a".to_string(),
+                executed_at,
+                success,
+                result: serde_json::json!({ "success": success }),
+            })
+            .unwrap();
+        }
+
+        let recent = db
+            .recent_recipe_executions(now - chrono::Duration::days(30), 10)
+            .unwrap();
+        assert_eq!(recent.len(), 1);
+        assert!(!recent[0].success);
+        assert_eq!(db.recent_recipe_executions(old, 10).unwrap().len(), 2);
+        assert_eq!(
+            db.recent_recipe_executions(old, 1).unwrap()[0].executed_at,
+            recent[0].executed_at
+        );
+
+        assert_eq!(
+            db.prune_recipe_executions(now - chrono::Duration::days(30))
+                .unwrap(),
+            1
+        );
+        assert_eq!(db.recent_recipe_executions(old, 10).unwrap().len(), 1);
+    }
+
+    #[test]
+    fn delete_task_leaves_tombstone_until_purged() {
+        let db = ScheduleDb::open_memory().unwrap();
+        let task = make_test_task();
+        db.create_task(&task).unwrap();
+        db.delete_task(&task.id).unwrap();
+        // Deleting an unknown task leaves no tombstone
+        db.delete_task("missing").unwrap();
+
+        assert!(db.list_tasks().unwrap().is_empty());
+        let tombstones = db.list_task_tombstones().unwrap();
+        assert_eq!(tombstones.len(), 1);
+        assert_eq!(tombstones[0].id, task.id);
+        assert!(tombstones[0].synced_at.is_none());
+
+        // Unsynced tombstones survive a purge
+        let later = Utc::now() + chrono::Duration::days(1);
+        assert_eq!(db.purge_tombstones(later).unwrap(), 0);
+
+        let synced_at = Utc::now();
+        assert_eq!(
+            db.mark_tombstones_synced(std::slice::from_ref(&task.id), synced_at)
+                .unwrap(),
+            1
+        );
+        assert_eq!(db.purge_tombstones(synced_at).unwrap(), 0);
+        assert_eq!(db.purge_tombstones(later).unwrap(), 1);
+        assert!(db.list_task_tombstones().unwrap().is_empty());
+
+        // Re-creating a deleted task clears its tombstone
+        let other = make_test_task();
+        db.create_task(&other).unwrap();
+        db.delete_task(&other.id).unwrap();
+        db.create_task(&other).unwrap();
+        assert!(db.list_task_tombstones().unwrap().is_empty());
+    }
+
+    #[test]
+    fn task_notes_persist_and_round_trip() {
+        let db = ScheduleDb::open_memory().unwrap();
+        let mut task = make_test_task();
+        task.add_note("Blocked on API key from ops");
+        db.create_task(&task).unwrap();
+
+        let mut loaded = db.get_task(&task.id).unwrap().unwrap();
+        assert_eq!(loaded.notes, task.notes);
+
+        // Saving again keeps existing notes and appends new ones
+        loaded.add_note("Key received, unblocked");
+        db.update_task(&loaded).unwrap();
+        let listed = db.list_tasks().unwrap();
+        let texts: Vec<&str> = listed[0].notes.iter().map(|n| n.text.as_str()).collect();
+        assert_eq!(
+            texts,
+            ["Blocked on API key from ops", "Key received, unblocked"]
+        );
+
+        // Serialized tasks keep their notes; older payloads without notes load
+        let json = serde_json::to_value(&listed[0]).unwrap();
+        let decoded: Task = serde_json::from_value(json.clone()).unwrap();
+        assert_eq!(decoded.notes, listed[0].notes);
+        let mut legacy = json;
+        legacy.as_object_mut().unwrap().remove("notes");
+        let decoded: Task = serde_json::from_value(legacy).unwrap();
+        assert!(decoded.notes.is_empty());
+
+        db.delete_task(&task.id).unwrap();
+        let remaining: i64 = db
+            .conn
+            .query_row("SELECT COUNT(*) FROM task_notes", [], |row| row.get(0))
+            .unwrap();
+        assert_eq!(remaining, 0);
+    }
+
     #[test]
     fn create_and_get_project() {
         let db = ScheduleDb::open_memory().unwrap();
@@ -1634,6 +2284,65 @@ mod tests {
         assert_eq!(retrieved.name, "Test Project");
     }
 
+    #[test]
+    fn deleting_project_or_group_removes_its_task_links() {
+        let db = ScheduleDb::open_memory().unwrap();
+        let project = Project {
+            id: Uuid::new_v4().to_string(),
+            name: "Launch".to_string(),
+            deadline: None,
+            tasks: vec![],
+            created_at: Utc::now(),
+            is_pinned: false,
+            references: vec![],
+            default_tags: vec![],
+            color: None,
+        };
+        db.create_project(&project).unwrap();
+        let group = Group {
+            id: Uuid::new_v4().to_string(),
+            name: "Writing".to_string(),
+            parent_id: None,
+            order_index: 0,
+            created_at: Utc::now(),
+            updated_at: Utc::now(),
+        };
+        db.create_group(&group).unwrap();
+        let mut task = make_test_task();
+        task.project_ids = vec![project.id.clone()];
+        task.group_ids = vec![group.id.clone()];
+        db.create_task(&task).unwrap();
+        assert!(db.orphan_check().unwrap().is_clean());
+
+        db.delete_project(&project.id).unwrap();
+        db.delete_group(&group.id).unwrap();
+        let links: i64 = db
+            .conn
+            .query_row(
+                "SELECT (SELECT COUNT(*) FROM task_projects) + (SELECT COUNT(*) FROM task_groups)",
+                [],
+                |row| row.get(0),
+            )
+            .unwrap();
+        assert_eq!(links, 0);
+        assert!(db.get_task(&task.id).unwrap().unwrap().project_ids.is_empty());
+
+        // Links to entities that never existed are reported
+        db.conn
+            .execute(
+                "INSERT INTO task_groups (task_id, group_id) VALUES (?1, 'missing')",
+                params![task.id],
+            )
+            .unwrap();
+        let report = db.orphan_check().unwrap();
+        assert_eq!(report.task_groups, vec![(task.id.clone(), "missing".to_string())]);
+        assert!(report.task_projects.is_empty());
+
+        // Deleting the task cleans up after it as well
+        db.delete_task(&task.id).unwrap();
+        assert!(db.orphan_check().unwrap().is_clean());
+    }
+
     #[test]
     fn daily_template() {
         let db = ScheduleDb::open_memory().unwrap();
@@ -1647,6 +2356,7 @@ mod tests {
                 duration_minutes: 60,
                 days: vec![1, 2, 3, 4, 5],
                 enabled: true,
+                kind: FixedEventKind::Meal,
             }],
             max_parallel_lanes: Some(2),
         };
@@ -1657,6 +2367,95 @@ mod tests {
         assert_eq!(retrieved.wake_up, "07:00");
         assert_eq!(retrieved.fixed_events.len(), 1);
         assert_eq!(retrieved.fixed_events[0].name, "Lunch");
+        assert_eq!(retrieved.fixed_events[0].kind, FixedEventKind::Meal);
+    }
+
+    #[test]
+    fn external_block_round_trip() {
+        let db = ScheduleDb::open_memory().unwrap();
+        let mut task = make_test_task();
+        db.create_task(&task).unwrap();
+        assert!(db.get_task(&task.id).unwrap().unwrap().external_block.is_none());
+
+        task.state = TaskState::Paused;
+        task.external_block = Some("Waiting on API keys".to_string());
+        db.update_task(&task).unwrap();
+
+        let retrieved = db.get_task(&task.id).unwrap().unwrap();
+        assert_eq!(retrieved.external_block.as_deref(), Some("Waiting on API keys"));
+        assert_eq!(retrieved.effective_category(), TaskCategory::Wait);
+    }
+
+    #[test]
+    fn recurring_instances_persist_and_leave_template_open() {
+        use crate::task::Recurrence;
+
+        let db = ScheduleDb::open_memory().unwrap();
+        let now = Utc::now();
+        let mut template = make_test_task();
+        template.recurrence = Some(Recurrence::daily());
+        template.created_at = now - chrono::Duration::days(1);
+        db.create_task(&template).unwrap();
+
+        let generated = db.generate_due_instances(now).unwrap();
+        assert_eq!(generated.len(), 1);
+        assert!(db.generate_due_instances(now).unwrap().is_empty());
+
+        let mut instance = db.get_task(&generated[0].id).unwrap().unwrap();
+        assert_eq!(instance.recurrence_parent_id.as_deref(), Some(template.id.as_str()));
+        instance.state = TaskState::Done;
+        instance.completed = true;
+        db.update_task(&instance).unwrap();
+
+        let stored_template = db.get_task(&template.id).unwrap().unwrap();
+        assert_eq!(stored_template.state, TaskState::Ready);
+        assert!(!stored_template.completed);
+        assert_eq!(stored_template.recurrence, Some(Recurrence::daily()));
+    }
+
+    #[test]
+    fn deleted_recurring_instance_is_not_recreated() {
+        use crate::task::Recurrence;
+
+        let db = ScheduleDb::open_memory().unwrap();
+        let now = Utc::now();
+        let mut template = make_test_task();
+        template.recurrence = Some(Recurrence::daily());
+        template.created_at = now - chrono::Duration::days(1);
+        db.create_task(&template).unwrap();
+
+        let generated = db.generate_due_instances(now).unwrap();
+        assert_eq!(generated.len(), 1);
+        db.delete_task(&generated[0].id).unwrap();
+
+        assert!(db.generate_due_instances(now).unwrap().is_empty());
+        assert!(db.get_task(&generated[0].id).unwrap().is_none());
+        let tombstones = db.list_task_tombstones().unwrap();
+        assert_eq!(tombstones.len(), 1);
+        assert_eq!(tombstones[0].id, generated[0].id);
+    }
+
+    #[test]
+    fn query_tasks_skips_recurring_templates() {
+        use crate::task::Recurrence;
+
+        let db = ScheduleDb::open_memory().unwrap();
+        let mut template = make_test_task();
+        template.recurrence = Some(Recurrence::daily());
+        db.create_task(&template).unwrap();
+        let generated = db.generate_due_instances(Utc::now()).unwrap();
+
+        let page = db.query_tasks(&TaskQuery::default()).unwrap();
+        assert_eq!(page.total, generated.len());
+        assert!(page.tasks.iter().all(|task| !task.is_recurrence_template()));
+
+        let all = db
+            .query_tasks(&TaskQuery {
+                include_templates: true,
+                ..Default::default()
+            })
+            .unwrap();
+        assert_eq!(all.total, generated.len() + 1);
     }
 
     #[test]
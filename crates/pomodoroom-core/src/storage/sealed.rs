@@ -0,0 +1,240 @@
+//! At-rest encryption for sensitive config fields.
+//!
+//! Most of `config.toml` is harmless and stays readable, but fields listed
+//! in `SENSITIVE_KEYS` can hold personal data. When
+//! `Config::encrypt_sensitive_fields` is on, those values are written as
+//! `enc:v1:<base64>` strings sealed with AES-256-GCM. The key is derived from
+//! a random secret kept in the OS keyring, so copying the config file alone
+//! does not reveal them.
+//!
+//! Decryption is keyed off the value prefix rather than the setting, so a
+//! file that still holds sealed values is readable after encryption is
+//! turned off; the next save writes them back in plaintext.
+
+use aes_gcm::aead::{Aead, KeyInit};
+use aes_gcm::{Aes256Gcm, Nonce};
+use base64::Engine;
+use hmac::{Hmac, Mac};
+use sha2::Sha256;
+
+use crate::error::ConfigError;
+use crate::integrations::keyring_store;
+
+/// Dotted config keys encrypted at rest.
+pub const SENSITIVE_KEYS: &[&str] = &["notifications.custom_sound"];
+
+/// Prefix marking a sealed value.
+pub const SEALED_PREFIX: &str = "enc:v1:";
+
+/// Keyring entry holding the secret the field key is derived from.
+const KEYRING_ENTRY: &str = "config_field_secret";
+
+/// Domain separation label for key derivation.
+const KEY_CONTEXT: &[u8] = b"pomodoroom config fields v1";
+
+const NONCE_LEN: usize = 12;
+
+/// Encrypts and decrypts sensitive config values.
+pub struct FieldCipher {
+    cipher: Aes256Gcm,
+}
+
+impl FieldCipher {
+    /// Cipher with a key derived from `secret`.
+    pub fn from_secret(secret: &[u8]) -> Self {
+        let mut mac =
+            <Hmac<Sha256> as Mac>::new_from_slice(secret).expect("HMAC accepts any key length");
+        mac.update(KEY_CONTEXT);
+        let key = mac.finalize().into_bytes();
+        Self {
+            cipher: Aes256Gcm::new(&key),
+        }
+    }
+
+    /// Cipher keyed from the OS keyring.
+    ///
+    /// A secret is generated and stored on first use. With `create` false a
+    /// missing secret is an error, since values sealed under it could never
+    /// be opened again.
+    ///
+    /// # Errors
+    /// Returns `ConfigError::KeyUnavailable` if the keyring cannot be read
+    /// or written, or holds no secret when `create` is false.
+    pub fn from_keyring(create: bool) -> Result<Self, ConfigError> {
+        let unavailable =
+            |e: Box<dyn std::error::Error>| ConfigError::KeyUnavailable(e.to_string());
+        let encoded = match keyring_store::get(KEYRING_ENTRY).map_err(unavailable)? {
+            Some(encoded) => encoded,
+            None if create => {
+                let mut secret = [0u8; 32];
+                getrandom::getrandom(&mut secret)
+                    .map_err(|e| ConfigError::KeyUnavailable(e.to_string()))?;
+                let encoded = hex::encode(secret);
+                keyring_store::set(KEYRING_ENTRY, &encoded).map_err(unavailable)?;
+                encoded
+            }
+            None => {
+                return Err(ConfigError::KeyUnavailable(
+                    "no config secret in the keyring".to_string(),
+                ))
+            }
+        };
+        let secret =
+            hex::decode(encoded.trim()).map_err(|e| ConfigError::KeyUnavailable(e.to_string()))?;
+        Ok(Self::from_secret(&secret))
+    }
+
+    /// Seal `plaintext` under a fresh random nonce.
+    pub fn encrypt(&self, plaintext: &str) -> String {
+        let mut nonce = [0u8; NONCE_LEN];
+        getrandom::getrandom(&mut nonce).expect("Failed to generate nonce");
+        let ciphertext = self
+            .cipher
+            .encrypt(Nonce::from_slice(&nonce), plaintext.as_bytes())
+            .expect("AES-GCM encryption of an in-memory buffer cannot fail");
+        let mut sealed = nonce.to_vec();
+        sealed.extend_from_slice(&ciphertext);
+        format!(
+            "{SEALED_PREFIX}{}",
+            base64::engine::general_purpose::STANDARD.encode(sealed)
+        )
+    }
+
+    /// Open a value produced by `encrypt`.
+    ///
+    /// # Errors
+    /// Returns `ConfigError::DecryptFailed` if `value` is malformed or was
+    /// sealed under another key.
+    pub fn decrypt(&self, key: &str, value: &str) -> Result<String, ConfigError> {
+        let failed = |message: &str| ConfigError::DecryptFailed {
+            key: key.to_string(),
+            message: message.to_string(),
+        };
+        let encoded = value
+            .strip_prefix(SEALED_PREFIX)
+            .ok_or_else(|| failed("value is not sealed"))?;
+        let sealed = base64::engine::general_purpose::STANDARD
+            .decode(encoded)
+            .map_err(|_| failed("invalid base64"))?;
+        if sealed.len() < NONCE_LEN {
+            return Err(failed("value is truncated"));
+        }
+        let (nonce, ciphertext) = sealed.split_at(NONCE_LEN);
+        let plaintext = self
+            .cipher
+            .decrypt(Nonce::from_slice(nonce), ciphertext)
+            .map_err(|_| failed("wrong key or corrupted value"))?;
+        String::from_utf8(plaintext).map_err(|_| failed("value is not UTF-8"))
+    }
+
+    /// Encrypt every plaintext sensitive field in `doc`.
+    ///
+    /// Fields that are already sealed are left alone.
+    pub fn seal_fields(&self, doc: &mut toml::Value) {
+        for key in SENSITIVE_KEYS {
+            if let Some(toml::Value::String(value)) = field_mut(doc, key) {
+                if !is_sealed(value) {
+                    *value = self.encrypt(value);
+                }
+            }
+        }
+    }
+
+    /// Decrypt every sealed sensitive field in `doc`.
+    ///
+    /// # Errors
+    /// Returns `ConfigError::DecryptFailed` for the first field that cannot
+    /// be opened; `doc` may then be partially decrypted.
+    pub fn open_fields(&self, doc: &mut toml::Value) -> Result<(), ConfigError> {
+        for key in SENSITIVE_KEYS {
+            if let Some(toml::Value::String(value)) = field_mut(doc, key) {
+                if is_sealed(value) {
+                    *value = self.decrypt(key, value)?;
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Whether `value` was produced by `FieldCipher::encrypt`.
+pub fn is_sealed(value: &str) -> bool {
+    value.starts_with(SEALED_PREFIX)
+}
+
+/// Whether any sensitive field in `doc` is sealed.
+pub fn has_sealed_fields(doc: &toml::Value) -> bool {
+    SENSITIVE_KEYS.iter().any(|key| {
+        let mut value = Some(doc);
+        for part in key.split('.') {
+            value = value.and_then(|v| v.get(part));
+        }
+        matches!(value, Some(toml::Value::String(s)) if is_sealed(s))
+    })
+}
+
+fn field_mut<'a>(doc: &'a mut toml::Value, key: &str) -> Option<&'a mut toml::Value> {
+    key.split('.')
+        .try_fold(doc, |value, part| value.get_mut(part))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::storage::Config;
+
+    #[test]
+    fn sensitive_field_is_unreadable_on_disk_but_round_trips() {
+        let cipher = FieldCipher::from_secret(b"test secret");
+        let mut config = Config {
+            encrypt_sensitive_fields: true,
+            ..Config::default()
+        };
+        config.notifications.custom_sound = Some("/home/alice/sounds/bell.wav".to_string());
+
+        let raw = config.to_stored_toml(Some(&cipher)).unwrap();
+        assert!(!raw.contains("alice"));
+        assert!(raw.contains(SEALED_PREFIX));
+        // Other fields stay readable
+        assert!(raw.contains("focus_duration = 25"));
+
+        let loaded = Config::from_stored_toml(&raw, Some(&cipher)).unwrap();
+        assert_eq!(
+            loaded.notifications.custom_sound.as_deref(),
+            Some("/home/alice/sounds/bell.wav")
+        );
+
+        // Sealing is idempotent, so re-saving a loaded file keeps one layer
+        let mut doc: toml::Value = toml::from_str(&raw).unwrap();
+        cipher.seal_fields(&mut doc);
+        cipher.open_fields(&mut doc).unwrap();
+        assert!(!has_sealed_fields(&doc));
+    }
+
+    #[test]
+    fn sealed_field_fails_closed_without_the_right_key() {
+        let cipher = FieldCipher::from_secret(b"test secret");
+        let mut config = Config {
+            encrypt_sensitive_fields: true,
+            ..Config::default()
+        };
+        config.notifications.custom_sound = Some("bell.wav".to_string());
+        let raw = config.to_stored_toml(Some(&cipher)).unwrap();
+
+        let other = FieldCipher::from_secret(b"other secret");
+        assert!(Config::from_stored_toml(&raw, Some(&other)).is_err());
+        assert!(Config::from_stored_toml(&raw, None).is_err());
+
+        // Saving with encryption on but no key refuses to write plaintext
+        assert!(config.to_stored_toml(None).is_err());
+
+        // Existing plaintext is sealed on the first save after opting in
+        let plain = toml::to_string_pretty(&config).unwrap();
+        assert!(plain.contains("bell.wav"));
+        let loaded = Config::from_stored_toml(&plain, None).unwrap();
+        assert!(!loaded
+            .to_stored_toml(Some(&cipher))
+            .unwrap()
+            .contains("bell.wav"));
+    }
+}
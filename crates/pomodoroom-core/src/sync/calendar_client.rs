@@ -1,5 +1,8 @@
 //! Google Calendar API client for sync operations.
 
+use crate::sync::calendar_dedup::{
+    choose_primary, consolidate, Consolidation, PomodoroomCalendar, ARCHIVED_CALENDAR_SUMMARY,
+};
 use crate::sync::types::{SyncEvent, SyncError, SyncEventType};
 use crate::integrations::google::GoogleIntegration;
 use crate::integrations::traits::Integration;
@@ -46,23 +49,14 @@ impl CalendarClient {
             .map_err(|e| SyncError::CalendarApi(e.to_string()))?;
 
         // List calendars to find Pomodoroom
-        let calendars: serde_json::Value = tokio::runtime::Handle::current()
-            .block_on(async {
-                reqwest::Client::new()
-                    .get("https://www.googleapis.com/calendar/v3/users/me/calendarList")
-                    .bearer_auth(&token)
-                    .send()
-                    .await?
-                    .json()
-                    .await
-            })?;
-
-        if let Some(items) = calendars["items"].as_array() {
-            for cal in items {
-                if cal["summary"].as_str() == Some("Pomodoroom") {
-                    return Ok(cal["id"].as_str().map(|s| s.to_string()));
-                }
-            }
+        let ids = find_pomodoroom_calendars_in_list(&self.list_calendars()?);
+        if ids.len() > 1 {
+            // Duplicates: pick the same primary every device would pick
+            let calendars = self.load_calendars(&ids)?;
+            return Ok(choose_primary(calendars).map(|(primary, _)| primary.id));
+        }
+        if let Some(id) = ids.into_iter().next() {
+            return Ok(Some(id));
         }
 
         // Not found - create new calendar
@@ -88,6 +82,15 @@ impl CalendarClient {
     ) -> Result<Vec<serde_json::Value>, SyncError> {
         let calendar_id = self.calendar_id.as_ref()
             .ok_or(SyncError::CalendarNotFound)?;
+        self.fetch_events_from(calendar_id, since)
+    }
+
+    /// Fetch events of a specific calendar.
+    fn fetch_events_from(
+        &self,
+        calendar_id: &str,
+        since: Option<DateTime<Utc>>,
+    ) -> Result<Vec<serde_json::Value>, SyncError> {
         let token = self.google.access_token()
             .map_err(|e| SyncError::CalendarApi(e.to_string()))?;
 
@@ -143,6 +146,85 @@ impl CalendarClient {
         Ok(())
     }
 
+    /// Merge duplicate Pomodoroom calendars into one.
+    ///
+    /// Moves events from the extra calendars into the primary chosen by
+    /// `choose_primary`, then renames the extras to
+    /// `ARCHIVED_CALENDAR_SUMMARY` so they are no longer picked up. The
+    /// client uses the primary calendar afterwards.
+    pub fn consolidate_calendars(&mut self) -> Result<Consolidation, SyncError> {
+        if !self.google.is_authenticated() {
+            return Err(SyncError::AuthenticationRequired);
+        }
+
+        let ids = find_pomodoroom_calendars_in_list(&self.list_calendars()?);
+        let (primary, others) = choose_primary(self.load_calendars(&ids)?)
+            .ok_or(SyncError::CalendarNotFound)?;
+        let consolidation = consolidate(&primary, &others);
+
+        self.calendar_id = Some(primary.id);
+        self.batch_upsert(&consolidation.moved)?;
+        for id in &consolidation.archive {
+            self.archive_calendar(id)?;
+        }
+
+        Ok(consolidation)
+    }
+
+    /// List all calendars of the user.
+    fn list_calendars(&self) -> Result<Vec<serde_json::Value>, SyncError> {
+        let token = self.google.access_token()
+            .map_err(|e| SyncError::CalendarApi(e.to_string()))?;
+
+        let calendars: serde_json::Value = tokio::runtime::Handle::current()
+            .block_on(async {
+                reqwest::Client::new()
+                    .get("https://www.googleapis.com/calendar/v3/users/me/calendarList")
+                    .bearer_auth(&token)
+                    .send()
+                    .await?
+                    .json()
+                    .await
+            })?;
+
+        Ok(calendars["items"].as_array().cloned().unwrap_or_default())
+    }
+
+    /// Fetch all events of each calendar.
+    fn load_calendars(&self, ids: &[String]) -> Result<Vec<PomodoroomCalendar>, SyncError> {
+        ids.iter()
+            .map(|id| {
+                Ok(PomodoroomCalendar {
+                    id: id.clone(),
+                    events: self.fetch_events_from(id, None)?,
+                })
+            })
+            .collect()
+    }
+
+    /// Rename a calendar so it is no longer treated as a Pomodoroom calendar.
+    fn archive_calendar(&self, calendar_id: &str) -> Result<(), SyncError> {
+        let token = self.google.access_token()
+            .map_err(|e| SyncError::CalendarApi(e.to_string()))?;
+
+        let url = format!(
+            "https://www.googleapis.com/calendar/v3/calendars/{}",
+            calendar_id
+        );
+
+        tokio::runtime::Handle::current().block_on(async {
+            reqwest::Client::new()
+                .patch(&url)
+                .bearer_auth(&token)
+                .json(&json!({"summary": ARCHIVED_CALENDAR_SUMMARY}))
+                .send()
+                .await?
+                .error_for_status()
+                .map(|_| ())
+                .map_err(|e| SyncError::CalendarApi(e.to_string()))
+        })
+    }
+
     /// Upsert single event.
     fn upsert_event(&self, event: &serde_json::Value) -> Result<(), SyncError> {
         let calendar_id = self.calendar_id.as_ref()
@@ -200,6 +282,38 @@ impl Default for CalendarClient {
     }
 }
 
+/// Remote calendar operations used by the sync engine.
+pub trait RemoteCalendar {
+    /// Ensure the Pomodoroom calendar exists, returning its ID.
+    fn ensure_pomodoroom_calendar(&mut self) -> Result<String, SyncError>;
+
+    /// Fetch raw calendar events, optionally only those since a timestamp.
+    fn fetch_events(
+        &self,
+        since: Option<DateTime<Utc>>,
+    ) -> Result<Vec<serde_json::Value>, SyncError>;
+
+    /// Create, update or cancel (for deleted events) calendar events.
+    fn batch_upsert(&self, events: &[SyncEvent]) -> Result<(), SyncError>;
+}
+
+impl RemoteCalendar for CalendarClient {
+    fn ensure_pomodoroom_calendar(&mut self) -> Result<String, SyncError> {
+        CalendarClient::ensure_pomodoroom_calendar(self)
+    }
+
+    fn fetch_events(
+        &self,
+        since: Option<DateTime<Utc>>,
+    ) -> Result<Vec<serde_json::Value>, SyncError> {
+        CalendarClient::fetch_events(self, since)
+    }
+
+    fn batch_upsert(&self, events: &[SyncEvent]) -> Result<(), SyncError> {
+        CalendarClient::batch_upsert(self, events)
+    }
+}
+
 /// Convert SyncEvent to Google Calendar event format.
 ///
 /// Follows integrator.md §3.2-3.4 specification:
@@ -336,11 +450,19 @@ fn build_extended_properties(event: &SyncEvent) -> Result<serde_json::Value, Syn
 pub fn find_pomodoroom_calendar_in_list(
     calendars: &[serde_json::Value],
 ) -> Option<String> {
+    find_pomodoroom_calendars_in_list(calendars).into_iter().next()
+}
+
+/// Find every Pomodoroom calendar in a list of calendars.
+///
+/// More than one result means duplicates; see `CalendarClient::consolidate_calendars`.
+pub fn find_pomodoroom_calendars_in_list(calendars: &[serde_json::Value]) -> Vec<String> {
     calendars
         .iter()
-        .find(|c| c["summary"].as_str() == Some("Pomodoroom"))
-        .and_then(|c| c["id"].as_str())
+        .filter(|c| c["summary"].as_str() == Some("Pomodoroom"))
+        .filter_map(|c| c["id"].as_str())
         .map(|s| s.to_string())
+        .collect()
 }
 
 #[cfg(test)]
@@ -0,0 +1,135 @@
+//! Recovery from duplicate Pomodoroom calendars.
+//!
+//! Setting up sync on several devices at once can create more than one
+//! "Pomodoroom" calendar, splitting state between them. This module picks one
+//! calendar as the primary and computes the events to migrate into it from
+//! the extras, which are then marked for archival rather than deleted.
+
+use std::cmp::Reverse;
+use std::collections::HashMap;
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::sync::sync_engine::parse_gcal_event;
+use crate::sync::types::{SyncEvent, SyncEventType};
+
+/// Summary given to an extra calendar once its events have been migrated.
+pub const ARCHIVED_CALENDAR_SUMMARY: &str = "Pomodoroom (archived)";
+
+/// A Pomodoroom calendar and its raw Google Calendar events.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PomodoroomCalendar {
+    /// Google Calendar ID
+    pub id: String,
+    /// Raw event resources as returned by the events list endpoint
+    pub events: Vec<serde_json::Value>,
+}
+
+impl PomodoroomCalendar {
+    /// Number of Pomodoroom events, ignoring unrelated entries.
+    pub fn event_count(&self) -> usize {
+        self.events
+            .iter()
+            .filter(|event| parse_gcal_event(event).is_ok())
+            .count()
+    }
+
+    /// Earliest event creation time, used as the calendar's age.
+    ///
+    /// The calendar list does not report creation times, so the oldest event
+    /// stands in for it. `None` for a calendar without dated events.
+    pub fn created_at(&self) -> Option<DateTime<Utc>> {
+        self.events
+            .iter()
+            .filter_map(|event| event["created"].as_str())
+            .filter_map(|created| DateTime::parse_from_rfc3339(created).ok())
+            .map(|created| created.with_timezone(&Utc))
+            .min()
+    }
+}
+
+/// Result of merging duplicate calendars into a primary.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Consolidation {
+    /// Calendar that keeps all events
+    pub primary_id: String,
+    /// Events to upsert into the primary calendar
+    pub moved: Vec<SyncEvent>,
+    /// Extra calendars to archive once `moved` has been written
+    pub archive: Vec<String>,
+}
+
+impl Consolidation {
+    /// Whether there was only one calendar to begin with.
+    pub fn is_noop(&self) -> bool {
+        self.moved.is_empty() && self.archive.is_empty()
+    }
+}
+
+/// Split calendars into a primary and the extras.
+///
+/// The primary is the calendar with the most Pomodoroom events; ties go to the oldest
+/// calendar, then to the smallest ID, so every device picks the same one.
+pub fn choose_primary(
+    mut calendars: Vec<PomodoroomCalendar>,
+) -> Option<(PomodoroomCalendar, Vec<PomodoroomCalendar>)> {
+    calendars.sort_by_cached_key(|calendar| {
+        let created_at = calendar.created_at();
+        // Calendars without a known age sort after dated ones
+        (
+            Reverse(calendar.event_count()),
+            created_at.is_none(),
+            created_at,
+            calendar.id.clone(),
+        )
+    });
+    let mut calendars = calendars.into_iter();
+    let primary = calendars.next()?;
+    Some((primary, calendars.collect()))
+}
+
+/// Compute the events to migrate from `others` into `primary`.
+///
+/// An event is moved when the primary has no copy of the entity or holds an
+/// older one; when several extras carry the same entity the newest wins.
+/// Tombstones are moved like any other event so deletions are not lost.
+/// Events that are not Pomodoroom events are left behind.
+pub fn consolidate(primary: &PomodoroomCalendar, others: &[PomodoroomCalendar]) -> Consolidation {
+    let in_primary: HashMap<(SyncEventType, String), DateTime<Utc>> = primary
+        .events
+        .iter()
+        .filter_map(|event| parse_gcal_event(event).ok())
+        .map(|event| ((event.event_type, event.id), event.updated_at))
+        .collect();
+
+    let mut moved: Vec<SyncEvent> = Vec::new();
+    let mut index: HashMap<(SyncEventType, String), usize> = HashMap::new();
+    for event in others
+        .iter()
+        .flat_map(|calendar| &calendar.events)
+        .filter_map(|event| parse_gcal_event(event).ok())
+    {
+        let key = (event.event_type, event.id.clone());
+        if in_primary
+            .get(&key)
+            .is_some_and(|updated_at| *updated_at >= event.updated_at)
+        {
+            continue;
+        }
+        match index.get(&key) {
+            Some(&i) if moved[i].updated_at >= event.updated_at => {}
+            Some(&i) => moved[i] = event,
+            None => {
+                index.insert(key, moved.len());
+                moved.push(event);
+            }
+        }
+    }
+
+    Consolidation {
+        primary_id: primary.id.clone(),
+        moved,
+        archive: others.iter().map(|calendar| calendar.id.clone()).collect(),
+    }
+}
@@ -0,0 +1,116 @@
+//! Tests for calendar_dedup module.
+
+#[cfg(test)]
+mod tests {
+    use super::super::calendar_dedup::*;
+    use crate::sync::calendar_client::{find_pomodoroom_calendars_in_list, to_gcal_event};
+    use crate::sync::types::{SyncEvent, SyncEventType};
+    use chrono::{DateTime, Duration, TimeZone, Utc};
+
+    fn base_time() -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(2025, 3, 1, 12, 0, 0).unwrap()
+    }
+
+    /// Raw calendar event for a task, created at `created`.
+    fn gcal_task(id: &str, updated_at: DateTime<Utc>, created: DateTime<Utc>) -> serde_json::Value {
+        let event = SyncEvent {
+            id: id.to_string(),
+            event_type: SyncEventType::Task,
+            data: serde_json::json!({ "id": id, "title": id }),
+            updated_at,
+            deleted: false,
+        };
+        let mut gcal = to_gcal_event(&event, "Pomodoroom").unwrap();
+        gcal["created"] = serde_json::json!(created.to_rfc3339());
+        gcal
+    }
+
+    #[test]
+    fn test_find_all_pomodoroom_calendars() {
+        let calendars = vec![
+            serde_json::json!({"id": "cal1", "summary": "Pomodoroom"}),
+            serde_json::json!({"id": "cal2", "summary": "Personal"}),
+            serde_json::json!({"id": "cal3", "summary": "Pomodoroom"}),
+        ];
+        assert_eq!(
+            find_pomodoroom_calendars_in_list(&calendars),
+            vec!["cal1".to_string(), "cal3".to_string()]
+        );
+    }
+
+    #[test]
+    fn test_choose_primary_is_deterministic() {
+        let t = base_time();
+        let older = PomodoroomCalendar {
+            id: "b".to_string(),
+            events: vec![gcal_task("task-1", t, t)],
+        };
+        let newer = PomodoroomCalendar {
+            id: "a".to_string(),
+            events: vec![gcal_task("task-2", t, t + Duration::days(1))],
+        };
+        let larger = PomodoroomCalendar {
+            id: "c".to_string(),
+            events: vec![
+                gcal_task("task-3", t, t + Duration::days(2)),
+                gcal_task("task-4", t, t + Duration::days(2)),
+            ],
+        };
+
+        // Most events wins, regardless of input order
+        let (primary, others) =
+            choose_primary(vec![older.clone(), larger.clone(), newer.clone()]).unwrap();
+        assert_eq!(primary.id, "c");
+        assert_eq!(others.len(), 2);
+
+        // Equal size: the oldest calendar wins over the smaller ID
+        let (primary, _) = choose_primary(vec![newer.clone(), older.clone()]).unwrap();
+        assert_eq!(primary.id, "b");
+        let (primary, _) = choose_primary(vec![older, newer]).unwrap();
+        assert_eq!(primary.id, "b");
+
+        assert!(choose_primary(Vec::new()).is_none());
+    }
+
+    #[test]
+    fn test_consolidate_migrates_events_into_primary() {
+        let t = base_time();
+        let first = PomodoroomCalendar {
+            id: "first".to_string(),
+            events: vec![
+                gcal_task("shared-stale", t + Duration::hours(1), t),
+                gcal_task("shared-fresh", t, t),
+                gcal_task("only-first", t, t),
+            ],
+        };
+        let second = PomodoroomCalendar {
+            id: "second".to_string(),
+            events: vec![
+                gcal_task("shared-stale", t, t + Duration::days(1)),
+                gcal_task(
+                    "shared-fresh",
+                    t + Duration::hours(2),
+                    t + Duration::days(1),
+                ),
+                gcal_task("only-second", t, t + Duration::days(1)),
+                // Not a Pomodoroom event
+                serde_json::json!({"summary": "Lunch"}),
+            ],
+        };
+
+        let (primary, others) = choose_primary(vec![second, first]).unwrap();
+        assert_eq!(primary.id, "first");
+
+        let result = consolidate(&primary, &others);
+        assert_eq!(result.primary_id, "first");
+        assert_eq!(result.archive, vec!["second".to_string()]);
+
+        let moved: Vec<&str> = result.moved.iter().map(|e| e.id.as_str()).collect();
+        assert_eq!(moved, vec!["shared-fresh", "only-second"]);
+        assert_eq!(result.moved[0].updated_at, t + Duration::hours(2));
+        assert!(!result.is_noop());
+
+        // A single calendar needs no consolidation
+        assert!(consolidate(&primary, &[]).is_noop());
+    }
+}
@@ -0,0 +1,385 @@
+//! Audit log of automatically resolved sync conflicts.
+//!
+//! Every field where the local and remote versions of an entity disagreed is
+//! recorded with both values and the side that won, so a user can review an
+//! automated merge and restore the losing value by hand. Entries are
+//! appended as JSON lines to `sync_conflicts.jsonl` in the data directory.
+
+use std::fs::OpenOptions;
+use std::io::{BufRead, BufReader, Write};
+use std::ops::RangeBounds;
+use std::path::PathBuf;
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::storage::{data_dir, SyncConfig};
+use crate::sync::sync_engine::MergeDecision;
+use crate::sync::types::{SyncEvent, SyncEventType};
+
+/// Placeholder written instead of a redacted value.
+pub const REDACTED: &str = "[redacted]";
+
+/// Bookkeeping fields that differ on every conflict and are not recorded.
+const IGNORED_FIELDS: &[&str] = &["updated_at"];
+
+/// Side whose value was kept for a field.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ConflictChoice {
+    Local,
+    Remote,
+    /// A combination of both values, e.g. a union of tags
+    Merged,
+}
+
+/// Rule that decided the conflict.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ConflictStrategy {
+    /// A deletion on either side wins over an edit
+    DeletionWins,
+    /// The version with the newer `updated_at` wins
+    NewerWins,
+    /// Fields were merged one by one
+    FieldMerge,
+}
+
+/// One field of one entity where local and remote disagreed.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ConflictRecord {
+    pub entity_id: String,
+    pub entity_type: SyncEventType,
+    /// Top-level field of the entity data, or `deleted`
+    pub field: String,
+    pub local_value: Value,
+    pub remote_value: Value,
+    pub chosen: ConflictChoice,
+    pub strategy: ConflictStrategy,
+    /// When the conflict was resolved.
+    pub at: DateTime<Utc>,
+}
+
+/// Field-level audit records for a resolved conflict.
+///
+/// `winner` is the event that was kept: `local`, `remote`, or the merged
+/// event. Fields whose kept value matches neither side are `Merged`.
+pub fn conflict_records(
+    local: &SyncEvent,
+    remote: &SyncEvent,
+    winner: &SyncEvent,
+    strategy: ConflictStrategy,
+    at: DateTime<Utc>,
+) -> Vec<ConflictRecord> {
+    let record = |field: &str, local_value: Value, remote_value: Value, kept: &Value| {
+        let chosen = if *kept == local_value {
+            ConflictChoice::Local
+        } else if *kept == remote_value {
+            ConflictChoice::Remote
+        } else {
+            ConflictChoice::Merged
+        };
+        ConflictRecord {
+            entity_id: local.id.clone(),
+            entity_type: local.event_type,
+            field: field.to_string(),
+            local_value,
+            remote_value,
+            chosen,
+            strategy,
+            at,
+        }
+    };
+
+    let mut records = Vec::new();
+    if local.deleted != remote.deleted {
+        records.push(record(
+            "deleted",
+            Value::Bool(local.deleted),
+            Value::Bool(remote.deleted),
+            &Value::Bool(winner.deleted),
+        ));
+    }
+    match (&local.data, &remote.data) {
+        (Value::Object(local_fields), Value::Object(remote_fields)) => {
+            let mut fields: Vec<&String> =
+                local_fields.keys().chain(remote_fields.keys()).collect();
+            fields.sort();
+            fields.dedup();
+            for field in fields {
+                if IGNORED_FIELDS.contains(&field.as_str()) {
+                    continue;
+                }
+                let local_value = local_fields.get(field).cloned().unwrap_or(Value::Null);
+                let remote_value = remote_fields.get(field).cloned().unwrap_or(Value::Null);
+                if local_value == remote_value {
+                    continue;
+                }
+                let kept = winner.data.get(field).cloned().unwrap_or(Value::Null);
+                records.push(record(field, local_value, remote_value, &kept));
+            }
+        }
+        (local_data, remote_data) if local_data != remote_data => {
+            records.push(record(
+                "data",
+                local_data.clone(),
+                remote_data.clone(),
+                &winner.data,
+            ));
+        }
+        _ => {}
+    }
+    records
+}
+
+/// Audit records for a `decide_merge` outcome; empty if it needs a user choice.
+pub fn records_for_decision(
+    local: &SyncEvent,
+    remote: &SyncEvent,
+    decision: &MergeDecision,
+    at: DateTime<Utc>,
+) -> Vec<ConflictRecord> {
+    let strategy = whole_event_strategy(local, remote);
+    match decision {
+        MergeDecision::UseLocal => conflict_records(local, remote, local, strategy, at),
+        MergeDecision::UseRemote => conflict_records(local, remote, remote, strategy, at),
+        MergeDecision::Merged(merged) => {
+            conflict_records(local, remote, merged, ConflictStrategy::FieldMerge, at)
+        }
+        MergeDecision::NeedsUserChoice => Vec::new(),
+    }
+}
+
+/// Strategy behind keeping one side's event whole.
+pub(crate) fn whole_event_strategy(local: &SyncEvent, remote: &SyncEvent) -> ConflictStrategy {
+    if local.deleted || remote.deleted {
+        ConflictStrategy::DeletionWins
+    } else {
+        ConflictStrategy::NewerWins
+    }
+}
+
+/// Append-only store of conflict records.
+#[derive(Debug, Clone)]
+pub struct ConflictLog {
+    path: PathBuf,
+    /// Longest value kept, in characters; longer strings are cut
+    max_value_chars: Option<usize>,
+    /// Fields whose values are never written
+    redacted_fields: Vec<String>,
+}
+
+impl ConflictLog {
+    /// Log in the data directory.
+    pub fn new() -> Self {
+        let dir = data_dir().unwrap_or_else(|_| PathBuf::from("."));
+        Self::new_with_path(dir.join("sync_conflicts.jsonl"))
+    }
+
+    /// Log at a specific path (for testing).
+    pub fn new_with_path(path: PathBuf) -> Self {
+        Self {
+            path,
+            max_value_chars: None,
+            redacted_fields: Vec::new(),
+        }
+    }
+
+    /// Apply the length limit and redacted fields from the `sync` config.
+    pub fn with_config(mut self, config: &SyncConfig) -> Self {
+        self.max_value_chars = config.conflict_log_max_value_chars;
+        self.with_redacted_fields(config.conflict_log_redacted_fields.iter().cloned())
+    }
+
+    /// Cut recorded values longer than `chars` characters.
+    ///
+    /// Strings are shortened and marked with a trailing `…`; other values
+    /// that serialize longer are stored as their shortened JSON text.
+    pub fn with_max_value_chars(mut self, chars: usize) -> Self {
+        self.max_value_chars = Some(chars);
+        self
+    }
+
+    /// Store `REDACTED` instead of the values of `fields`.
+    pub fn with_redacted_fields<I, S>(mut self, fields: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.redacted_fields = fields.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// Append records, applying the configured redaction.
+    ///
+    /// # Errors
+    /// Returns an error if the log file cannot be written.
+    pub fn append(&self, records: &[ConflictRecord]) -> std::io::Result<()> {
+        if records.is_empty() {
+            return Ok(());
+        }
+        if let Some(parent) = self.path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        let mut file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&self.path)?;
+        for record in records {
+            let record = self.redact(record.clone());
+            let line = serde_json::to_string(&record).map_err(std::io::Error::other)?;
+            writeln!(file, "{line}")?;
+        }
+        Ok(())
+    }
+
+    /// Records resolved within `range`, oldest first.
+    ///
+    /// Unreadable lines are skipped.
+    ///
+    /// # Errors
+    /// Returns an error if the log exists but cannot be read.
+    pub fn entries(
+        &self,
+        range: impl RangeBounds<DateTime<Utc>>,
+    ) -> std::io::Result<Vec<ConflictRecord>> {
+        let file = match std::fs::File::open(&self.path) {
+            Ok(file) => file,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(Vec::new()),
+            Err(e) => return Err(e),
+        };
+        let mut records = Vec::new();
+        for line in BufReader::new(file).lines() {
+            let line = line?;
+            if let Ok(record) = serde_json::from_str::<ConflictRecord>(&line) {
+                if range.contains(&record.at) {
+                    records.push(record);
+                }
+            }
+        }
+        records.sort_by_key(|r| r.at);
+        Ok(records)
+    }
+
+    fn redact(&self, mut record: ConflictRecord) -> ConflictRecord {
+        if self.redacted_fields.contains(&record.field) {
+            record.local_value = Value::from(REDACTED);
+            record.remote_value = Value::from(REDACTED);
+            return record;
+        }
+        if let Some(max) = self.max_value_chars {
+            record.local_value = truncate_value(record.local_value, max);
+            record.remote_value = truncate_value(record.remote_value, max);
+        }
+        record
+    }
+}
+
+impl Default for ConflictLog {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+fn truncate_value(value: Value, max: usize) -> Value {
+    let text = match value {
+        Value::String(text) => text,
+        other => {
+            let text = other.to_string();
+            if text.chars().count() <= max {
+                return other;
+            }
+            text
+        }
+    };
+    if text.chars().count() <= max {
+        return Value::String(text);
+    }
+    let mut cut: String = text.chars().take(max).collect();
+    cut.push('…');
+    Value::String(cut)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Duration;
+    use serde_json::json;
+
+    fn event(data: Value, updated_at: DateTime<Utc>) -> SyncEvent {
+        SyncEvent {
+            id: "task-1".to_string(),
+            event_type: SyncEventType::Task,
+            data,
+            updated_at,
+            deleted: false,
+        }
+    }
+
+    #[test]
+    fn redaction_and_range_filtering() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let log = ConflictLog::new_with_path(dir.path().join("conflicts.jsonl"))
+            .with_max_value_chars(5)
+            .with_redacted_fields(["description"]);
+        let now = Utc::now();
+        let local = event(
+            json!({"title": "Write report", "description": "secret"}),
+            now,
+        );
+        let remote = event(
+            json!({"title": "Write", "description": "other secret"}),
+            now + Duration::minutes(1),
+        );
+
+        let records = records_for_decision(&local, &remote, &MergeDecision::UseRemote, now);
+        assert_eq!(records.len(), 2);
+        log.append(&records).unwrap();
+
+        let stored = log.entries(..).unwrap();
+        let description = stored.iter().find(|r| r.field == "description").unwrap();
+        assert_eq!(description.local_value, json!(REDACTED));
+        let title = stored.iter().find(|r| r.field == "title").unwrap();
+        assert_eq!(title.local_value, json!("Write…"));
+        assert_eq!(title.remote_value, json!("Write"));
+        assert_eq!(title.chosen, ConflictChoice::Remote);
+        assert_eq!(title.strategy, ConflictStrategy::NewerWins);
+
+        assert!(log
+            .entries(now + Duration::seconds(1)..)
+            .unwrap()
+            .is_empty());
+        assert!(
+            records_for_decision(&local, &remote, &MergeDecision::NeedsUserChoice, now).is_empty()
+        );
+    }
+
+    #[test]
+    fn limits_and_redaction_come_from_sync_config() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let config: SyncConfig = toml::from_str(
+            "conflict_log_max_value_chars = 3\nconflict_log_redacted_fields = [\"notes\"]",
+        )
+        .unwrap();
+        let log =
+            ConflictLog::new_with_path(dir.path().join("conflicts.jsonl")).with_config(&config);
+        let now = Utc::now();
+        let local = event(json!({"title": "Write report", "notes": "secret"}), now);
+        let remote = event(json!({"title": "Read", "notes": "other"}), now);
+
+        log.append(&records_for_decision(
+            &local,
+            &remote,
+            &MergeDecision::UseLocal,
+            now,
+        ))
+        .unwrap();
+
+        let stored = log.entries(..).unwrap();
+        let notes = stored.iter().find(|r| r.field == "notes").unwrap();
+        assert_eq!(notes.remote_value, json!(REDACTED));
+        let title = stored.iter().find(|r| r.field == "title").unwrap();
+        assert_eq!(title.local_value, json!("Wri…"));
+    }
+}
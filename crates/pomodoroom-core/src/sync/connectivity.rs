@@ -0,0 +1,56 @@
+//! Connectivity detection for deferring sync while offline.
+
+use std::net::{TcpStream, ToSocketAddrs};
+use std::time::Duration;
+
+/// Host probed by `TcpProbe::default`.
+pub const DEFAULT_PROBE_HOST: &str = "www.googleapis.com:443";
+
+/// Reports whether the sync backend is reachable.
+pub trait ConnectivityProvider {
+    /// Whether the network is currently reachable.
+    fn is_online(&self) -> bool;
+}
+
+impl<F: Fn() -> bool> ConnectivityProvider for F {
+    fn is_online(&self) -> bool {
+        self()
+    }
+}
+
+/// Reachability probe that opens a TCP connection to a host.
+#[derive(Debug, Clone)]
+pub struct TcpProbe {
+    /// `host:port` to connect to.
+    pub host: String,
+    /// Connection timeout.
+    pub timeout: Duration,
+}
+
+impl TcpProbe {
+    /// Probe `host` (as `host:port`) with the given timeout.
+    pub fn new(host: impl Into<String>, timeout: Duration) -> Self {
+        Self {
+            host: host.into(),
+            timeout,
+        }
+    }
+}
+
+impl Default for TcpProbe {
+    fn default() -> Self {
+        Self::new(DEFAULT_PROBE_HOST, Duration::from_secs(3))
+    }
+}
+
+impl ConnectivityProvider for TcpProbe {
+    fn is_online(&self) -> bool {
+        let Ok(addrs) = self.host.to_socket_addrs() else {
+            // Name resolution failing is the usual offline symptom
+            return false;
+        };
+        addrs
+            .into_iter()
+            .any(|addr| TcpStream::connect_timeout(&addr, self.timeout).is_ok())
+    }
+}
@@ -4,6 +4,7 @@ use crate::sync::types::{SyncEvent, SyncEventType, SyncError};
 use crate::task::Task;
 use crate::schedule::{Project, Group, DailyTemplate};
 use crate::storage::database::SessionRecord;
+use crate::storage::schedule_db::TaskTombstone;
 
 // ============================================================================
 // Task Encoding/Decoding
@@ -39,6 +40,17 @@ pub fn task_deletion_event(task: &Task) -> SyncEvent {
     }
 }
 
+/// Create deletion event from a task tombstone.
+pub fn tombstone_deletion_event(tombstone: &TaskTombstone) -> SyncEvent {
+    SyncEvent {
+        id: tombstone.id.clone(),
+        event_type: SyncEventType::Task,
+        data: serde_json::json!({ "id": tombstone.id }),
+        updated_at: tombstone.deleted_at,
+        deleted: true,
+    }
+}
+
 // ============================================================================
 // Project Encoding/Decoding
 // ============================================================================
@@ -4,27 +4,39 @@
 //! All data is stored as calendar events in a dedicated "Pomodoroom" calendar.
 
 pub mod calendar_client;
+pub mod calendar_dedup;
+pub mod conflict_log;
 pub mod conflict_resolver;
+pub mod connectivity;
 pub mod device_id;
 pub mod event_codec;
 pub mod sync_engine;
+pub mod sync_plan;
 pub mod sync_queue;
 pub mod types;
 
 #[cfg(test)]
 mod calendar_client_tests;
 #[cfg(test)]
+mod calendar_dedup_tests;
+#[cfg(test)]
 mod conflict_resolver_tests;
 #[cfg(test)]
 mod event_codec_tests;
 #[cfg(test)]
+mod sync_plan_tests;
+#[cfg(test)]
 mod sync_queue_tests;
 #[cfg(test)]
 mod types_tests;
 
-pub use calendar_client::{CalendarClient, find_pomodoroom_calendar_in_list, to_gcal_event};
+pub use calendar_client::{CalendarClient, RemoteCalendar, find_pomodoroom_calendar_in_list, find_pomodoroom_calendars_in_list, to_gcal_event};
+pub use calendar_dedup::{ARCHIVED_CALENDAR_SUMMARY, Consolidation, PomodoroomCalendar, choose_primary, consolidate};
+pub use conflict_log::{ConflictChoice, ConflictLog, ConflictRecord, ConflictStrategy};
+pub use connectivity::{ConnectivityProvider, TcpProbe, DEFAULT_PROBE_HOST};
 pub use conflict_resolver::{MergeDecision as ConflictMergeDecision, merge_task_fields, merge_task_state, resolve_conflict};
 pub use device_id::{get_or_create_device_id, get_or_create_device_id_at, DeviceIdError};
 pub use sync_engine::{MergeDecision, SyncEngine, decide_merge, parse_gcal_event};
+pub use sync_plan::{ChangeKind, ChangeSide, PlannedChange, SyncConflict, SyncPlan, apply_local_changes, build_plan};
 pub use sync_queue::SyncQueue;
 pub use types::{SyncEvent, SyncEventType, SyncStatus, SyncError};
@@ -1,9 +1,16 @@
 //! Sync engine for bidirectional calendar synchronization.
 
 use crate::sync::types::{SyncEvent, SyncError, SyncStatus, SyncEventType};
-use crate::sync::calendar_client::CalendarClient;
+use crate::sync::calendar_client::{CalendarClient, RemoteCalendar};
+use crate::storage::Config;
+use crate::sync::conflict_log::{records_for_decision, ConflictLog, ConflictRecord};
+use crate::sync::conflict_resolver::{resolve_conflict, MergeDecision as ConflictMergeDecision};
+use crate::sync::connectivity::{ConnectivityProvider, TcpProbe};
+use crate::sync::sync_queue::SyncQueue;
 use crate::sync::event_codec::*;
+use crate::sync::sync_plan::{build_plan, ChangeSide, SyncPlan};
 use chrono::{DateTime, Utc, Duration};
+use std::ops::RangeBounds;
 use std::sync::{Arc, Mutex};
 
 /// Simple logging macro for sync engine (removes log crate dependency).
@@ -23,21 +30,196 @@ pub enum MergeDecision {
     NeedsUserChoice,
 }
 
+impl From<&ConflictMergeDecision> for MergeDecision {
+    fn from(decision: &ConflictMergeDecision) -> Self {
+        match decision {
+            ConflictMergeDecision::UseLocal => Self::UseLocal,
+            ConflictMergeDecision::UseRemote => Self::UseRemote,
+            ConflictMergeDecision::Merged(merged) => Self::Merged(merged.clone()),
+            ConflictMergeDecision::NeedsUserChoice => Self::NeedsUserChoice,
+        }
+    }
+}
+
+/// Maximum events uploaded per flush.
+pub const FLUSH_BATCH_SIZE: usize = 50;
+
 /// Sync engine managing bidirectional sync.
-pub struct SyncEngine {
-    client: CalendarClient,
+pub struct SyncEngine<C: RemoteCalendar = CalendarClient> {
+    client: C,
     last_sync_at: Arc<Mutex<Option<DateTime<Utc>>>>,
+    connectivity: Box<dyn ConnectivityProvider + Send + Sync>,
+    /// Flushes that failed in a row; offline flushes are not counted.
+    consecutive_failures: u32,
+    conflict_log: ConflictLog,
 }
 
 impl SyncEngine {
     /// Create new sync engine.
+    ///
+    /// The conflict log follows the `sync` section of the saved config.
     pub fn new() -> Self {
+        let config = Config::load_or_default();
+        Self::with_client(CalendarClient::new())
+            .with_conflict_log(ConflictLog::new().with_config(&config.sync))
+    }
+}
+
+impl<C: RemoteCalendar> SyncEngine<C> {
+    /// Create a sync engine backed by a specific calendar client.
+    pub fn with_client(client: C) -> Self {
         Self {
-            client: CalendarClient::new(),
+            client,
             last_sync_at: Arc::new(Mutex::new(None)),
+            connectivity: Box::new(TcpProbe::default()),
+            consecutive_failures: 0,
+            conflict_log: ConflictLog::new(),
         }
     }
 
+    /// Record resolved conflicts in `log` instead of the default file.
+    pub fn with_conflict_log(mut self, log: ConflictLog) -> Self {
+        self.conflict_log = log;
+        self
+    }
+
+    /// Resolved conflicts recorded within `range`, oldest first.
+    pub fn conflict_log(
+        &self,
+        range: impl RangeBounds<DateTime<Utc>>,
+    ) -> Result<Vec<ConflictRecord>, SyncError> {
+        Ok(self.conflict_log.entries(range)?)
+    }
+
+    /// Resolve a conflict with `resolve_conflict` and log the losing values.
+    pub fn resolve_conflict(
+        &self,
+        local: &SyncEvent,
+        remote: &SyncEvent,
+    ) -> Result<ConflictMergeDecision, SyncError> {
+        let decision = resolve_conflict(local, remote);
+        let records = records_for_decision(
+            local,
+            remote,
+            &MergeDecision::from(&decision),
+            Utc::now(),
+        );
+        self.conflict_log.append(&records)?;
+        Ok(decision)
+    }
+
+    /// Use `connectivity` to decide whether flushing can reach the network.
+    pub fn with_connectivity(
+        mut self,
+        connectivity: impl ConnectivityProvider + Send + Sync + 'static,
+    ) -> Self {
+        self.connectivity = Box::new(connectivity);
+        self
+    }
+
+    /// Flushes that failed in a row since the last success.
+    pub fn consecutive_failures(&self) -> u32 {
+        self.consecutive_failures
+    }
+
+    /// Upload queued local changes.
+    ///
+    /// While offline the queue is left untouched and an `offline` status is
+    /// returned without counting a failure. Online, ready events are uploaded
+    /// oldest first in batches of `FLUSH_BATCH_SIZE` until the queue has no
+    /// ready events left. A failed upload puts its batch back on the queue.
+    /// The queue is persisted after every online flush, so uploaded events
+    /// are not sent again after a restart.
+    pub fn flush(&mut self, queue: &mut SyncQueue) -> Result<SyncStatus, SyncError> {
+        if !self.connectivity.is_online() {
+            return Ok(SyncStatus {
+                last_sync_at: *self.last_sync_at.lock().unwrap(),
+                pending_count: queue.len(),
+                in_progress: false,
+                offline: true,
+            });
+        }
+
+        loop {
+            let batch = queue.drain_up_to(FLUSH_BATCH_SIZE);
+            if batch.is_empty() {
+                break;
+            }
+            let result = self
+                .client
+                .ensure_pomodoroom_calendar()
+                .and_then(|_| self.client.batch_upsert(&batch));
+            if let Err(err) = result {
+                queue.requeue(batch);
+                self.consecutive_failures += 1;
+                // Report the upload error; a failed write is retried next flush
+                let _ = queue.persist();
+                return Err(err);
+            }
+        }
+        queue.persist()?;
+
+        self.consecutive_failures = 0;
+        let now = Utc::now();
+        *self.last_sync_at.lock().unwrap() = Some(now);
+        Ok(SyncStatus {
+            last_sync_at: Some(now),
+            pending_count: queue.len(),
+            in_progress: false,
+            offline: false,
+        })
+    }
+
+    /// Calendar client backing this engine.
+    pub fn client(&self) -> &C {
+        &self.client
+    }
+
+    /// Compute the changes a sync would perform, without applying them.
+    ///
+    /// `local` holds the current local entities as sync events, including
+    /// tombstones for locally deleted ones. Remote events are only deleted
+    /// for such tombstones.
+    pub fn plan(&mut self, local: &[SyncEvent]) -> Result<SyncPlan, SyncError> {
+        self.client.ensure_pomodoroom_calendar()?;
+        let remote: Vec<SyncEvent> = self
+            .client
+            .fetch_events(None)?
+            .iter()
+            .filter_map(|event_json| parse_gcal_event(event_json).ok())
+            .collect();
+        Ok(build_plan(local, &remote, Utc::now()))
+    }
+
+    /// Apply a plan produced by `plan`.
+    ///
+    /// Only the remote side is written here; local changes are applied to the
+    /// database with [`apply_local_changes`](crate::sync::apply_local_changes).
+    /// Conflicts in the plan are left untouched. The plan's resolved fields
+    /// are written to the conflict log first.
+    pub fn apply(&mut self, plan: &SyncPlan) -> Result<SyncStatus, SyncError> {
+        self.client.ensure_pomodoroom_calendar()?;
+        self.conflict_log.append(&plan.resolved)?;
+
+        let remote_events: Vec<SyncEvent> = plan
+            .changes_for(ChangeSide::Remote)
+            .map(|change| change.event.clone())
+            .collect();
+        if !remote_events.is_empty() {
+            self.client.batch_upsert(&remote_events)?;
+        }
+
+        let now = Utc::now();
+        *self.last_sync_at.lock().unwrap() = Some(now);
+
+        Ok(SyncStatus {
+            last_sync_at: Some(now),
+            pending_count: plan.conflicts.len(),
+            in_progress: false,
+            offline: false,
+        })
+    }
+
     /// Perform initial sync on startup.
     pub fn startup_sync(&mut self) -> Result<SyncStatus, SyncError> {
         // Ensure calendar exists
@@ -65,6 +247,7 @@ impl SyncEngine {
             last_sync_at: Some(Utc::now()),
             pending_count: 0,
             in_progress: false,
+            offline: false,
         })
     }
 
@@ -111,6 +294,7 @@ impl SyncEngine {
             last_sync_at: *guard,
             pending_count: 0,
             in_progress: false,
+            offline: false,
         }
     }
 }
@@ -243,6 +427,124 @@ pub fn decide_merge(
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::sync::conflict_log::ConflictStrategy;
+    use crate::sync::types::SyncEventType;
+    use std::cell::{Cell, RefCell};
+    use std::sync::atomic::{AtomicBool, Ordering};
+
+    /// Calendar recording uploads, optionally failing them.
+    #[derive(Default)]
+    struct RecordingCalendar {
+        uploads: RefCell<Vec<String>>,
+        fail: Cell<bool>,
+    }
+
+    impl RemoteCalendar for RecordingCalendar {
+        fn ensure_pomodoroom_calendar(&mut self) -> Result<String, SyncError> {
+            Ok("fake".to_string())
+        }
+
+        fn fetch_events(
+            &self,
+            _since: Option<DateTime<Utc>>,
+        ) -> Result<Vec<serde_json::Value>, SyncError> {
+            Ok(Vec::new())
+        }
+
+        fn batch_upsert(&self, events: &[SyncEvent]) -> Result<(), SyncError> {
+            if self.fail.get() {
+                return Err(SyncError::RateLimited);
+            }
+            self.uploads
+                .borrow_mut()
+                .extend(events.iter().map(|e| e.id.clone()));
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_offline_flush_keeps_queue_and_retry_budget() {
+        static ONLINE: AtomicBool = AtomicBool::new(false);
+        let dir = tempfile::TempDir::new().unwrap();
+        let mut queue = SyncQueue::new_with_path(dir.path().join("queue.json"));
+        let start = Utc::now() - Duration::hours(1);
+        for (i, id) in ["b", "a", "c"].iter().enumerate() {
+            queue.enqueue_with_debounce(
+                SyncEvent {
+                    id: id.to_string(),
+                    event_type: SyncEventType::Task,
+                    data: serde_json::json!({}),
+                    updated_at: start + Duration::minutes(i as i64),
+                    deleted: false,
+                },
+                Duration::zero(),
+            );
+        }
+
+        let mut engine = SyncEngine::with_client(RecordingCalendar::default())
+            .with_connectivity(|| ONLINE.load(Ordering::SeqCst));
+        for _ in 0..3 {
+            let status = engine.flush(&mut queue).unwrap();
+            assert!(status.offline);
+            assert_eq!(status.pending_count, 3);
+        }
+        assert_eq!(engine.consecutive_failures(), 0);
+        assert!(engine.client().uploads.borrow().is_empty());
+
+        // A failing upload counts once and keeps the events queued
+        ONLINE.store(true, Ordering::SeqCst);
+        engine.client().fail.set(true);
+        assert!(engine.flush(&mut queue).is_err());
+        assert_eq!(engine.consecutive_failures(), 1);
+        assert_eq!(queue.len(), 3);
+        let mut persisted = SyncQueue::new_with_path(dir.path().join("queue.json"));
+        persisted.load().unwrap();
+        assert_eq!(persisted.len(), 3);
+
+        engine.client().fail.set(false);
+        let status = engine.flush(&mut queue).unwrap();
+        assert!(!status.offline);
+        assert_eq!(status.pending_count, 0);
+        assert_eq!(engine.consecutive_failures(), 0);
+        assert_eq!(*engine.client().uploads.borrow(), vec!["b", "a", "c"]);
+        persisted.load().unwrap();
+        assert!(persisted.is_empty());
+    }
+
+    #[test]
+    fn test_resolved_title_conflict_is_logged_with_both_values() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let engine = SyncEngine::with_client(RecordingCalendar::default())
+            .with_conflict_log(ConflictLog::new_with_path(dir.path().join("conflicts.jsonl")));
+        let now = Utc::now();
+        let local_task = crate::task::Task {
+            id: "task-1".to_string(),
+            title: "Draft report".to_string(),
+            updated_at: now - Duration::minutes(10),
+            ..Default::default()
+        };
+        let remote_task = crate::task::Task {
+            title: "Final report".to_string(),
+            updated_at: now,
+            ..local_task.clone()
+        };
+        let local = task_to_sync_event(&local_task).unwrap();
+        let remote = task_to_sync_event(&remote_task).unwrap();
+
+        let decision = engine.resolve_conflict(&local, &remote).unwrap();
+        assert!(matches!(decision, ConflictMergeDecision::Merged(_)));
+
+        let entries = engine.conflict_log(..).unwrap();
+        assert_eq!(entries.len(), 1);
+        let entry = &entries[0];
+        assert_eq!(entry.entity_id, "task-1");
+        assert_eq!(entry.field, "title");
+        assert_eq!(entry.local_value, serde_json::json!("Draft report"));
+        assert_eq!(entry.remote_value, serde_json::json!("Final report"));
+        assert_eq!(entry.chosen, crate::sync::ConflictChoice::Remote);
+        assert_eq!(entry.strategy, ConflictStrategy::FieldMerge);
+        assert!(engine.conflict_log(now + Duration::hours(1)..).unwrap().is_empty());
+    }
 
     #[test]
     fn test_decide_merge_local_newer() {
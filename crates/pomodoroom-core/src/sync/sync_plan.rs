@@ -0,0 +1,225 @@
+//! Dry-run sync planning.
+//!
+//! Compares local and remote events and lists the creates/updates/deletes a
+//! sync would perform, without touching either side. Remote events are only
+//! deleted for explicit local tombstones, never because an event is missing
+//! locally, so a diverged local database cannot wipe the calendar.
+
+use std::collections::{BTreeMap, HashMap};
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::storage::ScheduleDb;
+use crate::sync::conflict_log::{records_for_decision, ConflictRecord};
+use crate::sync::event_codec::{sync_event_to_project, sync_event_to_task};
+use crate::sync::sync_engine::{decide_merge, MergeDecision};
+use crate::sync::types::{SyncError, SyncEvent, SyncEventType};
+
+/// Side of the sync a planned change is applied to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ChangeSide {
+    /// Google Calendar
+    Remote,
+    /// Local database
+    Local,
+}
+
+/// Kind of a planned change.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ChangeKind {
+    Create,
+    Update,
+    Delete,
+}
+
+/// A single change a sync would perform.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PlannedChange {
+    /// Where the change is applied.
+    pub side: ChangeSide,
+    /// What kind of change it is.
+    pub kind: ChangeKind,
+    /// Event to write; `deleted` is set for deletions.
+    pub event: SyncEvent,
+}
+
+/// An entity whose local and remote versions need a manual decision.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SyncConflict {
+    pub local: SyncEvent,
+    pub remote: SyncEvent,
+}
+
+/// Reviewable set of changes produced by `SyncEngine::plan`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SyncPlan {
+    /// When the plan was computed.
+    pub generated_at: DateTime<Utc>,
+    /// Changes in a stable order (by event type, then id).
+    pub changes: Vec<PlannedChange>,
+    /// Entities that need a user choice; these are not applied.
+    pub conflicts: Vec<SyncConflict>,
+    /// Number of entities already identical on both sides.
+    pub unchanged: usize,
+    /// Fields where one side's value is overwritten, written to the
+    /// conflict log when the plan is applied.
+    #[serde(default)]
+    pub resolved: Vec<ConflictRecord>,
+}
+
+impl SyncPlan {
+    /// Whether applying the plan would change nothing.
+    pub fn is_empty(&self) -> bool {
+        self.changes.is_empty() && self.conflicts.is_empty()
+    }
+
+    /// Changes applied to one side.
+    pub fn changes_for(&self, side: ChangeSide) -> impl Iterator<Item = &PlannedChange> {
+        self.changes.iter().filter(move |c| c.side == side)
+    }
+
+    /// Number of changes of `kind` applied to `side`.
+    pub fn count(&self, side: ChangeSide, kind: ChangeKind) -> usize {
+        self.changes_for(side).filter(|c| c.kind == kind).count()
+    }
+}
+
+/// Local and remote versions of one entity.
+type EventPair<'a> = (Option<&'a SyncEvent>, Option<&'a SyncEvent>);
+
+/// Build a sync plan from local and remote events.
+///
+/// Entities are matched by type and id. For entities present on both sides,
+/// `decide_merge` picks the winner and the losing side receives the change.
+pub fn build_plan(local: &[SyncEvent], remote: &[SyncEvent], now: DateTime<Utc>) -> SyncPlan {
+    let remote_by_key: HashMap<(SyncEventType, &str), &SyncEvent> = remote
+        .iter()
+        .map(|event| ((event.event_type, event.id.as_str()), event))
+        .collect();
+
+    // Key by (type name, id) for a deterministic order
+    let mut pairs: BTreeMap<(String, String), EventPair> = BTreeMap::new();
+    for event in local {
+        let remote = remote_by_key
+            .get(&(event.event_type, event.id.as_str()))
+            .copied();
+        pairs.insert(sort_key(event), (Some(event), remote));
+    }
+    for event in remote {
+        pairs.entry(sort_key(event)).or_insert((None, Some(event)));
+    }
+
+    let mut plan = SyncPlan {
+        generated_at: now,
+        changes: Vec::new(),
+        conflicts: Vec::new(),
+        unchanged: 0,
+        resolved: Vec::new(),
+    };
+    for (local, remote) in pairs.into_values() {
+        plan_entity(&mut plan, local, remote, now);
+    }
+    plan
+}
+
+/// Write the plan's local-side task and project changes to `db`.
+///
+/// Returns the number of changes applied. Changes for other event types are
+/// left for the caller to report, since there is no local store for them yet.
+pub fn apply_local_changes(db: &ScheduleDb, plan: &SyncPlan) -> Result<usize, SyncError> {
+    let mut applied = 0;
+    for change in plan.changes_for(ChangeSide::Local) {
+        let event = &change.event;
+        match (event.event_type, change.kind) {
+            (SyncEventType::Task, ChangeKind::Delete) => db.delete_task(&event.id),
+            (SyncEventType::Task, _) => {
+                let task = sync_event_to_task(&event.id, &event.data)?;
+                match db.get_task(&task.id).map_err(db_error)? {
+                    Some(_) => db.update_task(&task),
+                    None => db.create_task(&task),
+                }
+            }
+            (SyncEventType::Project, ChangeKind::Delete) => db.delete_project(&event.id),
+            (SyncEventType::Project, _) => {
+                let project = sync_event_to_project(&event.id, &event.data)?;
+                match db.get_project(&project.id).map_err(db_error)? {
+                    Some(_) => db.update_project(&project),
+                    None => db.create_project(&project),
+                }
+            }
+            _ => continue,
+        }
+        .map_err(db_error)?;
+        applied += 1;
+    }
+    Ok(applied)
+}
+
+fn db_error(e: rusqlite::Error) -> SyncError {
+    SyncError::Generic(Box::new(e))
+}
+
+fn plan_entity(
+    plan: &mut SyncPlan,
+    local: Option<&SyncEvent>,
+    remote: Option<&SyncEvent>,
+    now: DateTime<Utc>,
+) {
+    let mut push = |side, kind, event: &SyncEvent| {
+        plan.changes.push(PlannedChange {
+            side,
+            kind,
+            event: event.clone(),
+        })
+    };
+
+    match (local, remote) {
+        (Some(local), None) if !local.deleted => {
+            push(ChangeSide::Remote, ChangeKind::Create, local)
+        }
+        (None, Some(remote)) if !remote.deleted => {
+            push(ChangeSide::Local, ChangeKind::Create, remote)
+        }
+        (Some(local), Some(remote)) => {
+            if local.deleted == remote.deleted && (local.deleted || local.data == remote.data) {
+                plan.unchanged += 1;
+                return;
+            }
+            let decision = decide_merge(
+                local.updated_at,
+                remote.updated_at,
+                local.deleted,
+                remote.deleted,
+            );
+            plan.resolved
+                .extend(records_for_decision(local, remote, &decision, now));
+            match decision {
+                MergeDecision::UseLocal if local.deleted => {
+                    push(ChangeSide::Remote, ChangeKind::Delete, local)
+                }
+                MergeDecision::UseLocal => push(ChangeSide::Remote, ChangeKind::Update, local),
+                MergeDecision::UseRemote if remote.deleted => {
+                    push(ChangeSide::Local, ChangeKind::Delete, remote)
+                }
+                MergeDecision::UseRemote => push(ChangeSide::Local, ChangeKind::Update, remote),
+                MergeDecision::Merged(merged) => {
+                    push(ChangeSide::Remote, ChangeKind::Update, &merged);
+                    push(ChangeSide::Local, ChangeKind::Update, &merged);
+                }
+                MergeDecision::NeedsUserChoice => plan.conflicts.push(SyncConflict {
+                    local: local.clone(),
+                    remote: remote.clone(),
+                }),
+            }
+        }
+        // Tombstones with no counterpart are already in sync
+        _ => plan.unchanged += 1,
+    }
+}
+
+fn sort_key(event: &SyncEvent) -> (String, String) {
+    (format!("{:?}", event.event_type), event.id.clone())
+}
@@ -0,0 +1,202 @@
+//! Tests for sync_plan module.
+
+#[cfg(test)]
+mod tests {
+    use super::super::sync_plan::*;
+    use crate::storage::ScheduleDb;
+    use crate::sync::calendar_client::{to_gcal_event, RemoteCalendar};
+    use crate::sync::event_codec::{task_to_sync_event, tombstone_deletion_event};
+    use crate::sync::sync_engine::SyncEngine;
+    use crate::sync::types::{SyncError, SyncEvent, SyncEventType};
+    use crate::task::Task;
+    use chrono::{DateTime, Duration, TimeZone, Utc};
+    use std::cell::RefCell;
+
+    /// In-memory calendar recording every upsert.
+    #[derive(Default)]
+    struct FakeCalendar {
+        events: Vec<serde_json::Value>,
+        upserts: RefCell<Vec<SyncEvent>>,
+    }
+
+    impl RemoteCalendar for FakeCalendar {
+        fn ensure_pomodoroom_calendar(&mut self) -> Result<String, SyncError> {
+            Ok("fake".to_string())
+        }
+
+        fn fetch_events(
+            &self,
+            _since: Option<DateTime<Utc>>,
+        ) -> Result<Vec<serde_json::Value>, SyncError> {
+            Ok(self.events.clone())
+        }
+
+        fn batch_upsert(&self, events: &[SyncEvent]) -> Result<(), SyncError> {
+            self.upserts.borrow_mut().extend_from_slice(events);
+            Ok(())
+        }
+    }
+
+    fn base_time() -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(2025, 3, 1, 12, 0, 0).unwrap()
+    }
+
+    fn task(id: &str, title: &str, updated_at: DateTime<Utc>, deleted: bool) -> SyncEvent {
+        SyncEvent {
+            id: id.to_string(),
+            event_type: SyncEventType::Task,
+            data: serde_json::json!({ "id": id, "title": title }),
+            updated_at,
+            deleted,
+        }
+    }
+
+    #[test]
+    fn test_build_plan_classifies_changes() {
+        let t = base_time();
+        let local = vec![
+            task("new-local", "A", t, false),
+            task("same", "B", t, false),
+            task("edited-local", "C2", t + Duration::hours(1), false),
+            task("edited-remote", "D", t, false),
+        ];
+        let remote = vec![
+            task("new-remote", "E", t, false),
+            task("same", "B", t, false),
+            task("edited-local", "C", t, false),
+            task("edited-remote", "D2", t + Duration::hours(1), false),
+            task("gone", "F", t, true),
+        ];
+
+        let plan = build_plan(&local, &remote, t);
+
+        assert_eq!(plan.count(ChangeSide::Remote, ChangeKind::Create), 1);
+        assert_eq!(plan.count(ChangeSide::Remote, ChangeKind::Update), 1);
+        assert_eq!(plan.count(ChangeSide::Local, ChangeKind::Create), 1);
+        assert_eq!(plan.count(ChangeSide::Local, ChangeKind::Update), 1);
+        assert_eq!(plan.count(ChangeSide::Remote, ChangeKind::Delete), 0);
+        assert_eq!(plan.unchanged, 2);
+        assert!(plan.conflicts.is_empty());
+
+        // Missing locally is pulled, never deleted remotely
+        let pulled = plan.changes_for(ChangeSide::Local).next().unwrap();
+        assert_eq!(pulled.event.id, "edited-remote");
+        assert!(build_plan(&[], &remote, t)
+            .changes
+            .iter()
+            .all(|c| c.kind != ChangeKind::Delete));
+    }
+
+    #[test]
+    fn test_local_delete_is_planned_but_not_executed_until_apply() {
+        let t = base_time();
+        let remote_task = task("task-1", "Write report", t, false);
+        let calendar = FakeCalendar {
+            events: vec![to_gcal_event(&remote_task, "fake").unwrap()],
+            ..FakeCalendar::default()
+        };
+        let mut engine = SyncEngine::with_client(calendar);
+        let local = vec![task(
+            "task-1",
+            "Write report",
+            t + Duration::minutes(5),
+            true,
+        )];
+
+        let plan = engine.plan(&local).unwrap();
+        assert_eq!(plan.changes.len(), 1);
+        let change = &plan.changes[0];
+        assert_eq!(change.side, ChangeSide::Remote);
+        assert_eq!(change.kind, ChangeKind::Delete);
+        assert_eq!(change.event.id, "task-1");
+        assert!(change.event.deleted);
+
+        // Planning did not touch the remote calendar or the sync status
+        assert!(engine.client().upserts.borrow().is_empty());
+        assert!(engine.status().last_sync_at.is_none());
+
+        let status = engine.apply(&plan).unwrap();
+        assert!(status.last_sync_at.is_some());
+        let upserts = engine.client().upserts.borrow();
+        assert_eq!(upserts.len(), 1);
+        assert_eq!(upserts[0].id, "task-1");
+        assert!(upserts[0].deleted);
+    }
+
+    #[test]
+    fn test_deleted_task_does_not_reappear_after_sync() {
+        let db = ScheduleDb::open_memory().unwrap();
+        let task = Task::new("Write report");
+        db.create_task(&task).unwrap();
+
+        // The task was synced earlier, so remote still has it
+        let synced = task_to_sync_event(&task).unwrap();
+        let calendar = FakeCalendar {
+            events: vec![to_gcal_event(&synced, "fake").unwrap()],
+            ..FakeCalendar::default()
+        };
+        let mut engine = SyncEngine::with_client(calendar);
+
+        db.delete_task(&task.id).unwrap();
+        let local: Vec<SyncEvent> = db
+            .list_task_tombstones()
+            .unwrap()
+            .iter()
+            .map(tombstone_deletion_event)
+            .collect();
+
+        let plan = engine.plan(&local).unwrap();
+        assert_eq!(plan.count(ChangeSide::Local, ChangeKind::Create), 0);
+        assert_eq!(plan.count(ChangeSide::Remote, ChangeKind::Delete), 1);
+        engine.apply(&plan).unwrap();
+        assert!(engine.client().upserts.borrow()[0].deleted);
+        assert!(db.list_tasks().unwrap().is_empty());
+
+        // Without the tombstone the remote copy would be pulled back in
+        let plan = engine.plan(&[]).unwrap();
+        assert_eq!(plan.count(ChangeSide::Local, ChangeKind::Create), 1);
+    }
+
+    #[test]
+    fn test_apply_local_changes_writes_pulled_tasks() {
+        let db = ScheduleDb::open_memory().unwrap();
+        let existing = Task::new("Old title");
+        db.create_task(&existing).unwrap();
+        let doomed = Task::new("Removed remotely");
+        db.create_task(&doomed).unwrap();
+
+        let t = base_time();
+        let mut renamed = existing.clone();
+        renamed.title = "New title".to_string();
+        let pulled = Task::new("Created remotely");
+        let change = |kind, task: &Task, deleted| PlannedChange {
+            side: ChangeSide::Local,
+            kind,
+            event: SyncEvent {
+                deleted,
+                ..task_to_sync_event(task).unwrap()
+            },
+        };
+        let plan = SyncPlan {
+            generated_at: t,
+            changes: vec![
+                change(ChangeKind::Update, &renamed, false),
+                change(ChangeKind::Create, &pulled, false),
+                change(ChangeKind::Delete, &doomed, true),
+                PlannedChange {
+                    side: ChangeSide::Remote,
+                    ..change(ChangeKind::Create, &Task::new("Remote only"), false)
+                },
+            ],
+            conflicts: Vec::new(),
+            unchanged: 0,
+            resolved: Vec::new(),
+        };
+
+        assert_eq!(apply_local_changes(&db, &plan).unwrap(), 3);
+        let titles: Vec<String> = db.list_tasks().unwrap().into_iter().map(|t| t.title).collect();
+        assert_eq!(titles.len(), 2);
+        assert!(titles.contains(&"New title".to_string()));
+        assert!(titles.contains(&"Created remotely".to_string()));
+    }
+}
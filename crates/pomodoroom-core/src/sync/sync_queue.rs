@@ -49,7 +49,12 @@ impl SyncQueue {
 
     /// Enqueue an event for sync (with debounce).
     pub fn enqueue(&mut self, event: SyncEvent) {
-        let debounce_until = Utc::now() + Duration::seconds(3);
+        self.enqueue_with_debounce(event, Duration::seconds(3));
+    }
+
+    /// Enqueue an event that becomes ready after `debounce`.
+    pub fn enqueue_with_debounce(&mut self, event: SyncEvent, debounce: Duration) {
+        let debounce_until = Utc::now() + debounce;
         self.pending.insert(
             event.id.clone(),
             PendingEvent {
@@ -62,22 +67,42 @@ impl SyncQueue {
         self.update_next_process();
     }
 
-    /// Drain up to n events ready for sync.
+    /// Drain up to n events ready for sync, oldest change first.
     pub fn drain_up_to(&mut self, n: usize) -> Vec<SyncEvent> {
         let now = Utc::now();
-        let mut ready = Vec::new();
+        let mut ready: Vec<(DateTime<Utc>, String)> = self
+            .pending
+            .values()
+            .filter(|pending| pending.debounce_until <= now)
+            .map(|pending| (pending.event.updated_at, pending.event.id.clone()))
+            .collect();
+        ready.sort();
+        ready.truncate(n);
+
+        let drained = ready
+            .into_iter()
+            .filter_map(|(_, id)| self.pending.remove(&id))
+            .map(|pending| pending.event)
+            .collect();
 
-        self.pending.retain(|_, pending| {
-            if pending.debounce_until <= now && ready.len() < n {
-                ready.push(pending.event.clone());
-                false // Remove from pending
-            } else {
-                true // Keep in pending
-            }
-        });
+        self.update_next_process();
+        drained
+    }
 
+    /// Put back events whose upload failed, ready for the next flush.
+    ///
+    /// An event enqueued again in the meantime is newer and wins.
+    pub fn requeue(&mut self, events: Vec<SyncEvent>) {
+        let now = Utc::now();
+        for event in events {
+            self.pending
+                .entry(event.id.clone())
+                .or_insert(PendingEvent {
+                    event,
+                    debounce_until: now,
+                });
+        }
         self.update_next_process();
-        ready
     }
 
     /// Get number of pending events.
@@ -69,6 +69,9 @@ pub struct SyncStatus {
     pub pending_count: usize,
     /// Whether a sync is currently in progress.
     pub in_progress: bool,
+    /// Whether the last flush was skipped because the network was unreachable.
+    #[serde(default)]
+    pub offline: bool,
 }
 
 /// Sync error types.
@@ -123,6 +126,7 @@ mod tests {
             last_sync_at: None,
             pending_count: 5,
             in_progress: true,
+            offline: false,
         };
         assert_eq!(status.pending_count, 5);
         assert!(status.in_progress);
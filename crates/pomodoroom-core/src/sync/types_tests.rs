@@ -24,6 +24,7 @@ mod tests {
             last_sync_at: None,
             pending_count: 5,
             in_progress: true,
+            offline: false,
         };
         assert_eq!(status.pending_count, 5);
         assert!(status.in_progress);
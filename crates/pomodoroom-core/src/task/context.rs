@@ -380,18 +380,10 @@ impl ResumeContext {
 
         // Operation pattern insight
         let ops = &pause_ctx.operation_summary;
-        if ops.pause_count >= 3 {
-            insights.push(ContextInsight {
-                insight_type: InsightType::Pattern,
-                message: "This task has been paused multiple times. Consider breaking it down.".to_string(),
-                data: vec![
-                    ("pause_count".to_string(), ops.pause_count.to_string()),
-                    ("extend_count".to_string(), ops.extend_count.to_string()),
-                ]
-                .into_iter()
-                .collect(),
-            });
-        }
+        insights.extend(repeated_pause_insight(
+            ops.pause_count,
+            ("extend_count", ops.extend_count.to_string()),
+        ));
 
         Self {
             task_id: pause_ctx.task_id,
@@ -428,6 +420,42 @@ impl ResumeContext {
     }
 }
 
+/// Task switches within a window that count as excessive switching.
+pub const EXCESSIVE_SWITCH_THRESHOLD: u32 = 5;
+
+/// Pauses of one task that suggest breaking it down.
+const REPEATED_PAUSE_THRESHOLD: u32 = 3;
+
+/// "Paused multiple times" insight once `pause_count` reaches
+/// `REPEATED_PAUSE_THRESHOLD`, with `detail` added to its data.
+fn repeated_pause_insight(pause_count: u32, detail: (&str, String)) -> Option<ContextInsight> {
+    (pause_count >= REPEATED_PAUSE_THRESHOLD).then(|| ContextInsight {
+        insight_type: InsightType::Pattern,
+        message: "This task has been paused multiple times. Consider breaking it down.".to_string(),
+        data: vec![
+            ("pause_count".to_string(), pause_count.to_string()),
+            (detail.0.to_string(), detail.1),
+        ]
+        .into_iter()
+        .collect(),
+    })
+}
+
+/// Operations recorded within a time window and what they add up to.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ContextWindow {
+    /// Window start (inclusive)
+    pub start: DateTime<Utc>,
+    /// Window end (exclusive)
+    pub end: DateTime<Utc>,
+    /// Counts over the operations in the window
+    pub summary: OperationSummary,
+    /// Raw operations in the window, oldest first
+    pub operations: Vec<OperationLog>,
+    /// Insights computed from the window only
+    pub insights: Vec<ContextInsight>,
+}
+
 /// Context manager for tracking and reconstructing task context.
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
 pub struct ContextManager {
@@ -478,6 +506,52 @@ impl ContextManager {
         self.operation_logs.get(task_id).cloned().unwrap_or_default()
     }
 
+    /// Get all operations in `[start, end)` across tasks, oldest first.
+    pub fn operations_between(
+        &self,
+        start: DateTime<Utc>,
+        end: DateTime<Utc>,
+    ) -> Vec<OperationLog> {
+        let mut operations: Vec<OperationLog> = self
+            .operation_logs
+            .values()
+            .flatten()
+            .filter(|log| log.timestamp >= start && log.timestamp < end)
+            .cloned()
+            .collect();
+        operations.sort_by(|a, b| a.timestamp.cmp(&b.timestamp).then_with(|| a.id.cmp(&b.id)));
+        operations
+    }
+
+    /// Get operation summary across tasks for `[start, end)`.
+    pub fn summary_between(&self, start: DateTime<Utc>, end: DateTime<Utc>) -> OperationSummary {
+        let mut summary = OperationSummary::new();
+        for log in self.operations_between(start, end) {
+            summary.add_operation(log.operation, log.timestamp);
+        }
+        summary
+    }
+
+    /// Summary, raw operations and insights for `[start, end)`.
+    ///
+    /// Used for reviews such as "what did I do this afternoon" and as input
+    /// for handoff notes. Nothing outside the window affects the result.
+    pub fn export_window(&self, start: DateTime<Utc>, end: DateTime<Utc>) -> ContextWindow {
+        let operations = self.operations_between(start, end);
+        let mut summary = OperationSummary::new();
+        for log in &operations {
+            summary.add_operation(log.operation, log.timestamp);
+        }
+        let insights = window_insights(&operations);
+        ContextWindow {
+            start,
+            end,
+            summary,
+            operations,
+            insights,
+        }
+    }
+
     /// Save pause context for a task.
     pub fn save_pause_context(&mut self, context: PauseContext) {
         self.pause_contexts.insert(context.task_id.clone(), context);
@@ -579,6 +653,51 @@ impl ContextManager {
     }
 }
 
+/// Pattern insights for operations in one window, sorted oldest first.
+fn window_insights(operations: &[OperationLog]) -> Vec<ContextInsight> {
+    let mut insights = Vec::new();
+
+    // A switch is starting or resuming a task other than the last one worked on
+    let mut switch_count = 0u32;
+    let mut current: Option<&str> = None;
+    for log in operations {
+        if matches!(log.operation, OperationType::Start | OperationType::Resume) {
+            if current.is_some_and(|task_id| task_id != log.task_id) {
+                switch_count += 1;
+            }
+            current = Some(&log.task_id);
+        }
+    }
+    if switch_count >= EXCESSIVE_SWITCH_THRESHOLD {
+        insights.push(ContextInsight {
+            insight_type: InsightType::Pattern,
+            message: format!(
+                "Switched tasks {} times in this period. Consider batching related work.",
+                switch_count
+            ),
+            data: vec![("switch_count".to_string(), switch_count.to_string())]
+                .into_iter()
+                .collect(),
+        });
+    }
+
+    let mut pauses: Vec<(&str, u32)> = Vec::new();
+    for log in operations {
+        if log.operation != OperationType::Pause {
+            continue;
+        }
+        match pauses.iter_mut().find(|(id, _)| *id == log.task_id) {
+            Some((_, count)) => *count += 1,
+            None => pauses.push((&log.task_id, 1)),
+        }
+    }
+    for (task_id, pause_count) in pauses {
+        insights.extend(repeated_pause_insight(pause_count, ("task_id", task_id.to_string())));
+    }
+
+    insights
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -707,4 +826,76 @@ mod tests {
         assert_eq!(summary.start_count, 1);
         assert_eq!(summary.total_operations(), 1);
     }
+
+    fn record(manager: &mut ContextManager, task_id: &str, op: OperationType, at: DateTime<Utc>) {
+        let context = OperationContext {
+            from_state: String::new(),
+            to_state: String::new(),
+            priority_delta: None,
+            energy: "medium".to_string(),
+            tags: vec![],
+            project_ids: vec![],
+        };
+        manager.record_operation(task_id.to_string(), op, at, 0, context);
+    }
+
+    #[test]
+    fn test_summary_between_excludes_operations_outside_window() {
+        let mut manager = ContextManager::new();
+        let noon = Utc::now();
+        let end = noon + Duration::hours(4);
+        let just_before = |at: DateTime<Utc>| at - Duration::minutes(1);
+
+        record(&mut manager, "a", OperationType::Start, just_before(noon));
+        record(&mut manager, "a", OperationType::Pause, noon);
+        record(&mut manager, "b", OperationType::Start, end - Duration::minutes(2));
+        record(&mut manager, "b", OperationType::Extend, just_before(end));
+        record(&mut manager, "b", OperationType::Pause, end);
+
+        let summary = manager.summary_between(noon, end);
+        assert_eq!(summary.start_count, 1);
+        assert_eq!(summary.pause_count, 1);
+        assert_eq!(summary.extend_count, 1);
+        assert_eq!(summary.first_operation_at, Some(noon));
+        assert_eq!(summary.last_operation_at, Some(just_before(end)));
+
+        let window = manager.export_window(noon, end);
+        let ops: Vec<_> = window
+            .operations
+            .iter()
+            .map(|log| (log.task_id.as_str(), log.operation))
+            .collect();
+        assert_eq!(
+            ops,
+            vec![
+                ("a", OperationType::Pause),
+                ("b", OperationType::Start),
+                ("b", OperationType::Extend),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_window_insights_ignore_switching_outside_window() {
+        let mut manager = ContextManager::new();
+        let start = Utc::now();
+
+        // Rapid switching in the morning, before the window
+        for i in 0..8 {
+            let task_id = if i % 2 == 0 { "a" } else { "b" };
+            let at = start - Duration::hours(3) + Duration::minutes(i * 5);
+            record(&mut manager, task_id, OperationType::Start, at);
+        }
+        let done_at = start + Duration::hours(1);
+        record(&mut manager, "a", OperationType::Start, start);
+        record(&mut manager, "a", OperationType::Complete, done_at);
+
+        let window = manager.export_window(start, start + Duration::hours(4));
+        assert!(window.insights.is_empty());
+
+        let morning = manager.export_window(start - Duration::hours(4), start);
+        let switching = &morning.insights[0];
+        assert_eq!(switching.insight_type, InsightType::Pattern);
+        assert_eq!(switching.data["switch_count"], "7");
+    }
 }
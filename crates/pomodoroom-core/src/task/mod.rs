@@ -7,18 +7,21 @@ pub mod carry_over;
 pub mod context;
 pub mod micro_merge;
 pub mod reconciliation;
+pub mod recurrence;
 pub mod split_templates;
 
 // Re-export context types for convenience
 pub use context::{
-    ContextInsight, ContextManager, InsightType, OperationContext, OperationLog, OperationSummary,
-    OperationType, PauseContext, RelatedTasks, ResumeContext,
+    ContextInsight, ContextManager, ContextWindow, InsightType, OperationContext, OperationLog,
+    OperationSummary, OperationType, PauseContext, RelatedTasks, ResumeContext,
+    EXCESSIVE_SWITCH_THRESHOLD,
 };
 // Re-export reconciliation types for convenience
 pub use carry_over::{
     calculate_remaining_workload, CarryOverEngine, CarryOverPolicy, CarryOverResult,
     DroppedSegment, DropReason, ParentTaskStatus, RemainingWorkload,
 };
+pub use recurrence::{generate_due_instances, Recurrence, RecurrenceFrequency};
 pub use reconciliation::{
     ReconciliationConfig, ReconciliationEngine, ReconciliationSummary, ReconciledTask,
     DEFAULT_STALE_THRESHOLD_MINUTES, MAX_STALE_THRESHOLD_MINUTES, MIN_STALE_THRESHOLD_MINUTES,
@@ -28,6 +31,10 @@ use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use std::fmt;
 
+/// Minutes in one pomodoro when converting between pomodoro counts and
+/// minute estimates.
+pub const POMODORO_MINUTES: u32 = 25;
+
 /// Task state enumeration.
 ///
 /// States follow strict transitions as defined in docs/ui-redesign-strategy.md:
@@ -271,6 +278,29 @@ pub struct Task {
     /// User-approved tags from suggested tags.
     #[serde(default, rename = "approvedTags", alias = "approved_tags")]
     pub approved_tags: Vec<String>,
+    /// Description of the external factor blocking this task (None if not blocked).
+    ///
+    /// A paused task is classified as Wait only when this is set.
+    #[serde(default, alias = "externalBlock")]
+    pub external_block: Option<String>,
+    /// Recurrence rule when this task is a recurring template.
+    #[serde(default)]
+    pub recurrence: Option<Recurrence>,
+    /// Template task ID when this task is a generated recurring instance.
+    #[serde(default, alias = "recurrenceParentId")]
+    pub recurrence_parent_id: Option<String>,
+    /// Dated notes appended to the task, oldest first.
+    #[serde(default)]
+    pub notes: Vec<TaskNote>,
+}
+
+/// A dated note on a task, e.g. "blocked on API key from ops".
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TaskNote {
+    /// When the note was written
+    pub at: DateTime<Utc>,
+    /// Note text
+    pub text: String,
 }
 
 /// Default value for allow_split field.
@@ -320,6 +350,10 @@ impl Task {
             allow_split: true,
             suggested_tags: Vec::new(),
             approved_tags: Vec::new(),
+            external_block: None,
+            recurrence: None,
+            recurrence_parent_id: None,
+            notes: Vec::new(),
         }
     }
 
@@ -364,13 +398,37 @@ impl Task {
         Ok(())
     }
 
+    /// Append a note stamped with the current time.
+    pub fn add_note(&mut self, text: impl Into<String>) {
+        let now = Utc::now();
+        self.notes.push(TaskNote {
+            at: now,
+            text: text.into(),
+        });
+        self.updated_at = now;
+    }
+
     /// Update elapsed minutes.
     pub fn add_elapsed_minutes(&mut self, minutes: u32) {
         self.elapsed_minutes += minutes;
         self.updated_at = Utc::now();
     }
 
-    /// Calculate completion percentage (0.0 to 1.0).
+    /// Fraction of the task done (0.0 to 1.0).
+    ///
+    /// Uses `elapsed_minutes / estimated_minutes` when a minute estimate is
+    /// set, so time-tracked tasks don't depend on pomodoro counts. Without
+    /// one (or with a zero estimate) this is `completion_percentage`.
+    pub fn completion_fraction(&self) -> f64 {
+        match self.estimated_minutes {
+            Some(estimate) if estimate > 0 => {
+                (self.elapsed_minutes as f64 / estimate as f64).min(1.0)
+            }
+            _ => self.completion_percentage(),
+        }
+    }
+
+    /// Calculate completion percentage (0.0 to 1.0) from pomodoro counts.
     pub fn completion_percentage(&self) -> f64 {
         if self.estimated_pomodoros == 0 {
             0.0
@@ -379,6 +437,14 @@ impl Task {
         }
     }
 
+    /// Check if this task is a recurring template rather than work to do.
+    ///
+    /// Templates only spawn instances; scheduling, suggestions and task
+    /// lists should skip them.
+    pub fn is_recurrence_template(&self) -> bool {
+        self.recurrence.is_some()
+    }
+
     /// Check if this task has any projects associated.
     pub fn has_projects(&self) -> bool {
         self.project_id.is_some() || self.project_name.is_some() || !self.project_ids.is_empty()
@@ -435,6 +501,7 @@ impl Task {
     /// |-------|----------|-----------|
     /// | `running` | **Active** | Always Active (max 1) |
     /// | `paused` + external block | **Wait** | External factors blocking progress |
+    /// | `paused` without block | **Floating** | Parked, picked up when convenient |
     /// | `ready` + low priority/energy | **Floating** | Scheduler assigns |
     /// | `ready` + normal priority | Active candidate | Next Active proposal |
     /// | `done` | - | Excluded from classification |
@@ -450,10 +517,13 @@ impl Task {
                 TaskCategory::Floating
             }
             TaskState::Paused => {
-                // Check if paused due to external blocking conditions
-                // For now, we assume Paused tasks are Wait (external block)
-                // TODO: Add explicit external_block flag to distinguish Wait vs Floating
-                TaskCategory::Wait
+                // Only an explicit external block makes a paused task Wait;
+                // otherwise it is a parked low-priority floater.
+                if self.external_block.is_some() {
+                    TaskCategory::Wait
+                } else {
+                    TaskCategory::Floating
+                }
             }
             TaskState::Ready => {
                 // Determine Floating vs Active candidate based on priority/energy
@@ -511,6 +581,50 @@ impl std::fmt::Display for TaskTransitionError {
 
 impl std::error::Error for TaskTransitionError {}
 
+/// Outcome of applying a transition to one task in a batch.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "status", rename_all = "snake_case")]
+pub enum TaskTransitionOutcome {
+    /// The transition was applied; carries the updated task.
+    Applied { task: Box<Task> },
+    /// The task was left unchanged.
+    Failed { task_id: String, error: String },
+}
+
+impl TaskTransitionOutcome {
+    /// ID of the task this outcome is for.
+    pub fn task_id(&self) -> &str {
+        match self {
+            TaskTransitionOutcome::Applied { task } => &task.id,
+            TaskTransitionOutcome::Failed { task_id, .. } => task_id,
+        }
+    }
+
+    /// Whether the transition was applied.
+    pub fn is_applied(&self) -> bool {
+        matches!(self, TaskTransitionOutcome::Applied { .. })
+    }
+}
+
+/// Per-task results of a batch transition, in request order.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct BatchTransitionResult {
+    /// One outcome per requested task.
+    pub outcomes: Vec<TaskTransitionOutcome>,
+}
+
+impl BatchTransitionResult {
+    /// Number of tasks transitioned.
+    pub fn applied_count(&self) -> usize {
+        self.outcomes.iter().filter(|o| o.is_applied()).count()
+    }
+
+    /// Number of tasks left unchanged.
+    pub fn failed_count(&self) -> usize {
+        self.outcomes.len() - self.applied_count()
+    }
+}
+
 /// Action that can be applied to transition task state.
 ///
 /// Each action represents a user-facing operation that may cause
@@ -889,6 +1003,10 @@ mod tests {
             allow_split: true,
             suggested_tags: vec![],
             approved_tags: vec![],
+            external_block: None,
+            recurrence: None,
+            recurrence_parent_id: None,
+            notes: Vec::new(),
         };
 
         let json = serde_json::to_string(&task).unwrap();
@@ -920,6 +1038,46 @@ mod tests {
         assert_eq!(task.completion_percentage(), 0.0);
     }
 
+    #[test]
+    fn completion_fraction_uses_minutes_when_estimated() {
+        let mut task = Task::new("Minutes");
+        task.estimated_pomodoros = 0;
+        task.estimated_minutes = Some(90);
+        task.elapsed_minutes = 45;
+        assert_eq!(task.completion_fraction(), 0.5);
+
+        task.elapsed_minutes = 120;
+        assert_eq!(task.completion_fraction(), 1.0);
+    }
+
+    #[test]
+    fn completion_fraction_falls_back_to_pomodoros() {
+        let mut task = Task::new("Pomodoros");
+        task.estimated_pomodoros = 4;
+        task.completed_pomodoros = 1;
+        task.elapsed_minutes = 80;
+        assert_eq!(task.completion_fraction(), 0.25);
+
+        // A zero minute estimate is treated as unset
+        task.estimated_minutes = Some(0);
+        assert_eq!(task.completion_fraction(), 0.25);
+
+        task.estimated_pomodoros = 0;
+        assert_eq!(task.completion_fraction(), 0.0);
+    }
+
+    #[test]
+    fn completion_fraction_prefers_minutes_over_pomodoros() {
+        let mut task = Task::new("Mixed");
+        task.estimated_pomodoros = 4;
+        task.completed_pomodoros = 3;
+        task.estimated_minutes = Some(100);
+        task.elapsed_minutes = 25;
+
+        assert_eq!(task.completion_fraction(), 0.25);
+        assert_eq!(task.completion_percentage(), 0.75);
+    }
+
     #[test]
     fn task_add_elapsed_minutes() {
         let mut task = Task::new("Test");
@@ -1352,6 +1510,10 @@ mod tests {
             allow_split: true,
             suggested_tags: vec![],
             approved_tags: vec![],
+            external_block: None,
+            recurrence: None,
+            recurrence_parent_id: None,
+            notes: Vec::new(),
         };
 
         // Test serialization to JSON
@@ -1439,15 +1601,28 @@ mod tests {
         }
 
         #[test]
-        fn test_effective_category_paused_is_wait() {
+        fn test_effective_category_paused_with_block_is_wait() {
             let mut task = Task::new("Test task");
             task.state = TaskState::Paused;
+            task.external_block = Some("Waiting for design review".to_string());
             assert_eq!(task.effective_category(), TaskCategory::Wait);
             assert!(!task.is_active());
             assert!(task.is_waiting());
             assert!(!task.is_floating());
         }
 
+        #[test]
+        fn test_effective_category_paused_without_block_is_floating() {
+            let mut task = Task::new("Test task");
+            task.state = TaskState::Paused;
+            task.priority = Some(20);
+            assert!(task.external_block.is_none());
+            assert_eq!(task.effective_category(), TaskCategory::Floating);
+            assert!(!task.is_active());
+            assert!(!task.is_waiting());
+            assert!(task.is_floating());
+        }
+
         #[test]
         fn test_effective_category_ready_normal_priority_is_active() {
             let mut task = Task::new("Test task");
@@ -0,0 +1,277 @@
+//! Recurring task templates and instance generation.
+//!
+//! A task with `recurrence` set acts as a template. Concrete instances are
+//! materialized per period and linked back via `recurrence_parent_id`, so
+//! completing an instance never touches the template itself.
+
+use chrono::{DateTime, Datelike, Duration, Local, NaiveDate, Utc, Weekday};
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+
+use super::{Task, TaskState};
+
+/// Recurrence frequency.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum RecurrenceFrequency {
+    /// Every `interval` days.
+    Daily,
+    /// Every `interval` weeks.
+    Weekly,
+}
+
+/// Recurrence rule for a template task.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct Recurrence {
+    /// Base frequency.
+    pub frequency: RecurrenceFrequency,
+    /// Repeat every N days/weeks (0 is treated as 1).
+    #[serde(default = "default_interval")]
+    pub interval: u32,
+    /// Weekdays on which the task occurs.
+    ///
+    /// Empty means every day for `Daily`, and the anchor's weekday for `Weekly`.
+    #[serde(default)]
+    pub weekdays: Vec<Weekday>,
+}
+
+fn default_interval() -> u32 {
+    1
+}
+
+impl Recurrence {
+    /// Every day.
+    pub fn daily() -> Self {
+        Self {
+            frequency: RecurrenceFrequency::Daily,
+            interval: 1,
+            weekdays: Vec::new(),
+        }
+    }
+
+    /// Monday through Friday.
+    pub fn weekdays_only() -> Self {
+        Self {
+            frequency: RecurrenceFrequency::Daily,
+            interval: 1,
+            weekdays: vec![
+                Weekday::Mon,
+                Weekday::Tue,
+                Weekday::Wed,
+                Weekday::Thu,
+                Weekday::Fri,
+            ],
+        }
+    }
+
+    /// Weekly on the given weekdays.
+    pub fn weekly(weekdays: Vec<Weekday>) -> Self {
+        Self {
+            frequency: RecurrenceFrequency::Weekly,
+            interval: 1,
+            weekdays,
+        }
+    }
+
+    /// Check whether the rule produces an occurrence on `date`.
+    ///
+    /// `anchor` is the first day of the series (the template's creation date);
+    /// intervals are counted from it and no occurrence falls before it.
+    pub fn occurs_on(&self, anchor: NaiveDate, date: NaiveDate) -> bool {
+        if date < anchor {
+            return false;
+        }
+        let interval = i64::from(self.interval.max(1));
+        match self.frequency {
+            RecurrenceFrequency::Daily => {
+                let days = (date - anchor).num_days();
+                days % interval == 0
+                    && (self.weekdays.is_empty() || self.weekdays.contains(&date.weekday()))
+            }
+            RecurrenceFrequency::Weekly => {
+                let weekday_matches = if self.weekdays.is_empty() {
+                    date.weekday() == anchor.weekday()
+                } else {
+                    self.weekdays.contains(&date.weekday())
+                };
+                let weeks = (week_start(date) - week_start(anchor)).num_days() / 7;
+                weekday_matches && weeks % interval == 0
+            }
+        }
+    }
+}
+
+/// Monday of the week containing `date`.
+fn week_start(date: NaiveDate) -> NaiveDate {
+    date - Duration::days(i64::from(date.weekday().num_days_from_monday()))
+}
+
+/// Calendar day of `at` on the user's local clock.
+///
+/// Occurrences follow the user's day, not the UTC date.
+fn local_date(at: DateTime<Utc>) -> NaiveDate {
+    at.with_timezone(&Local).date_naive()
+}
+
+/// Deterministic ID of a template's instance for a given day.
+///
+/// Using a stable ID per (template, period) makes generation idempotent.
+pub fn instance_id(template_id: &str, date: NaiveDate) -> String {
+    format!("{}@{}", template_id, date.format("%Y-%m-%d"))
+}
+
+/// Materialize a concrete instance of `template` for `date`.
+///
+/// The instance starts fresh (READY, no progress) and has its fixed/window
+/// bounds shifted by whole days from the template's (local) creation date.
+pub fn materialize_instance(template: &Task, date: NaiveDate, now: DateTime<Utc>) -> Task {
+    let shift = date - local_date(template.created_at);
+    let shift_time = |dt: Option<DateTime<Utc>>| dt.map(|value| value + shift);
+
+    let mut instance = template.clone();
+    instance.id = instance_id(&template.id, date);
+    instance.recurrence = None;
+    instance.recurrence_parent_id = Some(template.id.clone());
+    instance.state = TaskState::Ready;
+    instance.completed = false;
+    instance.completed_pomodoros = 0;
+    instance.elapsed_minutes = 0;
+    instance.created_at = now;
+    instance.updated_at = now;
+    instance.completed_at = None;
+    instance.paused_at = None;
+    instance.started_at = None;
+    instance.estimated_start_at = None;
+    instance.source_service = None;
+    instance.source_external_id = None;
+    instance.notes = Vec::new();
+    instance.fixed_start_at = shift_time(template.fixed_start_at);
+    instance.fixed_end_at = shift_time(template.fixed_end_at);
+    instance.window_start_at = shift_time(template.window_start_at);
+    instance.window_end_at = shift_time(template.window_end_at);
+    instance
+}
+
+/// Generate instances that are due on `now`'s local day and do not exist yet.
+///
+/// `tasks` should contain both the templates and their existing instances.
+/// Missed days are not backfilled; only the current period is materialized.
+pub fn generate_due_instances(tasks: &[Task], now: DateTime<Utc>) -> Vec<Task> {
+    let today = local_date(now);
+    let existing: HashSet<&str> = tasks
+        .iter()
+        .filter(|task| task.recurrence_parent_id.is_some())
+        .map(|task| task.id.as_str())
+        .collect();
+
+    tasks
+        .iter()
+        .filter_map(|template| {
+            let recurrence = template.recurrence.as_ref()?;
+            if !recurrence.occurs_on(local_date(template.created_at), today) {
+                return None;
+            }
+            if existing.contains(instance_id(&template.id, today).as_str()) {
+                return None;
+            }
+            Some(materialize_instance(template, today, now))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    /// Local noon on the given day, as UTC.
+    fn local_noon(year: i32, month: u32, day: u32) -> DateTime<Utc> {
+        Local
+            .with_ymd_and_hms(year, month, day, 12, 0, 0)
+            .unwrap()
+            .with_timezone(&Utc)
+    }
+
+    fn template(recurrence: Recurrence, created_at: DateTime<Utc>) -> Task {
+        let mut task = Task::new("Email triage");
+        task.id = "template-1".to_string();
+        task.recurrence = Some(recurrence);
+        task.created_at = created_at;
+        task
+    }
+
+    #[test]
+    fn weekday_recurrence_skips_weekends() {
+        // 2024-01-01 is a Monday.
+        let anchor = local_noon(2024, 1, 1);
+        let tpl = template(Recurrence::weekdays_only(), anchor);
+
+        let mut generated_days = Vec::new();
+        for day in 0..14 {
+            let now = anchor + Duration::days(day);
+            if !generate_due_instances(std::slice::from_ref(&tpl), now).is_empty() {
+                generated_days.push(local_date(now).weekday());
+            }
+        }
+
+        assert_eq!(generated_days.len(), 10);
+        assert!(!generated_days.contains(&Weekday::Sat));
+        assert!(!generated_days.contains(&Weekday::Sun));
+    }
+
+    #[test]
+    fn existing_instance_is_not_regenerated() {
+        let anchor = local_noon(2024, 1, 1);
+        let tpl = template(Recurrence::daily(), anchor);
+        let now = anchor + Duration::days(2);
+
+        let first = generate_due_instances(std::slice::from_ref(&tpl), now);
+        assert_eq!(first.len(), 1);
+        assert_eq!(first[0].recurrence_parent_id.as_deref(), Some("template-1"));
+        assert!(first[0].recurrence.is_none());
+
+        let tasks = vec![tpl, first[0].clone()];
+        assert!(generate_due_instances(&tasks, now).is_empty());
+    }
+
+    #[test]
+    fn weekly_interval_skips_off_weeks() {
+        let anchor = Utc.with_ymd_and_hms(2024, 1, 1, 8, 0, 0).unwrap();
+        let rule = Recurrence {
+            interval: 2,
+            ..Recurrence::weekly(vec![Weekday::Mon])
+        };
+        let start = anchor.date_naive();
+        assert!(rule.occurs_on(start, start));
+        assert!(!rule.occurs_on(start, start + Duration::days(7)));
+        assert!(rule.occurs_on(start, start + Duration::days(14)));
+        assert!(!rule.occurs_on(start, start - Duration::days(14)));
+    }
+
+    #[test]
+    fn instance_shifts_fixed_times_to_occurrence_day() {
+        let anchor = local_noon(2024, 1, 1);
+        let mut tpl = template(Recurrence::daily(), anchor);
+        tpl.fixed_start_at = Some(anchor + Duration::hours(1));
+
+        let instance = materialize_instance(&tpl, local_date(anchor) + Duration::days(3), anchor);
+        assert_eq!(
+            instance.fixed_start_at,
+            Some(local_noon(2024, 1, 4) + Duration::hours(1))
+        );
+    }
+
+    #[test]
+    fn due_day_follows_local_date() {
+        let anchor = local_noon(2024, 1, 1);
+        let tpl = template(Recurrence::daily(), anchor);
+        let now = local_noon(2024, 1, 3);
+
+        let generated = generate_due_instances(std::slice::from_ref(&tpl), now);
+        assert_eq!(generated.len(), 1);
+        assert_eq!(
+            generated[0].id,
+            instance_id("template-1", NaiveDate::from_ymd_opt(2024, 1, 3).unwrap())
+        );
+    }
+}
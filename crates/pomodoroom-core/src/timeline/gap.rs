@@ -1,7 +1,9 @@
 //! Time gap detection between calendar events.
 //!
 //! Finds available time slots between existing events that can be used
-//! for focused work.
+//! for focused work. Events are clipped to the day window first, so events
+//! spanning midnight only block the part inside it, and an all-day event
+//! blocks the whole window.
 
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
@@ -107,8 +109,11 @@ impl TimeGapDetector {
     ) -> Vec<TimeGap> {
         let mut gaps = Vec::new();
 
-        // Sort events by start time
-        let mut sorted_events: Vec<_> = events.to_vec();
+        // Clip events to the window and sort by start time
+        let mut sorted_events: Vec<_> = events
+            .iter()
+            .filter_map(|e| e.clip_to(day_start, day_end))
+            .collect();
         sorted_events.sort_by_key(|e| e.start_time);
 
         let mut last_end = day_start;
@@ -164,6 +169,8 @@ impl Default for TimeGapDetector {
 pub struct TimelineEvent {
     pub start_time: DateTime<Utc>,
     pub end_time: DateTime<Utc>,
+    /// All-day events block the whole day window they overlap
+    pub all_day: bool,
 }
 
 impl TimelineEvent {
@@ -171,9 +178,34 @@ impl TimelineEvent {
         Self {
             start_time,
             end_time,
+            all_day: false,
         }
     }
 
+    /// Create an all-day event covering the given dates
+    pub fn all_day(start_time: DateTime<Utc>, end_time: DateTime<Utc>) -> Self {
+        Self {
+            all_day: true,
+            ..Self::new(start_time, end_time)
+        }
+    }
+
+    /// The part of this event inside the window, if any
+    ///
+    /// An all-day event overlapping the window covers all of it.
+    pub fn clip_to(&self, window_start: DateTime<Utc>, window_end: DateTime<Utc>) -> Option<Self> {
+        if self.start_time >= window_end || self.end_time <= window_start {
+            return None;
+        }
+        if self.all_day {
+            return Some(Self::all_day(window_start, window_end));
+        }
+        Some(Self::new(
+            self.start_time.max(window_start),
+            self.end_time.min(window_end),
+        ))
+    }
+
     /// Get duration in minutes
     pub fn duration_minutes(&self) -> i64 {
         (self.end_time - self.start_time).num_minutes()
@@ -192,6 +224,7 @@ pub fn detect_time_gaps(
 #[cfg(test)]
 mod tests {
     use super::*;
+    use chrono::TimeZone;
 
     #[test]
     fn test_gap_size_classification() {
@@ -239,4 +272,44 @@ mod tests {
         // Should find a gap from start to 9am, 10am-11am, and 12pm-end
         assert!(gaps.len() >= 2);
     }
+
+    #[test]
+    fn test_all_day_event_leaves_no_gaps() {
+        let day_start = Utc.with_ymd_and_hms(2026, 3, 2, 0, 0, 0).unwrap();
+        let day_end = day_start + chrono::Duration::days(1);
+
+        // All-day dates stored in UTC can be offset from the local day window
+        let events = vec![
+            TimelineEvent::all_day(
+                day_start - chrono::Duration::hours(9),
+                day_end - chrono::Duration::hours(9),
+            ),
+            TimelineEvent::new(
+                day_start + chrono::Duration::hours(10),
+                day_start + chrono::Duration::hours(11),
+            ),
+        ];
+
+        assert!(detect_time_gaps(&events, day_start, day_end).is_empty());
+    }
+
+    #[test]
+    fn test_event_spanning_midnight_is_clipped() {
+        let day_start = Utc.with_ymd_and_hms(2026, 3, 2, 0, 0, 0).unwrap();
+        let day_end = day_start + chrono::Duration::days(1);
+        let late = TimelineEvent::new(
+            day_start + chrono::Duration::hours(23),
+            day_end + chrono::Duration::hours(2),
+        );
+
+        let gaps = detect_time_gaps(std::slice::from_ref(&late), day_start, day_end);
+        assert_eq!(gaps.len(), 1);
+        assert_eq!(gaps[0].start_time, day_start);
+        assert_eq!(gaps[0].end_time, late.start_time);
+
+        // The next day is blocked until the event ends
+        let gaps = detect_time_gaps(&[late], day_end, day_end + chrono::Duration::days(1));
+        assert_eq!(gaps.len(), 1);
+        assert_eq!(gaps[0].start_time, day_end + chrono::Duration::hours(2));
+    }
 }
@@ -67,6 +67,9 @@ pub struct TimelineItem {
     #[serde(default)]
     pub tags: Vec<String>,
     pub url: Option<String>,
+    /// Subtasks; their urgency and remaining work roll up into this item's priority
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub children: Vec<TimelineItem>,
     #[serde(flatten)]
     pub metadata: serde_json::Value,
 }
@@ -119,6 +122,7 @@ impl TimelineItem {
             deadline: None,
             tags: Vec::new(),
             url: None,
+            children: Vec::new(),
             metadata: serde_json::json!({}),
         })
     }
@@ -156,6 +160,12 @@ impl TimelineItem {
         self.completed = completed;
         self
     }
+
+    /// Add a subtask
+    pub fn with_child(mut self, child: TimelineItem) -> Self {
+        self.children.push(child);
+        self
+    }
 }
 
 /// Errors that can occur when creating a TimelineItem
@@ -6,7 +6,10 @@
 //! - Effort estimation (shorter tasks may get priority for quick wins)
 //! - Dependencies (blocking tasks get priority)
 //!
-//! The algorithm weights each factor to produce a normalized score.
+//! The algorithm weights each factor to produce a normalized score. For items
+//! with subtasks, the most urgent incomplete child deadline and the summed
+//! remaining child effort are rolled up into the parent's deadline and effort
+//! factors.
 
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
@@ -46,6 +49,8 @@ pub struct PriorityConfig {
     pub current_time: DateTime<Utc>,
     /// Whether to boost priority for uncompleted tasks
     pub boost_incomplete: bool,
+    /// Whether incomplete subtasks contribute to a parent's deadline and effort
+    pub rollup_children: bool,
 }
 
 impl Default for PriorityConfig {
@@ -54,6 +59,7 @@ impl Default for PriorityConfig {
             weights: PriorityWeights::default(),
             current_time: Utc::now(),
             boost_incomplete: true,
+            rollup_children: true,
         }
     }
 }
@@ -118,8 +124,11 @@ impl PriorityCalculator {
     /// - Due within 7 days: 30-59
     /// - Due within 30 days: 10-29
     /// - No deadline or >30 days: 0-9
+    ///
+    /// With rollup enabled, the earliest deadline among the task and its
+    /// incomplete subtasks is used.
     fn calculate_deadline_score(&self, task: &TimelineItem) -> f32 {
-        let Some(deadline) = task.deadline else {
+        let Some(deadline) = self.effective_deadline(task) else {
             return 5.0; // Base score for tasks without deadline
         };
 
@@ -167,8 +176,11 @@ impl PriorityCalculator {
     /// - 1-2 hours: 40
     /// - 2-4 hours: 20
     /// - > 4 hours: 10 (large task, defer)
+    ///
+    /// With rollup enabled, the remaining effort of incomplete subtasks is
+    /// added to the task's own duration.
     fn calculate_effort_score(&self, task: &TimelineItem) -> f32 {
-        let duration = task.duration_minutes();
+        let duration = self.remaining_minutes(task);
 
         if duration <= 15 {
             100.0 // Quick win
@@ -185,6 +197,33 @@ impl PriorityCalculator {
         }
     }
 
+    /// Earliest deadline of the task and, with rollup, its incomplete subtasks
+    fn effective_deadline(&self, task: &TimelineItem) -> Option<DateTime<Utc>> {
+        if !self.config.rollup_children {
+            return task.deadline;
+        }
+        task.children
+            .iter()
+            .filter(|child| !child.completed)
+            .filter_map(|child| self.effective_deadline(child))
+            .chain(task.deadline)
+            .min()
+    }
+
+    /// Own duration plus, with rollup, the remaining effort of incomplete subtasks
+    fn remaining_minutes(&self, task: &TimelineItem) -> i64 {
+        if !self.config.rollup_children {
+            return task.duration_minutes();
+        }
+        task.duration_minutes()
+            + task
+                .children
+                .iter()
+                .filter(|child| !child.completed)
+                .map(|child| self.remaining_minutes(child))
+                .sum::<i64>()
+    }
+
     /// Calculate dependency score (0-100)
     ///
     /// Tasks that block others get higher priority.
@@ -442,4 +481,81 @@ mod tests {
         assert_eq!(tasks[1].id, "3", "Medium priority task should be second");
         assert_eq!(tasks[2].id, "1", "Low priority task should be last");
     }
+
+    #[test]
+    fn test_parent_rolls_up_urgent_child_deadline() {
+        let now = Utc::now();
+        let item = |id: &str, minutes: i64| {
+            TimelineItem::new(
+                id,
+                TimelineItemType::Task,
+                TimelineItemSource::Manual,
+                id,
+                now,
+                now + chrono::Duration::minutes(minutes),
+            )
+        };
+        let distant = now + chrono::Duration::days(20);
+
+        let urgent_child = item("child", 30).with_deadline(now + chrono::Duration::hours(6));
+        let parent = item("parent", 60)
+            .with_deadline(distant)
+            .with_child(urgent_child.clone());
+        let childless = item("childless", 60).with_deadline(distant);
+
+        let calculator = PriorityCalculator::with_config(PriorityConfig {
+            current_time: now,
+            ..Default::default()
+        });
+        assert!(
+            calculator.calculate_priority(&parent) > calculator.calculate_priority(&childless),
+            "Parent with a near-deadline child should outrank a childless parent"
+        );
+
+        // Completed children and disabled rollup fall back to leaf behavior
+        let done = item("parent", 60)
+            .with_deadline(distant)
+            .with_child(urgent_child.clone().with_completed(true));
+        assert_eq!(
+            calculator.calculate_priority(&done),
+            calculator.calculate_priority(&childless)
+        );
+        let leaf_only = PriorityCalculator::with_config(PriorityConfig {
+            current_time: now,
+            rollup_children: false,
+            ..Default::default()
+        });
+        assert_eq!(
+            leaf_only.calculate_priority(&parent),
+            leaf_only.calculate_priority(&childless)
+        );
+    }
+
+    #[test]
+    fn test_parent_rolls_up_child_effort() {
+        let now = Utc::now();
+        let item = |id: &str, minutes: i64| {
+            TimelineItem::new(
+                id,
+                TimelineItemType::Task,
+                TimelineItemSource::Manual,
+                id,
+                now,
+                now + chrono::Duration::minutes(minutes),
+            )
+        };
+        let parent = item("parent", 10)
+            .with_child(item("a", 120))
+            .with_child(item("b", 150));
+
+        let calculator = PriorityCalculator::with_config(PriorityConfig {
+            current_time: now,
+            ..Default::default()
+        });
+        assert_eq!(calculator.remaining_minutes(&parent), 280);
+        assert!(
+            calculator.calculate_priority(&parent)
+                < calculator.calculate_priority(&item("leaf", 10))
+        );
+    }
 }
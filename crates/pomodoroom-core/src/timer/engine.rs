@@ -8,11 +8,16 @@
 //! ```text
 //! Idle (no running task) -> Running (task active) -> Drifting (time's up) -> Idle/Done
 //! ```
+//!
+//! With auto-start enabled for the next step type, an expiring step moves
+//! straight into the next step of the schedule instead of drifting.
 
 use chrono::Utc;
 use serde::{Deserialize, Serialize};
 
 use crate::events::Event;
+use crate::storage::Config;
+use crate::timer::{Gatekeeper, Schedule, Step, StepType};
 
 /// Timer state.
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
@@ -28,6 +33,15 @@ pub enum TimerState {
 
 impl Eq for TimerState {}
 
+/// Kind of step that follows the current one, for previews.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum NextStepKind {
+    Focus,
+    ShortBreak,
+    LongBreak,
+}
+
 /// Metadata for the Drifting state.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct DriftingState {
@@ -70,12 +84,47 @@ pub struct TimerEngine {
     remaining_ms: u64,
     /// Total milliseconds for current task (required - initial_elapsed).
     total_ms: u64,
+    /// Duration the current step was started with, before extensions.
+    #[serde(default)]
+    planned_ms: u64,
     /// Timestamp when last tick occurred.
     #[serde(default)]
     last_tick_epoch_ms: Option<u64>,
     /// Metadata for Drifting state.
     #[serde(default)]
     drifting: Option<DriftingState>,
+    /// Position in the step schedule, maintained by the caller.
+    #[serde(default)]
+    step_index: usize,
+    /// Type of the step being timed.
+    #[serde(default = "default_step_type")]
+    step_type: StepType,
+    /// Label of an auto-started step; the task title is shown otherwise.
+    #[serde(default)]
+    step_label: Option<String>,
+    /// Schedule the next step is taken from when auto-starting.
+    #[serde(default)]
+    schedule: Option<Schedule>,
+    /// Start break steps automatically when a focus step expires.
+    #[serde(default)]
+    auto_start_breaks: bool,
+    /// Start focus steps automatically when a break step expires.
+    #[serde(default)]
+    auto_start_focus: bool,
+    /// Focus steps completed since the last long break.
+    #[serde(default)]
+    cycle_position: u32,
+    /// Focus steps per cycle; the break after the last one is long.
+    #[serde(default = "default_pomodoros_before_long_break")]
+    pomodoros_before_long_break: u32,
+}
+
+fn default_step_type() -> StepType {
+    StepType::Focus
+}
+
+fn default_pomodoros_before_long_break() -> u32 {
+    4
 }
 
 impl TimerEngine {
@@ -86,11 +135,65 @@ impl TimerEngine {
             session: ActiveSession::default(),
             remaining_ms: 0,
             total_ms: 0,
+            planned_ms: 0,
             last_tick_epoch_ms: None,
             drifting: None,
+            step_index: 0,
+            step_type: StepType::Focus,
+            step_label: None,
+            schedule: None,
+            auto_start_breaks: false,
+            auto_start_focus: false,
+            cycle_position: 0,
+            pomodoros_before_long_break: default_pomodoros_before_long_break(),
         }
     }
 
+    /// Enable automatic start of the step following an expired one.
+    ///
+    /// Steps are taken cyclically from `schedule`. Without a flag for the
+    /// next step type the engine drifts as usual.
+    pub fn with_auto_start(
+        mut self,
+        schedule: Schedule,
+        auto_start_breaks: bool,
+        auto_start_focus: bool,
+    ) -> Self {
+        self.set_auto_start(schedule, auto_start_breaks, auto_start_focus);
+        self
+    }
+
+    /// Take the step schedule, auto-start flags and long break cadence from
+    /// `config`.
+    ///
+    /// The step being timed keeps its duration; the new schedule applies
+    /// from the next step, or after the engine is reset.
+    pub fn apply_config(&mut self, config: &Config) {
+        self.set_auto_start(
+            config.schedule(),
+            config.auto_start_breaks,
+            config.auto_start_focus,
+        );
+        self.set_pomodoros_before_long_break(config.schedule.pomodoros_before_long_break);
+    }
+
+    /// Set how many focus steps come before a long break.
+    pub fn set_pomodoros_before_long_break(&mut self, count: u32) {
+        self.pomodoros_before_long_break = count.max(1);
+    }
+
+    /// See `with_auto_start`.
+    pub fn set_auto_start(
+        &mut self,
+        schedule: Schedule,
+        auto_start_breaks: bool,
+        auto_start_focus: bool,
+    ) {
+        self.schedule = Some(schedule);
+        self.auto_start_breaks = auto_start_breaks;
+        self.auto_start_focus = auto_start_focus;
+    }
+
     // ── Queries ──────────────────────────────────────────────────────
 
     pub fn state(&self) -> TimerState {
@@ -105,6 +208,11 @@ impl TimerEngine {
         self.total_ms
     }
 
+    /// Duration the current step was started with, before extensions.
+    pub fn planned_ms(&self) -> u64 {
+        self.planned_ms
+    }
+
     pub fn current_task_id(&self) -> Option<&str> {
         self.session.task_id.as_deref()
     }
@@ -113,6 +221,16 @@ impl TimerEngine {
         self.session.task_title.as_deref()
     }
 
+    /// Type of the step being timed.
+    pub fn step_type(&self) -> StepType {
+        self.step_type
+    }
+
+    /// Schedule used for auto-starting steps, once configured.
+    pub fn schedule(&self) -> Option<&Schedule> {
+        self.schedule.as_ref()
+    }
+
     /// Progress percentage (0.0 to 1.0) within current task.
     pub fn progress(&self) -> f64 {
         if self.total_ms == 0 {
@@ -126,6 +244,47 @@ impl TimerEngine {
         self.drifting.as_ref()
     }
 
+    /// Current position in the step schedule.
+    pub fn step_index(&self) -> usize {
+        self.step_index
+    }
+
+    /// Focus steps completed since the last long break.
+    pub fn cycle_position(&self) -> u32 {
+        self.cycle_position
+    }
+
+    /// Focus steps per cycle before a long break.
+    pub fn pomodoros_before_long_break(&self) -> u32 {
+        self.pomodoros_before_long_break
+    }
+
+    /// Kind of step that follows the current one.
+    ///
+    /// A focus step is followed by a long break when it completes the
+    /// cycle. While a focus step is still being timed it is not yet counted
+    /// in `cycle_position`.
+    pub fn next_step(&self) -> NextStepKind {
+        if self.step_type == StepType::Break {
+            return NextStepKind::Focus;
+        }
+        let focus_pending = self.state != TimerState::Drifting;
+        let completed = self.cycle_position + u32::from(focus_pending);
+        if completed >= self.pomodoros_before_long_break {
+            NextStepKind::LongBreak
+        } else {
+            NextStepKind::ShortBreak
+        }
+    }
+
+    /// Next `n` steps of `schedule` after the current position.
+    ///
+    /// Does not advance the engine. A finite schedule may return fewer than
+    /// `n` steps near its end; a cyclic one wraps around.
+    pub fn upcoming_steps(&self, schedule: &Schedule, n: usize, cyclic: bool) -> Vec<Step> {
+        schedule.upcoming(self.step_index, n, cyclic)
+    }
+
     // ── Commands ─────────────────────────────────────────────────────
 
     /// Update the active session with new task information.
@@ -155,8 +314,11 @@ impl TimerEngine {
         };
 
         self.total_ms = total_required_ms;
+        self.planned_ms = total_required_ms;
         self.remaining_ms = remaining_ms;
         self.last_tick_epoch_ms = Some(now_ms());
+        self.step_type = StepType::Focus;
+        self.step_label = None;
 
         // State transition
         if task_id.is_none() {
@@ -166,10 +328,14 @@ impl TimerEngine {
             None
         } else if remaining_ms == 0 && !had_drifting {
             // Time already expired - enter drifting immediately
+            self.start_cycle_if_complete();
+            self.complete_step();
             self.enter_drifting(task_id.unwrap(), task_title.unwrap_or_default());
             Some(Event::TimerCompleted {
                 step_index: 0,
-                step_type: crate::timer::StepType::Focus,
+                step_type: StepType::Focus,
+                planned_ms: self.planned_ms,
+                actual_ms: self.total_ms,
                 at: Utc::now(),
             })
         } else if remaining_ms == 0 && had_drifting {
@@ -180,28 +346,64 @@ impl TimerEngine {
             // Normal running state
             self.state = TimerState::Running;
             self.drifting = None;
+            self.start_cycle_if_complete();
             None
         }
     }
 
     /// Call periodically to update remaining time.
     /// Returns event when task time expires.
+    ///
+    /// When the next step is auto-started its `TimerStarted` event is only
+    /// returned by `tick_events`.
     pub fn tick(&mut self) -> Option<Event> {
+        self.tick_events().into_iter().next()
+    }
+
+    /// Like `tick`, but returns every event of the tick.
+    ///
+    /// An expiring step yields `TimerCompleted`, followed by `TimerStarted`
+    /// when the next step was started automatically. An expiring focus step
+    /// then yields `GatekeeperPrompt`, whether or not its break auto-started.
+    ///
+    /// An auto-started step skips drifting, since the next step is already
+    /// running; drifting begins when a step that is not auto-started runs
+    /// out, e.g. the break before a manual focus.
+    pub fn tick_events(&mut self) -> Vec<Event> {
         match self.state {
             TimerState::Running => {
                 self.flush_elapsed();
-                if self.remaining_ms == 0 {
-                    // Time's up - enter drifting
-                    let task_id = self.session.task_id.clone().unwrap_or_default();
-                    let task_title = self.session.task_title.clone().unwrap_or_default();
-                    self.enter_drifting(task_id, task_title);
-                    return Some(Event::TimerCompleted {
-                        step_index: 0,
-                        step_type: crate::timer::StepType::Focus,
-                        at: Utc::now(),
+                if self.remaining_ms > 0 {
+                    return Vec::new();
+                }
+                let now = Utc::now();
+                let completed_focus = self.step_type == StepType::Focus;
+                let mut events = vec![Event::TimerCompleted {
+                    step_index: self.step_index,
+                    step_type: self.step_type,
+                    planned_ms: self.planned_ms,
+                    actual_ms: self.total_ms,
+                    at: now,
+                }];
+                self.complete_step();
+                match self.auto_start_next() {
+                    Some(started) => events.push(started),
+                    None => {
+                        // Time's up - enter drifting
+                        let task_id = self.session.task_id.clone().unwrap_or_default();
+                        let task_title = self.session.task_title.clone().unwrap_or_default();
+                        self.enter_drifting(task_id, task_title);
+                    }
+                }
+                if completed_focus {
+                    let task_id = self.session.task_id.clone();
+                    events.push(Event::GatekeeperPrompt {
+                        prompt_key: Gatekeeper::focus_complete_key(task_id.as_deref()),
+                        task_id,
+                        at: now,
                     });
                 }
-                None
+                events
             }
             TimerState::Drifting => {
                 // Update break debt while drifting
@@ -219,9 +421,9 @@ impl TimerEngine {
                         }
                     }
                 }
-                None
+                Vec::new()
             }
-            TimerState::Idle => None,
+            TimerState::Idle => Vec::new(),
         }
     }
 
@@ -231,8 +433,17 @@ impl TimerEngine {
         self.session = ActiveSession::default();
         self.remaining_ms = 0;
         self.total_ms = 0;
+        self.planned_ms = 0;
         self.last_tick_epoch_ms = None;
         self.drifting = None;
+        self.step_type = StepType::Focus;
+        self.step_label = None;
+        self.cycle_position = 0;
+    }
+
+    /// Move to `step_index` in the step schedule.
+    pub fn set_step_index(&mut self, step_index: usize) {
+        self.step_index = step_index;
     }
 
     /// Extend the remaining time by the given minutes.
@@ -242,8 +453,81 @@ impl TimerEngine {
         self.total_ms += additional_ms;
     }
 
+    /// End the running task now and enter drifting, as if its time had run out.
+    /// Returns false when no task is running.
+    pub fn skip_to_break(&mut self) -> bool {
+        if self.state != TimerState::Running {
+            return false;
+        }
+        self.flush_elapsed();
+        self.remaining_ms = 0;
+        self.complete_step();
+        let task_id = self.session.task_id.clone().unwrap_or_default();
+        let task_title = self.session.task_title.clone().unwrap_or_default();
+        self.enter_drifting(task_id, task_title);
+        true
+    }
+
     // ── Internal ─────────────────────────────────────────────────────
 
+    /// Advance the cycle for the step that just ended.
+    ///
+    /// A finished focus step counts towards the cycle; a finished long break
+    /// starts a new one.
+    fn complete_step(&mut self) {
+        match self.step_type {
+            StepType::Focus => self.cycle_position = self.cycle_position.saturating_add(1),
+            StepType::Break => self.start_cycle_if_complete(),
+        }
+    }
+
+    /// Start a new cycle once the long break is due or taken.
+    ///
+    /// Breaks the engine does not time still end a cycle when the next
+    /// focus step starts.
+    fn start_cycle_if_complete(&mut self) {
+        if self.cycle_position >= self.pomodoros_before_long_break {
+            self.cycle_position = 0;
+        }
+    }
+
+    /// Start the step after the current one if auto-start covers its type.
+    ///
+    /// The task session is kept so focus resumes on the same task after an
+    /// auto-started break.
+    fn auto_start_next(&mut self) -> Option<Event> {
+        let schedule = self.schedule.as_ref()?;
+        let next_index = (self.step_index + 1) % schedule.steps.len().max(1);
+        let step = schedule.steps.get(next_index)?.clone();
+        let enabled = match step.step_type {
+            StepType::Break => self.auto_start_breaks,
+            StepType::Focus => self.auto_start_focus,
+        };
+        if !enabled {
+            return None;
+        }
+
+        let duration_ms = step.duration_min * 60_000;
+        self.step_index = next_index;
+        self.step_type = step.step_type;
+        self.step_label = match step.step_type {
+            StepType::Break => Some(step.label),
+            StepType::Focus => None,
+        };
+        self.state = TimerState::Running;
+        self.drifting = None;
+        self.remaining_ms = duration_ms;
+        self.total_ms = duration_ms;
+        self.planned_ms = duration_ms;
+        self.last_tick_epoch_ms = Some(now_ms());
+        Some(Event::TimerStarted {
+            step_index: next_index,
+            step_type: step.step_type,
+            duration_secs: duration_ms / 1000,
+            at: Utc::now(),
+        })
+    }
+
     fn flush_elapsed(&mut self) {
         if let Some(last) = self.last_tick_epoch_ms {
             let now = now_ms();
@@ -269,12 +553,19 @@ impl TimerEngine {
     pub fn snapshot(&self) -> Event {
         Event::StateSnapshot {
             state: self.state.clone(),
-            step_index: 0,
-            step_type: crate::timer::StepType::Focus,
-            step_label: self.session.task_title.clone().unwrap_or_default(),
+            step_index: self.step_index,
+            step_type: self.step_type,
+            step_label: self
+                .step_label
+                .clone()
+                .or_else(|| self.session.task_title.clone())
+                .unwrap_or_default(),
             remaining_ms: self.remaining_ms,
             total_ms: self.total_ms,
             schedule_progress_pct: self.progress() * 100.0,
+            cycle_position: self.cycle_position,
+            pomodoros_before_long_break: self.pomodoros_before_long_break,
+            next_step: self.next_step(),
             at: Utc::now(),
         }
     }
@@ -408,4 +699,249 @@ mod tests {
         assert!(drift.break_debt_ms >= 100);
         assert_eq!(drift.task_id, "task-1");
     }
+
+    #[test]
+    fn upcoming_steps_place_long_break_every_fourth_pomodoro() {
+        let schedule = Schedule::pomodoro(25, 5, 15, 4).unwrap();
+        let mut engine = TimerEngine::new();
+        engine.set_step_index(2);
+
+        let upcoming = engine.upcoming_steps(&schedule, 16, true);
+        assert_eq!(upcoming.len(), 16);
+        let long_breaks: Vec<usize> = upcoming
+            .iter()
+            .enumerate()
+            .filter(|(_, step)| step.label == "Long Break")
+            .map(|(i, _)| i)
+            .collect();
+        // Step 2 is the second focus; long breaks close every 8-step cycle.
+        assert_eq!(long_breaks, vec![4, 12]);
+        assert!(upcoming
+            .iter()
+            .filter(|step| step.step_type == crate::timer::StepType::Break)
+            .all(|step| step.duration_min == if step.label == "Long Break" { 15 } else { 5 }));
+        assert_eq!(engine.step_index(), 2);
+    }
+
+    #[test]
+    fn upcoming_steps_near_end_of_finite_schedule() {
+        let schedule = Schedule::pomodoro(25, 5, 15, 4).unwrap();
+        let mut engine = TimerEngine::new();
+        engine.set_step_index(6);
+
+        let upcoming = engine.upcoming_steps(&schedule, 3, false);
+        assert_eq!(upcoming.len(), 1);
+        assert_eq!(upcoming[0].label, "Long Break");
+    }
+
+    fn expired_focus(auto_start_breaks: bool) -> TimerEngine {
+        let schedule = Schedule::pomodoro(25, 5, 15, 4).unwrap();
+        let mut engine = TimerEngine::new().with_auto_start(schedule, auto_start_breaks, false);
+        engine.update_session(
+            Some("task-1".to_string()),
+            Some("Test Task".to_string()),
+            25,
+            0,
+        );
+        // Let the focus step run out
+        engine.remaining_ms = 0;
+        engine
+    }
+
+    #[test]
+    fn auto_start_breaks_runs_break_right_after_focus() {
+        let mut engine = expired_focus(true);
+        let events = engine.tick_events();
+
+        assert_eq!(events.len(), 3);
+        assert!(matches!(
+            events[0],
+            Event::TimerCompleted {
+                step_type: StepType::Focus,
+                ..
+            }
+        ));
+        assert!(matches!(
+            events[1],
+            Event::TimerStarted {
+                step_index: 1,
+                step_type: StepType::Break,
+                duration_secs: 300,
+                ..
+            }
+        ));
+        assert_eq!(engine.state(), TimerState::Running);
+        assert_eq!(engine.step_type(), StepType::Break);
+        assert_eq!(engine.step_index(), 1);
+        assert!(engine.drifting_state().is_none());
+        assert_eq!(engine.current_task_id(), Some("task-1"));
+        match engine.snapshot() {
+            Event::StateSnapshot { step_label, .. } => assert_eq!(step_label, "Short Break"),
+            other => panic!("unexpected event {other:?}"),
+        }
+
+        // Focus auto-start is off, so the break drifts when it runs out
+        engine.remaining_ms = 0;
+        let completed = engine.tick();
+        assert!(matches!(
+            completed,
+            Some(Event::TimerCompleted {
+                step_type: StepType::Break,
+                ..
+            })
+        ));
+        assert_eq!(engine.state(), TimerState::Drifting);
+    }
+
+    #[test]
+    fn gatekeeper_prompt_fires_on_focus_completion_with_auto_start() {
+        for auto_start_breaks in [true, false] {
+            let mut engine = expired_focus(auto_start_breaks);
+            let events = engine.tick_events();
+            match events.last() {
+                Some(Event::GatekeeperPrompt {
+                    prompt_key,
+                    task_id,
+                    ..
+                }) => {
+                    assert_eq!(prompt_key, "focus-complete:task-1");
+                    assert_eq!(task_id.as_deref(), Some("task-1"));
+                }
+                other => panic!("expected a gatekeeper prompt, got {other:?}"),
+            }
+        }
+
+        // The auto-started break ending does not prompt again
+        let mut engine = expired_focus(true);
+        engine.tick_events();
+        engine.remaining_ms = 0;
+        let events = engine.tick_events();
+        assert!(!events
+            .iter()
+            .any(|e| matches!(e, Event::GatekeeperPrompt { .. })));
+    }
+
+    #[test]
+    fn extended_step_reports_actual_longer_than_planned() {
+        let mut engine = expired_focus(true);
+        engine.extend(5);
+        assert_eq!(engine.planned_ms(), 25 * 60_000);
+        engine.remaining_ms = 0;
+
+        let events = engine.tick_events();
+        match events[0] {
+            Event::TimerCompleted {
+                planned_ms,
+                actual_ms,
+                ..
+            } => {
+                assert_eq!(planned_ms, 25 * 60_000);
+                assert_eq!(actual_ms, 30 * 60_000);
+                assert!(actual_ms > planned_ms);
+            }
+            ref other => panic!("unexpected event {other:?}"),
+        }
+
+        // The auto-started break gets its own plan, not the extended focus
+        engine.remaining_ms = 0;
+        match engine.tick() {
+            Some(Event::TimerCompleted {
+                planned_ms,
+                actual_ms,
+                ..
+            }) => {
+                assert_eq!(planned_ms, 5 * 60_000);
+                assert_eq!(actual_ms, planned_ms);
+            }
+            other => panic!("unexpected event {other:?}"),
+        }
+    }
+
+    #[test]
+    fn changing_focus_duration_rebuilds_schedule_but_not_running_step() {
+        let mut config = Config::default();
+        let mut engine = TimerEngine::new();
+        engine.apply_config(&config);
+        engine.update_session(
+            Some("task-1".to_string()),
+            Some("Test Task".to_string()),
+            25,
+            0,
+        );
+
+        config.schedule.focus_duration = 50;
+        engine.apply_config(&config);
+
+        let focus = engine
+            .schedule()
+            .unwrap()
+            .steps
+            .iter()
+            .find(|step| step.step_type == StepType::Focus)
+            .unwrap();
+        assert_eq!(focus.duration_min, 50);
+        assert_eq!(engine.state(), TimerState::Running);
+        assert_eq!(engine.total_ms(), 25 * 60_000);
+    }
+
+    #[test]
+    fn snapshot_previews_long_break_after_three_cycles() {
+        let schedule = Schedule::pomodoro(25, 5, 15, 4).unwrap();
+        let mut engine = TimerEngine::new().with_auto_start(schedule, true, true);
+        engine.update_session(
+            Some("task-1".to_string()),
+            Some("Test Task".to_string()),
+            25,
+            0,
+        );
+        assert_eq!(engine.next_step(), NextStepKind::ShortBreak);
+
+        for _ in 0..3 {
+            engine.remaining_ms = 0;
+            engine.tick_events(); // focus -> short break
+            assert_eq!(engine.next_step(), NextStepKind::Focus);
+            engine.remaining_ms = 0;
+            engine.tick_events(); // short break -> focus
+        }
+
+        match engine.snapshot() {
+            Event::StateSnapshot {
+                cycle_position,
+                pomodoros_before_long_break,
+                next_step,
+                ..
+            } => {
+                assert_eq!(cycle_position, 3);
+                assert_eq!(pomodoros_before_long_break, 4);
+                assert_eq!(next_step, NextStepKind::LongBreak);
+            }
+            other => panic!("unexpected event {other:?}"),
+        }
+
+        // The long break closes the cycle
+        engine.remaining_ms = 0;
+        engine.tick_events();
+        assert_eq!(engine.cycle_position(), 4);
+        engine.remaining_ms = 0;
+        engine.tick_events();
+        assert_eq!(engine.step_type(), StepType::Focus);
+        assert_eq!(engine.cycle_position(), 0);
+        assert_eq!(engine.next_step(), NextStepKind::ShortBreak);
+
+        engine.remaining_ms = 0;
+        engine.tick_events();
+        assert_eq!(engine.cycle_position(), 1);
+        engine.reset();
+        assert_eq!(engine.cycle_position(), 0);
+    }
+
+    #[test]
+    fn without_auto_start_focus_completion_drifts() {
+        let mut engine = expired_focus(false);
+        let events = engine.tick_events();
+        // Completion plus the gatekeeper prompt, no auto-started step
+        assert_eq!(events.len(), 2);
+        assert_eq!(engine.state(), TimerState::Drifting);
+        assert_eq!(engine.step_type(), StepType::Focus);
+    }
 }
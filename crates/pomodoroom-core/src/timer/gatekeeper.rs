@@ -9,15 +9,29 @@
 //! - **Level 1 (Alert)**: 3 minutes passed - more urgent notification
 //! - **Level 2 (Gravity)**: 5 minutes passed - cannot be dismissed, forces action
 //!
+//! ## Snoozing
+//!
+//! Before Gravity the user may snooze a limited number of times, which pushes
+//! the escalation timeline back. Once the snoozes are used up, escalation
+//! proceeds as normal.
+//!
 //! ## Integration with Timer Engine
 //!
 //! The Gatekeeper integrates with `TimerEngine::DriftingState` which tracks:
 //! - `break_debt_ms`: How long the user has been drifting
 //! - `escalation_level`: Current gatekeeper level (0-2)
+//!
+//! `Gatekeeper::tick_escalation` reports each rise in level as an
+//! `Event::GatekeeperEscalated`, so callers can raise native prompts without
+//! diffing polled state.
+
+use std::collections::HashMap;
 
-use chrono::{DateTime, Timelike, Utc};
+use chrono::{DateTime, Datelike, Timelike, Utc, Weekday};
 use serde::{Deserialize, Serialize};
 
+use crate::events::Event;
+
 /// Gatekeeper escalation level
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "lowercase")]
@@ -73,6 +87,40 @@ pub struct GatekeeperState {
     pub break_debt_ms: u64,
     /// Associated prompt key for tracking ignored prompts
     pub prompt_key: String,
+    /// Total time escalation has been pushed back by snoozes (milliseconds)
+    #[serde(default)]
+    pub snoozed_ms: u64,
+    /// Snoozes still allowed for this prompt
+    #[serde(default = "default_max_snoozes")]
+    pub remaining_snoozes: u32,
+}
+
+/// A snooze granted by the gatekeeper
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SnoozeRecord {
+    /// Prompt that was snoozed
+    pub prompt_key: String,
+    /// Snooze length in minutes
+    pub minutes: u32,
+    /// Level the prompt was at when snoozed
+    pub level: GatekeeperLevel,
+    /// When the snooze was granted
+    pub snoozed_at: DateTime<Utc>,
+    /// Snoozes left after this one
+    pub remaining_snoozes: u32,
+}
+
+/// Reasons a snooze is refused
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum SnoozeError {
+    #[error("no prompt is being tracked")]
+    NotActive,
+
+    #[error("cannot snooze at gravity level")]
+    AtGravity,
+
+    #[error("snooze limit of {max} reached")]
+    LimitReached { max: u32 },
 }
 
 /// Context for escalation decisions
@@ -91,6 +139,20 @@ pub struct EscalationThresholds {
     pub alert_threshold_ms: u64,
     /// Duration before Gravity level (default: 5 minutes)
     pub gravity_threshold_ms: u64,
+    /// Snoozes allowed per prompt (default: 2)
+    #[serde(default = "default_max_snoozes")]
+    pub max_snoozes: u32,
+    /// Longest single snooze in minutes (default: 10)
+    #[serde(default = "default_max_snooze_minutes")]
+    pub max_snooze_minutes: u32,
+}
+
+fn default_max_snoozes() -> u32 {
+    2
+}
+
+fn default_max_snooze_minutes() -> u32 {
+    10
 }
 
 impl Default for EscalationThresholds {
@@ -98,17 +160,26 @@ impl Default for EscalationThresholds {
         Self {
             alert_threshold_ms: 3 * 60 * 1000,  // 3 minutes
             gravity_threshold_ms: 5 * 60 * 1000, // 5 minutes
+            max_snoozes: default_max_snoozes(),
+            max_snooze_minutes: default_max_snooze_minutes(),
         }
     }
 }
 
 /// Quiet hours policy
+///
+/// `start_hour`/`end_hour` form the default window for every day; `weekdays`
+/// replaces it on specific days. A window belongs to the day it starts on, so
+/// a Saturday window of 23-10 keeps Sunday morning quiet until 10:00.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct QuietHoursPolicy {
     pub enabled: bool,
     pub start_hour: u8,
     pub end_hour: u8,
+    /// Per-weekday overrides of the default window
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    pub weekdays: HashMap<Weekday, QuietHoursWindow>,
 }
 
 impl Default for QuietHoursPolicy {
@@ -117,10 +188,48 @@ impl Default for QuietHoursPolicy {
             enabled: true,
             start_hour: 22,
             end_hour: 7,
+            weekdays: HashMap::new(),
         }
     }
 }
 
+impl QuietHoursPolicy {
+    /// Override the window for one weekday
+    pub fn with_weekday(mut self, weekday: Weekday, window: QuietHoursWindow) -> Self {
+        self.weekdays.insert(weekday, window);
+        self
+    }
+
+    /// Window starting on `weekday`, falling back to the default window
+    pub fn window_for(&self, weekday: Weekday) -> QuietHoursWindow {
+        self.weekdays
+            .get(&weekday)
+            .copied()
+            .unwrap_or(QuietHoursWindow {
+                start_hour: self.start_hour,
+                end_hour: self.end_hour,
+            })
+    }
+}
+
+/// Quiet hours window for a single day
+///
+/// Crosses midnight when `start_hour > end_hour`; `start_hour == end_hour`
+/// means no quiet hours that day.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct QuietHoursWindow {
+    pub start_hour: u8,
+    pub end_hour: u8,
+}
+
+impl QuietHoursWindow {
+    /// Whether the window continues into the next day
+    pub fn crosses_midnight(&self) -> bool {
+        self.start_hour > self.end_hour
+    }
+}
+
 /// Gatekeeper - ensures users respond to timer completion
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Gatekeeper {
@@ -158,6 +267,8 @@ impl Gatekeeper {
             completed_at,
             break_debt_ms: 0,
             prompt_key,
+            snoozed_ms: 0,
+            remaining_snoozes: self.thresholds.max_snoozes,
         });
     }
 
@@ -167,15 +278,19 @@ impl Gatekeeper {
     }
 
     /// Update break debt and calculate escalation level
+    ///
+    /// Break debt counts the full time since completion; escalation counts it
+    /// minus any snoozed time.
     pub fn tick(&mut self, now: DateTime<Utc>) {
         if let Some(ref mut state) = self.state {
             let elapsed_ms = (now - state.completed_at).num_milliseconds().max(0) as u64;
             state.break_debt_ms = elapsed_ms;
+            let escalation_ms = elapsed_ms.saturating_sub(state.snoozed_ms);
 
             // Update escalation level based on thresholds
-            if elapsed_ms >= self.thresholds.gravity_threshold_ms {
+            if escalation_ms >= self.thresholds.gravity_threshold_ms {
                 state.level = GatekeeperLevel::Gravity;
-            } else if elapsed_ms >= self.thresholds.alert_threshold_ms {
+            } else if escalation_ms >= self.thresholds.alert_threshold_ms {
                 state.level = GatekeeperLevel::Alert;
             } else {
                 state.level = GatekeeperLevel::Nudge;
@@ -183,6 +298,71 @@ impl Gatekeeper {
         }
     }
 
+    /// Tick and report a level rise as an event
+    ///
+    /// Returns `Event::GatekeeperEscalated` only on the tick where the level
+    /// goes up, so repeated ticks at one level yield nothing. A tick that
+    /// skips past both thresholds yields a single event straight to Gravity.
+    /// Drops in level, e.g. after a snooze, are not reported.
+    pub fn tick_escalation(
+        &mut self,
+        now: DateTime<Utc>,
+        context: &EscalationContext,
+    ) -> Option<Event> {
+        let from_level = self.state.as_ref()?.level;
+        self.tick(now);
+        let state = self.state.as_ref()?;
+        if state.level.as_u8() <= from_level.as_u8() {
+            return None;
+        }
+        Some(Event::GatekeeperEscalated {
+            prompt_key: state.prompt_key.clone(),
+            from_level,
+            level: state.level,
+            channel: self.get_notification_channel(context),
+            dismissible: self.can_dismiss(),
+            break_debt_ms: state.break_debt_ms,
+            at: now,
+        })
+    }
+
+    /// Snooze the current prompt at `now`, pushing escalation back by `minutes`
+    ///
+    /// `minutes` is capped at `max_snooze_minutes`; the returned record holds
+    /// the length actually granted. The new level takes effect on the next
+    /// `tick`. Pass the returned
+    /// record to [`PromptTracker::record_snooze`] to keep an audit trail.
+    ///
+    /// # Errors
+    /// Refused when no prompt is tracked, at Gravity level, or once
+    /// `max_snoozes` snoozes have been granted.
+    pub fn snooze(
+        &mut self,
+        minutes: u32,
+        now: DateTime<Utc>,
+    ) -> Result<SnoozeRecord, SnoozeError> {
+        let state = self.state.as_mut().ok_or(SnoozeError::NotActive)?;
+        if state.level == GatekeeperLevel::Gravity {
+            return Err(SnoozeError::AtGravity);
+        }
+        if state.remaining_snoozes == 0 {
+            return Err(SnoozeError::LimitReached {
+                max: self.thresholds.max_snoozes,
+            });
+        }
+
+        let minutes = minutes.min(self.thresholds.max_snooze_minutes);
+        state.remaining_snoozes -= 1;
+        state.snoozed_ms += u64::from(minutes) * 60 * 1000;
+        Ok(SnoozeRecord {
+            prompt_key: state.prompt_key.clone(),
+            minutes,
+            level: state.level,
+            snoozed_at: now,
+            remaining_snoozes: state.remaining_snoozes,
+        })
+    }
+
     /// Get current state
     pub fn state(&self) -> Option<&GatekeeperState> {
         self.state.as_ref()
@@ -212,26 +392,39 @@ impl Gatekeeper {
     }
 
     /// Check if a given time is within quiet hours
+    ///
+    /// Uses the window for the weekday of `time`, plus the tail of the
+    /// previous day's window when that one crosses midnight.
     pub fn is_quiet_hours(time: DateTime<Utc>, policy: &QuietHoursPolicy) -> bool {
         if !policy.enabled {
             return false;
         }
 
         let hour = time.hour();
+        let today = policy.window_for(time.weekday());
+        let yesterday = policy.window_for(time.weekday().pred());
 
-        // Overnight window (e.g., 22:00 - 07:00)
-        if policy.start_hour > policy.end_hour {
-            return hour >= policy.start_hour as u32 || hour < policy.end_hour as u32;
-        }
+        let in_today = if today.crosses_midnight() {
+            // Overnight window (e.g., 22:00 - 07:00): evening part
+            hour >= today.start_hour as u32
+        } else {
+            // Daytime window (e.g., 12:00 - 17:00)
+            hour >= today.start_hour as u32 && hour < today.end_hour as u32
+        };
 
-        // Daytime window (e.g., 12:00 - 17:00)
-        hour >= policy.start_hour as u32 && hour < policy.end_hour as u32
+        // Morning part of an overnight window that started yesterday
+        in_today || (yesterday.crosses_midnight() && hour < yesterday.end_hour as u32)
     }
 
     /// Create prompt key for critical start notification
     pub fn critical_start_key(task_id: &str) -> String {
         format!("critical-start:{}", task_id)
     }
+
+    /// Create prompt key for the break prompt after a focus step
+    pub fn focus_complete_key(task_id: Option<&str>) -> String {
+        format!("focus-complete:{}", task_id.unwrap_or_default())
+    }
 }
 
 /// In-memory ignored prompt tracker (session-based)
@@ -240,6 +433,8 @@ impl Gatekeeper {
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct PromptTracker {
     ignored_counts: std::collections::HashMap<String, u32>,
+    #[serde(default)]
+    snoozes: std::collections::HashMap<String, Vec<SnoozeRecord>>,
 }
 
 impl PromptTracker {
@@ -249,10 +444,25 @@ impl PromptTracker {
     }
 
     /// Acknowledge a prompt, resetting the escalation ladder
+    ///
+    /// The snooze log is kept for auditing.
     pub fn acknowledge(&mut self, prompt_key: &str) {
         self.ignored_counts.remove(prompt_key);
     }
 
+    /// Log a snooze granted by the gatekeeper
+    pub fn record_snooze(&mut self, record: SnoozeRecord) {
+        self.snoozes
+            .entry(record.prompt_key.clone())
+            .or_default()
+            .push(record);
+    }
+
+    /// Snoozes logged for a prompt, oldest first
+    pub fn snoozes(&self, prompt_key: &str) -> &[SnoozeRecord] {
+        self.snoozes.get(prompt_key).map_or(&[], Vec::as_slice)
+    }
+
     /// Get ignored count for a prompt
     pub fn ignored_count(&self, prompt_key: &str) -> u32 {
         *self.ignored_counts.get(prompt_key).unwrap_or(&0)
@@ -329,6 +539,81 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_tick_escalation_emits_once_per_transition() {
+        let mut gatekeeper = Gatekeeper::new();
+        let context = EscalationContext {
+            is_dnd: false,
+            is_quiet_hours: false,
+        };
+        let completed_at = Utc::now();
+        gatekeeper.start("test-prompt".to_string(), completed_at);
+
+        let events: Vec<Event> = (0..=7 * 60)
+            .step_by(10)
+            .filter_map(|secs| {
+                gatekeeper.tick_escalation(completed_at + Duration::from_secs(secs), &context)
+            })
+            .collect();
+        assert_eq!(events.len(), 2);
+        match &events[0] {
+            Event::GatekeeperEscalated {
+                from_level,
+                level,
+                channel,
+                dismissible,
+                ..
+            } => {
+                assert_eq!(*from_level, GatekeeperLevel::Nudge);
+                assert_eq!(*level, GatekeeperLevel::Alert);
+                assert_eq!(*channel, NotificationChannel::Toast);
+                assert!(dismissible);
+            }
+            other => panic!("unexpected event {other:?}"),
+        }
+        match &events[1] {
+            Event::GatekeeperEscalated {
+                prompt_key,
+                from_level,
+                level,
+                channel,
+                dismissible,
+                break_debt_ms,
+                ..
+            } => {
+                assert_eq!(prompt_key, "test-prompt");
+                assert_eq!(*from_level, GatekeeperLevel::Alert);
+                assert_eq!(*level, GatekeeperLevel::Gravity);
+                assert_eq!(*channel, NotificationChannel::Modal);
+                assert!(!dismissible);
+                assert_eq!(*break_debt_ms, 5 * 60 * 1000);
+            }
+            other => panic!("unexpected event {other:?}"),
+        }
+
+        // A polling gap across both thresholds is a single transition
+        gatekeeper.start("test-prompt".to_string(), completed_at);
+        let quiet = EscalationContext {
+            is_dnd: false,
+            is_quiet_hours: true,
+        };
+        let jump = gatekeeper
+            .tick_escalation(completed_at + Duration::from_secs(6 * 60), &quiet)
+            .unwrap();
+        assert!(matches!(
+            jump,
+            Event::GatekeeperEscalated {
+                from_level: GatekeeperLevel::Nudge,
+                level: GatekeeperLevel::Gravity,
+                channel: NotificationChannel::Badge,
+                ..
+            }
+        ));
+
+        gatekeeper.stop();
+        assert!(gatekeeper.tick_escalation(Utc::now(), &context).is_none());
+    }
+
     #[test]
     fn test_can_dismiss() {
         let mut gatekeeper = Gatekeeper::new();
@@ -354,6 +639,7 @@ mod tests {
             enabled: true,
             start_hour: 22,
             end_hour: 7,
+            weekdays: HashMap::new(),
         };
 
         // 23:00 should be in quiet hours
@@ -369,6 +655,58 @@ mod tests {
         assert!(!Gatekeeper::is_quiet_hours(day, &policy));
     }
 
+    #[test]
+    fn test_quiet_hours_weekend_override() {
+        use chrono::TimeZone;
+
+        // Weekdays 22-7; Friday and Saturday nights run until 10:00
+        let weekend = QuietHoursWindow {
+            start_hour: 23,
+            end_hour: 10,
+        };
+        let policy = QuietHoursPolicy::default()
+            .with_weekday(Weekday::Fri, weekend)
+            .with_weekday(Weekday::Sat, weekend);
+        // 2025-03-03 is a Monday
+        let at = |day: u32, hour: u32| Utc.with_ymd_and_hms(2025, 3, day, hour, 0, 0).unwrap();
+
+        // Tuesday uses the default window
+        assert!(Gatekeeper::is_quiet_hours(at(4, 6), &policy));
+        assert!(!Gatekeeper::is_quiet_hours(at(4, 8), &policy));
+        assert!(Gatekeeper::is_quiet_hours(at(4, 22), &policy));
+
+        // Friday night starts later and carries into Saturday morning
+        assert!(!Gatekeeper::is_quiet_hours(at(7, 22), &policy));
+        assert!(Gatekeeper::is_quiet_hours(at(7, 23), &policy));
+        assert!(Gatekeeper::is_quiet_hours(at(8, 9), &policy));
+        assert!(!Gatekeeper::is_quiet_hours(at(8, 10), &policy));
+
+        // Sunday 9:00 is still covered by Saturday's window
+        assert!(Gatekeeper::is_quiet_hours(at(9, 9), &policy));
+        // Sunday night is back to the default window, ending Monday 7:00
+        assert!(Gatekeeper::is_quiet_hours(at(9, 22), &policy));
+        assert!(!Gatekeeper::is_quiet_hours(at(10, 7), &policy));
+    }
+
+    #[test]
+    fn test_quiet_hours_policy_serde_with_weekdays() {
+        let policy = QuietHoursPolicy::default().with_weekday(
+            Weekday::Sun,
+            QuietHoursWindow {
+                start_hour: 0,
+                end_hour: 10,
+            },
+        );
+        let json = serde_json::to_value(&policy).unwrap();
+        assert_eq!(json["weekdays"]["Sun"]["endHour"], 10);
+
+        // The single-window form still parses
+        let legacy: QuietHoursPolicy =
+            serde_json::from_str(r#"{"enabled":true,"startHour":22,"endHour":7}"#).unwrap();
+        assert!(legacy.weekdays.is_empty());
+        assert_eq!(legacy.window_for(Weekday::Sun).end_hour, 7);
+    }
+
     #[test]
     fn test_notification_channel_with_dnd() {
         let gatekeeper = Gatekeeper::new();
@@ -408,6 +746,93 @@ mod tests {
         assert_eq!(tracker.compute_channel("test", &context), NotificationChannel::Badge);
     }
 
+    #[test]
+    fn test_snooze_is_bounded_and_escalation_resumes() {
+        let mut gatekeeper = Gatekeeper::new();
+        let mut tracker = PromptTracker::default();
+        let completed_at = Utc::now();
+        gatekeeper.start("test-prompt".to_string(), completed_at);
+        assert_eq!(gatekeeper.state().unwrap().remaining_snoozes, 2);
+
+        // Alert after 3 minutes; a 5 minute snooze drops back to Nudge
+        gatekeeper.tick(completed_at + Duration::from_secs(3 * 60));
+        assert_eq!(gatekeeper.state().unwrap().level, GatekeeperLevel::Alert);
+        let snoozed_at = completed_at + Duration::from_secs(3 * 60);
+        tracker.record_snooze(gatekeeper.snooze(5, snoozed_at).unwrap());
+        gatekeeper.tick(completed_at + Duration::from_secs(4 * 60));
+        assert_eq!(gatekeeper.state().unwrap().level, GatekeeperLevel::Nudge);
+        assert_eq!(gatekeeper.state().unwrap().break_debt_ms, 4 * 60 * 1000);
+
+        tracker.record_snooze(gatekeeper.snooze(5, snoozed_at).unwrap());
+        assert_eq!(gatekeeper.state().unwrap().remaining_snoozes, 0);
+
+        // Third snooze is refused
+        assert_eq!(
+            gatekeeper.snooze(5, snoozed_at),
+            Err(SnoozeError::LimitReached { max: 2 })
+        );
+        assert_eq!(tracker.snoozes("test-prompt").len(), 2);
+        assert_eq!(
+            tracker.snoozes("test-prompt")[0].level,
+            GatekeeperLevel::Alert
+        );
+        assert_eq!(tracker.snoozes("test-prompt")[0].snoozed_at, snoozed_at);
+        assert_eq!(tracker.snoozes("test-prompt")[1].remaining_snoozes, 0);
+
+        // Escalation resumes: 5 minutes of gravity threshold + 10 snoozed
+        gatekeeper.tick(completed_at + Duration::from_secs(14 * 60));
+        assert_eq!(gatekeeper.state().unwrap().level, GatekeeperLevel::Alert);
+        gatekeeper.tick(completed_at + Duration::from_secs(15 * 60));
+        assert_eq!(gatekeeper.state().unwrap().level, GatekeeperLevel::Gravity);
+    }
+
+    #[test]
+    fn test_snooze_refused_at_gravity() {
+        let mut gatekeeper = Gatekeeper::with_thresholds(EscalationThresholds {
+            max_snoozes: 5,
+            ..EscalationThresholds::default()
+        });
+        assert_eq!(gatekeeper.snooze(5, Utc::now()), Err(SnoozeError::NotActive));
+
+        let completed_at = Utc::now();
+        gatekeeper.start("test-prompt".to_string(), completed_at);
+        gatekeeper.tick(completed_at + Duration::from_secs(6 * 60));
+
+        assert_eq!(
+            gatekeeper.snooze(5, completed_at + Duration::from_secs(6 * 60)),
+            Err(SnoozeError::AtGravity)
+        );
+        assert_eq!(gatekeeper.state().unwrap().remaining_snoozes, 5);
+    }
+
+    #[test]
+    fn test_snooze_length_is_capped() {
+        let mut gatekeeper = Gatekeeper::new();
+        let completed_at = Utc::now();
+        gatekeeper.start("test-prompt".to_string(), completed_at);
+
+        let record = gatekeeper.snooze(600, completed_at).unwrap();
+        assert_eq!(record.minutes, 10);
+        assert_eq!(gatekeeper.state().unwrap().snoozed_ms, 10 * 60 * 1000);
+
+        // Still reaches Gravity once the capped snooze has passed
+        gatekeeper.tick(completed_at + Duration::from_secs(15 * 60));
+        assert_eq!(gatekeeper.state().unwrap().level, GatekeeperLevel::Gravity);
+    }
+
+    #[test]
+    fn test_state_without_remaining_snoozes_gets_default_budget() {
+        let state: GatekeeperState = serde_json::from_value(serde_json::json!({
+            "level": "nudge",
+            "completed_at": "2026-01-01T00:00:00Z",
+            "break_debt_ms": 0,
+            "prompt_key": "test-prompt",
+        }))
+        .unwrap();
+        assert_eq!(state.remaining_snoozes, 2);
+        assert_eq!(state.snoozed_ms, 0);
+    }
+
     #[test]
     fn test_critical_start_key() {
         assert_eq!(
@@ -3,10 +3,11 @@ mod gatekeeper;
 mod schedule;
 mod streak_decay;
 
-pub use engine::{DriftingState, TimerEngine, TimerState};
+pub use engine::{DriftingState, NextStepKind, TimerEngine, TimerState};
 pub use gatekeeper::{
     EscalationContext, EscalationThresholds, Gatekeeper, GatekeeperLevel, GatekeeperState,
-    NotificationChannel, PromptTracker, QuietHoursPolicy,
+    NotificationChannel, PromptTracker, QuietHoursPolicy, QuietHoursWindow, SnoozeError,
+    SnoozeRecord,
 };
 pub use schedule::{Schedule, Step, StepType};
 pub use streak_decay::{
@@ -123,6 +123,60 @@ impl Schedule {
         }
     }
 
+    /// Classic pomodoro cycle: `pomodoros_before_long_break` focus steps,
+    /// each followed by a short break, with a long break closing the cycle.
+    ///
+    /// # Errors
+    /// Returns an error if `pomodoros_before_long_break` is zero.
+    pub fn pomodoro(
+        focus_min: u32,
+        short_break_min: u32,
+        long_break_min: u32,
+        pomodoros_before_long_break: u32,
+    ) -> Result<Self> {
+        let mut steps = Vec::new();
+        for i in 0..pomodoros_before_long_break {
+            steps.push(Step {
+                step_type: StepType::Focus,
+                duration_min: u64::from(focus_min),
+                label: format!("Focus {}", i + 1),
+                description: String::new(),
+            });
+            let is_long_break = (i + 1) % pomodoros_before_long_break == 0;
+            steps.push(Step {
+                step_type: StepType::Break,
+                duration_min: u64::from(if is_long_break {
+                    long_break_min
+                } else {
+                    short_break_min
+                }),
+                label: if is_long_break {
+                    "Long Break".to_string()
+                } else {
+                    "Short Break".to_string()
+                },
+                description: String::new(),
+            });
+        }
+        Self::new(steps)
+    }
+
+    /// Up to `n` steps following `step_index`.
+    ///
+    /// A finite schedule stops at its last step; a cyclic one wraps to the
+    /// start and always yields `n` steps.
+    pub fn upcoming(&self, step_index: usize, n: usize, cyclic: bool) -> Vec<Step> {
+        let len = self.steps.len();
+        let start = step_index + 1;
+        if cyclic {
+            (start..start + n)
+                .map(|i| self.steps[i % len].clone())
+                .collect()
+        } else {
+            self.steps.iter().skip(start).take(n).cloned().collect()
+        }
+    }
+
     pub fn total_duration_min(&self) -> u64 {
         self.steps.iter().map(|s| s.duration_min).sum()
     }
@@ -194,4 +248,24 @@ mod tests {
         let schedule = result.unwrap();
         assert_eq!(schedule.steps.len(), 1);
     }
+
+    #[test]
+    fn pomodoro_schedule_cadence() {
+        let s = Schedule::pomodoro(25, 5, 15, 4).unwrap();
+        assert_eq!(s.steps.len(), 8);
+        assert_eq!(s.focus_count(), 4);
+        assert_eq!(s.steps[7].label, "Long Break");
+        assert_eq!(s.steps[7].duration_min, 15);
+        assert_eq!(s.steps[5].duration_min, 5);
+        assert!(Schedule::pomodoro(25, 5, 15, 0).is_err());
+    }
+
+    #[test]
+    fn upcoming_stops_at_end_of_finite_schedule() {
+        let s = Schedule::pomodoro(25, 5, 15, 2).unwrap();
+        let next = s.upcoming(1, 5, false);
+        assert_eq!(next.len(), 2);
+        assert_eq!(next[1].label, "Long Break");
+        assert!(s.upcoming(3, 5, false).is_empty());
+    }
 }
@@ -3,6 +3,9 @@
 //! This module implements a weighted decay system for focus streaks
 //! based on interruption types. Different interruptions have different
 //! impacts on the streak value.
+//!
+//! When interruptions are tracked as interrupt/resume pairs, one resolved
+//! within `grace_window_seconds` leaves the streak untouched.
 
 use chrono::{DateTime, Duration, Utc};
 use serde::{Deserialize, Serialize};
@@ -51,7 +54,8 @@ impl InterruptionType {
 /// Configuration for streak decay behavior
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct StreakDecayConfig {
-    /// Grace window duration for quick checks (seconds)
+    /// Grace window duration for quick checks (seconds); interruptions
+    /// resumed within it do not decay the streak
     pub grace_window_seconds: i64,
     /// Minimum streak value before decay
     pub min_streak: u32,
@@ -89,6 +93,9 @@ pub struct StreakDecayEvent {
     pub reason: String,
     /// Duration of the interruption (if known)
     pub interruption_duration: Option<Duration>,
+    /// Resumed within `grace_window_seconds`, so no decay was applied
+    #[serde(default)]
+    pub within_grace: bool,
 }
 
 /// Streak decay calculator
@@ -167,6 +174,42 @@ impl StreakDecayCalculator {
             decay_amount,
             reason,
             interruption_duration,
+            within_grace: false,
+        }
+    }
+
+    /// Create the event for an interruption that ended at `resumed_at`
+    ///
+    /// If it lasted no longer than `grace_window_seconds` the streak is kept
+    /// and the event is marked `within_grace`; otherwise it decays as usual.
+    pub fn create_resume_event(
+        &self,
+        streak_before: u32,
+        interruption_type: InterruptionType,
+        interrupted_at: DateTime<Utc>,
+        resumed_at: DateTime<Utc>,
+    ) -> StreakDecayEvent {
+        let duration = (resumed_at - interrupted_at).max(Duration::zero());
+        if duration.num_seconds() > self.config.grace_window_seconds {
+            return StreakDecayEvent {
+                timestamp: resumed_at,
+                ..self.create_decay_event(streak_before, interruption_type, Some(duration))
+            };
+        }
+
+        StreakDecayEvent {
+            timestamp: resumed_at,
+            interruption_type,
+            streak_before,
+            streak_after: streak_before,
+            decay_amount: 0.0,
+            reason: format!(
+                "{} resolved within grace ({} seconds), no decay",
+                interruption_type.description(),
+                duration.num_seconds()
+            ),
+            interruption_duration: Some(duration),
+            within_grace: true,
         }
     }
 
@@ -265,15 +308,13 @@ pub struct StreakManager {
     calculator: StreakDecayCalculator,
     current_streak: u32,
     decay_history: Vec<StreakDecayEvent>,
+    /// Interruption awaiting its resume
+    pending_interruption: Option<(InterruptionType, DateTime<Utc>)>,
 }
 
 impl StreakManager {
     pub fn new() -> Self {
-        Self {
-            calculator: StreakDecayCalculator::new(),
-            current_streak: 0,
-            decay_history: Vec::new(),
-        }
+        Self::with_config(StreakDecayConfig::default())
     }
 
     pub fn with_config(config: StreakDecayConfig) -> Self {
@@ -281,6 +322,7 @@ impl StreakManager {
             calculator: StreakDecayCalculator::with_config(config),
             current_streak: 0,
             decay_history: Vec::new(),
+            pending_interruption: None,
         }
     }
 
@@ -303,7 +345,42 @@ impl StreakManager {
         let event =
             self.calculator
                 .create_decay_event(self.current_streak, interruption_type, duration);
+        self.record(event)
+    }
 
+    /// Mark the start of an interruption; decay is decided on `resume`
+    ///
+    /// An interruption still pending is resolved at `at` first.
+    pub fn begin_interruption(
+        &mut self,
+        interruption_type: InterruptionType,
+        at: DateTime<Utc>,
+    ) -> Option<StreakDecayEvent> {
+        let previous = self.resume(at);
+        self.pending_interruption = Some((interruption_type, at));
+        previous
+    }
+
+    /// Resolve the pending interruption at `at`
+    ///
+    /// Returns `None` when no interruption is pending.
+    pub fn resume(&mut self, at: DateTime<Utc>) -> Option<StreakDecayEvent> {
+        let (interruption_type, interrupted_at) = self.pending_interruption.take()?;
+        let event = self.calculator.create_resume_event(
+            self.current_streak,
+            interruption_type,
+            interrupted_at,
+            at,
+        );
+        Some(self.record(event))
+    }
+
+    /// The interruption awaiting its resume, with when it started
+    pub fn pending_interruption(&self) -> Option<(InterruptionType, DateTime<Utc>)> {
+        self.pending_interruption
+    }
+
+    fn record(&mut self, event: StreakDecayEvent) -> StreakDecayEvent {
         self.current_streak = event.streak_after;
 
         if self.calculator.config.enable_logging {
@@ -431,6 +508,37 @@ mod tests {
         assert_eq!(manager.decay_history().len(), 1);
     }
 
+    #[test]
+    fn test_interruption_resumed_within_grace_keeps_streak() {
+        let config = StreakDecayConfig {
+            grace_window_seconds: 120,
+            ..Default::default()
+        };
+        let mut manager = StreakManager::with_config(config);
+        for _ in 0..20 {
+            manager.increment_streak();
+        }
+        let start = Utc::now();
+
+        manager.begin_interruption(InterruptionType::ExternalNotification, start);
+        assert!(manager.pending_interruption().is_some());
+        let event = manager.resume(start + Duration::seconds(30)).unwrap();
+        assert!(event.within_grace);
+        assert_eq!(event.streak_after, 20);
+        assert!(event.reason.contains("no decay"));
+        assert_eq!(manager.current_streak(), 20);
+
+        let later = start + Duration::minutes(10);
+        manager.begin_interruption(InterruptionType::ExternalNotification, later);
+        let event = manager.resume(later + Duration::minutes(5)).unwrap();
+        assert!(!event.within_grace);
+        assert!(event.streak_after < 20);
+        assert_eq!(manager.current_streak(), event.streak_after);
+
+        assert!(manager.resume(later + Duration::minutes(6)).is_none());
+        assert_eq!(manager.decay_history().len(), 2);
+    }
+
     #[test]
     fn test_streak_min_max() {
         let config = StreakDecayConfig {
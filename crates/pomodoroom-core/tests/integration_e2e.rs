@@ -4,16 +4,17 @@
 //! without requiring real credentials or external API access.
 //!
 //! Test matrix coverage:
-//! | Service | authenticate | is_authenticated | disconnect | on_focus_start | on_break_start | on_session_complete |
-//! |---------|-------------|------------------|------------|----------------|----------------|---------------------|
-//! | Google  | OAuth2      | Token check      | Clear      | Calendar event | no-op          | no-op               |
-//! | Notion  | API verify  | Token+DB check   | Clear both | no-op          | no-op          | Create page         |
-//! | Linear  | GraphQL     | Key check        | Clear      | Tracking mark  | no-op          | Clear marker        |
-//! | GitHub  | API verify  | Token check      | Clear      | Set status     | Set status     | Clear status        |
-//! | Discord | URL validate| URL check        | Clear      | Post message   | no-op          | Post message        |
-//! | Slack   | auth.test   | Token check      | Clear      | Status+DND     | Clear DND      | Clear status        |
+//! | Service | authenticate | is_authenticated | disconnect | on_focus_start | on_break_start | on_session_complete | health_check  |
+//! |---------|-------------|------------------|------------|----------------|----------------|---------------------|---------------|
+//! | Google  | OAuth2      | Token check      | Clear      | Calendar event | no-op          | no-op               | calendarList  |
+//! | Notion  | API verify  | Token+DB check   | Clear both | no-op          | no-op          | Create page         | users/me      |
+//! | Linear  | GraphQL     | Key check        | Clear      | Tracking mark  | no-op          | Clear marker        | viewer query  |
+//! | GitHub  | API verify  | Token check      | Clear      | Set status     | Set status     | Clear status        | GET /user     |
+//! | Discord | URL validate| URL check        | Clear      | Post message   | no-op          | Post message        | GET webhook   |
+//! | Slack   | auth.test   | Token check      | Clear      | Status+DND     | Clear DND      | Clear status        | auth.test     |
 
 use chrono::Utc;
+use pomodoroom_core::integrations::health::HealthState;
 use pomodoroom_core::integrations::traits::Integration;
 use pomodoroom_core::storage::database::SessionRecord;
 use std::collections::HashMap;
@@ -138,6 +139,66 @@ fn test_github_name_and_display_name() {
     assert_eq!(integration.display_name(), "GitHub");
 }
 
+#[test]
+fn test_github_health_check_healthy_on_200() {
+    let mut server = mockito::Server::new();
+    let whoami = server
+        .mock("GET", "/user")
+        .match_header("authorization", "Bearer ghp_valid")
+        .with_status(200)
+        .with_body(r#"{"login":"octocat"}"#)
+        .create();
+
+    let integration = pomodoroom_core::integrations::github::GitHubIntegration::with_api_base(
+        "ghp_valid",
+        server.url(),
+    );
+    let status = integration.health_check();
+
+    whoami.assert();
+    assert_eq!(status.state, HealthState::Connected);
+    assert!(status.latency_ms.is_some());
+    assert!(!status.needs_reauth());
+}
+
+#[test]
+fn test_github_health_check_unauthorized_on_401() {
+    let mut server = mockito::Server::new();
+    server
+        .mock("GET", "/user")
+        .with_status(401)
+        .with_body(r#"{"message":"Bad credentials"}"#)
+        .create();
+
+    let integration = pomodoroom_core::integrations::github::GitHubIntegration::with_api_base(
+        "ghp_revoked",
+        server.url(),
+    );
+    let status = integration.health_check();
+
+    assert_eq!(status.state, HealthState::Unauthorized);
+    assert!(status.needs_reauth());
+}
+
+#[test]
+fn test_github_health_check_unreachable_without_server() {
+    // Bind and release a port so nothing is listening on it
+    let port = std::net::TcpListener::bind("127.0.0.1:0")
+        .unwrap()
+        .local_addr()
+        .unwrap()
+        .port();
+
+    let integration = pomodoroom_core::integrations::github::GitHubIntegration::with_api_base(
+        "ghp_valid",
+        format!("http://127.0.0.1:{port}"),
+    );
+    let status = integration.health_check();
+
+    assert_eq!(status.state, HealthState::Unreachable);
+    assert!(!status.needs_reauth());
+}
+
 // ============================================================================
 // Google E2E Tests
 // ============================================================================
@@ -239,6 +300,35 @@ fn test_linear_name_and_display_name() {
     assert_eq!(integration.display_name(), "Linear");
 }
 
+#[test]
+fn test_linear_issue_states_map_to_task_state() {
+    use pomodoroom_core::integrations::linear::{reconcile_issues, LinearIssue, StateMapping};
+    use pomodoroom_core::task::TaskState;
+
+    let issue = |id: &str, state: &str| LinearIssue {
+        id: id.to_string(),
+        identifier: "LIN-123".to_string(),
+        title: "Ship state mapping".to_string(),
+        state: state.to_string(),
+        state_type: None,
+        priority: 2,
+        url: "https://linear.app/team/issue/LIN-123".to_string(),
+    };
+
+    let mut tasks = Vec::new();
+    reconcile_issues(
+        &mut tasks,
+        &[issue("issue-1", "In Progress"), issue("issue-2", "Done")],
+        &StateMapping::default(),
+    );
+
+    assert_eq!(tasks.len(), 2);
+    assert_eq!(tasks[0].state, TaskState::Running);
+    assert_eq!(tasks[0].source_external_id.as_deref(), Some("issue-1"));
+    assert_eq!(tasks[1].state, TaskState::Done);
+    assert!(tasks[1].completed);
+}
+
 // ============================================================================
 // Notion E2E Tests
 // ============================================================================
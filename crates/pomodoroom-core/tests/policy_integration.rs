@@ -100,6 +100,7 @@ fn test_apply_overwrites_config() {
             pomodoros_before_long_break: 2,
             custom_schedule: None,
         },
+        signature: None,
     };
 
     let mut config = Config::default();
@@ -12,7 +12,7 @@
 
 use chrono::{DateTime, Duration, Utc};
 use pomodoroom_core::events::Event;
-use pomodoroom_core::storage::Database;
+use pomodoroom_core::storage::{Database, DbRecovery};
 use pomodoroom_core::timeline::{
     calculate_priority, calculate_priority_with_config, detect_time_gaps, generate_proposals,
     PriorityConfig, TimeGap, TimelineEvent, TimelineItem,
@@ -20,11 +20,12 @@ use pomodoroom_core::timeline::{
 use pomodoroom_core::timer::{TimerEngine, TimerState};
 use pomodoroom_core::Config;
 use pomodoroom_core::storage::schedule_db::ScheduleDb;
-use pomodoroom_core::jit_engine::{JitContext, JitEngine, TaskSuggestion};
+use pomodoroom_core::jit_engine::{BreakDecision, JitContext, JitEngine, TaskSuggestion};
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
-use std::sync::Mutex;
-use tauri::{AppHandle, Manager, State};
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex, RwLock};
+use tauri::{AppHandle, Emitter, Manager, State};
 
 // === Security Validation Constants ===
 
@@ -173,33 +174,203 @@ pub struct ActiveSession {
     last_elapsed_update: Option<DateTime<Utc>>,
 }
 
+impl ActiveSession {
+    /// Record that `task_id` (in `project_id`) has just started tracking.
+    pub fn start(&mut self, task_id: String, project_id: Option<String>) {
+        let now = Utc::now();
+        self.task_id = Some(task_id);
+        self.project_id = project_id;
+        self.started_at = Some(now);
+        self.last_elapsed_update = Some(now);
+    }
+}
+
+/// Timer engine and session for one parallel lane.
+pub struct LaneTimer {
+    pub engine: Mutex<TimerEngine>,
+    pub active_session: Mutex<ActiveSession>,
+}
+
+/// Timer engine with the schedule and auto-start settings from `Config`.
+fn configured_engine() -> TimerEngine {
+    let mut engine = TimerEngine::new();
+    engine.apply_config(&Config::load_or_default());
+    engine
+}
+
+impl LaneTimer {
+    fn new() -> Self {
+        Self {
+            engine: Mutex::new(configured_engine()),
+            active_session: Mutex::new(ActiveSession::default()),
+        }
+    }
+}
+
 /// Shared timer engine state, protected by a Mutex.
 ///
 /// The engine lives in-process for the desktop app (no subprocess needed
 /// for the hot path). The CLI binary uses the same core library independently.
+///
+/// `engine`/`active_session` are lane 0, which all single-engine commands use.
+/// Additional parallel lanes live in `lanes`, each behind its own locks so
+/// ticking one lane never blocks another. Lanes are limited to the daily
+/// template's `max_parallel_lanes` and dropped again when closed.
 pub struct EngineState {
     pub engine: Mutex<TimerEngine>,
     pub active_session: Mutex<ActiveSession>,
+    pub lanes: RwLock<HashMap<u32, Arc<LaneTimer>>>,
 }
 
 impl EngineState {
     /// Creates a new engine state with task-based timer.
     pub fn new() -> Self {
         Self {
-            engine: Mutex::new(TimerEngine::new()),
+            engine: Mutex::new(configured_engine()),
             active_session: Mutex::new(ActiveSession::default()),
+            lanes: RwLock::new(HashMap::new()),
+        }
+    }
+
+    /// Get the timer for an additional lane (>= 1), creating it on first use.
+    ///
+    /// Lanes numbered `max_lanes` or higher are rejected.
+    fn lane(&self, lane: u32, max_lanes: u32) -> Result<Arc<LaneTimer>, String> {
+        if lane >= max_lanes {
+            return Err(format!(
+                "Lane {lane} is out of range (max parallel lanes: {max_lanes})"
+            ));
         }
+        if let Some(timer) = self
+            .lanes
+            .read()
+            .map_err(|e| format!("Lock failed: {e}"))?
+            .get(&lane)
+        {
+            return Ok(Arc::clone(timer));
+        }
+        let mut lanes = self
+            .lanes
+            .write()
+            .map_err(|e| format!("Lock failed: {e}"))?;
+        Ok(Arc::clone(
+            lanes
+                .entry(lane)
+                .or_insert_with(|| Arc::new(LaneTimer::new())),
+        ))
+    }
+
+    /// Drop the timer for an additional lane so it is no longer ticked.
+    fn close_lane(&self, lane: u32) -> Result<(), String> {
+        self.lanes
+            .write()
+            .map_err(|e| format!("Lock failed: {e}"))?
+            .remove(&lane);
+        Ok(())
+    }
+
+    /// Snapshot of additional lanes, sorted by lane number.
+    ///
+    /// The map lock is released before returning so callers can lock lanes freely.
+    fn extra_lanes(&self) -> Result<Vec<(u32, Arc<LaneTimer>)>, String> {
+        let lanes = self.lanes.read().map_err(|e| format!("Lock failed: {e}"))?;
+        let mut list: Vec<_> = lanes
+            .iter()
+            .map(|(lane, timer)| (*lane, Arc::clone(timer)))
+            .collect();
+        list.sort_by_key(|(lane, _)| *lane);
+        Ok(list)
+    }
+
+    /// Rebuild every lane's schedule from `config`.
+    ///
+    /// Running steps keep their remaining time; see `TimerEngine::apply_config`.
+    fn apply_config(&self, config: &Config) -> Result<(), String> {
+        self.engine
+            .lock()
+            .map_err(|e| format!("Lock failed: {e}"))?
+            .apply_config(config);
+        for (_, timer) in self.extra_lanes()? {
+            timer
+                .engine
+                .lock()
+                .map_err(|e| format!("Lock failed: {e}"))?
+                .apply_config(config);
+        }
+        Ok(())
     }
 }
 
 /// Database state stored in Tauri State to avoid re-opening per call.
-pub struct DbState(pub Mutex<Database>);
+pub struct DbState(pub Mutex<Database>, pub Option<DbRecovery>);
 
 impl DbState {
+    /// Open the database, replacing an unreadable file with a fresh one.
+    ///
+    /// The app then starts with empty history instead of failing; the
+    /// recovery is reported by `cmd_db_status`.
     pub fn new() -> Result<Self, String> {
-        Database::open()
-            .map(|db| Self(Mutex::new(db)))
-            .map_err(|e| e.to_string())
+        let (db, recovery) = Database::open_or_recover().map_err(|e| e.to_string())?;
+        if let Some(recovery) = &recovery {
+            eprintln!(
+                "Database could not be opened ({}); moved it to {} and started fresh",
+                recovery.error,
+                recovery.backup_path.display()
+            );
+        }
+        Ok(Self(Mutex::new(db), recovery))
+    }
+}
+
+// ── Event push ─────────────────────────────────────────────────────────
+
+/// Tauri event carrying core `Event`s, tagged by their `type` field.
+pub const CORE_EVENT: &str = "core:event";
+
+/// Tauri event carrying the `ReconciliationSummary` of a reconciliation run.
+pub const RECONCILIATION_EVENT: &str = "reconciliation:completed";
+
+/// Push a core event to every webview as `core:event`.
+///
+/// Best effort: failures are logged and the polling commands keep working
+/// for callers that do not listen.
+pub fn emit_event(app: &AppHandle, event: &Event) {
+    if let Err(e) = app.emit(CORE_EVENT, event) {
+        tracing::warn!("Failed to emit {CORE_EVENT}: {e}");
+    }
+}
+
+/// Announce written settings and refresh the timers when they are affected.
+///
+/// Emits `ConfigChanged` with the changed keys; nothing happens when no key
+/// changed.
+fn notify_config_changed(
+    app: &AppHandle,
+    engine: &EngineState,
+    before: &Config,
+    after: &Config,
+) -> Result<(), String> {
+    let keys = before.changed_keys(after);
+    if keys.is_empty() {
+        return Ok(());
+    }
+    if Config::affects_timer(&keys) {
+        engine.apply_config(after)?;
+    }
+    emit_event(
+        app,
+        &Event::ConfigChanged {
+            keys,
+            at: Utc::now(),
+        },
+    );
+    Ok(())
+}
+
+/// Push the `completed` and auto-`started` events of a tick.
+fn emit_events(app: &AppHandle, events: &[Event]) {
+    for event in events {
+        emit_event(app, event);
     }
 }
 
@@ -216,19 +387,46 @@ pub fn internal_timer_update_session(
     elapsed_minutes: u32,
 ) -> Option<Event> {
     let mut engine_guard = engine.engine.lock().ok()?;
+    update_session_locked(
+        &mut engine_guard,
+        &engine.active_session,
+        task_id,
+        project_id,
+        task_title,
+        required_minutes,
+        elapsed_minutes,
+    )
+    .ok()
+    .flatten()
+}
+
+/// Point an already locked engine at a task and record its active session.
+///
+/// Clears the session when `task_id` is `None`. The caller keeps the engine
+/// lock, so it can restore the engine if recording the session fails.
+pub fn update_session_locked(
+    engine_guard: &mut TimerEngine,
+    session_lock: &Mutex<ActiveSession>,
+    task_id: Option<String>,
+    project_id: Option<String>,
+    task_title: Option<String>,
+    required_minutes: u32,
+    elapsed_minutes: u32,
+) -> Result<Option<Event>, String> {
     let event = engine_guard.update_session(
         task_id.clone(),
         task_title,
         required_minutes,
         elapsed_minutes,
     );
-    let mut session = engine.active_session.lock().ok()?;
-    let now = Utc::now();
-    session.task_id = task_id;
-    session.project_id = project_id;
-    session.started_at = Some(now);
-    session.last_elapsed_update = Some(now);
-    event
+    let mut session = session_lock
+        .lock()
+        .map_err(|e| format!("Lock failed: {e}"))?;
+    match task_id {
+        Some(id) => session.start(id, project_id),
+        None => *session = ActiveSession::default(),
+    }
+    Ok(event)
 }
 
 /// Internal helper: Reset timer without command wrapper.
@@ -261,17 +459,158 @@ pub fn cmd_timer_status(engine: State<'_, EngineState>) -> Result<Value, String>
 /// Returns the timer state plus a "completed" event if task time expired.
 ///
 /// Also updates task.elapsed_minutes every 1 minute while timer is running.
+/// Completion is also pushed as a `core:event`.
 #[tauri::command]
 pub fn cmd_timer_tick(
+    app: AppHandle,
     engine: State<'_, EngineState>,
     db: State<'_, DbState>,
 ) -> Result<Value, String> {
-    let mut engine_guard = engine
-        .engine
+    let (result, events) = tick_lane(&engine.engine, &engine.active_session, &db.0)?;
+    start_gatekeeper_prompts(&app, &events)?;
+    emit_events(&app, &events);
+    Ok(result)
+}
+
+/// Advances every lane's timer and returns one snapshot per lane.
+///
+/// Each entry carries a `lane` number plus the same fields as
+/// `cmd_timer_tick`; lanes that complete record their own session.
+#[tauri::command]
+pub fn cmd_timer_tick_all(
+    app: AppHandle,
+    engine: State<'_, EngineState>,
+    db: State<'_, DbState>,
+) -> Result<Value, String> {
+    let (results, events) = tick_all_lanes(&engine, &db.0)?;
+    start_gatekeeper_prompts(&app, &events)?;
+    emit_events(&app, &events);
+    Ok(results)
+}
+
+/// Starts gatekeeper tracking for every `GatekeeperPrompt` a tick raised.
+///
+/// The engine raises one whenever a focus step ends, so the break is
+/// prompted for even when it was started automatically.
+fn start_gatekeeper_prompts(app: &AppHandle, events: &[Event]) -> Result<(), String> {
+    for event in events {
+        if let Event::GatekeeperPrompt { prompt_key, at, .. } = event {
+            let state = app.state::<GatekeeperState>();
+            let mut guard = state.0.lock().map_err(|e| format!("Lock failed: {e}"))?;
+            guard.start(prompt_key.clone(), *at);
+        }
+    }
+    Ok(())
+}
+
+/// Number of timer lanes, lane 0 included, the daily template allows.
+///
+/// Uses the default template's value when none is saved, like `cmd_template_get`.
+fn max_parallel_lanes() -> Result<u32, String> {
+    let db = ScheduleDb::open().map_err(|e| format!("Database error: {e}"))?;
+    let lanes = db
+        .get_daily_template()
+        .map_err(|e| format!("Failed to get template: {e}"))?
+        .map_or(
+            crate::schedule_commands::DEFAULT_MAX_PARALLEL_LANES,
+            |template| template.max_parallel_lanes,
+        );
+    Ok(lanes.unwrap_or(1).max(1) as u32)
+}
+
+/// Starts or updates the timer session on a specific lane.
+///
+/// Lane 0 is the same timer used by `cmd_timer_update_session`. Passing no
+/// `task_id` closes an additional lane; lanes beyond the daily template's
+/// `max_parallel_lanes` are rejected.
+#[tauri::command]
+pub fn cmd_timer_update_lane_session(
+    engine: State<'_, EngineState>,
+    lane: u32,
+    task_id: Option<String>,
+    project_id: Option<String>,
+    task_title: Option<String>,
+    required_minutes: u32,
+    elapsed_minutes: u32,
+) -> Result<Value, String> {
+    let update = |engine_lock: &Mutex<TimerEngine>,
+                  session_lock: &Mutex<ActiveSession>|
+     -> Result<Option<Event>, String> {
+        let mut engine_guard = engine_lock
+            .lock()
+            .map_err(|e| format!("Lock failed: {e}"))?;
+        update_session_locked(
+            &mut engine_guard,
+            session_lock,
+            task_id.clone(),
+            project_id.clone(),
+            task_title.clone(),
+            required_minutes,
+            elapsed_minutes,
+        )
+    };
+
+    let event = if lane == 0 {
+        update(&engine.engine, &engine.active_session)?
+    } else if task_id.is_none() {
+        engine.close_lane(lane)?;
+        None
+    } else {
+        let timer = engine.lane(lane, max_parallel_lanes()?)?;
+        update(&timer.engine, &timer.active_session)?
+    };
+
+    match event {
+        Some(e) => serde_json::to_value(e).map_err(|e| format!("JSON error: {e}")),
+        None => Ok(Value::Null),
+    }
+}
+
+/// Tick lane 0 and every additional lane, tagging each snapshot with its lane.
+///
+/// Also returns the events raised by every lane, in lane order.
+fn tick_all_lanes(
+    engine: &EngineState,
+    db: &Mutex<Database>,
+) -> Result<(Value, Vec<Event>), String> {
+    let mut results = Vec::new();
+
+    let (mut primary, mut events) = tick_lane(&engine.engine, &engine.active_session, db)?;
+    primary["lane"] = Value::from(0);
+    results.push(primary);
+
+    for (lane, timer) in engine.extra_lanes()? {
+        let (mut result, lane_events) = tick_lane(&timer.engine, &timer.active_session, db)?;
+        result["lane"] = Value::from(lane);
+        results.push(result);
+        events.extend(lane_events);
+    }
+
+    Ok((Value::Array(results), events))
+}
+
+/// Tick a single lane, updating elapsed time and recording its session on completion.
+///
+/// Returns the snapshot JSON, which carries the `completed` and `started`
+/// events, and every event the tick raised.
+fn tick_lane(
+    engine_lock: &Mutex<TimerEngine>,
+    session_lock: &Mutex<ActiveSession>,
+    db: &Mutex<Database>,
+) -> Result<(Value, Vec<Event>), String> {
+    let mut engine_guard = engine_lock
         .lock()
         .map_err(|e| format!("Lock failed: {e}"))?;
     let is_running = engine_guard.state() == TimerState::Running;
-    let completed = engine_guard.tick();
+    let raised = engine_guard.tick_events();
+    let completed = raised
+        .iter()
+        .find(|e| matches!(e, Event::TimerCompleted { .. }))
+        .cloned();
+    let started = raised
+        .iter()
+        .find(|e| matches!(e, Event::TimerStarted { .. }))
+        .cloned();
     let snapshot = engine_guard.snapshot();
     let mut result = serde_json::to_value(snapshot).map_err(|e| format!("JSON error: {e}"))?;
 
@@ -279,8 +618,7 @@ pub fn cmd_timer_tick(
     if is_running {
         let now = Utc::now();
         let (task_id, should_update) = {
-            let session = engine
-                .active_session
+            let session = session_lock
                 .lock()
                 .map_err(|e| format!("Lock failed: {e}"))?;
             let should = session.task_id.is_some()
@@ -300,8 +638,7 @@ pub fn cmd_timer_tick(
                 }
             }
             // Update last_elapsed_update timestamp
-            let mut session = engine
-                .active_session
+            let mut session = session_lock
                 .lock()
                 .map_err(|e| format!("Lock failed: {e}"))?;
             session.last_elapsed_update = Some(now);
@@ -310,19 +647,24 @@ pub fn cmd_timer_tick(
 
     if let Some(event) = completed {
         // Record session to database on completion
-        if let Event::TimerCompleted { step_type, at, .. } = event {
-            let db_guard = db.0.lock().map_err(|e| format!("Lock failed: {e}"))?;
+        if let Event::TimerCompleted {
+            step_type,
+            actual_ms,
+            at,
+            ..
+        } = event
+        {
+            let db_guard = db.lock().map_err(|e| format!("Lock failed: {e}"))?;
 
             // Get task info from engine
             let task_label = engine_guard
                 .current_task_title()
                 .unwrap_or("Task");
-            let duration_min = engine_guard.total_ms() / 60000;
+            let duration_min = actual_ms / 60000;
 
             // Get active session info (task_id, project_id) before clearing
             let (task_id, project_id) = {
-                let session = engine
-                    .active_session
+                let session = session_lock
                     .lock()
                     .map_err(|e| format!("Lock failed: {e}"))?;
                 (session.task_id.clone(), session.project_id.clone())
@@ -342,8 +684,7 @@ pub fn cmd_timer_tick(
             }
 
             // Clear active session on completion
-            let mut session = engine
-                .active_session
+            let mut session = session_lock
                 .lock()
                 .map_err(|e| format!("Lock failed: {e}"))?;
             *session = ActiveSession::default();
@@ -352,7 +693,11 @@ pub fn cmd_timer_tick(
         result["completed"] =
             serde_json::to_value(event).map_err(|e| format!("JSON error: {e}"))?;
     }
-    Ok(result)
+    if let Some(event) = started {
+        result["started"] =
+            serde_json::to_value(event).map_err(|e| format!("JSON error: {e}"))?;
+    }
+    Ok((result, raised))
 }
 
 /// Updates the timer with current task information.
@@ -365,6 +710,7 @@ pub fn cmd_timer_tick(
 /// * `elapsed_minutes` - Already elapsed time (from database).
 #[tauri::command]
 pub fn cmd_timer_update_session(
+    app: AppHandle,
     engine: State<'_, EngineState>,
     task_id: Option<String>,
     task_title: Option<String>,
@@ -392,8 +738,12 @@ pub fn cmd_timer_update_session(
         }
     }
 
+    emit_event(&app, &engine_guard.snapshot());
     match event {
-        Some(e) => serde_json::to_value(e).map_err(|e| format!("JSON error: {e}")),
+        Some(e) => {
+            emit_event(&app, &e);
+            serde_json::to_value(e).map_err(|e| format!("JSON error: {e}"))
+        }
         None => Ok(Value::Null),
     }
 }
@@ -438,6 +788,7 @@ pub fn cmd_timer_resume(engine: State<'_, EngineState>) -> Result<Value, String>
 /// Called when user marks a task as done or time expires.
 #[tauri::command]
 pub fn cmd_timer_complete(
+    app: AppHandle,
     engine: State<'_, EngineState>,
     db: State<'_, DbState>,
 ) -> Result<Value, String> {
@@ -459,14 +810,20 @@ pub fn cmd_timer_complete(
 
     if let Some(event) = event_opt {
         // Record session to database on completion
-        if let Event::TimerCompleted { step_type, at, .. } = event {
+        if let Event::TimerCompleted {
+            step_type,
+            actual_ms,
+            at,
+            ..
+        } = event
+        {
             let db_guard = db.0.lock().map_err(|e| format!("Lock failed: {e}"))?;
 
             // Get task info from engine
             let task_label = engine_guard
                 .current_task_title()
                 .unwrap_or("Task");
-            let duration_min = engine_guard.total_ms() / 60000;
+            let duration_min = actual_ms / 60000;
 
             // Get active session info before clearing
             let (task_id, project_id) = {
@@ -498,6 +855,7 @@ pub fn cmd_timer_complete(
             *session = ActiveSession::default();
         }
 
+        emit_event(&app, &event);
         serde_json::to_value(event).map_err(|e| format!("JSON error: {e}"))
     } else {
         Ok(Value::Null)
@@ -510,7 +868,11 @@ pub fn cmd_timer_complete(
 /// # Arguments
 /// * `minutes` - Number of minutes to add
 #[tauri::command]
-pub fn cmd_timer_extend(engine: State<'_, EngineState>, minutes: u32) -> Result<Value, String> {
+pub fn cmd_timer_extend(
+    app: AppHandle,
+    engine: State<'_, EngineState>,
+    minutes: u32,
+) -> Result<Value, String> {
     let mut engine_guard = engine
         .engine
         .lock()
@@ -518,6 +880,7 @@ pub fn cmd_timer_extend(engine: State<'_, EngineState>, minutes: u32) -> Result<
 
     engine_guard.extend(minutes);
     let new_remaining = engine_guard.remaining_ms();
+    emit_event(&app, &engine_guard.snapshot());
 
     let event_json = serde_json::json!({
         "type": "timer_extended",
@@ -529,10 +892,31 @@ pub fn cmd_timer_extend(engine: State<'_, EngineState>, minutes: u32) -> Result<
     Ok(event_json)
 }
 
+/// Returns the next steps of the configured schedule without advancing the timer.
+///
+/// # Arguments
+/// * `count` - Number of steps to return
+/// * `cyclic` - Wrap around at the end of the schedule (default: true)
+#[tauri::command]
+pub fn cmd_timer_upcoming_steps(
+    engine: State<'_, EngineState>,
+    count: usize,
+    cyclic: Option<bool>,
+) -> Result<Value, String> {
+    let schedule = Config::load_or_default().schedule();
+    let engine_guard = engine
+        .engine
+        .lock()
+        .map_err(|e| format!("Lock failed: {e}"))?;
+    let steps = engine_guard.upcoming_steps(&schedule, count, cyclic.unwrap_or(true));
+    serde_json::to_value(steps).map_err(|e| format!("Failed to serialize steps: {e}"))
+}
+
 /// Skips/abandons current task session.
 /// Called when user switches to a different task without completing.
 #[tauri::command]
 pub fn cmd_timer_skip(
+    app: AppHandle,
     engine: State<'_, EngineState>,
     db: State<'_, DbState>,
 ) -> Result<Value, String> {
@@ -556,6 +940,7 @@ pub fn cmd_timer_skip(
 
     // Reset engine
     engine_guard.reset();
+    emit_event(&app, &engine_guard.snapshot());
 
     // Clear active session
     let mut session = engine
@@ -590,12 +975,13 @@ pub fn cmd_timer_skip(
 ///
 /// Returns the TimerReset event.
 #[tauri::command]
-pub fn cmd_timer_reset(engine: State<'_, EngineState>) -> Result<Value, String> {
+pub fn cmd_timer_reset(app: AppHandle, engine: State<'_, EngineState>) -> Result<Value, String> {
     let mut engine_guard = engine
         .engine
         .lock()
         .map_err(|e| format!("Lock failed: {e}"))?;
     engine_guard.reset();
+    emit_event(&app, &Event::TimerReset { at: Utc::now() });
 
     // Clear active session on reset
     let mut session = engine
@@ -634,13 +1020,23 @@ pub fn cmd_config_get(key: String) -> Result<Value, String> {
 
 /// Sets a configuration value.
 ///
+/// Emits `ConfigChanged` once saved and rebuilds the timer schedule when a
+/// timer setting changed.
+///
 /// # Arguments
 /// * `key` - Configuration key to set
 /// * `value` - Value to set
 #[tauri::command]
-pub fn cmd_config_set(key: String, value: String) -> Result<(), String> {
-    let mut config = Config::load_or_default();
-    config.set(&key, &value).map_err(|e| e.to_string())
+pub fn cmd_config_set(
+    app: AppHandle,
+    engine: State<'_, EngineState>,
+    key: String,
+    value: String,
+) -> Result<(), String> {
+    let mut config = Config::load().map_err(|e| format!("Failed to load config: {e}"))?;
+    let before = config.clone();
+    config.set(&key, &value).map_err(|e| e.to_string())?;
+    notify_config_changed(&app, &engine, &before, &config)
 }
 
 /// Lists all configuration values.
@@ -661,23 +1057,42 @@ pub fn cmd_shortcuts_get() -> Result<Value, String> {
 
 /// Sets shortcuts bindings in config.
 ///
+/// Rejects sets where two commands share a keybinding or a binding uses a
+/// system-reserved combination; see `cmd_shortcuts_validate`.
+///
 /// # Arguments
 /// * `bindings_json` - JSON object with command -> keybinding mapping
 #[tauri::command]
 pub fn cmd_shortcuts_set(bindings_json: Value) -> Result<(), String> {
     use std::collections::HashMap;
-    let mut config = Config::load_or_default();
+    let mut config = Config::load().map_err(|e| format!("Failed to load config: {e}"))?;
 
     // Parse bindings from JSON
     let bindings: HashMap<String, String> =
         serde_json::from_value(bindings_json).map_err(|e| format!("Invalid bindings JSON: {e}"))?;
 
-    config.shortcuts.bindings = bindings;
+    config.shortcuts.set_bindings(bindings).map_err(|e| e.to_string())?;
     config
         .save()
         .map_err(|e| format!("Failed to save config: {e}"))
 }
 
+/// Validates shortcut bindings without saving them.
+///
+/// Returns the list of conflicts and reserved-combo violations so the UI
+/// can highlight the offending bindings.
+///
+/// # Arguments
+/// * `bindings_json` - JSON object with command -> keybinding mapping
+#[tauri::command]
+pub fn cmd_shortcuts_validate(bindings_json: Value) -> Result<Value, String> {
+    let shortcuts = pomodoroom_core::storage::ShortcutsConfig {
+        bindings: serde_json::from_value(bindings_json)
+            .map_err(|e| format!("Invalid bindings JSON: {e}"))?,
+    };
+    serde_json::to_value(shortcuts.validate()).map_err(|e| format!("JSON error: {e}"))
+}
+
 // ── Profile Pack commands ─────────────────────────────────────────────
 
 /// Gets all available profile packs.
@@ -730,7 +1145,7 @@ pub fn cmd_profile_apply(id: String) -> Result<Value, String> {
     let mut manager = pomodoroom_core::storage::ProfileManager::load()
         .map_err(|e| format!("Failed to load profile manager: {e}"))?;
 
-    let mut config = Config::load_or_default();
+    let mut config = Config::load().map_err(|e| format!("Failed to load config: {e}"))?;
 
     let backup = manager
         .apply_pack(&id, &mut config)
@@ -747,7 +1162,7 @@ pub fn cmd_profile_rollback() -> Result<Value, String> {
     let mut manager = pomodoroom_core::storage::ProfileManager::load()
         .map_err(|e| format!("Failed to load profile manager: {e}"))?;
 
-    let mut config = Config::load_or_default();
+    let mut config = Config::load().map_err(|e| format!("Failed to load config: {e}"))?;
 
     match manager.rollback(&mut config) {
         Some(rolled_back_id) => Ok(Value::String(rolled_back_id)),
@@ -820,6 +1235,23 @@ pub fn cmd_stats_all(db: State<'_, DbState>) -> Result<Value, String> {
     serde_json::to_value(stats).map_err(|e| format!("JSON error: {e}"))
 }
 
+/// Reports whether the database was recovered at startup.
+///
+/// `last_recovery` is the note left by the most recent recovery, which may
+/// predate this run.
+#[tauri::command]
+pub fn cmd_db_status(db: State<'_, DbState>) -> Result<Value, String> {
+    let db_guard = db.0.lock().map_err(|e| format!("Lock failed: {e}"))?;
+    let last_recovery = db_guard
+        .last_recovery()
+        .map_err(|e| format!("Database error: {e}"))?;
+    Ok(serde_json::json!({
+        "recovered": db.1.is_some(),
+        "recovery": db.1,
+        "last_recovery": last_recovery,
+    }))
+}
+
 // ── Session commands ───────────────────────────────────────────────────
 
 /// Gets sessions within a date range.
@@ -882,7 +1314,7 @@ pub fn cmd_sessions_get_all(db: State<'_, DbState>, limit: Option<usize>) -> Res
 #[tauri::command]
 pub fn cmd_timeline_detect_gaps(events_json: Value) -> Result<Value, String> {
     // Parse events from JSON
-    // Expected format: [{"start_time": "ISO string", "end_time": "ISO string"}, ...]
+    // Expected format: [{"start_time": "ISO string", "end_time": "ISO string", "all_day": bool?}, ...]
     let events_array = events_json
         .as_array()
         .ok_or_else(|| "events must be an array".to_string())?;
@@ -909,7 +1341,15 @@ pub fn cmd_timeline_detect_gaps(events_json: Value) -> Result<Value, String> {
         let start_time = validate_date_bounds(start_time)?;
         let end_time = validate_date_bounds(end_time)?;
 
-        events.push(TimelineEvent::new(start_time, end_time));
+        let all_day = event_json
+            .get("all_day")
+            .and_then(|v| v.as_bool())
+            .unwrap_or(false);
+        events.push(if all_day {
+            TimelineEvent::all_day(start_time, end_time)
+        } else {
+            TimelineEvent::new(start_time, end_time)
+        });
     }
 
     // Get day boundaries from now
@@ -1491,8 +1931,14 @@ pub fn cmd_policy_preview_day_plan(
 }
 
 /// Apply policy to config (save).
+///
+/// Emits `ConfigChanged` like `cmd_config_set`.
 #[tauri::command]
-pub fn cmd_policy_apply(state: State<'_, PolicyEditorState>) -> Result<(), String> {
+pub fn cmd_policy_apply(
+    app: AppHandle,
+    engine: State<'_, EngineState>,
+    state: State<'_, PolicyEditorState>,
+) -> Result<(), String> {
     let editor = state
         .editor
         .lock()
@@ -1515,6 +1961,7 @@ pub fn cmd_policy_apply(state: State<'_, PolicyEditorState>) -> Result<(), Strin
 
     // Apply to config
     let mut config = Config::load().map_err(|e| format!("Failed to load config: {e}"))?;
+    let before = config.clone();
     editor
         .apply_to_config(&mut config)
         .map_err(|errors| format!("Apply failed: {:?}", errors))?;
@@ -1522,7 +1969,7 @@ pub fn cmd_policy_apply(state: State<'_, PolicyEditorState>) -> Result<(), Strin
         .save()
         .map_err(|e| format!("Failed to save config: {e}"))?;
 
-    Ok(())
+    notify_config_changed(&app, &engine, &before, &config)
 }
 
 /// Reset policy editor to defaults.
@@ -1551,13 +1998,15 @@ pub fn cmd_policy_export(state: State<'_, PolicyEditorState>) -> Result<Value, S
 }
 
 /// Import policy from JSON bundle.
+///
+/// Bundles from older policy versions are migrated to the current schema first.
 #[tauri::command]
 pub fn cmd_policy_import(
     state: State<'_, PolicyEditorState>,
     bundle_json: Value,
 ) -> Result<(), String> {
-    let bundle: pomodoroom_core::policy::PolicyBundle =
-        serde_json::from_value(bundle_json).map_err(|e| format!("Invalid bundle JSON: {e}"))?;
+    let bundle = pomodoroom_core::policy::migrate_bundle(bundle_json)
+        .map_err(|e| format!("Invalid bundle: {e}"))?;
 
     let mut editor = state
         .editor
@@ -1591,6 +2040,7 @@ use pomodoroom_core::task::{
 /// - List of reconciled tasks with resume hints
 #[tauri::command]
 pub fn cmd_reconciliation_run(
+    app: AppHandle,
     _db_state: State<'_, DbState>,
     stale_threshold_minutes: Option<i64>,
     auto_pause: Option<bool>,
@@ -1626,6 +2076,9 @@ pub fn cmd_reconciliation_run(
         }
     }
 
+    if let Err(e) = app.emit(RECONCILIATION_EVENT, &summary) {
+        tracing::warn!("Failed to emit {RECONCILIATION_EVENT}: {e}");
+    }
     Ok(summary)
 }
 
@@ -1758,6 +2211,15 @@ pub fn cmd_metrics_clear_command(
     Ok(())
 }
 
+/// Get the daily latency trend for a command over the retention window.
+#[tauri::command]
+pub fn cmd_metrics_get_trend(
+    collector: State<'_, std::sync::Arc<MetricsCollector>>,
+    command: String,
+) -> Result<Vec<crate::metrics::DailyLatency>, String> {
+    Ok(collector.get_latency_trend(&command))
+}
+
 // ── Journal Commands ───────────────────────────────────────────────────
 
 /// State for journal storage.
@@ -1851,6 +2313,17 @@ pub fn cmd_journal_compact(
     guard.compact().map_err(|e| e.to_string())
 }
 
+/// Compact the journal to the latest committed transition per entity.
+///
+/// Pending and applied entries are always kept, so recovery is unaffected.
+#[tauri::command]
+pub fn cmd_journal_compact_latest(
+    journal: State<'_, JournalState>,
+) -> Result<crate::journal::CompactionReport, String> {
+    let guard = journal.0.lock().map_err(|e| format!("Lock failed: {e}"))?;
+    guard.compact_keeping_latest().map_err(|e| e.to_string())
+}
+
 // ── Journal Recovery Commands ───────────────────────────────────────────────────
 
 /// Helper function to create a recovery plan from pending entries.
@@ -1910,78 +2383,32 @@ pub fn cmd_journal_recovery_plan(
 }
 
 /// Run journal recovery.
+///
+/// Task transitions are re-applied to the schedule database and timer
+/// transitions to the engine. Replaying a transition whose target state is
+/// already in place is a no-op reported as "already consistent".
+///
+/// # Arguments
+/// * `dry_run` - When true, report what recovery would do without writing
 #[tauri::command]
 pub fn cmd_journal_recovery_run(
     journal: State<'_, JournalState>,
+    engine: State<'_, EngineState>,
+    dry_run: Option<bool>,
 ) -> Result<crate::journal::RecoveryResult, String> {
-    // First, get the plan
-    let plan = {
-        let guard = journal.0.lock().map_err(|e| format!("Lock failed: {e}"))?;
-        create_recovery_plan(&guard)?
-    };
-
-    let mut result = crate::journal::RecoveryResult::new();
-    result.total_entries = plan.to_replay.len() + plan.to_skip.len() + plan.expired.len();
-
-    // Handle expired entries
-    for (id, age) in &plan.expired {
-        let guard = journal.0.lock().map_err(|e| format!("Lock failed: {e}"))?;
-        if let Err(e) = guard.rollback(id, &format!("Entry expired (age: {}s)", age)) {
-            result.failed_count += 1;
-            result.actions.push(crate::journal::RecoveryAction::Failed {
-                entry_id: id.clone(),
-                error: e.to_string(),
-            });
-        } else {
-            result.expired_count += 1;
-            result.actions.push(crate::journal::RecoveryAction::Expired {
-                entry_id: id.clone(),
-                age_seconds: *age,
-            });
-        }
-    }
-
-    // Handle skipped entries
-    for (id, reason) in &plan.to_skip {
-        result.skipped_count += 1;
-        result.actions.push(crate::journal::RecoveryAction::Skipped {
-            entry_id: id.clone(),
-            reason: reason.clone(),
-        });
-    }
+    let guard = journal.0.lock().map_err(|e| format!("Lock failed: {e}"))?;
+    let plan = create_recovery_plan(&guard)?;
 
-    // Replay entries
-    for entry in &plan.to_replay {
-        let guard = journal.0.lock().map_err(|e| format!("Lock failed: {e}"))?;
+    let db = ScheduleDb::open().map_err(|e| format!("Database error: {e}"))?;
+    let applier = crate::journal::StateApplier::new(&db, Some(&engine.engine));
 
-        // Mark as applied
-        if let Err(e) = guard.update_status(&entry.id, crate::journal::EntryStatus::Applied, None) {
-            result.failed_count += 1;
-            result.actions.push(crate::journal::RecoveryAction::Failed {
-                entry_id: entry.id.clone(),
-                error: e.to_string(),
-            });
-            continue;
-        }
-
-        // In production, this would apply the actual transition
-        // For now, we just checkpoint it
-        if let Err(e) = guard.checkpoint(&entry.id) {
-            result.failed_count += 1;
-            result.actions.push(crate::journal::RecoveryAction::Failed {
-                entry_id: entry.id.clone(),
-                error: e.to_string(),
-            });
-        } else {
-            result.recovered_count += 1;
-            result.actions.push(crate::journal::RecoveryAction::Replayed {
-                entry_id: entry.id.clone(),
-                transition: entry.transition.clone(),
-            });
-        }
-    }
-
-    Ok(result)
+    Ok(crate::journal::execute_plan(
+        &guard,
+        &plan,
+        &applier,
+        &crate::journal::RecoveryConfig::default(),
+        dry_run.unwrap_or(false),
+    ))
 }
 
 // ── PR-Focused Mode Commands ───────────────────────────────────────────────────
@@ -2030,6 +2457,24 @@ pub fn cmd_pr_focused_deactivate(
     manager.deactivate(duration_minutes)
 }
 
+/// Check the PR-focused idle timeout, auto-deactivating if it elapsed.
+///
+/// Returns the switch result when the mode was exited, or null otherwise.
+#[tauri::command]
+pub fn cmd_pr_focused_tick(
+    manager: State<'_, std::sync::Arc<crate::pr_focused::PrFocusedManager>>,
+) -> Result<Option<crate::pr_focused::ModeSwitchResult>, String> {
+    manager.tick(Utc::now())
+}
+
+/// Record activity on the linked item, postponing the idle timeout.
+#[tauri::command]
+pub fn cmd_pr_focused_record_activity(
+    manager: State<'_, std::sync::Arc<crate::pr_focused::PrFocusedManager>>,
+) -> Result<(), String> {
+    manager.record_activity(Utc::now())
+}
+
 /// Link an item to the current PR-focused session.
 #[tauri::command]
 pub fn cmd_pr_focused_link_item(
@@ -2136,34 +2581,39 @@ pub fn cmd_parent_child_is_synced(
     Ok(guard.is_synced(&local_id))
 }
 
-/// Detect conflicts between local and remote task.
+/// Detect fields changed differently on both sides since a mapping's last sync.
 #[tauri::command]
 pub fn cmd_parent_child_detect_conflicts(
     state: State<'_, ParentChildSyncState>,
-    local_title: String,
-    local_completed: bool,
-    remote_title: String,
-    remote_completed: bool,
-    local_updated: String,
-    remote_updated: String,
+    local_id: String,
+    local: crate::parent_child_sync::TaskSnapshot,
+    remote: crate::parent_child_sync::TaskSnapshot,
 ) -> Result<Vec<crate::parent_child_sync::SyncConflict>, String> {
+    let guard = state.0.lock().map_err(|e| format!("Lock failed: {e}"))?;
+    guard.detect_conflicts(&local_id, &local, &remote)
+}
+
+/// Three-way merge local and remote task state for a mapping.
+#[tauri::command]
+pub fn cmd_parent_child_merge(
+    state: State<'_, ParentChildSyncState>,
+    local_id: String,
+    local: crate::parent_child_sync::TaskSnapshot,
+    remote: crate::parent_child_sync::TaskSnapshot,
+) -> Result<crate::parent_child_sync::MergeOutcome, String> {
     let mut guard = state.0.lock().map_err(|e| format!("Lock failed: {e}"))?;
+    guard.merge(&local_id, &local, &remote)
+}
 
-    let local_dt = chrono::DateTime::parse_from_rfc3339(&local_updated)
-        .map_err(|e| format!("Invalid local_updated: {e}"))?
-        .with_timezone(&chrono::Utc);
-    let remote_dt = chrono::DateTime::parse_from_rfc3339(&remote_updated)
-        .map_err(|e| format!("Invalid remote_updated: {e}"))?
-        .with_timezone(&chrono::Utc);
-
-    Ok(guard.detect_conflicts(
-        &local_title,
-        local_completed,
-        &remote_title,
-        remote_completed,
-        local_dt,
-        remote_dt,
-    ))
+/// Record the last-synced snapshot of a mapping.
+#[tauri::command]
+pub fn cmd_parent_child_record_synced(
+    state: State<'_, ParentChildSyncState>,
+    local_id: String,
+    snapshot: crate::parent_child_sync::TaskSnapshot,
+) -> Result<(), String> {
+    let mut guard = state.0.lock().map_err(|e| format!("Lock failed: {e}"))?;
+    guard.record_synced(&local_id, snapshot)
 }
 
 /// Prepare a subtask creation payload for Google Tasks API.
@@ -2392,6 +2842,19 @@ pub fn cmd_webhook_sign_payload(
     Ok(payload.sign(secret.as_bytes()))
 }
 
+/// Verify a signed inbound webhook and return its payload.
+///
+/// `signature` accepts the bare hex HMAC or the `sha256=<hex>` header form.
+#[tauri::command]
+pub fn cmd_webhook_verify_inbound(
+    body: String,
+    signature: String,
+    secret: String,
+) -> Result<crate::webhook::WebhookPayload, String> {
+    crate::webhook::verify_inbound(body.as_bytes(), &signature, secret.as_bytes())
+        .map_err(|e| e.to_string())
+}
+
 // ============================================================================
 // RECIPE ENGINE STATE AND COMMANDS
 // ============================================================================
@@ -2400,8 +2863,16 @@ pub fn cmd_webhook_sign_payload(
 pub struct RecipeEngineState(pub std::sync::Mutex<crate::recipe_engine::RecipeEngine>);
 
 impl RecipeEngineState {
+    /// Load persisted recipes, falling back to an in-memory engine if the
+    /// database cannot be opened.
     pub fn new() -> Self {
-        Self(std::sync::Mutex::new(crate::recipe_engine::RecipeEngine::new()))
+        let engine = crate::recipe_engine::RecipeStore::open()
+            .and_then(crate::recipe_engine::RecipeEngine::with_store)
+            .unwrap_or_else(|e| {
+                eprintln!("Recipe store unavailable, recipes will not persist: {e}");
+                crate::recipe_engine::RecipeEngine::new()
+            });
+        Self(std::sync::Mutex::new(engine))
     }
 }
 
@@ -2510,7 +2981,7 @@ pub fn cmd_recipe_clear_stats(state: State<'_, RecipeEngineState>) -> Result<(),
     Ok(())
 }
 
-/// Get execution log for failed actions.
+/// Get recent recipe execution history.
 #[tauri::command]
 pub fn cmd_recipe_get_execution_log(
     state: State<'_, RecipeEngineState>,
@@ -2535,12 +3006,18 @@ pub fn cmd_recipe_clear_execution_log(state: State<'_, RecipeEngineState>) -> Re
 // escalation system (Gatekeeper Protocol), replacing the TypeScript
 // implementation in src/utils/notification-escalation.ts.
 
-/// In-memory gatekeeper state (session-based)
-pub struct GatekeeperState(Mutex<pomodoroom_core::timer::Gatekeeper>);
+/// In-memory gatekeeper state (session-based), with the log of granted snoozes
+pub struct GatekeeperState(
+    Mutex<pomodoroom_core::timer::Gatekeeper>,
+    Mutex<pomodoroom_core::timer::PromptTracker>,
+);
 
 impl GatekeeperState {
     pub fn new() -> Self {
-        Self(Mutex::new(pomodoroom_core::timer::Gatekeeper::new()))
+        Self(
+            Mutex::new(pomodoroom_core::timer::Gatekeeper::new()),
+            Mutex::new(pomodoroom_core::timer::PromptTracker::default()),
+        )
     }
 }
 
@@ -2606,16 +3083,67 @@ pub fn cmd_gatekeeper_get_notification_channel(
 /// Update gatekeeper with current time and return escalation state.
 ///
 /// Should be called periodically (e.g., every second) to update
-/// escalation level based on elapsed time.
+/// escalation level based on elapsed time. A raised level is also pushed
+/// as `DriftingEscalated` and `GatekeeperEscalated` `core:event`s; the
+/// latter carries the channel (given DND and quiet hours, both default
+/// false) and dismissibility for the native prompt.
 #[tauri::command]
 pub fn cmd_gatekeeper_tick(
+    app: AppHandle,
     state: State<'_, GatekeeperState>,
+    is_dnd: Option<bool>,
+    is_quiet_hours: Option<bool>,
 ) -> Result<Option<pomodoroom_core::timer::GatekeeperState>, String> {
     let mut guard = state.0.lock().map_err(|e| format!("Lock failed: {e}"))?;
-    guard.tick(Utc::now());
+    let context = pomodoroom_core::timer::EscalationContext {
+        is_dnd: is_dnd.unwrap_or(false),
+        is_quiet_hours: is_quiet_hours.unwrap_or(false),
+    };
+    let now = Utc::now();
+    if let Some(escalated) = guard.tick_escalation(now, &context) {
+        if let Event::GatekeeperEscalated {
+            level,
+            break_debt_ms,
+            ..
+        } = &escalated
+        {
+            emit_event(
+                &app,
+                &Event::DriftingEscalated {
+                    escalation_level: level.as_u8(),
+                    break_debt_ms: *break_debt_ms,
+                    at: now,
+                },
+            );
+        }
+        emit_event(&app, &escalated);
+    }
     Ok(guard.state().cloned())
 }
 
+/// Snooze the current prompt, pushing escalation back.
+///
+/// # Arguments
+/// * `minutes` - Snooze length in minutes, capped at the per-snooze maximum
+///
+/// # Returns
+/// The granted snooze, including how many snoozes remain. Fails at Gravity
+/// level or once the snooze limit is reached. Granted snoozes are logged in
+/// the session's prompt tracker.
+#[tauri::command]
+pub fn cmd_gatekeeper_snooze(
+    state: State<'_, GatekeeperState>,
+    minutes: u32,
+) -> Result<pomodoroom_core::timer::SnoozeRecord, String> {
+    let mut guard = state.0.lock().map_err(|e| format!("Lock failed: {e}"))?;
+    let record = guard
+        .snooze(minutes, Utc::now())
+        .map_err(|e| e.to_string())?;
+    let mut tracker = state.1.lock().map_err(|e| format!("Lock failed: {e}"))?;
+    tracker.record_snooze(record.clone());
+    Ok(record)
+}
+
 /// Check if notification can be dismissed (Gravity level cannot be dismissed).
 #[tauri::command]
 pub fn cmd_gatekeeper_can_dismiss(state: State<'_, GatekeeperState>) -> Result<bool, String> {
@@ -2652,6 +3180,17 @@ pub fn cmd_gatekeeper_critical_start_key(task_id: String) -> String {
 // JIT (Just-In-Time) Task Engine Commands
 // ─────────────────────────────────────────────────────────────────────────────
 
+/// KV key for recently dismissed JIT suggestions.
+const JIT_DISMISSALS_KEY: &str = "jit_dismissals";
+
+fn load_jit_dismissals(db: &Database) -> HashMap<String, DateTime<Utc>> {
+    db.kv_get(JIT_DISMISSALS_KEY)
+        .ok()
+        .flatten()
+        .and_then(|json| serde_json::from_str(&json).ok())
+        .unwrap_or_default()
+}
+
 /// Suggest next tasks based on current context.
 #[tauri::command]
 pub fn cmd_jit_suggest_next_tasks(
@@ -2665,12 +3204,17 @@ pub fn cmd_jit_suggest_next_tasks(
     let tasks = db.list_tasks()
         .map_err(|e| format!("Failed to list tasks: {e}"))?;
 
+    let session_db = Database::open()
+        .map_err(|e| format!("Failed to open database: {e}"))?;
+
     let context = JitContext {
         energy: energy.unwrap_or(50),
         time_since_last_break_min: time_since_break.unwrap_or(0),
         current_task: None,
         completed_sessions: completed_sessions.unwrap_or(0),
         now: Utc::now(),
+        recent_dismissals: load_jit_dismissals(&session_db),
+        interruptions_since_break: 0,
     };
 
     let engine = JitEngine::new();
@@ -2679,6 +3223,31 @@ pub fn cmd_jit_suggest_next_tasks(
     Ok(suggestions)
 }
 
+/// Dismiss a suggested task so it is held back for the cooldown window.
+#[tauri::command]
+pub fn cmd_jit_dismiss_suggestion(task_id: String) -> Result<(), String> {
+    let db = Database::open()
+        .map_err(|e| format!("Failed to open database: {e}"))?;
+
+    let engine = JitEngine::new();
+    let mut context = JitContext {
+        energy: 50,
+        time_since_last_break_min: 0,
+        current_task: None,
+        completed_sessions: 0,
+        now: Utc::now(),
+        recent_dismissals: load_jit_dismissals(&db),
+        interruptions_since_break: 0,
+    };
+    engine.expire_dismissals(&mut context);
+    context.dismiss(&task_id);
+
+    let json = serde_json::to_string(&context.recent_dismissals)
+        .map_err(|e| format!("Failed to serialize dismissals: {e}"))?;
+    db.kv_set(JIT_DISMISSALS_KEY, &json)
+        .map_err(|e| format!("Failed to save dismissals: {e}"))
+}
+
 /// Suggest optimal break duration based on context.
 #[tauri::command]
 pub fn cmd_jit_suggest_break_duration(
@@ -2691,6 +3260,8 @@ pub fn cmd_jit_suggest_break_duration(
         current_task: None,
         completed_sessions: completed_sessions.unwrap_or(0),
         now: Utc::now(),
+        recent_dismissals: HashMap::new(),
+        interruptions_since_break: 0,
     };
 
     let engine = JitEngine::new();
@@ -2712,6 +3283,8 @@ pub fn cmd_jit_should_take_break(
         current_task: None,
         completed_sessions: completed_sessions.unwrap_or(0),
         now: Utc::now(),
+        recent_dismissals: HashMap::new(),
+        interruptions_since_break: 0,
     };
 
     let engine = JitEngine::new();
@@ -2719,3 +3292,105 @@ pub fn cmd_jit_should_take_break(
 
     Ok(should_break)
 }
+
+/// Recommend a break or one more focus session, with confidence and reasons.
+#[tauri::command]
+pub fn cmd_jit_break_decision(
+    energy: Option<u8>,
+    time_since_break: Option<u64>,
+    completed_sessions: Option<u32>,
+    interruptions: Option<u32>,
+) -> Result<BreakDecision, String> {
+    let context = JitContext {
+        energy: energy.unwrap_or(50),
+        time_since_last_break_min: time_since_break.unwrap_or(0),
+        current_task: None,
+        completed_sessions: completed_sessions.unwrap_or(0),
+        now: Utc::now(),
+        recent_dismissals: HashMap::new(),
+        interruptions_since_break: interruptions.unwrap_or(0),
+    };
+
+    Ok(JitEngine::new().break_decision(&context))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A running engine whose remaining time has already run out.
+    fn expired_engine(task_id: &str) -> TimerEngine {
+        serde_json::from_value(serde_json::json!({
+            "state": "running",
+            "session": {
+                "task_id": task_id,
+                "task_title": task_id,
+                "required_minutes": 25,
+                "initial_elapsed_minutes": 0,
+                "started_at_ms": 0
+            },
+            "remaining_ms": 0,
+            "total_ms": 25 * 60_000,
+            "last_tick_epoch_ms": 0
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn lanes_complete_independently_and_record_sessions() {
+        let state = EngineState::new();
+        let db = Mutex::new(Database::open_memory().unwrap());
+
+        *state.engine.lock().unwrap() = expired_engine("task-a");
+        state
+            .active_session
+            .lock()
+            .unwrap()
+            .start("task-a".to_string(), None);
+
+        let lane = state.lane(1, 3).unwrap();
+        *lane.engine.lock().unwrap() = expired_engine("task-b");
+        lane.active_session
+            .lock()
+            .unwrap()
+            .start("task-b".to_string(), None);
+
+        // Lane 2 exists but is idle.
+        state.lane(2, 3).unwrap();
+
+        let (results, events) = tick_all_lanes(&state, &db).unwrap();
+        let completed = events
+            .iter()
+            .filter(|e| matches!(e, Event::TimerCompleted { .. }))
+            .count();
+        assert_eq!(completed, 2);
+        let results = results.as_array().unwrap();
+        assert_eq!(results.len(), 3);
+        assert!(results[0].get("completed").is_some());
+        assert!(results[1].get("completed").is_some());
+        assert!(results[2].get("completed").is_none());
+        assert_eq!(results[2]["lane"], 2);
+
+        let sessions = db.lock().unwrap().get_all_sessions(10).unwrap();
+        let mut task_ids: Vec<_> = sessions.iter().filter_map(|s| s.task_id.clone()).collect();
+        task_ids.sort();
+        assert_eq!(task_ids, vec!["task-a".to_string(), "task-b".to_string()]);
+
+        // Completion clears each lane's own session only.
+        assert!(state.active_session.lock().unwrap().task_id.is_none());
+        assert!(lane.active_session.lock().unwrap().task_id.is_none());
+    }
+
+    #[test]
+    fn lanes_are_capped_and_dropped_when_closed() {
+        let state = EngineState::new();
+
+        assert!(state.lane(1, 2).is_ok());
+        assert!(state.lane(2, 2).is_err());
+        assert!(state.lane(u32::MAX, 2).is_err());
+        assert_eq!(state.extra_lanes().unwrap().len(), 1);
+
+        state.close_lane(1).unwrap();
+        assert!(state.extra_lanes().unwrap().is_empty());
+    }
+}
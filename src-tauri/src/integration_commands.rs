@@ -6,6 +6,7 @@
 //! These commands handle:
 //! - Listing configured integrations
 //! - Getting connection status for services
+//! - Probing live reachability of services
 //! - Disconnecting integrations (clearing tokens)
 //! - Triggering manual sync with services
 //! - Calculating priority considering all connected integrations
@@ -16,8 +17,13 @@ use serde_json::{json, Value};
 use chrono::{DateTime, Duration, Timelike, Utc};
 use indexmap::IndexMap;
 use pomodoroom_core::{
+    integrations::{
+        self,
+        mapping::{GITHUB_SOURCE, GOOGLE_TASKS_SOURCE},
+        sync_item, ExternalItem, HealthCache, Integration,
+    },
     storage::schedule_db::ScheduleDb,
-    task::{Task, TaskState},
+    task::Task,
 };
 use std::sync::Mutex;
 use tauri::State;
@@ -47,22 +53,6 @@ struct IntegrationRegistry {
     entries: IndexMap<String, IntegrationEntry>,
 }
 
-#[derive(Debug, Clone)]
-struct LocalTaskSnapshot {
-    title: String,
-    description: Option<String>,
-    state: TaskState,
-}
-
-#[derive(Debug, Clone)]
-struct RemoteTaskSnapshot {
-    external_id: String,
-    list_title: String,
-    title: String,
-    notes: Option<String>,
-    state: TaskState,
-}
-
 #[derive(Debug, Default)]
 struct SyncCounts {
     items_fetched: usize,
@@ -71,100 +61,36 @@ struct SyncCounts {
     items_unchanged: usize,
 }
 
-fn classify_sync_change(remote: &RemoteTaskSnapshot, existing: Option<&LocalTaskSnapshot>) -> &'static str {
+/// How syncing changes the local task; `task` is the task after `sync_item`.
+fn classify_sync_change(task: &Task, existing: Option<&Task>) -> &'static str {
     match existing {
         None => "create",
-        Some(local) => {
-            if local.title == remote.title
-                && local.description == remote.notes
-                && local.state == remote.state
-            {
-                "unchanged"
-            } else {
-                "update"
-            }
+        Some(local)
+            if local.title == task.title
+                && local.description == task.description
+                && local.state == task.state =>
+        {
+            "unchanged"
         }
+        Some(_) => "update",
     }
 }
 
-fn build_task_from_remote(remote: &RemoteTaskSnapshot, existing: Option<&LocalTaskSnapshot>) -> Task {
-    let now = Utc::now();
-    let mut task = Task::new(remote.title.clone());
-    task.description = remote.notes.clone();
-    task.tags = vec!["google_tasks".to_string(), format!("google_list:{}", remote.list_title)];
-    task.estimated_minutes = Some(25);
-    task.required_minutes = Some(25);
-    task.source_service = Some("google_tasks".to_string());
-    task.source_external_id = Some(remote.external_id.clone());
-    task.updated_at = now;
-
-    let mut state = remote.state;
-    if let Some(local) = existing {
-        if matches!(local.state, TaskState::Running | TaskState::Paused) && remote.state == TaskState::Ready {
-            state = local.state;
-        }
-    }
-    task.state = state;
-    if state == TaskState::Done {
-        task.completed = true;
-        task.completed_at = Some(now);
-    }
-    task
-}
-
-fn load_existing_google_snapshots(db: &ScheduleDb) -> Result<std::collections::HashMap<String, LocalTaskSnapshot>, String> {
+/// Tasks imported from `service`, keyed by their external ID.
+fn load_source_tasks(db: &ScheduleDb, service: &str) -> Result<std::collections::HashMap<String, Task>, String> {
     let mut map = std::collections::HashMap::new();
     for task in db.list_tasks().map_err(|e| e.to_string())? {
-        if task.source_service.as_deref() != Some("google_tasks") {
+        if task.source_service.as_deref() != Some(service) {
             continue;
         }
-        let Some(source_id) = task.source_external_id.clone() else {
-            continue;
-        };
-        map.insert(
-            source_id,
-            LocalTaskSnapshot {
-                title: task.title,
-                description: task.description,
-                state: task.state,
-            },
-        );
+        if let Some(source_id) = task.source_external_id.clone() {
+            map.insert(source_id, task);
+        }
     }
     Ok(map)
 }
 
-fn parse_remote_task(list_id: &str, list_title: &str, raw: &Value) -> Option<RemoteTaskSnapshot> {
-    let task_id = raw.get("id")?.as_str()?.trim();
-    if task_id.is_empty() {
-        return None;
-    }
-    let title = raw
-        .get("title")
-        .and_then(Value::as_str)
-        .unwrap_or("(untitled)")
-        .trim()
-        .to_string();
-    let notes = raw
-        .get("notes")
-        .and_then(Value::as_str)
-        .map(|s| s.trim().to_string())
-        .filter(|s| !s.is_empty());
-    let status = raw.get("status").and_then(Value::as_str).unwrap_or("needsAction");
-    let state = if status.eq_ignore_ascii_case("completed") {
-        TaskState::Done
-    } else {
-        TaskState::Ready
-    };
-    Some(RemoteTaskSnapshot {
-        external_id: format!("{list_id}:{task_id}"),
-        list_title: list_title.to_string(),
-        title,
-        notes,
-        state,
-    })
-}
-
-fn fetch_google_task_snapshots() -> Result<Vec<RemoteTaskSnapshot>, String> {
+fn fetch_google_task_items() -> Result<Vec<ExternalItem>, String> {
     let lists_value = crate::google_tasks::cmd_google_tasks_list_tasklists()?;
     let lists = lists_value
         .as_array()
@@ -186,8 +112,8 @@ fn fetch_google_task_snapshots() -> Result<Vec<RemoteTaskSnapshot>, String> {
         )?;
         if let Some(raw_tasks) = task_values.as_array() {
             for raw in raw_tasks {
-                if let Some(task) = parse_remote_task(list_id, list_title, raw) {
-                    tasks.push(task);
+                if let Some(item) = ExternalItem::from_google_task(list_id, list_title, raw) {
+                    tasks.push(item);
                 }
             }
         }
@@ -195,29 +121,47 @@ fn fetch_google_task_snapshots() -> Result<Vec<RemoteTaskSnapshot>, String> {
     Ok(tasks)
 }
 
-fn sync_google_tasks_and_count() -> Result<SyncCounts, String> {
-    let remote_tasks = fetch_google_task_snapshots()?;
+/// Create or update the local tasks for `items` fetched from `service`.
+fn apply_remote_items(service: &str, items: Vec<ExternalItem>) -> Result<SyncCounts, String> {
     let db = ScheduleDb::open().map_err(|e| e.to_string())?;
-    let existing = load_existing_google_snapshots(&db)?;
+    let existing = load_source_tasks(&db, service)?;
     let mut counts = SyncCounts {
-        items_fetched: remote_tasks.len(),
+        items_fetched: items.len(),
         ..SyncCounts::default()
     };
 
-    for remote in &remote_tasks {
-        let existing_snapshot = existing.get(&remote.external_id);
-        match classify_sync_change(remote, existing_snapshot) {
+    for item in items {
+        let local = existing.get(&item.external_id);
+        let task = sync_item(local, item);
+        match classify_sync_change(&task, local) {
             "create" => counts.items_created += 1,
             "update" => counts.items_updated += 1,
             _ => counts.items_unchanged += 1,
         }
-        let task = build_task_from_remote(remote, existing_snapshot);
         db.upsert_task_from_source(&task).map_err(|e| e.to_string())?;
     }
 
     Ok(counts)
 }
 
+fn sync_google_tasks_and_count() -> Result<SyncCounts, String> {
+    apply_remote_items(GOOGLE_TASKS_SOURCE, fetch_google_task_items()?)
+}
+
+/// Import assigned GitHub issues and pull requests as tasks.
+fn sync_github_items_and_count() -> Result<SyncCounts, String> {
+    let github = integrations::github::GitHubIntegration::new();
+    let rt = tokio::runtime::Runtime::new()
+        .map_err(|e| format!("Failed to create runtime: {e}"))?;
+    let items = {
+        let _guard = rt.enter();
+        github
+            .fetch_assigned_items()
+            .map_err(|e| format!("GitHub fetch failed: {e}"))?
+    };
+    apply_remote_items(GITHUB_SOURCE, items.iter().map(ExternalItem::from).collect())
+}
+
 /// Find the "Pomodoroom" calendar ID in a Google Calendar calendarList response.
 /// Returns None if not found.
 fn find_pomodoroom_in_calendar_list(body: &Value) -> Option<String> {
@@ -308,14 +252,27 @@ fn count_google_calendar_events() -> Result<(usize, bool), String> {
     })
 }
 
-pub struct IntegrationState(Mutex<IntegrationRegistry>);
+pub struct IntegrationState(Mutex<IntegrationRegistry>, HealthCache);
 
 impl IntegrationState {
     pub fn new() -> Self {
-        Self(Mutex::new(IntegrationRegistry::new()))
+        Self(Mutex::new(IntegrationRegistry::new()), HealthCache::default())
     }
 }
 
+/// Core integration behind a registry service name.
+fn core_integration(service_name: &str) -> Option<Box<dyn Integration>> {
+    Some(match service_name {
+        "google_calendar" => Box::new(integrations::google::GoogleIntegration::new()),
+        "notion" => Box::new(integrations::notion::NotionIntegration::new()),
+        "linear" => Box::new(integrations::linear::LinearIntegration::new()),
+        "github" => Box::new(integrations::github::GitHubIntegration::new()),
+        "discord" => Box::new(integrations::discord::DiscordIntegration::new()),
+        "slack" => Box::new(integrations::slack::SlackIntegration::new()),
+        _ => return None,
+    })
+}
+
 impl IntegrationRegistry {
     /// Create a new integration registry with all supported services.
     /// Services are added in priority order: Google > Notion > Linear > GitHub > Discord > Slack
@@ -685,6 +642,38 @@ pub fn cmd_integration_get_status(
     registry.get_status_json(&service_name)
 }
 
+/// Probes a service live with a lightweight authenticated call.
+///
+/// Results are cached for a short while, so polling this for a status dot
+/// does not hit the service each time. Unlike `cmd_integration_get_status`,
+/// this tells a rejected token (`unauthorized`, sign in again) apart from
+/// network trouble (`unreachable`).
+///
+/// # Arguments
+/// * `service_name` - The service identifier (e.g., "google_calendar", "notion")
+///
+/// # Returns
+/// Health object with `state`, `latency_ms`, `message` and `checked_at`.
+///
+/// # Errors
+/// Returns an error if the service name is unknown.
+#[tauri::command]
+pub fn cmd_integration_health_check(
+    service_name: String,
+    state: State<'_, IntegrationState>,
+) -> Result<Value, String> {
+    let integration = core_integration(&service_name)
+        .ok_or_else(|| format!("Unknown service: {service_name}"))?;
+
+    // Token refresh in the integrations expects a runtime context
+    let rt = tokio::runtime::Runtime::new()
+        .map_err(|e| format!("Failed to create runtime: {e}"))?;
+    let _guard = rt.enter();
+    let health = state.1.check(integration.as_ref());
+
+    serde_json::to_value(health).map_err(|e| format!("Failed to serialize health: {e}"))
+}
+
 /// Disconnects an integration by clearing its tokens.
 ///
 /// # Arguments
@@ -706,6 +695,9 @@ pub fn cmd_integration_disconnect(
 
     // Clear tokens using the bridge command
     crate::bridge::cmd_clear_oauth_tokens(service_name.clone())?;
+    if let Some(integration) = core_integration(&service_name) {
+        state.1.invalidate(integration.name());
+    }
 
     // Update registry state
     if let Some(entry) = registry.entries.get_mut(&service_name) {
@@ -758,7 +750,8 @@ pub fn cmd_integration_sync(
             counts.items_unchanged = task_counts.items_unchanged;
             calendar_created = calendar_created_flag;
         }
-        "notion" | "linear" | "github" | "discord" | "slack" => {
+        "github" => counts = sync_github_items_and_count()?,
+        "notion" | "linear" | "discord" | "slack" => {
             // These integrations are currently push-oriented from app events.
             counts.items_fetched = 0;
             counts.items_created = 0;
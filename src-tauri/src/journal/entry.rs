@@ -80,6 +80,18 @@ impl TransitionType {
         }
     }
 
+    /// Get the (entity type, entity id) key this transition applies to.
+    ///
+    /// The timer is a singleton entity. Custom transitions have no entity.
+    pub fn entity_key(&self) -> Option<(&'static str, &str)> {
+        match self {
+            TransitionType::TaskState { task_id, .. } => Some(("task", task_id)),
+            TransitionType::TimerState { .. } => Some(("timer", "timer")),
+            TransitionType::SessionEvent { session_id, .. } => Some(("session", session_id)),
+            TransitionType::Custom { .. } => None,
+        }
+    }
+
     /// Get a human-readable description.
     pub fn description(&self) -> String {
         match self {
@@ -34,6 +34,9 @@ mod storage;
 #[allow(unused_imports)]
 pub use entry::{EntryId, EntryStatus, JournalEntry, JournalError, TransitionType};
 #[allow(unused_imports)]
-pub use recovery::{RecoveryAction, RecoveryEngine, RecoveryImpact, RecoveryPlan, RecoveryResult};
+pub use recovery::{
+    execute_plan, ApplyOutcome, LoggingApplier, RecoveryAction, RecoveryConfig, RecoveryEngine,
+    RecoveryImpact, RecoveryPlan, RecoveryResult, StateApplier, TransitionApplier,
+};
 #[allow(unused_imports)]
-pub use storage::{JournalConfig, JournalStats, JournalStorage};
+pub use storage::{CompactionReport, JournalConfig, JournalStats, JournalStorage, KeptEntity};
@@ -4,17 +4,26 @@
 
 use crate::journal::entry::{EntryStatus, JournalEntry, JournalError, TransitionType};
 use crate::journal::storage::JournalStorage;
+use pomodoroom_core::storage::{ScheduleDb, TaskQuery};
+use pomodoroom_core::task::TaskState;
+use pomodoroom_core::timer::{TimerEngine, TimerState};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
+use std::sync::Mutex;
 
 /// Result of attempting to recover a single entry.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub enum RecoveryAction {
-    /// Entry was successfully replayed.
+    /// Entry was successfully replayed (the transition was applied).
     Replayed {
         entry_id: String,
         transition: TransitionType,
     },
+    /// Entry's target state was already in place, so nothing was applied.
+    AlreadyConsistent {
+        entry_id: String,
+        transition: TransitionType,
+    },
     /// Entry was skipped (already committed or rolled back).
     Skipped {
         entry_id: String,
@@ -37,8 +46,11 @@ pub enum RecoveryAction {
 pub struct RecoveryResult {
     /// Total entries examined.
     pub total_entries: usize,
-    /// Successfully recovered entries.
+    /// Successfully recovered entries (transition applied).
     pub recovered_count: usize,
+    /// Entries whose target state was already in place.
+    #[serde(default)]
+    pub already_consistent_count: usize,
     /// Skipped entries.
     pub skipped_count: usize,
     /// Failed entries.
@@ -47,6 +59,9 @@ pub struct RecoveryResult {
     pub expired_count: usize,
     /// Detailed actions taken.
     pub actions: Vec<RecoveryAction>,
+    /// Whether this was a dry run (nothing was written).
+    #[serde(default)]
+    pub dry_run: bool,
 }
 
 impl RecoveryResult {
@@ -55,10 +70,12 @@ impl RecoveryResult {
         Self {
             total_entries: 0,
             recovered_count: 0,
+            already_consistent_count: 0,
             skipped_count: 0,
             failed_count: 0,
             expired_count: 0,
             actions: Vec::new(),
+            dry_run: false,
         }
     }
 
@@ -124,6 +141,287 @@ pub struct RecoveryImpact {
     pub custom_operations: usize,
 }
 
+/// Outcome of applying a single transition.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ApplyOutcome {
+    /// The transition was applied.
+    Applied,
+    /// The target state was already in place; nothing changed.
+    AlreadyConsistent,
+}
+
+/// Applies journaled transitions to live state.
+///
+/// Implementations must be idempotent: applying a transition whose target
+/// state is already in place returns `AlreadyConsistent` without changes.
+pub trait TransitionApplier {
+    /// Check whether the target state of `transition` is already in place.
+    fn is_consistent(&self, transition: &TransitionType) -> Result<bool, String>;
+
+    /// Apply `transition` to live state.
+    fn apply(&self, transition: &TransitionType) -> Result<ApplyOutcome, String>;
+}
+
+/// Applier that only logs transitions, without touching live state.
+pub struct LoggingApplier;
+
+impl TransitionApplier for LoggingApplier {
+    fn is_consistent(&self, _transition: &TransitionType) -> Result<bool, String> {
+        Ok(false)
+    }
+
+    fn apply(&self, transition: &TransitionType) -> Result<ApplyOutcome, String> {
+        tracing::info!("Replaying {}", transition.description());
+        Ok(ApplyOutcome::Applied)
+    }
+}
+
+/// Applier that replays task transitions against `ScheduleDb` and timer
+/// transitions against the timer engine.
+///
+/// Session and custom transitions have no live handler and are only logged.
+pub struct StateApplier<'a> {
+    db: &'a ScheduleDb,
+    engine: Option<&'a Mutex<TimerEngine>>,
+}
+
+impl<'a> StateApplier<'a> {
+    /// Create an applier over the schedule database and, optionally, the timer engine.
+    pub fn new(db: &'a ScheduleDb, engine: Option<&'a Mutex<TimerEngine>>) -> Self {
+        Self { db, engine }
+    }
+
+    fn task_state(&self, task_id: &str) -> Result<TaskState, String> {
+        self.db
+            .get_task(task_id)
+            .map_err(|e| format!("Failed to get task: {e}"))?
+            .map(|task| task.state)
+            .ok_or_else(|| format!("Task not found: {task_id}"))
+    }
+
+    fn timer_state(&self) -> Result<TimerState, String> {
+        let engine = self.engine.ok_or("Timer engine not available")?;
+        let guard = engine.lock().map_err(|e| format!("Lock failed: {e}"))?;
+        Ok(guard.state())
+    }
+
+    fn apply_task(&self, task_id: &str, from: &str, to: &str) -> Result<ApplyOutcome, String> {
+        let from = parse_task_state(from)?;
+        let to = parse_task_state(to)?;
+        let mut task = self
+            .db
+            .get_task(task_id)
+            .map_err(|e| format!("Failed to get task: {e}"))?
+            .ok_or_else(|| format!("Task not found: {task_id}"))?;
+
+        if task.state == to {
+            return Ok(ApplyOutcome::AlreadyConsistent);
+        }
+        if task.state != from {
+            return Err(format!(
+                "Task {task_id} is {:?}, expected {:?} or {:?}",
+                task.state, from, to
+            ));
+        }
+
+        task.transition_to(to).map_err(|e| e.to_string())?;
+        self.db
+            .update_task(&task)
+            .map_err(|e| format!("Failed to update task: {e}"))?;
+        Ok(ApplyOutcome::Applied)
+    }
+
+    fn apply_timer(&self, from: &str, to: &str) -> Result<ApplyOutcome, String> {
+        let from = parse_timer_state(from)?;
+        let to = parse_timer_state(to)?;
+        let engine = self.engine.ok_or("Timer engine not available")?;
+        let mut guard = engine.lock().map_err(|e| format!("Lock failed: {e}"))?;
+
+        let current = guard.state();
+        if current == to {
+            return Ok(ApplyOutcome::AlreadyConsistent);
+        }
+        if current != from {
+            return Err(format!(
+                "Timer is {current:?}, expected {from:?} or {to:?}"
+            ));
+        }
+
+        match to {
+            TimerState::Idle => guard.reset(),
+            TimerState::Running => {
+                // Re-link the timer to the task the database says is running.
+                let query = TaskQuery {
+                    state: Some(TaskState::Running),
+                    limit: Some(1),
+                    ..Default::default()
+                };
+                let task = self
+                    .db
+                    .query_tasks(&query)
+                    .map_err(|e| format!("Failed to query tasks: {e}"))?
+                    .tasks
+                    .into_iter()
+                    .next()
+                    .ok_or("No running task to resume the timer for")?;
+                guard.update_session(
+                    Some(task.id.clone()),
+                    Some(task.title.clone()),
+                    task.required_minutes.unwrap_or(25),
+                    task.elapsed_minutes,
+                );
+            }
+            TimerState::Drifting => {
+                return Err("Cannot restore a drifting timer".to_string());
+            }
+        }
+        Ok(ApplyOutcome::Applied)
+    }
+}
+
+impl TransitionApplier for StateApplier<'_> {
+    fn is_consistent(&self, transition: &TransitionType) -> Result<bool, String> {
+        match transition {
+            TransitionType::TaskState { task_id, to_state, .. } => {
+                Ok(self.task_state(task_id)? == parse_task_state(to_state)?)
+            }
+            TransitionType::TimerState { to_state, .. } => {
+                Ok(self.timer_state()? == parse_timer_state(to_state)?)
+            }
+            TransitionType::SessionEvent { .. } | TransitionType::Custom { .. } => Ok(false),
+        }
+    }
+
+    fn apply(&self, transition: &TransitionType) -> Result<ApplyOutcome, String> {
+        match transition {
+            TransitionType::TaskState { task_id, from_state, to_state } => {
+                self.apply_task(task_id, from_state, to_state)
+            }
+            TransitionType::TimerState { from_state, to_state } => {
+                self.apply_timer(from_state, to_state)
+            }
+            TransitionType::SessionEvent { .. } | TransitionType::Custom { .. } => {
+                LoggingApplier.apply(transition)
+            }
+        }
+    }
+}
+
+/// Parse a journaled task state ("READY", "running", ...).
+fn parse_task_state(value: &str) -> Result<TaskState, String> {
+    serde_json::from_value(serde_json::Value::String(value.to_uppercase()))
+        .map_err(|_| format!("Invalid task state: {value}"))
+}
+
+/// Parse a journaled timer state ("Idle", "running", ...).
+fn parse_timer_state(value: &str) -> Result<TimerState, String> {
+    serde_json::from_value(serde_json::Value::String(value.to_lowercase()))
+        .map_err(|_| format!("Invalid timer state: {value}"))
+}
+
+/// Execute a recovery plan against `storage`, applying transitions with `applier`.
+///
+/// With `dry_run`, nothing is written: each replayable entry is classified as
+/// would-be replayed or already consistent, mirroring the plan.
+pub fn execute_plan(
+    storage: &JournalStorage,
+    plan: &RecoveryPlan,
+    applier: &dyn TransitionApplier,
+    config: &RecoveryConfig,
+    dry_run: bool,
+) -> RecoveryResult {
+    let mut result = RecoveryResult::new();
+    result.dry_run = dry_run;
+    result.total_entries = plan.to_replay.len() + plan.to_skip.len() + plan.expired.len();
+
+    // Handle expired entries
+    for (id, age) in &plan.expired {
+        if config.auto_rollback_expired && !dry_run {
+            if let Err(e) = storage.rollback(id, &format!("Entry expired (age: {}s)", age)) {
+                result.failed_count += 1;
+                result.actions.push(RecoveryAction::Failed {
+                    entry_id: id.clone(),
+                    error: e.to_string(),
+                });
+                continue;
+            }
+        }
+        result.expired_count += 1;
+        result.actions.push(RecoveryAction::Expired {
+            entry_id: id.clone(),
+            age_seconds: *age,
+        });
+    }
+
+    // Handle skipped entries
+    for (id, reason) in &plan.to_skip {
+        result.skipped_count += 1;
+        result.actions.push(RecoveryAction::Skipped {
+            entry_id: id.clone(),
+            reason: reason.clone(),
+        });
+    }
+
+    // Replay entries
+    for entry in &plan.to_replay {
+        let outcome = if dry_run {
+            applier.is_consistent(&entry.transition).map(|consistent| {
+                if consistent {
+                    ApplyOutcome::AlreadyConsistent
+                } else {
+                    ApplyOutcome::Applied
+                }
+            })
+        } else {
+            replay_entry(storage, entry, applier)
+        };
+
+        match outcome {
+            Ok(ApplyOutcome::Applied) => {
+                result.recovered_count += 1;
+                result.actions.push(RecoveryAction::Replayed {
+                    entry_id: entry.id.clone(),
+                    transition: entry.transition.clone(),
+                });
+            }
+            Ok(ApplyOutcome::AlreadyConsistent) => {
+                result.already_consistent_count += 1;
+                result.actions.push(RecoveryAction::AlreadyConsistent {
+                    entry_id: entry.id.clone(),
+                    transition: entry.transition.clone(),
+                });
+            }
+            Err(e) => {
+                result.failed_count += 1;
+                result.actions.push(RecoveryAction::Failed {
+                    entry_id: entry.id.clone(),
+                    error: e,
+                });
+
+                if !config.continue_on_failure {
+                    break;
+                }
+            }
+        }
+    }
+
+    result
+}
+
+/// Apply a single journal entry and checkpoint it.
+fn replay_entry(
+    storage: &JournalStorage,
+    entry: &JournalEntry,
+    applier: &dyn TransitionApplier,
+) -> Result<ApplyOutcome, String> {
+    storage
+        .update_status(&entry.id, EntryStatus::Applied, None)
+        .map_err(|e| e.to_string())?;
+    let outcome = applier.apply(&entry.transition)?;
+    storage.checkpoint(&entry.id).map_err(|e| e.to_string())?;
+    Ok(outcome)
+}
+
 /// Engine for recovering from uncommitted journal entries.
 pub struct RecoveryEngine {
     storage: JournalStorage,
@@ -181,114 +479,23 @@ impl RecoveryEngine {
     }
 
     /// Run recovery on all pending entries.
+    ///
+    /// Transitions are only logged; use [`RecoveryEngine::run_with`] to apply
+    /// them to live state.
     pub fn run(&self) -> Result<RecoveryResult, JournalError> {
-        let plan = self.plan()?;
-        let mut result = RecoveryResult::new();
-        result.total_entries = plan.to_replay.len() + plan.to_skip.len() + plan.expired.len();
-
-        // Handle expired entries
-        for (id, age) in &plan.expired {
-            if self.config.auto_rollback_expired {
-                if let Err(e) = self.storage.rollback(id, &format!("Entry expired (age: {}s)", age)) {
-                    result.failed_count += 1;
-                    result.actions.push(RecoveryAction::Failed {
-                        entry_id: id.clone(),
-                        error: e.to_string(),
-                    });
-                } else {
-                    result.expired_count += 1;
-                    result.actions.push(RecoveryAction::Expired {
-                        entry_id: id.clone(),
-                        age_seconds: *age,
-                    });
-                }
-            } else {
-                result.expired_count += 1;
-                result.actions.push(RecoveryAction::Expired {
-                    entry_id: id.clone(),
-                    age_seconds: *age,
-                });
-            }
-        }
-
-        // Handle skipped entries
-        for (id, reason) in &plan.to_skip {
-            result.skipped_count += 1;
-            result.actions.push(RecoveryAction::Skipped {
-                entry_id: id.clone(),
-                reason: reason.clone(),
-            });
-        }
-
-        // Replay entries
-        for entry in &plan.to_replay {
-            match self.replay_entry(entry) {
-                Ok(()) => {
-                    result.recovered_count += 1;
-                    result.actions.push(RecoveryAction::Replayed {
-                        entry_id: entry.id.clone(),
-                        transition: entry.transition.clone(),
-                    });
-                }
-                Err(e) => {
-                    result.failed_count += 1;
-                    result.actions.push(RecoveryAction::Failed {
-                        entry_id: entry.id.clone(),
-                        error: e.to_string(),
-                    });
-
-                    if !self.config.continue_on_failure {
-                        break;
-                    }
-                }
-            }
-        }
-
-        Ok(result)
+        self.run_with(&LoggingApplier, false)
     }
 
-    /// Replay a single journal entry.
-    fn replay_entry(&self, entry: &JournalEntry) -> Result<(), JournalError> {
-        // In a real implementation, this would actually apply the transition
-        // to the relevant state (task, timer, session, etc.)
-        // For now, we just mark it as applied and checkpoint it.
-
-        // Mark as applied
-        self.storage.update_status(&entry.id, EntryStatus::Applied, None)?;
-
-        // Simulate applying the transition
-        // In production, this would call the appropriate state handler
-        match &entry.transition {
-            TransitionType::TaskState { task_id, from_state, to_state } => {
-                tracing::info!(
-                    "Replaying task transition: {} from {} to {}",
-                    task_id, from_state, to_state
-                );
-            }
-            TransitionType::TimerState { from_state, to_state } => {
-                tracing::info!(
-                    "Replaying timer transition: {} to {}",
-                    from_state, to_state
-                );
-            }
-            TransitionType::SessionEvent { session_id, event } => {
-                tracing::info!(
-                    "Replaying session event: {} - {}",
-                    session_id, event
-                );
-            }
-            TransitionType::Custom { category, operation, .. } => {
-                tracing::info!(
-                    "Replaying custom operation: {}.{}",
-                    category, operation
-                );
-            }
-        }
-
-        // Checkpoint after successful replay
-        self.storage.checkpoint(&entry.id)?;
-
-        Ok(())
+    /// Run recovery, applying transitions with `applier`.
+    ///
+    /// With `dry_run`, nothing is written and the result mirrors the plan.
+    pub fn run_with(
+        &self,
+        applier: &dyn TransitionApplier,
+        dry_run: bool,
+    ) -> Result<RecoveryResult, JournalError> {
+        let plan = self.plan()?;
+        Ok(execute_plan(&self.storage, &plan, applier, &self.config, dry_run))
     }
 
     /// Get the underlying storage reference.
@@ -428,6 +635,101 @@ mod tests {
         assert_eq!(plan.to_replay.len(), 0);
     }
 
+    fn create_ready_task(db: &ScheduleDb, id: &str) {
+        let mut task = pomodoroom_core::task::Task::new("Recovered task");
+        task.id = id.to_string();
+        db.create_task(&task).unwrap();
+    }
+
+    #[test]
+    fn recovery_applies_pending_task_transition() {
+        let engine = create_test_engine();
+        let db = ScheduleDb::open_memory().unwrap();
+        create_ready_task(&db, "task-1");
+
+        // Simulated crash: journaled but never applied to the database.
+        let entry = engine
+            .storage()
+            .append(TransitionType::task_transition("task-1", "READY", "RUNNING"))
+            .unwrap();
+
+        let result = engine.run_with(&StateApplier::new(&db, None), false).unwrap();
+
+        assert_eq!(result.recovered_count, 1);
+        assert_eq!(result.already_consistent_count, 0);
+        assert_eq!(db.get_task("task-1").unwrap().unwrap().state, TaskState::Running);
+        let stored = engine.storage().get(&entry.id).unwrap().unwrap();
+        assert_eq!(stored.status, EntryStatus::Committed);
+    }
+
+    #[test]
+    fn recovery_of_already_applied_transition_is_noop() {
+        let engine = create_test_engine();
+        let db = ScheduleDb::open_memory().unwrap();
+        create_ready_task(&db, "task-1");
+
+        // Simulated crash after the database write but before the checkpoint.
+        let entry = engine
+            .storage()
+            .append(TransitionType::task_transition("task-1", "READY", "RUNNING"))
+            .unwrap();
+        let mut task = db.get_task("task-1").unwrap().unwrap();
+        task.transition_to(TaskState::Running).unwrap();
+        db.update_task(&task).unwrap();
+        engine
+            .storage()
+            .update_status(&entry.id, EntryStatus::Applied, None)
+            .unwrap();
+
+        let result = engine.run_with(&StateApplier::new(&db, None), false).unwrap();
+
+        assert_eq!(result.recovered_count, 0);
+        assert_eq!(result.already_consistent_count, 1);
+        let after = db.get_task("task-1").unwrap().unwrap();
+        assert_eq!(after.state, TaskState::Running);
+        assert_eq!(after.updated_at, task.updated_at);
+    }
+
+    #[test]
+    fn recovery_dry_run_mirrors_plan_without_writing() {
+        let engine = create_test_engine();
+        let db = ScheduleDb::open_memory().unwrap();
+        create_ready_task(&db, "task-1");
+        let entry = engine
+            .storage()
+            .append(TransitionType::task_transition("task-1", "READY", "RUNNING"))
+            .unwrap();
+
+        let result = engine.run_with(&StateApplier::new(&db, None), true).unwrap();
+
+        assert!(result.dry_run);
+        assert_eq!(result.recovered_count, 1);
+        assert_eq!(db.get_task("task-1").unwrap().unwrap().state, TaskState::Ready);
+        assert!(engine.storage().get(&entry.id).unwrap().unwrap().is_pending());
+    }
+
+    #[test]
+    fn recovery_restores_idle_timer() {
+        let engine = create_test_engine();
+        let db = ScheduleDb::open_memory().unwrap();
+        let timer = Mutex::new(TimerEngine::new());
+        timer
+            .lock()
+            .unwrap()
+            .update_session(Some("task-1".to_string()), None, 25, 0);
+
+        engine
+            .storage()
+            .append(TransitionType::timer_transition("Running", "Idle"))
+            .unwrap();
+        let result = engine
+            .run_with(&StateApplier::new(&db, Some(&timer)), false)
+            .unwrap();
+
+        assert_eq!(result.recovered_count, 1);
+        assert_eq!(timer.lock().unwrap().state(), TimerState::Idle);
+    }
+
     #[test]
     fn recovery_result_default() {
         let result = RecoveryResult::default();
@@ -4,6 +4,7 @@
 
 use crate::journal::entry::{EntryId, EntryStatus, JournalEntry, JournalError, TransitionType};
 use chrono::{DateTime, Utc};
+use pomodoroom_core::storage::ConnectionOptions;
 use rusqlite::{params, Connection, OptionalExtension};
 use serde::{Deserialize, Serialize};
 use std::path::PathBuf;
@@ -51,6 +52,26 @@ pub struct JournalStats {
     pub file_size_bytes: u64,
 }
 
+/// An entity whose latest committed transition was kept by compaction.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct KeptEntity {
+    /// Entity type ("task", "timer", "session").
+    pub entity_type: String,
+    /// Entity identifier.
+    pub entity_id: String,
+    /// ID of the kept journal entry.
+    pub entry_id: EntryId,
+}
+
+/// Result of compacting the journal down to the latest state per entity.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct CompactionReport {
+    /// Number of entries removed.
+    pub removed: usize,
+    /// Entities whose latest committed transition was kept.
+    pub kept: Vec<KeptEntity>,
+}
+
 /// SQLite-based journal storage.
 pub struct JournalStorage {
     conn: Mutex<Connection>,
@@ -62,7 +83,8 @@ impl JournalStorage {
     /// Open the journal storage at the default location.
     pub fn open() -> Result<Self, JournalError> {
         let path = Self::journal_path()?;
-        let conn = Connection::open(&path)
+        let conn = ConnectionOptions::default()
+            .open(&path)
             .map_err(|e| JournalError::StorageError(e.to_string()))?;
 
         let storage = Self {
@@ -80,7 +102,8 @@ impl JournalStorage {
     /// Open an in-memory journal (for testing).
     #[cfg(test)]
     pub fn open_memory() -> Result<Self, JournalError> {
-        let conn = Connection::open_in_memory()
+        let conn = ConnectionOptions::default()
+            .open_in_memory()
             .map_err(|e| JournalError::StorageError(e.to_string()))?;
 
         let storage = Self {
@@ -271,6 +294,66 @@ impl JournalStorage {
         Ok(rows_deleted)
     }
 
+    /// Remove superseded committed entries, keeping the latest per entity.
+    ///
+    /// For each (entity type, entity id) only the most recent committed
+    /// transition survives. Pending, applied and rolled back entries are never
+    /// removed, nor are custom transitions (which have no entity).
+    pub fn compact_keeping_latest(&self) -> Result<CompactionReport, JournalError> {
+        let mut conn = self.conn.lock()
+            .map_err(|_| JournalError::StorageError("Failed to lock connection".into()))?;
+
+        let mut committed = Vec::new();
+        {
+            let mut stmt = conn.prepare(
+                "SELECT id, transition_json, status, created_at, updated_at, correlation_id, error, sequence
+                 FROM journal_entries
+                 WHERE status = 'Committed'
+                 ORDER BY sequence DESC"
+            )
+            .map_err(|e| JournalError::StorageError(e.to_string()))?;
+
+            let rows = stmt.query_map([], |row| self.row_to_entry(row))
+                .map_err(|e| JournalError::StorageError(e.to_string()))?;
+            for row in rows {
+                committed.push(row.map_err(|e| JournalError::StorageError(e.to_string()))?);
+            }
+        }
+
+        let mut report = CompactionReport::default();
+        let mut seen = std::collections::HashSet::new();
+        let mut to_remove = Vec::new();
+        for entry in &committed {
+            let Some((entity_type, entity_id)) = entry.transition.entity_key() else {
+                continue;
+            };
+            if seen.insert((entity_type, entity_id)) {
+                report.kept.push(KeptEntity {
+                    entity_type: entity_type.to_string(),
+                    entity_id: entity_id.to_string(),
+                    entry_id: entry.id.clone(),
+                });
+            } else {
+                to_remove.push(entry.id.as_str());
+            }
+        }
+
+        let tx = conn.transaction()
+            .map_err(|e| JournalError::StorageError(e.to_string()))?;
+        for id in &to_remove {
+            report.removed += tx
+                .execute(
+                    "DELETE FROM journal_entries WHERE id = ?1 AND status = 'Committed'",
+                    params![id],
+                )
+                .map_err(|e| JournalError::StorageError(e.to_string()))?;
+        }
+        tx.commit()
+            .map_err(|e| JournalError::StorageError(e.to_string()))?;
+
+        Ok(report)
+    }
+
     /// Compact if entry count exceeds threshold.
     fn compact_if_needed(&self) -> Result<(), JournalError> {
         let stats = self.get_stats()?;
@@ -441,6 +524,33 @@ mod tests {
         assert_eq!(stats.committed_count, 1);
     }
 
+    #[test]
+    fn compaction_keeps_latest_committed_per_entity() {
+        let storage = JournalStorage::open_memory().unwrap();
+
+        let mut last = None;
+        for (from, to) in [("READY", "RUNNING"), ("RUNNING", "PAUSED"), ("PAUSED", "RUNNING")] {
+            let entry = storage.append(TransitionType::task_transition("task-1", from, to)).unwrap();
+            storage.checkpoint(&entry.id).unwrap();
+            last = Some(entry.id);
+        }
+        let pending = storage.append(TransitionType::task_transition("task-1", "RUNNING", "DONE")).unwrap();
+        let other = storage.append(TransitionType::task_transition("task-2", "READY", "RUNNING")).unwrap();
+        storage.checkpoint(&other.id).unwrap();
+
+        let report = storage.compact_keeping_latest().unwrap();
+        assert_eq!(report.removed, 2);
+        assert_eq!(report.kept.len(), 2);
+        let kept_task = report.kept.iter().find(|k| k.entity_id == "task-1").unwrap();
+        assert_eq!(kept_task.entity_type, "task");
+        assert_eq!(Some(kept_task.entry_id.clone()), last);
+
+        let stats = storage.get_stats().unwrap();
+        assert_eq!(stats.total_entries, 3);
+        assert_eq!(stats.committed_count, 2);
+        assert!(storage.get(&pending.id).unwrap().unwrap().is_pending());
+    }
+
     #[test]
     fn storage_rollback() {
         let storage = JournalStorage::open_memory().unwrap();
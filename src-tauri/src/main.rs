@@ -32,6 +32,14 @@ fn main() {
     // Load .env file for Google OAuth credentials
     dotenv::dotenv().ok();
 
+    let metrics_collector = std::sync::Arc::new(metrics::MetricsCollector::open_persistent(
+        metrics::MetricsConfig {
+            persist: true,
+            ..Default::default()
+        },
+    ));
+    metrics::MetricsCollector::spawn_flusher(&metrics_collector);
+
     tauri::Builder::default()
         .plugin(tauri_plugin_updater::Builder::new().build())
         .plugin(tauri_plugin_process::init())
@@ -44,7 +52,7 @@ fn main() {
         .manage(bridge::PolicyEditorState::default())
         .manage(integration_commands::IntegrationState::new())
         .manage(google_calendar::GoogleCalendarOAuthConfig::new())
-        .manage(std::sync::Arc::new(metrics::MetricsCollector::new()))
+        .manage(metrics_collector)
         .manage(bridge::JournalState::new())
         .manage(std::sync::Arc::new(pr_focused::PrFocusedManager::new()))
         .manage(bridge::ParentChildSyncState::new())
@@ -61,6 +69,12 @@ fn main() {
                 }
             }
             tray::setup(app)?;
+            // Materialize today's recurring task instances on startup.
+            if let Err(e) = pomodoroom_core::storage::ScheduleDb::open()
+                .and_then(|db| Ok(db.generate_due_instances(chrono::Utc::now())?))
+            {
+                eprintln!("Failed to generate recurring tasks: {e}");
+            }
             Ok(())
         })
         .invoke_handler(tauri::generate_handler![
@@ -93,11 +107,15 @@ fn main() {
             bridge::cmd_timer_extend,
             bridge::cmd_timer_reset,
             bridge::cmd_timer_tick,
+            bridge::cmd_timer_tick_all,
+            bridge::cmd_timer_update_lane_session,
+            bridge::cmd_timer_upcoming_steps,
             bridge::cmd_config_get,
             bridge::cmd_config_set,
             bridge::cmd_config_list,
             bridge::cmd_shortcuts_get,
             bridge::cmd_shortcuts_set,
+            bridge::cmd_shortcuts_validate,
             // Profile pack commands
             bridge::cmd_profile_list,
             bridge::cmd_profile_get,
@@ -109,6 +127,7 @@ fn main() {
             bridge::cmd_profile_record_session,
             bridge::cmd_stats_today,
             bridge::cmd_stats_all,
+            bridge::cmd_db_status,
             bridge::cmd_log,
             // Session commands
             bridge::cmd_sessions_get_by_date_range,
@@ -158,10 +177,12 @@ fn main() {
             schedule_commands::cmd_task_list,
             schedule_commands::cmd_task_get,
             schedule_commands::cmd_task_start,
+            schedule_commands::cmd_focus_start,
             schedule_commands::cmd_task_pause,
             schedule_commands::cmd_task_interrupt,
             schedule_commands::cmd_task_resume,
             schedule_commands::cmd_task_complete,
+            schedule_commands::cmd_tasks_transition_batch,
             schedule_commands::cmd_task_postpone,
             schedule_commands::cmd_task_defer_until,
             schedule_commands::cmd_task_extend,
@@ -186,6 +207,7 @@ fn main() {
             // Integration commands
             integration_commands::cmd_integration_list,
             integration_commands::cmd_integration_get_status,
+            integration_commands::cmd_integration_health_check,
             integration_commands::cmd_integration_disconnect,
             integration_commands::cmd_integration_sync,
             integration_commands::cmd_integration_calculate_priority,
@@ -229,6 +251,7 @@ fn main() {
             bridge::cmd_metrics_get_config,
             bridge::cmd_metrics_clear,
             bridge::cmd_metrics_clear_command,
+            bridge::cmd_metrics_get_trend,
             // Journal commands
             bridge::cmd_journal_append,
             bridge::cmd_journal_get,
@@ -237,6 +260,7 @@ fn main() {
             bridge::cmd_journal_rollback,
             bridge::cmd_journal_stats,
             bridge::cmd_journal_compact,
+            bridge::cmd_journal_compact_latest,
             bridge::cmd_journal_recovery_plan,
             bridge::cmd_journal_recovery_run,
             // PR-focused mode commands
@@ -244,6 +268,8 @@ fn main() {
             bridge::cmd_pr_focused_is_active,
             bridge::cmd_pr_focused_activate,
             bridge::cmd_pr_focused_deactivate,
+            bridge::cmd_pr_focused_tick,
+            bridge::cmd_pr_focused_record_activity,
             bridge::cmd_pr_focused_link_item,
             bridge::cmd_pr_focused_get_linked_item,
             bridge::cmd_pr_focused_detect_context,
@@ -256,6 +282,8 @@ fn main() {
             bridge::cmd_parent_child_remove_mapping,
             bridge::cmd_parent_child_is_synced,
             bridge::cmd_parent_child_detect_conflicts,
+            bridge::cmd_parent_child_merge,
+            bridge::cmd_parent_child_record_synced,
             bridge::cmd_parent_child_prepare_subtask,
             bridge::cmd_parent_child_build_hierarchy,
             bridge::cmd_parent_child_get_stats,
@@ -275,6 +303,7 @@ fn main() {
             bridge::cmd_webhook_clear_stats,
             bridge::cmd_webhook_get_config,
             bridge::cmd_webhook_sign_payload,
+            bridge::cmd_webhook_verify_inbound,
             // Recipe engine commands
             bridge::cmd_recipe_register,
             bridge::cmd_recipe_unregister,
@@ -294,13 +323,16 @@ fn main() {
             bridge::cmd_gatekeeper_get_state,
             bridge::cmd_gatekeeper_get_notification_channel,
             bridge::cmd_gatekeeper_tick,
+            bridge::cmd_gatekeeper_snooze,
             bridge::cmd_gatekeeper_can_dismiss,
             bridge::cmd_gatekeeper_is_quiet_hours,
             bridge::cmd_gatekeeper_critical_start_key,
             // JIT (Just-In-Time) task engine commands
             bridge::cmd_jit_suggest_next_tasks,
+            bridge::cmd_jit_dismiss_suggestion,
             bridge::cmd_jit_suggest_break_duration,
             bridge::cmd_jit_should_take_break,
+            bridge::cmd_jit_break_decision,
             // Sync commands
             sync_commands::cmd_sync_startup,
             sync_commands::cmd_sync_manual,
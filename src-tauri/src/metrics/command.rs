@@ -4,10 +4,12 @@
 
 #![allow(dead_code)]
 
-use chrono::{DateTime, Utc};
+use super::histogram::LatencyHistogram;
+use super::store::{DailyLatency, MetricsStore};
+use chrono::{DateTime, NaiveDate, Utc};
 use serde::{Deserialize, Serialize};
 use std::collections::VecDeque;
-use std::sync::Mutex;
+use std::sync::{Arc, Mutex, Weak};
 use std::time::Duration;
 
 /// Maximum number of records to keep per command.
@@ -16,6 +18,18 @@ const MAX_RECORDS_PER_COMMAND: usize = 1000;
 /// Default threshold for slow command alerts (in milliseconds).
 const DEFAULT_SLOW_THRESHOLD_MS: u64 = 1000;
 
+/// Maximum number of slow command alerts to keep.
+const MAX_SLOW_ALERTS: usize = 100;
+
+/// Window bucket used for records without a window label.
+pub const DEFAULT_WINDOW_LABEL: &str = "default";
+
+/// Default number of days persisted metrics are kept.
+const DEFAULT_RETENTION_DAYS: u32 = 56;
+
+/// Default interval between flushes to storage (in seconds).
+const DEFAULT_FLUSH_INTERVAL_SECS: u64 = 60;
+
 /// Configuration for metrics collection.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct MetricsConfig {
@@ -27,6 +41,26 @@ pub struct MetricsConfig {
 
     /// Whether to enable metrics collection.
     pub enabled: bool,
+
+    /// Whether to persist latency aggregates so they survive restarts.
+    #[serde(default)]
+    pub persist: bool,
+
+    /// Number of days persisted metrics are kept.
+    #[serde(default = "default_retention_days")]
+    pub retention_days: u32,
+
+    /// Interval in seconds between flushes of pending metrics to storage.
+    #[serde(default = "default_flush_interval_secs")]
+    pub flush_interval_secs: u64,
+}
+
+fn default_retention_days() -> u32 {
+    DEFAULT_RETENTION_DAYS
+}
+
+fn default_flush_interval_secs() -> u64 {
+    DEFAULT_FLUSH_INTERVAL_SECS
 }
 
 impl Default for MetricsConfig {
@@ -35,6 +69,9 @@ impl Default for MetricsConfig {
             max_records_per_command: MAX_RECORDS_PER_COMMAND,
             slow_threshold_ms: DEFAULT_SLOW_THRESHOLD_MS,
             enabled: true,
+            persist: false,
+            retention_days: DEFAULT_RETENTION_DAYS,
+            flush_interval_secs: DEFAULT_FLUSH_INTERVAL_SECS,
         }
     }
 }
@@ -113,10 +150,30 @@ pub struct CommandRecord {
     pub failure_classification: Option<FailureClassification>,
     /// Window label context.
     pub window_label: Option<String>,
+    /// Free-form caller context (e.g. the view or action that issued the command).
+    #[serde(default)]
+    pub context: Option<String>,
     /// Timestamp of execution.
     pub timestamp: DateTime<Utc>,
 }
 
+/// Latency statistics for commands issued from one window.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WindowLatency {
+    /// Window label (`"default"` when the caller gave none).
+    pub window_label: String,
+    /// Number of invocations.
+    pub count: u64,
+    /// Number of failed invocations.
+    pub failure_count: u64,
+    /// p50 latency in milliseconds.
+    pub p50: u64,
+    /// p95 latency in milliseconds.
+    pub p95: u64,
+    /// Average latency in milliseconds.
+    pub avg_ms: u64,
+}
+
 /// Summary statistics for a command.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct CommandMetrics {
@@ -142,6 +199,9 @@ pub struct CommandMetrics {
     pub avg_ms: u64,
     /// Failure breakdown by classification.
     pub failure_breakdown: std::collections::HashMap<String, u64>,
+    /// Latency breakdown by window label (in-memory records only).
+    #[serde(default)]
+    pub by_window: std::collections::HashMap<String, WindowLatency>,
     /// Last execution timestamp.
     pub last_executed_at: Option<DateTime<Utc>>,
 }
@@ -175,13 +235,34 @@ pub struct MetricsSummary {
     /// Time range of collected data.
     pub oldest_record: Option<DateTime<Utc>>,
     pub newest_record: Option<DateTime<Utc>>,
+    /// Latency across all commands, broken down by window label.
+    #[serde(default)]
+    pub windows: std::collections::HashMap<String, WindowLatency>,
+}
+
+impl MetricsSummary {
+    /// Latency across all commands, keyed by window label.
+    ///
+    /// Records without a label are grouped under `"default"`.
+    pub fn by_window(&self) -> &std::collections::HashMap<String, WindowLatency> {
+        &self.windows
+    }
 }
 
 /// Thread-safe metrics collector.
+///
+/// With persistence enabled, each record is also folded into in-memory
+/// histograms: `history` (everything since the retention cutoff, used for
+/// summaries) and `pending` (not yet flushed). Recording never touches disk;
+/// `flush` writes pending data in one transaction.
 pub struct MetricsCollector {
     config: MetricsConfig,
     records: Mutex<std::collections::HashMap<String, VecDeque<CommandRecord>>>,
     slow_alerts: Mutex<Vec<SlowCommandAlert>>,
+    store: Option<Arc<MetricsStore>>,
+    history: Mutex<std::collections::HashMap<String, LatencyHistogram>>,
+    pending: Mutex<std::collections::HashMap<(String, NaiveDate), LatencyHistogram>>,
+    pending_alerts: Mutex<Vec<SlowCommandAlert>>,
 }
 
 impl MetricsCollector {
@@ -196,7 +277,128 @@ impl MetricsCollector {
             config,
             records: Mutex::new(std::collections::HashMap::new()),
             slow_alerts: Mutex::new(Vec::new()),
+            store: None,
+            history: Mutex::new(std::collections::HashMap::new()),
+            pending: Mutex::new(std::collections::HashMap::new()),
+            pending_alerts: Mutex::new(Vec::new()),
+        }
+    }
+
+    /// Create a collector that persists to the default metrics store.
+    ///
+    /// Falls back to in-memory collection if `config.persist` is off or the
+    /// store cannot be opened.
+    pub fn open_persistent(config: MetricsConfig) -> Self {
+        if !config.persist {
+            return Self::with_config(config);
+        }
+        match MetricsStore::open() {
+            Ok(store) => Self::with_store(config, Arc::new(store)),
+            Err(e) => {
+                eprintln!("Failed to open metrics store: {e}");
+                Self::with_config(config)
+            }
+        }
+    }
+
+    /// Create a collector backed by `store`, loading persisted history.
+    ///
+    /// Data older than the retention window is pruned on load.
+    pub fn with_store(config: MetricsConfig, store: Arc<MetricsStore>) -> Self {
+        let cutoff = Self::retention_cutoff(&config);
+        if let Err(e) = store.prune(cutoff) {
+            eprintln!("Failed to prune metrics store: {e}");
+        }
+        let history = store.load_histograms(cutoff).unwrap_or_else(|e| {
+            eprintln!("Failed to load persisted metrics: {e}");
+            std::collections::HashMap::new()
+        });
+        let slow_alerts = store.load_slow_alerts(MAX_SLOW_ALERTS).unwrap_or_default();
+
+        let mut collector = Self::with_config(config);
+        collector.store = Some(store);
+        collector.history = Mutex::new(history);
+        collector.slow_alerts = Mutex::new(slow_alerts);
+        collector
+    }
+
+    /// First day kept under the configured retention window.
+    fn retention_cutoff(config: &MetricsConfig) -> NaiveDate {
+        (Utc::now() - chrono::Duration::days(i64::from(config.retention_days))).date_naive()
+    }
+
+    /// Write pending aggregates and slow alerts to the store.
+    ///
+    /// On failure the pending data is kept for the next flush.
+    pub fn flush(&self) -> Result<(), String> {
+        let Some(store) = &self.store else {
+            return Ok(());
+        };
+
+        let histograms = std::mem::take(
+            &mut *self
+                .pending
+                .lock()
+                .map_err(|e| format!("Lock failed: {e}"))?,
+        );
+        let alerts = std::mem::take(
+            &mut *self
+                .pending_alerts
+                .lock()
+                .map_err(|e| format!("Lock failed: {e}"))?,
+        );
+        if histograms.is_empty() && alerts.is_empty() {
+            return Ok(());
+        }
+
+        if let Err(e) = store.save(&histograms, &alerts) {
+            if let Ok(mut pending) = self.pending.lock() {
+                for (key, hist) in histograms {
+                    pending.entry(key).or_default().merge(&hist);
+                }
+            }
+            if let Ok(mut pending_alerts) = self.pending_alerts.lock() {
+                let newer = std::mem::replace(&mut *pending_alerts, alerts);
+                pending_alerts.extend(newer);
+            }
+            return Err(e);
         }
+        Ok(())
+    }
+
+    /// Spawn a background thread that flushes every `flush_interval_secs`.
+    ///
+    /// The thread exits once the collector is dropped.
+    pub fn spawn_flusher(collector: &Arc<Self>) {
+        if collector.store.is_none() {
+            return;
+        }
+        let interval = Duration::from_secs(collector.config.flush_interval_secs.max(1));
+        let weak: Weak<Self> = Arc::downgrade(collector);
+        std::thread::spawn(move || loop {
+            std::thread::sleep(interval);
+            let Some(collector) = weak.upgrade() else {
+                break;
+            };
+            if let Err(e) = collector.flush() {
+                eprintln!("Failed to flush metrics: {e}");
+            }
+        });
+    }
+
+    /// Daily latency trend for a command over the retention window.
+    ///
+    /// Includes data not yet flushed. Empty when persistence is disabled.
+    pub fn get_latency_trend(&self, command: &str) -> Vec<DailyLatency> {
+        let Some(store) = &self.store else {
+            return Vec::new();
+        };
+        if let Err(e) = self.flush() {
+            eprintln!("Failed to flush metrics: {e}");
+        }
+        store
+            .load_daily(command, Self::retention_cutoff(&self.config))
+            .unwrap_or_default()
     }
 
     /// Record a command execution.
@@ -206,6 +408,18 @@ impl MetricsCollector {
         duration: Duration,
         result: Result<(), &str>,
         window_label: Option<String>,
+    ) {
+        self.record_command(command, duration, result, window_label, None);
+    }
+
+    /// Record a command execution with its window label and caller context.
+    pub fn record_command(
+        &self,
+        command: impl Into<String>,
+        duration: Duration,
+        result: Result<(), &str>,
+        window_label: Option<String>,
+        context: Option<String>,
     ) {
         if !self.config.enabled {
             return;
@@ -226,6 +440,7 @@ impl MetricsCollector {
             error,
             failure_classification,
             window_label,
+            context,
             timestamp: Utc::now(),
         };
 
@@ -239,15 +454,39 @@ impl MetricsCollector {
                 window_label: record.window_label.clone(),
             };
 
+            if self.store.is_some() {
+                if let Ok(mut pending_alerts) = self.pending_alerts.lock() {
+                    pending_alerts.push(alert.clone());
+                }
+            }
+
             if let Ok(mut alerts) = self.slow_alerts.lock() {
                 alerts.push(alert);
                 // Keep only last 100 alerts
-                if alerts.len() > 100 {
+                if alerts.len() > MAX_SLOW_ALERTS {
                     alerts.remove(0);
                 }
             }
         }
 
+        if self.store.is_some() {
+            let day = record.timestamp.date_naive();
+            if let Ok(mut history) = self.history.lock() {
+                history.entry(command.clone()).or_default().record(
+                    duration_ms,
+                    success,
+                    record.timestamp,
+                );
+            }
+            if let Ok(mut pending) = self.pending.lock() {
+                pending.entry((command.clone(), day)).or_default().record(
+                    duration_ms,
+                    success,
+                    record.timestamp,
+                );
+            }
+        }
+
         // Add record
         if let Ok(mut records) = self.records.lock() {
             let entry = records.entry(command).or_default();
@@ -261,15 +500,23 @@ impl MetricsCollector {
     }
 
     /// Get metrics for a specific command.
+    ///
+    /// With persistence enabled, counts and latency come from the persisted
+    /// history, so they survive restarts.
     pub fn get_command_metrics(&self, command: &str) -> Option<CommandMetrics> {
-        let records = self.records.lock().ok()?;
-        let command_records = records.get(command)?;
+        let metrics = {
+            let records = self.records.lock().ok()?;
+            records
+                .get(command)
+                .filter(|r| !r.is_empty())
+                .map(|r| self.compute_metrics(command, r))
+        };
 
-        if command_records.is_empty() {
-            return None;
+        let history = self.history.lock().ok()?;
+        match history.get(command) {
+            Some(hist) if hist.count > 0 => Some(apply_history(command, metrics, hist)),
+            _ => metrics,
         }
-
-        Some(self.compute_metrics(command, command_records))
     }
 
     /// Get metrics for all commands.
@@ -279,11 +526,20 @@ impl MetricsCollector {
             Err(_) => return std::collections::HashMap::new(),
         };
 
-        records
+        let mut all: std::collections::HashMap<String, CommandMetrics> = records
             .iter()
             .filter(|(_, r)| !r.is_empty())
             .map(|(cmd, recs)| (cmd.clone(), self.compute_metrics(cmd, recs)))
-            .collect()
+            .collect();
+        drop(records);
+
+        if let Ok(history) = self.history.lock() {
+            for (cmd, hist) in history.iter().filter(|(_, h)| h.count > 0) {
+                let metrics = all.remove(cmd);
+                all.insert(cmd.clone(), apply_history(cmd, metrics, hist));
+            }
+        }
+        all
     }
 
     /// Get slow command alerts.
@@ -302,19 +558,36 @@ impl MetricsCollector {
         let total_commands: u64 = commands.values().map(|m| m.total_count).sum();
         let total_failures: u64 = commands.values().map(|m| m.failure_count).sum();
 
-        let (oldest, newest) = {
+        let (oldest, newest, windows) = {
             let records = self.records.lock();
             match records {
                 Ok(r) => {
                     let all_records: Vec<_> = r.values().flatten().collect();
                     let oldest = all_records.iter().map(|r| r.timestamp).min();
                     let newest = all_records.iter().map(|r| r.timestamp).max();
-                    (oldest, newest)
+                    (oldest, newest, window_breakdown(all_records))
                 }
-                Err(_) => (None, None),
+                Err(_) => (None, None, std::collections::HashMap::new()),
             }
         };
 
+        // Persisted history may reach further back than in-memory records.
+        let (oldest, newest) = match self.history.lock() {
+            Ok(history) => (
+                history
+                    .values()
+                    .filter_map(|h| h.first_executed_at)
+                    .chain(oldest)
+                    .min(),
+                history
+                    .values()
+                    .filter_map(|h| h.last_executed_at)
+                    .chain(newest)
+                    .max(),
+            ),
+            Err(_) => (oldest, newest),
+        };
+
         MetricsSummary {
             commands,
             slow_alerts,
@@ -322,10 +595,11 @@ impl MetricsCollector {
             total_failures,
             oldest_record: oldest,
             newest_record: newest,
+            windows,
         }
     }
 
-    /// Clear all metrics.
+    /// Clear all metrics, including persisted data.
     pub fn clear(&self) {
         if let Ok(mut records) = self.records.lock() {
             records.clear();
@@ -333,13 +607,41 @@ impl MetricsCollector {
         if let Ok(mut alerts) = self.slow_alerts.lock() {
             alerts.clear();
         }
+        if let Ok(mut history) = self.history.lock() {
+            history.clear();
+        }
+        if let Ok(mut pending) = self.pending.lock() {
+            pending.clear();
+        }
+        if let Ok(mut pending_alerts) = self.pending_alerts.lock() {
+            pending_alerts.clear();
+        }
+        if let Some(store) = &self.store {
+            if let Err(e) = store.clear(None) {
+                eprintln!("Failed to clear persisted metrics: {e}");
+            }
+        }
     }
 
-    /// Clear metrics for a specific command.
+    /// Clear metrics for a specific command, including persisted data.
     pub fn clear_command(&self, command: &str) {
         if let Ok(mut records) = self.records.lock() {
             records.remove(command);
         }
+        if let Ok(mut history) = self.history.lock() {
+            history.remove(command);
+        }
+        if let Ok(mut pending) = self.pending.lock() {
+            pending.retain(|(cmd, _), _| cmd != command);
+        }
+        if let Ok(mut pending_alerts) = self.pending_alerts.lock() {
+            pending_alerts.retain(|alert| alert.command != command);
+        }
+        if let Some(store) = &self.store {
+            if let Err(e) = store.clear(Some(command)) {
+                eprintln!("Failed to clear persisted metrics: {e}");
+            }
+        }
     }
 
     /// Compute metrics from records.
@@ -376,6 +678,7 @@ impl MetricsCollector {
             }
         }
 
+        let by_window = window_breakdown(records.iter());
         let last_executed_at = records.back().map(|r| r.timestamp);
 
         CommandMetrics {
@@ -390,6 +693,7 @@ impl MetricsCollector {
             max_ms,
             avg_ms,
             failure_breakdown,
+            by_window,
             last_executed_at,
         }
     }
@@ -401,6 +705,71 @@ impl Default for MetricsCollector {
     }
 }
 
+/// Overlay persisted history on in-memory metrics.
+///
+/// Counts and latency come from the histogram; the failure and window
+/// breakdowns are only available for in-memory records.
+fn apply_history(
+    command: &str,
+    metrics: Option<CommandMetrics>,
+    hist: &LatencyHistogram,
+) -> CommandMetrics {
+    let (failure_breakdown, by_window) = metrics
+        .map(|m| (m.failure_breakdown, m.by_window))
+        .unwrap_or_default();
+    CommandMetrics {
+        command: command.to_string(),
+        total_count: hist.count,
+        success_count: hist.success_count,
+        failure_count: hist.failure_count,
+        p50: hist.percentile(50),
+        p95: hist.percentile(95),
+        p99: hist.percentile(99),
+        min_ms: hist.min_ms,
+        max_ms: hist.max_ms,
+        avg_ms: hist.avg_ms(),
+        failure_breakdown,
+        by_window,
+        last_executed_at: hist.last_executed_at,
+    }
+}
+
+/// Group records by window label and compute latency for each group.
+fn window_breakdown<'a>(
+    records: impl IntoIterator<Item = &'a CommandRecord>,
+) -> std::collections::HashMap<String, WindowLatency> {
+    let mut grouped: std::collections::HashMap<String, (Vec<u64>, u64)> =
+        std::collections::HashMap::new();
+    for record in records {
+        let label = record
+            .window_label
+            .clone()
+            .unwrap_or_else(|| DEFAULT_WINDOW_LABEL.to_string());
+        let entry = grouped.entry(label).or_default();
+        entry.0.push(record.duration_ms);
+        if !record.success {
+            entry.1 += 1;
+        }
+    }
+
+    grouped
+        .into_iter()
+        .map(|(label, (mut durations, failure_count))| {
+            durations.sort_unstable();
+            let count = durations.len() as u64;
+            let latency = WindowLatency {
+                window_label: label.clone(),
+                count,
+                failure_count,
+                p50: percentile(&durations, 50),
+                p95: percentile(&durations, 95),
+                avg_ms: durations.iter().sum::<u64>() / count.max(1),
+            };
+            (label, latency)
+        })
+        .collect()
+}
+
 /// Calculate percentile from sorted data.
 fn percentile(sorted_data: &[u64], p: u64) -> u64 {
     if sorted_data.is_empty() {
@@ -562,6 +931,84 @@ mod tests {
         assert_eq!(summary.commands.len(), 2);
     }
 
+    #[test]
+    fn metrics_break_down_latency_by_window() {
+        let collector = MetricsCollector::new();
+
+        for _ in 0..10 {
+            collector.record_command(
+                "cmd_timer_tick",
+                Duration::from_millis(10),
+                Ok(()),
+                Some("main".to_string()),
+                None,
+            );
+            collector.record_command(
+                "cmd_timer_tick",
+                Duration::from_millis(80),
+                Ok(()),
+                Some("mini-timer".to_string()),
+                Some("always-on-top".to_string()),
+            );
+        }
+        collector.record("cmd_config_get", Duration::from_millis(5), Err("error"), None);
+
+        let summary = collector.get_command_metrics("cmd_timer_tick").unwrap();
+        assert_eq!(summary.by_window.len(), 2);
+        assert_eq!(summary.by_window["main"].p50, 10);
+        assert_eq!(summary.by_window["mini-timer"].p50, 80);
+
+        let overall = collector.get_summary();
+        let windows = overall.by_window();
+        assert_eq!(windows.len(), 3);
+        assert_eq!(windows["mini-timer"].count, 10);
+        assert_eq!(windows[DEFAULT_WINDOW_LABEL].count, 1);
+        assert_eq!(windows[DEFAULT_WINDOW_LABEL].failure_count, 1);
+    }
+
+    #[test]
+    fn persisted_metrics_survive_reload() {
+        let store = Arc::new(MetricsStore::open_memory().unwrap());
+        let config = MetricsConfig {
+            persist: true,
+            slow_threshold_ms: 90,
+            ..Default::default()
+        };
+
+        let collector = MetricsCollector::with_store(config.clone(), Arc::clone(&store));
+        for i in 0..100 {
+            collector.record("cmd_test", Duration::from_millis(i), Ok(()), None);
+        }
+        collector.record("cmd_test", Duration::from_millis(100), Err("error"), None);
+        let before = collector.get_command_metrics("cmd_test").unwrap();
+        collector.flush().unwrap();
+        drop(collector);
+
+        let reloaded = MetricsCollector::with_store(config, store);
+        let after = reloaded.get_command_metrics("cmd_test").unwrap();
+        assert_eq!(after.total_count, 101);
+        assert_eq!(after.failure_count, 1);
+        assert_eq!(after.min_ms, 0);
+        assert_eq!(after.max_ms, 100);
+        assert_eq!(after.p50, before.p50);
+        assert!(after.p50.abs_diff(50) <= 5, "p50 = {}", after.p50);
+        assert!(after.p95.abs_diff(95) <= 8, "p95 = {}", after.p95);
+        assert_eq!(reloaded.get_slow_alerts().len(), 10);
+        assert_eq!(reloaded.get_summary().total_commands, 101);
+
+        let trend = reloaded.get_latency_trend("cmd_test");
+        assert_eq!(trend.len(), 1);
+        assert_eq!(trend[0].count, 101);
+    }
+
+    #[test]
+    fn flush_without_persistence_is_noop() {
+        let collector = MetricsCollector::new();
+        collector.record("cmd_test", Duration::from_millis(10), Ok(()), None);
+        assert!(collector.flush().is_ok());
+        assert!(collector.get_latency_trend("cmd_test").is_empty());
+    }
+
     #[test]
     fn percentile_empty_data() {
         assert_eq!(percentile(&[], 50), 0);
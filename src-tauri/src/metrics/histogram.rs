@@ -0,0 +1,180 @@
+//! Mergeable latency histogram.
+//!
+//! Durations are bucketed on a log-linear scale (exact below 16ms, then eight
+//! sub-buckets per power of two), so percentiles stay within ~12.5% while
+//! the histogram can be persisted and merged across restarts.
+
+#![allow(dead_code)]
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+/// Values below this are bucketed exactly.
+const LINEAR_LIMIT: u64 = 16;
+
+/// Sub-buckets per power of two above `LINEAR_LIMIT`.
+const SUB_BUCKETS: u32 = 8;
+
+/// Aggregated latency distribution for a command.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct LatencyHistogram {
+    /// Number of recorded executions.
+    pub count: u64,
+    /// Number of successful executions.
+    pub success_count: u64,
+    /// Number of failed executions.
+    pub failure_count: u64,
+    /// Sum of durations in milliseconds.
+    pub sum_ms: u64,
+    /// Minimum duration in milliseconds.
+    pub min_ms: u64,
+    /// Maximum duration in milliseconds.
+    pub max_ms: u64,
+    /// Sparse bucket counts keyed by bucket index.
+    pub buckets: BTreeMap<u32, u64>,
+    /// First execution timestamp.
+    pub first_executed_at: Option<DateTime<Utc>>,
+    /// Last execution timestamp.
+    pub last_executed_at: Option<DateTime<Utc>>,
+}
+
+impl LatencyHistogram {
+    /// Record a single execution.
+    pub fn record(&mut self, duration_ms: u64, success: bool, at: DateTime<Utc>) {
+        if self.count == 0 || duration_ms < self.min_ms {
+            self.min_ms = duration_ms;
+        }
+        self.max_ms = self.max_ms.max(duration_ms);
+        self.count += 1;
+        if success {
+            self.success_count += 1;
+        } else {
+            self.failure_count += 1;
+        }
+        self.sum_ms = self.sum_ms.saturating_add(duration_ms);
+        *self.buckets.entry(bucket_index(duration_ms)).or_insert(0) += 1;
+        self.first_executed_at = Some(self.first_executed_at.map_or(at, |t| t.min(at)));
+        self.last_executed_at = Some(self.last_executed_at.map_or(at, |t| t.max(at)));
+    }
+
+    /// Merge another histogram into this one.
+    pub fn merge(&mut self, other: &LatencyHistogram) {
+        if other.count == 0 {
+            return;
+        }
+        if self.count == 0 || other.min_ms < self.min_ms {
+            self.min_ms = other.min_ms;
+        }
+        self.max_ms = self.max_ms.max(other.max_ms);
+        self.count += other.count;
+        self.success_count += other.success_count;
+        self.failure_count += other.failure_count;
+        self.sum_ms = self.sum_ms.saturating_add(other.sum_ms);
+        for (index, count) in &other.buckets {
+            *self.buckets.entry(*index).or_insert(0) += count;
+        }
+        self.first_executed_at = match (self.first_executed_at, other.first_executed_at) {
+            (Some(a), Some(b)) => Some(a.min(b)),
+            (a, b) => a.or(b),
+        };
+        self.last_executed_at = match (self.last_executed_at, other.last_executed_at) {
+            (Some(a), Some(b)) => Some(a.max(b)),
+            (a, b) => a.or(b),
+        };
+    }
+
+    /// Average duration in milliseconds.
+    pub fn avg_ms(&self) -> u64 {
+        self.sum_ms.checked_div(self.count).unwrap_or(0)
+    }
+
+    /// Approximate percentile (0-100) in milliseconds.
+    ///
+    /// Uses the same rank convention as exact in-memory percentiles and
+    /// reports the midpoint of the matching bucket, clamped to min/max.
+    pub fn percentile(&self, p: u64) -> u64 {
+        if self.count == 0 {
+            return 0;
+        }
+        let rank = (self.count * p / 100).min(self.count - 1);
+        let mut seen = 0;
+        for (index, count) in &self.buckets {
+            seen += count;
+            if seen > rank {
+                let lower = bucket_lower_bound(*index);
+                let upper = bucket_lower_bound(*index + 1);
+                let mid = lower + (upper - lower - 1) / 2;
+                return mid.clamp(self.min_ms, self.max_ms);
+            }
+        }
+        self.max_ms
+    }
+}
+
+/// Bucket index for a duration.
+fn bucket_index(value: u64) -> u32 {
+    if value < LINEAR_LIMIT {
+        return value as u32;
+    }
+    let msb = 63 - value.leading_zeros();
+    let sub = ((value >> (msb - 3)) as u32) & (SUB_BUCKETS - 1);
+    LINEAR_LIMIT as u32 + (msb - 4) * SUB_BUCKETS + sub
+}
+
+/// Smallest duration that falls into `index`.
+fn bucket_lower_bound(index: u32) -> u64 {
+    if u64::from(index) < LINEAR_LIMIT {
+        return u64::from(index);
+    }
+    let offset = index - LINEAR_LIMIT as u32;
+    let msb = offset / SUB_BUCKETS + 4;
+    let sub = u64::from(offset % SUB_BUCKETS);
+    (u64::from(SUB_BUCKETS) + sub) << (msb - 3)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn bucket_bounds_round_trip() {
+        for value in [0, 1, 15, 16, 17, 31, 32, 100, 1_000, 65_535, 1_000_000] {
+            let index = bucket_index(value);
+            assert!(bucket_lower_bound(index) <= value);
+            assert!(value < bucket_lower_bound(index + 1));
+        }
+    }
+
+    #[test]
+    fn merge_matches_single_histogram() {
+        let now = Utc::now();
+        let mut all = LatencyHistogram::default();
+        let mut a = LatencyHistogram::default();
+        let mut b = LatencyHistogram::default();
+        for i in 0..200 {
+            all.record(i, i % 10 != 0, now);
+            if i < 100 {
+                a.record(i, i % 10 != 0, now);
+            } else {
+                b.record(i, i % 10 != 0, now);
+            }
+        }
+        a.merge(&b);
+        assert_eq!(a, all);
+    }
+
+    #[test]
+    fn percentiles_are_approximate() {
+        let mut hist = LatencyHistogram::default();
+        for i in 0..1_000 {
+            hist.record(i, true, Utc::now());
+        }
+        let p50 = hist.percentile(50);
+        let p95 = hist.percentile(95);
+        assert!((440..=560).contains(&p50), "p50 = {p50}");
+        assert!((840..=1_000).contains(&p95), "p95 = {p95}");
+        assert_eq!(hist.percentile(0), 0);
+        assert_eq!(hist.avg_ms(), 499);
+    }
+}
@@ -4,6 +4,7 @@
 //! - Capture p50/p95 latency by command
 //! - Classify recoverable vs fatal failures
 //! - Correlate with window label/context
+//! - Optionally persist latency histograms so summaries survive restarts
 //!
 //! ## Usage
 //! ```rust,ignore
@@ -12,17 +13,34 @@
 //! let collector = MetricsCollector::new();
 //!
 //! // Record command execution
-//! collector.record_command("cmd_timer_start", Duration::from_millis(15), Ok(()));
+//! collector.record_command(
+//!     "cmd_timer_start",
+//!     Duration::from_millis(15),
+//!     Ok(()),
+//!     Some("main".to_string()),
+//!     None,
+//! );
 //!
 //! // Query metrics
-//! let summary = collector.get_command_summary("cmd_timer_start");
-//! println!("p50: {:?}, p95: {:?}", summary.p50, summary.p95);
+//! let summary = collector.get_command_metrics("cmd_timer_start");
+//! if let Some(summary) = summary {
+//!     println!("p50: {:?}, p95: {:?}", summary.p50, summary.p95);
+//!     for (window, latency) in &summary.by_window {
+//!         println!("{window}: p95 {}", latency.p95);
+//!     }
+//! }
 //! ```
 
 mod command;
+mod histogram;
+mod store;
 
 #[allow(unused_imports)]
 pub use command::{
     CommandMetrics, CommandRecord, FailureClassification, MetricsCollector, MetricsConfig,
-    MetricsSummary, SlowCommandAlert,
+    MetricsSummary, SlowCommandAlert, WindowLatency, DEFAULT_WINDOW_LABEL,
 };
+#[allow(unused_imports)]
+pub use histogram::LatencyHistogram;
+#[allow(unused_imports)]
+pub use store::{DailyLatency, MetricsStore};
@@ -0,0 +1,327 @@
+//! SQLite persistence for command metrics.
+//!
+//! Latency is stored as one histogram row per (command, day), which keeps the
+//! table small and allows week-over-week trends without raw records.
+
+#![allow(dead_code)]
+
+use super::command::SlowCommandAlert;
+use super::histogram::LatencyHistogram;
+use chrono::{DateTime, NaiveDate, Utc};
+use pomodoroom_core::storage::ConnectionOptions;
+use rusqlite::{params, Connection, OptionalExtension};
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashMap};
+use std::path::PathBuf;
+use std::sync::Mutex;
+
+/// Latency aggregate for one command on one day.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DailyLatency {
+    /// Day (UTC) the executions happened on.
+    pub day: NaiveDate,
+    /// Number of executions.
+    pub count: u64,
+    /// Number of failed executions.
+    pub failure_count: u64,
+    /// Average latency in milliseconds.
+    pub avg_ms: u64,
+    /// Approximate p50 latency in milliseconds.
+    pub p50: u64,
+    /// Approximate p95 latency in milliseconds.
+    pub p95: u64,
+}
+
+/// SQLite-backed metrics store.
+pub struct MetricsStore {
+    conn: Mutex<Connection>,
+}
+
+impl MetricsStore {
+    /// Open the metrics store at the default location.
+    pub fn open() -> Result<Self, String> {
+        let path = Self::metrics_path()?;
+        let conn = ConnectionOptions::default()
+            .open(&path)
+            .map_err(|e| e.to_string())?;
+        Self::with_connection(conn)
+    }
+
+    /// Open an in-memory store (for testing).
+    #[cfg(test)]
+    pub fn open_memory() -> Result<Self, String> {
+        let conn = ConnectionOptions::default()
+            .open_in_memory()
+            .map_err(|e| e.to_string())?;
+        Self::with_connection(conn)
+    }
+
+    fn with_connection(conn: Connection) -> Result<Self, String> {
+        conn.execute_batch(
+            "CREATE TABLE IF NOT EXISTS command_metrics (
+                command TEXT NOT NULL,
+                day TEXT NOT NULL,
+                count INTEGER NOT NULL,
+                success_count INTEGER NOT NULL,
+                failure_count INTEGER NOT NULL,
+                sum_ms INTEGER NOT NULL,
+                min_ms INTEGER NOT NULL,
+                max_ms INTEGER NOT NULL,
+                buckets TEXT NOT NULL,
+                first_executed_at TEXT,
+                last_executed_at TEXT,
+                PRIMARY KEY (command, day)
+            );
+            CREATE INDEX IF NOT EXISTS idx_command_metrics_day ON command_metrics(day);
+            CREATE TABLE IF NOT EXISTS command_slow_alerts (
+                id INTEGER PRIMARY KEY AUTOINCREMENT,
+                command TEXT NOT NULL,
+                duration_ms INTEGER NOT NULL,
+                threshold_ms INTEGER NOT NULL,
+                window_label TEXT,
+                timestamp TEXT NOT NULL
+            );
+            CREATE INDEX IF NOT EXISTS idx_command_slow_alerts_timestamp
+                ON command_slow_alerts(timestamp);
+            ",
+        )
+        .map_err(|e| e.to_string())?;
+
+        Ok(Self {
+            conn: Mutex::new(conn),
+        })
+    }
+
+    /// Get the default metrics file path.
+    fn metrics_path() -> Result<PathBuf, String> {
+        let data_dir = dirs::data_dir().ok_or("Cannot determine data directory")?;
+        let app_dir = data_dir.join("pomodoroom");
+        std::fs::create_dir_all(&app_dir).map_err(|e| e.to_string())?;
+        Ok(app_dir.join("metrics.db"))
+    }
+
+    /// Merge pending per-day histograms and slow alerts into storage.
+    pub fn save(
+        &self,
+        histograms: &HashMap<(String, NaiveDate), LatencyHistogram>,
+        alerts: &[SlowCommandAlert],
+    ) -> Result<(), String> {
+        let mut conn = self.conn.lock().map_err(|e| format!("Lock failed: {e}"))?;
+        let tx = conn.transaction().map_err(|e| e.to_string())?;
+
+        for ((command, day), pending) in histograms {
+            let day_str = day.to_string();
+            let mut merged = tx
+                .query_row(
+                    "SELECT count, success_count, failure_count, sum_ms, min_ms, max_ms,
+                            buckets, first_executed_at, last_executed_at
+                     FROM command_metrics WHERE command = ?1 AND day = ?2",
+                    params![command, day_str],
+                    row_to_histogram,
+                )
+                .optional()
+                .map_err(|e| e.to_string())?
+                .unwrap_or_default();
+            merged.merge(pending);
+
+            let buckets = serde_json::to_string(&merged.buckets).map_err(|e| e.to_string())?;
+            tx.execute(
+                "INSERT OR REPLACE INTO command_metrics
+                 (command, day, count, success_count, failure_count, sum_ms, min_ms, max_ms,
+                  buckets, first_executed_at, last_executed_at)
+                 VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11)",
+                params![
+                    command,
+                    day_str,
+                    merged.count as i64,
+                    merged.success_count as i64,
+                    merged.failure_count as i64,
+                    merged.sum_ms as i64,
+                    merged.min_ms as i64,
+                    merged.max_ms as i64,
+                    buckets,
+                    merged.first_executed_at.map(|t| t.to_rfc3339()),
+                    merged.last_executed_at.map(|t| t.to_rfc3339()),
+                ],
+            )
+            .map_err(|e| e.to_string())?;
+        }
+
+        for alert in alerts {
+            tx.execute(
+                "INSERT INTO command_slow_alerts
+                 (command, duration_ms, threshold_ms, window_label, timestamp)
+                 VALUES (?1, ?2, ?3, ?4, ?5)",
+                params![
+                    alert.command,
+                    alert.duration_ms as i64,
+                    alert.threshold_ms as i64,
+                    alert.window_label,
+                    alert.timestamp.to_rfc3339(),
+                ],
+            )
+            .map_err(|e| e.to_string())?;
+        }
+
+        tx.commit().map_err(|e| e.to_string())
+    }
+
+    /// Load per-command histograms for days on or after `since`.
+    pub fn load_histograms(
+        &self,
+        since: NaiveDate,
+    ) -> Result<HashMap<String, LatencyHistogram>, String> {
+        let conn = self.conn.lock().map_err(|e| format!("Lock failed: {e}"))?;
+        let mut stmt = conn
+            .prepare(
+                "SELECT count, success_count, failure_count, sum_ms, min_ms, max_ms,
+                        buckets, first_executed_at, last_executed_at, command
+                 FROM command_metrics WHERE day >= ?1",
+            )
+            .map_err(|e| e.to_string())?;
+        let rows = stmt
+            .query_map(params![since.to_string()], |row| {
+                Ok((row.get::<_, String>(9)?, row_to_histogram(row)?))
+            })
+            .map_err(|e| e.to_string())?;
+
+        let mut histograms: HashMap<String, LatencyHistogram> = HashMap::new();
+        for row in rows {
+            let (command, hist) = row.map_err(|e| e.to_string())?;
+            histograms.entry(command).or_default().merge(&hist);
+        }
+        Ok(histograms)
+    }
+
+    /// Daily latency for `command` on or after `since`, oldest first.
+    pub fn load_daily(&self, command: &str, since: NaiveDate) -> Result<Vec<DailyLatency>, String> {
+        let conn = self.conn.lock().map_err(|e| format!("Lock failed: {e}"))?;
+        let mut stmt = conn
+            .prepare(
+                "SELECT count, success_count, failure_count, sum_ms, min_ms, max_ms,
+                        buckets, first_executed_at, last_executed_at, day
+                 FROM command_metrics WHERE command = ?1 AND day >= ?2
+                 ORDER BY day ASC",
+            )
+            .map_err(|e| e.to_string())?;
+        let rows = stmt
+            .query_map(params![command, since.to_string()], |row| {
+                Ok((row.get::<_, String>(9)?, row_to_histogram(row)?))
+            })
+            .map_err(|e| e.to_string())?;
+
+        let mut days = Vec::new();
+        for row in rows {
+            let (day, hist) = row.map_err(|e| e.to_string())?;
+            let day = day.parse::<NaiveDate>().map_err(|e| e.to_string())?;
+            days.push(DailyLatency {
+                day,
+                count: hist.count,
+                failure_count: hist.failure_count,
+                avg_ms: hist.avg_ms(),
+                p50: hist.percentile(50),
+                p95: hist.percentile(95),
+            });
+        }
+        Ok(days)
+    }
+
+    /// Load the most recent slow alerts, oldest first.
+    pub fn load_slow_alerts(&self, limit: usize) -> Result<Vec<SlowCommandAlert>, String> {
+        let conn = self.conn.lock().map_err(|e| format!("Lock failed: {e}"))?;
+        let mut stmt = conn
+            .prepare(
+                "SELECT command, duration_ms, threshold_ms, window_label, timestamp
+                 FROM command_slow_alerts ORDER BY timestamp DESC, id DESC LIMIT ?1",
+            )
+            .map_err(|e| e.to_string())?;
+        let rows = stmt
+            .query_map(params![limit as i64], |row| {
+                Ok(SlowCommandAlert {
+                    command: row.get(0)?,
+                    duration_ms: row.get::<_, i64>(1)? as u64,
+                    threshold_ms: row.get::<_, i64>(2)? as u64,
+                    window_label: row.get(3)?,
+                    timestamp: parse_timestamp(&row.get::<_, String>(4)?).unwrap_or_default(),
+                })
+            })
+            .map_err(|e| e.to_string())?;
+
+        let mut alerts = rows
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(|e| e.to_string())?;
+        alerts.reverse();
+        Ok(alerts)
+    }
+
+    /// Delete data older than `before`. Returns the number of rows removed.
+    pub fn prune(&self, before: NaiveDate) -> Result<usize, String> {
+        let conn = self.conn.lock().map_err(|e| format!("Lock failed: {e}"))?;
+        let metrics = conn
+            .execute(
+                "DELETE FROM command_metrics WHERE day < ?1",
+                params![before.to_string()],
+            )
+            .map_err(|e| e.to_string())?;
+        let alerts = conn
+            .execute(
+                "DELETE FROM command_slow_alerts WHERE timestamp < ?1",
+                params![before.to_string()],
+            )
+            .map_err(|e| e.to_string())?;
+        Ok(metrics + alerts)
+    }
+
+    /// Delete persisted data, for one command or all of them.
+    pub fn clear(&self, command: Option<&str>) -> Result<(), String> {
+        let conn = self.conn.lock().map_err(|e| format!("Lock failed: {e}"))?;
+        match command {
+            Some(command) => {
+                conn.execute(
+                    "DELETE FROM command_metrics WHERE command = ?1",
+                    params![command],
+                )
+                .map_err(|e| e.to_string())?;
+                conn.execute(
+                    "DELETE FROM command_slow_alerts WHERE command = ?1",
+                    params![command],
+                )
+                .map_err(|e| e.to_string())?;
+            }
+            None => {
+                conn.execute_batch("DELETE FROM command_metrics; DELETE FROM command_slow_alerts;")
+                    .map_err(|e| e.to_string())?;
+            }
+        }
+        Ok(())
+    }
+}
+
+fn parse_timestamp(value: &str) -> Option<DateTime<Utc>> {
+    DateTime::parse_from_rfc3339(value)
+        .ok()
+        .map(|dt| dt.with_timezone(&Utc))
+}
+
+/// Map the first nine histogram columns of a row.
+fn row_to_histogram(row: &rusqlite::Row) -> Result<LatencyHistogram, rusqlite::Error> {
+    let buckets: String = row.get(6)?;
+    let buckets: BTreeMap<u32, u64> = serde_json::from_str(&buckets).unwrap_or_default();
+    Ok(LatencyHistogram {
+        count: row.get::<_, i64>(0)? as u64,
+        success_count: row.get::<_, i64>(1)? as u64,
+        failure_count: row.get::<_, i64>(2)? as u64,
+        sum_ms: row.get::<_, i64>(3)? as u64,
+        min_ms: row.get::<_, i64>(4)? as u64,
+        max_ms: row.get::<_, i64>(5)? as u64,
+        buckets,
+        first_executed_at: row
+            .get::<_, Option<String>>(7)?
+            .as_deref()
+            .and_then(parse_timestamp),
+        last_executed_at: row
+            .get::<_, Option<String>>(8)?
+            .as_deref()
+            .and_then(parse_timestamp),
+    })
+}
@@ -7,6 +7,7 @@
 //! - Map parent task to tasklist entry
 //! - Sync child segments as checkable subtasks
 //! - Deterministic conflict resolution
+//! - Three-way merge against the last-synced snapshot
 //! - Bidirectional sync support
 
 #![allow(dead_code)]
@@ -62,6 +63,41 @@ pub struct TaskMapping {
     pub status: SyncStatus,
     /// ETag for optimistic concurrency.
     pub etag: Option<String>,
+    /// Snapshot of the task as of the last successful sync (merge base).
+    #[serde(default)]
+    pub base: Option<TaskSnapshot>,
+}
+
+/// Synced fields of a task, used as the common ancestor for merges.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct TaskSnapshot {
+    /// Task title.
+    pub title: String,
+    /// Task notes.
+    pub notes: Option<String>,
+    /// Whether task is completed.
+    pub completed: bool,
+}
+
+impl From<&LocalTaskInfo> for TaskSnapshot {
+    fn from(task: &LocalTaskInfo) -> Self {
+        Self {
+            title: task.title.clone(),
+            notes: task.notes.clone(),
+            completed: task.completed,
+        }
+    }
+}
+
+/// Result of a three-way merge for one mapping.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MergeOutcome {
+    /// Merged task state. Conflicting fields keep the local value.
+    pub merged: TaskSnapshot,
+    /// Fields taken from the only side that changed them.
+    pub auto_merged_fields: Vec<String>,
+    /// Fields changed differently on both sides.
+    pub conflicts: Vec<SyncConflict>,
 }
 
 /// Conflict record for sync operations.
@@ -149,6 +185,10 @@ pub struct ParentChildSyncManager {
     mappings: HashMap<String, TaskMapping>,
     /// Unresolved conflicts.
     conflicts: Vec<SyncConflict>,
+    /// Fields auto-merged by three-way merges.
+    auto_merged_count: usize,
+    /// Fields that diverged on both sides during three-way merges.
+    true_conflict_count: usize,
 }
 
 impl ParentChildSyncManager {
@@ -158,6 +198,8 @@ impl ParentChildSyncManager {
             config: SyncConfig::default(),
             mappings: HashMap::new(),
             conflicts: Vec::new(),
+            auto_merged_count: 0,
+            true_conflict_count: 0,
         }
     }
 
@@ -167,6 +209,8 @@ impl ParentChildSyncManager {
             config,
             mappings: HashMap::new(),
             conflicts: Vec::new(),
+            auto_merged_count: 0,
+            true_conflict_count: 0,
         }
     }
 
@@ -200,45 +244,72 @@ impl ParentChildSyncManager {
         self.mappings.get(local_id).map_or(false, |m| m.status == SyncStatus::Synced)
     }
 
-    /// Detect conflicts between local and remote task data.
+    /// Fields changed differently on both sides since the mapping's last sync.
+    ///
+    /// Uses the same three-way comparison as [`Self::merge`] but records
+    /// nothing, so it can be called to preview a sync.
     pub fn detect_conflicts(
+        &self,
+        local_id: &str,
+        local: &TaskSnapshot,
+        remote: &TaskSnapshot,
+    ) -> Result<Vec<SyncConflict>, String> {
+        let mapping = self
+            .mappings
+            .get(local_id)
+            .ok_or_else(|| format!("No mapping for task {local_id}"))?;
+        Ok(three_way_merge(mapping, local, remote, Utc::now()).conflicts)
+    }
+
+    /// Record `snapshot` as the last-synced state of a mapping.
+    pub fn record_synced(&mut self, local_id: &str, snapshot: TaskSnapshot) -> Result<(), String> {
+        let mapping = self
+            .mappings
+            .get_mut(local_id)
+            .ok_or_else(|| format!("No mapping for task {local_id}"))?;
+        mapping.base = Some(snapshot);
+        mapping.last_synced_at = Utc::now();
+        mapping.status = SyncStatus::Synced;
+        Ok(())
+    }
+
+    /// Three-way merge local and remote state against the mapping's base.
+    ///
+    /// A field changed on only one side takes that side's value. A field
+    /// changed differently on both sides is a conflict; it keeps the local
+    /// value and is added to the unresolved list. Without a base every
+    /// differing field is a conflict. A clean merge becomes the new base.
+    pub fn merge(
         &mut self,
-        local_title: &str,
-        local_completed: bool,
-        remote_title: &str,
-        remote_completed: bool,
-        local_updated: DateTime<Utc>,
-        remote_updated: DateTime<Utc>,
-    ) -> Vec<SyncConflict> {
-        let mut conflicts = Vec::new();
-
-        // Title conflict
-        if local_title != remote_title && local_updated > remote_updated {
-            conflicts.push(SyncConflict {
-                local_id: String::new(),
-                google_task_id: String::new(),
-                conflict_type: "title".to_string(),
-                local_value: local_title.to_string(),
-                remote_value: remote_title.to_string(),
-                detected_at: Utc::now(),
-                resolution: None,
-            });
+        local_id: &str,
+        local: &TaskSnapshot,
+        remote: &TaskSnapshot,
+    ) -> Result<MergeOutcome, String> {
+        let mapping = self
+            .mappings
+            .get(local_id)
+            .ok_or_else(|| format!("No mapping for task {local_id}"))?;
+        let MergeOutcome {
+            merged,
+            auto_merged_fields,
+            conflicts,
+        } = three_way_merge(mapping, local, remote, Utc::now());
+
+        self.auto_merged_count += auto_merged_fields.len();
+        self.true_conflict_count += conflicts.len();
+        self.conflicts.extend(conflicts.iter().cloned());
+
+        if conflicts.is_empty() {
+            self.record_synced(local_id, merged.clone())?;
+        } else if let Some(mapping) = self.mappings.get_mut(local_id) {
+            mapping.status = SyncStatus::Conflict;
         }
 
-        // Completion status conflict
-        if local_completed != remote_completed {
-            conflicts.push(SyncConflict {
-                local_id: String::new(),
-                google_task_id: String::new(),
-                conflict_type: "completed".to_string(),
-                local_value: local_completed.to_string(),
-                remote_value: remote_completed.to_string(),
-                detected_at: Utc::now(),
-                resolution: None,
-            });
-        }
-
-        conflicts
+        Ok(MergeOutcome {
+            merged,
+            auto_merged_fields,
+            conflicts,
+        })
     }
 
     /// Resolve a conflict using the configured strategy.
@@ -313,6 +384,101 @@ impl ParentChildSyncManager {
             synced_count,
             pending_count,
             conflict_count,
+            auto_merged_count: self.auto_merged_count,
+            true_conflict_count: self.true_conflict_count,
+        }
+    }
+}
+
+/// Three-way merge of `local` and `remote` against `mapping.base`.
+///
+/// Fields are compared as their own types; conflicting fields keep the
+/// local value.
+fn three_way_merge(
+    mapping: &TaskMapping,
+    local: &TaskSnapshot,
+    remote: &TaskSnapshot,
+    now: DateTime<Utc>,
+) -> MergeOutcome {
+    let base = mapping.base.as_ref();
+    let mut merger = FieldMerger {
+        mapping,
+        now,
+        auto_merged_fields: Vec::new(),
+        conflicts: Vec::new(),
+    };
+    let merged = TaskSnapshot {
+        title: merger.field(
+            "title",
+            &local.title,
+            &remote.title,
+            base.map(|b| &b.title),
+            String::clone,
+        ),
+        notes: merger.field(
+            "notes",
+            &local.notes,
+            &remote.notes,
+            base.map(|b| &b.notes),
+            |notes| notes.clone().unwrap_or_default(),
+        ),
+        completed: merger.field(
+            "completed",
+            &local.completed,
+            &remote.completed,
+            base.map(|b| &b.completed),
+            bool::to_string,
+        ),
+    };
+    MergeOutcome {
+        merged,
+        auto_merged_fields: merger.auto_merged_fields,
+        conflicts: merger.conflicts,
+    }
+}
+
+/// Collects the outcome of merging one snapshot field at a time.
+struct FieldMerger<'a> {
+    mapping: &'a TaskMapping,
+    now: DateTime<Utc>,
+    auto_merged_fields: Vec<String>,
+    conflicts: Vec<SyncConflict>,
+}
+
+impl FieldMerger<'_> {
+    /// Merge one field; `describe` renders values for conflict records.
+    fn field<T: Clone + PartialEq>(
+        &mut self,
+        name: &str,
+        local: &T,
+        remote: &T,
+        base: Option<&T>,
+        describe: fn(&T) -> String,
+    ) -> T {
+        if local == remote {
+            return local.clone();
+        }
+        match base {
+            Some(base) if base == local => {
+                self.auto_merged_fields.push(name.to_string());
+                remote.clone()
+            }
+            Some(base) if base == remote => {
+                self.auto_merged_fields.push(name.to_string());
+                local.clone()
+            }
+            _ => {
+                self.conflicts.push(SyncConflict {
+                    local_id: self.mapping.local_id.clone(),
+                    google_task_id: self.mapping.google_task_id.clone(),
+                    conflict_type: name.to_string(),
+                    local_value: describe(local),
+                    remote_value: describe(remote),
+                    detected_at: self.now,
+                    resolution: None,
+                });
+                local.clone()
+            }
         }
     }
 }
@@ -353,6 +519,10 @@ pub struct SyncStats {
     pub pending_count: usize,
     /// Unresolved conflicts.
     pub conflict_count: usize,
+    /// Fields auto-merged because only one side changed them.
+    pub auto_merged_count: usize,
+    /// Fields that diverged on both sides during merges.
+    pub true_conflict_count: usize,
 }
 
 #[cfg(test)]
@@ -372,6 +542,15 @@ mod tests {
             last_synced_at: Utc::now(),
             status: SyncStatus::Synced,
             etag: None,
+            base: None,
+        }
+    }
+
+    fn snapshot(title: &str, completed: bool) -> TaskSnapshot {
+        TaskSnapshot {
+            title: title.to_string(),
+            notes: None,
+            completed,
         }
     }
 
@@ -403,33 +582,52 @@ mod tests {
     #[test]
     fn detect_title_conflict() {
         let mut manager = create_manager();
-        let conflicts = manager.detect_conflicts(
-            "Local Title",
-            false,
-            "Remote Title",
-            false,
-            Utc::now(),
-            Utc::now() - chrono::Duration::seconds(10),
-        );
+        let mut mapping = create_mapping("task-1", "google-1");
+        mapping.base = Some(snapshot("Base Title", false));
+        manager.register_mapping(mapping);
+
+        let conflicts = manager
+            .detect_conflicts(
+                "task-1",
+                &snapshot("Local Title", false),
+                &snapshot("Remote Title", false),
+            )
+            .unwrap();
 
         assert_eq!(conflicts.len(), 1);
         assert_eq!(conflicts[0].conflict_type, "title");
+        assert_eq!(conflicts[0].local_value, "Local Title");
+        // Detection does not record anything
+        assert!(manager.get_unresolved_conflicts().is_empty());
+        assert!(manager.is_synced("task-1"));
     }
 
     #[test]
-    fn detect_completion_conflict() {
+    fn detect_ignores_one_sided_changes() {
         let mut manager = create_manager();
-        let conflicts = manager.detect_conflicts(
-            "Title",
-            true,
-            "Title",
-            false,
-            Utc::now(),
-            Utc::now(),
-        );
+        let mut mapping = create_mapping("task-1", "google-1");
+        mapping.base = Some(snapshot("Title", false));
+        manager.register_mapping(mapping);
+
+        // Only the remote side completed the task; only the local side renamed it
+        let conflicts = manager
+            .detect_conflicts("task-1", &snapshot("Renamed", false), &snapshot("Title", true))
+            .unwrap();
+        assert!(conflicts.is_empty());
+    }
+
+    #[test]
+    fn detect_completion_conflict_without_base() {
+        let mut manager = create_manager();
+        manager.register_mapping(create_mapping("task-1", "google-1"));
+
+        let conflicts = manager
+            .detect_conflicts("task-1", &snapshot("Title", true), &snapshot("Title", false))
+            .unwrap();
 
         assert_eq!(conflicts.len(), 1);
         assert_eq!(conflicts[0].conflict_type, "completed");
+        assert_eq!(conflicts[0].remote_value, "false");
     }
 
     #[test]
@@ -562,4 +760,68 @@ mod tests {
         manager.clear_resolved_conflicts();
         assert_eq!(manager.get_unresolved_conflicts().len(), 1); // Still unresolved
     }
+
+    #[test]
+    fn merge_combines_one_sided_changes() {
+        let mut manager = create_manager();
+        let mut mapping = create_mapping("task-1", "google-1");
+        mapping.base = Some(snapshot("Write report", false));
+        manager.register_mapping(mapping);
+
+        let local = snapshot("Write quarterly report", false);
+        let remote = snapshot("Write report", true);
+        let outcome = manager.merge("task-1", &local, &remote).unwrap();
+
+        assert!(outcome.conflicts.is_empty());
+        assert_eq!(outcome.merged, snapshot("Write quarterly report", true));
+        assert_eq!(outcome.auto_merged_fields, vec!["title", "completed"]);
+        assert_eq!(
+            manager.get_mapping("task-1").unwrap().base.as_ref(),
+            Some(&outcome.merged)
+        );
+
+        let stats = manager.get_stats();
+        assert_eq!(stats.auto_merged_count, 2);
+        assert_eq!(stats.true_conflict_count, 0);
+        assert_eq!(stats.conflict_count, 0);
+    }
+
+    #[test]
+    fn merge_flags_fields_changed_on_both_sides() {
+        let mut manager = create_manager();
+        let mut mapping = create_mapping("task-1", "google-1");
+        mapping.base = Some(snapshot("Base", false));
+        manager.register_mapping(mapping);
+
+        let outcome = manager
+            .merge("task-1", &snapshot("Local", false), &snapshot("Remote", false))
+            .unwrap();
+
+        assert_eq!(outcome.conflicts.len(), 1);
+        assert_eq!(outcome.conflicts[0].conflict_type, "title");
+        assert_eq!(outcome.conflicts[0].google_task_id, "google-1");
+        assert_eq!(outcome.merged.title, "Local");
+
+        let mapping = manager.get_mapping("task-1").unwrap();
+        assert_eq!(mapping.status, SyncStatus::Conflict);
+        assert_eq!(mapping.base, Some(snapshot("Base", false)));
+
+        let stats = manager.get_stats();
+        assert_eq!(stats.true_conflict_count, 1);
+        assert_eq!(stats.conflict_count, 1);
+    }
+
+    #[test]
+    fn merge_without_base_treats_differences_as_conflicts() {
+        let mut manager = create_manager();
+        manager.register_mapping(create_mapping("task-1", "google-1"));
+
+        let outcome = manager
+            .merge("task-1", &snapshot("Title", true), &snapshot("Title", false))
+            .unwrap();
+
+        assert_eq!(outcome.conflicts.len(), 1);
+        assert_eq!(outcome.conflicts[0].conflict_type, "completed");
+        assert!(manager.merge("missing", &snapshot("a", false), &snapshot("a", false)).is_err());
+    }
 }
@@ -9,6 +9,7 @@
 //! - Reversible profile switching with backup
 //! - Segment linking to source issue/PR
 //! - No default behavior regression when integrations disabled
+//! - Auto-deactivation after an idle period without linked-item activity
 
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
@@ -46,6 +47,28 @@ pub struct LinkedItem {
     pub linked_at: DateTime<Utc>,
 }
 
+/// Profile restored when no previous profile was recorded.
+pub const DEFAULT_PROFILE: &str = "balanced";
+
+/// Configuration for PR-focused mode.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PrFocusedConfig {
+    /// Minutes without linked-item activity before the mode auto-deactivates.
+    /// 0 disables auto-deactivation.
+    pub idle_timeout_minutes: u64,
+    /// Profile to restore when no previous profile was recorded.
+    pub default_profile: String,
+}
+
+impl Default for PrFocusedConfig {
+    fn default() -> Self {
+        Self {
+            idle_timeout_minutes: 30,
+            default_profile: DEFAULT_PROFILE.to_string(),
+        }
+    }
+}
+
 /// PR-focused mode state.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PrFocusedState {
@@ -59,6 +82,9 @@ pub struct PrFocusedState {
     pub linked_item: Option<LinkedItem>,
     /// Reason for activation.
     pub reason: String,
+    /// Last linked-item activity (activation counts as activity).
+    #[serde(default)]
+    pub last_activity_at: Option<DateTime<Utc>>,
 }
 
 impl Default for PrFocusedState {
@@ -69,6 +95,7 @@ impl Default for PrFocusedState {
             activated_at: None,
             linked_item: None,
             reason: String::new(),
+            last_activity_at: None,
         }
     }
 }
@@ -82,6 +109,9 @@ pub struct ModeSwitchResult {
     pub state: PrFocusedState,
     /// Message describing what happened.
     pub message: String,
+    /// Profile to restore after deactivation.
+    #[serde(default)]
+    pub restored_profile: Option<String>,
 }
 
 /// Statistics for PR-focused mode usage.
@@ -95,10 +125,18 @@ pub struct PrFocusedStats {
     pub by_source: HashMap<String, u64>,
     /// Most recent activation.
     pub last_activation: Option<DateTime<Utc>>,
+    /// Deactivations requested by the user.
+    #[serde(default)]
+    pub manual_deactivations: u64,
+    /// Deactivations triggered by the idle timeout.
+    #[serde(default)]
+    pub auto_deactivations: u64,
 }
 
 /// Manager for PR-focused mode state.
 pub struct PrFocusedManager {
+    /// Configuration.
+    config: PrFocusedConfig,
     /// Current state.
     state: Mutex<PrFocusedState>,
     /// Usage statistics.
@@ -108,7 +146,13 @@ pub struct PrFocusedManager {
 impl PrFocusedManager {
     /// Create a new PR-focused mode manager.
     pub fn new() -> Self {
+        Self::with_config(PrFocusedConfig::default())
+    }
+
+    /// Create a manager with custom configuration.
+    pub fn with_config(config: PrFocusedConfig) -> Self {
         Self {
+            config,
             state: Mutex::new(PrFocusedState::default()),
             stats: Mutex::new(PrFocusedStats::default()),
         }
@@ -140,12 +184,15 @@ impl PrFocusedManager {
                 success: false,
                 state: state.clone(),
                 message: "PR-focused mode is already active".to_string(),
+                restored_profile: None,
             });
         }
 
+        let now = Utc::now();
         state.active = true;
         state.previous_profile = previous_profile;
-        state.activated_at = Some(Utc::now());
+        state.activated_at = Some(now);
+        state.last_activity_at = Some(now);
         state.linked_item = linked_item.clone();
         state.reason = reason.clone();
 
@@ -163,6 +210,7 @@ impl PrFocusedManager {
             success: true,
             state: state.clone(),
             message: format!("Activated PR-focused mode: {}", reason),
+            restored_profile: None,
         })
     }
 
@@ -175,15 +223,19 @@ impl PrFocusedManager {
                 success: false,
                 state: state.clone(),
                 message: "PR-focused mode is not active".to_string(),
+                restored_profile: None,
             });
         }
 
         let previous_profile = state.previous_profile.clone();
 
         // Update stats with duration
-        if let Some(minutes) = duration_minutes {
+        {
             let mut stats = self.stats.lock().map_err(|e| format!("Lock failed: {e}"))?;
-            stats.total_minutes += minutes;
+            stats.manual_deactivations += 1;
+            if let Some(minutes) = duration_minutes {
+                stats.total_minutes += minutes;
+            }
         }
 
         // Reset state
@@ -198,13 +250,76 @@ impl PrFocusedManager {
             success: true,
             state: state.clone(),
             message,
+            restored_profile: Some(
+                previous_profile.unwrap_or_else(|| self.config.default_profile.clone()),
+            ),
         })
     }
 
+    /// Record linked-item activity, postponing the idle timeout.
+    pub fn record_activity(&self, now: DateTime<Utc>) -> Result<(), String> {
+        let mut state = self.state.lock().map_err(|e| format!("Lock failed: {e}"))?;
+        if state.active {
+            state.last_activity_at = Some(now);
+        }
+        Ok(())
+    }
+
+    /// Check the idle timeout and auto-deactivate if it has elapsed.
+    ///
+    /// Returns the switch result when the mode was exited, `None` otherwise.
+    /// The previous profile is restored, or the default when none was recorded.
+    pub fn tick(&self, now: DateTime<Utc>) -> Result<Option<ModeSwitchResult>, String> {
+        if self.config.idle_timeout_minutes == 0 {
+            return Ok(None);
+        }
+
+        let mut state = self.state.lock().map_err(|e| format!("Lock failed: {e}"))?;
+        if !state.active {
+            return Ok(None);
+        }
+
+        let Some(last_activity) = state.last_activity_at.or(state.activated_at) else {
+            return Ok(None);
+        };
+        let idle_minutes = (now - last_activity).num_minutes();
+        if idle_minutes < self.config.idle_timeout_minutes as i64 {
+            return Ok(None);
+        }
+
+        let active_minutes = state
+            .activated_at
+            .map(|at| (now - at).num_minutes().max(0) as u64)
+            .unwrap_or(0);
+        let restored = state
+            .previous_profile
+            .clone()
+            .unwrap_or_else(|| self.config.default_profile.clone());
+
+        {
+            let mut stats = self.stats.lock().map_err(|e| format!("Lock failed: {e}"))?;
+            stats.auto_deactivations += 1;
+            stats.total_minutes += active_minutes;
+        }
+
+        *state = PrFocusedState::default();
+
+        Ok(Some(ModeSwitchResult {
+            success: true,
+            state: state.clone(),
+            message: format!(
+                "PR-focused mode auto-deactivated after {} idle minutes. Restore profile: {}",
+                idle_minutes, restored
+            ),
+            restored_profile: Some(restored),
+        }))
+    }
+
     /// Link an item to the current session.
     pub fn link_item(&self, item: LinkedItem) -> Result<(), String> {
         let mut state = self.state.lock().map_err(|e| format!("Lock failed: {e}"))?;
         state.linked_item = Some(item);
+        state.last_activity_at = Some(Utc::now());
         Ok(())
     }
 
@@ -363,6 +478,57 @@ mod tests {
         assert!(stats.by_source.contains_key("GitHubPr"));
     }
 
+    #[test]
+    fn tick_auto_deactivates_after_idle_timeout() {
+        let manager = PrFocusedManager::with_config(PrFocusedConfig {
+            idle_timeout_minutes: 20,
+            ..Default::default()
+        });
+        manager.activate(Some("deep-work".to_string()), None, "Review".to_string()).unwrap();
+        let start = manager.get_state().unwrap().activated_at.unwrap();
+
+        assert!(manager.tick(start + chrono::Duration::minutes(19)).unwrap().is_none());
+        manager.record_activity(start + chrono::Duration::minutes(15)).unwrap();
+        assert!(manager.tick(start + chrono::Duration::minutes(30)).unwrap().is_none());
+
+        let result = manager
+            .tick(start + chrono::Duration::minutes(35))
+            .unwrap()
+            .expect("should auto-deactivate");
+        assert!(result.success);
+        assert_eq!(result.restored_profile.as_deref(), Some("deep-work"));
+        assert!(!manager.is_active().unwrap());
+
+        let stats = manager.get_stats().unwrap();
+        assert_eq!(stats.auto_deactivations, 1);
+        assert_eq!(stats.manual_deactivations, 0);
+        assert_eq!(stats.total_minutes, 35);
+    }
+
+    #[test]
+    fn tick_restores_default_profile_when_none_recorded() {
+        let manager = create_manager();
+        manager.activate(None, None, "Review".to_string()).unwrap();
+        let start = manager.get_state().unwrap().activated_at.unwrap();
+
+        let result = manager
+            .tick(start + chrono::Duration::minutes(60))
+            .unwrap()
+            .unwrap();
+        assert_eq!(result.restored_profile.as_deref(), Some(DEFAULT_PROFILE));
+    }
+
+    #[test]
+    fn manual_deactivation_is_counted() {
+        let manager = create_manager();
+        manager.activate(None, None, "Review".to_string()).unwrap();
+        manager.deactivate(None).unwrap();
+
+        let stats = manager.get_stats().unwrap();
+        assert_eq!(stats.manual_deactivations, 1);
+        assert_eq!(stats.auto_deactivations, 0);
+    }
+
     #[test]
     fn detect_pr_context_pr_title() {
         let result = detect_pr_focused_context("Review PR #123");
@@ -7,14 +7,16 @@
 //! - Triggers: timer state, task state, schedule events
 //! - Conditions: time range, tags, energy level
 //! - Actions: create break, defer task, notify, switch mode
-//! - Deterministic execution with failure logging
+//! - Deterministic execution with execution logging
 //! - Test-run simulation support
+//! - Recipes and execution history persisted to SQLite via `RecipeStore`
 
 #![allow(dead_code)]
 
-use chrono::{DateTime, Datelike, Timelike, Utc};
+use chrono::{DateTime, Datelike, Duration, Timelike, Utc};
+use pomodoroom_core::storage::{RecipeExecutionRecord, ScheduleDb, StoredRecipe};
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use std::collections::{HashMap, VecDeque};
 use std::sync::Mutex;
 
 /// Trigger types that can start recipe execution.
@@ -375,28 +377,169 @@ pub struct RecipeStats {
     pub by_recipe: HashMap<String, u64>,
 }
 
+/// How long execution history is kept on disk.
+pub const EXECUTION_LOG_RETENTION_DAYS: i64 = 30;
+
+/// Maximum number of entries returned by `get_execution_log`.
+pub const EXECUTION_LOG_LIMIT: usize = 500;
+
+/// SQLite persistence for recipes and their execution history.
+pub struct RecipeStore {
+    db: Mutex<ScheduleDb>,
+    retention: Duration,
+}
+
+impl RecipeStore {
+    /// Open the store in the default schedule database.
+    pub fn open() -> Result<Self, String> {
+        let db = ScheduleDb::open().map_err(|e| format!("Database error: {e}"))?;
+        Ok(Self::with_db(db))
+    }
+
+    /// Wrap an already opened database.
+    pub fn with_db(db: ScheduleDb) -> Self {
+        Self {
+            db: Mutex::new(db),
+            retention: Duration::days(EXECUTION_LOG_RETENTION_DAYS),
+        }
+    }
+
+    /// Load every stored recipe.
+    ///
+    /// Rows whose definition no longer deserializes are skipped.
+    pub fn load(&self) -> Result<Vec<Recipe>, String> {
+        let stored = self
+            .db
+            .lock()
+            .unwrap()
+            .list_recipes()
+            .map_err(|e| e.to_string())?;
+        Ok(stored
+            .into_iter()
+            .filter_map(|row| serde_json::from_value(row.definition).ok())
+            .collect())
+    }
+
+    /// Insert or replace a recipe.
+    pub fn save(&self, recipe: &Recipe) -> Result<(), String> {
+        let stored = StoredRecipe {
+            id: recipe.id.clone(),
+            name: recipe.name.clone(),
+            enabled: recipe.enabled,
+            priority: recipe.priority as i32,
+            definition: serde_json::to_value(recipe).map_err(|e| e.to_string())?,
+            updated_at: recipe.updated_at,
+        };
+        self.db
+            .lock()
+            .unwrap()
+            .upsert_recipe(&stored)
+            .map_err(|e| e.to_string())
+    }
+
+    /// Remove a recipe, keeping its execution history.
+    pub fn remove(&self, id: &str) -> Result<bool, String> {
+        self.db
+            .lock()
+            .unwrap()
+            .delete_recipe(id)
+            .map_err(|e| e.to_string())
+    }
+
+    /// Append an execution result to the log.
+    pub fn record(&self, result: &RecipeResult) -> Result<(), String> {
+        let record = RecipeExecutionRecord {
+            recipe_id: result.recipe_id.clone(),
+            executed_at: result.executed_at,
+            success: result.success,
+            result: serde_json::to_value(result).map_err(|e| e.to_string())?,
+        };
+        self.db
+            .lock()
+            .unwrap()
+            .record_recipe_execution(&record)
+            .map_err(|e| e.to_string())
+    }
+
+    /// Execution results within the retention window, oldest first.
+    ///
+    /// Entries past retention are pruned as a side effect.
+    pub fn recent(&self, limit: usize) -> Result<Vec<RecipeResult>, String> {
+        let since = Utc::now() - self.retention;
+        let db = self.db.lock().unwrap();
+        db.prune_recipe_executions(since)
+            .map_err(|e| e.to_string())?;
+        let records = db
+            .recent_recipe_executions(since, limit)
+            .map_err(|e| e.to_string())?;
+        Ok(records
+            .into_iter()
+            .rev()
+            .filter_map(|record| serde_json::from_value(record.result).ok())
+            .collect())
+    }
+
+    /// Remove all execution history.
+    pub fn clear_log(&self) -> Result<(), String> {
+        self.db
+            .lock()
+            .unwrap()
+            .prune_recipe_executions(Utc::now() + Duration::seconds(1))
+            .map(|_| ())
+            .map_err(|e| e.to_string())
+    }
+}
+
 /// Recipe engine for managing and executing recipes.
 pub struct RecipeEngine {
     /// Registered recipes.
     recipes: Mutex<HashMap<String, Recipe>>,
     /// Execution statistics.
     stats: Mutex<RecipeStats>,
-    /// Execution log for failed actions.
-    execution_log: Mutex<Vec<RecipeResult>>,
+    /// Execution log for the current session, keeping the latest
+    /// `EXECUTION_LOG_LIMIT` entries.
+    execution_log: Mutex<VecDeque<RecipeResult>>,
+    /// Persistent storage, if any.
+    store: Option<RecipeStore>,
 }
 
 impl RecipeEngine {
-    /// Create a new recipe engine.
+    /// Create a new in-memory recipe engine.
     pub fn new() -> Self {
         Self {
             recipes: Mutex::new(HashMap::new()),
             stats: Mutex::new(RecipeStats::default()),
-            execution_log: Mutex::new(Vec::new()),
+            execution_log: Mutex::new(VecDeque::new()),
+            store: None,
         }
     }
 
+    /// Create a recipe engine backed by `store`, loading saved recipes.
+    pub fn with_store(store: RecipeStore) -> Result<Self, String> {
+        let recipes: HashMap<_, _> = store
+            .load()?
+            .into_iter()
+            .map(|recipe| (recipe.id.clone(), recipe))
+            .collect();
+        let stats = RecipeStats {
+            total_recipes: recipes.len() as u64,
+            ..RecipeStats::default()
+        };
+        Ok(Self {
+            recipes: Mutex::new(recipes),
+            stats: Mutex::new(stats),
+            execution_log: Mutex::new(VecDeque::new()),
+            store: Some(store),
+        })
+    }
+
     /// Register a recipe.
     pub fn register(&self, recipe: Recipe) {
+        if let Some(store) = &self.store {
+            if let Err(e) = store.save(&recipe) {
+                eprintln!("Failed to persist recipe {}: {}", recipe.id, e);
+            }
+        }
         let mut recipes = self.recipes.lock().unwrap();
         let mut stats = self.stats.lock().unwrap();
         let id = recipe.id.clone();
@@ -406,6 +549,11 @@ impl RecipeEngine {
 
     /// Unregister a recipe.
     pub fn unregister(&self, id: &str) -> bool {
+        if let Some(store) = &self.store {
+            if let Err(e) = store.remove(id) {
+                eprintln!("Failed to remove stored recipe {}: {}", id, e);
+            }
+        }
         let mut recipes = self.recipes.lock().unwrap();
         let mut stats = self.stats.lock().unwrap();
         let removed = recipes.remove(id).is_some();
@@ -605,10 +753,17 @@ impl RecipeEngine {
             *stats.by_recipe.entry(recipe_id.to_string()).or_insert(0) += 1;
         }
 
-        // Log failed actions
-        if !success {
-            self.execution_log.lock().unwrap().push(result.clone());
+        // Log the execution
+        if let Some(store) = &self.store {
+            if let Err(e) = store.record(&result) {
+                eprintln!("Failed to persist recipe execution {}: {}", recipe_id, e);
+            }
         }
+        let mut log = self.execution_log.lock().unwrap();
+        if log.len() == EXECUTION_LOG_LIMIT {
+            log.pop_front();
+        }
+        log.push_back(result.clone());
 
         Some(result)
     }
@@ -639,13 +794,27 @@ impl RecipeEngine {
         *stats = RecipeStats::default();
     }
 
-    /// Get execution log.
+    /// Get the execution log, oldest first.
+    ///
+    /// With a store this reads recent history from disk, bounded by
+    /// `EXECUTION_LOG_RETENTION_DAYS` and `EXECUTION_LOG_LIMIT`.
     pub fn get_execution_log(&self) -> Vec<RecipeResult> {
-        self.execution_log.lock().unwrap().clone()
+        if let Some(store) = &self.store {
+            match store.recent(EXECUTION_LOG_LIMIT) {
+                Ok(log) => return log,
+                Err(e) => eprintln!("Failed to read recipe execution log: {}", e),
+            }
+        }
+        self.execution_log.lock().unwrap().iter().cloned().collect()
     }
 
     /// Clear execution log.
     pub fn clear_execution_log(&self) {
+        if let Some(store) = &self.store {
+            if let Err(e) = store.clear_log() {
+                eprintln!("Failed to clear recipe execution log: {}", e);
+            }
+        }
         self.execution_log.lock().unwrap().clear();
     }
 }
@@ -938,6 +1107,61 @@ mod tests {
         assert_eq!(log.len(), 1);
     }
 
+    #[test]
+    fn in_memory_execution_log_keeps_latest_entries() {
+        let engine = create_engine();
+        engine.register(create_recipe("first", TriggerType::Manual));
+        engine.register(create_recipe("rest", TriggerType::Manual));
+
+        engine.execute("first", &RecipeContext::new());
+        for _ in 0..EXECUTION_LOG_LIMIT {
+            engine.execute("rest", &RecipeContext::new());
+        }
+
+        let log = engine.get_execution_log();
+        assert_eq!(log.len(), EXECUTION_LOG_LIMIT);
+        assert!(log.iter().all(|result| result.recipe_id == "rest"));
+    }
+
+    #[test]
+    fn stored_recipe_survives_engine_recreation() {
+        let path =
+            std::env::temp_dir().join(format!("pomodoroom-recipes-{}.db", uuid::Uuid::new_v4()));
+        let open_store = || {
+            let options = pomodoroom_core::storage::ConnectionOptions::default();
+            RecipeStore::with_db(ScheduleDb::open_with_options(&path, &options).unwrap())
+        };
+
+        {
+            let engine = RecipeEngine::with_store(open_store()).unwrap();
+            engine.register(
+                create_recipe("persisted", TriggerType::Manual)
+                    .with_priority(3)
+                    .with_enabled(false),
+            );
+            engine.register(create_recipe("removed", TriggerType::Manual));
+            engine.unregister("removed");
+            assert!(engine.execute("persisted", &RecipeContext::new()).is_some());
+        }
+
+        let engine = RecipeEngine::with_store(open_store()).unwrap();
+        let recipe = engine.get("persisted").unwrap();
+        assert_eq!(recipe.priority, 3);
+        assert!(!recipe.enabled);
+        assert!(engine.get("removed").is_none());
+        assert_eq!(engine.get_stats().total_recipes, 1);
+
+        let log = engine.get_execution_log();
+        assert_eq!(log.len(), 1);
+        assert_eq!(log[0].recipe_id, "persisted");
+        assert!(log[0].success);
+
+        engine.clear_execution_log();
+        assert!(engine.get_execution_log().is_empty());
+        drop(engine);
+        let _ = std::fs::remove_file(&path);
+    }
+
     #[test]
     fn recipe_priority_ordering() {
         let engine = create_engine();
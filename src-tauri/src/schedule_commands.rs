@@ -11,18 +11,24 @@ use chrono::{DateTime, Duration, Utc};
 use pomodoroom_core::schedule::{
     DailyTemplate, Group, Project, ProjectReference, Task, TaskCategory, TaskKind,
 };
-use pomodoroom_core::scheduler::{AutoScheduler, CalendarEvent};
-use pomodoroom_core::storage::{DataResetOptions, ScheduleDb};
-use pomodoroom_core::task::{TaskState, TaskStateMachine, TransitionAction};
+use pomodoroom_core::scheduler::{AutoScheduler, CalendarEvent, ScheduledBlock};
+use pomodoroom_core::simulation::check_invariants;
+use pomodoroom_core::storage::{DataResetOptions, ScheduleDb, TaskQuery};
+use pomodoroom_core::task::{
+    Recurrence, TaskState, TaskStateMachine, TaskTransitionOutcome, TransitionAction,
+};
+use pomodoroom_core::timer::TimerEngine;
 use serde::Deserialize;
 use serde_json::Value;
 use std::collections::HashMap;
+use std::sync::Mutex;
 use tauri::State;
 use uuid::Uuid;
 
 // Re-use timer state from bridge module
 use crate::bridge::{
-    internal_timer_reset, internal_timer_update_session, EngineState,
+    internal_timer_reset, internal_timer_update_session, update_session_locked, ActiveSession,
+    EngineState,
 };
 
 // === Security Validation Constants ===
@@ -158,7 +164,7 @@ const DEFAULT_WAKE_UP: &str = "07:00";
 const DEFAULT_SLEEP: &str = "23:00";
 
 /// Default max parallel lanes for daily template
-const DEFAULT_MAX_PARALLEL_LANES: Option<i32> = Some(2);
+pub(crate) const DEFAULT_MAX_PARALLEL_LANES: Option<i32> = Some(2);
 
 // === Helper Functions ===
 
@@ -327,6 +333,8 @@ fn load_all_tasks(db: &ScheduleDb) -> Result<Vec<Task>, String> {
 /// * `estimated_pomodoros` - Estimated number of pomodoros (default: 1)
 /// * `priority` - Optional priority 0-100 (default: 50)
 /// * `category` - Task category: "active" or "someday" (default: "active")
+/// * `recurrence` - Optional recurrence rule; the task becomes a recurring
+///   template that spawns instances instead of being scheduled itself
 ///
 /// # Returns
 /// The created task as JSON
@@ -349,6 +357,7 @@ pub fn cmd_task_create(
     estimated_start_at: Option<String>,
     state: Option<String>,
     completed: Option<bool>,
+    recurrence: Option<Value>,
 ) -> Result<Value, String> {
     // Validate title
     validate_name(&title)?;
@@ -370,6 +379,10 @@ pub fn cmd_task_create(
     let window_start_at = parse_optional_datetime(window_start_at, "window_start_at")?;
     let window_end_at = parse_optional_datetime(window_end_at, "window_end_at")?;
     let estimated_start_at = parse_optional_datetime(estimated_start_at, "estimated_start_at")?;
+    let recurrence: Option<Recurrence> = recurrence
+        .map(serde_json::from_value)
+        .transpose()
+        .map_err(|e| format!("Invalid recurrence: {e}"))?;
 
     validate_task_kind_fields(
         kind,
@@ -429,6 +442,10 @@ pub fn cmd_task_create(
         allow_split: true,
         suggested_tags: Vec::new(),
         approved_tags: Vec::new(),
+        external_block: None,
+        recurrence,
+        recurrence_parent_id: None,
+        notes: Vec::new(),
     };
 
     db.create_task(&task)
@@ -450,6 +467,8 @@ pub fn cmd_task_create(
 /// * `completed` - New completion status (optional)
 /// * `priority` - New priority (optional)
 /// * `category` - New category (optional)
+/// * `external_block` - Description of an external blocker (optional)
+/// * `clear_external_block` - Remove the external blocker (optional)
 ///
 /// # Returns
 /// The updated task as JSON
@@ -477,6 +496,8 @@ pub fn cmd_task_update(
     clear_window_start_at: Option<bool>,
     clear_window_end_at: Option<bool>,
     clear_estimated_start_at: Option<bool>,
+    external_block: Option<String>,
+    clear_external_block: Option<bool>,
 ) -> Result<Value, String> {
     // Validate task ID
     validate_task_id(&id)?;
@@ -567,6 +588,11 @@ pub fn cmd_task_update(
     } else if estimated_start_at.is_some() {
         task.estimated_start_at = estimated_start_at;
     }
+    if clear_external_block.unwrap_or(false) {
+        task.external_block = None;
+    } else if external_block.is_some() {
+        task.external_block = external_block;
+    }
 
     validate_task_kind_fields(
         task.kind,
@@ -597,53 +623,75 @@ pub fn cmd_task_delete(id: String) -> Result<(), String> {
         .map_err(|e| format!("Failed to delete task: {e}"))
 }
 
-/// Lists tasks with optional filtering.
+/// Lists tasks with optional filtering and pagination.
 ///
 /// # Arguments
 /// * `project_id` - Optional project ID to filter by
-/// * `category` - Optional category filter ("active" or "someday")
+/// * `category` - Optional category filter ("active", "wait" or "floating")
+/// * `state` - Optional state filter ("READY", "RUNNING", "PAUSED" or "DONE")
+/// * `created_from` - Optional inclusive lower bound on creation time (ISO 8601)
+/// * `created_to` - Optional exclusive upper bound on creation time (ISO 8601)
+/// * `limit` - Optional maximum number of tasks to return
+/// * `offset` - Optional number of matching tasks to skip
 ///
 /// # Returns
-/// Array of tasks as JSON
+/// `{ items, total, offset, limit }`: the page of tasks, ordered by priority
+/// (desc) then creation time, and the number of matching tasks across all pages
 #[tauri::command]
 pub fn cmd_task_list(
     project_id: Option<String>,
     category: Option<String>,
+    state: Option<String>,
+    created_from: Option<String>,
+    created_to: Option<String>,
+    limit: Option<u32>,
+    offset: Option<u32>,
 ) -> Result<Value, String> {
     // Validate project_id if provided
     if let Some(ref pid) = project_id {
         validate_project_id(pid)?;
     }
 
+    let category = match category.as_deref() {
+        None => None,
+        Some("active") => Some(TaskCategory::Active),
+        Some("wait") => Some(TaskCategory::Wait),
+        Some("floating") => Some(TaskCategory::Floating),
+        Some(other) => return Err(format!("Invalid task category: {other}")),
+    };
+    let state = match state {
+        Some(value) => Some(parse_task_state(Some(value))?),
+        None => None,
+    };
+
+    let query = TaskQuery {
+        state,
+        category,
+        project_id,
+        created_from: parse_optional_datetime(created_from, "created_from")?,
+        created_to: parse_optional_datetime(created_to, "created_to")?,
+        limit,
+        offset: offset.unwrap_or(0),
+        include_templates: false,
+    };
+
     let db = ScheduleDb::open().map_err(|e| format!("Database error: {e}"))?;
 
-    let all_tasks = db
-        .list_tasks()
-        .map_err(|e| format!("Failed to list tasks: {e}"))?;
+    // Listing is the app's daily touch point: materialize today's recurring
+    // instances first (idempotent per day).
+    db.generate_due_instances(Utc::now())
+        .map_err(|e| format!("Failed to generate recurring tasks: {e}"))?;
 
-    let filtered: Vec<Task> = all_tasks
-        .into_iter()
-        .filter(|task| {
-            if let Some(ref pid) = project_id {
-                if task.project_id.as_ref() != Some(pid) {
-                    return false;
-                }
-            }
-            if let Some(ref cat) = category {
-                let task_cat = match task.category {
-                    TaskCategory::Active => "active",
-                    TaskCategory::Wait => "wait",
-                    TaskCategory::Floating => "floating",
-                };
-                if task_cat != cat.as_str() {
-                    return false;
-                }
-            }
-            true
-        })
-        .collect();
+    let page = db
+        .query_tasks(&query)
+        .map_err(|e| format!("Failed to list tasks: {e}"))?;
 
-    serde_json::to_value(&filtered).map_err(|e| format!("JSON error: {e}"))
+    Ok(serde_json::json!({
+        "items": page.tasks,
+        "total": page.total,
+        "offset": query.offset,
+        "limit": query.limit,
+    }))
 }
 
 /// Gets a single task by ID.
@@ -1067,6 +1115,8 @@ pub fn cmd_template_set(template_json: Value) -> Result<(), String> {
 
 /// Generates a daily schedule from template and available tasks.
 ///
+/// Locked schedule blocks of the day are kept in place and scheduled around.
+///
 /// # Arguments
 /// * `date_iso` - Target date in ISO format (YYYY-MM-DD)
 /// * `calendar_events_json` - Optional array of calendar events to avoid
@@ -1087,8 +1137,14 @@ pub fn cmd_schedule_generate(
         .transpose()?
         .unwrap_or_default();
 
+    let existing_blocks = db
+        .list_schedule_blocks(Some(&date), None)
+        .map_err(|e| format!("Failed to load schedule blocks: {e}"))?;
+
     let scheduler = AutoScheduler::new();
-    let scheduled_blocks = scheduler.generate_schedule(&template, &tasks, &calendar_events, date);
+    let scheduled_blocks =
+        scheduler.regenerate_schedule(&template, &tasks, &calendar_events, &existing_blocks, date);
+    validate_schedule(&scheduled_blocks, &template, &calendar_events, date)?;
 
     serde_json::to_value(&scheduled_blocks).map_err(|e| format!("JSON error: {e}"))
 }
@@ -1119,10 +1175,28 @@ pub fn cmd_schedule_auto_fill(
 
     let scheduler = AutoScheduler::new();
     let scheduled_blocks = scheduler.auto_fill(&template, &tasks, &calendar_events, date);
+    validate_schedule(&scheduled_blocks, &template, &calendar_events, date)?;
 
     serde_json::to_value(&scheduled_blocks).map_err(|e| format!("JSON error: {e}"))
 }
 
+/// Refuse to hand the UI a schedule that breaks a planning invariant.
+///
+/// Locked blocks and fixed tasks are exempt (see `check_invariants`).
+fn validate_schedule(
+    blocks: &[ScheduledBlock],
+    template: &DailyTemplate,
+    calendar_events: &[CalendarEvent],
+    date: DateTime<Utc>,
+) -> Result<(), String> {
+    let violations = check_invariants(blocks, template, calendar_events, date);
+    if violations.is_empty() {
+        return Ok(());
+    }
+    let details: Vec<String> = violations.iter().map(|v| v.to_string()).collect();
+    Err(format!("Generated schedule is invalid: {}", details.join("; ")))
+}
+
 // === Schedule Block commands ===
 
 use pomodoroom_core::schedule::{BlockType, ScheduleBlock};
@@ -1342,16 +1416,105 @@ pub fn cmd_task_start(id: String, engine: State<'_, EngineState>) -> Result<Valu
         .map_err(|e| format!("Failed to update task: {e}"))?;
 
     // Auto-start timer with task info
+    start_task_timer(&engine, &updated_task);
+
+    serde_json::to_value(&updated_task).map_err(|e| format!("JSON error: {e}"))
+}
+
+/// Point the timer at a task that has just become RUNNING.
+///
+/// Shared by single-task and batch start/resume so both link the timer the
+/// same way.
+fn start_task_timer(engine: &EngineState, task: &Task) {
     internal_timer_update_session(
-        &engine,
-        Some(id.clone()),
+        engine,
+        Some(task.id.clone()),
+        task.project_id.clone(),
+        Some(task.title.clone()),
+        task.required_minutes.unwrap_or(25) as u32,
+        task.elapsed_minutes as u32,
+    );
+}
+
+/// Start focusing on a task: task RUNNING + timer started, all or nothing.
+///
+/// # Arguments
+/// * `task_id` - Task ID to focus on
+///
+/// # Returns
+/// JSON object with the updated `task` and the `timer` state snapshot
+///
+/// # Behavior
+/// - Transitions the task to RUNNING and persists it
+/// - Starts the timer linked to the task and records the active session
+/// - If the timer cannot be started, the task is restored to its previous
+///   state so a RUNNING task never exists with a stopped timer
+/// - The engine lock is held throughout, so concurrent starts are serialized
+#[tauri::command]
+pub fn cmd_focus_start(task_id: String, engine: State<'_, EngineState>) -> Result<Value, String> {
+    validate_task_id(&task_id)?;
+
+    let db = ScheduleDb::open().map_err(|e| format!("Database error: {e}"))?;
+
+    let mut engine_guard = engine
+        .engine
+        .lock()
+        .map_err(|e| format!("Lock failed: {e}"))?;
+
+    start_focus_locked(&db, &mut engine_guard, &engine.active_session, &task_id)
+}
+
+/// Starts a task and its timer with the engine lock already held.
+///
+/// All or nothing: if the timer cannot be started, the engine and the task
+/// are put back as they were.
+fn start_focus_locked(
+    db: &ScheduleDb,
+    engine_guard: &mut TimerEngine,
+    session_lock: &Mutex<ActiveSession>,
+    task_id: &str,
+) -> Result<Value, String> {
+    let original_task = db
+        .get_task(task_id)
+        .map_err(|e| format!("Failed to get task: {e}"))?
+        .ok_or_else(|| format!("Task not found: {task_id}"))?;
+
+    let mut state_machine = TaskStateMachine::new(original_task.clone());
+    state_machine
+        .apply_action(TransitionAction::Start)
+        .map_err(|e| format!("Cannot start task: {e}"))?;
+
+    let updated_task = state_machine.task;
+    db.update_task(&updated_task)
+        .map_err(|e| format!("Failed to update task: {e}"))?;
+
+    // Start the timer; on any failure restore both the engine and the task.
+    let previous_engine = engine_guard.clone();
+    let session_result = update_session_locked(
+        engine_guard,
+        session_lock,
+        Some(task_id.to_string()),
         updated_task.project_id.clone(),
         Some(updated_task.title.clone()),
         updated_task.required_minutes.unwrap_or(25) as u32,
         updated_task.elapsed_minutes as u32,
     );
 
-    serde_json::to_value(&updated_task).map_err(|e| format!("JSON error: {e}"))
+    if let Err(e) = session_result {
+        *engine_guard = previous_engine;
+        return match db.update_task(&original_task) {
+            Ok(()) => Err(format!("Failed to start focus: {e}")),
+            Err(rollback) => Err(format!(
+                "Failed to start focus: {e}; task rollback failed: {rollback}"
+            )),
+        };
+    }
+
+    let snapshot = engine_guard.snapshot();
+    Ok(serde_json::json!({
+        "task": updated_task,
+        "timer": snapshot,
+    }))
 }
 
 /// Pause a running task: RUNNING → PAUSED
@@ -1473,14 +1636,7 @@ pub fn cmd_task_resume(id: String, engine: State<'_, EngineState>) -> Result<Val
         .map_err(|e| format!("Failed to update task: {e}"))?;
 
     // Resume timer with updated task info
-    internal_timer_update_session(
-        &engine,
-        Some(id.clone()),
-        updated_task.project_id.clone(),
-        Some(updated_task.title.clone()),
-        updated_task.required_minutes.unwrap_or(25) as u32,
-        updated_task.elapsed_minutes as u32,
-    );
+    start_task_timer(&engine, &updated_task);
 
     serde_json::to_value(&updated_task).map_err(|e| format!("JSON error: {e}"))
 }
@@ -1564,6 +1720,57 @@ pub fn cmd_task_postpone(id: String, engine: State<'_, EngineState>) -> Result<V
     serde_json::to_value(&updated_task).map_err(|e| format!("JSON error: {e}"))
 }
 
+/// Apply one transition action to several tasks at once.
+///
+/// # Arguments
+/// * `ids` - Task IDs to transition
+/// * `action` - Transition action (e.g. "complete", "pause")
+///
+/// # Returns
+/// A `BatchTransitionResult` with one outcome per task, in request order
+///
+/// # Behavior
+/// - Tasks that cannot take the action (missing, or in a terminal state) are
+///   reported as failed; the rest are still transitioned
+/// - All successful transitions are committed together
+/// - **Resets the timer** when a task leaves RUNNING
+/// - **Starts the timer** on start/resume like `cmd_task_start`; with several
+///   tasks it follows the last one transitioned
+#[tauri::command]
+pub fn cmd_tasks_transition_batch(
+    ids: Vec<String>,
+    action: TransitionAction,
+    engine: State<'_, EngineState>,
+) -> Result<Value, String> {
+    for id in &ids {
+        validate_task_id(id)?;
+    }
+
+    let db = ScheduleDb::open().map_err(|e| format!("Database error: {e}"))?;
+    let result = db
+        .apply_transitions(&ids, action)
+        .map_err(|e| format!("Failed to transition tasks: {e}"))?;
+
+    let leaves_running = matches!(
+        action,
+        TransitionAction::Pause | TransitionAction::Complete | TransitionAction::Postpone
+    );
+    if leaves_running && result.applied_count() > 0 {
+        internal_timer_reset(&engine);
+    }
+    if matches!(action, TransitionAction::Start | TransitionAction::Resume) {
+        let started = result.outcomes.iter().rev().find_map(|outcome| match outcome {
+            TaskTransitionOutcome::Applied { task } => Some(task),
+            TaskTransitionOutcome::Failed { .. } => None,
+        });
+        if let Some(task) = started {
+            start_task_timer(&engine, task);
+        }
+    }
+
+    serde_json::to_value(&result).map_err(|e| format!("JSON error: {e}"))
+}
+
 /// Defer a task until specified datetime with reason tracking and priority recalculation.
 ///
 /// # Arguments
@@ -1755,3 +1962,47 @@ pub fn cmd_task_available_actions(id: String) -> Result<Value, String> {
 
     serde_json::to_value(&action_names).map_err(|e| format!("JSON error: {e}"))
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn failed_timer_start_rolls_back_task_and_engine() {
+        let db = ScheduleDb::open_memory().unwrap();
+        let task = Task::new("Write report");
+        db.create_task(&task).unwrap();
+
+        // A poisoned session lock makes starting the timer fail
+        let state = EngineState::new();
+        let _ = std::panic::catch_unwind(|| {
+            let _session = state.active_session.lock().unwrap();
+            panic!("poison the session lock");
+        });
+
+        let mut engine = state.engine.lock().unwrap();
+        let before = serde_json::to_value(&*engine).unwrap();
+        let err =
+            start_focus_locked(&db, &mut engine, &state.active_session, &task.id).unwrap_err();
+        assert!(err.starts_with("Failed to start focus"));
+
+        assert_eq!(serde_json::to_value(&*engine).unwrap(), before);
+        let stored = db.get_task(&task.id).unwrap().unwrap();
+        assert_eq!(stored.state, task.state);
+    }
+
+    #[test]
+    fn focus_start_runs_task_and_timer() {
+        let db = ScheduleDb::open_memory().unwrap();
+        let task = Task::new("Write report");
+        db.create_task(&task).unwrap();
+
+        let state = EngineState::new();
+        let mut engine = state.engine.lock().unwrap();
+        start_focus_locked(&db, &mut engine, &state.active_session, &task.id).unwrap();
+
+        let stored = db.get_task(&task.id).unwrap().unwrap();
+        assert_eq!(stored.state, TaskState::Running);
+        assert_eq!(engine.state(), pomodoroom_core::timer::TimerState::Running);
+    }
+}
@@ -1,12 +1,12 @@
 //! Google Calendar sync commands for Tauri IPC.
 //!
 //! Provides commands for:
-//! - Startup sync (fetch remote changes on app launch)
+//! - Startup sync (fetch remote changes on app launch, push queued local ones)
 //! - Manual sync (user-initiated sync)
 //! - Sync status query
 
 use chrono::Utc;
-use pomodoroom_core::sync::{CalendarClient, SyncStatus};
+use pomodoroom_core::sync::{CalendarClient, SyncEngine, SyncQueue, SyncStatus};
 use pomodoroom_core::integrations::{google::GoogleIntegration, Integration};
 use serde::{Deserialize, Serialize};
 use std::sync::Mutex;
@@ -89,8 +89,7 @@ pub async fn cmd_sync_startup(
 pub async fn cmd_sync_manual(
     sync_state: State<'_, SyncState>,
 ) -> Result<SyncResult, String> {
-    // For now, manual sync is same as startup sync
-    // In future, will also push local changes
+    // Manual sync runs the same fetch and push as startup sync
     cmd_sync_startup(sync_state).await
 }
 
@@ -142,7 +141,27 @@ async fn do_sync() -> SyncResult {
         },
     };
 
-    let count = events.len();
+    // Push local changes queued while offline or since the last sync
+    let mut queue = SyncQueue::new();
+    if let Err(e) = queue.load() {
+        return SyncResult {
+            success: false,
+            events_processed: events.len(),
+            synced_at: Utc::now().to_rfc3339(),
+            error: Some(format!("Failed to load sync queue: {e}")),
+        };
+    }
+    let queued = queue.len();
+    if let Err(e) = SyncEngine::new().flush(&mut queue) {
+        return SyncResult {
+            success: false,
+            events_processed: events.len(),
+            synced_at: Utc::now().to_rfc3339(),
+            error: Some(format!("Failed to push local changes: {e}")),
+        };
+    }
+
+    let count = events.len() + queued - queue.len();
 
     SyncResult {
         success: true,
@@ -6,6 +6,7 @@
 //! ## Features
 //! - Structured events: focus_started, break_started, segment_completed, interruption
 //! - Signed payloads with HMAC signatures
+//! - Verification of signed inbound webhooks
 //! - Retry policy with exponential backoff
 //! - Local queue for offline resilience
 //! - Versioned payload schema
@@ -15,8 +16,12 @@
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use std::collections::VecDeque;
+use std::fmt;
 use std::sync::Mutex;
 
+/// Prefix of the `sha256=<hex>` signature header format.
+const SHA256_PREFIX: &str = "sha256=";
+
 /// Webhook event types for session lifecycle.
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
 #[serde(rename_all = "snake_case")]
@@ -96,6 +101,71 @@ impl WebhookPayload {
     }
 }
 
+/// Errors from verifying an inbound webhook.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum WebhookError {
+    /// Signature header is empty.
+    MissingSignature,
+    /// Signature header is not a hex HMAC-SHA256, optionally prefixed with `sha256=`.
+    MalformedSignature,
+    /// Signature does not match the body and secret.
+    BadSignature,
+    /// Signature is valid but the body is not a webhook payload.
+    MalformedBody(String),
+}
+
+impl fmt::Display for WebhookError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::MissingSignature => write!(f, "Missing webhook signature"),
+            Self::MalformedSignature => write!(f, "Malformed webhook signature header"),
+            Self::BadSignature => write!(f, "Webhook signature does not match"),
+            Self::MalformedBody(e) => write!(f, "Malformed webhook body: {}", e),
+        }
+    }
+}
+
+impl std::error::Error for WebhookError {}
+
+/// Verify a signed inbound webhook and parse its payload.
+///
+/// `signature_header` is the hex HMAC-SHA256 of the raw `body`, either bare or
+/// in the `sha256=<hex>` form. The signature is checked in constant time
+/// before the body is parsed, so unauthenticated input is never deserialized.
+pub fn verify_inbound(
+    body: &[u8],
+    signature_header: &str,
+    secret: &[u8],
+) -> Result<WebhookPayload, WebhookError> {
+    let header = signature_header.trim();
+    if header.is_empty() {
+        return Err(WebhookError::MissingSignature);
+    }
+    let hex_signature = match header.get(..SHA256_PREFIX.len()) {
+        Some(prefix) if prefix.eq_ignore_ascii_case(SHA256_PREFIX) => {
+            &header[SHA256_PREFIX.len()..]
+        }
+        _ if header.contains('=') => return Err(WebhookError::MalformedSignature),
+        _ => header,
+    };
+    let signature = hex::decode(hex_signature).map_err(|_| WebhookError::MalformedSignature)?;
+
+    let expected = hmac_sha256::HMAC::mac(body, secret);
+    if !constant_time_eq(&signature, &expected) {
+        return Err(WebhookError::BadSignature);
+    }
+
+    serde_json::from_slice(body).map_err(|e| WebhookError::MalformedBody(e.to_string()))
+}
+
+/// Compare two byte strings without short-circuiting on the first mismatch.
+fn constant_time_eq(a: &[u8], b: &[u8]) -> bool {
+    if a.len() != b.len() {
+        return false;
+    }
+    a.iter().zip(b).fold(0u8, |acc, (x, y)| acc | (x ^ y)) == 0
+}
+
 /// Webhook endpoint configuration.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct WebhookEndpoint {
@@ -674,6 +744,69 @@ mod tests {
         assert_eq!(signature.len(), 64); // SHA-256 hex
     }
 
+    #[test]
+    fn verify_inbound_accepts_valid_signature() {
+        let payload = create_payload().with_task_id("task-1");
+        let body = payload.to_bytes().unwrap();
+        let signature = payload.sign(b"inbound_secret");
+
+        let verified =
+            verify_inbound(&body, &format!("sha256={}", signature), b"inbound_secret").unwrap();
+        assert_eq!(verified.event_id, payload.event_id);
+        assert_eq!(verified.task_id, Some("task-1".to_string()));
+
+        // Bare hex and uppercase prefix are accepted too
+        assert!(verify_inbound(&body, &signature, b"inbound_secret").is_ok());
+        assert!(verify_inbound(&body, &format!("SHA256={}", signature), b"inbound_secret").is_ok());
+    }
+
+    #[test]
+    fn verify_inbound_rejects_tampered_body() {
+        let payload = create_payload();
+        let signature = format!("sha256={}", payload.sign(b"inbound_secret"));
+
+        let tampered = payload.with_task_id("injected").to_bytes().unwrap();
+        assert_eq!(
+            verify_inbound(&tampered, &signature, b"inbound_secret").unwrap_err(),
+            WebhookError::BadSignature
+        );
+    }
+
+    #[test]
+    fn verify_inbound_rejects_wrong_secret() {
+        let payload = create_payload();
+        let body = payload.to_bytes().unwrap();
+        let signature = format!("sha256={}", payload.sign(b"inbound_secret"));
+
+        assert_eq!(
+            verify_inbound(&body, &signature, b"other_secret").unwrap_err(),
+            WebhookError::BadSignature
+        );
+    }
+
+    #[test]
+    fn verify_inbound_distinguishes_malformed_input() {
+        let body = b"not json";
+        let signature = hex::encode(hmac_sha256::HMAC::mac(body, b"inbound_secret"));
+
+        assert!(matches!(
+            verify_inbound(body, &signature, b"inbound_secret"),
+            Err(WebhookError::MalformedBody(_))
+        ));
+        assert_eq!(
+            verify_inbound(body, "", b"inbound_secret").unwrap_err(),
+            WebhookError::MissingSignature
+        );
+        assert_eq!(
+            verify_inbound(body, "sha256=zz", b"inbound_secret").unwrap_err(),
+            WebhookError::MalformedSignature
+        );
+        assert_eq!(
+            verify_inbound(body, &format!("sha1={}", signature), b"inbound_secret").unwrap_err(),
+            WebhookError::MalformedSignature
+        );
+    }
+
     #[test]
     fn payload_with_ids() {
         let payload = create_payload()
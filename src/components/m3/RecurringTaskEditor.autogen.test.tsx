@@ -29,10 +29,15 @@ describe("RecurringTaskEditor auto generation", () => {
 
 	it("does not create recurring tasks when DB already has today's recurring markers", async () => {
 		const dateKey = formatLocalDateKey(new Date());
-		invokeMock.mockResolvedValue([
-			{ description: `[recurring:life:lunch:${dateKey}] Auto-generated` },
-			{ description: `[recurring:life:dinner:${dateKey}] Auto-generated` },
-		]);
+		invokeMock.mockResolvedValue({
+			items: [
+				{ description: `[recurring:life:lunch:${dateKey}] Auto-generated` },
+				{ description: `[recurring:life:dinner:${dateKey}] Auto-generated` },
+			],
+			total: 2,
+			offset: 0,
+			limit: null,
+		});
 
 		render(<RecurringTaskEditor />);
 
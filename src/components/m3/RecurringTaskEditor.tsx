@@ -6,7 +6,7 @@ import { TextField } from "@/components/m3/TextField";
 import { DayTimelinePanel } from "@/components/m3/DayTimelinePanel";
 import { DEFAULT_DAILY_TEMPLATE } from "@/types/schedule";
 import type { DailyTemplate } from "@/types/schedule";
-import type { Task } from "@/types/task";
+import type { Task, TaskListPage } from "@/types/task";
 import { useTaskStore } from "@/hooks/useTaskStore";
 import {
 	buildRecurringAutoTasks,
@@ -488,7 +488,7 @@ export function RecurringTaskEditor({ action, actionNonce }: RecurringTaskEditor
 			// Load persisted tasks from database
 			const loadPersistedTasks = async () => {
 				try {
-					const persisted = await invoke<Array<Record<string, unknown>>>("cmd_task_list");
+					const { items: persisted } = await invoke<TaskListPage>("cmd_task_list");
 					return persisted.map((row) => ({
 						description: typeof row.description === "string" ? row.description : undefined,
 					}));
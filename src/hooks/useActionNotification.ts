@@ -7,6 +7,7 @@
 
 import { invoke } from "@tauri-apps/api/core";
 import type { ActionNotificationData } from "@/types/notification";
+import type { TaskListPage } from "@/types/task";
 import { pushNotificationDiagnostic } from "@/utils/notification-diagnostics";
 import {
 	dequeueReplayableNudge,
@@ -80,7 +81,7 @@ export async function showActionNotification(
 
 	let hasRunningFocus = false;
 	try {
-		const tasks = await invoke<any[]>("cmd_task_list");
+		const tasks = (await invoke<TaskListPage<any>>("cmd_task_list")).items;
 		hasRunningFocus = (tasks ?? []).some(
 			(task) => task?.state === "RUNNING" && task?.kind !== "break",
 		);
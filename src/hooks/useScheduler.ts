@@ -18,6 +18,7 @@
 import { useState, useCallback, useRef, useEffect } from "react";
 import { invoke } from "@tauri-apps/api/core";
 import type { ScheduleBlock, Task } from "@/types/schedule";
+import type { TaskListPage } from "@/types/task";
 import { generateMockSchedule, createMockProjects } from "@/utils/dev-mock-scheduler";
 import {
 	buildReplanDiff,
@@ -71,7 +72,8 @@ async function ensureRecurringTasksForDate(dateIso: string): Promise<void> {
 		}
 
 		// Load existing tasks from database
-		const existingTasks = await invoke<Array<Record<string, unknown>>>("cmd_task_list").catch(
+		const existingTasks = await invoke<TaskListPage>("cmd_task_list").then(
+			(page) => page.items,
 			() => [],
 		);
 		const existingTaskLikes: Array<{ description?: string }> = existingTasks.map((row) => ({
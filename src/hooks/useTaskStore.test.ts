@@ -47,7 +47,9 @@ describe("useTaskStore", () => {
 			},
 		];
 		mockInvoke.mockImplementation((command: string) => {
-			if (command === "cmd_task_list") return Promise.resolve(rows);
+			if (command === "cmd_task_list") {
+				return Promise.resolve({ items: rows, total: rows.length, offset: 0, limit: null });
+			}
 			return Promise.resolve(undefined);
 		});
 
@@ -10,7 +10,7 @@ import { useCallback, useMemo, useEffect, useState } from "react";
 import { invoke } from "@tauri-apps/api/core";
 import { listen } from "@tauri-apps/api/event";
 import { useTaskStateMap } from "./useTaskState";
-import type { Task } from "../types/task";
+import type { Task, TaskListPage } from "../types/task";
 import type { TaskState } from "../types/task-state";
 import { recalculateEstimatedStarts } from "@/utils/auto-schedule-time";
 import { findRecurringDuplicateTaskIds } from "@/utils/recurring-auto-generation";
@@ -462,7 +462,7 @@ export function useTaskStore(): UseTaskStoreReturn {
 		console.log("[useTaskStore] loadTasksFromSqlite called");
 		let tasksJson: any[];
 		try {
-			tasksJson = await invoke<any[]>("cmd_task_list");
+			tasksJson = (await invoke<TaskListPage<any>>("cmd_task_list")).items;
 		} catch (error) {
 			console.error("[useTaskStore] Failed to load tasks from SQLite:", error);
 			setTasks([]);
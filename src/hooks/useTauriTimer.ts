@@ -8,6 +8,7 @@ import { useCallback, useEffect, useRef, useState } from "react";
 import { invoke } from "@tauri-apps/api/core";
 import { isTauriEnvironment } from "@/lib/tauriEnv";
 import { pushNotificationDiagnostic } from "@/utils/notification-diagnostics";
+import { listenCoreEvents } from "@/utils/core-events";
 
 // Check if we're in a Tauri environment
 function isTauriAvailable(): boolean {
@@ -37,6 +38,8 @@ export interface TimerSnapshot {
 		type: "TimerCompleted";
 		step_index: number;
 		step_type: "focus" | "break";
+		planned_ms: number;
+		actual_ms: number;
 		at: string;
 	};
 }
@@ -93,6 +96,9 @@ const MAX_CONSECUTIVE_ERRORS = 10;
 // Dedup key for notifications
 let lastNotifiedCompletionKey: string | null = null;
 
+// Pushed snapshots from the bridge, shared by all hook instances
+let coreEventUnlisten: Promise<() => void> | null = null;
+
 function notifyTimerStateListeners(): void {
 	for (const listener of timerStateListeners) {
 		listener({
@@ -198,7 +204,7 @@ export function useTauriTimer() {
 		try {
 			// cmd_timer_tick returns TimerSnapshot with optional completed field
 			interface TimerTickResponse extends TimerSnapshot {
-				completed?: { type: "TimerCompleted"; step_index: number; step_type: "focus" | "break"; at: string };
+				completed?: { type: "TimerCompleted"; step_index: number; step_type: "focus" | "break"; planned_ms: number; actual_ms: number; at: string };
 			}
 			const response = await safeInvoke<TimerTickResponse>("cmd_timer_tick");
 			snap = response;
@@ -292,11 +298,22 @@ export function useTauriTimer() {
 		void fetchStatus();
 		void fetchWindowState();
 
+		// Apply snapshots pushed by the bridge without waiting for the next poll
+		if (isTauriAvailable() && !coreEventUnlisten) {
+			coreEventUnlisten = listenCoreEvents((event) => {
+				if (event.type === "StateSnapshot") {
+					setSharedSnapshot(event);
+				}
+			});
+		}
+
 		return () => {
 			unsubscribe();
 			subscriberCount = Math.max(0, subscriberCount - 1);
 			if (subscriberCount === 0) {
 				stopTicking();
+				coreEventUnlisten?.then((unlisten) => unlisten());
+				coreEventUnlisten = null;
 			}
 		};
 	}, [fetchStatus, fetchWindowState, stopTicking]);
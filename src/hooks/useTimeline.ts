@@ -1,6 +1,7 @@
 import { invoke } from "@tauri-apps/api/core";
 import type { TimelineItem, TaskProposal, TimeGap } from "../types";
 import type { GoogleCalendarEvent } from "./useGoogleCalendar";
+import type { TaskListPage } from "@/types/task";
 import { eventToTimeRange } from "@/utils/googleCalendarAdapter";
 import { isTauriEnvironment } from "@/lib/tauriEnv";
 
@@ -78,7 +79,7 @@ export function useTimeline() {
 		}
 
 		try {
-			const tasks = await invoke<Record<string, unknown>[]>("cmd_task_list", {
+			const { items: tasks } = await invoke<TaskListPage>("cmd_task_list", {
 				projectId: null,
 				category: "active",
 			});
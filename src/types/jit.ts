@@ -23,6 +23,8 @@ export interface JitContext {
 	completed_sessions: number;
 	/** Current timestamp for context (ISO string) */
 	now: string;
+	/** Interruptions logged since the last break */
+	interruptions_since_break?: number;
 }
 
 /**
@@ -49,6 +51,8 @@ export type SuggestionReason =
 	| "HighPriority"
 	/** Matches current energy level */
 	| "EnergyMatch"
+	/** Matches the energy expected from the learned curve at this hour */
+	| "EnergyCurveMatch"
 	/** Quick win (short duration) */
 	| "QuickWin"
 	/** Most recently deferred */
@@ -67,6 +71,33 @@ export interface TaskSuggestion {
 	reason: SuggestionReason;
 }
 
+/**
+ * What to do at the end of a focus session
+ */
+export type BreakRecommendation = "TakeBreak" | "OneMoreFocus";
+
+/**
+ * A signal that pushes toward taking a break
+ */
+export type BreakFactor =
+	| { LongBreakDue: { completed_sessions: number } }
+	| { LowEnergy: { energy: number } }
+	| { LongStretch: { minutes_since_break: number } }
+	| { FrequentInterruptions: { interruptions: number; minutes_since_break: number } };
+
+/**
+ * Break-or-continue recommendation with its reasons
+ */
+export interface BreakDecision {
+	recommendation: BreakRecommendation;
+	/** How sure the recommendation is (0.0-1.0) */
+	confidence: number;
+	/** Signals toward a break; empty for OneMoreFocus */
+	factors: BreakFactor[];
+	/** One-line explanation for display */
+	rationale: string;
+}
+
 // ─────────────────────────────────────────────────────────────────────────────
 // Tauri Command Types
 // ─────────────────────────────────────────────────────────────────────────────
@@ -108,6 +139,21 @@ export interface JitCommands {
 		time_since_break?: number,
 		completed_sessions?: number,
 	) => Promise<boolean>;
+
+	/**
+	 * Recommend a break or one more focus session, with reasons
+	 * @param energy Current energy level (0-100)
+	 * @param time_since_break Time since last break in minutes
+	 * @param completed_sessions Number of completed sessions today
+	 * @param interruptions Interruptions since the last break
+	 * @returns Recommendation, confidence and contributing factors
+	 */
+	jit_break_decision: (
+		energy?: number,
+		time_since_break?: number,
+		completed_sessions?: number,
+		interruptions?: number,
+	) => Promise<BreakDecision>;
 }
 
 // ─────────────────────────────────────────────────────────────────────────────
@@ -123,6 +169,8 @@ export function getSuggestionReasonLabel(reason: SuggestionReason): string {
 			return "High Priority";
 		case "EnergyMatch":
 			return "Matches Your Energy";
+		case "EnergyCurveMatch":
+			return "Your Peak Hours";
 		case "QuickWin":
 			return "Quick Win";
 		case "RecentlyDeferred":
@@ -143,6 +191,8 @@ export function getSuggestionReasonIcon(reason: SuggestionReason): string {
 			return "🔥";
 		case "EnergyMatch":
 			return "⚡";
+		case "EnergyCurveMatch":
+			return "📈";
 		case "QuickWin":
 			return "⚡";
 		case "RecentlyDeferred":
@@ -128,6 +128,8 @@ export interface FixedEvent {
 	durationMinutes: number;
 	days: number[]; // 0=Sun … 6=Sat
 	enabled: boolean;
+	/** Meals get a buffer so focus does not run right up to them (default "other") */
+	kind?: "meal" | "meeting" | "other";
 }
 
 export interface DailyTemplate {
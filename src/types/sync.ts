@@ -12,6 +12,8 @@ export interface SyncStatus {
 	pending_count: number;
 	/** Whether a sync is currently in progress */
 	in_progress: boolean;
+	/** Whether the last flush was skipped because the network was unreachable */
+	offline?: boolean;
 }
 
 /**
@@ -25,6 +25,18 @@ export type TransitionAction =
 	| "resume" // PAUSED → RUNNING
 	| "defer"; // READY → READY (priority down)
 
+/**
+ * One page of `cmd_task_list` results.
+ */
+export interface TaskListPage<T = Record<string, unknown>> {
+	items: T[];
+	/** Number of matching tasks across all pages */
+	total: number;
+	offset: number;
+	/** Page size, or null when unbounded */
+	limit: number | null;
+}
+
 /**
  * Task for v2 redesign with Active/Floating support.
  *
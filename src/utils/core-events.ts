@@ -0,0 +1,62 @@
+/**
+ * Core events pushed by the Rust bridge.
+ *
+ * The bridge emits timer, gatekeeper, settings and reconciliation changes as Tauri
+ * events so views can react without polling. See docs/API.md ("Pushed Events").
+ */
+
+import { listen, type UnlistenFn } from "@tauri-apps/api/event";
+
+/** Tauri event carrying core `Event`s */
+export const CORE_EVENT = "core:event";
+
+/** Tauri event carrying a reconciliation summary */
+export const RECONCILIATION_EVENT = "reconciliation:completed";
+
+export type CoreEvent =
+	| {
+			type: "TimerCompleted";
+			step_index: number;
+			step_type: "focus" | "break";
+			planned_ms: number;
+			actual_ms: number;
+			at: string;
+	  }
+	| {
+			type: "StateSnapshot";
+			state: "idle" | "running" | "drifting";
+			step_index: number;
+			step_type: "focus" | "break";
+			step_label: string;
+			remaining_ms: number;
+			total_ms: number;
+			schedule_progress_pct: number;
+			cycle_position: number;
+			pomodoros_before_long_break: number;
+			next_step: "focus" | "short_break" | "long_break";
+			at: string;
+	  }
+	| { type: "TimerReset"; at: string }
+	| {
+			type: "DriftingEscalated";
+			escalation_level: number;
+			break_debt_ms: number;
+			at: string;
+	  }
+	| {
+			type: "GatekeeperEscalated";
+			prompt_key: string;
+			from_level: "nudge" | "alert" | "gravity";
+			level: "nudge" | "alert" | "gravity";
+			channel: "badge" | "toast" | "modal";
+			dismissible: boolean;
+			break_debt_ms: number;
+			at: string;
+	  }
+	| { type: "GatekeeperPrompt"; prompt_key: string; task_id: string | null; at: string }
+	| { type: "ConfigChanged"; keys: string[]; at: string };
+
+/** Subscribe to core events; resolves to the unsubscribe function */
+export function listenCoreEvents(handler: (event: CoreEvent) => void): Promise<UnlistenFn> {
+	return listen<CoreEvent>(CORE_EVENT, ({ payload }) => handler(payload));
+}
@@ -29,6 +29,16 @@ export interface QuietHoursPolicy {
 	enabled: boolean;
 	startHour: number;
 	endHour: number;
+	/** Per-weekday overrides; a window belongs to the day it starts on */
+	weekdays?: Partial<Record<QuietHoursWeekday, QuietHoursWindow>>;
+}
+
+export type QuietHoursWeekday = "Mon" | "Tue" | "Wed" | "Thu" | "Fri" | "Sat" | "Sun";
+
+/** Quiet hours window for one day (crosses midnight when startHour > endHour) */
+export interface QuietHoursWindow {
+	startHour: number;
+	endHour: number;
 }
 
 /** Gatekeeper state from Rust backend */
@@ -37,6 +47,16 @@ export interface GatekeeperStateJson {
 	completedAt: string; // ISO timestamp
 	breakDebtMs: number;
 	promptKey: string;
+	snoozedMs: number;
+	remainingSnoozes: number;
+}
+
+export interface SnoozeRecordJson {
+	promptKey: string;
+	minutes: number;
+	level: "nudge" | "alert" | "gravity";
+	snoozedAt: string; // ISO timestamp
+	remainingSnoozes: number;
 }
 
 // === Gatekeeper Commands (Rust-backed) ===
@@ -88,10 +108,28 @@ export async function gatekeeperGetNotificationChannel(
  * Update gatekeeper with current time and return escalation state.
  *
  * Should be called periodically (e.g., every second) to update
- * escalation level based on elapsed time.
+ * escalation level based on elapsed time. Level rises are also pushed as
+ * `GatekeeperEscalated` core events.
+ *
+ * @param isDnd - Is DND enabled? Picks the channel of pushed events
+ * @param isQuietHours - Is currently in quiet hours?
+ */
+export async function gatekeeperTick(
+	isDnd = false,
+	isQuietHours = false,
+): Promise<GatekeeperStateJson | null> {
+	return await invoke("cmd_gatekeeper_tick", { isDnd, isQuietHours });
+}
+
+/**
+ * Snooze the current prompt, pushing escalation back.
+ *
+ * Rejected at Gravity level or once the snooze limit is reached.
+ *
+ * @param minutes - Snooze length in minutes (capped at 10 by default)
  */
-export async function gatekeeperTick(): Promise<GatekeeperStateJson | null> {
-	return await invoke("cmd_gatekeeper_tick");
+export async function gatekeeperSnooze(minutes: number): Promise<SnoozeRecordJson> {
+	return await invoke("cmd_gatekeeper_snooze", { minutes });
 }
 
 /**
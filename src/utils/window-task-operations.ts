@@ -1,5 +1,5 @@
 import { invoke } from "@tauri-apps/api/core";
-import type { Task } from "@/types/task";
+import type { Task, TaskListPage } from "@/types/task";
 import type { TaskOperation } from "@/components/m3/TaskOperations";
 import {
 	evaluateTaskEnergyMismatch,
@@ -53,7 +53,7 @@ export async function runWindowTaskOperation(
 				if (task.state === "PAUSED") {
 					await invoke("cmd_task_resume", { id: task.id });
 				} else {
-					const rawTasks = await invoke<any[]>("cmd_task_list");
+					const rawTasks = (await invoke<TaskListPage<any>>("cmd_task_list"))?.items;
 					const pressureValue = estimatePressureValue(rawTasks ?? []);
 					const target = toEnergyMismatchTask(task);
 					const mismatch = evaluateTaskEnergyMismatch(target, { pressureValue });
@@ -44,6 +44,7 @@ import {
 } from "@/utils/break-debt-policy";
 import { playNotificationSoundMaybe } from "@/utils/soundPlayer";
 import { pushNotificationDiagnostic } from "@/utils/notification-diagnostics";
+import type { TaskListPage } from "@/types/task";
 
 // Defer reason templates for postponement tracking
 export const DEFER_REASON_TEMPLATES = [
@@ -312,7 +313,7 @@ export function ActionNotificationView() {
 			let tasks: any[];
 			let tasksResult: any[];
 			try {
-				tasksResult = await invoke<any[]>("cmd_task_list");
+				tasksResult = (await invoke<TaskListPage<any>>("cmd_task_list"))?.items;
 				if (tasksResult !== null && tasksResult !== undefined) {
 					tasks = tasksResult;
 				} else {
@@ -454,7 +455,7 @@ export function ActionNotificationView() {
 					if (!action.start_task.ignoreEnergyMismatch) {
 						const [rawTask, rawTasks] = await Promise.all([
 							invoke<any>("cmd_task_get", { id: action.start_task.id }),
-							invoke<any[]>("cmd_task_list"),
+							invoke<TaskListPage<any>>("cmd_task_list").then((page) => page?.items),
 						]);
 
 						if (rawTask) {
@@ -536,7 +537,7 @@ export function ActionNotificationView() {
 					return;
 				}
 
-				const tasks = await invoke<any[]>("cmd_task_list");
+				const { items: tasks } = await invoke<TaskListPage<any>>("cmd_task_list");
 				const nowMs = Date.now();
 
 				const { durationMs } = calculateTaskData(task);